@@ -1,28 +1,35 @@
-use gpui::{App, Context, Entity, FocusHandle, Focusable, Render, SharedString, Styled, Window, div, prelude::*, px};
+use std::time::Duration;
+
+use gpui::{
+  App, Context, Entity, FocusHandle, Focusable, Render, SharedString, Styled, Subscription, Task, Timer, Window,
+  WindowBounds, div, prelude::*, px,
+};
 use gpui_component::{
-  Icon, IconName, Root, WindowExt,
+  Icon, IconName, Root, Selectable, Sizable, WindowExt,
   button::{Button, ButtonVariants},
   h_flex,
-  notification::NotificationType,
+  notification::{Notification, NotificationType},
   scroll::ScrollableElement,
   sidebar::{Sidebar, SidebarGroup, SidebarMenu, SidebarMenuItem},
   theme::{ActiveTheme, Theme},
   v_flex,
 };
 
-use crate::ui::components::spinning_loader;
+use crate::ui::components::{ErrorDetails, render_docker_disconnected, render_k8s_unavailable, spinning_loader};
 
 use crate::keybindings::{
   DeleteSelected, FocusSearch, GoToActivityMonitor, GoToCompose, GoToContainers, GoToDeployments, GoToImages,
   GoToMachines, GoToNetworks, GoToPods, GoToServices, GoToSettings, GoToVolumes, InspectSelected, NewResource,
   OpenCommandPalette, OpenTerminal, Refresh, RestartSelected, ShowKeyboardShortcuts, StartSelected, StopSelected,
-  ViewLogs,
+  SwitchDockerContext, ToggleSidebar, ViewLogs,
 };
 
 use crate::assets::AppIcon;
 use crate::colima::MachineId;
-use crate::services::{DispatcherEvent, dispatcher, task_manager};
-use crate::state::{CurrentView, DockerState, Selection, StateChanged, docker_state};
+use crate::services::{
+  DispatcherEvent, TaskRetry, cancel_task, dispatcher, explain_error, record_notification, task_manager,
+};
+use crate::state::{CurrentView, DockerState, Selection, SidebarSection, StateChanged, docker_state};
 use crate::ui::activity::ActivityMonitorView;
 use crate::ui::cluster::ClusterView;
 use crate::ui::clusters::ClustersView;
@@ -58,6 +65,20 @@ use crate::ui::storage::StorageView;
 use crate::ui::volumes::VolumesView;
 use crate::ui::workloads::WorkloadsView;
 
+/// One sidebar entry
+struct NavItem {
+  label: &'static str,
+  icon: Icon,
+  view: CurrentView,
+  active: bool,
+}
+
+/// A titled section of sidebar entries
+struct NavGroup {
+  title: SharedString,
+  items: Vec<NavItem>,
+}
+
 /// Main application - only handles layout and view switching
 pub struct DocksideApp {
   docker_state: Entity<DockerState>,
@@ -90,7 +111,7 @@ pub struct DocksideApp {
   storage_view: Entity<StorageView>,
   dashboard_view: Entity<DashboardView>,
   // Centralized notification handling - prevents duplicate notifications on view switch
-  pending_notifications: Vec<(NotificationType, String)>,
+  pending_notifications: Vec<Notification>,
   // Pending setup check result - triggers dialog when set
   pending_setup_check: Option<(bool, bool, bool)>, // (colima_installed, docker_installed, colima_running)
   // Focus handle for keyboard shortcuts
@@ -103,6 +124,39 @@ pub struct DocksideApp {
   global_search: Option<Entity<GlobalSearch>>,
   // Pending action from command palette (processed in render to have access to window)
   pending_palette_action: Option<PaletteAction>,
+  // Window move/resize observer and the debounced save it schedules
+  _bounds_subscription: Subscription,
+  bounds_save_task: Option<Task<()>>,
+}
+
+/// Delay before persisting window geometry, so a drag or resize writes
+/// the settings file once instead of on every frame.
+const WINDOW_BOUNDS_SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Store the window's current geometry in settings. On close the file is
+/// written right away, since the app may exit before a deferred save runs;
+/// otherwise it goes through `save_later`, off the UI thread.
+fn save_window_bounds(window: &Window, on_close: bool, cx: &mut App) {
+  let (bounds, maximized) = match window.window_bounds() {
+    WindowBounds::Windowed(bounds) => (bounds, false),
+    WindowBounds::Maximized(bounds) | WindowBounds::Fullscreen(bounds) => (bounds, true),
+  };
+  let saved = crate::state::SavedBounds {
+    x: f32::from(bounds.origin.x),
+    y: f32::from(bounds.origin.y),
+    width: f32::from(bounds.size.width),
+    height: f32::from(bounds.size.height),
+    maximized,
+  };
+  crate::state::settings_state(cx).update(cx, |state, cx| {
+    let changed = state.settings.window_bounds != Some(saved);
+    state.settings.window_bounds = Some(saved);
+    if on_close {
+      let _ = state.settings.save();
+    } else if changed {
+      state.save_later(cx);
+    }
+  });
 }
 
 impl Focusable for DocksideApp {
@@ -120,7 +174,15 @@ impl DocksideApp {
 
     // Subscribe to state changes for re-rendering on view changes
     cx.subscribe(&docker_state, |_this, _state, event: &StateChanged, cx| {
-      if matches!(event, StateChanged::ViewChanged | StateChanged::Loading) {
+      if matches!(
+        event,
+        StateChanged::ViewChanged
+          | StateChanged::Loading
+          | StateChanged::DockerConnectionChanged
+          | StateChanged::PodsUpdated
+          | StateChanged::NamespacesUpdated
+          | StateChanged::KubeContextSwitched
+      ) {
         cx.notify();
       }
     })
@@ -132,19 +194,35 @@ impl DocksideApp {
     })
     .detach();
 
+    // With the theme on "Auto", swap themes when the system goes light/dark
+    cx.observe_window_appearance(window, |_this, window, cx| {
+      let settings = crate::state::settings_state(cx).read(cx).settings.clone();
+      if settings.theme_follow_system {
+        crate::state::apply_active_theme(&settings, window.appearance(), cx);
+        window.refresh();
+      }
+    })
+    .detach();
+
     // Subscribe to dispatcher events for centralized notification handling
     let disp = dispatcher(cx);
     cx.subscribe(&disp, |this, _disp, event: &DispatcherEvent, cx| {
       match event {
         DispatcherEvent::TaskCompleted { message, .. } => {
+          record_notification(cx, false, message.clone());
+          this.pending_notifications.push(Notification::success(message.clone()));
+        }
+        DispatcherEvent::TaskFailed { error, .. } => {
+          record_notification(cx, true, error.clone());
           this
             .pending_notifications
-            .push((NotificationType::Success, message.clone()));
+            .push(Self::failure_notification(error.clone()));
         }
-        DispatcherEvent::TaskFailed { error, .. } => {
+        DispatcherEvent::RetryableTaskFailed { error, retry } => {
+          record_notification(cx, true, error.clone());
           this
             .pending_notifications
-            .push((NotificationType::Error, error.clone()));
+            .push(Self::retry_notification(error.clone(), retry.clone()));
         }
       }
       cx.notify();
@@ -179,7 +257,7 @@ impl DocksideApp {
       let daemonsets = daemonsets_view.clone();
       let jobs = jobs_view.clone();
       let cronjobs = cronjobs_view.clone();
-      move |_| WorkloadsView::new(pods, deployments, statefulsets, daemonsets, jobs, cronjobs)
+      move |cx| WorkloadsView::new(pods, deployments, statefulsets, daemonsets, jobs, cronjobs, cx)
     });
     let config_resources_view = cx.new({
       let cm = configmaps_view.clone();
@@ -191,7 +269,7 @@ impl DocksideApp {
     let networking_view = cx.new({
       let svcs = services_view.clone();
       let ing = ingresses_view.clone();
-      move |_| NetworkingView::new(svcs, ing)
+      move |cx| NetworkingView::new(svcs, ing, cx)
     });
     let cluster_view = cx.new(|cx| ClusterView::new(window, cx));
     let clusters_view = cx.new(|cx| ClustersView::new(window, cx));
@@ -243,6 +321,19 @@ impl DocksideApp {
     })
     .detach();
 
+    // Remember window geometry: debounced while moving/resizing, and
+    // once more on close in case the last change is still pending.
+    let bounds_subscription = cx.observe_window_bounds(window, |this, window, cx| {
+      this.bounds_save_task = Some(cx.spawn_in(window, async move |_this, cx| {
+        Timer::after(WINDOW_BOUNDS_SAVE_DEBOUNCE).await;
+        let _ = cx.update(|window, cx| save_window_bounds(window, false, cx));
+      }));
+    });
+    window.on_window_should_close(cx, |window, cx| {
+      save_window_bounds(window, true, cx);
+      true
+    });
+
     // Focus the app immediately so keyboard shortcuts work
     focus_handle.focus(window);
 
@@ -283,6 +374,8 @@ impl DocksideApp {
       command_palette: None,
       global_search: None,
       pending_palette_action: None,
+      _bounds_subscription: bounds_subscription,
+      bounds_save_task: None,
     }
   }
 
@@ -343,6 +436,17 @@ impl DocksideApp {
           CommandPaletteEvent::Action(action) => {
             this.pending_palette_action = Some(*action);
           }
+          CommandPaletteEvent::Resource(res) => {
+            crate::services::set_view(res.view, cx);
+            this.docker_state.update(cx, |state, cx| {
+              state.set_selection(res.selection.clone());
+              cx.emit(StateChanged::SelectionChanged);
+            });
+            // Runs through the app-level "selected resource" handlers
+            if let Some(action) = res.verb.action() {
+              window.dispatch_action(action, cx);
+            }
+          }
         }
         cx.notify();
       },
@@ -358,6 +462,17 @@ impl DocksideApp {
     cx.notify();
   }
 
+  /// Switch the sidebar between full width and the icon rail, and remember
+  /// the choice.
+  fn toggle_sidebar(cx: &mut Context<'_, Self>) {
+    crate::state::settings_state(cx).update(cx, |state, cx| {
+      state.settings.sidebar_collapsed = !state.settings.sidebar_collapsed;
+      let _ = state.settings.save();
+      cx.emit(crate::state::SettingsChanged::SettingsUpdated);
+    });
+    cx.notify();
+  }
+
   fn render_shortcuts_overlay(cx: &mut Context<'_, Self>) -> impl IntoElement + use<> {
     let shortcuts = crate::keybindings::get_all_shortcuts();
     let colors = cx.theme().colors;
@@ -431,7 +546,8 @@ impl DocksideApp {
     // Get categories
     let navigation = categories.get("Navigation").cloned().unwrap_or_default();
     let general = categories.get("General").cloned().unwrap_or_default();
-    let containers = categories.get("Containers").cloned().unwrap_or_default();
+    let actions = categories.get("Actions").cloned().unwrap_or_default();
+    let lists = categories.get("Lists").cloned().unwrap_or_default();
 
     div()
       .id("shortcuts-overlay")
@@ -523,20 +639,21 @@ impl DocksideApp {
                   .overflow_y_scrollbar()
                   .p(px(24.))
                   .gap(px(32.))
-                  // Left column - Navigation
+                  // Left column - Navigation & Lists
                   .child(
                     v_flex()
                       .flex_1()
                       .gap(px(20.))
                       .child(render_category("Navigation", &navigation, fg, muted, bg, border))
+                      .child(render_category("Lists", &lists, fg, muted, bg, border))
                   )
-                  // Right column - General & Containers
+                  // Right column - General & Actions
                   .child(
                     v_flex()
                       .flex_1()
                       .gap(px(20.))
                       .child(render_category("General", &general, fg, muted, bg, border))
-                      .child(render_category("Containers", &containers, fg, muted, bg, border))
+                      .child(render_category("Actions", &actions, fg, muted, bg, border))
                   ),
               ),
           ),
@@ -583,7 +700,9 @@ impl DocksideApp {
     });
   }
 
-  fn render_sidebar(&self, cx: &mut Context<'_, Self>) -> impl IntoElement + use<> {
+  /// Sidebar entries grouped by section, shared by the full sidebar and the
+  /// collapsed icon rail.
+  fn sidebar_groups(&self, cx: &App) -> Vec<NavGroup> {
     let state = self.docker_state.read(cx);
     let current_view = state.current_view;
 
@@ -597,210 +716,196 @@ impl DocksideApp {
     // Optional features driven by settings + platform.
     // Colima is required on macOS (where it's the container runtime); on Linux/
     // Windows it's an opt-in feature controlled by Settings.
-    let settings = crate::state::settings_state(cx).read(cx).settings.clone();
-    let show_kubernetes = settings.kubernetes_enabled;
+    let settings = &crate::state::settings_state(cx).read(cx).settings;
+    let item = |label, icon: Icon, view| NavItem {
+      label,
+      icon,
+      view,
+      active: current_view == view,
+    };
+
+    let mut groups = vec![NavGroup {
+      title: "Overview".into(),
+      items: vec![item(
+        "Dashboard",
+        Icon::new(IconName::LayoutDashboard),
+        CurrentView::Dashboard,
+      )],
+    }];
+
+    if settings.section_visible(SidebarSection::Docker) {
+      groups.push(NavGroup {
+        title: docker_header.into(),
+        items: vec![
+          item("Containers", Icon::new(AppIcon::Container), CurrentView::Containers),
+          item("Compose", Icon::new(AppIcon::Compose), CurrentView::Compose),
+          item("Volumes", Icon::new(AppIcon::Volume), CurrentView::Volumes),
+          item("Images", Icon::new(AppIcon::Image), CurrentView::Images),
+          item("Networks", Icon::new(AppIcon::Network), CurrentView::Networks),
+        ],
+      });
+    }
+
+    if settings.section_visible(SidebarSection::Kubernetes) {
+      // Workload + Config + Service tabs collapse the per-resource
+      // sidebar entries (was 9, now 3) — the deep-link CurrentView
+      // variants for individual resources still resolve via command
+      // palette / global search.
+      let workloads_active = matches!(
+        current_view,
+        CurrentView::Workloads
+          | CurrentView::Pods
+          | CurrentView::Deployments
+          | CurrentView::StatefulSets
+          | CurrentView::DaemonSets
+          | CurrentView::Jobs
+          | CurrentView::CronJobs
+      );
+      let networking_active = matches!(
+        current_view,
+        CurrentView::Networking | CurrentView::Services | CurrentView::Ingresses
+      );
+      let config_active = matches!(
+        current_view,
+        CurrentView::Config | CurrentView::ConfigMaps | CurrentView::Secrets
+      );
+      let storage_active = matches!(current_view, CurrentView::Storage | CurrentView::Pvcs);
+      groups.push(NavGroup {
+        title: "Kubernetes".into(),
+        items: vec![
+          item("Clusters", Icon::new(IconName::Globe), CurrentView::Clusters),
+          item("Overview", Icon::new(IconName::LayoutDashboard), CurrentView::Cluster),
+          NavItem {
+            active: workloads_active,
+            ..item("Workloads", Icon::new(AppIcon::Pod), CurrentView::Workloads)
+          },
+          NavItem {
+            active: networking_active,
+            ..item("Networking", Icon::new(AppIcon::Service), CurrentView::Networking)
+          },
+          NavItem {
+            active: config_active,
+            ..item("Config", Icon::new(IconName::Settings), CurrentView::Config)
+          },
+          NavItem {
+            active: storage_active,
+            ..item("Storage", Icon::new(IconName::Folder), CurrentView::Storage)
+          },
+        ],
+      });
+    }
+
     // The Machines view always lists at least the Host runtime; Colima
     // VMs show up as additional rows when Colima is enabled. Don't gate
-    // the menu item behind `colima_enabled`.
-    let _ = settings.colima_enabled;
+    // the menu item behind `colima_enabled`, only the section toggle.
+    if settings.section_visible(SidebarSection::Runtimes) {
+      let items = vec![item("Machines", Icon::new(AppIcon::Machine), CurrentView::Machines)];
+      // AI Models view: krunkit + colima model. macOS Apple Silicon only.
+      #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+      let items = {
+        let mut items = items;
+        items.push(item("Models", Icon::new(IconName::Bot), CurrentView::Models));
+        items
+      };
+      groups.push(NavGroup {
+        title: "Runtimes".into(),
+        items,
+      });
+    }
 
-    Sidebar::left()
-            .collapsible(false)
-            .pt(px(52.)) // Space for traffic lights
-            .child(
-                SidebarGroup::new("Overview").child(
-                    SidebarMenu::new().child(
-                        SidebarMenuItem::new("Dashboard")
-                            .icon(IconName::LayoutDashboard)
-                            .active(current_view == CurrentView::Dashboard)
-                            .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                crate::services::set_view(CurrentView::Dashboard, cx);
-                            })),
-                    ),
-                ),
-            )
-            .child(
-                SidebarGroup::new(docker_header).child(
-                    SidebarMenu::new()
-                        .child(
-                            SidebarMenuItem::new("Containers")
-                                .icon(AppIcon::Container)
-                                .active(current_view == CurrentView::Containers)
-                                .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                    crate::services::set_view(CurrentView::Containers, cx);
-                                })),
-                        )
-                        .child(
-                            SidebarMenuItem::new("Compose")
-                                .icon(AppIcon::Compose)
-                                .active(current_view == CurrentView::Compose)
-                                .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                    crate::services::set_view(CurrentView::Compose, cx);
-                                })),
-                        )
-                        .child(
-                            SidebarMenuItem::new("Volumes")
-                                .icon(AppIcon::Volume)
-                                .active(current_view == CurrentView::Volumes)
-                                .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                    crate::services::set_view(CurrentView::Volumes, cx);
-                                })),
-                        )
-                        .child(
-                            SidebarMenuItem::new("Images")
-                                .icon(AppIcon::Image)
-                                .active(current_view == CurrentView::Images)
-                                .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                    crate::services::set_view(CurrentView::Images, cx);
-                                })),
-                        )
-                        .child(
-                            SidebarMenuItem::new("Networks")
-                                .icon(AppIcon::Network)
-                                .active(current_view == CurrentView::Networks)
-                                .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                    crate::services::set_view(CurrentView::Networks, cx);
-                                })),
-                        ),
-                ),
-            )
-            .when(show_kubernetes, |sidebar| {
-                // Workload + Config + Service tabs collapse the per-resource
-                // sidebar entries (was 9, now 3) — the deep-link CurrentView
-                // variants for individual resources still resolve via command
-                // palette / global search.
-                let workloads_active = matches!(
-                    current_view,
-                    CurrentView::Workloads
-                        | CurrentView::Pods
-                        | CurrentView::Deployments
-                        | CurrentView::StatefulSets
-                        | CurrentView::DaemonSets
-                        | CurrentView::Jobs
-                        | CurrentView::CronJobs
-                );
-                let networking_active = matches!(
-                    current_view,
-                    CurrentView::Networking | CurrentView::Services | CurrentView::Ingresses
-                );
-                let config_active = matches!(
-                    current_view,
-                    CurrentView::Config | CurrentView::ConfigMaps | CurrentView::Secrets
-                );
-                sidebar.child(
-                    SidebarGroup::new("Kubernetes").child(
-                        SidebarMenu::new()
-                            .child(
-                                SidebarMenuItem::new("Clusters")
-                                    .icon(IconName::Globe)
-                                    .active(current_view == CurrentView::Clusters)
-                                    .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                        crate::services::set_view(CurrentView::Clusters, cx);
-                                    })),
-                            )
-                            .child(
-                                SidebarMenuItem::new("Overview")
-                                    .icon(IconName::LayoutDashboard)
-                                    .active(current_view == CurrentView::Cluster)
-                                    .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                        crate::services::set_view(CurrentView::Cluster, cx);
-                                    })),
-                            )
-                            .child(
-                                SidebarMenuItem::new("Workloads")
-                                    .icon(AppIcon::Pod)
-                                    .active(workloads_active)
-                                    .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                        crate::services::set_view(CurrentView::Workloads, cx);
-                                    })),
-                            )
-                            .child(
-                                SidebarMenuItem::new("Networking")
-                                    .icon(AppIcon::Service)
-                                    .active(networking_active)
-                                    .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                        crate::services::set_view(CurrentView::Networking, cx);
-                                    })),
-                            )
-                            .child(
-                                SidebarMenuItem::new("Config")
-                                    .icon(IconName::Settings)
-                                    .active(config_active)
-                                    .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                        crate::services::set_view(CurrentView::Config, cx);
-                                    })),
-                            )
-                            .child(
-                                SidebarMenuItem::new("Storage")
-                                    .icon(IconName::Folder)
-                                    .active(matches!(
-                                        current_view,
-                                        CurrentView::Storage | CurrentView::Pvcs
-                                    ))
-                                    .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                        crate::services::set_view(CurrentView::Storage, cx);
-                                    })),
-                            ),
-                    ),
-                )
-            })
-            .child(
-                SidebarGroup::new("Runtimes").child({
-                    let menu = SidebarMenu::new().child(
-                        SidebarMenuItem::new("Machines")
-                            .icon(AppIcon::Machine)
-                            .active(current_view == CurrentView::Machines)
-                            .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                crate::services::set_view(CurrentView::Machines, cx);
-                            })),
-                    );
-                    // AI Models view: krunkit + colima model. macOS Apple Silicon only.
-                    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-                    let menu = menu.child(
-                        SidebarMenuItem::new("Models")
-                            .icon(IconName::Bot)
-                            .active(current_view == CurrentView::Models)
-                            .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                crate::services::set_view(CurrentView::Models, cx);
-                            })),
-                    );
-                    menu
-                }),
-            )
-            .child(
-                SidebarGroup::new("General").child(
-                    SidebarMenu::new()
-                        .child(
-                            SidebarMenuItem::new("Activity Monitor")
-                                .icon(AppIcon::Activity)
-                                .active(current_view == CurrentView::ActivityMonitor)
-                                .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                    crate::services::set_view(CurrentView::ActivityMonitor, cx);
-                                })),
-                        )
-                        .child(
-                            SidebarMenuItem::new("Prune")
-                                .icon(AppIcon::Trash)
-                                .active(current_view == CurrentView::Prune)
-                                .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                    crate::services::set_view(CurrentView::Prune, cx);
-                                })),
-                        )
-                        .child(
-                            SidebarMenuItem::new("Settings")
-                                .icon(AppIcon::Settings)
-                                .active(current_view == CurrentView::Settings)
-                                .on_click(cx.listener(|_this, _ev, _window, cx| {
-                                    crate::services::set_view(CurrentView::Settings, cx);
-                                })),
-                        ),
-                ),
+    groups.push(NavGroup {
+      title: "General".into(),
+      items: vec![
+        item(
+          "Activity Monitor",
+          Icon::new(AppIcon::Activity),
+          CurrentView::ActivityMonitor,
+        ),
+        item("Prune", Icon::new(AppIcon::Trash), CurrentView::Prune),
+        item("Settings", Icon::new(AppIcon::Settings), CurrentView::Settings),
+      ],
+    });
+
+    groups
+  }
+
+  fn render_sidebar(&self, collapsed: bool, cx: &mut Context<'_, Self>) -> gpui::AnyElement {
+    let groups = self.sidebar_groups(cx);
+    if collapsed {
+      return Self::render_sidebar_rail(groups, cx).into_any_element();
+    }
+
+    groups
+      .into_iter()
+      .fold(
+        Sidebar::left().collapsible(false).pt(px(52.)), // Space for traffic lights
+        |sidebar, group| {
+          let menu = group.items.into_iter().fold(SidebarMenu::new(), |menu, item| {
+            let view = item.view;
+            menu.child(
+              SidebarMenuItem::new(item.label)
+                .icon(item.icon)
+                .active(item.active)
+                .on_click(cx.listener(move |_this, _ev, _window, cx| {
+                  crate::services::set_view(view, cx);
+                })),
             )
+          });
+          sidebar.child(SidebarGroup::new(group.title).child(menu))
+        },
+      )
+      .into_any_element()
+  }
+
+  /// Icon-only sidebar. Group titles become dividers and each label moves
+  /// into the icon's tooltip.
+  fn render_sidebar_rail(groups: Vec<NavGroup>, cx: &mut Context<'_, Self>) -> impl IntoElement + use<> {
+    let border = cx.theme().colors.border;
+    let last = groups.len().saturating_sub(1);
+
+    v_flex()
+      .size_full()
+      .pt(px(52.)) // Space for traffic lights
+      .pb(px(8.))
+      .items_center()
+      .gap(px(4.))
+      .overflow_y_scrollbar()
+      .children(groups.into_iter().enumerate().map(|(i, group)| {
+        v_flex()
+          .items_center()
+          .gap(px(4.))
+          .children(group.items.into_iter().map(|item| {
+            let view = item.view;
+            Button::new(item.label)
+              .icon(item.icon)
+              .ghost()
+              .selected(item.active)
+              .tooltip(item.label)
+              .on_click(cx.listener(move |_this, _ev, _window, cx| {
+                crate::services::set_view(view, cx);
+              }))
+          }))
+          .when(i < last, |el| el.child(div().w(px(24.)).h(px(1.)).my(px(4.)).bg(border)))
+      }))
   }
 
   fn render_content(&self, cx: &mut Context<'_, Self>) -> impl IntoElement + use<> {
     let state = self.docker_state.read(cx);
 
+    // Unreachable backends get a dedicated state instead of empty lists.
+    // The cluster manager stays usable so another context can be picked.
+    match state.current_view.section() {
+      Some(SidebarSection::Docker) if state.docker_connected == Some(false) => {
+        return div().size_full().child(render_docker_disconnected(cx));
+      }
+      Some(SidebarSection::Kubernetes) if state.current_view != CurrentView::Clusters && !state.k8s_available => {
+        if let Some(error) = state.k8s_error.clone() {
+          return div().size_full().child(render_k8s_unavailable(&error, cx));
+        }
+      }
+      _ => {}
+    }
+
     match state.current_view {
       CurrentView::Machines => div().size_full().child(self.machines_view.clone()),
       CurrentView::Prune => div().size_full().child(self.prune_view.clone()),
@@ -833,6 +938,146 @@ impl DocksideApp {
     }
   }
 
+  /// Banner across the content area while the Docker daemon is
+  /// unreachable. The connection monitor keeps retrying in the background.
+  fn render_docker_banner(&self, cx: &App) -> Option<impl IntoElement + use<>> {
+    let settings = crate::state::settings_state(cx).read(cx).settings.clone();
+    let state = self.docker_state.read(cx);
+    // Docker views show the full disconnected state instead
+    if state.docker_connected != Some(false)
+      || !settings.section_visible(SidebarSection::Docker)
+      || state.current_view.section() == Some(SidebarSection::Docker)
+    {
+      return None;
+    }
+    let colors = &cx.theme().colors;
+    Some(
+      h_flex()
+        .w_full()
+        .px(px(16.))
+        .py(px(8.))
+        .gap(px(10.))
+        .items_center()
+        .flex_shrink_0()
+        .bg(colors.warning.opacity(0.12))
+        .border_b_1()
+        .border_color(colors.warning.opacity(0.5))
+        .child(spinning_loader(px(14.), colors.warning))
+        .child(
+          div()
+            .flex_1()
+            .text_sm()
+            .text_color(colors.foreground)
+            .child("Docker disconnected — retrying"),
+        )
+        .child(
+          Button::new("docker-retry-now")
+            .label("Retry now")
+            .ghost()
+            .xsmall()
+            .on_click(|_, _, cx| crate::services::load_initial_data(cx)),
+        ),
+    )
+  }
+
+  /// Sidebar footer with Docker / Kubernetes reachability dots. Docker
+  /// opens the context switcher; Kubernetes opens the setup diagnostics.
+  /// The collapsed sidebar stacks the dots without labels.
+  fn render_connection_status(&self, collapsed: bool, cx: &mut Context<'_, Self>) -> impl IntoElement + use<> {
+    let state = self.docker_state.read(cx);
+    let settings = crate::state::settings_state(cx).read(cx).settings.clone();
+    let colors = cx.theme().colors;
+
+    let (docker_color, docker_tip) = match state.docker_connected {
+      Some(true) => (colors.success, "Docker connected"),
+      Some(false) => (colors.danger, "Docker disconnected"),
+      None => (colors.warning, "Connecting to Docker..."),
+    };
+    let (k8s_color, k8s_tip) = if state.k8s_error.is_some() {
+      (colors.danger, "Kubernetes API unreachable")
+    } else if state.k8s_available {
+      (colors.success, "Kubernetes API reachable")
+    } else {
+      (colors.warning, "Kubernetes not connected")
+    };
+
+    let status_item = |id: &'static str, label: &'static str, color: gpui::Hsla, tip: &'static str| {
+      h_flex()
+        .id(id)
+        .gap(px(6.))
+        .px(px(6.))
+        .py(px(2.))
+        .items_center()
+        .rounded(px(4.))
+        .cursor_pointer()
+        .hover(|s| s.bg(colors.list_hover))
+        .tooltip(move |window, cx| gpui_component::tooltip::Tooltip::new(tip).build(window, cx))
+        .child(div().size(px(8.)).rounded_full().bg(color))
+        .when(!collapsed, |el| {
+          el.child(div().text_xs().text_color(colors.muted_foreground).child(label))
+        })
+    };
+
+    h_flex()
+      .w_full()
+      .px(px(10.))
+      .py(px(8.))
+      .gap(px(8.))
+      .when(collapsed, |el| el.flex_col().px(px(4.)).gap(px(4.)))
+      .flex_shrink_0()
+      .border_t_1()
+      .border_color(colors.border)
+      .when(settings.section_visible(SidebarSection::Docker), |el| {
+        el.child(
+          status_item("status-docker", "Docker", docker_color, docker_tip)
+            .on_click(|_, window, cx| dialogs::open_docker_context_dialog(window, cx)),
+        )
+      })
+      .when(settings.section_visible(SidebarSection::Kubernetes), |el| {
+        el.child(
+          status_item("status-k8s", "Kubernetes", k8s_color, k8s_tip).on_click(cx.listener(
+            |_this, _ev, window, cx| {
+              Self::show_setup_dialog_with_title(window, cx, "Kubernetes Diagnostics");
+            },
+          )),
+        )
+      })
+  }
+
+  /// Notification for a failed task. Failures `explain_error` recognizes
+  /// lead with a title and suggested fix, with the raw error behind "Show
+  /// details"; anything else shows the raw error.
+  fn failure_notification(error: String) -> Notification {
+    match explain_error(&error) {
+      Some(hint) => Notification::error(hint.suggestion)
+        .title(hint.title)
+        .autohide(false)
+        .content(move |_this, _window, cx| {
+          ErrorDetails::new(("error-details", cx.entity_id()), error.clone()).into_any_element()
+        }),
+      None => Notification::error(error),
+    }
+  }
+
+  /// Error notification with a Retry button that re-runs the operation.
+  /// Stays up until dismissed so the button doesn't vanish mid-reach.
+  fn retry_notification(error: String, retry: TaskRetry) -> Notification {
+    Self::failure_notification(error)
+      .autohide(false)
+      .action(move |_this, _window, cx| {
+        let retry = retry.clone();
+        let notification = cx.entity();
+        Button::new("retry-task")
+          .label("Retry")
+          .primary()
+          .xsmall()
+          .on_click(move |_ev, window, cx| {
+            retry.run(cx);
+            notification.update(cx, |n, cx| n.dismiss(window, cx));
+          })
+      })
+  }
+
   fn render_task_bar(cx: &App) -> Option<impl IntoElement + use<>> {
     let tasks = task_manager(cx);
     let running_tasks: Vec<_> = tasks.read(cx).running_tasks().into_iter().cloned().collect();
@@ -854,9 +1099,28 @@ impl DocksideApp {
         .gap(px(16.))
         .items_center()
         .children(running_tasks.iter().map(|task| {
-          let progress = task.stage_progress();
+          let progress = task.overall_progress();
           let status_text = task.display_status();
           let has_stages = !task.stages.is_empty();
+          let task_id = task.id;
+          // One mini bar per part (e.g. image layer) under the main bar
+          let parts = task.parts.iter().enumerate().map(|(i, part)| {
+            let tooltip_text = format!("{}: {}", part.label, part.status);
+            div()
+              .id(SharedString::from(format!("task-{task_id}-part-{i}")))
+              .w(px(18.))
+              .h(px(4.))
+              .rounded(px(1.))
+              .bg(colors.border)
+              .tooltip(move |window, cx| gpui_component::tooltip::Tooltip::new(tooltip_text.clone()).build(window, cx))
+              .child(
+                div()
+                  .h_full()
+                  .rounded(px(1.))
+                  .bg(if part.done { colors.success } else { colors.primary })
+                  .w(gpui::relative(part.progress.clamp(0.0, 1.0))),
+              )
+          });
 
           h_flex()
             .flex_1()
@@ -882,21 +1146,24 @@ impl DocksideApp {
                     .child(status_text),
                 ),
             )
-            // Progress bar (if staged)
+            // Progress bar (if staged), with per-part bars below it
             .when(has_stages, |el| {
               el.child(
-                div()
+                v_flex()
                   .flex_1()
-                  .h(px(4.))
-                  .rounded(px(2.))
-                  .bg(colors.border)
+                  .gap(px(4.))
                   .child(
-                    div()
-                      .h_full()
-                      .rounded(px(2.))
-                      .bg(colors.primary)
-                      .w(gpui::relative(progress)),
-                  ),
+                    div().w_full().h(px(4.)).rounded(px(2.)).bg(colors.border).child(
+                      div()
+                        .h_full()
+                        .rounded(px(2.))
+                        .bg(colors.primary)
+                        .w(gpui::relative(progress)),
+                    ),
+                  )
+                  .when(!task.parts.is_empty(), |el| {
+                    el.child(h_flex().flex_wrap().gap(px(2.)).children(parts))
+                  }),
               )
             })
             // Stage counter (if staged)
@@ -912,6 +1179,17 @@ impl DocksideApp {
                   )),
               )
             })
+            // Cancel button (if the task supports it)
+            .when(task.cancellable && !task.cancel_requested, |el| {
+              el.child(
+                Button::new(SharedString::from(format!("cancel-task-{task_id}")))
+                  .icon(IconName::Close)
+                  .ghost()
+                  .xsmall()
+                  .tooltip("Cancel")
+                  .on_click(move |_, _, cx| cancel_task(cx, task_id)),
+              )
+            })
         })),
     )
   }
@@ -951,6 +1229,9 @@ impl DocksideApp {
       PaletteAction::RefreshServices => {
         crate::services::refresh_services(cx);
       }
+      PaletteAction::ToggleAutoRefresh => {
+        crate::services::toggle_auto_refresh(cx);
+      }
 
       // Dialog actions - use centralized dialog helpers
       PaletteAction::ShowPullImageDialog => {
@@ -971,6 +1252,12 @@ impl DocksideApp {
       PaletteAction::ShowCreateServiceDialog => {
         dialogs::open_create_service_dialog(window, cx);
       }
+      PaletteAction::ShowDockerContextSwitcher => {
+        dialogs::open_docker_context_dialog(window, cx);
+      }
+      PaletteAction::ShowApplyYamlDialog => {
+        dialogs::open_apply_yaml_dialog(window, cx);
+      }
 
       // Machine actions (default profile)
       PaletteAction::StartDefaultMachine => {
@@ -1001,9 +1288,24 @@ impl DocksideApp {
       }
 
       // UI actions
+      PaletteAction::ToggleSidebar => {
+        Self::toggle_sidebar(cx);
+      }
+      PaletteAction::ReloadThemes => {
+        crate::services::reload_themes(cx);
+      }
       PaletteAction::ShowShortcuts => {
         self.show_shortcuts_overlay = true;
       }
+      PaletteAction::ShowCommandLog => {
+        dialogs::open_command_log_dialog(window, cx);
+      }
+      PaletteAction::GenerateDiagnosticReport => {
+        dialogs::prompt_save_diagnostic_report(window, cx);
+      }
+      PaletteAction::ExportDiagnostics => {
+        dialogs::prompt_export_diagnostics(window, cx);
+      }
     }
     cx.notify();
   }
@@ -1028,8 +1330,8 @@ impl Render for DocksideApp {
     }
 
     // Push any pending notifications (centralized handling)
-    for (notification_type, message) in self.pending_notifications.drain(..) {
-      window.push_notification((notification_type, SharedString::from(message)), cx);
+    for notification in self.pending_notifications.drain(..) {
+      window.push_notification(notification, cx);
     }
 
     // Handle pending palette action (needs window access for dialogs)
@@ -1041,9 +1343,12 @@ impl Render for DocksideApp {
     let notification_layer = Root::render_notification_layer(window, cx);
     let dialog_layer = Root::render_dialog_layer(window, cx);
 
-    let sidebar = self.render_sidebar(cx);
+    let sidebar_collapsed = crate::state::settings_state(cx).read(cx).settings.sidebar_collapsed;
+    let sidebar = self.render_sidebar(sidebar_collapsed, cx);
+    let connection_status = self.render_connection_status(sidebar_collapsed, cx);
     let content = self.render_content(cx);
     let task_bar = Self::render_task_bar(cx);
+    let docker_banner = self.render_docker_banner(cx);
     let show_shortcuts = self.show_shortcuts_overlay;
     let shortcuts_overlay = if show_shortcuts {
       Some(Self::render_shortcuts_overlay(cx))
@@ -1121,10 +1426,16 @@ impl Render for DocksideApp {
       .on_action(cx.listener(|this, _: &ShowKeyboardShortcuts, _window, cx| {
         this.toggle_shortcuts_overlay(cx);
       }))
+      .on_action(cx.listener(|_this, _: &ToggleSidebar, _window, cx| {
+        Self::toggle_sidebar(cx);
+      }))
       // Command palette
       .on_action(cx.listener(|this, _: &OpenCommandPalette, window, cx| {
         this.open_command_palette(window, cx);
       }))
+      .on_action(cx.listener(|_this, _: &SwitchDockerContext, window, cx| {
+        dialogs::open_docker_context_dialog(window, cx);
+      }))
       .on_action(cx.listener(|this, _: &NewResource, window, cx| {
         let current_view = this.docker_state.read(cx).current_view;
         match current_view {
@@ -1194,7 +1505,7 @@ impl Render for DocksideApp {
         let selection = this.docker_state.read(cx).selection.clone();
         match selection {
           Selection::Container(container) => {
-            crate::services::stop_container(container.id, cx);
+            crate::services::stop_container(container.id, None, None, cx);
           }
           Selection::Machine(MachineId::Colima(name)) => {
             crate::services::stop_machine(name, cx);
@@ -1261,53 +1572,130 @@ impl Render for DocksideApp {
         }
       }))
       .on_action(cx.listener(|this, _: &DeleteSelected, window, cx| {
+        type DeleteFn = Box<dyn Fn(&mut App)>;
         let selection = this.docker_state.read(cx).selection.clone();
-        match selection {
+        let (kind, label, warning, delete): (&str, String, Option<SharedString>, DeleteFn) = match selection {
           Selection::Container(container) => {
-            crate::services::delete_container(container.id, cx);
+            let warning = container
+              .state
+              .is_running()
+              .then(|| SharedString::from("This container is running and will be stopped before it is removed."));
+            (
+              "container",
+              container.name.clone(),
+              warning,
+              Box::new(move |cx: &mut App| crate::services::delete_container(container.id.clone(), cx)),
+            )
           }
           Selection::Image(image) => {
-            crate::services::delete_image(image.id, cx);
+            let users = crate::ui::images::image_users(&image.id, &this.docker_state.read(cx).containers);
+            let warning = (!users.is_empty()).then(|| {
+              SharedString::from(format!(
+                "This image is used by: {}. Those containers will break.",
+                users.join(", ")
+              ))
+            });
+            // Confirming past the warning is the go-ahead to force it out
+            let force = !users.is_empty();
+            (
+              "image",
+              image.display_name(),
+              warning,
+              Box::new(move |cx: &mut App| crate::services::delete_image(image.id.clone(), force, cx)),
+            )
           }
           Selection::Volume(name) => {
-            crate::services::delete_volume(name, cx);
+            let users = crate::ui::volumes::volume_users(&name, &this.docker_state.read(cx).containers);
+            let warning = (!users.is_empty())
+              .then(|| SharedString::from(format!("This volume is used by: {}. Its data will be lost.", users.join(", "))));
+            (
+              "volume",
+              name.clone(),
+              warning,
+              Box::new(move |cx: &mut App| crate::services::delete_volume(name.clone(), cx)),
+            )
           }
           Selection::Network(id) => {
-            crate::services::delete_network(id, cx);
-          }
-          Selection::Pod { name, namespace } => {
-            crate::services::delete_pod(name, namespace, cx);
-          }
-          Selection::Deployment { name, namespace } => {
-            crate::services::delete_deployment(name, namespace, cx);
-          }
-          Selection::Service { name, namespace } => {
-            crate::services::delete_service(name, namespace, cx);
-          }
-          Selection::StatefulSet { name, namespace } => {
-            crate::services::delete_statefulset(name, namespace, cx);
-          }
-          Selection::DaemonSet { name, namespace } => {
-            crate::services::delete_daemonset(name, namespace, cx);
-          }
-          Selection::Job { name, namespace } => {
-            crate::services::delete_job(name, namespace, cx);
-          }
-          Selection::CronJob { name, namespace } => {
-            crate::services::delete_cronjob(name, namespace, cx);
-          }
-          Selection::Ingress { name, namespace } => {
-            crate::services::delete_ingress(name, namespace, cx);
-          }
-          Selection::Pvc { name, namespace } => {
-            crate::services::delete_pvc(name, namespace, cx);
-          }
-          Selection::Secret { name, namespace } => {
-            crate::services::delete_secret(name, namespace, cx);
-          }
-          Selection::ConfigMap { name, namespace } => {
-            crate::services::delete_configmap(name, namespace, cx);
+            let label = this
+              .docker_state
+              .read(cx)
+              .networks
+              .iter()
+              .find(|n| n.id == id)
+              .map_or_else(|| id.clone(), |n| n.name.clone());
+            (
+              "network",
+              label,
+              None,
+              Box::new(move |cx: &mut App| crate::services::delete_network(id.clone(), cx)),
+            )
           }
+          Selection::Pod { name, namespace } => (
+            "pod",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_pod(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::Deployment { name, namespace } => (
+            "deployment",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_deployment(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::Service { name, namespace } => (
+            "service",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_service(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::StatefulSet { name, namespace } => (
+            "stateful set",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_statefulset(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::DaemonSet { name, namespace } => (
+            "daemon set",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_daemonset(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::Job { name, namespace } => (
+            "job",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_job(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::CronJob { name, namespace } => (
+            "cron job",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_cronjob(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::Ingress { name, namespace } => (
+            "ingress",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_ingress(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::Pvc { name, namespace } => (
+            "persistent volume claim",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_pvc(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::Secret { name, namespace } => (
+            "secret",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_secret(name.clone(), namespace.clone(), cx)),
+          ),
+          Selection::ConfigMap { name, namespace } => (
+            "config map",
+            format!("{namespace}/{name}"),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_configmap(name.clone(), namespace.clone(), cx)),
+          ),
           Selection::Node(_) => {
             window.push_notification(
               (
@@ -1316,22 +1704,42 @@ impl Render for DocksideApp {
               ),
               cx,
             );
+            return;
           }
-          Selection::Machine(MachineId::Colima(name)) => {
-            crate::services::delete_machine(name, cx);
-          }
+          Selection::Machine(MachineId::Colima(name)) => (
+            "machine",
+            name.clone(),
+            None,
+            Box::new(move |cx: &mut App| crate::services::delete_machine(name.clone(), cx)),
+          ),
           Selection::Machine(MachineId::Host) => {
             window.push_notification(
               (NotificationType::Info, SharedString::from("Cannot delete Host machine.")),
               cx,
             );
+            return;
           }
           Selection::None => {
             window.push_notification(
               (NotificationType::Info, SharedString::from("Select a resource first.")),
               cx,
             );
+            return;
           }
+        };
+
+        if crate::state::settings_state(cx).read(cx).settings.confirm_destructive {
+          dialogs::open_confirm_dialog(
+            format!("Delete {kind}?"),
+            format!("'{label}' will be permanently deleted."),
+            warning,
+            "Delete",
+            move |_window, cx| delete(cx),
+            window,
+            cx,
+          );
+        } else {
+          delete(cx);
         }
       }))
       .on_action(cx.listener(|this, _: &ViewLogs, window, cx| {
@@ -1391,14 +1799,15 @@ impl Render for DocksideApp {
         }
       }))
       .child(
-        div()
-          .w(px(220.))
+        v_flex()
+          .w(px(if sidebar_collapsed { 52. } else { 220. }))
           .h_full()
           .flex_shrink_0()
           .overflow_hidden()
           .border_r_1()
           .border_color(border)
-          .child(sidebar),
+          .child(div().flex_1().min_h_0().overflow_hidden().child(sidebar))
+          .child(connection_status),
       )
       .child(
         div()
@@ -1407,6 +1816,7 @@ impl Render for DocksideApp {
           .overflow_hidden()
           .flex()
           .flex_col()
+          .children(docker_banner)
           .child(div().flex_1().overflow_hidden().child(content))
           .children(task_bar),
       )
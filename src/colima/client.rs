@@ -1,5 +1,7 @@
 use anyhow::{Result, anyhow};
-use std::process::Stdio;
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
 
 use super::{
   ColimaConfig, ColimaVm, ModelRunner, MountType, VmArch, VmFileEntry, VmOsInfo, VmRuntime, VmStatus, VmType,
@@ -235,6 +237,12 @@ impl ColimaClient {
   /// Start a VM using config file approach
   /// This writes the config to the YAML file then starts colima
   pub fn start_with_config(profile: &str, config: &ColimaConfig) -> Result<()> {
+    Self::start_with_config_streaming(profile, config, |_| {})
+  }
+
+  /// Same as `start_with_config`, handing each line colima logs to
+  /// `on_line` as it's printed so long VM boots can report progress.
+  pub fn start_with_config_streaming(profile: &str, config: &ColimaConfig, on_line: impl FnMut(&str)) -> Result<()> {
     // Write config to the profile's config file
     let profile_opt = if profile == "default" { None } else { Some(profile) };
     Self::write_config(profile_opt, config)?;
@@ -247,18 +255,12 @@ impl ColimaClient {
       cmd.arg("--profile").arg(profile);
     }
 
-    let output = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).output()?;
-
-    if !output.status.success() {
-      let stderr = String::from_utf8_lossy(&output.stderr);
-      return Err(anyhow!("colima start failed: {stderr}"));
-    }
-
-    Ok(())
+    Self::run_start(cmd, on_line)
   }
 
-  /// Start an existing VM (uses its existing config file)
-  pub fn start_existing(name: Option<&str>) -> Result<()> {
+  /// Start an existing VM (uses its existing config file), streaming
+  /// colima's log lines to `on_line`.
+  pub fn start_existing_streaming(name: Option<&str>, on_line: impl FnMut(&str)) -> Result<()> {
     let mut cmd = colima_cmd();
     cmd.arg("start");
 
@@ -268,11 +270,29 @@ impl ColimaClient {
       cmd.arg("--profile").arg(n);
     }
 
-    let output = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).output()?;
+    Self::run_start(cmd, on_line)
+  }
 
-    if !output.status.success() {
-      let stderr = String::from_utf8_lossy(&output.stderr);
-      return Err(anyhow!("colima start failed: {stderr}"));
+  /// Run `colima start`, reading its log (stderr) line by line. The last
+  /// few lines become the error message on failure.
+  fn run_start(mut cmd: Command, mut on_line: impl FnMut(&str)) -> Result<()> {
+    const ERROR_TAIL: usize = 20;
+
+    let mut child = cmd.stdout(Stdio::null()).stderr(Stdio::piped()).spawn()?;
+    let mut tail: VecDeque<String> = VecDeque::with_capacity(ERROR_TAIL);
+    if let Some(stderr) = child.stderr.take() {
+      for line in BufReader::new(stderr).lines().map_while(std::result::Result::ok) {
+        on_line(&line);
+        if tail.len() == ERROR_TAIL {
+          tail.pop_front();
+        }
+        tail.push_back(line);
+      }
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+      return Err(anyhow!("colima start failed: {}", Vec::from(tail).join("\n")));
     }
 
     Ok(())
@@ -578,12 +598,75 @@ impl ColimaClient {
     Ok(entries)
   }
 
-  /// Read a file from the VM
+  /// Read a file from the VM. Files longer than `max_lines` are cut off
+  /// with a trailing "--- truncated at N lines ---" marker.
   pub fn read_file(name: Option<&str>, path: &str, max_lines: u32) -> Result<String> {
-    Self::run_command(
-      name,
-      &format!("head -n {max_lines} {path} 2>/dev/null || echo 'Unable to read file'"),
-    )
+    let output = Self::run_command(name, &format!("head -n {} {}", max_lines + 1, quote_arg(path)))?;
+    Ok(truncate_lines(output, max_lines))
+  }
+
+  /// Copy a file out of the VM to `dest` on the host, byte for byte.
+  pub fn download_file(name: Option<&str>, path: &str, dest: &std::path::Path) -> Result<()> {
+    let file = std::fs::File::create(dest).map_err(|e| anyhow!("Failed to create {}: {e}", dest.display()))?;
+
+    let mut cmd = colima_cmd();
+    cmd.arg("ssh");
+
+    if let Some(n) = name
+      && n != "default"
+    {
+      cmd.arg("--profile").arg(n);
+    }
+
+    cmd
+      .arg("--")
+      .arg("sh")
+      .arg("-c")
+      .arg(format!("cat {}", quote_arg(path)));
+
+    let output = cmd.stdout(file).stderr(Stdio::piped()).output()?;
+
+    if output.status.success() {
+      Ok(())
+    } else {
+      let stderr = String::from_utf8_lossy(&output.stderr);
+      Err(anyhow!("Failed to read {path}: {}", stderr.trim()))
+    }
+  }
+
+  /// Overwrite a file in the VM with `content`, streamed over `colima ssh`.
+  /// The file is rewritten in place, so its mode and owner stay as they
+  /// were. `as_root` writes through `sudo tee` for root-owned files.
+  pub fn write_file(name: Option<&str>, path: &str, content: &str, as_root: bool) -> Result<()> {
+    use std::io::Write;
+
+    let mut cmd = colima_cmd();
+    cmd.arg("ssh");
+
+    if let Some(n) = name
+      && n != "default"
+    {
+      cmd.arg("--profile").arg(n);
+    }
+
+    cmd.arg("--").arg("sh").arg("-c").arg(write_file_command(path, as_root));
+
+    let mut child = cmd
+      .stdin(Stdio::piped())
+      .stdout(Stdio::null())
+      .stderr(Stdio::piped())
+      .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+      stdin.write_all(content.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+
+    if output.status.success() {
+      Ok(())
+    } else {
+      let stderr = String::from_utf8_lossy(&output.stderr);
+      Err(anyhow!("Failed to write {path}: {}", stderr.trim()))
+    }
   }
 
   /// Resolve a symlink to its target path
@@ -608,6 +691,11 @@ impl ColimaClient {
     Self::run_command(name, "free -h 2>/dev/null || echo 'Unable to get memory info'")
   }
 
+  /// Get the CPU summary lines from `top` in the VM
+  pub fn get_cpu_usage(name: Option<&str>) -> Result<String> {
+    Self::run_command(name, "top -bn1 2>/dev/null | head -5 || echo 'Unable to get CPU usage'")
+  }
+
   /// Get running processes from the VM
   pub fn get_processes(name: Option<&str>) -> Result<String> {
     Self::run_command(
@@ -726,7 +814,7 @@ impl ColimaClient {
     }
 
     // Use du to get directory size
-    let output = std::process::Command::new("du")
+    let output = Command::new("du")
       .args(["-sh", &cache_path.to_string_lossy()])
       .stdout(Stdio::piped())
       .stderr(Stdio::piped())
@@ -800,3 +888,51 @@ impl Default for ColimaClient {
     Self::new()
   }
 }
+
+/// Single-quote an argument for the VM shell
+fn quote_arg(arg: &str) -> String {
+  format!("'{}'", arg.replace('\'', "'\"'\"'"))
+}
+
+/// Shell command that replaces `path` with whatever arrives on stdin
+fn write_file_command(path: &str, as_root: bool) -> String {
+  if as_root {
+    format!("sudo tee {} > /dev/null", quote_arg(path))
+  } else {
+    format!("cat > {}", quote_arg(path))
+  }
+}
+
+/// Keep the first `max_lines` lines, marking the cut so the viewer knows
+/// the text is partial.
+fn truncate_lines(content: String, max_lines: u32) -> String {
+  let max = max_lines as usize;
+  if content.lines().count() <= max {
+    return content;
+  }
+  let kept: Vec<&str> = content.lines().take(max).collect();
+  format!("{}\n\n--- truncated at {max_lines} lines ---\n", kept.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_write_file_command_quotes_path() {
+    assert_eq!(write_file_command("/etc/hosts", false), "cat > '/etc/hosts'");
+    assert_eq!(
+      write_file_command("/tmp/it's", true),
+      "sudo tee '/tmp/it'\"'\"'s' > /dev/null"
+    );
+  }
+
+  #[test]
+  fn test_truncate_lines() {
+    assert_eq!(truncate_lines("a\nb\n".to_string(), 2), "a\nb\n");
+    assert_eq!(
+      truncate_lines("a\nb\nc\n".to_string(), 2),
+      "a\nb\n\n--- truncated at 2 lines ---\n"
+    );
+  }
+}
@@ -8,8 +8,10 @@
 //! returns errors / empty results so callers compile unchanged.
 
 mod types;
+mod usage;
 
 pub use types::*;
+pub use usage::*;
 
 #[cfg(any(target_os = "macos", target_os = "linux"))]
 mod client;
@@ -50,7 +52,15 @@ mod client_stub {
       unsupported()
     }
 
-    pub fn start_existing(_name: Option<&str>) -> Result<()> {
+    pub fn start_with_config_streaming(
+      _profile: &str,
+      _config: &ColimaConfig,
+      _on_line: impl FnMut(&str),
+    ) -> Result<()> {
+      unsupported()
+    }
+
+    pub fn start_existing_streaming(_name: Option<&str>, _on_line: impl FnMut(&str)) -> Result<()> {
       unsupported()
     }
 
@@ -98,6 +108,14 @@ mod client_stub {
       unsupported()
     }
 
+    pub fn download_file(_name: Option<&str>, _path: &str, _dest: &std::path::Path) -> Result<()> {
+      unsupported()
+    }
+
+    pub fn write_file(_name: Option<&str>, _path: &str, _content: &str, _as_root: bool) -> Result<()> {
+      unsupported()
+    }
+
     pub fn resolve_symlink(_name: Option<&str>, _path: &str) -> Result<String> {
       unsupported()
     }
@@ -114,6 +132,10 @@ mod client_stub {
       unsupported()
     }
 
+    pub fn get_cpu_usage(_name: Option<&str>) -> Result<String> {
+      unsupported()
+    }
+
     pub fn get_processes(_name: Option<&str>) -> Result<String> {
       unsupported()
     }
@@ -564,6 +564,16 @@ impl MachineId {
   pub fn is_colima(&self) -> bool {
     matches!(self, MachineId::Colima(_))
   }
+
+  /// Docker CLI context pointing at this machine's daemon: `default` for
+  /// the host, the context Colima creates for a profile otherwise
+  pub fn docker_context_name(&self) -> String {
+    match self {
+      MachineId::Host => "default".to_string(),
+      MachineId::Colima(name) if name == "default" => "colima".to_string(),
+      MachineId::Colima(name) => format!("colima-{name}"),
+    }
+  }
 }
 
 impl std::fmt::Display for MachineId {
@@ -776,10 +786,138 @@ impl Machine {
   }
 }
 
+/// Parsed `colima version` output
+#[derive(Debug, Clone, Default)]
+pub struct ColimaVersionInfo {
+  pub version: String,
+  pub git_commit: String,
+  pub runtime: String,
+  pub arch: String,
+  pub client_version: String,
+  pub server_version: String,
+}
+
+impl ColimaVersionInfo {
+  pub fn parse(raw: &str) -> Self {
+    let mut info = Self::default();
+
+    for line in raw.lines() {
+      let line = line.trim();
+      if line.starts_with("colima version") {
+        info.version = line.strip_prefix("colima version").unwrap_or("").trim().to_string();
+      } else if let Some(val) = line.strip_prefix("git commit:") {
+        info.git_commit = val.trim().to_string();
+      } else if let Some(val) = line.strip_prefix("runtime:") {
+        info.runtime = val.trim().to_string();
+      } else if let Some(val) = line.strip_prefix("arch:") {
+        info.arch = val.trim().to_string();
+      } else if let Some(val) = line.strip_prefix("client:") {
+        info.client_version = val.trim().to_string();
+      } else if let Some(val) = line.strip_prefix("server:") {
+        info.server_version = val.trim().to_string();
+      }
+    }
+
+    info
+  }
+
+  pub fn is_loaded(&self) -> bool {
+    !self.version.is_empty()
+  }
+}
+
+/// One progress line from `colima start`. Colima logs through logrus,
+/// either as `INFO[0012] provisioning ...   context=docker` on a terminal or
+/// as `time="..." level=info msg="provisioning ..." context=docker` when
+/// piped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColimaLogLine {
+  pub message: String,
+  /// Subsystem being brought up: `vm`, `docker`, `containerd`, `kubernetes`
+  pub context: Option<String>,
+}
+
+impl ColimaLogLine {
+  pub fn parse(line: &str) -> Option<Self> {
+    let line = line.trim();
+    if line.is_empty() {
+      return None;
+    }
+    let context = line
+      .rsplit_once("context=")
+      .map(|(_, ctx)| ctx.trim_matches('"').to_string())
+      .filter(|ctx| !ctx.is_empty() && !ctx.contains(' '));
+
+    let message = if let Some((_, rest)) = line.split_once("msg=") {
+      match rest.strip_prefix('"') {
+        Some(quoted) => quoted.split_once('"').map_or(quoted, |(msg, _)| msg),
+        None => rest.split_whitespace().next().unwrap_or_default(),
+      }
+    } else {
+      // "INFO[0012] message   context=vm"
+      let body = match line.split_once(']') {
+        Some((level, rest)) if level.contains('[') => rest,
+        _ => line,
+      };
+      body.split(" context=").next().unwrap_or(body)
+    };
+    let message = message.trim();
+    if message.is_empty() {
+      return None;
+    }
+    Some(Self {
+      message: message.to_string(),
+      context,
+    })
+  }
+
+  /// "docker: provisioning ..." — how the task bar shows it
+  pub fn display(&self) -> String {
+    match &self.context {
+      Some(ctx) => format!("{ctx}: {}", self.message),
+      None => self.message.clone(),
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_colima_log_line_parse() {
+    let tty = ColimaLogLine::parse("INFO[0001] downloading disk image ...                     context=vm").unwrap();
+    assert_eq!(tty.message, "downloading disk image ...");
+    assert_eq!(tty.context.as_deref(), Some("vm"));
+    assert_eq!(tty.display(), "vm: downloading disk image ...");
+
+    let piped =
+      ColimaLogLine::parse(r#"time="2024-05-01T10:00:00Z" level=info msg="provisioning ..." context=docker"#).unwrap();
+    assert_eq!(piped.message, "provisioning ...");
+    assert_eq!(piped.context.as_deref(), Some("docker"));
+
+    let plain = ColimaLogLine::parse("INFO[0000] starting colima").unwrap();
+    assert_eq!(plain.message, "starting colima");
+    assert_eq!(plain.context, None);
+
+    assert_eq!(ColimaLogLine::parse("   "), None);
+  }
+
+  #[test]
+  fn test_colima_version_info_parse() {
+    let raw =
+      "colima version 0.8.1\ngit commit: 96598cc\n\nruntime: docker\narch: aarch64\nclient: v27.4.0\nserver: v27.4.0";
+    let info = ColimaVersionInfo::parse(raw);
+    assert!(info.is_loaded());
+    assert_eq!(info.version, "0.8.1");
+    assert_eq!(info.git_commit, "96598cc");
+    assert_eq!(info.runtime, "docker");
+    assert_eq!(info.arch, "aarch64");
+    assert_eq!(info.client_version, "v27.4.0");
+    assert_eq!(info.server_version, "v27.4.0");
+    assert!(!ColimaVersionInfo::parse("").is_loaded());
+  }
+
   #[test]
   fn test_vm_status_is_running() {
     assert!(VmStatus::Running.is_running());
@@ -1099,6 +1237,13 @@ mod tests {
     assert_eq!(format!("{id}"), "dev");
   }
 
+  #[test]
+  fn test_machine_id_docker_context_name() {
+    assert_eq!(MachineId::Host.docker_context_name(), "default");
+    assert_eq!(MachineId::Colima("default".to_string()).docker_context_name(), "colima");
+    assert_eq!(MachineId::Colima("dev".to_string()).docker_context_name(), "colima-dev");
+  }
+
   #[test]
   fn test_machine_id_equality() {
     let host1 = MachineId::Host;
@@ -0,0 +1,182 @@
+//! Resource usage of a Docker VM or host, parsed from the output of
+//! `free -h`, `df -h /` and `top -bn1` run inside it.
+
+/// Usage snapshot shown in the activity monitor header
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MachineUsage {
+  /// Busy CPU across all cores, when `top` was available
+  pub cpu_percent: Option<f64>,
+  pub memory_used: String,
+  pub memory_total: String,
+  pub memory_percent: f64,
+  pub disk_used: String,
+  pub disk_total: String,
+  pub disk_percent: f64,
+}
+
+impl MachineUsage {
+  pub fn parse(memory_info: &str, disk_usage: &str, cpu_info: &str) -> Self {
+    let (memory_used, memory_total, memory_percent) = parse_memory_info(memory_info);
+    let (disk_used, disk_total, disk_percent) = parse_disk_info(disk_usage);
+    Self {
+      cpu_percent: parse_cpu_usage(cpu_info),
+      memory_used,
+      memory_total,
+      memory_percent,
+      disk_used,
+      disk_total,
+      disk_percent,
+    }
+  }
+}
+
+/// Parse memory info from "free -h" output
+/// Returns (used, total, `percent_used`)
+pub fn parse_memory_info(info: &str) -> (String, String, f64) {
+  // Format:
+  //               total        used        free      shared  buff/cache   available
+  // Mem:          7.7Gi       1.2Gi       5.8Gi       0.0Ki       760Mi       6.2Gi
+  for line in info.lines() {
+    let line = line.trim();
+    if line.starts_with("Mem:") {
+      let parts: Vec<&str> = line.split_whitespace().collect();
+      if parts.len() >= 3 {
+        let total = parts[1].to_string();
+        let used = parts[2].to_string();
+
+        // Parse values to calculate percentage
+        let total_val = parse_memory_value(parts[1]);
+        let used_val = parse_memory_value(parts[2]);
+
+        let percent = if total_val > 0.0 {
+          (used_val / total_val) * 100.0
+        } else {
+          0.0
+        };
+
+        return (used, total, percent);
+      }
+    }
+  }
+
+  ("--".to_string(), "--".to_string(), 0.0)
+}
+
+/// Parse memory value like "7.7Gi", "760Mi", "1.2Gi" to bytes
+fn parse_memory_value(s: &str) -> f64 {
+  let s = s.trim();
+
+  // Find where the number ends and unit begins
+  let num_end = s
+    .chars()
+    .position(|c| !c.is_ascii_digit() && c != '.')
+    .unwrap_or(s.len());
+
+  let (num_str, unit) = s.split_at(num_end);
+  let value: f64 = num_str.parse().unwrap_or(0.0);
+
+  match unit.to_lowercase().as_str() {
+    "gi" | "g" => value * 1024.0 * 1024.0 * 1024.0,
+    "mi" | "m" => value * 1024.0 * 1024.0,
+    "ki" | "k" => value * 1024.0,
+    _ => value,
+  }
+}
+
+/// Parse disk info from "df -h /" output
+/// Returns (used, total, `percent_used`)
+pub fn parse_disk_info(info: &str) -> (String, String, f64) {
+  // Format:
+  // Filesystem      Size  Used Avail Use% Mounted on
+  // /dev/vda1        59G   10G   46G  19% /
+  for line in info.lines() {
+    let line = line.trim();
+    // Skip header
+    if line.starts_with("Filesystem") {
+      continue;
+    }
+
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() >= 5 {
+      let total = parts[1].to_string();
+      let used = parts[2].to_string();
+      let percent_str = parts[4].trim_end_matches('%');
+
+      let percent: f64 = percent_str.parse().unwrap_or(0.0);
+
+      return (used, total, percent);
+    }
+  }
+
+  ("--".to_string(), "--".to_string(), 0.0)
+}
+
+/// Parse busy CPU% from `top -bn1` output: 100 minus the idle figure.
+/// Handles procps (`%Cpu(s):  2.0 us, ... 96.5 id, ...`) and busybox
+/// (`CPU:   2% usr   1% sys ... 96% idle ...`) summary lines.
+pub fn parse_cpu_usage(info: &str) -> Option<f64> {
+  let line = info
+    .lines()
+    .map(str::trim)
+    .find(|l| l.starts_with("%Cpu") || l.starts_with("CPU:"))?;
+  let (_, fields) = line.split_once(':')?;
+  let tokens: Vec<&str> = fields
+    .split(|c: char| c == ',' || c.is_whitespace())
+    .filter(|t| !t.is_empty())
+    .collect();
+  let idle_at = tokens.iter().position(|t| *t == "id" || *t == "idle")?;
+  let idle: f64 = tokens
+    .get(idle_at.checked_sub(1)?)?
+    .trim_end_matches('%')
+    .parse()
+    .ok()?;
+  Some((100.0 - idle).clamp(0.0, 100.0))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_memory_info() {
+    let free = "               total        used        free      shared  buff/cache   available\n\
+                Mem:           7.7Gi       1.9Gi       4.6Gi       3.0Mi       1.4Gi       5.8Gi\n\
+                Swap:             0B          0B          0B";
+    let (used, total, percent) = parse_memory_info(free);
+    assert_eq!(used, "1.9Gi");
+    assert_eq!(total, "7.7Gi");
+    assert!((percent - 24.675).abs() < 0.01);
+
+    let (used, total, _) = parse_memory_info("garbage");
+    assert_eq!((used.as_str(), total.as_str()), ("--", "--"));
+  }
+
+  #[test]
+  fn test_parse_disk_info() {
+    let df = "Filesystem      Size  Used Avail Use% Mounted on\n/dev/vda1        59G   10G   46G  19% /";
+    let (used, total, percent) = parse_disk_info(df);
+    assert_eq!((used.as_str(), total.as_str()), ("10G", "59G"));
+    assert!((percent - 19.0).abs() < 0.01);
+  }
+
+  #[test]
+  fn test_parse_cpu_usage() {
+    let procps = "top - 10:00:00 up 1 day,  load average: 0.10, 0.05, 0.01\n\
+                  Tasks: 120 total,   1 running\n\
+                  %Cpu(s):  2.0 us,  1.5 sy,  0.0 ni, 96.5 id,  0.0 wa,  0.0 hi,  0.0 si,  0.0 st";
+    assert!((parse_cpu_usage(procps).unwrap() - 3.5).abs() < 0.01);
+
+    let busybox = "Mem: 1000K used, 500K free\nCPU:   4% usr   2% sys   0% nic  94% idle   0% io   0% irq   0% sirq";
+    assert!((parse_cpu_usage(busybox).unwrap() - 6.0).abs() < 0.01);
+
+    assert_eq!(parse_cpu_usage("Unable to get CPU usage"), None);
+  }
+
+  #[test]
+  fn test_machine_usage_parse() {
+    let usage = MachineUsage::parse("", "", "");
+    assert_eq!(usage.cpu_percent, None);
+    assert_eq!(usage.memory_used, "--");
+    assert_eq!(usage.disk_total, "--");
+  }
+}
@@ -42,10 +42,21 @@ impl ComposeProject {
     self.running_count() == 0
   }
 
-  /// Status display string (e.g., "3/3 running" or "0/2 stopped")
+  /// `(running, total)` counted per service rather than per container,
+  /// so a scaled service with three replicas counts once. A service is
+  /// running when any of its containers is.
+  pub fn service_counts(&self) -> (usize, usize) {
+    let mut running: HashMap<&str, bool> = HashMap::new();
+    for service in &self.services {
+      *running.entry(service.name.as_str()).or_default() |= service.state.is_running();
+    }
+    (running.values().filter(|r| **r).count(), running.len())
+  }
+
+  /// Status display string counted per service (e.g., "3/3 running" or
+  /// "0/2 stopped")
   pub fn status_display(&self) -> String {
-    let running = self.running_count();
-    let total = self.container_count();
+    let (running, total) = self.service_counts();
     if running == total && total > 0 {
       format!("{running}/{total} running")
     } else if running == 0 {
@@ -155,6 +166,8 @@ mod tests {
       volumes_used: vec![],
       networks_used: vec![],
       bridge_ip: None,
+      health: None,
+      restart_count: None,
     }
   }
 
@@ -700,4 +713,32 @@ mod tests {
     let projects = extract_compose_projects(&containers);
     assert_eq!(projects[0].config_files, vec!["/srv/c.yml".to_string()]);
   }
+
+  #[test]
+  fn test_compose_project_service_counts_dedupes_replicas() {
+    let containers = vec![
+      make_container(
+        "app-web-1",
+        "nginx",
+        ContainerState::Running,
+        make_compose_labels("app", "web"),
+      ),
+      make_container(
+        "app-web-2",
+        "nginx",
+        ContainerState::Exited,
+        make_compose_labels("app", "web"),
+      ),
+      make_container(
+        "app-db-1",
+        "postgres",
+        ContainerState::Exited,
+        make_compose_labels("app", "db"),
+      ),
+    ];
+    let projects = extract_compose_projects(&containers);
+    assert_eq!(projects[0].container_count(), 3);
+    assert_eq!(projects[0].service_counts(), (1, 2));
+    assert_eq!(projects[0].status_display(), "1/2 running");
+  }
 }
@@ -1,11 +1,12 @@
 use anyhow::{Result, anyhow};
 use bollard::container::LogOutput;
 use bollard::exec::{CreateExecOptions, StartExecResults};
-use bollard::models::{ContainerCreateBody, HostConfig};
+use bollard::models::{ContainerCreateBody, ContainerUpdateBody, HostConfig};
 use bollard::query_parameters::{
-  CommitContainerOptions, CreateContainerOptions, DownloadFromContainerOptionsBuilder, KillContainerOptions,
-  ListContainersOptions, LogsOptions, RemoveContainerOptions, RenameContainerOptions, RestartContainerOptions,
-  StartContainerOptions, StopContainerOptions, TopOptionsBuilder, UploadToContainerOptionsBuilder,
+  AttachContainerOptionsBuilder, CommitContainerOptions, CreateContainerOptions, DownloadFromContainerOptionsBuilder,
+  KillContainerOptions, ListContainersOptions, LogsOptions, RemoveContainerOptions, RenameContainerOptions,
+  RestartContainerOptions, StartContainerOptions, StopContainerOptions, TopOptionsBuilder,
+  UploadToContainerOptionsBuilder,
 };
 use chrono::{DateTime, Utc};
 use futures::stream::StreamExt;
@@ -74,6 +75,18 @@ impl ContainerState {
     matches!(self, ContainerState::Paused)
   }
 
+  /// Ordering bucket for lists: running first, then paused/restarting,
+  /// created, exited, and dead last.
+  pub fn sort_rank(self) -> u8 {
+    match self {
+      ContainerState::Running => 0,
+      ContainerState::Paused | ContainerState::Restarting => 1,
+      ContainerState::Created => 2,
+      ContainerState::Exited | ContainerState::Removing => 3,
+      ContainerState::Dead | ContainerState::Unknown => 4,
+    }
+  }
+
   pub fn from_str(s: &str) -> Self {
     match s.to_lowercase().as_str() {
       "running" => ContainerState::Running,
@@ -88,6 +101,38 @@ impl ContainerState {
   }
 }
 
+/// Healthcheck state of a container that defines a `HEALTHCHECK`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HealthStatus {
+  Starting,
+  Healthy,
+  Unhealthy,
+}
+
+impl HealthStatus {
+  /// Parse the suffix the daemon appends to the list `Status` text:
+  /// "Up 2 minutes (healthy)", "(unhealthy)", "(health: starting)".
+  pub fn from_status(status: &str) -> Option<Self> {
+    if status.contains("(unhealthy)") {
+      Some(HealthStatus::Unhealthy)
+    } else if status.contains("(healthy)") {
+      Some(HealthStatus::Healthy)
+    } else if status.contains("(health: starting)") {
+      Some(HealthStatus::Starting)
+    } else {
+      None
+    }
+  }
+
+  pub fn label(self) -> &'static str {
+    match self {
+      HealthStatus::Starting => "starting",
+      HealthStatus::Healthy => "healthy",
+      HealthStatus::Unhealthy => "unhealthy",
+    }
+  }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PortMapping {
   pub private_port: u16,
@@ -125,6 +170,8 @@ pub struct ContainerCreateConfig {
   pub labels: Vec<(String, String)>,
   /// Number of CPU cores allowed (e.g. 1.5 → 1,500,000,000 `NanoCPUs`).
   pub cpus: Option<f64>,
+  /// Relative CPU weight against other containers (default 1024).
+  pub cpu_shares: Option<i64>,
   /// Hard memory cap in bytes.
   pub memory_bytes: Option<i64>,
   /// Memory + swap cap in bytes (Docker uses `memory_bytes` + swap; -1 = unlimited).
@@ -142,6 +189,17 @@ pub struct ContainerCreateConfig {
   pub healthcheck_start_period_ns: Option<i64>,
   /// Number of retries before marking unhealthy.
   pub healthcheck_retries: Option<i64>,
+  /// Custom DNS servers (`--dns`).
+  pub dns: Vec<String>,
+  /// Extra `/etc/hosts` entries as `name:ip` (`--add-host`).
+  pub extra_hosts: Vec<String>,
+  /// Linux capabilities to grant / revoke, without the `CAP_` prefix
+  /// (`--cap-add` / `--cap-drop`).
+  pub cap_add: Vec<String>,
+  pub cap_drop: Vec<String>,
+  /// Security options such as `no-new-privileges` or `seccomp=unconfined`
+  /// (`--security-opt`).
+  pub security_opt: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -171,12 +229,130 @@ pub struct ContainerInfo {
   /// native Docker this is host-routable via `docker0`; on Docker Desktop
   /// / Colima it is not.
   pub bridge_ip: Option<std::net::IpAddr>,
+  /// Healthcheck state, `None` when the container has no `HEALTHCHECK`
+  /// or isn't running. Refreshed with the list on every poll.
+  pub health: Option<HealthStatus>,
+  /// `RestartCount` from inspect. The list endpoint doesn't report it, so
+  /// it's only fetched for containers that look like they're crash-looping
+  /// (see `may_be_restarting`); `None` everywhere else.
+  pub restart_count: Option<i64>,
 }
 
+/// Restart count at which the list flags a container as crash-looping.
+pub const RESTART_WARN_THRESHOLD: i64 = 3;
+
 impl ContainerInfo {
   pub fn short_id(&self) -> &str {
     if self.id.len() >= 12 { &self.id[..12] } else { &self.id }
   }
+
+  pub fn is_restart_looping(&self) -> bool {
+    self.restart_count.is_some_and(|n| n >= RESTART_WARN_THRESHOLD)
+  }
+
+  /// Bare container for tests, with id `<name>-id` and everything else
+  /// empty. Set other fields with struct update syntax.
+  #[cfg(test)]
+  pub fn for_test(name: &str, image: &str, state: ContainerState) -> Self {
+    Self {
+      id: format!("{name}-id"),
+      name: name.to_string(),
+      image: image.to_string(),
+      image_id: String::new(),
+      state,
+      status: String::new(),
+      created: None,
+      ports: vec![],
+      labels: HashMap::new(),
+      command: None,
+      size_rw: None,
+      size_root_fs: None,
+      volumes_used: vec![],
+      networks_used: vec![],
+      bridge_ip: None,
+      health: None,
+      restart_count: None,
+    }
+  }
+}
+
+/// A crash-looping container is either mid-restart or was (re)started
+/// seconds ago ("Up 5 seconds", "Up Less than a second"). Only these get
+/// the extra inspect needed for `restart_count`.
+fn may_be_restarting(state: ContainerState, status: &str) -> bool {
+  match state {
+    ContainerState::Restarting => true,
+    ContainerState::Running => {
+      let uptime = status.split(" (").next().unwrap_or(status);
+      uptime.ends_with("second") || uptime.ends_with("seconds")
+    }
+    _ => false,
+  }
+}
+
+/// `docker stop`'s grace period before SIGKILL
+pub const DEFAULT_STOP_TIMEOUT_SECS: u64 = 10;
+
+/// A stop that used up the whole grace period and left exit code 137
+/// (SIGKILL, not the OOM killer) was escalated to a kill by the daemon.
+fn stop_escalated_to_kill(
+  elapsed: std::time::Duration,
+  timeout_secs: u64,
+  exit_code: Option<i64>,
+  oom_killed: Option<bool>,
+) -> bool {
+  elapsed.as_secs() >= timeout_secs && exit_code == Some(137) && oom_killed != Some(true)
+}
+
+/// JSON pointers kept in the Inspect tab's "Summary" mode, in display order.
+const INSPECT_SUMMARY_FIELDS: &[&str] = &[
+  "/Id",
+  "/Name",
+  "/Created",
+  "/Config/Image",
+  "/State/Status",
+  "/State/ExitCode",
+  "/State/OOMKilled",
+  "/State/StartedAt",
+  "/State/FinishedAt",
+  "/State/Health/Status",
+  "/RestartCount",
+  "/Config/Entrypoint",
+  "/Config/Cmd",
+  "/Config/WorkingDir",
+  "/Config/User",
+  "/Config/Env",
+  "/Config/Labels",
+  "/HostConfig/RestartPolicy",
+  "/HostConfig/NetworkMode",
+  "/HostConfig/Memory",
+  "/HostConfig/NanoCpus",
+  "/HostConfig/Privileged",
+  "/NetworkSettings/Ports",
+  "/NetworkSettings/Networks",
+  "/Mounts",
+];
+
+/// Trim raw `docker inspect` JSON down to the fields people usually look
+/// for, keeping their nesting. `None` when `raw` isn't JSON (an error
+/// message), so callers can show it unchanged.
+pub fn inspect_summary(raw: &str) -> Option<String> {
+  let full: serde_json::Value = serde_json::from_str(raw).ok()?;
+  let mut summary = serde_json::Value::Object(serde_json::Map::new());
+  for pointer in INSPECT_SUMMARY_FIELDS {
+    let Some(value) = full.pointer(pointer).filter(|v| !v.is_null()) else {
+      continue;
+    };
+    let mut slot = &mut summary;
+    for key in pointer[1..].split('/') {
+      slot = slot
+        .as_object_mut()?
+        .entry(key)
+        .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+    }
+    *slot = value.clone();
+  }
+  serde_json::to_string_pretty(&summary).ok()
 }
 
 /// Extras pulled from a full container inspect — surfaced on the Info tab.
@@ -184,10 +360,93 @@ impl ContainerInfo {
 pub struct ContainerExtras {
   pub restart_count: Option<i64>,
   pub exit_code: Option<i64>,
+  /// `State.OOMKilled`: the last exit was the kernel's OOM killer.
+  pub oom_killed: Option<bool>,
   pub started_at: Option<String>,
   pub finished_at: Option<String>,
   pub health: Option<ContainerHealth>,
   pub mounts: Vec<MountInfo>,
+  /// Configured DNS servers (`HostConfig.Dns`).
+  pub dns: Vec<String>,
+  /// Configured DNS search domains (`HostConfig.DnsSearch`).
+  pub dns_search: Vec<String>,
+  /// Extra `/etc/hosts` entries (`HostConfig.ExtraHosts`), as `name:ip`.
+  pub extra_hosts: Vec<String>,
+  /// `HostConfig.RestartPolicy.Name`, `no` when unset.
+  pub restart_policy: String,
+  /// CPU limit (`HostConfig.NanoCpus`), `None` when unlimited.
+  pub nano_cpus: Option<i64>,
+  /// Memory limit in bytes (`HostConfig.Memory`), `None` when unlimited.
+  pub memory_limit: Option<i64>,
+}
+
+/// Settings `docker update` can change on an existing container without
+/// recreating it. `None` leaves a setting as it is.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerUpdate {
+  /// `no`, `always`, `on-failure` or `unless-stopped`.
+  pub restart_policy: Option<String>,
+  pub cpus: Option<f64>,
+  pub memory_bytes: Option<i64>,
+}
+
+impl ContainerUpdate {
+  /// Request body for the update. `current_swap` is the container's
+  /// `HostConfig.MemorySwap`.
+  fn into_body(self, current_swap: Option<i64>) -> ContainerUpdateBody {
+    // The daemon rejects a memory limit above the current swap limit, so
+    // only then move swap along with it, at the `docker run --memory`
+    // default of twice the memory. Unlimited (-1) or unset swap is kept.
+    let memory_swap = self
+      .memory_bytes
+      .filter(|&memory| current_swap.is_some_and(|swap| swap > 0 && swap < memory))
+      .map(|memory| memory.saturating_mul(2));
+    ContainerUpdateBody {
+      restart_policy: self.restart_policy.as_deref().map(restart_policy),
+      nano_cpus: self.cpus.map(nano_cpus),
+      memory: self.memory_bytes,
+      memory_swap,
+      ..Default::default()
+    }
+  }
+}
+
+/// Docker restart policy from its CLI name. Unknown names mean `no`.
+fn restart_policy(name: &str) -> bollard::models::RestartPolicy {
+  bollard::models::RestartPolicy {
+    name: Some(match name {
+      "always" => bollard::models::RestartPolicyNameEnum::ALWAYS,
+      "on-failure" => bollard::models::RestartPolicyNameEnum::ON_FAILURE,
+      "unless-stopped" => bollard::models::RestartPolicyNameEnum::UNLESS_STOPPED,
+      _ => bollard::models::RestartPolicyNameEnum::NO,
+    }),
+    maximum_retry_count: None,
+  }
+}
+
+/// Check `name` against Docker's container name rule,
+/// `[a-zA-Z0-9][a-zA-Z0-9_.-]+`, so a bad name is caught before the call.
+pub fn validate_container_name(name: &str) -> Result<(), String> {
+  let mut chars = name.chars();
+  let Some(first) = chars.next() else {
+    return Err("Enter a name".to_string());
+  };
+  if !first.is_ascii_alphanumeric() {
+    return Err("Names must start with a letter or digit".to_string());
+  }
+  if name.len() < 2 {
+    return Err("Names must be at least 2 characters".to_string());
+  }
+  if let Some(bad) = chars.find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))) {
+    return Err(format!("'{bad}' isn't allowed; use letters, digits, '_', '.' or '-'"));
+  }
+  Ok(())
+}
+
+/// 1 CPU = `1_000_000_000` `NanoCPUs`.
+#[allow(clippy::cast_possible_truncation)]
+fn nano_cpus(cpus: f64) -> i64 {
+  (cpus * 1_000_000_000.0) as i64
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -238,6 +497,32 @@ impl ContainerFileEntry {
   }
 }
 
+/// Key sequence that detaches an attach session without signalling the
+/// container's main process (same default as `docker attach`).
+pub const ATTACH_DETACH_KEYS: &str = "ctrl-p,ctrl-q";
+
+/// Raw bytes for [`ATTACH_DETACH_KEYS`] — Ctrl-P followed by Ctrl-Q.
+pub const ATTACH_DETACH_BYTES: [u8; 2] = [0x10, 0x11];
+
+/// How the container's main process was started, which decides whether an
+/// attach session can type into it or only watch its output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AttachMode {
+  /// Container was created with a TTY (`-t`).
+  pub tty: bool,
+  /// Container keeps stdin open (`-i`).
+  pub open_stdin: bool,
+}
+
+impl AttachMode {
+  /// Input is only forwarded to `-it` containers. Without a TTY Docker
+  /// ignores detach keys, so typing would risk signalling the process
+  /// with no safe way back out.
+  pub fn is_interactive(self) -> bool {
+    self.tty && self.open_stdin
+  }
+}
+
 impl DockerClient {
   pub async fn list_containers(&self, all: bool) -> Result<Vec<ContainerInfo>> {
     let docker = self.client()?;
@@ -306,6 +591,7 @@ impl DockerClient {
         image: container.image.unwrap_or_default(),
         image_id: container.image_id.unwrap_or_default(),
         state: ContainerState::from_str(&container.state.map(|s| format!("{s:?}")).unwrap_or_default()),
+        health: container.status.as_deref().and_then(HealthStatus::from_status),
         status: container.status.unwrap_or_default(),
         created,
         ports,
@@ -316,21 +602,36 @@ impl DockerClient {
         volumes_used,
         networks_used,
         bridge_ip,
+        restart_count: None,
       });
     }
 
+    // Inspect concurrently, only for the handful that may be crash-looping
+    let restart_counts = futures::future::join_all(
+      result
+        .iter()
+        .filter(|c| may_be_restarting(c.state, &c.status))
+        .map(|c| async {
+          let info = docker
+            .inspect_container(&c.id, None::<bollard::query_parameters::InspectContainerOptions>)
+            .await
+            .ok()?;
+          Some((c.id.clone(), info.restart_count?))
+        }),
+    )
+    .await;
+    for (id, count) in restart_counts.into_iter().flatten() {
+      if let Some(c) = result.iter_mut().find(|c| c.id == id) {
+        c.restart_count = Some(count);
+      }
+    }
+
     // Sort: running first (then paused, restarting), then stopped/exited/dead.
     // Within each bucket, most recently created first, name-tiebreak.
     result.sort_by(|a, b| {
-      let bucket = |s: ContainerState| match s {
-        ContainerState::Running => 0,
-        ContainerState::Paused | ContainerState::Restarting => 1,
-        ContainerState::Created => 2,
-        ContainerState::Exited | ContainerState::Removing => 3,
-        ContainerState::Dead | ContainerState::Unknown => 4,
-      };
-      bucket(a.state)
-        .cmp(&bucket(b.state))
+      a.state
+        .sort_rank()
+        .cmp(&b.state.sort_rank())
         .then_with(|| b.created.cmp(&a.created))
         .then_with(|| a.name.cmp(&b.name))
     });
@@ -343,18 +644,38 @@ impl DockerClient {
     Ok(())
   }
 
-  pub async fn stop_container(&self, id: &str) -> Result<()> {
+  /// Stop a container: send `signal` (default: the image's `STOPSIGNAL`,
+  /// usually SIGTERM), then SIGKILL after `timeout` seconds (default 10).
+  /// Returns true when the grace period ran out and the daemon killed it.
+  pub async fn stop_container(&self, id: &str, timeout: Option<u64>, signal: Option<&str>) -> Result<bool> {
+    use bollard::query_parameters::InspectContainerOptions;
     let docker = self.client()?;
+    let timeout = timeout.unwrap_or(DEFAULT_STOP_TIMEOUT_SECS);
+    let started = std::time::Instant::now();
     docker
       .stop_container(
         id,
         Some(StopContainerOptions {
-          t: Some(10),
-          signal: None,
+          t: Some(i32::try_from(timeout).unwrap_or(i32::MAX)),
+          signal: signal.map(str::to_string),
         }),
       )
       .await?;
-    Ok(())
+    let elapsed = started.elapsed();
+    if elapsed.as_secs() < timeout {
+      return Ok(false);
+    }
+    let state = docker
+      .inspect_container(id, None::<InspectContainerOptions>)
+      .await?
+      .state
+      .unwrap_or_default();
+    Ok(stop_escalated_to_kill(
+      elapsed,
+      timeout,
+      state.exit_code,
+      state.oom_killed,
+    ))
   }
 
   pub async fn restart_container(&self, id: &str) -> Result<()> {
@@ -420,8 +741,13 @@ impl DockerClient {
           name: new_name.to_string(),
         },
       )
-      .await?;
-    Ok(())
+      .await
+      .map_err(|e| match e {
+        bollard::errors::Error::DockerResponseServerError { status_code: 409, .. } => {
+          anyhow!("The name '{new_name}' is already in use by another container")
+        }
+        e => e.into(),
+      })
   }
 
   pub async fn commit_container(
@@ -482,15 +808,7 @@ impl DockerClient {
     if let Some(ref policy) = cfg.restart_policy
       && policy != "no"
     {
-      host_config.restart_policy = Some(bollard::models::RestartPolicy {
-        name: Some(match policy.as_str() {
-          "always" => bollard::models::RestartPolicyNameEnum::ALWAYS,
-          "on-failure" => bollard::models::RestartPolicyNameEnum::ON_FAILURE,
-          "unless-stopped" => bollard::models::RestartPolicyNameEnum::UNLESS_STOPPED,
-          _ => bollard::models::RestartPolicyNameEnum::NO,
-        }),
-        maximum_retry_count: None,
-      });
+      host_config.restart_policy = Some(restart_policy(policy));
     }
 
     // Port bindings
@@ -532,13 +850,16 @@ impl DockerClient {
 
     // Resource limits.
     if let Some(cpus) = cfg.cpus {
-      // 1 CPU = 1_000_000_000 NanoCPUs.
-      #[allow(clippy::cast_possible_truncation)]
-      let nano = (cpus * 1_000_000_000.0) as i64;
+      let nano = nano_cpus(cpus);
       if nano > 0 {
         host_config.nano_cpus = Some(nano);
       }
     }
+    if let Some(shares) = cfg.cpu_shares
+      && shares > 0
+    {
+      host_config.cpu_shares = Some(shares);
+    }
     if let Some(mem) = cfg.memory_bytes
       && mem > 0
     {
@@ -553,6 +874,25 @@ impl DockerClient {
       host_config.pids_limit = Some(pids);
     }
 
+    // DNS and /etc/hosts overrides.
+    if !cfg.dns.is_empty() {
+      host_config.dns = Some(cfg.dns.clone());
+    }
+    if !cfg.extra_hosts.is_empty() {
+      host_config.extra_hosts = Some(cfg.extra_hosts.clone());
+    }
+
+    // Capabilities and security options.
+    if !cfg.cap_add.is_empty() {
+      host_config.cap_add = Some(cfg.cap_add.clone());
+    }
+    if !cfg.cap_drop.is_empty() {
+      host_config.cap_drop = Some(cfg.cap_drop.clone());
+    }
+    if !cfg.security_opt.is_empty() {
+      host_config.security_opt = Some(cfg.security_opt.clone());
+    }
+
     // Environment variables
     let env: Option<Vec<String>> = if cfg.env_vars.is_empty() {
       None
@@ -625,7 +965,8 @@ impl DockerClient {
     Ok(response.id)
   }
 
-  /// Fetch container logs as a snapshot (no follow).
+  /// Fetch container logs as a snapshot (no follow). `tail: None` fetches
+  /// the whole log.
   pub async fn container_logs(&self, id: &str, tail: Option<usize>, timestamps: bool) -> Result<String> {
     let docker = self.client()?;
 
@@ -633,7 +974,7 @@ impl DockerClient {
       stdout: true,
       stderr: true,
       timestamps,
-      tail: tail.map_or_else(|| "100".to_string(), |t| t.to_string()),
+      tail: tail.map_or_else(|| "all".to_string(), |t| t.to_string()),
       ..Default::default()
     };
 
@@ -701,6 +1042,91 @@ impl DockerClient {
     Ok(())
   }
 
+  /// Read the TTY / stdin flags of a container's main process.
+  pub async fn attach_mode(&self, id: &str) -> Result<AttachMode> {
+    use bollard::query_parameters::InspectContainerOptions;
+    let docker = self.client()?;
+    let info = docker.inspect_container(id, None::<InspectContainerOptions>).await?;
+    let config = info.config.unwrap_or_default();
+    Ok(AttachMode {
+      tty: config.tty.unwrap_or(false),
+      open_stdin: config.open_stdin.unwrap_or(false),
+    })
+  }
+
+  /// Attach to a container's main process (`docker attach`). Output is
+  /// forwarded to `tx`; bytes received on `input` are written to the
+  /// process's stdin when `mode` is interactive, and `(cols, rows)` sizes
+  /// received on `resize` resize the container's TTY. Unlike exec this does
+  /// not start a new process, so a Ctrl-C typed here reaches PID 1. Returns
+  /// once the container detaches or exits, or either channel is closed.
+  pub async fn attach_container(
+    &self,
+    id: &str,
+    mode: AttachMode,
+    tx: tokio::sync::mpsc::Sender<Vec<u8>>,
+    mut input: tokio::sync::mpsc::UnboundedReceiver<Vec<u8>>,
+    mut resize: tokio::sync::mpsc::UnboundedReceiver<(u16, u16)>,
+  ) -> Result<()> {
+    use bollard::container::AttachContainerResults;
+    use bollard::query_parameters::ResizeContainerTTYOptionsBuilder;
+    use tokio::io::AsyncWriteExt;
+
+    let docker = self.client()?;
+    let options = AttachContainerOptionsBuilder::new()
+      .stdin(mode.is_interactive())
+      .stdout(true)
+      .stderr(true)
+      .stream(true)
+      .detach_keys(ATTACH_DETACH_KEYS)
+      .build();
+
+    let AttachContainerResults {
+      mut output,
+      input: mut stdin,
+    } = docker
+      .attach_container(id, Some(options))
+      .await
+      .map_err(|e| translate_exec_error("attach", id, &e))?;
+
+    loop {
+      tokio::select! {
+        chunk = output.next() => match chunk {
+          Some(Ok(out)) => {
+            let bytes = out.into_bytes();
+            // A TTY already emits CRLF; multiplexed non-TTY output doesn't.
+            let raw = if mode.tty { bytes.to_vec() } else { ensure_crlf(&bytes) };
+            if tx.send(raw).await.is_err() {
+              return Ok(());
+            }
+          }
+          Some(Err(e)) => return Err(anyhow!("Attach stream failed: {e}")),
+          None => return Ok(()),
+        },
+        bytes = input.recv() => match bytes {
+          Some(bytes) if mode.is_interactive() => {
+            stdin.write_all(&bytes).await?;
+            stdin.flush().await?;
+          }
+          Some(_) => {}
+          None => return Ok(()),
+        },
+        Some((cols, rows)) = resize.recv() => {
+          if mode.tty {
+            let options = ResizeContainerTTYOptionsBuilder::new()
+              .w(i32::from(cols))
+              .h(i32::from(rows))
+              .build();
+            // A failed resize only garbles the layout; keep the session alive.
+            if let Err(e) = docker.resize_container_tty(id, options).await {
+              tracing::debug!(target: "dockside.attach", err = %e, "tty resize failed");
+            }
+          }
+        }
+      }
+    }
+  }
+
   /// Inspect a container and return JSON
   pub async fn inspect_container(&self, id: &str) -> Result<String> {
     use bollard::query_parameters::InspectContainerOptions;
@@ -719,6 +1145,7 @@ impl DockerClient {
 
     let state = info.state.unwrap_or_default();
     let exit_code = state.exit_code;
+    let oom_killed = state.oom_killed;
     let restart_count = info.restart_count;
     let started_at = state.started_at;
     let finished_at = state.finished_at;
@@ -758,16 +1185,51 @@ impl DockerClient {
       })
       .collect();
 
+    let host_config = info.host_config.unwrap_or_default();
+    let dns = host_config.dns.unwrap_or_default();
+    let dns_search = host_config.dns_search.unwrap_or_default();
+    let extra_hosts = host_config.extra_hosts.unwrap_or_default();
+    let restart_policy = host_config
+      .restart_policy
+      .and_then(|p| p.name)
+      .map(|n| n.to_string())
+      .filter(|n| !n.is_empty())
+      .unwrap_or_else(|| "no".to_string());
+    let nano_cpus = host_config.nano_cpus.filter(|n| *n > 0);
+    let memory_limit = host_config.memory.filter(|m| *m > 0);
+
     Ok(ContainerExtras {
       restart_count,
       exit_code,
+      oom_killed,
       started_at,
       finished_at,
       health,
       mounts,
+      dns,
+      dns_search,
+      extra_hosts,
+      restart_policy,
+      nano_cpus,
+      memory_limit,
     })
   }
 
+  /// Change restart policy or resource limits on an existing container
+  pub async fn update_container(&self, id: &str, update: ContainerUpdate) -> Result<()> {
+    use bollard::query_parameters::InspectContainerOptions;
+
+    let docker = self.client()?;
+    let current_swap = if update.memory_bytes.is_some() {
+      let info = docker.inspect_container(id, None::<InspectContainerOptions>).await?;
+      info.host_config.and_then(|h| h.memory_swap)
+    } else {
+      None
+    };
+    docker.update_container(id, update.into_body(current_swap)).await?;
+    Ok(())
+  }
+
   /// Execute a command in a container and return combined stdout+stderr.
   /// Treats any nonzero exit code as success (caller may want stderr text).
   /// For richer error handling use `exec_command_full`.
@@ -930,31 +1392,85 @@ impl DockerClient {
     Ok(())
   }
 
-  /// Stream a tar archive of `src_path` from the container and unpack it
-  /// into `dest_dir` on the host. The archive root contains the resource
-  /// addressed by `src_path` (matching `docker cp` semantics).
-  pub async fn cp_from_container(&self, id: &str, src_path: &str, dest_dir: &std::path::Path) -> Result<()> {
-    use std::io::Cursor;
+  /// Overwrite the regular file at `path` with `content`, keeping its mode
+  /// and ownership. The current entry is fetched through the archive
+  /// endpoint so its tar header can be reused for the upload; binary,
+  /// non-UTF-8 and over-sized files are refused since the viewer could not
+  /// have shown them faithfully.
+  pub async fn write_container_file(&self, id: &str, path: &str, content: &[u8]) -> Result<()> {
+    let docker = self.client()?;
+    let opts = DownloadFromContainerOptionsBuilder::default().path(path).build();
+    let mut stream = docker.download_from_container(id, Some(opts));
+    let mut original: Vec<u8> = Vec::new();
+    while let Some(chunk) = stream.next().await {
+      original.extend_from_slice(&chunk.map_err(|e| anyhow!("Failed to read {path}: {e}"))?);
+    }
+
+    let archive = replace_archive_file(&original, content)?;
+    let opts = UploadToContainerOptionsBuilder::default()
+      .path(parent_dir(path))
+      .build();
+    docker
+      .upload_to_container(id, Some(opts), bollard::body_full(archive.into()))
+      .await
+      .map_err(|e| anyhow!("Failed to write {path}: {e}"))?;
+    Ok(())
+  }
+
+  /// Download `src_path` from the container into the host directory
+  /// `dest_dir`, reporting bytes received as the archive streams in.
+  /// Files are unpacked in place; directories are saved untouched as a
+  /// `<name>.tar` tarball, numbered so an existing one is never replaced.
+  /// Returns the host path that was written.
+  pub async fn download_from_container<F>(
+    &self,
+    id: &str,
+    src_path: &str,
+    dest_dir: &std::path::Path,
+    mut on_progress: F,
+  ) -> Result<std::path::PathBuf>
+  where
+    F: FnMut(u64) + Send,
+  {
+    use std::io::{Cursor, Write};
 
     let docker = self.client()?;
     if !dest_dir.is_dir() {
       return Err(anyhow!("Destination is not a directory: {}", dest_dir.display()));
     }
+    let is_dir = self.is_directory(id, src_path).await.unwrap_or(false);
 
     let opts = DownloadFromContainerOptionsBuilder::default().path(src_path).build();
     let mut stream = docker.download_from_container(id, Some(opts));
 
+    if is_dir {
+      let target = unused_archive_path(dest_dir, src_path);
+      let mut file =
+        std::fs::File::create_new(&target).map_err(|e| anyhow!("Failed to create {}: {e}", target.display()))?;
+      let mut received: u64 = 0;
+      while let Some(chunk) = stream.next().await {
+        let bytes = chunk.map_err(|e| anyhow!("Download stream error: {e}"))?;
+        file
+          .write_all(&bytes)
+          .map_err(|e| anyhow!("Failed to write {}: {e}", target.display()))?;
+        received += bytes.len() as u64;
+        on_progress(received);
+      }
+      return Ok(target);
+    }
+
     let mut buf: Vec<u8> = Vec::new();
     while let Some(chunk) = stream.next().await {
       let bytes = chunk.map_err(|e| anyhow!("Download stream error: {e}"))?;
       buf.extend_from_slice(&bytes);
+      on_progress(buf.len() as u64);
     }
 
     let mut archive = tar::Archive::new(Cursor::new(buf));
     archive
       .unpack(dest_dir)
       .map_err(|e| anyhow!("Failed to unpack archive into {}: {e}", dest_dir.display()))?;
-    Ok(())
+    Ok(dest_dir.join(download_archive_base(src_path)))
   }
 
   /// Resolve a symlink to its absolute target path.
@@ -1181,16 +1697,176 @@ fn finalize_file_read(content: String, max_bytes: u64) -> Result<String> {
   Ok(content)
 }
 
+/// Largest file the viewer loads in full, and so the largest one it can edit.
+const MAX_EDIT_BYTES: usize = 1024 * 1024;
+
+/// Whether text returned by `read_container_file` is the whole file,
+/// decoded losslessly, so saving it back can't corrupt anything.
+pub fn is_editable_file_content(content: &str) -> bool {
+  !content.contains('\u{FFFD}') && !content.contains("\n\n--- truncated at ")
+}
+
+/// Rebuild the single-entry archive `docker cp` returns for a file with
+/// new `content`, keeping the entry's name, mode and ownership.
+fn replace_archive_file(original: &[u8], content: &[u8]) -> Result<Vec<u8>> {
+  use std::io::{Cursor, Read};
+
+  let mut archive = tar::Archive::new(Cursor::new(original));
+  let mut entries = archive.entries().map_err(|e| anyhow!("Failed to read archive: {e}"))?;
+  let mut entry = entries
+    .next()
+    .ok_or_else(|| anyhow!("File not found"))?
+    .map_err(|e| anyhow!("Failed to read archive: {e}"))?;
+  if !entry.header().entry_type().is_file() {
+    return Err(anyhow!("Only regular files can be edited"));
+  }
+  let mut existing = Vec::new();
+  entry
+    .read_to_end(&mut existing)
+    .map_err(|e| anyhow!("Failed to read archive: {e}"))?;
+  if existing.len() > MAX_EDIT_BYTES {
+    return Err(anyhow!(
+      "File is larger than {} and can't be edited here",
+      bytesize::ByteSize(MAX_EDIT_BYTES as u64)
+    ));
+  }
+  if existing.contains(&0) || std::str::from_utf8(&existing).is_err() {
+    return Err(anyhow!("Binary or non-UTF-8 files can't be edited"));
+  }
+
+  let name = entry
+    .path()
+    .map_err(|e| anyhow!("Failed to read archive: {e}"))?
+    .into_owned();
+  let mut header = entry.header().clone();
+  header.set_size(content.len() as u64);
+  header.set_mtime(
+    std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .map_or(0, |d| d.as_secs()),
+  );
+
+  let mut builder = tar::Builder::new(Vec::new());
+  builder
+    .append_data(&mut header, name, content)
+    .map_err(|e| anyhow!("Failed to build archive: {e}"))?;
+  builder
+    .into_inner()
+    .map_err(|e| anyhow!("Failed to build archive: {e}"))
+}
+
+/// Directory containing an in-container path (`/` for top-level entries).
+fn parent_dir(path: &str) -> &str {
+  match path.trim_end_matches('/').rsplit_once('/') {
+    Some((parent, _)) if !parent.is_empty() => parent,
+    _ => "/",
+  }
+}
+
 /// Single-quote a path for `sh -c` use.
 fn shell_quote(s: &str) -> String {
   let escaped = s.replace('\'', r"'\''");
   format!("'{escaped}'")
 }
 
+/// Last path component of an in-container path, falling back to
+/// `container-root` for `/`.
+fn download_archive_base(src_path: &str) -> String {
+  src_path
+    .trim_end_matches('/')
+    .rsplit('/')
+    .next()
+    .filter(|s| !s.is_empty())
+    .unwrap_or("container-root")
+    .to_string()
+}
+
+/// Host file name used when a directory is downloaded as a tarball.
+fn download_archive_name(src_path: &str) -> String {
+  format!("{}.tar", download_archive_base(src_path))
+}
+
+/// `<name>.tar` in `dest_dir`, or `<name>-1.tar`, `<name>-2.tar`, ... when
+/// that file already exists.
+fn unused_archive_path(dest_dir: &std::path::Path, src_path: &str) -> std::path::PathBuf {
+  let mut target = dest_dir.join(download_archive_name(src_path));
+  let base = download_archive_base(src_path);
+  let mut n = 1;
+  while target.exists() {
+    target = dest_dir.join(format!("{base}-{n}.tar"));
+    n += 1;
+  }
+  target
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_container_update_body() {
+    let update = ContainerUpdate {
+      restart_policy: Some("unless-stopped".to_string()),
+      cpus: Some(1.5),
+      memory_bytes: Some(512 * 1024 * 1024),
+    };
+    let body = update.clone().into_body(Some(256 * 1024 * 1024));
+    assert_eq!(
+      body.restart_policy.and_then(|p| p.name),
+      Some(bollard::models::RestartPolicyNameEnum::UNLESS_STOPPED)
+    );
+    assert_eq!(body.nano_cpus, Some(1_500_000_000));
+    assert_eq!(body.memory, Some(512 * 1024 * 1024));
+    assert_eq!(body.memory_swap, Some(1024 * 1024 * 1024));
+
+    // Swap already above the new limit, unlimited, or unset stays as is
+    for swap in [Some(2048 * 1024 * 1024), Some(-1), Some(0), None] {
+      assert_eq!(update.clone().into_body(swap).memory_swap, None);
+    }
+
+    let untouched = ContainerUpdate::default().into_body(Some(256 * 1024 * 1024));
+    assert!(untouched.restart_policy.is_none());
+    assert!(untouched.memory.is_none() && untouched.memory_swap.is_none());
+  }
+
+  #[test]
+  fn test_validate_container_name() {
+    assert!(validate_container_name("web").is_ok());
+    assert!(validate_container_name("my_app.v2-1").is_ok());
+    assert!(validate_container_name("").is_err());
+    assert!(validate_container_name("a").is_err());
+    assert!(validate_container_name("-web").is_err());
+    assert!(validate_container_name("_web").is_err());
+    assert_eq!(
+      validate_container_name("my web"),
+      Err("' ' isn't allowed; use letters, digits, '_', '.' or '-'".to_string())
+    );
+  }
+
+  #[test]
+  fn test_attach_mode_requires_tty_and_stdin() {
+    let it = AttachMode {
+      tty: true,
+      open_stdin: true,
+    };
+    assert!(it.is_interactive());
+    assert!(
+      !AttachMode {
+        tty: true,
+        open_stdin: false
+      }
+      .is_interactive()
+    );
+    assert!(
+      !AttachMode {
+        tty: false,
+        open_stdin: true
+      }
+      .is_interactive()
+    );
+    assert!(!AttachMode::default().is_interactive());
+  }
+
   #[test]
   fn test_container_state_from_str() {
     assert_eq!(ContainerState::from_str("running"), ContainerState::Running);
@@ -1233,6 +1909,79 @@ mod tests {
     assert_eq!(format!("{}", ContainerState::Unknown), "Unknown");
   }
 
+  #[test]
+  fn test_health_status_from_status() {
+    assert_eq!(
+      HealthStatus::from_status("Up 2 minutes (healthy)"),
+      Some(HealthStatus::Healthy)
+    );
+    assert_eq!(
+      HealthStatus::from_status("Up 1 minute (unhealthy)"),
+      Some(HealthStatus::Unhealthy)
+    );
+    assert_eq!(
+      HealthStatus::from_status("Up 3 seconds (health: starting)"),
+      Some(HealthStatus::Starting)
+    );
+    assert_eq!(HealthStatus::from_status("Up 5 minutes"), None);
+    assert_eq!(HealthStatus::from_status("Exited (0) 2 hours ago"), None);
+  }
+
+  #[test]
+  fn test_inspect_summary_keeps_selected_fields() {
+    let raw = r#"{
+      "Id": "abc123",
+      "Name": "/web",
+      "State": {"Status": "exited", "ExitCode": 137, "OOMKilled": true, "Pid": 0},
+      "Config": {"Image": "nginx", "Env": ["A=1"], "Hostname": "abc"},
+      "GraphDriver": {"Name": "overlay2"}
+    }"#;
+    let summary: serde_json::Value = serde_json::from_str(&inspect_summary(raw).unwrap()).unwrap();
+    assert_eq!(summary["Name"], "/web");
+    assert_eq!(summary["State"]["ExitCode"], 137);
+    assert_eq!(summary["State"]["OOMKilled"], true);
+    assert_eq!(summary["Config"]["Env"][0], "A=1");
+    assert!(summary["State"].get("Pid").is_none());
+    assert!(summary["Config"].get("Hostname").is_none());
+    assert!(summary.get("GraphDriver").is_none());
+    assert!(inspect_summary("Failed to inspect: no such container").is_none());
+  }
+
+  #[test]
+  fn test_stop_escalated_to_kill() {
+    use std::time::Duration;
+    assert!(stop_escalated_to_kill(
+      Duration::from_secs(10),
+      10,
+      Some(137),
+      Some(false)
+    ));
+    assert!(!stop_escalated_to_kill(Duration::from_secs(2), 10, Some(137), None));
+    assert!(!stop_escalated_to_kill(Duration::from_secs(10), 10, Some(0), None));
+    assert!(!stop_escalated_to_kill(
+      Duration::from_secs(12),
+      10,
+      Some(137),
+      Some(true)
+    ));
+  }
+
+  #[test]
+  fn test_may_be_restarting() {
+    assert!(may_be_restarting(
+      ContainerState::Restarting,
+      "Restarting (1) 3 seconds ago"
+    ));
+    assert!(may_be_restarting(ContainerState::Running, "Up 4 seconds"));
+    assert!(may_be_restarting(ContainerState::Running, "Up Less than a second"));
+    assert!(may_be_restarting(
+      ContainerState::Running,
+      "Up 2 seconds (health: starting)"
+    ));
+    assert!(!may_be_restarting(ContainerState::Running, "Up 2 minutes"));
+    assert!(!may_be_restarting(ContainerState::Exited, "Exited (1) 2 seconds ago"));
+  }
+
   #[test]
   fn test_container_info_short_id() {
     let container = ContainerInfo {
@@ -1251,6 +2000,8 @@ mod tests {
       volumes_used: vec![],
       networks_used: vec![],
       bridge_ip: None,
+      health: None,
+      restart_count: None,
     };
     assert_eq!(container.short_id(), "abc123def456");
 
@@ -1370,6 +2121,8 @@ mod tests {
       volumes_used: vec![],
       networks_used: vec![],
       bridge_ip: None,
+      health: None,
+      restart_count: None,
     };
     assert_eq!(container.short_id(), "123456789012");
   }
@@ -1401,4 +2154,60 @@ mod tests {
     };
     assert_eq!(symlink.display_size(), "42 B");
   }
+
+  #[test]
+  fn test_download_archive_name() {
+    assert_eq!(download_archive_name("/var/log"), "log.tar");
+    assert_eq!(download_archive_name("/var/log/"), "log.tar");
+    assert_eq!(download_archive_name("/"), "container-root.tar");
+    assert_eq!(download_archive_base("/etc/hosts"), "hosts");
+
+    let dir = std::env::temp_dir().join(format!("dockside-archive-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    assert_eq!(unused_archive_path(&dir, "/var/log"), dir.join("log.tar"));
+    std::fs::write(dir.join("log.tar"), b"").unwrap();
+    std::fs::write(dir.join("log-1.tar"), b"").unwrap();
+    assert_eq!(unused_archive_path(&dir, "/var/log"), dir.join("log-2.tar"));
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+
+  fn single_file_archive(name: &str, mode: u32, data: &[u8]) -> Vec<u8> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(mode);
+    header.set_uid(1000);
+    header.set_gid(1000);
+    let mut builder = tar::Builder::new(Vec::new());
+    builder.append_data(&mut header, name, data).unwrap();
+    builder.into_inner().unwrap()
+  }
+
+  #[test]
+  fn test_replace_archive_file_keeps_mode_and_owner() {
+    use std::io::Read;
+
+    let original = single_file_archive("app.conf", 0o640, b"port=80\n");
+    let rewritten = replace_archive_file(&original, b"port=8080\n").unwrap();
+
+    let mut archive = tar::Archive::new(std::io::Cursor::new(rewritten));
+    let mut entry = archive.entries().unwrap().next().unwrap().unwrap();
+    assert_eq!(entry.path().unwrap().to_str(), Some("app.conf"));
+    assert_eq!(entry.header().mode().unwrap(), 0o640);
+    assert_eq!(entry.header().uid().unwrap(), 1000);
+    let mut content = String::new();
+    entry.read_to_string(&mut content).unwrap();
+    assert_eq!(content, "port=8080\n");
+
+    let binary = single_file_archive("blob", 0o644, &[0x7f, b'E', 0, 0xff]);
+    assert!(replace_archive_file(&binary, b"text").is_err());
+  }
+
+  #[test]
+  fn test_parent_dir_and_editable_content() {
+    assert_eq!(parent_dir("/etc/nginx/nginx.conf"), "/etc/nginx");
+    assert_eq!(parent_dir("/hosts"), "/");
+    assert!(is_editable_file_content("a=1\n"));
+    assert!(!is_editable_file_content("a\u{FFFD}b"));
+    assert!(!is_editable_file_content("data\n\n--- truncated at 1.0 MiB ---\n"));
+  }
 }
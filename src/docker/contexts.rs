@@ -0,0 +1,131 @@
+//! Docker CLI contexts (`docker context ls`)
+
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+
+/// One entry from `docker context ls`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DockerContext {
+  pub name: String,
+  pub description: String,
+  /// Daemon endpoint, e.g. `unix:///var/run/docker.sock` or `tcp://host:2375`
+  pub endpoint: String,
+  /// Marked current by the CLI (`docker context use`)
+  pub current: bool,
+}
+
+impl DockerContext {
+  /// Connection string for `DockerRuntime::Custom`: socket path for
+  /// `unix://` endpoints, the URL itself for TCP/HTTP. SSH and other
+  /// transports can't be reached by the API client.
+  pub fn connection_string(&self) -> Result<String> {
+    if let Some(path) = self.endpoint.strip_prefix("unix://") {
+      Ok(path.to_string())
+    } else if self.endpoint.starts_with("tcp://") || self.endpoint.starts_with("http://") {
+      Ok(self.endpoint.clone())
+    } else if self.endpoint.is_empty() {
+      Err(anyhow!("Context '{}' has no Docker endpoint", self.name))
+    } else {
+      Err(anyhow!(
+        "Context '{}' uses an unsupported endpoint: {}",
+        self.name,
+        self.endpoint
+      ))
+    }
+  }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ContextLine {
+  name: String,
+  #[serde(default)]
+  description: String,
+  #[serde(default)]
+  docker_endpoint: String,
+  #[serde(default)]
+  current: bool,
+}
+
+/// Parse `docker context ls --format '{{json .}}'` (one JSON object per line).
+pub fn parse_docker_contexts(stdout: &str) -> Result<Vec<DockerContext>> {
+  stdout
+    .lines()
+    .filter(|l| !l.trim().is_empty())
+    .map(|line| {
+      let entry: ContextLine =
+        serde_json::from_str(line).map_err(|e| anyhow!("Failed to parse docker context ls output: {e}"))?;
+      Ok(DockerContext {
+        name: entry.name,
+        description: entry.description,
+        endpoint: entry.docker_endpoint,
+        current: entry.current,
+      })
+    })
+    .collect()
+}
+
+/// Run `docker context ls` and parse the result.
+pub fn list_docker_contexts() -> Result<Vec<DockerContext>> {
+  let output = crate::utils::docker_cmd()
+    .args(["context", "ls", "--format", "{{json .}}"])
+    .output()
+    .map_err(|e| anyhow!("Failed to run docker context ls: {e}"))?;
+  if !output.status.success() {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    return Err(anyhow!("docker context ls failed: {}", stderr.trim()));
+  }
+  parse_docker_contexts(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Run `docker context use <name>` so CLI-driven features (compose, scans)
+/// target the same daemon as the app.
+pub fn use_docker_context(name: &str) -> Result<()> {
+  let output = crate::utils::docker_cmd()
+    .args(["context", "use", name])
+    .output()
+    .map_err(|e| anyhow!("Failed to run docker context use: {e}"))?;
+  if output.status.success() {
+    Ok(())
+  } else {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    Err(anyhow!("docker context use failed: {}", stderr.trim()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_docker_contexts() {
+    let stdout = r#"{"Current":false,"Description":"Current DOCKER_HOST based configuration","DockerEndpoint":"unix:///var/run/docker.sock","Error":"","Name":"default"}
+{"Current":true,"Description":"colima [dev]","DockerEndpoint":"unix:///Users/me/.colima/dev/docker.sock","Error":"","Name":"colima-dev"}
+"#;
+    let contexts = parse_docker_contexts(stdout).unwrap();
+    assert_eq!(contexts.len(), 2);
+    assert_eq!(contexts[0].name, "default");
+    assert!(!contexts[0].current);
+    assert!(contexts[1].current);
+    assert_eq!(
+      contexts[1].connection_string().unwrap(),
+      "/Users/me/.colima/dev/docker.sock"
+    );
+  }
+
+  #[test]
+  fn test_connection_string_rejects_ssh() {
+    let ctx = DockerContext {
+      name: "remote".to_string(),
+      description: String::new(),
+      endpoint: "ssh://me@host".to_string(),
+      current: false,
+    };
+    assert!(ctx.connection_string().is_err());
+    let tcp = DockerContext {
+      endpoint: "tcp://10.0.0.2:2375".to_string(),
+      ..ctx
+    };
+    assert_eq!(tcp.connection_string().unwrap(), "tcp://10.0.0.2:2375");
+  }
+}
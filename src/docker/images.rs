@@ -8,6 +8,7 @@ use chrono::{DateTime, Utc};
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::path::Path;
 
 use super::DockerClient;
@@ -56,6 +57,218 @@ pub struct PullProgressEvent {
   pub total: Option<i64>,
 }
 
+/// Share of a layer's progress given to downloading; extraction fills
+/// the rest.
+const LAYER_DOWNLOAD_SHARE: f32 = 0.8;
+
+/// Where one layer is in an image pull
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayerPhase {
+  Waiting,
+  Downloading,
+  Downloaded,
+  Extracting,
+  Complete,
+  AlreadyExists,
+}
+
+impl LayerPhase {
+  /// Map a daemon status line to a phase. `None` for statuses that
+  /// aren't about a layer ("Pulling from ...", "Digest: ...").
+  fn from_status(status: &str) -> Option<Self> {
+    match status {
+      "Pulling fs layer" | "Waiting" => Some(Self::Waiting),
+      "Downloading" => Some(Self::Downloading),
+      "Verifying Checksum" | "Download complete" => Some(Self::Downloaded),
+      "Extracting" => Some(Self::Extracting),
+      "Pull complete" => Some(Self::Complete),
+      "Already exists" => Some(Self::AlreadyExists),
+      s if s.starts_with("Retrying") => Some(Self::Downloading),
+      _ => None,
+    }
+  }
+
+  pub fn is_done(self) -> bool {
+    matches!(self, Self::Complete | Self::AlreadyExists)
+  }
+
+  pub fn label(self) -> &'static str {
+    match self {
+      Self::Waiting => "Waiting",
+      Self::Downloading => "Downloading",
+      Self::Downloaded => "Downloaded",
+      Self::Extracting => "Extracting",
+      Self::Complete => "Complete",
+      Self::AlreadyExists => "Already exists",
+    }
+  }
+}
+
+/// One layer of an image pull
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayerProgress {
+  pub id: String,
+  pub phase: LayerPhase,
+  /// Bytes done / total in the current phase
+  pub current: i64,
+  pub total: i64,
+  /// Compressed size, known once the download starts
+  pub size: i64,
+}
+
+impl LayerProgress {
+  /// 0.0 - 1.0 across download and extraction
+  #[allow(clippy::cast_precision_loss)]
+  pub fn fraction(&self) -> f32 {
+    let bytes = if self.total > 0 {
+      (self.current as f32 / self.total as f32).clamp(0.0, 1.0)
+    } else {
+      0.0
+    };
+    match self.phase {
+      LayerPhase::Waiting => 0.0,
+      LayerPhase::Downloading => bytes * LAYER_DOWNLOAD_SHARE,
+      LayerPhase::Downloaded => LAYER_DOWNLOAD_SHARE,
+      LayerPhase::Extracting => LAYER_DOWNLOAD_SHARE + bytes * (1.0 - LAYER_DOWNLOAD_SHARE),
+      LayerPhase::Complete | LayerPhase::AlreadyExists => 1.0,
+    }
+  }
+
+  /// Bytes downloaded so far
+  fn downloaded(&self) -> i64 {
+    match self.phase {
+      LayerPhase::Downloading => self.current,
+      LayerPhase::Downloaded | LayerPhase::Extracting | LayerPhase::Complete => self.size,
+      LayerPhase::Waiting | LayerPhase::AlreadyExists => 0,
+    }
+  }
+}
+
+/// Layer-by-layer state of an image pull, folded from its progress events
+#[derive(Debug, Clone, Default)]
+pub struct PullProgress {
+  /// Layers in the order the daemon first reported them
+  pub layers: Vec<LayerProgress>,
+}
+
+impl PullProgress {
+  /// Stages a pull moves through, for a staged task
+  pub const STAGES: [&str; 3] = ["Resolving image", "Downloading layers", "Extracting layers"];
+
+  /// Fold in one event. Returns false when it wasn't about a layer.
+  pub fn apply(&mut self, ev: &PullProgressEvent) -> bool {
+    let Some(phase) = LayerPhase::from_status(&ev.status) else {
+      return false;
+    };
+    if ev.id.is_empty() {
+      return false;
+    }
+    let idx = if let Some(idx) = self.layers.iter().position(|l| l.id == ev.id) {
+      idx
+    } else {
+      self.layers.push(LayerProgress {
+        id: ev.id.clone(),
+        phase,
+        current: 0,
+        total: 0,
+        size: 0,
+      });
+      self.layers.len() - 1
+    };
+    let layer = &mut self.layers[idx];
+    if layer.phase != phase {
+      layer.current = 0;
+      layer.total = 0;
+    }
+    layer.phase = phase;
+    if let (Some(current), Some(total)) = (ev.current, ev.total)
+      && total > 0
+    {
+      layer.current = current;
+      layer.total = total;
+      if phase == LayerPhase::Downloading {
+        layer.size = total;
+      }
+    }
+    true
+  }
+
+  /// Overall 0.0 - 1.0, each layer counting equally
+  #[allow(clippy::cast_precision_loss)]
+  pub fn fraction(&self) -> f32 {
+    if self.layers.is_empty() {
+      return 0.0;
+    }
+    self.layers.iter().map(LayerProgress::fraction).sum::<f32>() / self.layers.len() as f32
+  }
+
+  /// Index into `STAGES`: resolving until layers show up, downloading
+  /// while any layer is still fetching, then extracting.
+  pub fn stage(&self) -> usize {
+    if self.layers.is_empty() {
+      0
+    } else if self
+      .layers
+      .iter()
+      .any(|l| matches!(l.phase, LayerPhase::Waiting | LayerPhase::Downloading))
+    {
+      1
+    } else {
+      2
+    }
+  }
+
+  /// "3/7 layers · 45.2 MB / 120.0 MB (38%)"
+  pub fn summary(&self) -> String {
+    let done = self.layers.iter().filter(|l| l.phase.is_done()).count();
+    let reused = self
+      .layers
+      .iter()
+      .filter(|l| l.phase == LayerPhase::AlreadyExists)
+      .count();
+    let mut text = format!("{done}/{} layers", self.layers.len());
+    if reused > 0 {
+      let _ = write!(text, " ({reused} cached)");
+    }
+    let size: i64 = self.layers.iter().map(|l| l.size).sum();
+    if size > 0 {
+      let downloaded: i64 = self.layers.iter().map(LayerProgress::downloaded).sum();
+      let _ = write!(
+        text,
+        " · {} / {}",
+        bytesize::ByteSize(u64::try_from(downloaded).unwrap_or(0)),
+        bytesize::ByteSize(u64::try_from(size).unwrap_or(0))
+      );
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let percent = (self.fraction() * 100.0).round() as u32;
+    let _ = write!(text, " ({percent}%)");
+    text
+  }
+}
+
+/// One streamed event from `push_image_with_progress`. `current` /
+/// `total` are byte counts for the layer currently being uploaded; the
+/// daemon does not say which layer, so callers show them as a per-stage
+/// fraction rather than an image-wide one.
+#[derive(Debug, Clone, Default)]
+pub struct PushProgressEvent {
+  pub status: String,
+  pub current: Option<i64>,
+  pub total: Option<i64>,
+}
+
+impl PushProgressEvent {
+  /// Fraction of the current stage, when the daemon reported byte counts.
+  pub fn fraction(&self) -> Option<f32> {
+    match (self.current, self.total) {
+      #[allow(clippy::cast_precision_loss)]
+      (Some(current), Some(total)) if total > 0 => Some((current as f32 / total as f32).clamp(0.0, 1.0)),
+      _ => None,
+    }
+  }
+}
+
 fn format_push_event(info: &bollard::models::PushImageInfo) -> String {
   let status = info.status.clone().unwrap_or_default();
   let progress = info.progress.clone().unwrap_or_default();
@@ -69,6 +282,51 @@ fn format_push_event(info: &bollard::models::PushImageInfo) -> String {
   out
 }
 
+/// Credentials for a single registry push. Held in memory only, so the
+/// `Debug` impl keeps the password out of logs.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct RegistryAuth {
+  /// Registry host (`docker.io`, `ghcr.io`, `localhost:5000`, ...).
+  pub registry: String,
+  pub username: String,
+  pub password: String,
+}
+
+impl std::fmt::Debug for RegistryAuth {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RegistryAuth")
+      .field("registry", &self.registry)
+      .field("username", &self.username)
+      .field("password", &"<redacted>")
+      .finish()
+  }
+}
+
+/// Split an image reference into repository and tag, defaulting the tag to
+/// `latest`. A colon that belongs to a registry port (`localhost:5000/app`)
+/// is not mistaken for a tag separator.
+pub fn split_image_ref(image: &str) -> (&str, &str) {
+  if let Some(pos) = image.rfind(':') {
+    let after_colon = &image[pos + 1..];
+    if after_colon.contains('/') {
+      (image, "latest")
+    } else {
+      (&image[..pos], after_colon)
+    }
+  } else {
+    (image, "latest")
+  }
+}
+
+/// Registry host an image reference resolves to. References without an
+/// explicit host (`nginx`, `library/nginx`, `me/app`) live on Docker Hub.
+pub fn registry_host(image: &str) -> &str {
+  match image.split_once('/') {
+    Some((first, _)) if first.contains('.') || first.contains(':') || first == "localhost" => first,
+    _ => "docker.io",
+  }
+}
+
 /// One entry in `docker image history` output. `id == "<missing>"` when
 /// Docker has dropped the original layer metadata for intermediate layers.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,7 +339,15 @@ pub struct ImageHistoryEntry {
   pub tags: Vec<String>,
 }
 
+/// Layers at or above this size are flagged in the history view.
+pub const LARGE_LAYER_BYTES: i64 = 100 * 1024 * 1024;
+
 impl ImageHistoryEntry {
+  /// Whether this layer is big enough to be worth a second look.
+  pub fn is_large(&self) -> bool {
+    self.size >= LARGE_LAYER_BYTES
+  }
+
   pub fn display_size(&self) -> String {
     bytesize::ByteSize(u64::try_from(self.size).unwrap_or(0)).to_string()
   }
@@ -102,6 +368,11 @@ impl ImageHistoryEntry {
 }
 
 impl ImageInfo {
+  /// Untagged image, usually left behind when a tag moved to a newer build
+  pub fn is_dangling(&self) -> bool {
+    self.repo_tags.iter().all(|t| t == "<none>:<none>")
+  }
+
   pub fn short_id(&self) -> &str {
     let id = self.id.strip_prefix("sha256:").unwrap_or(&self.id);
     if id.len() >= 12 { &id[..12] } else { id }
@@ -118,6 +389,24 @@ impl ImageInfo {
   pub fn display_size(&self) -> String {
     bytesize::ByteSize(u64::try_from(self.size).unwrap_or(0)).to_string()
   }
+
+  /// Whether this image satisfies `reference` (`nginx`, `nginx:1.25`,
+  /// `docker.io/library/nginx:latest`, `nginx@sha256:...`). Docker Hub
+  /// prefixes are ignored and a missing tag means `latest`.
+  pub fn matches_ref(&self, reference: &str) -> bool {
+    fn strip_hub(r: &str) -> &str {
+      r.strip_prefix("docker.io/library/")
+        .or_else(|| r.strip_prefix("docker.io/"))
+        .unwrap_or(r)
+    }
+    if reference.contains('@') {
+      let wanted = strip_hub(reference);
+      return self.repo_digests.iter().any(|d| strip_hub(d) == wanted);
+    }
+    let (repo, tag) = split_image_ref(reference);
+    let wanted = format!("{}:{tag}", strip_hub(repo));
+    self.repo_tags.iter().any(|t| strip_hub(t) == wanted)
+  }
 }
 
 impl DockerClient {
@@ -183,23 +472,24 @@ impl DockerClient {
     })
   }
 
-  /// Fetch the image history (per-layer breakdown) for an image.
+  /// Fetch the image history (per-layer breakdown) for an image,
+  /// newest layer first.
   pub async fn image_history(&self, id: &str) -> Result<Vec<ImageHistoryEntry>> {
     let docker = self.client()?;
     let history = docker.image_history(id).await?;
-    Ok(
-      history
-        .into_iter()
-        .map(|h| ImageHistoryEntry {
-          id: h.id,
-          created: DateTime::from_timestamp(h.created, 0),
-          created_by: h.created_by,
-          size: h.size,
-          comment: h.comment,
-          tags: h.tags,
-        })
-        .collect(),
-    )
+    let mut entries: Vec<ImageHistoryEntry> = history
+      .into_iter()
+      .map(|h| ImageHistoryEntry {
+        id: h.id,
+        created: DateTime::from_timestamp(h.created, 0),
+        created_by: h.created_by,
+        size: h.size,
+        comment: h.comment,
+        tags: h.tags,
+      })
+      .collect();
+    sort_history_newest_first(&mut entries);
+    Ok(entries)
   }
 
   /// Search Docker Hub via the daemon's `/images/search` endpoint.
@@ -319,34 +609,50 @@ impl DockerClient {
     Ok(())
   }
 
-  /// Push `image_name` to its registry. Supports an optional username +
-  /// password pair; pass `None` to use the daemon's stored credentials.
-  /// Streams progress via the callback (one entry per layer event).
+  /// Push `image_name` to its registry. Pass `None` for `auth` to use the
+  /// daemon's stored credentials. Streams progress via the callback (one
+  /// entry per layer event). Errors the daemon reports inside the stream
+  /// (auth denied, unknown repository) fail the push.
   pub async fn push_image_with_progress<F>(
     &self,
     image_name: &str,
     tag: &str,
-    auth: Option<(String, String)>,
+    auth: Option<RegistryAuth>,
     mut on_progress: F,
   ) -> Result<()>
   where
-    F: FnMut(String) + Send,
+    F: FnMut(PushProgressEvent) + Send,
   {
     let docker = self.client()?;
     let opts = PushImageOptionsBuilder::default().tag(tag).build();
-    let credentials = auth.map(|(username, password)| DockerCredentials {
-      username: Some(username),
-      password: Some(password),
+    let credentials = auth.map(|auth| DockerCredentials {
+      username: Some(auth.username),
+      password: Some(auth.password),
+      serveraddress: (!auth.registry.is_empty()).then_some(auth.registry),
       ..Default::default()
     });
     let mut stream = docker.push_image(image_name, Some(opts), credentials);
     while let Some(result) = stream.next().await {
       match result {
         Ok(info) => {
-          let line = format_push_event(&info);
-          if !line.is_empty() {
-            on_progress(line);
+          if let Some(error) = info
+            .error_detail
+            .as_ref()
+            .and_then(|d| d.message.clone())
+            .or(info.error.clone())
+          {
+            return Err(anyhow::anyhow!("Push failed: {error}"));
           }
+          let status = format_push_event(&info);
+          if status.is_empty() {
+            continue;
+          }
+          let detail = info.progress_detail.unwrap_or_default();
+          on_progress(PushProgressEvent {
+            status,
+            current: detail.current,
+            total: detail.total,
+          });
         }
         Err(e) => return Err(anyhow::anyhow!("Push failed: {e}")),
       }
@@ -376,18 +682,49 @@ impl DockerClient {
     Ok(total)
   }
 
-  /// POST a tarball at `src` to `/images/load` and stream the daemon
-  /// response. Each event is forwarded to `on_progress` (status string
-  /// or final image id).
-  pub async fn load_image<F>(&self, src: &Path, mut on_progress: F) -> Result<()>
+  /// Stream the tarball at `src` to `/images/load` in chunks, so large
+  /// archives aren't held in memory. `on_progress` gets `(sent, total)`
+  /// bytes as the upload goes. Returns the daemon's status lines
+  /// (`Loaded image: nginx:latest`).
+  pub async fn load_image<F>(&self, src: &Path, on_progress: F) -> Result<Vec<String>>
   where
-    F: FnMut(String) + Send,
+    F: FnMut(u64, u64) + Send + 'static,
   {
+    use tokio::io::AsyncReadExt;
+    const CHUNK_BYTES: usize = 1024 * 1024;
+
     let docker = self.client()?;
-    let bytes = tokio::fs::read(src).await?;
+    let file = tokio::fs::File::open(src).await?;
+    let total = file.metadata().await?.len();
+    // The body stream can't carry errors; remember a failed read and
+    // report it once the daemon has seen the truncated upload.
+    let read_error: std::sync::Arc<std::sync::Mutex<Option<std::io::Error>>> = std::sync::Arc::default();
+    let read_error_for_body = read_error.clone();
+    let body = futures::stream::unfold((file, 0u64, on_progress), move |(mut file, sent, mut on_progress)| {
+      let read_error = read_error_for_body.clone();
+      async move {
+        let mut buf = vec![0u8; CHUNK_BYTES];
+        match file.read(&mut buf).await {
+          Ok(0) => None,
+          Ok(n) => {
+            buf.truncate(n);
+            let sent = sent + n as u64;
+            on_progress(sent, total);
+            Some((bytes::Bytes::from(buf), (file, sent, on_progress)))
+          }
+          Err(e) => {
+            if let Ok(mut slot) = read_error.lock() {
+              *slot = Some(e);
+            }
+            None
+          }
+        }
+      }
+    });
+
     let opts = ImportImageOptionsBuilder::default().build();
-    let body = bollard::body_full(bytes::Bytes::from(bytes));
-    let mut stream = docker.import_image(opts, body, None);
+    let mut stream = docker.import_image_stream(opts, body, None);
+    let mut lines = Vec::new();
     while let Some(result) = stream.next().await {
       match result {
         Ok(info) => {
@@ -397,13 +734,16 @@ impl DockerClient {
           }
           let line = line.trim();
           if !line.is_empty() {
-            on_progress(line.to_string());
+            lines.push(line.to_string());
           }
         }
         Err(e) => return Err(anyhow::anyhow!("import_image: {e}")),
       }
     }
-    Ok(())
+    if let Some(e) = read_error.lock().ok().and_then(|mut slot| slot.take()) {
+      return Err(anyhow::anyhow!("reading {}: {e}", src.display()));
+    }
+    Ok(lines)
   }
 
   /// Pull an image from a registry
@@ -420,17 +760,7 @@ impl DockerClient {
   {
     let docker = self.client()?;
 
-    // Parse image name into repository and tag
-    let (repo, tag) = if let Some(pos) = image.rfind(':') {
-      let after_colon = &image[pos + 1..];
-      if after_colon.contains('/') || after_colon.parse::<u16>().is_ok() {
-        (image, "latest")
-      } else {
-        (&image[..pos], after_colon)
-      }
-    } else {
-      (image, "latest")
-    };
+    let (repo, tag) = split_image_ref(image);
 
     let options = CreateImageOptions {
       from_image: Some(repo.to_string()),
@@ -475,10 +805,156 @@ impl DockerClient {
   }
 }
 
+/// Docker already returns history newest-first, but layers from one build
+/// step share a timestamp, so a stable sort keeps their original order.
+fn sort_history_newest_first(entries: &mut [ImageHistoryEntry]) {
+  entries.sort_by_key(|e| std::cmp::Reverse(e.created));
+}
+
+/// Why the daemon refused to remove an image without `force`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageRemoveConflict {
+  pub message: String,
+  /// Forcing gets past the conflict. It doesn't for dependent child
+  /// images, which have to be removed first.
+  pub can_force: bool,
+}
+
+impl ImageRemoveConflict {
+  /// `Some` when `err` is the daemon's 409 reply to an image removal
+  pub fn from_error(err: &anyhow::Error) -> Option<Self> {
+    match err.downcast_ref::<bollard::errors::Error>()? {
+      bollard::errors::Error::DockerResponseServerError {
+        status_code: 409,
+        message,
+      } => Some(Self::from_message(message)),
+      _ => None,
+    }
+  }
+
+  fn from_message(message: &str) -> Self {
+    // The daemon says whether `force` would help: "(must be forced)" or
+    // "(cannot be forced)"
+    let can_force = !message.contains("cannot be forced");
+    if message.contains("dependent child images") {
+      return Self {
+        message: "Other images are built on top of this one. Delete them first.".to_string(),
+        can_force,
+      };
+    }
+    // "... image is being used by stopped container 0123abcd..."
+    if let Some(container) = message
+      .split("used by ")
+      .nth(1)
+      .and_then(|rest| rest.split_whitespace().last())
+    {
+      let short: String = container.chars().take(12).collect();
+      let message = if can_force {
+        format!("Container {short} still uses this image and can't be recreated once it is forced out.")
+      } else {
+        format!("Container {short} is running this image. Stop and remove it first.")
+      };
+      return Self { message, can_force };
+    }
+    if message.contains("referenced in multiple repositories") {
+      return Self {
+        message: "The image has several tags. Forcing removes all of them.".to_string(),
+        can_force,
+      };
+    }
+    Self {
+      message: message.to_string(),
+      can_force,
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_image_remove_conflict_messages() {
+    let children = ImageRemoveConflict::from_message(
+      "conflict: unable to delete 3f8a4339aadd (cannot be forced) - image has dependent child images",
+    );
+    assert!(!children.can_force);
+
+    let in_use = ImageRemoveConflict::from_message(
+      "conflict: unable to delete 3f8a4339aadd (must be forced) - image is being used by stopped container 0123456789abcdef0123",
+    );
+    assert!(in_use.can_force);
+    assert!(in_use.message.starts_with("Container 0123456789ab "));
+
+    let running = ImageRemoveConflict::from_message(
+      "conflict: unable to delete 3f8a4339aadd (cannot be forced) - image is being used by running container 0123456789ab",
+    );
+    assert!(!running.can_force);
+    assert!(running.message.contains("Stop and remove it first"));
+
+    let tags = ImageRemoveConflict::from_message(
+      "conflict: unable to delete 3f8a4339aadd (must be forced) - image is referenced in multiple repositories",
+    );
+    assert!(tags.can_force);
+    assert!(tags.message.contains("several tags"));
+  }
+
+  #[test]
+  fn test_image_is_dangling() {
+    let mut image = ImageInfo {
+      id: "sha256:3f8a4339aadd".to_string(),
+      repo_tags: vec![],
+      repo_digests: vec![],
+      created: None,
+      size: 0,
+      virtual_size: None,
+      labels: HashMap::new(),
+      architecture: None,
+      os: None,
+    };
+    assert!(image.is_dangling());
+    image.repo_tags = vec!["<none>:<none>".to_string()];
+    assert!(image.is_dangling());
+    image.repo_tags = vec!["nginx:latest".to_string()];
+    assert!(!image.is_dangling());
+  }
+
+  fn pull_event(id: &str, status: &str, progress: Option<(i64, i64)>) -> PullProgressEvent {
+    PullProgressEvent {
+      id: id.to_string(),
+      status: status.to_string(),
+      current: progress.map(|(c, _)| c),
+      total: progress.map(|(_, t)| t),
+    }
+  }
+
+  #[test]
+  fn test_pull_progress_layers() {
+    let mut progress = PullProgress::default();
+    assert!(!progress.apply(&pull_event("latest", "Pulling from library/nginx", None)));
+    assert_eq!(progress.stage(), 0);
+
+    assert!(progress.apply(&pull_event("aaa", "Already exists", None)));
+    assert!(progress.apply(&pull_event("bbb", "Pulling fs layer", None)));
+    assert_eq!(progress.layers.len(), 2);
+    assert_eq!(progress.stage(), 1);
+    assert!((progress.fraction() - 0.5).abs() < 0.01);
+
+    progress.apply(&pull_event("bbb", "Downloading", Some((50, 100))));
+    assert!((progress.layers[1].fraction() - 0.4).abs() < 0.01);
+    assert_eq!(progress.layers[1].size, 100);
+
+    progress.apply(&pull_event("bbb", "Download complete", None));
+    assert_eq!(progress.stage(), 2);
+    progress.apply(&pull_event("bbb", "Extracting", Some((50, 100))));
+    assert!((progress.layers[1].fraction() - 0.9).abs() < 0.01);
+
+    progress.apply(&pull_event("bbb", "Pull complete", None));
+    assert!((progress.fraction() - 1.0).abs() < 0.01);
+    assert!(!progress.apply(&pull_event("", "Digest: sha256:abc", None)));
+    assert_eq!(progress.summary(), "2/2 layers (1 cached) · 100 B / 100 B (100%)");
+  }
+
   #[test]
   fn test_image_info_short_id() {
     // With sha256 prefix
@@ -510,6 +986,27 @@ mod tests {
     assert_eq!(short.short_id(), "abc");
   }
 
+  #[test]
+  fn test_image_info_matches_ref() {
+    let image = ImageInfo {
+      id: "sha256:abc123".to_string(),
+      repo_tags: vec!["nginx:latest".to_string(), "ghcr.io/me/app:1.0".to_string()],
+      repo_digests: vec!["nginx@sha256:feed".to_string()],
+      created: None,
+      size: 0,
+      virtual_size: None,
+      labels: HashMap::new(),
+      architecture: None,
+      os: None,
+    };
+    assert!(image.matches_ref("nginx"));
+    assert!(image.matches_ref("docker.io/library/nginx:latest"));
+    assert!(image.matches_ref("ghcr.io/me/app:1.0"));
+    assert!(image.matches_ref("nginx@sha256:feed"));
+    assert!(!image.matches_ref("nginx:1.25"));
+    assert!(!image.matches_ref("ghcr.io/me/app"));
+  }
+
   #[test]
   fn test_image_info_display_name() {
     // With tag
@@ -582,4 +1079,59 @@ mod tests {
     assert_eq!(image.os, Some("linux".to_string()));
     assert_eq!(image.labels.get("maintainer"), Some(&"test@example.com".to_string()));
   }
+
+  #[test]
+  fn test_history_sorted_newest_first_and_large_layers() {
+    let layer = |id: &str, created: i64, size: i64| ImageHistoryEntry {
+      id: id.to_string(),
+      created: DateTime::from_timestamp(created, 0),
+      created_by: String::new(),
+      size,
+      comment: String::new(),
+      tags: vec![],
+    };
+    let mut history = vec![
+      layer("base", 100, 80 * 1024 * 1024),
+      layer("run", 300, LARGE_LAYER_BYTES),
+      layer("env", 200, 0),
+      layer("copy", 300, 1024),
+    ];
+    sort_history_newest_first(&mut history);
+    let ids: Vec<&str> = history.iter().map(|h| h.id.as_str()).collect();
+    assert_eq!(ids, ["run", "copy", "env", "base"]);
+
+    assert!(history[0].is_large());
+    assert!(!history[3].is_large());
+  }
+
+  #[test]
+  fn test_split_image_ref() {
+    assert_eq!(split_image_ref("nginx"), ("nginx", "latest"));
+    assert_eq!(split_image_ref("nginx:1.25"), ("nginx", "1.25"));
+    assert_eq!(split_image_ref("redis:7"), ("redis", "7"));
+    assert_eq!(split_image_ref("ghcr.io/me/app:v2"), ("ghcr.io/me/app", "v2"));
+    assert_eq!(split_image_ref("localhost:5000/app"), ("localhost:5000/app", "latest"));
+    assert_eq!(split_image_ref("localhost:5000/app:dev"), ("localhost:5000/app", "dev"));
+  }
+
+  #[test]
+  fn test_registry_host() {
+    assert_eq!(registry_host("nginx"), "docker.io");
+    assert_eq!(registry_host("me/app"), "docker.io");
+    assert_eq!(registry_host("ghcr.io/me/app"), "ghcr.io");
+    assert_eq!(registry_host("localhost:5000/app"), "localhost:5000");
+    assert_eq!(registry_host("localhost/app"), "localhost");
+  }
+
+  #[test]
+  fn test_registry_auth_debug_redacts_password() {
+    let auth = RegistryAuth {
+      registry: "ghcr.io".to_string(),
+      username: "me".to_string(),
+      password: "hunter2".to_string(),
+    };
+    let debug = format!("{auth:?}");
+    assert!(debug.contains("ghcr.io"));
+    assert!(!debug.contains("hunter2"));
+  }
 }
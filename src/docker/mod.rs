@@ -1,6 +1,7 @@
 mod client;
 mod compose;
 mod containers;
+mod contexts;
 mod images;
 mod networks;
 mod prune;
@@ -12,6 +13,7 @@ mod volumes;
 pub use client::*;
 pub use compose::*;
 pub use containers::*;
+pub use contexts::*;
 pub use images::*;
 pub use networks::*;
 pub use prune::*;
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use bollard::query_parameters::ListNetworksOptions;
+use bollard::query_parameters::{InspectNetworkOptions, ListNetworksOptions};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -57,6 +57,73 @@ impl NetworkInfo {
   }
 }
 
+/// Readable message for a failed `docker network connect`. The daemon
+/// reports a duplicate attach as an endpoint conflict, which doesn't say
+/// what happened.
+pub fn describe_network_connect_error(error: &str, container: &str, network: &str) -> String {
+  let lower = error.to_lowercase();
+  if lower.contains("already exists in network") || lower.contains("already attached") {
+    format!("{container} is already connected to {network}")
+  } else {
+    format!("Failed to connect {container} to {network}: {error}")
+  }
+}
+
+/// Convert a bollard network (list or inspect) into `NetworkInfo`.
+fn network_info(network: bollard::models::Network) -> NetworkInfo {
+  let created = network
+    .created
+    .as_ref()
+    .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+    .map(|dt| dt.with_timezone(&Utc));
+
+  let ipam = network.ipam.map(|ipam| IpamInfo {
+    driver: ipam.driver,
+    config: ipam
+      .config
+      .unwrap_or_default()
+      .into_iter()
+      .map(|c| IpamConfig {
+        subnet: c.subnet,
+        gateway: c.gateway,
+        ip_range: c.ip_range,
+      })
+      .collect(),
+  });
+
+  let containers = network
+    .containers
+    .unwrap_or_default()
+    .into_iter()
+    .map(|(id, container)| {
+      (
+        id,
+        NetworkContainer {
+          name: container.name,
+          endpoint_id: container.endpoint_id,
+          mac_address: container.mac_address,
+          ipv4_address: container.ipv4_address,
+          ipv6_address: container.ipv6_address,
+        },
+      )
+    })
+    .collect();
+
+  NetworkInfo {
+    id: network.id.unwrap_or_default(),
+    name: network.name.unwrap_or_default(),
+    driver: network.driver.unwrap_or_default(),
+    scope: network.scope.unwrap_or_default(),
+    internal: network.internal.unwrap_or(false),
+    enable_ipv6: network.enable_ipv6.unwrap_or(false),
+    created,
+    labels: network.labels.unwrap_or_default(),
+    options: network.options.unwrap_or_default(),
+    ipam,
+    containers,
+  }
+}
+
 impl DockerClient {
   pub async fn list_networks(&self) -> Result<Vec<NetworkInfo>> {
     let docker = self.client()?;
@@ -65,65 +132,19 @@ impl DockerClient {
 
     let networks = docker.list_networks(Some(options)).await?;
 
-    let mut result = Vec::new();
-    for network in networks {
-      let created = network
-        .created
-        .as_ref()
-        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
-        .map(|dt| dt.with_timezone(&Utc));
-
-      let ipam = network.ipam.map(|ipam| IpamInfo {
-        driver: ipam.driver,
-        config: ipam
-          .config
-          .unwrap_or_default()
-          .into_iter()
-          .map(|c| IpamConfig {
-            subnet: c.subnet,
-            gateway: c.gateway,
-            ip_range: c.ip_range,
-          })
-          .collect(),
-      });
-
-      let containers = network
-        .containers
-        .unwrap_or_default()
-        .into_iter()
-        .map(|(id, container)| {
-          (
-            id,
-            NetworkContainer {
-              name: container.name,
-              endpoint_id: container.endpoint_id,
-              mac_address: container.mac_address,
-              ipv4_address: container.ipv4_address,
-              ipv6_address: container.ipv6_address,
-            },
-          )
-        })
-        .collect();
-
-      result.push(NetworkInfo {
-        id: network.id.unwrap_or_default(),
-        name: network.name.unwrap_or_default(),
-        driver: network.driver.unwrap_or_default(),
-        scope: network.scope.unwrap_or_default(),
-        internal: network.internal.unwrap_or(false),
-        enable_ipv6: network.enable_ipv6.unwrap_or(false),
-        created,
-        labels: network.labels.unwrap_or_default(),
-        options: network.options.unwrap_or_default(),
-        ipam,
-        containers,
-      });
-    }
-
+    let mut result: Vec<NetworkInfo> = networks.into_iter().map(network_info).collect();
     result.sort_by(|a, b| a.name.cmp(&b.name));
     Ok(result)
   }
 
+  /// Full network details. Unlike the list endpoint, inspect includes the
+  /// attached containers with their addresses.
+  pub async fn inspect_network(&self, id: &str) -> Result<NetworkInfo> {
+    let docker = self.client()?;
+    let network = docker.inspect_network(id, None::<InspectNetworkOptions>).await?;
+    Ok(network_info(network))
+  }
+
   pub async fn remove_network(&self, id: &str) -> Result<()> {
     let docker = self.client()?;
     docker.remove_network(id).await?;
@@ -344,4 +365,50 @@ mod tests {
     assert_eq!(container.ipv4_address, Some("172.17.0.2/16".to_string()));
     assert_eq!(container.ipv6_address, Some("fd00::2/64".to_string()));
   }
+
+  #[test]
+  fn test_network_info_from_inspect() {
+    let network = bollard::models::Network {
+      id: Some("abc123".to_string()),
+      name: Some("shop_default".to_string()),
+      driver: Some("bridge".to_string()),
+      created: Some("2024-05-01T10:00:00Z".to_string()),
+      ipam: Some(bollard::models::Ipam {
+        driver: Some("default".to_string()),
+        config: Some(vec![bollard::models::IpamConfig {
+          subnet: Some("172.20.0.0/16".to_string()),
+          gateway: Some("172.20.0.1".to_string()),
+          ..Default::default()
+        }]),
+        options: None,
+      }),
+      containers: Some(HashMap::from([(
+        "c1".to_string(),
+        bollard::models::NetworkContainer {
+          name: Some("web".to_string()),
+          ipv4_address: Some("172.20.0.2/16".to_string()),
+          ..Default::default()
+        },
+      )])),
+      ..Default::default()
+    };
+    let info = network_info(network);
+    assert_eq!(info.name, "shop_default");
+    assert!(info.created.is_some());
+    assert_eq!(info.ipam.unwrap().config[0].gateway.as_deref(), Some("172.20.0.1"));
+    assert_eq!(info.containers["c1"].ipv4_address.as_deref(), Some("172.20.0.2/16"));
+  }
+
+  #[test]
+  fn test_describe_network_connect_error() {
+    let conflict = "Docker responded with status code 403: endpoint with name web already exists in network shop";
+    assert_eq!(
+      describe_network_connect_error(conflict, "web", "shop"),
+      "web is already connected to shop"
+    );
+    assert_eq!(
+      describe_network_connect_error("network nope not found", "web", "nope"),
+      "Failed to connect web to nope: network nope not found"
+    );
+  }
 }
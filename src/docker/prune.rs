@@ -1,5 +1,8 @@
 use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+
 use bollard::query_parameters::{
+  DataUsageOptions, ListContainersOptions, ListImagesOptions, ListNetworksOptions, ListVolumesOptions,
   PruneBuildOptionsBuilder, PruneContainersOptions, PruneImagesOptions, PruneNetworksOptions, PruneVolumesOptions,
 };
 use serde::{Deserialize, Serialize};
@@ -43,7 +46,199 @@ impl PruneResult {
   }
 }
 
+/// A resource a prune would remove. `size` is `None` when the daemon
+/// doesn't report one (networks, volumes without usage data).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneCandidate {
+  pub name: String,
+  pub size: Option<u64>,
+}
+
+/// What a prune with the current options would remove (dry run)
+#[derive(Debug, Clone, Default)]
+pub struct PrunePreview {
+  pub containers: Vec<PruneCandidate>,
+  pub images: Vec<PruneCandidate>,
+  pub volumes: Vec<PruneCandidate>,
+  pub networks: Vec<PruneCandidate>,
+  pub build_cache: Vec<PruneCandidate>,
+  // Kubernetes (`namespace/name`)
+  pub pods: Vec<String>,
+  pub deployments: Vec<String>,
+  pub services: Vec<String>,
+}
+
+impl PrunePreview {
+  /// Upper bound on the space the prune frees. Image sizes include layers
+  /// shared with images that stay, so the real figure can be lower.
+  pub fn reclaimable(&self) -> u64 {
+    [&self.containers, &self.images, &self.volumes, &self.build_cache]
+      .into_iter()
+      .flatten()
+      .filter_map(|c| c.size)
+      .sum()
+  }
+
+  pub fn display_reclaimable(&self) -> String {
+    bytesize::ByteSize(self.reclaimable()).to_string()
+  }
+
+  pub fn total_items(&self) -> usize {
+    self.containers.len()
+      + self.images.len()
+      + self.volumes.len()
+      + self.networks.len()
+      + self.build_cache.len()
+      + self.pods.len()
+      + self.deployments.len()
+      + self.services.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.total_items() == 0
+  }
+}
+
+fn filters(pairs: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+  pairs
+    .iter()
+    .map(|(k, vs)| ((*k).to_string(), vs.iter().map(|v| (*v).to_string()).collect()))
+    .collect()
+}
+
+fn size_of(bytes: i64) -> Option<u64> {
+  u64::try_from(bytes).ok()
+}
+
 impl DockerClient {
+  /// Containers `prune_containers` would remove: every stopped one.
+  pub async fn prune_candidates_containers(&self) -> Result<Vec<PruneCandidate>> {
+    let docker = self.client()?;
+    let options = ListContainersOptions {
+      all: true,
+      size: true,
+      filters: Some(filters(&[("status", &["created", "exited", "dead"])])),
+      ..Default::default()
+    };
+    let containers = docker.list_containers(Some(options)).await?;
+    Ok(
+      containers
+        .into_iter()
+        .map(|c| PruneCandidate {
+          name: c
+            .names
+            .and_then(|n| n.first().map(|n| n.trim_start_matches('/').to_string()))
+            .or(c.id)
+            .unwrap_or_default(),
+          size: c.size_rw.and_then(size_of),
+        })
+        .collect(),
+    )
+  }
+
+  /// Images `prune_images` would remove: untagged ones when
+  /// `dangling_only`, otherwise every image no container references.
+  pub async fn prune_candidates_images(&self, dangling_only: bool) -> Result<Vec<PruneCandidate>> {
+    let docker = self.client()?;
+    let in_use: HashSet<String> = docker
+      .list_containers(Some(ListContainersOptions {
+        all: true,
+        ..Default::default()
+      }))
+      .await?
+      .into_iter()
+      .filter_map(|c| c.image_id)
+      .collect();
+    let options = ListImagesOptions {
+      filters: dangling_only.then(|| filters(&[("dangling", &["true"])])),
+      ..Default::default()
+    };
+    let images = docker.list_images(Some(options)).await?;
+    Ok(
+      images
+        .into_iter()
+        .filter(|i| !in_use.contains(&i.id))
+        .map(|i| PruneCandidate {
+          name: i.repo_tags.first().cloned().unwrap_or_else(|| {
+            let id = i.id.strip_prefix("sha256:").unwrap_or(&i.id);
+            id[..id.len().min(12)].to_string()
+          }),
+          size: size_of(i.size),
+        })
+        .collect(),
+    )
+  }
+
+  /// Volumes `prune_volumes` would remove: unused anonymous volumes (the
+  /// daemon's default since API 1.42; named volumes need `--all`).
+  pub async fn prune_candidates_volumes(&self) -> Result<Vec<PruneCandidate>> {
+    let docker = self.client()?;
+    let options = ListVolumesOptions {
+      filters: Some(filters(&[("dangling", &["true"])])),
+    };
+    let volumes = docker.list_volumes(Some(options)).await?.volumes.unwrap_or_default();
+    // The list endpoint doesn't report sizes; `system df` does.
+    let sizes: HashMap<String, i64> = docker
+      .df(None::<DataUsageOptions>)
+      .await
+      .ok()
+      .and_then(|df| df.volumes)
+      .unwrap_or_default()
+      .into_iter()
+      .filter_map(|v| v.usage_data.map(|u| (v.name, u.size)))
+      .collect();
+    Ok(
+      volumes
+        .into_iter()
+        .filter(|v| v.labels.contains_key("com.docker.volume.anonymous"))
+        .map(|v| PruneCandidate {
+          size: sizes.get(&v.name).copied().and_then(size_of),
+          name: v.name,
+        })
+        .collect(),
+    )
+  }
+
+  /// Networks `prune_networks` would remove: custom networks with no
+  /// containers attached.
+  pub async fn prune_candidates_networks(&self) -> Result<Vec<PruneCandidate>> {
+    let docker = self.client()?;
+    let options = ListNetworksOptions {
+      filters: Some(filters(&[("dangling", &["true"])])),
+    };
+    let networks = docker.list_networks(Some(options)).await?;
+    Ok(
+      networks
+        .into_iter()
+        .filter_map(|n| n.name)
+        .filter(|name| !matches!(name.as_str(), "bridge" | "host" | "none"))
+        .map(|name| PruneCandidate { name, size: None })
+        .collect(),
+    )
+  }
+
+  /// Build cache entries `prune_build_cache` would remove. There's no
+  /// filtered list endpoint, so this reads `system df`: unused entries, and
+  /// without `all` only those not shared with other records.
+  pub async fn prune_candidates_build_cache(&self, all: bool) -> Result<Vec<PruneCandidate>> {
+    let docker = self.client()?;
+    let cache = docker
+      .df(None::<DataUsageOptions>)
+      .await?
+      .build_cache
+      .unwrap_or_default();
+    Ok(
+      cache
+        .into_iter()
+        .filter(|e| !e.in_use.unwrap_or(false) && (all || !e.shared.unwrap_or(false)))
+        .map(|e| PruneCandidate {
+          name: e.description.filter(|d| !d.is_empty()).or(e.id).unwrap_or_default(),
+          size: e.size.and_then(size_of),
+        })
+        .collect(),
+    )
+  }
+
   /// Prune stopped containers
   pub async fn prune_containers(&self) -> Result<PruneResult> {
     let docker = self.client()?;
@@ -137,6 +332,25 @@ impl DockerClient {
 mod tests {
   use super::*;
 
+  #[test]
+  fn test_prune_preview_reclaimable() {
+    let candidate = |name: &str, size: Option<u64>| PruneCandidate {
+      name: name.to_string(),
+      size,
+    };
+    let preview = PrunePreview {
+      containers: vec![candidate("web", Some(100))],
+      images: vec![candidate("nginx:old", Some(1_000))],
+      networks: vec![candidate("stale-net", None)],
+      pods: vec!["default/done-job".to_string()],
+      ..Default::default()
+    };
+    assert_eq!(preview.reclaimable(), 1_100);
+    assert_eq!(preview.total_items(), 4);
+    assert!(!preview.is_empty());
+    assert!(PrunePreview::default().is_empty());
+  }
+
   #[test]
   fn test_prune_result_default() {
     let result = PruneResult::default();
@@ -1,8 +1,9 @@
-//! Image vulnerability scanning via Trivy + Dockerfile linting via Hadolint.
+//! Image vulnerability scanning via Docker Scout or Trivy + Dockerfile
+//! linting via Hadolint.
 //!
-//! Wraps the user's local `trivy` and `hadolint` binaries and parses their
-//! JSON output. We deliberately don't ship either — if they aren't installed
-//! the UI shows a platform-aware install hint.
+//! Wraps the user's local `docker scout` plugin, `trivy` and `hadolint`
+//! binaries and parses their JSON output. We deliberately don't ship any of
+//! them — if they aren't installed the UI shows a platform-aware install hint.
 
 use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
@@ -17,6 +18,9 @@ pub struct ScanSummary {
   pub low: usize,
   pub unknown: usize,
   pub vulns: Vec<Vulnerability>,
+  /// Which scanner produced the report ("Docker Scout" or "Trivy")
+  #[serde(default)]
+  pub scanner: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +87,24 @@ pub fn trivy_install_hint() -> InstallHint {
   }
 }
 
+/// Install hint for the `docker scout` CLI plugin. Docker Desktop bundles
+/// it; elsewhere the upstream install script drops it into the plugin dir.
+pub fn scout_install_hint() -> InstallHint {
+  #[cfg(any(target_os = "macos", target_os = "windows"))]
+  let commands = vec![];
+  #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+  let commands = vec![
+    "curl -fsSL https://raw.githubusercontent.com/docker/scout-cli/main/install.sh -o install-scout.sh",
+    "sh install-scout.sh",
+  ];
+
+  InstallHint {
+    headline: "Docker Scout plugin not installed (bundled with Docker Desktop)",
+    commands,
+    docs_url: SCOUT_INSTALL_DOCS,
+  }
+}
+
 /// True when `trivy` is on PATH.
 pub fn trivy_available() -> bool {
   Command::new("trivy")
@@ -91,42 +113,179 @@ pub fn trivy_available() -> bool {
     .is_ok_and(|o| o.status.success())
 }
 
+/// True when the `docker scout` CLI plugin is installed.
+pub fn scout_available() -> bool {
+  crate::utils::docker_cmd()
+    .args(["scout", "version"])
+    .output()
+    .is_ok_and(|o| o.status.success())
+}
+
 /// Sentinel error string the UI matches on to render the structured
-/// install-hint widget instead of a raw error blob.
-pub const ERR_TRIVY_NOT_INSTALLED: &str = "TRIVY_NOT_INSTALLED";
+/// install-hint widget instead of a raw error blob. Returned when neither
+/// Docker Scout nor Trivy is available.
+pub const ERR_SCANNER_NOT_INSTALLED: &str = "SCANNER_NOT_INSTALLED";
+
+/// Docs for the `docker scout` CLI plugin, shown next to the Trivy hint.
+pub const SCOUT_INSTALL_DOCS: &str = "https://docs.docker.com/scout/install/";
+
+/// Which vulnerability scanner produced a `ScanSummary`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scanner {
+  Scout,
+  Trivy,
+}
+
+impl Scanner {
+  /// First available scanner, preferring Docker Scout since it ships with
+  /// Docker Desktop.
+  pub fn detect() -> Option<Self> {
+    if scout_available() {
+      Some(Self::Scout)
+    } else if trivy_available() {
+      Some(Self::Trivy)
+    } else {
+      None
+    }
+  }
+
+  pub fn label(self) -> &'static str {
+    match self {
+      Self::Scout => "Docker Scout",
+      Self::Trivy => "Trivy",
+    }
+  }
+
+  /// Command-line arguments (after the program name) used to scan `image_ref`.
+  pub fn args(self, image_ref: &str) -> Vec<String> {
+    let args: &[&str] = match self {
+      Self::Scout => &["scout", "cves", "--format", "gitlab"],
+      Self::Trivy => &["image", "--format", "json", "--quiet", "--scanners", "vuln"],
+    };
+    args
+      .iter()
+      .map(ToString::to_string)
+      .chain(std::iter::once(image_ref.to_string()))
+      .collect()
+  }
+
+  /// The full command as a user would type it, for the command log.
+  pub fn command_line(self, image_ref: &str) -> String {
+    let program = match self {
+      Self::Scout => "docker",
+      Self::Trivy => "trivy",
+    };
+    format!("{program} {}", self.args(image_ref).join(" "))
+  }
+
+  /// Run this scanner against `image_ref` and parse its report.
+  pub fn scan(self, image_ref: &str) -> Result<ScanSummary> {
+    let mut cmd = match self {
+      Self::Scout => crate::utils::docker_cmd(),
+      Self::Trivy => Command::new("trivy"),
+    };
+    let output = cmd
+      .args(self.args(image_ref))
+      .output()
+      .map_err(|e| anyhow!("Failed to invoke {}: {e}", self.label()))?;
+
+    if !output.status.success() {
+      let stderr = String::from_utf8_lossy(&output.stderr);
+      return Err(anyhow!("{} exited non-zero: {}", self.label(), stderr.trim()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match self {
+      Self::Scout => parse_scout_gitlab_json(&stdout),
+      Self::Trivy => parse_trivy_json(&stdout),
+    }
+  }
+}
 
-/// Run `trivy image --format json --quiet <id>` and parse the result.
+/// Scan `image_ref` with Docker Scout, falling back to Trivy.
 pub fn scan_image(image_ref: &str) -> Result<ScanSummary> {
-  if !trivy_available() {
-    return Err(anyhow!(ERR_TRIVY_NOT_INSTALLED));
+  Scanner::detect()
+    .ok_or_else(|| anyhow!(ERR_SCANNER_NOT_INSTALLED))?
+    .scan(image_ref)
+}
+
+impl ScanSummary {
+  fn push(&mut self, vuln: Vulnerability) {
+    match vuln.severity.as_str() {
+      "CRITICAL" => self.critical += 1,
+      "HIGH" => self.high += 1,
+      "MEDIUM" => self.medium += 1,
+      "LOW" => self.low += 1,
+      _ => self.unknown += 1,
+    }
+    self.vulns.push(vuln);
   }
-  let output = Command::new("trivy")
-    .args(["image", "--format", "json", "--quiet", "--scanners", "vuln", image_ref])
-    .output()
-    .map_err(|e| anyhow!("Failed to invoke trivy: {e}"))?;
 
-  if !output.status.success() {
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    return Err(anyhow!("trivy exited non-zero: {stderr}"));
+  /// Sort by severity (critical first) then CVE id for stable display.
+  fn sort(&mut self) {
+    self.vulns.sort_by(|a, b| {
+      severity_weight(&a.severity)
+        .cmp(&severity_weight(&b.severity))
+        .then_with(|| a.id.cmp(&b.id))
+    });
   }
 
-  let stdout = String::from_utf8_lossy(&output.stdout);
-  let report: TrivyReport = serde_json::from_str(&stdout).map_err(|e| anyhow!("Failed to parse trivy output: {e}"))?;
+  /// Vulnerabilities grouped by severity, most severe first. Each group
+  /// lists its affected packages once, in first-seen order.
+  pub fn by_severity(&self) -> Vec<SeverityGroup<'_>> {
+    let mut groups: Vec<SeverityGroup<'_>> = Vec::new();
+    for v in &self.vulns {
+      let idx = if let Some(idx) = groups.iter().position(|g| g.severity == v.severity) {
+        idx
+      } else {
+        groups.push(SeverityGroup {
+          severity: v.severity.as_str(),
+          vulns: Vec::new(),
+          packages: Vec::new(),
+        });
+        groups.len() - 1
+      };
+      let group = &mut groups[idx];
+      group.vulns.push(v);
+      if !group.packages.contains(&v.package.as_str()) {
+        group.packages.push(v.package.as_str());
+      }
+    }
+    groups.sort_by_key(|g| severity_weight(g.severity));
+    groups
+  }
+}
+
+/// One severity bucket of a `ScanSummary`
+pub struct SeverityGroup<'a> {
+  pub severity: &'a str,
+  pub vulns: Vec<&'a Vulnerability>,
+  pub packages: Vec<&'a str>,
+}
+
+fn severity_weight(severity: &str) -> u8 {
+  match severity {
+    "CRITICAL" => 0,
+    "HIGH" => 1,
+    "MEDIUM" => 2,
+    "LOW" => 3,
+    _ => 4,
+  }
+}
+
+/// Parse `trivy image --format json` output.
+pub(crate) fn parse_trivy_json(stdout: &str) -> Result<ScanSummary> {
+  let report: TrivyReport = serde_json::from_str(stdout).map_err(|e| anyhow!("Failed to parse trivy output: {e}"))?;
 
-  let mut summary = ScanSummary::default();
+  let mut summary = ScanSummary {
+    scanner: Scanner::Trivy.label().to_string(),
+    ..ScanSummary::default()
+  };
   for result in report.results.unwrap_or_default() {
     for v in result.vulnerabilities.unwrap_or_default() {
-      let severity = v.severity.unwrap_or_else(|| "UNKNOWN".to_string());
-      match severity.as_str() {
-        "CRITICAL" => summary.critical += 1,
-        "HIGH" => summary.high += 1,
-        "MEDIUM" => summary.medium += 1,
-        "LOW" => summary.low += 1,
-        _ => summary.unknown += 1,
-      }
-      summary.vulns.push(Vulnerability {
+      summary.push(Vulnerability {
         id: v.vulnerability_id.unwrap_or_default(),
-        severity,
+        severity: v.severity.unwrap_or_else(|| "UNKNOWN".to_string()),
         package: v.pkg_name.unwrap_or_default(),
         installed_version: v.installed_version.unwrap_or_default(),
         fixed_version: v.fixed_version,
@@ -135,24 +294,103 @@ pub fn scan_image(image_ref: &str) -> Result<ScanSummary> {
       });
     }
   }
+  summary.sort();
+  Ok(summary)
+}
 
-  // Sort by severity (critical first) then CVE id for stable display.
-  summary.vulns.sort_by(|a, b| {
-    let weight = |s: &str| match s {
-      "CRITICAL" => 0,
-      "HIGH" => 1,
-      "MEDIUM" => 2,
-      "LOW" => 3,
-      _ => 4,
-    };
-    weight(a.severity.as_str())
-      .cmp(&weight(b.severity.as_str()))
-      .then_with(|| a.id.cmp(&b.id))
-  });
+/// Parse `docker scout cves --format gitlab` output (GitLab container
+/// scanning report schema).
+pub(crate) fn parse_scout_gitlab_json(stdout: &str) -> Result<ScanSummary> {
+  let report: ScoutReport =
+    serde_json::from_str(stdout).map_err(|e| anyhow!("Failed to parse docker scout output: {e}"))?;
 
+  let mut summary = ScanSummary {
+    scanner: Scanner::Scout.label().to_string(),
+    ..ScanSummary::default()
+  };
+  for v in report.vulnerabilities {
+    let severity = match v.severity.as_deref().map(str::to_uppercase) {
+      Some(s) if matches!(s.as_str(), "CRITICAL" | "HIGH" | "MEDIUM" | "LOW") => s,
+      _ => "UNKNOWN".to_string(),
+    };
+    let cve = v
+      .identifiers
+      .iter()
+      .find(|i| i.kind.eq_ignore_ascii_case("cve"))
+      .or_else(|| v.identifiers.first());
+    let dependency = v.location.and_then(|l| l.dependency).unwrap_or_default();
+    // Scout phrases fixes as "Upgrade <pkg> to <version>".
+    let fixed_version = v
+      .solution
+      .as_deref()
+      .and_then(|s| s.rsplit_once(" to "))
+      .map(|(_, version)| version.trim().to_string())
+      .filter(|version| !version.is_empty());
+    summary.push(Vulnerability {
+      id: cve.map(|i| i.name.clone()).or(v.id).unwrap_or_default(),
+      severity,
+      package: dependency.package.map(|p| p.name).unwrap_or_default(),
+      installed_version: dependency.version.unwrap_or_default(),
+      fixed_version,
+      title: v.name.or(v.description).unwrap_or_default(),
+      primary_url: cve
+        .and_then(|i| i.url.clone())
+        .or_else(|| v.links.into_iter().next().map(|l| l.url)),
+    });
+  }
+  summary.sort();
   Ok(summary)
 }
 
+#[derive(Deserialize, Default)]
+struct ScoutReport {
+  #[serde(default)]
+  vulnerabilities: Vec<ScoutVuln>,
+}
+
+#[derive(Deserialize, Default)]
+struct ScoutVuln {
+  id: Option<String>,
+  name: Option<String>,
+  description: Option<String>,
+  severity: Option<String>,
+  solution: Option<String>,
+  location: Option<ScoutLocation>,
+  #[serde(default)]
+  identifiers: Vec<ScoutIdentifier>,
+  #[serde(default)]
+  links: Vec<ScoutLink>,
+}
+
+#[derive(Deserialize, Default)]
+struct ScoutLocation {
+  dependency: Option<ScoutDependency>,
+}
+
+#[derive(Deserialize, Default)]
+struct ScoutDependency {
+  package: Option<ScoutPackage>,
+  version: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct ScoutPackage {
+  name: String,
+}
+
+#[derive(Deserialize, Default)]
+struct ScoutIdentifier {
+  #[serde(rename = "type", default)]
+  kind: String,
+  name: String,
+  url: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+struct ScoutLink {
+  url: String,
+}
+
 #[derive(Deserialize, Default)]
 #[serde(rename_all = "PascalCase")]
 struct TrivyReport {
@@ -354,6 +592,56 @@ mod tests {
   fn parse_invalid_json_errors() {
     assert!(parse_hadolint_json("not json").is_err());
   }
+
+  #[test]
+  fn parse_trivy_counts_and_sorts() {
+    let json = r#"{"SchemaVersion":2,"Results":[{"Vulnerabilities":[
+      {"VulnerabilityID":"CVE-2","PkgName":"zlib","InstalledVersion":"1.2","Severity":"LOW"},
+      {"VulnerabilityID":"CVE-1","PkgName":"openssl","InstalledVersion":"3.0","FixedVersion":"3.1","Severity":"CRITICAL"}
+    ]}]}"#;
+    let s = parse_trivy_json(json).unwrap();
+    assert_eq!((s.critical, s.low), (1, 1));
+    assert_eq!(s.vulns[0].id, "CVE-1");
+    assert_eq!(s.scanner, "Trivy");
+  }
+
+  #[test]
+  fn parse_scout_gitlab_report() {
+    let json = r#"{"version":"15.0.6","vulnerabilities":[
+      {"id":"x1","name":"Out-of-bounds write","severity":"High","solution":"Upgrade openssl to 3.1.4",
+       "location":{"dependency":{"package":{"name":"openssl"},"version":"3.0.2"}},
+       "identifiers":[{"type":"cve","name":"CVE-2023-0001","url":"https://nvd.example/CVE-2023-0001"}]},
+      {"id":"x2","description":"Something odd","severity":"Negligible",
+       "location":{"dependency":{"package":{"name":"bash"},"version":"5.1"}},
+       "identifiers":[],"links":[{"url":"https://example/x2"}]}
+    ]}"#;
+    let s = parse_scout_gitlab_json(json).unwrap();
+    assert_eq!((s.high, s.unknown), (1, 1));
+    let v = &s.vulns[0];
+    assert_eq!(v.id, "CVE-2023-0001");
+    assert_eq!(v.package, "openssl");
+    assert_eq!(v.installed_version, "3.0.2");
+    assert_eq!(v.fixed_version.as_deref(), Some("3.1.4"));
+    assert_eq!(s.vulns[1].id, "x2");
+    assert_eq!(s.vulns[1].primary_url.as_deref(), Some("https://example/x2"));
+    assert_eq!(s.scanner, "Docker Scout");
+  }
+
+  #[test]
+  fn by_severity_groups_packages() {
+    let json = r#"{"Results":[{"Vulnerabilities":[
+      {"VulnerabilityID":"CVE-3","PkgName":"zlib","Severity":"HIGH"},
+      {"VulnerabilityID":"CVE-1","PkgName":"openssl","Severity":"CRITICAL"},
+      {"VulnerabilityID":"CVE-2","PkgName":"openssl","Severity":"CRITICAL"}
+    ]}]}"#;
+    let s = parse_trivy_json(json).unwrap();
+    let groups = s.by_severity();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].severity, "CRITICAL");
+    assert_eq!(groups[0].vulns.len(), 2);
+    assert_eq!(groups[0].packages, vec!["openssl"]);
+    assert_eq!(groups[1].packages, vec!["zlib"]);
+  }
 }
 
 #[derive(Deserialize, Default)]
@@ -1,12 +1,16 @@
 // Allow precision loss for display formatting of byte sizes and percentages
 #![allow(clippy::cast_precision_loss)]
 
+use std::time::{Duration, Instant};
+
 use anyhow::Result;
+use bollard::Docker;
+use bollard::models::{ContainerBlkioStatEntry, ContainerStatsResponse};
 use bollard::query_parameters::StatsOptions;
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 
-use super::{ContainerState, DockerClient};
+use super::DockerClient;
 
 /// Container resource statistics
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -17,10 +21,36 @@ pub struct ContainerStats {
   pub memory_usage: u64,
   pub memory_limit: u64,
   pub memory_percent: f64,
+  /// Bytes received / sent on all interfaces since the container started
   pub network_rx: u64,
   pub network_tx: u64,
+  /// Receive / send throughput in bytes per second since the previous
+  /// sample; zero until a second sample arrives
+  pub network_rx_rate: u64,
+  pub network_tx_rate: u64,
+  /// Traffic per interface, sorted by name
+  pub networks: Vec<InterfaceTraffic>,
+  /// Bytes read from / written to block devices since the container
+  /// started
   pub block_read: u64,
   pub block_write: u64,
+  /// Block read / write throughput in bytes per second since the
+  /// previous sample
+  pub block_read_rate: u64,
+  pub block_write_rate: u64,
+  /// Whether the daemon reported block I/O at all. Some cgroup v2
+  /// setups leave `io_service_bytes_recursive` empty.
+  pub block_io_available: bool,
+}
+
+/// One network interface's counters and throughput
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct InterfaceTraffic {
+  pub name: String,
+  pub rx_bytes: u64,
+  pub tx_bytes: u64,
+  pub rx_rate: u64,
+  pub tx_rate: u64,
 }
 
 impl ContainerStats {
@@ -28,12 +58,61 @@ impl ContainerStats {
     format_bytes(self.memory_usage)
   }
 
-  pub fn display_network_rx(&self) -> String {
-    format!("{}/s", format_bytes(self.network_rx))
+  /// Combined receive + send throughput
+  pub fn display_network_rate(&self) -> String {
+    format!("{}/s", format_bytes(self.network_rx_rate + self.network_tx_rate))
   }
 
-  pub fn display_block_read(&self) -> String {
-    format!("{}/s", format_bytes(self.block_read))
+  /// One `eth0: rx 1.2 MB/s, tx 40 KB/s` line per interface
+  pub fn network_breakdown(&self) -> String {
+    self
+      .networks
+      .iter()
+      .map(|n| {
+        format!(
+          "{}: rx {}/s, tx {}/s",
+          n.name,
+          format_bytes(n.rx_rate),
+          format_bytes(n.tx_rate)
+        )
+      })
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+
+  /// Combined block read + write throughput, or "n/a" when the daemon
+  /// doesn't report block I/O
+  pub fn display_block_rate(&self) -> String {
+    if self.block_io_available {
+      format!("{}/s", format_bytes(self.block_read_rate + self.block_write_rate))
+    } else {
+      "n/a".to_string()
+    }
+  }
+
+  /// Fill in the network and block I/O rates by diffing against `prev`,
+  /// the same container's sample taken `elapsed` earlier. Counters that
+  /// went backwards (the container restarted) count as no traffic.
+  #[must_use]
+  pub fn with_rates(mut self, prev: &ContainerStats, elapsed: Duration) -> Self {
+    let millis = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+    if millis == 0 {
+      return self;
+    }
+    let rate = |now: u64, before: u64| now.saturating_sub(before).saturating_mul(1000) / millis;
+    for net in &mut self.networks {
+      if let Some(before) = prev.networks.iter().find(|p| p.name == net.name) {
+        net.rx_rate = rate(net.rx_bytes, before.rx_bytes);
+        net.tx_rate = rate(net.tx_bytes, before.tx_bytes);
+      }
+    }
+    self.network_rx_rate = self.networks.iter().map(|n| n.rx_rate).sum();
+    self.network_tx_rate = self.networks.iter().map(|n| n.tx_rate).sum();
+    if self.block_io_available && prev.block_io_available {
+      self.block_read_rate = rate(self.block_read, prev.block_read);
+      self.block_write_rate = rate(self.block_write, prev.block_write);
+    }
+    self
   }
 }
 
@@ -58,14 +137,36 @@ fn format_bytes(bytes: u64) -> String {
 pub struct AggregateStats {
   pub total_cpu_percent: f64,
   pub total_memory: u64,
+  /// Summed network throughput in bytes per second
   pub total_network_rx: u64,
   pub total_network_tx: u64,
+  /// Summed block I/O throughput in bytes per second
   pub total_block_read: u64,
   pub total_block_write: u64,
   pub container_stats: Vec<ContainerStats>,
 }
 
 impl AggregateStats {
+  /// Sum per-container samples, heaviest CPU user first
+  pub fn from_samples(mut samples: Vec<ContainerStats>) -> Self {
+    samples.sort_by(|a, b| {
+      b.cpu_percent
+        .total_cmp(&a.cpu_percent)
+        .then_with(|| a.name.cmp(&b.name))
+    });
+    let mut aggregate = Self::default();
+    for stats in &samples {
+      aggregate.total_cpu_percent += stats.cpu_percent;
+      aggregate.total_memory += stats.memory_usage;
+      aggregate.total_network_rx += stats.network_rx_rate;
+      aggregate.total_network_tx += stats.network_tx_rate;
+      aggregate.total_block_read += stats.block_read_rate;
+      aggregate.total_block_write += stats.block_write_rate;
+    }
+    aggregate.container_stats = samples;
+    aggregate
+  }
+
   pub fn display_total_memory(&self) -> String {
     format_bytes(self.total_memory)
   }
@@ -92,123 +193,230 @@ impl DockerClient {
     let mut stream = docker.stats(container_id, Some(options));
 
     if let Some(result) = stream.next().await {
-      let stats = result?;
+      Ok(container_stats_from(result?))
+    } else {
+      Err(anyhow::anyhow!("No stats available for container"))
+    }
+  }
+}
 
-      // Calculate CPU percentage
-      let cpu_stats = stats.cpu_stats.as_ref();
-      let precpu_stats = stats.precpu_stats.as_ref();
+/// Follow one container's stats as the daemon reports them (about once a
+/// second) until the container stops, with network and block I/O rates
+/// diffed from the previous sample. Takes the bollard handle rather than `DockerClient`
+/// so callers don't hold the client lock for the life of the stream.
+pub async fn stream_container_stats(
+  docker: &Docker,
+  container_id: &str,
+  mut on_sample: impl FnMut(ContainerStats),
+) -> Result<()> {
+  let options = StatsOptions {
+    stream: true,
+    one_shot: false,
+  };
 
-      let cpu_delta = cpu_stats
+  let mut stream = docker.stats(container_id, Some(options));
+  let mut prev: Option<(ContainerStats, Instant)> = None;
+  while let Some(result) = stream.next().await {
+    let now = Instant::now();
+    let mut sample = container_stats_from(result?);
+    if let Some((before, at)) = &prev {
+      sample = sample.with_rates(before, now.duration_since(*at));
+    }
+    prev = Some((sample.clone(), now));
+    on_sample(sample);
+  }
+  Ok(())
+}
+
+fn container_stats_from(stats: ContainerStatsResponse) -> ContainerStats {
+  // Calculate CPU percentage
+  let cpu_stats = stats.cpu_stats.as_ref();
+  let precpu_stats = stats.precpu_stats.as_ref();
+
+  let cpu_delta = cpu_stats
+    .and_then(|s| s.cpu_usage.as_ref())
+    .and_then(|u| u.total_usage)
+    .unwrap_or(0)
+    .saturating_sub(
+      precpu_stats
         .and_then(|s| s.cpu_usage.as_ref())
         .and_then(|u| u.total_usage)
-        .unwrap_or(0)
-        .saturating_sub(
-          precpu_stats
-            .and_then(|s| s.cpu_usage.as_ref())
-            .and_then(|u| u.total_usage)
-            .unwrap_or(0),
-        );
-      let system_delta = cpu_stats
-        .and_then(|s| s.system_cpu_usage)
-        .unwrap_or(0)
-        .saturating_sub(precpu_stats.and_then(|s| s.system_cpu_usage).unwrap_or(0));
-
-      let cpu_percent = if system_delta > 0 && cpu_delta > 0 {
-        let num_cpus = f64::from(cpu_stats.and_then(|s| s.online_cpus).unwrap_or(1));
-        (cpu_delta as f64 / system_delta as f64) * num_cpus * 100.0
-      } else {
-        0.0
-      };
-
-      // Memory stats
-      let memory_stats = stats.memory_stats.as_ref();
-      let memory_usage = memory_stats.and_then(|s| s.usage).unwrap_or(0);
-      let memory_limit = memory_stats.and_then(|s| s.limit).unwrap_or(1);
-      let memory_percent = if memory_limit > 0 {
-        (memory_usage as f64 / memory_limit as f64) * 100.0
-      } else {
-        0.0
-      };
-
-      // Network stats (aggregate all interfaces)
-      let (network_rx, network_tx) = if let Some(networks) = &stats.networks {
-        networks.values().fold((0u64, 0u64), |(rx, tx), net| {
-          (rx + net.rx_bytes.unwrap_or(0), tx + net.tx_bytes.unwrap_or(0))
+        .unwrap_or(0),
+    );
+  let system_delta = cpu_stats
+    .and_then(|s| s.system_cpu_usage)
+    .unwrap_or(0)
+    .saturating_sub(precpu_stats.and_then(|s| s.system_cpu_usage).unwrap_or(0));
+
+  let cpu_percent = if system_delta > 0 && cpu_delta > 0 {
+    let num_cpus = f64::from(cpu_stats.and_then(|s| s.online_cpus).unwrap_or(1));
+    (cpu_delta as f64 / system_delta as f64) * num_cpus * 100.0
+  } else {
+    0.0
+  };
+
+  // Memory stats
+  let memory_stats = stats.memory_stats.as_ref();
+  let memory_usage = memory_stats.and_then(|s| s.usage).unwrap_or(0);
+  let memory_limit = memory_stats.and_then(|s| s.limit).unwrap_or(1);
+  let memory_percent = if memory_limit > 0 {
+    (memory_usage as f64 / memory_limit as f64) * 100.0
+  } else {
+    0.0
+  };
+
+  // Network stats, per interface and summed
+  let mut networks: Vec<InterfaceTraffic> = stats
+    .networks
+    .iter()
+    .flatten()
+    .map(|(name, net)| InterfaceTraffic {
+      name: name.clone(),
+      rx_bytes: net.rx_bytes.unwrap_or(0),
+      tx_bytes: net.tx_bytes.unwrap_or(0),
+      ..Default::default()
+    })
+    .collect();
+  networks.sort_by(|a, b| a.name.cmp(&b.name));
+  let network_rx = networks.iter().map(|n| n.rx_bytes).sum();
+  let network_tx = networks.iter().map(|n| n.tx_bytes).sum();
+
+  let block_io = block_io_totals(
+    stats
+      .blkio_stats
+      .as_ref()
+      .and_then(|s| s.io_service_bytes_recursive.as_deref()),
+  );
+  let (block_read, block_write) = block_io.unwrap_or((0, 0));
+
+  // Clean up the name (remove leading /)
+  let name = stats
+    .name
+    .as_deref()
+    .map_or("", |n| n.trim_start_matches('/'))
+    .to_string();
+
+  ContainerStats {
+    id: stats.id.unwrap_or_default(),
+    name,
+    cpu_percent,
+    memory_usage,
+    memory_limit,
+    memory_percent,
+    network_rx,
+    network_tx,
+    network_rx_rate: 0,
+    network_tx_rate: 0,
+    networks,
+    block_read,
+    block_write,
+    block_read_rate: 0,
+    block_write_rate: 0,
+    block_io_available: block_io.is_some(),
+  }
+}
+
+/// Sum read and write bytes over all devices. `None` when the daemon sent
+/// no entries, which cgroup v2 hosts without io accounting do.
+fn block_io_totals(entries: Option<&[ContainerBlkioStatEntry]>) -> Option<(u64, u64)> {
+  let entries = entries.filter(|e| !e.is_empty())?;
+  Some(entries.iter().fold((0u64, 0u64), |(read, write), entry| {
+    let value = entry.value.unwrap_or(0);
+    match entry.op.as_deref().unwrap_or("") {
+      "read" | "Read" => (read + value, write),
+      "write" | "Write" => (read, write + value),
+      _ => (read, write),
+    }
+  }))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sample(interfaces: &[(&str, u64, u64)]) -> ContainerStats {
+    ContainerStats {
+      networks: interfaces
+        .iter()
+        .map(|(name, rx, tx)| InterfaceTraffic {
+          name: (*name).to_string(),
+          rx_bytes: *rx,
+          tx_bytes: *tx,
+          ..Default::default()
         })
-      } else {
-        (0, 0)
-      };
-
-      // Block I/O stats
-      let (block_read, block_write) = stats
-        .blkio_stats
-        .as_ref()
-        .and_then(|s| s.io_service_bytes_recursive.as_ref())
-        .map_or((0, 0), |entries| {
-          entries.iter().fold((0u64, 0u64), |(read, write), entry| {
-            let op = entry.op.as_deref().unwrap_or("");
-            let value = entry.value.unwrap_or(0);
-            match op {
-              "read" | "Read" => (read + value, write),
-              "write" | "Write" => (read, write + value),
-              _ => (read, write),
-            }
-          })
-        });
-
-      // Clean up the name (remove leading /)
-      let name = stats
-        .name
-        .as_deref()
-        .map_or("", |n| n.trim_start_matches('/'))
-        .to_string();
-
-      Ok(ContainerStats {
-        id: stats.id.unwrap_or_default(),
-        name,
-        cpu_percent,
-        memory_usage,
-        memory_limit,
-        memory_percent,
-        network_rx,
-        network_tx,
-        block_read,
-        block_write,
-      })
-    } else {
-      Err(anyhow::anyhow!("No stats available for container"))
+        .collect(),
+      ..Default::default()
     }
   }
 
-  /// Get stats for all running containers
-  pub async fn get_all_container_stats(&self) -> Result<AggregateStats> {
-    let containers = self.list_containers(true).await?;
-
-    let mut aggregate = AggregateStats::default();
-    let mut stats_list = Vec::new();
-
-    for container in containers {
-      // Only get stats for running containers
-      if container.state == ContainerState::Running {
-        if let Ok(mut stats) = self.get_container_stats(&container.id).await {
-          // Use the container name from list_containers if stats name is empty
-          if stats.name.is_empty() {
-            container.name.clone_into(&mut stats.name);
-          }
-          aggregate.total_cpu_percent += stats.cpu_percent;
-          aggregate.total_memory += stats.memory_usage;
-          aggregate.total_network_rx += stats.network_rx;
-          aggregate.total_network_tx += stats.network_tx;
-          aggregate.total_block_read += stats.block_read;
-          aggregate.total_block_write += stats.block_write;
-          stats_list.push(stats);
-        } else {
-          // Container might have stopped, skip it
-        }
-      }
-    }
+  #[test]
+  fn test_with_rates() {
+    let prev = sample(&[("eth0", 1_000, 500), ("eth1", 0, 0)]);
+    let next = sample(&[("eth0", 5_000, 1_500), ("eth1", 2_000, 0), ("eth2", 9_000, 9_000)])
+      .with_rates(&prev, Duration::from_secs(2));
+    assert_eq!((next.networks[0].rx_rate, next.networks[0].tx_rate), (2_000, 500));
+    assert_eq!((next.networks[1].rx_rate, next.networks[1].tx_rate), (1_000, 0));
+    // No previous reading for a new interface
+    assert_eq!((next.networks[2].rx_rate, next.networks[2].tx_rate), (0, 0));
+    assert_eq!((next.network_rx_rate, next.network_tx_rate), (3_000, 500));
+
+    // Counters reset by a restart
+    let restarted = sample(&[("eth0", 10, 10)]).with_rates(&prev, Duration::from_secs(1));
+    assert_eq!((restarted.network_rx_rate, restarted.network_tx_rate), (0, 0));
+  }
+
+  #[test]
+  fn test_aggregate_from_samples() {
+    let usage = |name: &str, cpu, memory, rx| ContainerStats {
+      name: name.to_string(),
+      cpu_percent: cpu,
+      memory_usage: memory,
+      network_rx_rate: rx,
+      ..Default::default()
+    };
+    let aggregate = AggregateStats::from_samples(vec![
+      usage("db", 5.0, 300, 10),
+      usage("web", 20.0, 100, 40),
+      usage("api", 5.0, 200, 0),
+    ]);
+    let order: Vec<_> = aggregate.container_stats.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(order, vec!["web", "api", "db"]);
+    assert!((aggregate.total_cpu_percent - 30.0).abs() < f64::EPSILON);
+    assert_eq!(aggregate.total_memory, 600);
+    assert_eq!(aggregate.total_network_rx, 50);
+    assert_eq!(aggregate.total_network_tx, 0);
+  }
+
+  #[test]
+  fn test_block_io_rates() {
+    let disk = |read, write| ContainerStats {
+      block_read: read,
+      block_write: write,
+      block_io_available: true,
+      ..Default::default()
+    };
+    let next = disk(3_000, 8_000).with_rates(&disk(1_000, 0), Duration::from_millis(500));
+    assert_eq!((next.block_read_rate, next.block_write_rate), (4_000, 16_000));
 
-    aggregate.container_stats = stats_list;
-    Ok(aggregate)
+    let unreported = ContainerStats::default().with_rates(&disk(1_000, 0), Duration::from_secs(1));
+    assert_eq!(unreported.display_block_rate(), "n/a");
+  }
+
+  #[test]
+  fn test_block_io_totals() {
+    let entry = |op: &str, value| ContainerBlkioStatEntry {
+      op: Some(op.to_string()),
+      value: Some(value),
+      ..Default::default()
+    };
+    let entries = [
+      entry("read", 10),
+      entry("Write", 5),
+      entry("read", 1),
+      entry("sync", 99),
+    ];
+    assert_eq!(block_io_totals(Some(&entries[..])), Some((11, 5)));
+    assert_eq!(block_io_totals(Some(&[][..])), None);
+    assert_eq!(block_io_totals(None), None);
   }
 }
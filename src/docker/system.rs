@@ -119,6 +119,18 @@ pub struct DiskUsageSummary {
   pub build_cache_reclaimable: i64,
 }
 
+impl DiskUsageSummary {
+  /// Bytes used across images, containers, volumes and build cache.
+  pub fn total_size(&self) -> i64 {
+    self.images_size + self.containers_size + self.volumes_size + self.build_cache_size
+  }
+
+  /// Bytes a full prune could free.
+  pub fn total_reclaimable(&self) -> i64 {
+    self.images_reclaimable + self.containers_reclaimable + self.volumes_reclaimable + self.build_cache_reclaimable
+  }
+}
+
 impl DockerClient {
   /// Get system information from the Docker daemon
   pub async fn get_system_info(&self) -> Result<DockerHostInfo> {
@@ -222,6 +234,22 @@ mod tests {
     }
   }
 
+  #[test]
+  fn test_disk_usage_totals() {
+    let usage = DiskUsageSummary {
+      images_size: 1000,
+      images_reclaimable: 400,
+      containers_size: 50,
+      containers_reclaimable: 50,
+      volumes_size: 200,
+      build_cache_size: 300,
+      build_cache_reclaimable: 100,
+      ..Default::default()
+    };
+    assert_eq!(usage.total_size(), 1550);
+    assert_eq!(usage.total_reclaimable(), 550);
+  }
+
   #[test]
   fn test_memory_gb() {
     let host = create_test_host_info();
@@ -10,6 +10,10 @@ use std::collections::HashMap;
 
 use super::DockerClient;
 
+/// Image used for the throw-away containers that read and write volume
+/// contents (backup, restore, clone, file browsing).
+pub const VOLUME_HELPER_IMAGE: &str = "alpine:latest";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VolumeInfo {
   pub name: String,
@@ -106,6 +110,16 @@ impl DockerClient {
     Ok(())
   }
 
+  /// Pull the helper image when it isn't present locally, so the first
+  /// backup or restore on a fresh machine doesn't fail with "No such image".
+  pub async fn ensure_volume_helper_image(&self) -> Result<()> {
+    let docker = self.client()?;
+    if docker.inspect_image(VOLUME_HELPER_IMAGE).await.is_ok() {
+      return Ok(());
+    }
+    self.pull_image(VOLUME_HELPER_IMAGE, None).await
+  }
+
   /// Tar+gzip the volume contents into `<dest_dir>/<volume_name>-<ts>.tar.gz`
   /// using a throw-away alpine container. Returns the absolute path of the
   /// archive on success.
@@ -134,7 +148,7 @@ impl DockerClient {
     ];
 
     let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
+      image: Some(VOLUME_HELPER_IMAGE.to_string()),
       cmd: Some(cmd),
       host_config: Some(host_config),
       tty: Some(false),
@@ -208,7 +222,7 @@ impl DockerClient {
     ];
 
     let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
+      image: Some(VOLUME_HELPER_IMAGE.to_string()),
       cmd: Some(cmd),
       host_config: Some(host_config),
       tty: Some(false),
@@ -260,7 +274,7 @@ impl DockerClient {
     };
 
     let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
+      image: Some(VOLUME_HELPER_IMAGE.to_string()),
       cmd: Some(vec![
         "sh".to_string(),
         "-c".to_string(),
@@ -367,7 +381,7 @@ impl DockerClient {
     };
 
     let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
+      image: Some(VOLUME_HELPER_IMAGE.to_string()),
       cmd: Some(vec!["ls".to_string(), "-la".to_string(), normalized_path.clone()]),
       host_config: Some(host_config),
       tty: Some(false),
@@ -498,7 +512,7 @@ impl DockerClient {
     };
 
     let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
+      image: Some(VOLUME_HELPER_IMAGE.to_string()),
       cmd: Some(vec!["cat".to_string(), normalized_path]),
       host_config: Some(host_config),
       tty: Some(false),
@@ -573,7 +587,7 @@ impl DockerClient {
     };
 
     let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
+      image: Some(VOLUME_HELPER_IMAGE.to_string()),
       cmd: Some(vec!["readlink".to_string(), "-f".to_string(), normalized_path]),
       host_config: Some(host_config),
       tty: Some(false),
@@ -648,7 +662,7 @@ impl DockerClient {
     };
 
     let config = ContainerCreateBody {
-      image: Some("alpine:latest".to_string()),
+      image: Some(VOLUME_HELPER_IMAGE.to_string()),
       cmd: Some(vec![
         "sh".to_string(),
         "-c".to_string(),
@@ -4,9 +4,22 @@
 //! - Navigation between views (Cmd+1-9)
 //! - Common actions (refresh, new, etc.)
 //! - Command palette (Cmd+K)
+//! - Docker context switcher (Cmd+Shift+D)
+//! - Sidebar collapse (Cmd+B)
 //! - Help overlay (?)
+//! - Vim-style row navigation in resource lists (j/k)
 
-use gpui::{App, KeyBinding, actions};
+use std::rc::Rc;
+
+use gpui::{App, DummyKeyboardMapper, KeyBinding, KeyBindingContextPredicate, actions};
+
+/// Key context of gpui-component's `List`, which already binds the arrow
+/// keys and Enter.
+const LIST_CONTEXT: &str = "List";
+
+/// Extra keys for gpui-component's list actions. Its `actions` module is
+/// private, so the actions are built by their registered names.
+const LIST_BINDINGS: [(&str, &str); 2] = [("j", "ui::SelectDown"), ("k", "ui::SelectUp")];
 
 // ==================== Global Navigation Actions ====================
 actions!(
@@ -29,6 +42,8 @@ actions!(
     NewResource,
     OpenCommandPalette,
     ShowKeyboardShortcuts,
+    SwitchDockerContext,
+    ToggleSidebar,
     // Resource actions (work on selected resource)
     StartSelected,
     StopSelected,
@@ -66,6 +81,8 @@ pub fn register_keybindings(cx: &mut App) {
     KeyBinding::new("cmd-n", NewResource, None),
     KeyBinding::new("cmd-k", OpenCommandPalette, None),
     KeyBinding::new("shift-/", ShowKeyboardShortcuts, None), // ? key
+    KeyBinding::new("cmd-shift-d", SwitchDockerContext, None),
+    KeyBinding::new("cmd-b", ToggleSidebar, None),
     // Resource actions (work on selected resource)
     KeyBinding::new("cmd-enter", StartSelected, None),
     KeyBinding::new("cmd-.", StopSelected, None),
@@ -78,6 +95,22 @@ pub fn register_keybindings(cx: &mut App) {
     KeyBinding::new("cmd-f", FocusSearch, None),
     KeyBinding::new("/", FocusSearch, None),
   ]);
+
+  // List navigation. Moving the highlight selects the row, so the resource
+  // actions above follow it.
+  let list_bindings: Vec<_> = LIST_BINDINGS
+    .iter()
+    .filter_map(|(keystrokes, action)| list_binding(keystrokes, action, cx))
+    .collect();
+  cx.bind_keys(list_bindings);
+}
+
+/// Bind `keystrokes` to the action registered as `action_name`, scoped to
+/// the list key context
+fn list_binding(keystrokes: &str, action_name: &str, cx: &App) -> Option<KeyBinding> {
+  let action = cx.build_action(action_name, None).ok()?;
+  let context = KeyBindingContextPredicate::parse(LIST_CONTEXT).ok().map(Rc::new);
+  KeyBinding::load(keystrokes, action, context, false, None, &DummyKeyboardMapper).ok()
 }
 
 /// Keyboard shortcuts data for display in the help overlay
@@ -172,6 +205,32 @@ pub fn get_all_shortcuts() -> Vec<KeyboardShortcut> {
       description: "Show keyboard shortcuts",
       category: "General",
     },
+    KeyboardShortcut {
+      keys: "Cmd+Shift+D",
+      description: "Switch Docker context",
+      category: "General",
+    },
+    KeyboardShortcut {
+      keys: "Cmd+B",
+      description: "Collapse or expand the sidebar",
+      category: "General",
+    },
+    // Lists
+    KeyboardShortcut {
+      keys: "Down or J",
+      description: "Next row in the focused list",
+      category: "Lists",
+    },
+    KeyboardShortcut {
+      keys: "Up or K",
+      description: "Previous row in the focused list",
+      category: "Lists",
+    },
+    KeyboardShortcut {
+      keys: "Enter",
+      description: "Open the highlighted row",
+      category: "Lists",
+    },
     // Resource Actions
     KeyboardShortcut {
       keys: "Cmd+Enter",
@@ -2,7 +2,9 @@ use anyhow::{Context, Result};
 use chrono::Utc;
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet};
 use k8s_openapi::api::batch::v1::{CronJob, Job};
-use k8s_openapi::api::core::v1::{ConfigMap, Event, Namespace, Node, PersistentVolumeClaim, Pod, Secret};
+use k8s_openapi::api::core::v1::{
+  ConfigMap, Event, Namespace, Node, PersistentVolumeClaim, Pod, ResourceQuota, Secret,
+};
 use k8s_openapi::api::networking::v1::Ingress;
 use kube::{
   Api, Client, Config,
@@ -18,7 +20,8 @@ use k8s_openapi::api::core::v1::Service;
 use super::diagnostics::first_existing_known_kubeconfig;
 use super::types::{
   ConfigMapInfo, CronJobInfo, DaemonSetInfo, DeploymentInfo, EventInfo, IngressInfo, JobInfo, KubeContextInfo,
-  NamespaceInfo, NodeInfo, NodeTaint, PodInfo, PvcInfo, SecretInfo, ServiceInfo, StatefulSetInfo,
+  NamespaceInfo, NodeInfo, NodeTaint, PodInfo, PodLogOptions, PvcInfo, ResourceQuotaInfo, RolloutStatus, SecretInfo,
+  ServiceInfo, StatefulSetInfo,
 };
 
 /// Kubernetes client wrapper
@@ -208,14 +211,13 @@ impl KubeClient {
   }
 
   /// Stream pod logs as raw bytes into the given channel until the
-  /// receiver drops or the pod stops producing output.
+  /// receiver drops or the pod stops producing output. Without `follow`
+  /// the stream ends after the current log snapshot.
   pub async fn stream_pod_logs(
     &self,
     name: &str,
     namespace: &str,
-    container: Option<&str>,
-    tail_lines: Option<i64>,
-    timestamps: bool,
+    options: &PodLogOptions,
     tx: tokio::sync::mpsc::Sender<Vec<u8>>,
   ) -> Result<()> {
     use futures::AsyncBufReadExt;
@@ -223,15 +225,15 @@ impl KubeClient {
 
     let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
 
-    let mut params = LogParams::default();
-    if let Some(c) = container {
-      params.container = Some(c.to_string());
-    }
-    if let Some(lines) = tail_lines {
-      params.tail_lines = Some(lines);
-    }
-    params.follow = true;
-    params.timestamps = timestamps;
+    let params = LogParams {
+      container: options.container.clone(),
+      tail_lines: options.tail_lines,
+      // A terminated instance produces no new output, so never follow it.
+      follow: options.follow && !options.previous,
+      previous: options.previous,
+      timestamps: options.timestamps,
+      ..LogParams::default()
+    };
 
     let reader = api
       .log_stream(name, &params)
@@ -852,37 +854,6 @@ impl KubeClient {
   // Generic YAML apply / Deployment rollback
   // ========================================================================
 
-  /// Server-side apply YAML for a Deployment. Parses the user-edited
-  /// YAML and `replace`s the existing object preserving resourceVersion
-  /// to detect concurrent edits.
-  pub async fn apply_deployment_yaml(&self, name: &str, namespace: &str, yaml_str: &str) -> Result<()> {
-    use kube::api::PostParams;
-    let mut dep: Deployment = serde_yaml::from_str(yaml_str).context("Failed to parse deployment YAML")?;
-    // Force metadata.name + namespace to match the resource being edited
-    // so a typo can't accidentally rename the object.
-    dep.metadata.name = Some(name.to_string());
-    dep.metadata.namespace = Some(namespace.to_string());
-    let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
-    api
-      .replace(name, &PostParams::default(), &dep)
-      .await
-      .with_context(|| format!("Failed to apply deployment {name}"))?;
-    Ok(())
-  }
-
-  pub async fn apply_service_yaml(&self, name: &str, namespace: &str, yaml_str: &str) -> Result<()> {
-    use kube::api::PostParams;
-    let mut svc: Service = serde_yaml::from_str(yaml_str).context("Failed to parse service YAML")?;
-    svc.metadata.name = Some(name.to_string());
-    svc.metadata.namespace = Some(namespace.to_string());
-    let api: Api<Service> = Api::namespaced(self.client.clone(), namespace);
-    api
-      .replace(name, &PostParams::default(), &svc)
-      .await
-      .with_context(|| format!("Failed to apply service {name}"))?;
-    Ok(())
-  }
-
   pub async fn get_statefulset_yaml(&self, name: &str, namespace: &str) -> Result<String> {
     let api: Api<StatefulSet> = Api::namespaced(self.client.clone(), namespace);
     let s = api
@@ -1082,14 +1053,18 @@ impl KubeClient {
     Ok(out)
   }
 
-  /// Per-pod CPU/memory usage from `metrics.k8s.io/v1beta1/pods`,
-  /// summed across the pod's containers. `Err` means metrics-server is
-  /// absent. Returns `(namespace, name, cpu_millicores, mem_bytes)`.
-  pub async fn pod_metrics(&self) -> Result<Vec<(String, String, f64, u64)>> {
+  /// Per-pod CPU/memory usage from `metrics.k8s.io/v1beta1/pods` in one
+  /// namespace (or all when `None`), summed across the pod's containers.
+  /// `Err` means metrics-server is absent. Returns
+  /// `(namespace, name, cpu_millicores, mem_bytes)`.
+  pub async fn pod_metrics(&self, namespace: Option<&str>) -> Result<Vec<(String, String, f64, u64)>> {
     use kube::core::{ApiResource, DynamicObject, GroupVersionKind};
     let gvk = GroupVersionKind::gvk("metrics.k8s.io", "v1beta1", "PodMetrics");
     let ar = ApiResource::from_gvk_with_plural(&gvk, "pods");
-    let api: Api<DynamicObject> = Api::all_with(self.client.clone(), &ar);
+    let api: Api<DynamicObject> = match namespace {
+      Some(ns) => Api::namespaced_with(self.client.clone(), ns, &ar),
+      None => Api::all_with(self.client.clone(), &ar),
+    };
     let list = api
       .list(&ListParams::default())
       .await
@@ -1226,6 +1201,19 @@ impl KubeClient {
     Ok(())
   }
 
+  /// Events whose `involvedObject` is the given object, most recent first
+  pub async fn list_object_events(&self, kind: &str, name: &str, namespace: &str) -> Result<Vec<EventInfo>> {
+    let api: Api<Event> = Api::namespaced(self.client.clone(), namespace);
+    let params = ListParams::default().fields(&format!("involvedObject.kind={kind},involvedObject.name={name}"));
+    let items = api
+      .list(&params)
+      .await
+      .with_context(|| format!("Failed to list events for {kind} {name}"))?;
+    let mut events: Vec<EventInfo> = items.items.iter().map(EventInfo::from_event).collect();
+    events.sort_by_key(|e| std::cmp::Reverse(e.last_seen));
+    Ok(events)
+  }
+
   pub async fn list_events(&self, namespace: Option<&str>) -> Result<Vec<EventInfo>> {
     let items = if let Some(ns) = namespace {
       let api: Api<Event> = Api::namespaced(self.client.clone(), ns);
@@ -1235,27 +1223,20 @@ impl KubeClient {
       api.list(&ListParams::default()).await?
     };
     let mut events: Vec<EventInfo> = items.items.iter().map(EventInfo::from_event).collect();
-    // Most-recent first by event-age string is unreliable; sort by raw
-    // last_timestamp before mapping.
-    let mut indexed: Vec<(usize, Option<chrono::DateTime<Utc>>)> = items
-      .items
-      .iter()
-      .enumerate()
-      .map(|(i, e)| {
-        let when = e
-          .last_timestamp
-          .as_ref()
-          .map(|t| t.0)
-          .or_else(|| e.event_time.as_ref().map(|t| t.0))
-          .or_else(|| e.metadata.creation_timestamp.as_ref().map(|t| t.0));
-        (i, when)
-      })
-      .collect();
-    indexed.sort_by_key(|b| std::cmp::Reverse(b.1));
-    events = indexed.into_iter().map(|(i, _)| events[i].clone()).collect();
+    events.sort_by_key(|e| std::cmp::Reverse(e.last_seen));
     Ok(events)
   }
 
+  /// `ResourceQuota` objects in a namespace with their current usage.
+  pub async fn list_resource_quotas(&self, namespace: &str) -> Result<Vec<ResourceQuotaInfo>> {
+    let api: Api<ResourceQuota> = Api::namespaced(self.client.clone(), namespace);
+    let items = api
+      .list(&ListParams::default())
+      .await
+      .context(format!("Failed to list resource quotas in namespace {namespace}"))?;
+    Ok(items.items.iter().map(ResourceQuotaInfo::from_quota).collect())
+  }
+
   pub async fn create_namespace(&self, name: &str) -> Result<()> {
     use kube::api::PostParams;
     let api: Api<Namespace> = Api::all(self.client.clone());
@@ -1410,11 +1391,86 @@ impl KubeClient {
     Ok(format!("Deployment {name} scaled to {replicas} replicas"))
   }
 
+  /// Strategic-merge-patch one container in a single request: `image`
+  /// replaces its image, `set` upserts env vars and `removed` vars are
+  /// dropped. Other containers and vars are untouched.
+  pub async fn patch_deployment_container(
+    &self,
+    name: &str,
+    namespace: &str,
+    container: &str,
+    image: Option<&str>,
+    set: &[(String, String)],
+    removed: &[String],
+  ) -> Result<()> {
+    let mut env: Vec<serde_json::Value> = set.iter().map(|(k, v)| json!({ "name": k, "value": v })).collect();
+    env.extend(removed.iter().map(|k| json!({ "name": k, "$patch": "delete" })));
+    let mut patched = json!({ "name": container });
+    if let Some(image) = image {
+      patched["image"] = json!(image);
+    }
+    if !env.is_empty() {
+      patched["env"] = json!(env);
+    }
+    let patch = json!({
+      "spec": { "template": { "spec": { "containers": [patched] } } }
+    });
+    let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+    api
+      .patch(name, &PatchParams::default(), &Patch::Strategic(&patch))
+      .await
+      .with_context(|| format!("Failed to update container {container} in deployment {name}"))?;
+    Ok(())
+  }
+
   /// Restart a deployment (rollout restart)
   pub async fn restart_deployment(&self, name: &str, namespace: &str) -> Result<String> {
     self.rollout_restart_deployment(name, namespace).await
   }
 
+  /// Watch a deployment until its rollout completes or fails, calling
+  /// `on_progress` on every status change. Gives up a little after the
+  /// deployment's own progress deadline in case the controller never
+  /// reports it.
+  pub async fn watch_deployment_rollout(
+    &self,
+    name: &str,
+    namespace: &str,
+    mut on_progress: impl FnMut(&RolloutStatus),
+  ) -> Result<RolloutStatus> {
+    use futures::TryStreamExt;
+    use kube::runtime::{WatchStreamExt, watcher};
+
+    let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
+    let deadline_secs = api
+      .get(name)
+      .await
+      .context(format!("Failed to get deployment {name}"))?
+      .spec
+      .and_then(|s| s.progress_deadline_seconds)
+      .unwrap_or(600);
+    let deadline = Duration::from_secs(u64::try_from(deadline_secs).unwrap_or(600) + 30);
+
+    let config = watcher::Config::default().fields(&format!("metadata.name={name}"));
+    let stream = watcher(api, config).applied_objects();
+    futures::pin_mut!(stream);
+
+    let watch = async {
+      while let Some(dep) = stream.try_next().await? {
+        let status = RolloutStatus::from_deployment(&dep);
+        on_progress(&status);
+        if !matches!(status, RolloutStatus::Progressing { .. }) {
+          return Ok(status);
+        }
+      }
+      Err(anyhow::anyhow!("Deployment {name} watch ended unexpectedly"))
+    };
+
+    tokio::time::timeout(deadline, watch)
+      .await
+      .map_err(|_| anyhow::anyhow!("Timed out after {}s waiting for rollout of {name}", deadline.as_secs()))?
+  }
+
   /// Get deployment YAML
   pub async fn get_deployment_yaml(&self, name: &str, namespace: &str) -> Result<String> {
     let api: Api<Deployment> = Api::namespaced(self.client.clone(), namespace);
@@ -1789,7 +1845,7 @@ fn try_localhost_fallback(original_url: &str, mut config: Config) -> Option<Conf
 
 /// Parse a Kubernetes CPU quantity into millicores.
 /// Handles `n` (nano), `u` (micro), `m` (milli) suffixes and plain cores.
-fn parse_cpu_millicores(s: &str) -> f64 {
+pub(super) fn parse_cpu_millicores(s: &str) -> f64 {
   let s = s.trim();
   if let Some(v) = s.strip_suffix('n') {
     v.parse::<f64>().unwrap_or(0.0) / 1_000_000.0
@@ -1805,7 +1861,7 @@ fn parse_cpu_millicores(s: &str) -> f64 {
 /// Parse a Kubernetes memory quantity into bytes. Handles binary
 /// (`Ki`/`Mi`/`Gi`/`Ti`/`Pi`) and decimal (`k`/`M`/`G`/`T`/`P`)
 /// suffixes. Values are integers in practice (e.g. `52428Ki`).
-fn parse_mem_bytes(s: &str) -> u64 {
+pub(super) fn parse_mem_bytes(s: &str) -> u64 {
   let s = s.trim();
   let pairs: [(&str, u64); 10] = [
     ("Ki", 1 << 10),
@@ -11,6 +11,8 @@ pub use diagnostics::{K8sStatus, kubeconfig_setup_hint, kubectl_install_hint};
 pub use distro::{Distro, JoinGuide, join_guide};
 pub use kubeconfig::{AuthMethod, Kubeconfigs, NewCluster};
 pub use types::{
-  ConfigMapInfo, CronJobInfo, DaemonSetInfo, DeploymentInfo, EventInfo, IngressInfo, JobInfo, KubeContextInfo,
-  NodeInfo, NodeTaint, PodInfo, PodPhase, PvcInfo, SecretInfo, ServiceInfo, StatefulSetInfo, is_reserved_node_label,
+  ConfigMapInfo, CronJobInfo, DaemonSetInfo, DeploymentContainer, DeploymentHealth, DeploymentInfo, EventInfo,
+  IngressInfo, JobInfo, KubeContextInfo, NodeInfo, NodeTaint, PodInfo, PodLogOptions, PodPhase, PvcInfo, QuotaUsage,
+  ResourceQuotaInfo, RolloutStatus, SecretInfo, ServiceInfo, StatefulSetInfo, format_millicores,
+  is_reserved_node_label,
 };
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use k8s_openapi::api::core::v1::{ContainerStatus, Pod};
 
+use super::client::{parse_cpu_millicores, parse_mem_bytes};
+
 /// Pod status enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PodPhase {
@@ -161,6 +163,20 @@ impl PodInfo {
   }
 }
 
+/// Options for streaming a pod's logs
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct PodLogOptions {
+  /// Container to read from; `None` lets the API pick the only container.
+  pub container: Option<String>,
+  pub tail_lines: Option<i64>,
+  /// Keep the stream open and follow new output (`kubectl logs -f`).
+  pub follow: bool,
+  /// Read the previous (crashed) container instance (`--previous`).
+  pub previous: bool,
+  pub timestamps: bool,
+}
+
 /// Format age as human-readable string
 fn format_age(creation: DateTime<Utc>) -> String {
   let now = Utc::now();
@@ -205,6 +221,8 @@ pub struct ServiceInfo {
   pub service_type: String,
   pub cluster_ip: Option<String>,
   pub external_ips: Vec<String>,
+  /// IPs or hostnames assigned by the load balancer
+  pub load_balancer_ingress: Vec<String>,
   pub ports: Vec<ServicePortInfo>,
   pub selector: HashMap<String, String>,
   pub age: String,
@@ -229,6 +247,19 @@ impl ServiceInfo {
 
     let external_ips = spec.and_then(|s| s.external_ips.clone()).unwrap_or_default();
 
+    let load_balancer_ingress: Vec<String> = svc
+      .status
+      .as_ref()
+      .and_then(|s| s.load_balancer.as_ref())
+      .and_then(|lb| lb.ingress.as_ref())
+      .map(|ingress| {
+        ingress
+          .iter()
+          .filter_map(|i| i.ip.clone().or_else(|| i.hostname.clone()))
+          .collect()
+      })
+      .unwrap_or_default();
+
     let ports: Vec<ServicePortInfo> = spec
       .and_then(|s| s.ports.as_ref())
       .map(|ports| {
@@ -265,6 +296,7 @@ impl ServiceInfo {
       service_type,
       cluster_ip,
       external_ips,
+      load_balancer_ingress,
       ports,
       selector,
       age,
@@ -287,6 +319,18 @@ impl ServiceInfo {
       .collect::<Vec<_>>()
       .join(", ")
   }
+
+  /// URL for the first load balancer address and TCP port, e.g.
+  /// `http://203.0.113.10:8080`
+  pub fn load_balancer_url(&self) -> Option<String> {
+    let host = self.load_balancer_ingress.first()?;
+    let port = self.ports.iter().find(|p| p.protocol == "TCP")?.port;
+    Some(match port {
+      80 => format!("http://{host}"),
+      443 => format!("https://{host}"),
+      _ => format!("http://{host}:{port}"),
+    })
+  }
 }
 
 // ============================================================================
@@ -302,9 +346,43 @@ pub struct DeploymentInfo {
   pub ready_replicas: i32,
   pub updated_replicas: i32,
   pub available_replicas: i32,
+  pub unavailable_replicas: i32,
+  /// When the `Available` condition last turned false, if it is false now
+  pub unavailable_since: Option<DateTime<Utc>>,
+  /// Message of the `Progressing` condition once the deadline is exceeded
+  pub stalled_message: Option<String>,
   pub age: String,
   pub labels: HashMap<String, String>,
   pub images: Vec<String>,
+  pub containers: Vec<DeploymentContainer>,
+}
+
+/// How long replicas may stay unavailable before a deployment counts as
+/// unhealthy rather than still rolling out
+const UNAVAILABLE_GRACE_SECS: i64 = 120;
+
+/// Health summary shown as the ready badge in the deployment list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeploymentHealth {
+  /// Every desired replica is ready
+  Healthy,
+  /// Scaled to zero on purpose
+  ScaledDown,
+  /// Replicas are coming up or being replaced
+  Progressing,
+  /// Replicas have been unavailable past the grace period, or the rollout
+  /// exceeded its progress deadline
+  Unavailable,
+}
+
+/// A container in a deployment's pod template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentContainer {
+  pub name: String,
+  pub image: String,
+  /// Env vars with a literal value. Entries using `valueFrom` are left out
+  /// since they can't be edited as plain text.
+  pub env: Vec<(String, String)>,
 }
 
 impl DeploymentInfo {
@@ -322,6 +400,23 @@ impl DeploymentInfo {
     let ready_replicas = status.and_then(|s| s.ready_replicas).unwrap_or(0);
     let updated_replicas = status.and_then(|s| s.updated_replicas).unwrap_or(0);
     let available_replicas = status.and_then(|s| s.available_replicas).unwrap_or(0);
+    let unavailable_replicas = status.and_then(|s| s.unavailable_replicas).unwrap_or(0);
+
+    let conditions = status.and_then(|s| s.conditions.as_ref());
+    let unavailable_since = conditions
+      .and_then(|c| c.iter().find(|c| c.type_ == "Available" && c.status == "False"))
+      .and_then(|c| c.last_transition_time.as_ref())
+      .map(|t| t.0);
+    let stalled_message = conditions
+      .and_then(|c| {
+        c.iter()
+          .find(|c| c.type_ == "Progressing" && c.reason.as_deref() == Some("ProgressDeadlineExceeded"))
+      })
+      .map(|c| {
+        c.message
+          .clone()
+          .unwrap_or_else(|| "progress deadline exceeded".to_string())
+      });
 
     // Extract container images from pod template
     let images: Vec<String> = spec
@@ -329,6 +424,27 @@ impl DeploymentInfo {
       .map(|pod_spec| pod_spec.containers.iter().filter_map(|c| c.image.clone()).collect())
       .unwrap_or_default();
 
+    let containers: Vec<DeploymentContainer> = spec
+      .and_then(|s| s.template.spec.as_ref())
+      .map(|pod_spec| {
+        pod_spec
+          .containers
+          .iter()
+          .map(|c| DeploymentContainer {
+            name: c.name.clone(),
+            image: c.image.clone().unwrap_or_default(),
+            env: c
+              .env
+              .iter()
+              .flatten()
+              .filter(|e| e.value_from.is_none())
+              .map(|e| (e.name.clone(), e.value.clone().unwrap_or_default()))
+              .collect(),
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+
     let age = creation_timestamp.map_or_else(|| "Unknown".to_string(), format_age);
 
     Self {
@@ -338,9 +454,13 @@ impl DeploymentInfo {
       ready_replicas,
       updated_replicas,
       available_replicas,
+      unavailable_replicas,
+      unavailable_since,
+      stalled_message,
       age,
       labels,
       images,
+      containers,
     }
   }
 
@@ -348,6 +468,124 @@ impl DeploymentInfo {
   pub fn ready_display(&self) -> String {
     format!("{}/{}", self.ready_replicas, self.replicas)
   }
+
+  /// Health as of `now`, from the replica counts and status conditions
+  pub fn health(&self, now: DateTime<Utc>) -> DeploymentHealth {
+    if self.stalled_message.is_some() {
+      return DeploymentHealth::Unavailable;
+    }
+    if self.replicas == 0 {
+      return DeploymentHealth::ScaledDown;
+    }
+    if self.ready_replicas >= self.replicas && self.unavailable_replicas == 0 {
+      return DeploymentHealth::Healthy;
+    }
+    let unavailable_too_long = self
+      .unavailable_since
+      .is_some_and(|since| (now - since).num_seconds() >= UNAVAILABLE_GRACE_SECS);
+    if self.unavailable_replicas > 0 && unavailable_too_long {
+      DeploymentHealth::Unavailable
+    } else {
+      DeploymentHealth::Progressing
+    }
+  }
+
+  /// Tooltip text explaining `health`
+  pub fn health_explanation(&self, health: &DeploymentHealth) -> String {
+    match health {
+      DeploymentHealth::Healthy => format!("Healthy: all {} replica(s) ready", self.replicas),
+      DeploymentHealth::ScaledDown => "Scaled down to 0 replicas".to_string(),
+      DeploymentHealth::Progressing => format!(
+        "Progressing: {}/{} ready, {} updated",
+        self.ready_replicas, self.replicas, self.updated_replicas
+      ),
+      DeploymentHealth::Unavailable => match &self.stalled_message {
+        Some(message) => format!("Rollout stalled: {message}"),
+        None => format!(
+          "Unavailable: {} replica(s) not available for over {} minutes",
+          self.unavailable_replicas,
+          UNAVAILABLE_GRACE_SECS / 60
+        ),
+      },
+    }
+  }
+}
+
+/// Progress of a deployment rollout, following the same rules as
+/// `kubectl rollout status`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RolloutStatus {
+  /// Pods are still being replaced or brought up
+  Progressing { ready: i32, desired: i32, message: String },
+  /// Every desired replica is updated and available
+  Complete { desired: i32 },
+  /// The controller gave up (progress deadline exceeded)
+  Failed(String),
+}
+
+impl RolloutStatus {
+  pub fn from_deployment(dep: &k8s_openapi::api::apps::v1::Deployment) -> Self {
+    let Some(status) = dep.status.as_ref() else {
+      return Self::Progressing {
+        ready: 0,
+        desired: 0,
+        message: "Waiting for deployment status".to_string(),
+      };
+    };
+    let desired = dep.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+    let total = status.replicas.unwrap_or(0);
+    let updated = status.updated_replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+    let ready = updated.min(available);
+
+    if dep.metadata.generation > status.observed_generation {
+      return Self::Progressing {
+        ready,
+        desired,
+        message: "Waiting for the spec update to be observed".to_string(),
+      };
+    }
+
+    let deadline_exceeded = status
+      .conditions
+      .iter()
+      .flatten()
+      .find(|c| c.type_ == "Progressing" && c.reason.as_deref() == Some("ProgressDeadlineExceeded"));
+    if let Some(condition) = deadline_exceeded {
+      return Self::Failed(
+        condition
+          .message
+          .clone()
+          .unwrap_or_else(|| "progress deadline exceeded".to_string()),
+      );
+    }
+
+    let message = if updated < desired {
+      format!("{updated}/{desired} pods updated")
+    } else if total > updated {
+      format!("{} old pods pending termination", total - updated)
+    } else if available < updated {
+      format!("{available}/{updated} updated pods available")
+    } else {
+      return Self::Complete { desired };
+    };
+
+    Self::Progressing {
+      ready,
+      desired,
+      message,
+    }
+  }
+
+  /// Fraction of desired replicas that are updated and available
+  #[allow(clippy::cast_precision_loss)]
+  pub fn progress(&self) -> f32 {
+    match self {
+      Self::Progressing { desired: 0, .. } | Self::Failed(_) => 0.0,
+      Self::Progressing { ready, desired, .. } => (*ready as f32 / *desired as f32).clamp(0.0, 1.0),
+      Self::Complete { .. } => 1.0,
+    }
+  }
 }
 
 // ============================================================================
@@ -677,9 +915,15 @@ pub struct EventInfo {
   pub message: String,
   pub count: i32,
   pub age: String,
+  /// When the event last fired, for sorting
+  pub last_seen: Option<DateTime<Utc>>,
 }
 
 impl EventInfo {
+  pub fn is_warning(&self) -> bool {
+    self.event_type == "Warning"
+  }
+
   pub fn from_event(ev: &k8s_openapi::api::core::v1::Event) -> Self {
     let metadata = &ev.metadata;
     let namespace = metadata.namespace.clone().unwrap_or_default();
@@ -707,6 +951,7 @@ impl EventInfo {
       message,
       count,
       age,
+      last_seen: when,
     }
   }
 }
@@ -1018,6 +1263,91 @@ impl ConfigMapInfo {
   }
 }
 
+/// Render a CPU amount the way `kubectl top` does: `250m` below one
+/// core, `1.50 cores` above.
+pub fn format_millicores(millicores: f64) -> String {
+  if millicores >= 1000.0 {
+    format!("{:.2} cores", millicores / 1000.0)
+  } else {
+    format!("{millicores:.0}m")
+  }
+}
+
+/// One tracked resource in a `ResourceQuota`, e.g. `requests.cpu`
+/// at `500m` used of `2` hard.
+#[derive(Debug, Clone)]
+pub struct QuotaUsage {
+  pub resource: String,
+  pub used: String,
+  pub hard: String,
+}
+
+impl QuotaUsage {
+  /// Share of the hard limit in use, clamped to 0..=1. `None` when the
+  /// hard limit is zero or can't be parsed.
+  pub fn fraction(&self) -> Option<f64> {
+    let hard = quota_quantity(&self.resource, &self.hard);
+    if hard <= 0.0 {
+      return None;
+    }
+    Some((quota_quantity(&self.resource, &self.used) / hard).clamp(0.0, 1.0))
+  }
+
+  /// True once usage has reached the hard limit, the point where the
+  /// API server starts rejecting new pods in the namespace.
+  pub fn is_exhausted(&self) -> bool {
+    self.fraction().is_some_and(|f| f >= 1.0)
+  }
+}
+
+/// Numeric value of a quota quantity in the resource's natural unit:
+/// millicores for CPU, bytes for memory and storage, plain counts for
+/// object quotas like `pods` or `count/deployments.apps`.
+#[allow(clippy::cast_precision_loss)]
+fn quota_quantity(resource: &str, value: &str) -> f64 {
+  if resource.ends_with("cpu") {
+    parse_cpu_millicores(value)
+  } else if resource.contains("memory") || resource.contains("storage") {
+    parse_mem_bytes(value) as f64
+  } else {
+    value.trim().parse::<f64>().unwrap_or(0.0)
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResourceQuotaInfo {
+  pub name: String,
+  pub usage: Vec<QuotaUsage>,
+}
+
+impl ResourceQuotaInfo {
+  pub fn from_quota(quota: &k8s_openapi::api::core::v1::ResourceQuota) -> Self {
+    let name = quota.metadata.name.clone().unwrap_or_default();
+    let status = quota.status.as_ref();
+    // status.hard mirrors spec.hard once the quota controller has synced;
+    // fall back to the spec for a quota created moments ago.
+    let hard = status
+      .and_then(|s| s.hard.as_ref())
+      .or_else(|| quota.spec.as_ref().and_then(|s| s.hard.as_ref()));
+    let used = status.and_then(|s| s.used.as_ref());
+    let usage = hard
+      .map(|hard| {
+        hard
+          .iter()
+          .map(|(resource, limit)| QuotaUsage {
+            resource: resource.clone(),
+            used: used
+              .and_then(|u| u.get(resource))
+              .map_or_else(|| "0".to_string(), |q| q.0.clone()),
+            hard: limit.0.clone(),
+          })
+          .collect()
+      })
+      .unwrap_or_default();
+    Self { name, usage }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -1136,6 +1466,7 @@ mod tests {
       service_type: "NodePort".to_string(),
       cluster_ip: Some("10.96.0.1".to_string()),
       external_ips: vec![],
+      load_balancer_ingress: vec![],
       ports: vec![
         ServicePortInfo {
           name: Some("http".to_string()),
@@ -1159,6 +1490,35 @@ mod tests {
     assert_eq!(svc.ports_display(), "80:30080/TCP, 443:8443/TCP");
   }
 
+  #[test]
+  fn test_service_load_balancer_url() {
+    let mut svc = ServiceInfo {
+      name: "web".to_string(),
+      namespace: "default".to_string(),
+      service_type: "LoadBalancer".to_string(),
+      cluster_ip: Some("10.96.0.3".to_string()),
+      external_ips: vec![],
+      load_balancer_ingress: vec![],
+      ports: vec![ServicePortInfo {
+        name: None,
+        protocol: "TCP".to_string(),
+        port: 8080,
+        target_port: "8080".to_string(),
+        node_port: Some(30080),
+      }],
+      selector: HashMap::new(),
+      age: "1h".to_string(),
+      labels: HashMap::new(),
+    };
+    assert_eq!(svc.load_balancer_url(), None);
+
+    svc.load_balancer_ingress = vec!["lb.example.com".to_string()];
+    assert_eq!(svc.load_balancer_url().as_deref(), Some("http://lb.example.com:8080"));
+
+    svc.ports[0].port = 443;
+    assert_eq!(svc.load_balancer_url().as_deref(), Some("https://lb.example.com"));
+  }
+
   #[test]
   fn test_service_info_ports_display_no_node_port() {
     let svc = ServiceInfo {
@@ -1167,6 +1527,7 @@ mod tests {
       service_type: "ClusterIP".to_string(),
       cluster_ip: Some("10.96.0.2".to_string()),
       external_ips: vec![],
+      load_balancer_ingress: vec![],
       ports: vec![ServicePortInfo {
         name: None,
         protocol: "TCP".to_string(),
@@ -1190,9 +1551,13 @@ mod tests {
       ready_replicas: 2,
       updated_replicas: 3,
       available_replicas: 2,
+      unavailable_replicas: 1,
+      unavailable_since: None,
+      stalled_message: None,
       age: "1d".to_string(),
       labels: HashMap::new(),
       images: vec!["nginx:latest".to_string()],
+      containers: Vec::new(),
     };
     assert_eq!(dep.ready_display(), "2/3");
   }
@@ -1206,14 +1571,67 @@ mod tests {
       ready_replicas: 5,
       updated_replicas: 5,
       available_replicas: 5,
+      unavailable_replicas: 0,
+      unavailable_since: None,
+      stalled_message: None,
       age: "2d".to_string(),
       labels: HashMap::from([("app".to_string(), "web".to_string())]),
       images: vec!["app:v1".to_string(), "sidecar:v1".to_string()],
+      containers: Vec::new(),
     };
     assert_eq!(dep.ready_display(), "5/5");
     assert_eq!(dep.images.len(), 2);
   }
 
+  #[test]
+  fn test_deployment_info_containers() {
+    let dep: k8s_openapi::api::apps::v1::Deployment = serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "namespace": "prod" },
+      "spec": {
+        "selector": {},
+        "template": {
+          "spec": {
+            "containers": [
+              {
+                "name": "app",
+                "image": "app:v2",
+                "env": [
+                  { "name": "MODE", "value": "prod" },
+                  { "name": "EMPTY" },
+                  { "name": "TOKEN", "valueFrom": { "secretKeyRef": { "name": "creds", "key": "token" } } }
+                ]
+              },
+              { "name": "sidecar" }
+            ]
+          }
+        }
+      }
+    }))
+    .unwrap();
+
+    let info = DeploymentInfo::from_deployment(&dep);
+    assert_eq!(info.images, vec!["app:v2"]);
+    assert_eq!(
+      info.containers,
+      vec![
+        DeploymentContainer {
+          name: "app".to_string(),
+          image: "app:v2".to_string(),
+          // valueFrom entries can't be edited as text and are left out
+          env: vec![
+            ("MODE".to_string(), "prod".to_string()),
+            ("EMPTY".to_string(), String::new()),
+          ],
+        },
+        DeploymentContainer {
+          name: "sidecar".to_string(),
+          image: String::new(),
+          env: Vec::new(),
+        },
+      ]
+    );
+  }
+
   // Edge case tests
 
   #[test]
@@ -1233,6 +1651,7 @@ mod tests {
       service_type: "ClusterIP".to_string(),
       cluster_ip: None, // Headless service
       external_ips: vec![],
+      load_balancer_ingress: vec![],
       ports: vec![], // No ports defined
       selector: HashMap::new(),
       age: "1h".to_string(),
@@ -1251,11 +1670,55 @@ mod tests {
       ready_replicas: 0,
       updated_replicas: 0,
       available_replicas: 0,
+      unavailable_replicas: 0,
+      unavailable_since: None,
+      stalled_message: None,
       age: "1h".to_string(),
       labels: HashMap::new(),
       images: vec!["app:v1".to_string()],
+      containers: Vec::new(),
     };
     assert_eq!(dep.ready_display(), "0/0");
+    assert_eq!(dep.health(Utc::now()), DeploymentHealth::ScaledDown);
+  }
+
+  #[test]
+  fn test_deployment_health() {
+    let now = Utc::now();
+    let mut dep = DeploymentInfo {
+      name: "web".to_string(),
+      namespace: "default".to_string(),
+      replicas: 3,
+      ready_replicas: 3,
+      updated_replicas: 3,
+      available_replicas: 3,
+      unavailable_replicas: 0,
+      unavailable_since: None,
+      stalled_message: None,
+      age: "1h".to_string(),
+      labels: HashMap::new(),
+      images: Vec::new(),
+      containers: Vec::new(),
+    };
+    assert_eq!(dep.health(now), DeploymentHealth::Healthy);
+
+    dep.ready_replicas = 1;
+    dep.available_replicas = 1;
+    dep.unavailable_replicas = 2;
+    dep.unavailable_since = Some(now - chrono::Duration::seconds(30));
+    assert_eq!(dep.health(now), DeploymentHealth::Progressing);
+
+    dep.unavailable_since = Some(now - chrono::Duration::minutes(10));
+    assert_eq!(dep.health(now), DeploymentHealth::Unavailable);
+
+    dep.unavailable_since = None;
+    dep.stalled_message = Some("ReplicaSet \"web-1\" has timed out progressing.".to_string());
+    assert_eq!(dep.health(now), DeploymentHealth::Unavailable);
+    assert!(
+      dep
+        .health_explanation(&DeploymentHealth::Unavailable)
+        .starts_with("Rollout stalled")
+    );
   }
 
   #[test]
@@ -1310,4 +1773,111 @@ mod tests {
     assert_eq!(pod.restarts, 5);
     assert!(pod.phase.is_running());
   }
+
+  fn rollout_deployment(generation: i64, status: &serde_json::Value) -> k8s_openapi::api::apps::v1::Deployment {
+    serde_json::from_value(serde_json::json!({
+      "metadata": { "name": "web", "generation": generation },
+      "spec": {
+        "replicas": 3,
+        "selector": {},
+        "template": {}
+      },
+      "status": status.clone()
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_rollout_status_waits_for_observed_generation() {
+    let dep = rollout_deployment(
+      2,
+      &serde_json::json!({ "observedGeneration": 1, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 3 }),
+    );
+    assert!(matches!(
+      RolloutStatus::from_deployment(&dep),
+      RolloutStatus::Progressing { .. }
+    ));
+  }
+
+  #[test]
+  fn test_rollout_status_progressing() {
+    let dep = rollout_deployment(
+      2,
+      &serde_json::json!({ "observedGeneration": 2, "replicas": 4, "updatedReplicas": 1, "availableReplicas": 3 }),
+    );
+    let status = RolloutStatus::from_deployment(&dep);
+    assert_eq!(
+      status,
+      RolloutStatus::Progressing {
+        ready: 1,
+        desired: 3,
+        message: "1/3 pods updated".to_string(),
+      }
+    );
+    assert!((status.progress() - 1.0 / 3.0).abs() < f32::EPSILON);
+
+    let dep = rollout_deployment(
+      2,
+      &serde_json::json!({ "observedGeneration": 2, "replicas": 4, "updatedReplicas": 3, "availableReplicas": 3 }),
+    );
+    assert!(matches!(
+      RolloutStatus::from_deployment(&dep),
+      RolloutStatus::Progressing { message, .. } if message == "1 old pods pending termination"
+    ));
+  }
+
+  #[test]
+  fn test_rollout_status_complete() {
+    let dep = rollout_deployment(
+      2,
+      &serde_json::json!({ "observedGeneration": 2, "replicas": 3, "updatedReplicas": 3, "availableReplicas": 3 }),
+    );
+    let status = RolloutStatus::from_deployment(&dep);
+    assert_eq!(status, RolloutStatus::Complete { desired: 3 });
+    assert!((status.progress() - 1.0).abs() < f32::EPSILON);
+  }
+
+  #[test]
+  fn test_rollout_status_deadline_exceeded() {
+    let dep = rollout_deployment(
+      2,
+      &serde_json::json!({
+        "observedGeneration": 2,
+        "replicas": 4,
+        "updatedReplicas": 1,
+        "conditions": [{
+          "type": "Progressing",
+          "status": "False",
+          "reason": "ProgressDeadlineExceeded",
+          "message": "ReplicaSet \"web-abc\" has timed out progressing."
+        }]
+      }),
+    );
+    assert!(matches!(
+      RolloutStatus::from_deployment(&dep),
+      RolloutStatus::Failed(msg) if msg.contains("timed out")
+    ));
+  }
+
+  #[test]
+  fn test_quota_usage_fraction() {
+    let usage = |resource: &str, used: &str, hard: &str| QuotaUsage {
+      resource: resource.to_string(),
+      used: used.to_string(),
+      hard: hard.to_string(),
+    };
+    assert_eq!(usage("requests.cpu", "500m", "2").fraction(), Some(0.25));
+    assert_eq!(usage("limits.memory", "1Gi", "4Gi").fraction(), Some(0.25));
+    assert_eq!(usage("pods", "10", "10").fraction(), Some(1.0));
+    assert!(usage("pods", "10", "10").is_exhausted());
+    assert!(!usage("count/services", "1", "5").is_exhausted());
+    assert_eq!(usage("pods", "3", "0").fraction(), None);
+  }
+
+  #[test]
+  fn test_format_millicores() {
+    assert_eq!(format_millicores(0.4), "0m");
+    assert_eq!(format_millicores(250.0), "250m");
+    assert_eq!(format_millicores(1500.0), "1.50 cores");
+  }
 }
@@ -17,26 +17,19 @@ mod tray;
 mod ui;
 mod utils;
 
-use std::path::PathBuf;
-use std::rc::Rc;
 #[cfg(not(target_os = "linux"))]
 use std::time::Duration;
 
 #[cfg(not(target_os = "linux"))]
 use gpui::Timer;
-use gpui::{
-  App, AppContext, Bounds, SharedString, TitlebarOptions, WindowBounds, WindowHandle, WindowOptions, px, size,
-};
-use gpui_component::{
-  Root,
-  theme::{Theme, ThemeConfig, ThemeRegistry, ThemeSet},
-};
+use gpui::{App, AppContext, Bounds, TitlebarOptions, WindowBounds, WindowHandle, WindowOptions, px, size};
+use gpui_component::Root;
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
 use platform::Platform;
-use state::AppSettings;
 #[cfg(not(target_os = "linux"))]
 use state::CurrentView;
+use state::{AppSettings, ThemeCatalog};
 #[cfg(not(target_os = "linux"))]
 use tray::{AppTray, menu_ids};
 
@@ -61,14 +54,46 @@ fn get_titlebar_options() -> TitlebarOptions {
   }
 }
 
+/// Bounds to open the main window with: the saved geometry when it still
+/// lands on a connected display, otherwise a centered default.
+fn initial_window_bounds(cx: &App) -> WindowBounds {
+  let saved = state::settings_state(cx).read(cx).settings.window_bounds;
+  let on_screen = saved.filter(|saved| {
+    cx.displays().iter().any(|display| {
+      let area = display.bounds();
+      saved.visible_on(
+        f32::from(area.origin.x),
+        f32::from(area.origin.y),
+        f32::from(area.size.width),
+        f32::from(area.size.height),
+      )
+    })
+  });
+
+  match on_screen {
+    Some(saved) => {
+      let bounds = Bounds::new(
+        gpui::point(px(saved.x), px(saved.y)),
+        size(px(saved.width), px(saved.height)),
+      );
+      if saved.maximized {
+        WindowBounds::Maximized(bounds)
+      } else {
+        WindowBounds::Windowed(bounds)
+      }
+    }
+    None => WindowBounds::Windowed(Bounds::centered(None, size(px(1200.), px(800.)), cx)),
+  }
+}
+
 /// Open the main application window
 fn open_main_window(cx: &mut App) -> WindowHandle<Root> {
-  let bounds = Bounds::centered(None, size(px(1200.), px(800.)), cx);
+  let window_bounds = initial_window_bounds(cx);
 
   let handle = cx
     .open_window(
       WindowOptions {
-        window_bounds: Some(WindowBounds::Windowed(bounds)),
+        window_bounds: Some(window_bounds),
         titlebar: Some(get_titlebar_options()),
         ..Default::default()
       },
@@ -85,94 +110,6 @@ fn open_main_window(cx: &mut App) -> WindowHandle<Root> {
   handle
 }
 
-/// Get the themes directory path. Probes (in priority order):
-///
-/// 1. `$DOCKSIDE_THEMES_DIR` — explicit override.
-/// 2. `<exe-parent>/../Resources/themes` — macOS `.app` bundle.
-/// 3. `<exe-parent>/../lib/dockside/themes` — Linux `.deb` / `.pacman` /
-///    `AppImage` layout where cargo-packager places `resources`.
-/// 4. `<exe-parent>/themes` — portable tarball / dev `target/release` /
-///    sibling-of-binary case.
-/// 5. `/usr/lib/dockside/themes` — absolute fallback when the package
-///    binary somehow lacks a usable parent (e.g. symlinked into
-///    `~/.local/bin`).
-/// 6. `/usr/share/dockside/themes` — alternate FHS share path.
-/// 7. `./themes` — dev `cargo run` from the repo root.
-fn get_themes_dir() -> Option<PathBuf> {
-  if let Ok(env_dir) = std::env::var("DOCKSIDE_THEMES_DIR") {
-    let path = PathBuf::from(env_dir);
-    if path.exists() {
-      return Some(path);
-    }
-  }
-
-  if let Ok(exe_path) = std::env::current_exe()
-    && let Some(parent) = exe_path.parent()
-  {
-    // macOS .app bundle: Contents/MacOS/dockside -> Contents/Resources/themes.
-    if let Some(grand) = parent.parent() {
-      let resources = grand.join("Resources").join("themes");
-      if resources.exists() {
-        return Some(resources);
-      }
-      // Linux package layout: /usr/bin/dockside -> /usr/lib/dockside/themes.
-      let lib = grand.join("lib").join("dockside").join("themes");
-      if lib.exists() {
-        return Some(lib);
-      }
-    }
-    // Sibling: /tarball/dockside -> /tarball/themes.
-    let sibling = parent.join("themes");
-    if sibling.exists() {
-      return Some(sibling);
-    }
-  }
-
-  for absolute in ["/usr/lib/dockside/themes", "/usr/share/dockside/themes"] {
-    let path = PathBuf::from(absolute);
-    if path.exists() {
-      return Some(path);
-    }
-  }
-
-  let dev_themes = PathBuf::from("./themes");
-  if dev_themes.exists() {
-    return Some(dev_themes);
-  }
-
-  None
-}
-
-/// Load a specific theme from JSON files synchronously
-fn load_theme_sync(themes_dir: &PathBuf, theme_name: &str) -> Option<Rc<ThemeConfig>> {
-  if !themes_dir.exists() {
-    return None;
-  }
-
-  // Read all JSON files in themes directory
-  let entries = std::fs::read_dir(themes_dir).ok()?;
-
-  for entry in entries.flatten() {
-    let path = entry.path();
-    if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("json") {
-      continue;
-    }
-    let Ok(content) = std::fs::read_to_string(&path) else {
-      continue;
-    };
-    let Ok(theme_set) = serde_json::from_str::<ThemeSet>(&content) else {
-      continue;
-    };
-    for theme in theme_set.themes {
-      if theme.name == theme_name {
-        return Some(Rc::new(theme));
-      }
-    }
-  }
-
-  None
-}
-
 fn main() {
   // Default: info-level for our own crate, drop noisy third-party trace
   // streams (calloop event loop, blade GPU resource churn, Wayland/X11
@@ -222,37 +159,22 @@ fn main() {
     // Initialize gpui-component
     gpui_component::init(cx);
 
-    // Load saved settings to get the user's preferred theme
-    let settings = AppSettings::load();
-    let saved_theme_name = settings.theme.theme_name().to_string();
-
-    // Load and apply theme SYNCHRONOUSLY before window opens (prevents flicker)
-    if let Some(themes_dir) = get_themes_dir() {
-      // Load saved theme immediately
-      if let Some(theme_config) = load_theme_sync(&themes_dir, &saved_theme_name) {
-        Theme::global_mut(cx).apply_config(&theme_config);
-      }
-
-      // Also set up file watcher for hot reload — the callback re-reads
-      // the *current* settings every time so it tracks theme changes
-      // made through the Settings view.
-      if let Err(err) = ThemeRegistry::watch_dir(themes_dir, cx, |cx| {
-        let current = AppSettings::load().theme.theme_name().to_string();
-        let key = SharedString::from(current);
-        if let Some(theme) = ThemeRegistry::global(cx).themes().get(&key).cloned() {
-          Theme::global_mut(cx).apply_config(&theme);
-          cx.refresh_windows();
-        }
-      }) {
-        tracing::warn!("Failed to watch themes directory: {}", err);
-      }
-    } else {
-      tracing::warn!("Themes directory not found");
+    // Find bundled and imported themes, then apply the saved one
+    // SYNCHRONOUSLY before the window opens (prevents flicker).
+    let catalog = ThemeCatalog::discover();
+    if catalog.count() == 0 {
+      tracing::warn!("No themes found");
     }
+    let settings = AppSettings::load();
+    cx.set_global(catalog);
+    state::apply_active_theme(&settings, cx.window_appearance(), cx);
 
     // Initialize global services
     services::init_services(cx);
 
+    // Hot-reload theme files edited, added or removed on disk
+    services::watch_theme_dirs(cx);
+
     // Register keyboard shortcuts
     keybindings::register_keybindings(cx);
 
@@ -274,12 +196,24 @@ fn main() {
     // Restore the persisted kubeconfig context + populate the switcher.
     services::bootstrap_kube_contexts(cx);
 
+    // Populate the Docker context switcher.
+    services::list_docker_contexts(cx);
+
     // Start real-time resource watchers for automatic UI updates
     services::start_watchers(cx);
 
+    // Retry the Docker connection in the background until the daemon is up
+    services::start_connection_monitor(cx);
+
     // Open the main window
     open_main_window(cx);
 
+    // Pick up where the user left off, unless that view's sidebar group
+    // has since been hidden
+    if let Some(view) = settings.last_view.filter(|view| settings.view_visible(*view)) {
+      services::set_view(view, cx);
+    }
+
     // Tray icon is disabled on Linux for now: gpui 0.2 stops the
     // calloop event loop the moment `state.windows.is_empty()`
     // (`gpui-0.2.2/src/platform/linux/wayland/client.rs:387-389`),
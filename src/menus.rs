@@ -7,7 +7,7 @@ use gpui::{Menu, MenuItem, actions};
 use crate::keybindings::{
   FocusSearch, GoToActivityMonitor, GoToCompose, GoToContainers, GoToDeployments, GoToImages, GoToMachines,
   GoToNetworks, GoToPods, GoToServices, GoToSettings, GoToVolumes, NewResource, OpenCommandPalette, Refresh,
-  ShowKeyboardShortcuts,
+  ShowKeyboardShortcuts, ToggleSidebar,
 };
 
 // Actions for menu items
@@ -46,6 +46,7 @@ pub fn app_menus() -> Vec<Menu> {
       name: "View".into(),
       items: vec![
         MenuItem::action("Command Palette...", OpenCommandPalette),
+        MenuItem::action("Toggle Sidebar", ToggleSidebar),
         MenuItem::separator(),
         MenuItem::submenu(Menu {
           name: "Docker".into(),
@@ -0,0 +1,133 @@
+//! Periodic refresh for views on daemons or clusters without a reliable
+//! event stream.
+//!
+//! Each view owns its timer. The timer ticks once a second and re-reads the
+//! settings on every tick, so a saved interval restarts it right away and
+//! `auto_refresh_paused` stops it without tearing anything down.
+
+use std::time::Duration;
+
+use gpui::{App, Context, Timer};
+
+use crate::state::{AppSettings, SettingsChanged, settings_state};
+
+use super::core::{DispatcherEvent, dispatcher};
+
+/// Granularity of the refresh timers
+const TICK: Duration = Duration::from_secs(1);
+
+/// Which configured interval drives a timer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefreshInterval {
+  /// `container_refresh_interval` - resource lists
+  Lists,
+  /// `stats_refresh_interval` - usage figures
+  Stats,
+}
+
+impl RefreshInterval {
+  fn secs(self, settings: &AppSettings) -> u64 {
+    match self {
+      RefreshInterval::Lists => settings.container_refresh_interval,
+      RefreshInterval::Stats => settings.stats_refresh_interval,
+    }
+  }
+}
+
+/// Countdown for one timer. The interval it was started with is kept so a
+/// changed setting restarts the count instead of firing late or early.
+#[derive(Debug, Default)]
+struct RefreshTimer {
+  interval: u64,
+  elapsed: u64,
+}
+
+impl RefreshTimer {
+  /// Advance by one tick. Returns true when the refresh should run.
+  fn tick(&mut self, interval: u64, paused: bool) -> bool {
+    if paused || interval != self.interval {
+      self.interval = interval;
+      self.elapsed = 0;
+      return false;
+    }
+    self.elapsed += 1;
+    if self.elapsed >= interval {
+      self.elapsed = 0;
+      return true;
+    }
+    false
+  }
+}
+
+/// Run `refresh` every `kind` interval (never faster than `min_secs`) until
+/// the owning view is dropped. Skipped while auto-refresh is paused.
+pub fn spawn_auto_refresh<T: 'static>(
+  kind: RefreshInterval,
+  min_secs: u64,
+  cx: &mut Context<'_, T>,
+  refresh: impl Fn(&mut T, &mut Context<'_, T>) + 'static,
+) {
+  cx.spawn(async move |this, cx| {
+    let mut timer = RefreshTimer::default();
+    loop {
+      Timer::after(TICK).await;
+      let updated = this.update(cx, |view, cx| {
+        let settings = settings_state(cx);
+        let settings = &settings.read(cx).settings;
+        let interval = kind.secs(settings).max(min_secs).max(1);
+        if timer.tick(interval, settings.auto_refresh_paused) {
+          refresh(view, cx);
+        }
+      });
+      if updated.is_err() {
+        break;
+      }
+    }
+  })
+  .detach();
+}
+
+/// Pause or resume every auto-refresh timer and persist the choice.
+pub fn toggle_auto_refresh(cx: &mut App) {
+  let paused = settings_state(cx).update(cx, |state, cx| {
+    state.settings.auto_refresh_paused = !state.settings.auto_refresh_paused;
+    let _ = state.settings.save();
+    cx.emit(SettingsChanged::SettingsUpdated);
+    state.settings.auto_refresh_paused
+  });
+  dispatcher(cx).update(cx, |_, cx| {
+    cx.emit(DispatcherEvent::TaskCompleted {
+      message: if paused {
+        "Auto-refresh paused".to_string()
+      } else {
+        "Auto-refresh resumed".to_string()
+      },
+    });
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_refresh_timer() {
+    let mut timer = RefreshTimer::default();
+    // First tick adopts the interval
+    assert!(!timer.tick(3, false));
+    assert!(!timer.tick(3, false));
+    assert!(!timer.tick(3, false));
+    assert!(timer.tick(3, false));
+
+    // Changing the interval restarts the count
+    assert!(!timer.tick(3, false));
+    assert!(!timer.tick(2, false));
+    assert!(!timer.tick(2, false));
+    assert!(timer.tick(2, false));
+
+    // Paused never fires and restarts on resume
+    assert!(!timer.tick(1, true));
+    assert!(!timer.tick(1, true));
+    assert!(timer.tick(1, false));
+  }
+}
@@ -1,14 +1,23 @@
 //! Kubernetes control operations (start/stop/reset on Colima)
 
+use std::io::{BufRead, BufReader};
+use std::process::Stdio;
+use std::time::Duration;
+
 use gpui::App;
 
 use crate::colima::ColimaClient;
-use crate::services::{TaskStage, advance_stage, complete_task, fail_task, start_staged_task, start_task};
+use crate::services::{
+  TaskStage, advance_stage, complete_task, fail_task, log_command, set_task_progress, shell_quote, start_staged_task,
+  start_task,
+};
 use crate::state::{StateChanged, docker_state};
 use crate::utils::{colima_cmd, kubectl_cmd};
 
 use super::super::core::{DispatcherEvent, dispatcher};
-use super::super::kubernetes::{refresh_deployments, refresh_namespaces, refresh_pods, refresh_services};
+use super::super::kubernetes::{
+  refresh_deployments, refresh_kube_contexts, refresh_namespaces, refresh_pods, refresh_services, set_kube_context,
+};
 
 /// Switch kubectl context (async, non-blocking)
 pub fn switch_kubectl_context(context: String, cx: &mut App) {
@@ -32,16 +41,10 @@ pub fn switch_kubectl_context(context: String, cx: &mut App) {
     cx.update(|cx| match result {
       Ok(()) => {
         complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Switched to context '{ctx}'"),
-          });
-        });
-        // Refresh K8s data with new context
-        refresh_pods(cx);
-        refresh_namespaces(cx);
-        refresh_services(cx);
-        refresh_deployments(cx);
+        // Point the app's context selector at it too; that reloads every
+        // k8s view and reports the switch
+        set_kube_context(ctx, cx);
+        refresh_kube_contexts(cx);
       }
       Err(e) => {
         fail_task(cx, task_id, e.clone());
@@ -196,50 +199,160 @@ pub fn kubernetes_stop(name: String, cx: &mut App) {
   .detach();
 }
 
-/// Reset Kubernetes on a Colima machine (delete and recreate cluster)
+/// How many times to poll the API server after a reset before giving up.
+const RESET_READY_ATTEMPTS: u32 = 30;
+
+/// kubectl context Colima writes for a profile.
+fn colima_kube_context(name: &str) -> String {
+  if name == "default" {
+    "colima".to_string()
+  } else {
+    format!("colima-{name}")
+  }
+}
+
+/// Pull the human-readable part out of a Colima log line
+/// (`time=... level=info msg="..."`); other lines pass through trimmed.
+fn colima_log_message(line: &str) -> String {
+  match line.split_once("msg=") {
+    Some((_, msg)) => msg.trim().trim_matches('"').replace("\\\"", "\""),
+    None => line.trim().to_string(),
+  }
+}
+
+/// Reset Kubernetes on a Colima machine (delete and recreate cluster).
+///
+/// Streams Colima's output into the task status, then waits for the API
+/// server to answer and refreshes the cached K8s resources. A reset that
+/// succeeds but leaves the API unreachable is reported separately from a
+/// failed reset.
 pub fn kubernetes_reset(name: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Resetting K8s on '{name}'..."));
-  let name_clone = name.clone();
+  log_command(cx, format!("colima kubernetes reset --profile {}", shell_quote(&name)));
+  let stages = vec![
+    TaskStage::new(format!("Resetting K8s on '{name}'...")),
+    TaskStage::new(format!("Waiting for K8s API on '{name}'...")),
+    TaskStage::new("Refreshing Kubernetes resources..."),
+  ];
+  let task_id = start_staged_task(cx, format!("Resetting K8s on '{name}'"), stages);
   let disp = dispatcher(cx);
 
+  // Colima progress lines, forwarded to the task status as they arrive.
+  let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<String>();
   cx.spawn(async move |cx| {
-    let result = cx
+    while let Some(line) = rx.recv().await {
+      let _ = cx.update(|cx| set_task_progress(cx, task_id, 0.0, Some(line)));
+    }
+  })
+  .detach();
+
+  cx.spawn(async move |cx| {
+    // Stage 0: run the reset, streaming stderr (where Colima logs)
+    let reset_result = cx
       .background_executor()
-      .spawn(async move {
-        let profile = if name == "default" { None } else { Some(name.as_str()) };
-        let mut cmd = colima_cmd();
-        cmd.arg("kubernetes").arg("reset");
-        if let Some(p) = profile {
-          cmd.arg("--profile").arg(p);
-        }
-        let output = cmd.output()?;
-        if output.status.success() {
-          Ok(())
-        } else {
-          Err(anyhow::anyhow!("{}", String::from_utf8_lossy(&output.stderr)))
+      .spawn({
+        let name = name.clone();
+        async move {
+          let mut cmd = colima_cmd();
+          cmd.args(["kubernetes", "reset"]);
+          if name != "default" {
+            cmd.arg("--profile").arg(&name);
+          }
+          let mut child = cmd.stdout(Stdio::null()).stderr(Stdio::piped()).spawn()?;
+          let mut tail = Vec::new();
+          if let Some(stderr) = child.stderr.take() {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+              let msg = colima_log_message(&line);
+              if msg.is_empty() {
+                continue;
+              }
+              let _ = tx.send(msg.clone());
+              tail.push(msg);
+            }
+          }
+          if child.wait()?.success() {
+            Ok(())
+          } else {
+            let start = tail.len().saturating_sub(3);
+            Err(anyhow::anyhow!("{}", tail[start..].join("; ")))
+          }
         }
       })
       .await;
 
-    cx.update(|cx| match result {
-      Ok(()) => {
-        complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Kubernetes reset on '{name_clone}'"),
-          });
-        });
-        refresh_pods(cx);
-      }
-      Err(e) => {
+    if let Err(e) = reset_result {
+      cx.update(|cx| {
         fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to reset K8s on '{name_clone}': {e}"),
+            error: format!("Failed to reset K8s on '{name}': {e}"),
           });
         });
+      })
+      .ok();
+      return;
+    }
+
+    // Stage 1: wait for the recreated API server to answer
+    cx.update(|cx| advance_stage(cx, task_id)).ok();
+    let context = colima_kube_context(&name);
+    let mut ready = Err(String::from("no response"));
+    for attempt in 1..=RESET_READY_ATTEMPTS {
+      let context = context.clone();
+      ready = cx
+        .background_executor()
+        .spawn(async move {
+          match kubectl_cmd()
+            .args(["--context", &context, "get", "--raw", "/readyz"])
+            .output()
+          {
+            Ok(o) if o.status.success() => Ok(()),
+            Ok(o) => Err(String::from_utf8_lossy(&o.stderr).trim().to_string()),
+            Err(e) => Err(e.to_string()),
+          }
+        })
+        .await;
+      if ready.is_ok() {
+        break;
+      }
+      cx.update(|cx| {
+        set_task_progress(
+          cx,
+          task_id,
+          0.0,
+          Some(format!("Waiting for K8s API ({attempt}/{RESET_READY_ATTEMPTS})...")),
+        );
+      })
+      .ok();
+      cx.background_executor().timer(Duration::from_secs(2)).await;
+    }
+
+    // Stage 2: refresh whatever the UI has cached for the old cluster
+    cx.update(|cx| {
+      advance_stage(cx, task_id);
+      refresh_pods(cx);
+      refresh_namespaces(cx);
+      refresh_services(cx);
+      refresh_deployments(cx);
+      match ready {
+        Ok(()) => {
+          complete_task(cx, task_id);
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskCompleted {
+              message: format!("Kubernetes reset on '{name}'; the cluster is empty and ready"),
+            });
+          });
+        }
+        Err(e) => {
+          fail_task(cx, task_id, e.clone());
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskFailed {
+              error: format!("Kubernetes on '{name}' was reset, but its API server is not responding: {e}"),
+            });
+          });
+        }
       }
     })
+    .ok();
   })
   .detach();
 }
@@ -290,9 +403,7 @@ pub fn enable_kubernetes(name: String, cx: &mut App) {
     cx.update(|cx| advance_stage(cx, task_id)).ok();
 
     // Brief pause to let Colima release resources
-    cx.background_executor()
-      .timer(std::time::Duration::from_millis(500))
-      .await;
+    cx.background_executor().timer(Duration::from_millis(500)).await;
 
     let start_result = cx
       .background_executor()
@@ -366,3 +477,23 @@ pub fn enable_kubernetes(name: String, cx: &mut App) {
   })
   .detach();
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_colima_kube_context() {
+    assert_eq!(colima_kube_context("default"), "colima");
+    assert_eq!(colima_kube_context("dev"), "colima-dev");
+  }
+
+  #[test]
+  fn test_colima_log_message() {
+    assert_eq!(
+      colima_log_message(r#"time="2024-01-01T00:00:00Z" level=info msg="deleting kubernetes cluster ...""#),
+      "deleting kubernetes cluster ..."
+    );
+    assert_eq!(colima_log_message("  plain output \n"), "plain output");
+  }
+}
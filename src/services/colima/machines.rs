@@ -1,9 +1,13 @@
 //! Colima machine operations
 
-use gpui::App;
-
-use crate::colima::{ColimaClient, ColimaConfig};
-use crate::services::{TaskStage, advance_stage, complete_task, fail_task, start_staged_task, start_task};
+use futures::StreamExt;
+use gpui::{App, AsyncApp};
+
+use crate::colima::{ColimaClient, ColimaConfig, ColimaLogLine, Machine, MachineId, MachineUsage};
+use crate::services::{
+  TaskStage, advance_stage, complete_task, fail_task, log_command, set_task_stage, shell_quote, start_staged_task,
+  start_task,
+};
 use crate::state::{StateChanged, docker_state};
 use crate::utils::{docker_cmd, kubectl_cmd};
 
@@ -11,6 +15,50 @@ use super::super::core::{DispatcherEvent, dispatcher};
 use super::super::docker::refresh_containers;
 use super::super::kubernetes::{refresh_deployments, refresh_namespaces, refresh_pods, refresh_services};
 
+/// Which part of `colima start` a log line belongs to, used to move the
+/// staged task along as the VM boots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StartPhase {
+  DownloadImage,
+  Vm,
+  Runtime,
+  Kubernetes,
+}
+
+impl StartPhase {
+  fn of(line: &ColimaLogLine) -> Option<Self> {
+    match line.context.as_deref()? {
+      "vm" if line.message.contains("download") => Some(Self::DownloadImage),
+      "vm" => Some(Self::Vm),
+      "docker" | "containerd" | "incus" => Some(Self::Runtime),
+      "kubernetes" => Some(Self::Kubernetes),
+      _ => None,
+    }
+  }
+}
+
+/// Run a blocking `colima start` on the background executor and feed each
+/// log line to `on_line` on the main thread as it's printed.
+async fn stream_colima_start(
+  cx: &mut AsyncApp,
+  start: impl FnOnce(&mut dyn FnMut(&str)) -> anyhow::Result<()> + Send + 'static,
+  mut on_line: impl FnMut(&ColimaLogLine, &mut App),
+) -> anyhow::Result<()> {
+  let (tx, mut rx) = futures::channel::mpsc::unbounded::<String>();
+  let run = cx.background_executor().spawn(async move {
+    start(&mut |line: &str| {
+      let _ = tx.unbounded_send(line.to_string());
+    })
+  });
+  // Ends once `start` returns and drops the sender
+  while let Some(line) = rx.next().await {
+    if let Some(line) = ColimaLogLine::parse(&line) {
+      let _ = cx.update(|cx| on_line(&line, cx));
+    }
+  }
+  run.await
+}
+
 /// Create a new machine using the config file approach
 pub fn create_machine(profile: String, config: ColimaConfig, cx: &mut App) {
   let has_kubernetes = config.kubernetes.enabled;
@@ -28,6 +76,7 @@ pub fn create_machine(profile: String, config: ColimaConfig, cx: &mut App) {
   }
 
   stages.push(TaskStage::new("Verifying machine..."));
+  let verify_stage = stages.len() - 1;
 
   let task_id = start_staged_task(cx, format!("Creating '{profile}'"), stages);
   let profile_clone = profile.clone();
@@ -37,12 +86,32 @@ pub fn create_machine(profile: String, config: ColimaConfig, cx: &mut App) {
   let disp = dispatcher(cx);
 
   cx.spawn(async move |cx| {
-    let result = cx
-      .background_executor()
-      .spawn(async move {
-        // Start using config file approach
-        match ColimaClient::start_with_config(&profile, &config) {
-          Ok(()) => {
+    let start_result = stream_colima_start(
+      cx,
+      {
+        let profile = profile.clone();
+        move |on_line| ColimaClient::start_with_config_streaming(&profile, &config, on_line)
+      },
+      move |line, cx| {
+        // Stages only move forward, so 0 just updates the status text
+        let stage = match StartPhase::of(line) {
+          Some(StartPhase::DownloadImage) => 1,
+          Some(StartPhase::Vm) => 2,
+          Some(StartPhase::Runtime) => 3,
+          Some(StartPhase::Kubernetes) if has_kubernetes => 4,
+          _ => 0,
+        };
+        set_task_stage(cx, task_id, stage, Some(line.display()));
+      },
+    )
+    .await;
+
+    let result = match start_result {
+      Ok(()) => {
+        cx.update(|cx| set_task_stage(cx, task_id, verify_stage, None)).ok();
+        let vms = cx
+          .background_executor()
+          .spawn(async move {
             let vms = ColimaClient::list().unwrap_or_default();
 
             // If kubernetes is enabled, switch kubectl context
@@ -56,12 +125,13 @@ pub fn create_machine(profile: String, config: ColimaConfig, cx: &mut App) {
               let _ = kubectl_cmd().args(["config", "use-context", &kubectl_context]).output();
             }
 
-            Ok(vms)
-          }
-          Err(e) => Err(e.to_string()),
-        }
-      })
-      .await;
+            vms
+          })
+          .await;
+        Ok(vms)
+      }
+      Err(e) => Err(e.to_string()),
+    };
 
     cx.update(|cx| match result {
       Ok(vms) => {
@@ -156,14 +226,16 @@ pub fn edit_machine(profile: String, config: ColimaConfig, cx: &mut App) {
     // Stage 2: Start with new config
     cx.update(|cx| advance_stage(cx, task_id)).ok();
 
-    let start_result = cx
-      .background_executor()
-      .spawn({
+    let start_result = stream_colima_start(
+      cx,
+      {
         let profile = profile.clone();
         let config = config.clone();
-        async move { ColimaClient::start_with_config(&profile, &config) }
-      })
-      .await;
+        move |on_line| ColimaClient::start_with_config_streaming(&profile, &config, on_line)
+      },
+      move |line, cx| set_task_stage(cx, task_id, 2, Some(line.display())),
+    )
+    .await;
 
     if let Err(e) = start_result {
       cx.update(|cx| {
@@ -226,9 +298,27 @@ pub fn edit_machine(profile: String, config: ColimaConfig, cx: &mut App) {
   .detach();
 }
 
-/// Start an existing machine (uses existing config)
-pub fn start_machine(name: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Starting '{name}'..."));
+/// Start an existing machine (uses existing config). Returns the id of
+/// the start task so callers can follow its progress.
+pub fn start_machine(name: String, cx: &mut App) -> u64 {
+  log_command(cx, format!("colima start --profile {}", shell_quote(&name)));
+
+  // Kubernetes is known from the last list; the stage is skipped otherwise
+  let expects_k8s = docker_state(cx)
+    .read(cx)
+    .colima_vms()
+    .any(|vm| vm.name == name && vm.kubernetes);
+  let mut stages = vec![
+    TaskStage::new(format!("Booting '{name}'...")),
+    TaskStage::new("Starting runtime..."),
+  ];
+  if expects_k8s {
+    stages.push(TaskStage::new("Starting Kubernetes..."));
+  }
+  stages.push(TaskStage::new("Verifying machine..."));
+  let verify_stage = stages.len() - 1;
+
+  let task_id = start_staged_task(cx, format!("Starting '{name}'"), stages);
   let name_clone = name.clone();
   let name_for_context = name.clone();
 
@@ -236,12 +326,32 @@ pub fn start_machine(name: String, cx: &mut App) {
   let disp = dispatcher(cx);
 
   cx.spawn(async move |cx| {
-    let result = cx
-      .background_executor()
-      .spawn(async move {
-        let name_opt = if name == "default" { None } else { Some(name.as_str()) };
-        match ColimaClient::start_existing(name_opt) {
-          Ok(()) => {
+    let start_result = stream_colima_start(
+      cx,
+      {
+        let name = name.clone();
+        move |on_line| {
+          let name_opt = if name == "default" { None } else { Some(name.as_str()) };
+          ColimaClient::start_existing_streaming(name_opt, on_line)
+        }
+      },
+      move |line, cx| {
+        let stage = match StartPhase::of(line) {
+          Some(StartPhase::Runtime) => 1,
+          Some(StartPhase::Kubernetes) if expects_k8s => 2,
+          _ => 0,
+        };
+        set_task_stage(cx, task_id, stage, Some(line.display()));
+      },
+    )
+    .await;
+
+    let result = match start_result {
+      Ok(()) => {
+        cx.update(|cx| set_task_stage(cx, task_id, verify_stage, None)).ok();
+        let vms_and_k8s = cx
+          .background_executor()
+          .spawn(async move {
             let vms = ColimaClient::list().unwrap_or_default();
             // Check if the started machine has kubernetes enabled
             let has_k8s = vms.iter().any(|vm| vm.name == name && vm.kubernetes);
@@ -257,12 +367,13 @@ pub fn start_machine(name: String, cx: &mut App) {
               let _ = kubectl_cmd().args(["config", "use-context", &kubectl_context]).output();
             }
 
-            Ok((vms, has_k8s))
-          }
-          Err(e) => Err(e.to_string()),
-        }
-      })
-      .await;
+            (vms, has_k8s)
+          })
+          .await;
+        Ok(vms_and_k8s)
+      }
+      Err(e) => Err(e.to_string()),
+    };
 
     cx.update(|cx| match result {
       Ok((vms, has_k8s)) => {
@@ -295,9 +406,12 @@ pub fn start_machine(name: String, cx: &mut App) {
     })
   })
   .detach();
+
+  task_id
 }
 
 pub fn stop_machine(name: String, cx: &mut App) {
+  log_command(cx, format!("colima stop --profile {}", shell_quote(&name)));
   let task_id = start_task(cx, format!("Stopping '{name}'..."));
   let name_clone = name.clone();
 
@@ -343,6 +457,7 @@ pub fn stop_machine(name: String, cx: &mut App) {
 }
 
 pub fn restart_machine(name: String, cx: &mut App) {
+  log_command(cx, format!("colima restart --profile {}", shell_quote(&name)));
   let task_id = start_task(cx, format!("Restarting '{name}'..."));
   let name_clone = name.clone();
   let name_for_context = name.clone();
@@ -412,10 +527,11 @@ pub fn restart_machine(name: String, cx: &mut App) {
   .detach();
 }
 
-/// Start Colima with optional profile name (None = default profile)
-pub fn start_colima(profile: Option<&str>, cx: &mut App) {
+/// Start Colima with optional profile name (None = default profile).
+/// Returns the id of the start task.
+pub fn start_colima(profile: Option<&str>, cx: &mut App) -> u64 {
   let name = profile.unwrap_or("default").to_string();
-  start_machine(name, cx);
+  start_machine(name, cx)
 }
 
 /// Stop Colima with optional profile name (None = default profile)
@@ -431,6 +547,7 @@ pub fn restart_colima(profile: Option<&str>, cx: &mut App) {
 }
 
 pub fn delete_machine(name: String, cx: &mut App) {
+  log_command(cx, format!("colima delete --force --profile {}", shell_quote(&name)));
   let task_id = start_task(cx, format!("Deleting '{name}'..."));
   let name_clone = name.clone();
 
@@ -495,6 +612,62 @@ pub fn refresh_machines(cx: &mut App) {
   .detach();
 }
 
+/// Refresh CPU / memory / disk usage of the machine running Docker: the
+/// active machine, or the first running one when none is active yet.
+/// Colima VMs are queried over SSH, a Linux host with the same commands
+/// run locally. `free` and `top -b` are Linux tools, so other hosts are
+/// skipped.
+pub fn refresh_machine_usage(cx: &mut App) {
+  let state = docker_state(cx);
+  let machine = {
+    let s = state.read(cx);
+    s.active_machine
+      .clone()
+      .or_else(|| s.machines.iter().find(|m| m.is_running()).map(Machine::id))
+  };
+  let Some(machine) = machine else {
+    return;
+  };
+  if machine.is_host() && !cfg!(target_os = "linux") {
+    return;
+  }
+
+  let id = machine.clone();
+  let task = cx.background_executor().spawn(async move {
+    match &id {
+      MachineId::Colima(name) => {
+        let name_opt = (name != "default").then_some(name.as_str());
+        MachineUsage::parse(
+          &ColimaClient::get_memory_info(name_opt).unwrap_or_default(),
+          &ColimaClient::get_disk_usage(name_opt).unwrap_or_default(),
+          &ColimaClient::get_cpu_usage(name_opt).unwrap_or_default(),
+        )
+      }
+      MachineId::Host => {
+        let run = |prog: &str, args: &[&str]| {
+          std::process::Command::new(prog)
+            .args(args)
+            .output()
+            .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
+            .unwrap_or_default()
+        };
+        MachineUsage::parse(&run("free", &["-h"]), &run("df", &["-h", "/"]), &run("top", &["-bn1"]))
+      }
+    }
+  });
+
+  cx.spawn(async move |cx| {
+    let usage = task.await;
+    cx.update(|cx| {
+      state.update(cx, |state, cx| {
+        state.machine_usage = Some((machine, usage));
+        cx.emit(StateChanged::MachineUsageUpdated);
+      });
+    })
+  })
+  .detach();
+}
+
 /// Set a machine as the default by switching docker and k8s contexts
 pub fn set_default_machine(name: String, has_kubernetes: bool, cx: &mut App) {
   let task_id = start_task(cx, format!("Setting '{name}' as default..."));
@@ -506,12 +679,7 @@ pub fn set_default_machine(name: String, has_kubernetes: bool, cx: &mut App) {
     let result = cx
       .background_executor()
       .spawn(async move {
-        // Docker context name for colima is "colima" for default or "colima-<profile>" for others
-        let context_name = if name == "default" {
-          "colima".to_string()
-        } else {
-          format!("colima-{name}")
-        };
+        let context_name = MachineId::Colima(name.clone()).docker_context_name();
 
         // Switch docker context
         let docker_output = docker_cmd().args(["context", "use", &context_name]).output();
@@ -773,6 +941,65 @@ pub fn run_provision_script(name: String, script: String, as_root: bool, cx: &mu
   .detach();
 }
 
+/// Overwrite a file inside a machine with edited content. `as_root` writes
+/// through sudo; a permission error without it asks the machines view to
+/// offer a root retry.
+pub fn write_machine_file(name: String, path: String, content: String, as_root: bool, cx: &mut App) {
+  let task_id = start_task(cx, format!("Saving {path} on '{name}'..."));
+  let disp = dispatcher(cx);
+  let write_name = name.clone();
+  let write_path = path.clone();
+  let write_content = content.clone();
+
+  cx.spawn(async move |cx| {
+    let result = cx
+      .background_executor()
+      .spawn(async move {
+        let name_opt = if write_name == "default" {
+          None
+        } else {
+          Some(write_name.as_str())
+        };
+        ColimaClient::write_file(name_opt, &write_path, &write_content, as_root)
+      })
+      .await;
+
+    cx.update(|cx| match result {
+      Ok(()) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Saved {path} on '{name}'"),
+          });
+        });
+        docker_state(cx).update(cx, |_, cx| {
+          cx.emit(StateChanged::MachineFileSaved {
+            machine_name: name,
+            path,
+          });
+        });
+      }
+      Err(e) => {
+        let error = e.to_string();
+        fail_task(cx, task_id, error.clone());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed { error: error.clone() });
+        });
+        if !as_root && error.to_lowercase().contains("permission denied") {
+          docker_state(cx).update(cx, |_, cx| {
+            cx.emit(StateChanged::MachineFileWriteDenied {
+              machine_name: name,
+              path,
+              content,
+            });
+          });
+        }
+      }
+    })
+  })
+  .detach();
+}
+
 /// Open a machine folder in an external editor (VS Code, Cursor, or Zed)
 ///
 /// For VS Code and Cursor, uses the SSH Remote extension.
@@ -850,5 +1077,39 @@ pub fn open_machine_in_editor(profile: &str, path: &str, cx: &mut App) {
         });
       }
     }
+    return;
+  }
+
+  disp.update(cx, |_, cx| {
+    cx.emit(DispatcherEvent::TaskFailed {
+      error: format!(
+        "{} can't open folders inside a machine. Choose VS Code, Cursor or Zed in Settings > Editor.",
+        editor.display_name()
+      ),
+    });
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_start_phase_of() {
+    let phase = |line: &str| StartPhase::of(&ColimaLogLine::parse(line).unwrap());
+    assert_eq!(
+      phase("INFO[0001] downloading disk image ...   context=vm"),
+      Some(StartPhase::DownloadImage)
+    );
+    assert_eq!(phase("INFO[0002] starting ...   context=vm"), Some(StartPhase::Vm));
+    assert_eq!(
+      phase("INFO[0030] provisioning ...   context=docker"),
+      Some(StartPhase::Runtime)
+    );
+    assert_eq!(
+      phase("INFO[0040] starting ...   context=kubernetes"),
+      Some(StartPhase::Kubernetes)
+    );
+    assert_eq!(phase("INFO[0000] starting colima"), None);
   }
 }
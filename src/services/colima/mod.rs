@@ -41,6 +41,14 @@ mod stubs {
   pub fn disable_kubernetes(_profile: Option<&str>, _cx: &mut App) {
     tracing::warn!("Colima is not supported on this platform");
   }
+
+  /// Machine usage stub
+  pub fn refresh_machine_usage(_cx: &mut App) {}
+
+  /// Write machine file stub
+  pub fn write_machine_file(_name: String, _path: String, _content: String, _as_root: bool, _cx: &mut App) {
+    tracing::warn!("Colima is not supported on this platform");
+  }
 }
 
 #[cfg(not(any(target_os = "macos", target_os = "linux")))]
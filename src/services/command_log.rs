@@ -0,0 +1,353 @@
+//! CLI-equivalent command log
+//!
+//! Records the `docker` / `colima` / `kubectl` command that matches each
+//! operation the app performs so users can see what happened and script it
+//! later. Bollard API calls are translated into the equivalent docker CLI
+//! invocation. Recording is opt-in via `AppSettings::command_log_enabled`.
+
+use chrono::{DateTime, Local};
+use gpui::{App, AppContext, Entity, Global};
+use std::collections::VecDeque;
+
+use crate::docker::ContainerCreateConfig;
+use crate::state::settings_state;
+
+/// Maximum number of entries kept in memory. Oldest entries are dropped first.
+pub const COMMAND_LOG_CAPACITY: usize = 500;
+
+#[derive(Debug, Clone)]
+pub struct CommandLogEntry {
+  pub timestamp: DateTime<Local>,
+  pub command: String,
+}
+
+pub struct CommandLog {
+  entries: VecDeque<CommandLogEntry>,
+  capacity: usize,
+}
+
+impl Default for CommandLog {
+  fn default() -> Self {
+    Self::with_capacity(COMMAND_LOG_CAPACITY)
+  }
+}
+
+impl CommandLog {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn with_capacity(capacity: usize) -> Self {
+    Self {
+      entries: VecDeque::with_capacity(capacity.min(64)),
+      capacity: capacity.max(1),
+    }
+  }
+
+  /// Append a command, evicting the oldest entry once the cap is reached.
+  pub fn push(&mut self, command: impl Into<String>) {
+    if self.entries.len() >= self.capacity {
+      self.entries.pop_front();
+    }
+    self.entries.push_back(CommandLogEntry {
+      timestamp: Local::now(),
+      command: command.into(),
+    });
+  }
+
+  pub fn entries(&self) -> impl DoubleEndedIterator<Item = &CommandLogEntry> {
+    self.entries.iter()
+  }
+
+  pub fn clear(&mut self) {
+    self.entries.clear();
+  }
+
+  /// All commands joined by newlines, oldest first. Suitable for pasting
+  /// straight into a shell script.
+  pub fn as_script(&self) -> String {
+    self
+      .entries
+      .iter()
+      .map(|e| e.command.as_str())
+      .collect::<Vec<_>>()
+      .join("\n")
+  }
+}
+
+/// Global wrapper for `CommandLog`
+pub struct GlobalCommandLog(pub Entity<CommandLog>);
+
+impl Global for GlobalCommandLog {}
+
+/// Initialize the global command log
+pub fn init_command_log(cx: &mut App) {
+  let log = cx.new(|_cx| CommandLog::new());
+  cx.set_global(GlobalCommandLog(log));
+}
+
+/// Get the global command log entity
+pub fn command_log(cx: &App) -> Entity<CommandLog> {
+  cx.global::<GlobalCommandLog>().0.clone()
+}
+
+/// Record a CLI-equivalent command if the command log is enabled.
+pub fn log_command(cx: &mut App, command: impl Into<String>) {
+  if !settings_state(cx).read(cx).settings.command_log_enabled {
+    return;
+  }
+  let log = command_log(cx);
+  log.update(cx, |log, cx| {
+    log.push(command);
+    cx.notify();
+  });
+}
+
+/// Quote a single shell argument when it contains anything beyond a
+/// conservative set of safe characters.
+pub fn shell_quote(arg: &str) -> String {
+  let safe = !arg.is_empty()
+    && arg
+      .chars()
+      .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+%".contains(c));
+  if safe {
+    arg.to_string()
+  } else {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+  }
+}
+
+/// Split a command line into arguments the way a POSIX shell would,
+/// honouring single quotes, double quotes and backslash escapes. The
+/// inverse of joining [`shell_quote`]d arguments with spaces.
+pub fn split_shell_words(line: &str) -> Vec<String> {
+  let mut words = Vec::new();
+  let mut word = String::new();
+  let mut in_word = false;
+  let mut chars = line.chars();
+  while let Some(c) = chars.next() {
+    match c {
+      '\'' => {
+        in_word = true;
+        word.extend(chars.by_ref().take_while(|&c| c != '\''));
+      }
+      '"' => {
+        in_word = true;
+        while let Some(c) = chars.next() {
+          match c {
+            '"' => break,
+            '\\' => match chars.next() {
+              Some(next @ ('"' | '\\' | '$' | '`')) => word.push(next),
+              Some(next) => {
+                word.push('\\');
+                word.push(next);
+              }
+              None => word.push('\\'),
+            },
+            _ => word.push(c),
+          }
+        }
+      }
+      '\\' => {
+        in_word = true;
+        if let Some(next) = chars.next() {
+          word.push(next);
+        }
+      }
+      c if c.is_whitespace() => {
+        if in_word {
+          words.push(std::mem::take(&mut word));
+          in_word = false;
+        }
+      }
+      _ => {
+        in_word = true;
+        word.push(c);
+      }
+    }
+  }
+  if in_word {
+    words.push(word);
+  }
+  words
+}
+
+/// Build the `docker run -d` (or `docker create`) invocation equivalent to
+/// a `ContainerCreateConfig`.
+pub fn docker_run_command(cfg: &ContainerCreateConfig, start: bool) -> String {
+  let mut args: Vec<String> = vec!["docker".into()];
+  if start {
+    args.extend(["run".into(), "-d".into()]);
+  } else {
+    args.push("create".into());
+  }
+
+  let mut flag = |name: &str, value: &str| {
+    args.push(name.to_string());
+    args.push(shell_quote(value));
+  };
+
+  if let Some(name) = cfg.name.as_deref().filter(|n| !n.is_empty()) {
+    flag("--name", name);
+  }
+  if let Some(platform) = cfg.platform.as_deref() {
+    flag("--platform", platform);
+  }
+  if let Some(policy) = cfg.restart_policy.as_deref() {
+    flag("--restart", policy);
+  }
+  if let Some(hostname) = cfg.hostname.as_deref().filter(|h| !h.is_empty()) {
+    flag("--hostname", hostname);
+  }
+  if let Some(dir) = cfg.working_dir.as_deref().filter(|d| !d.is_empty()) {
+    flag("--workdir", dir);
+  }
+  if let Some(network) = cfg.network.as_deref().filter(|n| !n.is_empty()) {
+    flag("--network", network);
+  }
+  for (host, container, proto) in &cfg.ports {
+    let mapping = if host.is_empty() {
+      format!("{container}/{proto}")
+    } else {
+      format!("{host}:{container}/{proto}")
+    };
+    flag("-p", &mapping);
+  }
+  for (host, container, ro) in &cfg.volumes {
+    let mapping = if *ro {
+      format!("{host}:{container}:ro")
+    } else {
+      format!("{host}:{container}")
+    };
+    flag("-v", &mapping);
+  }
+  for (k, v) in &cfg.env_vars {
+    flag("-e", &format!("{k}={v}"));
+  }
+  for (k, v) in &cfg.labels {
+    flag("--label", &format!("{k}={v}"));
+  }
+  for server in &cfg.dns {
+    flag("--dns", server);
+  }
+  for host in &cfg.extra_hosts {
+    flag("--add-host", host);
+  }
+  for cap in &cfg.cap_add {
+    flag("--cap-add", cap);
+  }
+  for cap in &cfg.cap_drop {
+    flag("--cap-drop", cap);
+  }
+  for opt in &cfg.security_opt {
+    flag("--security-opt", opt);
+  }
+  if let Some(cpus) = cfg.cpus {
+    flag("--cpus", &cpus.to_string());
+  }
+  if let Some(shares) = cfg.cpu_shares {
+    flag("--cpu-shares", &shares.to_string());
+  }
+  if let Some(mem) = cfg.memory_bytes {
+    flag("--memory", &mem.to_string());
+  }
+  if let Some(swap) = cfg.memory_swap_bytes {
+    flag("--memory-swap", &swap.to_string());
+  }
+  if let Some(pids) = cfg.pids_limit {
+    flag("--pids-limit", &pids.to_string());
+  }
+  if let Some(cmd) = cfg.healthcheck_cmd.as_ref().and_then(|c| c.first()) {
+    flag("--health-cmd", cmd);
+  }
+  if let Some(entrypoint) = cfg.entrypoint.as_ref().filter(|e| !e.is_empty()) {
+    flag("--entrypoint", &entrypoint.join(" "));
+  }
+  if cfg.flags.auto_remove {
+    args.push("--rm".into());
+  }
+  if cfg.flags.privileged {
+    args.push("--privileged".into());
+  }
+  if cfg.flags.read_only {
+    args.push("--read-only".into());
+  }
+  if cfg.flags.init {
+    args.push("--init".into());
+  }
+
+  args.push(shell_quote(&cfg.image));
+  if let Some(cmd) = &cfg.command {
+    args.extend(cmd.iter().map(|a| shell_quote(a)));
+  }
+  args.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_push_caps_history() {
+    let mut log = CommandLog::with_capacity(3);
+    for i in 0..5 {
+      log.push(format!("docker start c{i}"));
+    }
+    let cmds: Vec<_> = log.entries().map(|e| e.command.clone()).collect();
+    assert_eq!(cmds, vec!["docker start c2", "docker start c3", "docker start c4"]);
+  }
+
+  #[test]
+  fn test_as_script_and_clear() {
+    let mut log = CommandLog::new();
+    log.push("docker stop web");
+    log.push("docker rm web");
+    assert_eq!(log.as_script(), "docker stop web\ndocker rm web");
+    log.clear();
+    assert_eq!(log.entries().count(), 0);
+  }
+
+  #[test]
+  fn test_shell_quote() {
+    assert_eq!(shell_quote("nginx:latest"), "nginx:latest");
+    assert_eq!(shell_quote("hello world"), "'hello world'");
+    assert_eq!(shell_quote("it's"), r"'it'\''s'");
+    assert_eq!(shell_quote(""), "''");
+  }
+
+  #[test]
+  fn test_split_shell_words() {
+    assert_eq!(
+      split_shell_words("nginx -g 'daemon off;'"),
+      vec!["nginx", "-g", "daemon off;"]
+    );
+    assert_eq!(
+      split_shell_words(r#"echo "a \"b\"" c\ d ''"#),
+      vec!["echo", r#"a "b""#, "c d", ""]
+    );
+    assert_eq!(split_shell_words("  ls   -la "), vec!["ls", "-la"]);
+
+    let args = ["sh", "-c", "echo it's done", ""];
+    let line = args.iter().map(|a| shell_quote(a)).collect::<Vec<_>>().join(" ");
+    assert_eq!(split_shell_words(&line), args);
+  }
+
+  #[test]
+  fn test_docker_run_command() {
+    let cfg = ContainerCreateConfig {
+      image: "nginx:latest".to_string(),
+      name: Some("web".to_string()),
+      ports: vec![("8080".to_string(), "80".to_string(), "tcp".to_string())],
+      env_vars: vec![("GREETING".to_string(), "hi there".to_string())],
+      extra_hosts: vec!["db:10.0.0.5".to_string()],
+      cap_add: vec!["NET_ADMIN".to_string()],
+      ..Default::default()
+    };
+    assert_eq!(
+      docker_run_command(&cfg, true),
+      "docker run -d --name web -p 8080:80/tcp -e 'GREETING=hi there' --add-host db:10.0.0.5 --cap-add NET_ADMIN \
+       nginx:latest"
+    );
+    assert!(docker_run_command(&cfg, false).starts_with("docker create --name web"));
+  }
+}
@@ -0,0 +1,141 @@
+//! Docker daemon connection monitor
+//!
+//! Health-checks the shared Docker client while connected and, once the
+//! daemon is unreachable (not started yet at launch, or gone mid-session),
+//! retries connecting with exponential backoff. Regaining the connection
+//! reloads all data and restarts the watchers.
+
+use std::time::Duration;
+
+use gpui::{App, AsyncApp};
+
+use crate::docker::DockerClient;
+use crate::platform::Platform;
+use crate::services::{Tokio, load_initial_data};
+use crate::state::{StateChanged, docker_state, settings_state};
+
+use super::core::{DispatcherEvent, dispatcher, docker_client};
+use super::init::select_docker_runtime;
+
+/// How often a live connection is pinged
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// First reconnect delay after the daemon goes away
+const RETRY_MIN: Duration = Duration::from_secs(1);
+/// Cap on the reconnect delay
+const RETRY_MAX: Duration = Duration::from_secs(30);
+
+/// Double the reconnect delay, capped at `RETRY_MAX`.
+fn next_retry_delay(current: Duration) -> Duration {
+  (current * 2).min(RETRY_MAX)
+}
+
+/// Start the background connection monitor. Runs for the app's lifetime.
+pub fn start_connection_monitor(cx: &mut App) {
+  cx.spawn(async move |cx| {
+    let mut retry_delay = RETRY_MIN;
+    loop {
+      let Ok(connected) = cx.update(|cx| docker_state(cx).read(cx).docker_connected) else {
+        break;
+      };
+      match connected {
+        // Initial load still in flight
+        None => cx.background_executor().timer(RETRY_MIN).await,
+        Some(true) => {
+          cx.background_executor().timer(HEALTH_CHECK_INTERVAL).await;
+          if !ping_docker(cx).await {
+            tracing::warn!("Lost connection to the Docker daemon");
+            retry_delay = RETRY_MIN;
+            let colima_installed = cx
+              .background_executor()
+              .spawn(async { crate::utils::is_colima_installed() })
+              .await;
+            let _ = cx.update(|cx| set_disconnected(colima_installed, cx));
+          }
+        }
+        Some(false) => {
+          if try_reconnect(cx).await {
+            tracing::info!("Reconnected to the Docker daemon");
+            retry_delay = RETRY_MIN;
+            // The reload flips the state to connected and restarts the
+            // watchers; give it time to land before checking again.
+            let _ = cx.update(|cx| {
+              load_initial_data(cx);
+              dispatcher(cx).update(cx, |_, cx| {
+                cx.emit(DispatcherEvent::TaskCompleted {
+                  message: "Connected to Docker".to_string(),
+                });
+              });
+            });
+            cx.background_executor().timer(HEALTH_CHECK_INTERVAL).await;
+          } else {
+            cx.background_executor().timer(retry_delay).await;
+            retry_delay = next_retry_delay(retry_delay);
+          }
+        }
+      }
+    }
+  })
+  .detach();
+}
+
+fn set_disconnected(colima_installed: bool, cx: &mut App) {
+  docker_state(cx).update(cx, |state, cx| {
+    state.colima_installed = colima_installed;
+    if state.set_docker_connected(false) {
+      cx.emit(StateChanged::DockerConnectionChanged);
+    }
+  });
+}
+
+/// Ping the daemon through the shared client.
+async fn ping_docker(cx: &mut AsyncApp) -> bool {
+  let client = docker_client();
+  let Ok(task) = cx.update(|cx| {
+    Tokio::spawn(cx, async move {
+      let guard = client.read().await;
+      match guard.as_ref().and_then(|c| c.client().ok()) {
+        Some(docker) => docker.ping().await.is_ok(),
+        None => false,
+      }
+    })
+  }) else {
+    return false;
+  };
+  task.await.unwrap_or(false)
+}
+
+/// Connect a fresh client to the runtime the app was last using (or the
+/// one settings select when it never connected) and swap it in.
+async fn try_reconnect(cx: &mut AsyncApp) -> bool {
+  let client = docker_client();
+  let Ok(task) = cx.update(|cx| {
+    let settings = settings_state(cx).read(cx).settings.clone();
+    Tokio::spawn(cx, async move {
+      let runtime = match client.read().await.as_ref() {
+        Some(current) => current.runtime().clone(),
+        None => select_docker_runtime(Platform::detect(), &settings),
+      };
+      let mut new_client = DockerClient::new(runtime);
+      if new_client.connect().await.is_err() {
+        return false;
+      }
+      *client.write().await = Some(new_client);
+      true
+    })
+  }) else {
+    return false;
+  };
+  task.await.unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_next_retry_delay_caps() {
+    assert_eq!(next_retry_delay(RETRY_MIN), Duration::from_secs(2));
+    assert_eq!(next_retry_delay(Duration::from_secs(20)), RETRY_MAX);
+    assert_eq!(next_retry_delay(RETRY_MAX), RETRY_MAX);
+  }
+}
@@ -4,10 +4,11 @@ use gpui::{App, AppContext, Entity, EventEmitter, Global};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crate::colima::MachineId;
+use crate::colima::{Machine, MachineId};
 use crate::docker::DockerClient;
 use crate::platform::DockerRuntime;
-use crate::state::{StateChanged, docker_state};
+use crate::services::TaskRetry;
+use crate::state::{CurrentView, Selection, SettingsChanged, StateChanged, docker_state, settings_state};
 
 /// Shared Docker client - initialized once in `load_initial_data`
 static DOCKER_CLIENT: std::sync::OnceLock<Arc<RwLock<Option<DockerClient>>>> = std::sync::OnceLock::new();
@@ -20,8 +21,27 @@ pub fn docker_client() -> Arc<RwLock<Option<DockerClient>>> {
 /// Event emitted when a task completes (for UI to show notifications)
 #[derive(Clone, Debug)]
 pub enum DispatcherEvent {
-  TaskCompleted { message: String },
-  TaskFailed { error: String },
+  TaskCompleted {
+    message: String,
+  },
+  TaskFailed {
+    error: String,
+  },
+  /// A failure whose notification offers to re-run the operation
+  RetryableTaskFailed {
+    error: String,
+    retry: TaskRetry,
+  },
+}
+
+impl DispatcherEvent {
+  /// `TaskFailed`, or `RetryableTaskFailed` when the task can be retried
+  pub fn task_failed(error: String, retry: Option<TaskRetry>) -> Self {
+    match retry {
+      Some(retry) => Self::RetryableTaskFailed { error, retry },
+      None => Self::TaskFailed { error },
+    }
+  }
 }
 
 /// Central action dispatcher - handles all async operations
@@ -58,10 +78,40 @@ pub fn dispatcher(cx: &App) -> Entity<ActionDispatcher> {
   cx.global::<GlobalActionDispatcher>().0.clone()
 }
 
+/// Flag `view`'s list refresh as in flight so its header shows a spinner.
+/// The matching `set_*` / `set_*_error` call on `DockerState` clears it.
+pub fn mark_refreshing(view: CurrentView, cx: &mut App) {
+  docker_state(cx).update(cx, |state, cx| {
+    state.begin_refresh(view);
+    cx.emit(StateChanged::RefreshStatusChanged(view));
+  });
+}
+
+/// Select a machine in the Machines view. With `docker_follows_machine`
+/// on, a running machine that isn't already active also becomes the
+/// Docker daemon every resource view talks to.
+pub fn select_machine(machine: &Machine, cx: &mut App) {
+  let state = docker_state(cx);
+  let machine_id = machine.id();
+  state.update(cx, |state, _cx| {
+    state.set_selection(Selection::Machine(machine_id.clone()));
+  });
+
+  let follow = settings_state(cx).read(cx).settings.docker_follows_machine;
+  let already_active = state.read(cx).active_machine.as_ref() == Some(&machine_id);
+  if follow && machine.is_running() && !already_active {
+    switch_runtime(&machine_id, cx);
+  }
+}
+
 /// Switch the Docker runtime to a different machine
 ///
 /// This disconnects the current Docker client and connects to a new runtime.
-/// Used when switching between Host Docker and Colima VMs.
+/// Used when switching between Host Docker and Colima VMs. On success
+/// everything is reloaded from the new daemon. With `docker_follows_machine`
+/// the machine's Docker CLI context is also made current (when it exists)
+/// and a Colima endpoint is persisted so later reloads stay on it; a socket
+/// the user configured themselves is never cleared.
 pub fn switch_runtime(machine_id: &MachineId, cx: &mut App) {
   let state = docker_state(cx);
   let disp = dispatcher(cx);
@@ -74,6 +124,23 @@ pub fn switch_runtime(machine_id: &MachineId, cx: &mut App) {
       profile: profile.clone(),
     },
   };
+  let follow = settings_state(cx).read(cx).settings.docker_follows_machine;
+  // A Colima profile is pinned by socket. Going back to the host only
+  // unpins a socket that an earlier switch wrote.
+  let docker_socket = match machine_id {
+    MachineId::Colima(_) => Some(runtime.connection_string()),
+    MachineId::Host => {
+      let current = settings_state(cx).read(cx).settings.docker_socket.clone();
+      let pinned = match state.read(cx).active_machine.clone() {
+        Some(MachineId::Colima(profile)) => DockerRuntime::Colima { profile }.connection_string() == current,
+        _ => false,
+      };
+      pinned.then(String::new)
+    }
+  }
+  .filter(|_| follow);
+  let context_name = machine_id.docker_context_name();
+  let has_context = follow && state.read(cx).docker_contexts.iter().any(|c| c.name == context_name);
 
   let machine_id_clone = machine_id.clone();
 
@@ -88,14 +155,42 @@ pub fn switch_runtime(machine_id: &MachineId, cx: &mut App) {
       *guard = Some(new_client);
       drop(guard);
 
+      // Keep the docker CLI (terminals, compose) on the same daemon
+      if has_context {
+        let name = context_name.clone();
+        let used = cx
+          .background_executor()
+          .spawn(async move { crate::docker::use_docker_context(&name) })
+          .await;
+        if let Err(e) = used {
+          tracing::warn!("Failed to switch Docker context to '{context_name}': {e}");
+        }
+      }
+
       // Update state with the new active machine
       cx.update(|cx| {
+        if let Some(docker_socket) = docker_socket {
+          settings_state(cx).update(cx, |s, cx| {
+            s.settings.docker_socket = docker_socket;
+            if let Err(e) = s.settings.save() {
+              tracing::warn!("Failed to persist docker_socket: {e}");
+            }
+            cx.emit(SettingsChanged::SettingsUpdated);
+          });
+        }
         state.update(cx, |state, cx| {
           state.set_active(machine_id_clone.clone());
+          if has_context {
+            state.mark_current_docker_context(&context_name);
+            cx.emit(StateChanged::DockerContextsUpdated);
+          }
           cx.emit(StateChanged::RuntimeSwitched {
             machine_id: machine_id_clone,
           });
         });
+        // Reload every resource list from the new daemon
+        super::load_initial_data(cx);
+        super::watchers::restart_watchers(cx);
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskCompleted {
             message: "Runtime switched successfully".to_string(),
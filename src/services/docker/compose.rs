@@ -2,10 +2,13 @@
 
 use gpui::App;
 use std::io::Read;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
 
-use crate::services::{complete_task, fail_task, start_task};
+use crate::docker::{ComposeProject, extract_compose_projects};
+use crate::services::{complete_task, fail_task, log_command, shell_quote, start_task};
+use crate::state::{docker_state, settings_state};
 use crate::terminal::LogStream;
 use crate::utils::docker_cmd;
 
@@ -55,6 +58,13 @@ impl Drop for ComposeWatchHandle {
   }
 }
 
+/// Compose projects discovered from the labels on the current container
+/// list, sorted by name. Recomputed from state, so projects brought up
+/// outside the app appear with the next container refresh.
+pub fn list_compose_projects(cx: &App) -> Vec<ComposeProject> {
+  extract_compose_projects(&docker_state(cx).read(cx).containers)
+}
+
 /// Build a `docker compose` invocation for `project_name`, prefixing
 /// `-f <config>` for every known compose file and chdir-ing into the
 /// project's working dir if available. Without these the daemon's
@@ -166,6 +176,77 @@ pub fn compose_down(project_name: String, working_dir: Option<String>, config_fi
   .detach();
 }
 
+/// Spawn `cmd` with piped output, feed stdout / stderr bytes to `feed` as
+/// they arrive and wait for the child, which `handle.stop()` can kill from
+/// another thread. A kill requested through the handle counts as success.
+fn stream_child(
+  mut cmd: Command,
+  handle: &ComposeWatchHandle,
+  feed: Arc<dyn Fn(Vec<u8>) + Send + Sync>,
+) -> Result<(), String> {
+  cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+  let mut child = cmd
+    .spawn()
+    .map_err(|e| format!("failed to spawn docker compose: {e}"))?;
+  let mut stdout = child.stdout.take();
+  let mut stderr = child.stderr.take();
+
+  // Drain stdout and stderr concurrently. CR-prefix raw '\n' so
+  // libghostty's grid breaks lines; the build pipeline does the same in
+  // services::build_image.
+  let feed_stdout = feed.clone();
+  let feed_stderr = feed;
+  let stdout_handle = std::thread::spawn(move || {
+    if let Some(out) = stdout.as_mut() {
+      let mut buf = [0u8; 4096];
+      while let Ok(n) = out.read(&mut buf)
+        && n > 0
+      {
+        feed_stdout(crlf_normalize(&buf[..n]));
+      }
+    }
+  });
+  let stderr_handle = std::thread::spawn(move || {
+    if let Some(err) = stderr.as_mut() {
+      let mut buf = [0u8; 4096];
+      while let Ok(n) = err.read(&mut buf)
+        && n > 0
+      {
+        feed_stderr(crlf_normalize(&buf[..n]));
+      }
+    }
+  });
+
+  // Hand the child off to the cancel handle so `stop()` can call
+  // `Child::kill` from another thread. We poll `try_wait` until the
+  // child exits (or stop is requested + the handle killed it for us).
+  handle.install(child);
+  let status: Option<std::process::ExitStatus> = loop {
+    let mut guard = handle.child.lock().map_err(|e| format!("handle poisoned: {e}"))?;
+    match guard.as_mut() {
+      Some(c) => match c.try_wait() {
+        Ok(Some(s)) => {
+          let _ = guard.take();
+          break Some(s);
+        }
+        Ok(None) => {}
+        Err(e) => return Err(format!("try_wait failed: {e}")),
+      },
+      // Handle::stop() already took the child + killed it.
+      None => break None,
+    }
+    drop(guard);
+    std::thread::sleep(std::time::Duration::from_millis(100));
+  };
+  let _ = stdout_handle.join();
+  let _ = stderr_handle.join();
+  match status {
+    None => Ok(()),
+    Some(s) if s.success() || handle.is_stop_requested() => Ok(()),
+    Some(s) => Err(format!("exited with status {s}")),
+  }
+}
+
 /// Spawn `docker compose -p <project> [--profile <p>] watch` and stream
 /// stdout / stderr bytes into `log_stream` as they arrive. Returns a
 /// `ComposeWatchHandle` that the caller (typically the output dialog)
@@ -194,71 +275,11 @@ pub fn compose_watch(
           cmd.args(["--profile", p]);
         }
         cmd.arg("watch");
-        cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
-        let mut child = cmd
-          .spawn()
-          .map_err(|e| format!("failed to spawn docker compose watch: {e}"))?;
-        let mut stdout = child.stdout.take();
-        let mut stderr = child.stderr.take();
-
-        // Drain stdout and stderr concurrently into the LogStream.
-        // CR-prefix raw '\n' so libghostty's grid breaks lines; the
-        // build pipeline does the same in services::build_image.
-        let log_stdout = log_for_task.clone();
-        let log_stderr = log_for_task.clone();
-        let stdout_handle = std::thread::spawn(move || {
-          if let Some(out) = stdout.as_mut() {
-            let mut buf = [0u8; 4096];
-            while let Ok(n) = out.read(&mut buf)
-              && n > 0
-            {
-              log_stdout.feed_bytes(crlf_normalize(&buf[..n]));
-            }
-          }
-        });
-        let stderr_handle = std::thread::spawn(move || {
-          if let Some(err) = stderr.as_mut() {
-            let mut buf = [0u8; 4096];
-            while let Ok(n) = err.read(&mut buf)
-              && n > 0
-            {
-              log_stderr.feed_bytes(crlf_normalize(&buf[..n]));
-            }
-          }
-        });
-
-        // Hand the child off to the cancel handle so `stop()` can
-        // call `Child::kill` from another thread. We poll `try_wait`
-        // until the child exits (or stop is requested + the handle
-        // killed it for us).
-        handle_for_task.install(child);
-        let status: Option<std::process::ExitStatus> = loop {
-          let mut guard = handle_for_task
-            .child
-            .lock()
-            .map_err(|e| format!("watch handle poisoned: {e}"))?;
-          match guard.as_mut() {
-            Some(c) => match c.try_wait() {
-              Ok(Some(s)) => {
-                let _ = guard.take();
-                break Some(s);
-              }
-              Ok(None) => {}
-              Err(e) => return Err(format!("try_wait failed: {e}")),
-            },
-            // Handle::stop() already took the child + killed it.
-            None => break None,
-          }
-          drop(guard);
-          std::thread::sleep(std::time::Duration::from_millis(100));
-        };
-        let _ = stdout_handle.join();
-        let _ = stderr_handle.join();
-        match status {
-          None => Ok(()),
-          Some(s) if s.success() || handle_for_task.is_stop_requested() => Ok(()),
-          Some(s) => Err(format!("docker compose watch exited with status {s}")),
-        }
+        stream_child(
+          cmd,
+          &handle_for_task,
+          Arc::new(move |bytes: Vec<u8>| log_for_task.feed_bytes(bytes)),
+        )
       })
       .await;
 
@@ -286,6 +307,152 @@ pub fn compose_watch(
   handle
 }
 
+/// Output held back while a `ComposeLogs` stream is paused. Past this
+/// the rest is dropped and counted so a long pause can't grow unbounded.
+const PAUSED_LOG_BUFFER_BYTES: usize = 1024 * 1024;
+
+/// A project's combined `docker compose logs` output, fed into a
+/// `LogStream`. Pausing holds new output back until resumed so the
+/// terminal stops scrolling; stopping (or dropping) kills the child.
+pub struct ComposeLogs {
+  log_stream: Arc<LogStream>,
+  process: ComposeWatchHandle,
+  paused: Mutex<Option<PausedOutput>>,
+}
+
+#[derive(Default)]
+struct PausedOutput {
+  pending: Vec<u8>,
+  dropped: usize,
+}
+
+impl PausedOutput {
+  /// Keep `bytes` for later, or count them as dropped once the buffer is
+  /// full. Nothing is kept after the first drop so the output resumes
+  /// with a clean gap rather than a torn line.
+  fn hold(&mut self, bytes: &[u8]) {
+    if self.dropped == 0 && self.pending.len() + bytes.len() <= PAUSED_LOG_BUFFER_BYTES {
+      self.pending.extend_from_slice(bytes);
+    } else {
+      self.dropped += bytes.len();
+    }
+  }
+}
+
+impl ComposeLogs {
+  fn new(log_stream: Arc<LogStream>) -> Self {
+    Self {
+      log_stream,
+      process: ComposeWatchHandle::default(),
+      paused: Mutex::new(None),
+    }
+  }
+
+  pub fn is_paused(&self) -> bool {
+    self.paused.lock().is_ok_and(|p| p.is_some())
+  }
+
+  /// Pause or resume. Resuming flushes what arrived in between.
+  pub fn set_paused(&self, paused: bool) {
+    let Ok(mut guard) = self.paused.lock() else {
+      return;
+    };
+    if paused {
+      guard.get_or_insert_with(PausedOutput::default);
+    } else if let Some(held) = guard.take() {
+      if !held.pending.is_empty() {
+        self.log_stream.feed_bytes(held.pending);
+      }
+      if held.dropped > 0 {
+        let note = format!(
+          "\r\n[{} skipped while paused]\r\n",
+          bytesize::ByteSize(held.dropped as u64)
+        );
+        self.log_stream.feed_bytes(note.into_bytes());
+      }
+    }
+  }
+
+  fn feed(&self, bytes: Vec<u8>) {
+    let Ok(mut guard) = self.paused.lock() else {
+      return;
+    };
+    match guard.as_mut() {
+      Some(held) => held.hold(&bytes),
+      None => self.log_stream.feed_bytes(bytes),
+    }
+  }
+
+  /// Kill the `docker compose logs` child. Idempotent.
+  pub fn stop(&self) {
+    self.process.stop();
+  }
+}
+
+/// Stream `docker compose -p <project> logs` into `log_stream`: every
+/// service interleaved, each line prefixed with its service name in the
+/// colour compose assigns it. With `follow` the stream stays open until
+/// the returned handle is stopped or the project's containers go away.
+pub fn compose_logs(
+  project_name: String,
+  working_dir: Option<String>,
+  config_files: Vec<String>,
+  follow: bool,
+  log_stream: Arc<LogStream>,
+  cx: &mut App,
+) -> Arc<ComposeLogs> {
+  log_command(
+    cx,
+    format!(
+      "docker compose -p {} logs{}",
+      shell_quote(&project_name),
+      if follow { " -f" } else { "" }
+    ),
+  );
+  let disp = dispatcher(cx);
+  let logs = Arc::new(ComposeLogs::new(log_stream));
+  let logs_for_task = logs.clone();
+
+  cx.spawn(async move |cx| {
+    let project = project_name.clone();
+    let result = cx
+      .background_executor()
+      .spawn(async move {
+        // Piped output turns compose's colours off; force them so the
+        // service prefixes stay distinguishable in the terminal.
+        let mut cmd = compose_invocation(&project, working_dir.as_deref(), &config_files);
+        cmd.args(["--ansi", "always", "logs", "--tail", "500"]);
+        if follow {
+          cmd.arg("--follow");
+        }
+        let feed_target = logs_for_task.clone();
+        let result = stream_child(
+          cmd,
+          &logs_for_task.process,
+          Arc::new(move |bytes: Vec<u8>| feed_target.feed(bytes)),
+        );
+        if !logs_for_task.process.is_stop_requested() {
+          logs_for_task.feed(b"\r\n[log stream ended]\r\n".to_vec());
+        }
+        result
+      })
+      .await;
+
+    if let Err(e) = result {
+      let _ = cx.update(|cx| {
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to stream logs for '{project_name}': {e}"),
+          });
+        });
+      });
+    }
+  })
+  .detach();
+
+  logs
+}
+
 fn crlf_normalize(input: &[u8]) -> Vec<u8> {
   let mut out = Vec::with_capacity(input.len() + 16);
   let mut prev = 0u8;
@@ -344,3 +511,215 @@ pub fn compose_restart(project_name: String, working_dir: Option<String>, config
   })
   .detach();
 }
+
+/// Project name compose derives from the directory holding `file`:
+/// lowercased, keeping only `a-z`, `0-9`, `_` and `-`, and starting
+/// with a letter or digit.
+pub fn compose_project_name_for_file(file: &Path) -> Option<String> {
+  let dir = file.parent()?.file_name()?.to_str()?;
+  let name: String = dir
+    .to_lowercase()
+    .chars()
+    .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    .collect();
+  let name = name.trim_start_matches(['_', '-']);
+  (!name.is_empty()).then(|| name.to_string())
+}
+
+/// `docker compose -f <file> up -d` for a compose file that isn't a
+/// running project yet. The project is named after the file's directory,
+/// as the CLI does, and the file is added to the recent list on success.
+pub fn compose_up_from_file(file: PathBuf, cx: &mut App) {
+  let disp = dispatcher(cx);
+  let Some(project_name) = compose_project_name_for_file(&file) else {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: format!("Can't derive a project name from {}", file.display()),
+      });
+    });
+    return;
+  };
+  let file_str = file.to_string_lossy().to_string();
+  log_command(
+    cx,
+    format!(
+      "docker compose -f {} -p {} up -d",
+      shell_quote(&file_str),
+      shell_quote(&project_name)
+    ),
+  );
+  let task_id = start_task(cx, format!("Starting '{project_name}'..."));
+
+  cx.spawn(async move |cx| {
+    let project = project_name.clone();
+    let config_file = file_str.clone();
+    let working_dir = file.parent().map(|d| d.to_string_lossy().to_string());
+    let result = cx
+      .background_executor()
+      .spawn(async move {
+        let output = compose_invocation(&project, working_dir.as_deref(), &[config_file])
+          .args(["up", "-d"])
+          .output();
+
+        match output {
+          Ok(out) if out.status.success() => Ok(()),
+          Ok(out) => Err(String::from_utf8_lossy(&out.stderr).trim().to_string()),
+          Err(e) => Err(e.to_string()),
+        }
+      })
+      .await;
+
+    cx.update(|cx| match result {
+      Ok(()) => {
+        complete_task(cx, task_id);
+        settings_state(cx).update(cx, |state, _cx| {
+          state.settings.remember_compose_file(&file_str);
+          let _ = state.settings.save();
+        });
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Started '{project_name}'"),
+          });
+        });
+        refresh_containers(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.clone());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to start '{project_name}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Lifecycle action on a single service of a compose project
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComposeServiceAction {
+  Start,
+  Stop,
+  Restart,
+}
+
+impl ComposeServiceAction {
+  /// `docker compose` subcommand
+  pub fn command(self) -> &'static str {
+    match self {
+      Self::Start => "start",
+      Self::Stop => "stop",
+      Self::Restart => "restart",
+    }
+  }
+
+  fn progress(self) -> &'static str {
+    match self {
+      Self::Start => "Starting",
+      Self::Stop => "Stopping",
+      Self::Restart => "Restarting",
+    }
+  }
+
+  fn done(self) -> &'static str {
+    match self {
+      Self::Start => "Started",
+      Self::Stop => "Stopped",
+      Self::Restart => "Restarted",
+    }
+  }
+}
+
+/// Run `docker compose -p <project> <start|stop|restart> <service>`.
+/// Only the service's existing containers are touched; nothing is
+/// recreated and the rest of the project keeps running.
+pub fn compose_service_action(
+  project_name: String,
+  working_dir: Option<String>,
+  config_files: Vec<String>,
+  service: String,
+  action: ComposeServiceAction,
+  cx: &mut App,
+) {
+  log_command(
+    cx,
+    format!(
+      "docker compose -p {} {} {}",
+      shell_quote(&project_name),
+      action.command(),
+      shell_quote(&service)
+    ),
+  );
+  let label = format!("{project_name}/{service}");
+  let task_id = start_task(cx, format!("{} '{label}'...", action.progress()));
+  let disp = dispatcher(cx);
+
+  cx.spawn(async move |cx| {
+    let result = cx
+      .background_executor()
+      .spawn(async move {
+        let output = compose_invocation(&project_name, working_dir.as_deref(), &config_files)
+          .args([action.command(), service.as_str()])
+          .output();
+
+        match output {
+          Ok(out) if out.status.success() => Ok(()),
+          Ok(out) => Err(String::from_utf8_lossy(&out.stderr).trim().to_string()),
+          Err(e) => Err(e.to_string()),
+        }
+      })
+      .await;
+
+    cx.update(|cx| match result {
+      Ok(()) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("{} '{label}'", action.done()),
+          });
+        });
+        refresh_containers(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.clone());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to {} '{label}': {e}", action.command()),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_compose_project_name_for_file() {
+    let name = |p: &str| compose_project_name_for_file(Path::new(p));
+    assert_eq!(name("/home/me/My App/compose.yaml").as_deref(), Some("myapp"));
+    assert_eq!(name("/srv/web_api-2/docker-compose.yml").as_deref(), Some("web_api-2"));
+    assert_eq!(name("/srv/_internal/compose.yaml").as_deref(), Some("internal"));
+    assert_eq!(name("/srv/.../compose.yaml"), None);
+    assert_eq!(name("compose.yaml"), None);
+  }
+
+  #[test]
+  fn test_paused_output_drops_past_the_limit() {
+    let mut held = PausedOutput::default();
+    held.hold(b"web-1  | ready\r\n");
+    assert_eq!(held.pending, b"web-1  | ready\r\n");
+    assert_eq!(held.dropped, 0);
+
+    held.hold(&vec![b'x'; PAUSED_LOG_BUFFER_BYTES]);
+    assert_eq!(held.dropped, PAUSED_LOG_BUFFER_BYTES);
+    // Once anything was dropped, later chunks are dropped too
+    held.hold(b"db-1  | ok\r\n");
+    assert_eq!(held.pending.len(), 16);
+    assert_eq!(held.dropped, PAUSED_LOG_BUFFER_BYTES + 12);
+  }
+}
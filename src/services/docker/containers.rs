@@ -1,12 +1,22 @@
 //! Container operations
 
+use futures::FutureExt;
+use futures::future::BoxFuture;
 use gpui::App;
+use std::fmt::Write as _;
 
-use crate::docker::{ContainerCreateConfig, ContainerFlags};
-use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{StateChanged, docker_state};
+use crate::docker::{
+  ATTACH_DETACH_KEYS, ContainerCreateConfig, ContainerFlags, ContainerUpdate, DEFAULT_STOP_TIMEOUT_SECS, DockerClient,
+};
+use crate::services::{
+  TaskRetry, Tokio, complete_task, docker_run_command, fail_task, log_command, set_task_progress, set_task_retry,
+  shell_quote, split_shell_words, start_task,
+};
+use crate::state::{CurrentView, StateChanged, docker_state};
+use crate::terminal::AttachStream;
 
-use super::super::core::{DispatcherEvent, dispatcher, docker_client};
+use super::super::core::{DispatcherEvent, dispatcher, docker_client, mark_refreshing};
+use super::refresh_images;
 
 pub fn toggle_container_bulk_selection(id: &str, cx: &mut App) {
   let state = docker_state(cx);
@@ -16,6 +26,16 @@ pub fn toggle_container_bulk_selection(id: &str, cx: &mut App) {
   });
 }
 
+/// Shift-click: extend the bulk set from the anchor to `id`, following
+/// the list's visible `order`.
+pub fn extend_container_bulk_selection(id: &str, order: &[String], cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |s, cx| {
+    s.extend_bulk_container(id, order);
+    cx.notify();
+  });
+}
+
 pub fn clear_container_bulk_selection(cx: &mut App) {
   let state = docker_state(cx);
   state.update(cx, |s, cx| {
@@ -31,20 +51,166 @@ pub fn bulk_action_containers(action: &str, cx: &mut App) {
     .iter()
     .cloned()
     .collect();
-  for id in ids {
-    match action {
-      "start" => start_container(id, cx),
-      "stop" => stop_container(id, cx),
-      "restart" => restart_container(id, cx),
-      "delete" => delete_container(id, cx),
-      _ => {}
-    }
+  match action {
+    "start" => start_containers(ids, cx),
+    "stop" => stop_containers(ids, cx),
+    "restart" => restart_containers(ids, cx),
+    "delete" => delete_containers(ids, cx),
+    _ => {}
   }
   clear_container_bulk_selection(cx);
 }
 
+/// Start several containers under a single task.
+pub fn start_containers(ids: Vec<String>, cx: &mut App) {
+  let kind = BulkKind::container("docker start", "Starting", "Started");
+  run_bulk(ids, kind, |docker, id| docker.start_container(id).boxed(), cx);
+}
+
+/// Stop several containers under a single task.
+pub fn stop_containers(ids: Vec<String>, cx: &mut App) {
+  let kind = BulkKind::container("docker stop", "Stopping", "Stopped");
+  run_bulk(
+    ids,
+    kind,
+    |docker, id| async move { docker.stop_container(id, None, None).await.map(|_| ()) }.boxed(),
+    cx,
+  );
+}
+
+/// Restart several containers under a single task.
+pub fn restart_containers(ids: Vec<String>, cx: &mut App) {
+  let kind = BulkKind::container("docker restart", "Restarting", "Restarted");
+  run_bulk(ids, kind, |docker, id| docker.restart_container(id).boxed(), cx);
+}
+
+/// Force-remove several containers under a single task.
+pub fn delete_containers(ids: Vec<String>, cx: &mut App) {
+  let kind = BulkKind::container("docker rm -f", "Deleting", "Deleted");
+  run_bulk(ids, kind, |docker, id| docker.remove_container(id, true).boxed(), cx);
+}
+
+/// How a [`run_bulk`] batch describes itself in the command log, the task
+/// list and the final notification.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct BulkKind {
+  /// CLI equivalent logged with the ids appended, e.g. `docker rmi`.
+  pub command: &'static str,
+  pub progressive: &'static str,
+  pub past: &'static str,
+  /// Singular resource name: "container", "image", ...
+  pub noun: &'static str,
+  /// How a failed item is named in the summary.
+  pub label: fn(&str) -> &str,
+  /// Reloads the affected list once the batch is done.
+  pub refresh: fn(&mut App),
+}
+
+impl BulkKind {
+  fn container(command: &'static str, progressive: &'static str, past: &'static str) -> Self {
+    Self {
+      command,
+      progressive,
+      past,
+      noun: "container",
+      label: short_id,
+      refresh: refresh_containers,
+    }
+  }
+
+  /// "1 container", "3 images", ...
+  fn count(self, count: usize) -> String {
+    if count == 1 {
+      format!("1 {}", self.noun)
+    } else {
+      format!("{count} {}s", self.noun)
+    }
+  }
+}
+
+/// First 12 characters of a container or image id, without `sha256:`.
+pub(super) fn short_id(id: &str) -> &str {
+  let id = id.strip_prefix("sha256:").unwrap_or(id);
+  &id[..id.len().min(12)]
+}
+
+/// Run `op` over `ids` one after another, reporting "3/5" progress on one
+/// aggregate task. Failures don't stop the batch; they're summarised in
+/// the final notification.
+#[allow(clippy::cast_precision_loss)]
+pub(super) fn run_bulk<F>(ids: Vec<String>, kind: BulkKind, op: F, cx: &mut App)
+where
+  F: for<'a> Fn(&'a DockerClient, &'a str) -> BoxFuture<'a, anyhow::Result<()>> + Send + 'static,
+{
+  if ids.is_empty() {
+    return;
+  }
+  let total = ids.len();
+  let noun = kind.count(total);
+  log_command(cx, format!("{} {}", kind.command, ids.join(" ")));
+  let task_id = start_task(cx, format!("{} {noun}...", kind.progressive));
+  let disp = dispatcher(cx);
+  let client = docker_client();
+  let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<usize>();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let docker = guard
+      .as_ref()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    let mut failures = Vec::new();
+    for (done, id) in ids.iter().enumerate() {
+      if let Err(e) = op(docker, id).await {
+        failures.push(format!("{}: {e}", (kind.label)(id)));
+      }
+      let _ = tx.send(done + 1);
+    }
+    anyhow::Ok(failures)
+  });
+
+  cx.spawn(async move |cx| {
+    while let Some(done) = rx.recv().await {
+      let progress = done as f32 / total as f32;
+      let _ = cx.update(|cx| set_task_progress(cx, task_id, progress, Some(format!("{done}/{total}"))));
+    }
+  })
+  .detach();
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    cx.update(|cx| {
+      let event = match result {
+        Ok(failures) if failures.is_empty() => {
+          complete_task(cx, task_id);
+          DispatcherEvent::TaskCompleted {
+            message: format!("{} {noun}", kind.past),
+          }
+        }
+        Ok(failures) => {
+          let summary = format!("{} of {total} failed: {}", failures.len(), failures.join("; "));
+          fail_task(cx, task_id, summary.clone());
+          DispatcherEvent::TaskFailed {
+            error: format!("{} {noun}: {summary}", kind.progressive),
+          }
+        }
+        Err(e) => {
+          fail_task(cx, task_id, e.to_string());
+          DispatcherEvent::TaskFailed {
+            error: format!("{} {noun} failed: {e}", kind.progressive),
+          }
+        }
+      };
+      disp.update(cx, |_, cx| cx.emit(event));
+      (kind.refresh)(cx);
+    })
+  })
+  .detach();
+}
+
 pub fn start_container(id: String, cx: &mut App) {
+  log_command(cx, format!("docker start {id}"));
   let task_id = start_task(cx, "Starting container...".to_string());
+  set_task_retry(cx, task_id, TaskRetry::StartContainer { id: id.clone() });
   let disp = dispatcher(cx);
   let client = docker_client();
 
@@ -69,19 +235,18 @@ pub fn start_container(id: String, cx: &mut App) {
         refresh_containers(cx);
       }
       Ok(Err(e)) => {
-        fail_task(cx, task_id, e.to_string());
+        let retry = fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to start container: {e}"),
-          });
+          cx.emit(DispatcherEvent::task_failed(
+            format!("Failed to start container: {e}"),
+            retry,
+          ));
         });
       }
       Err(join_err) => {
-        fail_task(cx, task_id, join_err.to_string());
+        let retry = fail_task(cx, task_id, join_err.to_string());
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Task failed: {join_err}"),
-          });
+          cx.emit(DispatcherEvent::task_failed(format!("Task failed: {join_err}"), retry));
         });
       }
     })
@@ -89,8 +254,28 @@ pub fn start_container(id: String, cx: &mut App) {
   .detach();
 }
 
-pub fn stop_container(id: String, cx: &mut App) {
+/// Stop a container. `timeout` is the grace period in seconds before the
+/// daemon sends SIGKILL (Docker's 10s when `None`); `signal` replaces the
+/// image's stop signal. Reports when the stop had to escalate to a kill.
+pub fn stop_container(id: String, timeout: Option<u64>, signal: Option<String>, cx: &mut App) {
+  let mut command = String::from("docker stop");
+  if let Some(t) = timeout {
+    let _ = write!(command, " -t {t}");
+  }
+  if let Some(sig) = &signal {
+    let _ = write!(command, " -s {sig}");
+  }
+  log_command(cx, format!("{command} {id}"));
   let task_id = start_task(cx, "Stopping container...".to_string());
+  set_task_retry(
+    cx,
+    task_id,
+    TaskRetry::StopContainer {
+      id: id.clone(),
+      timeout,
+      signal: signal.clone(),
+    },
+  );
   let disp = dispatcher(cx);
   let client = docker_client();
 
@@ -99,35 +284,40 @@ pub fn stop_container(id: String, cx: &mut App) {
     let docker = guard
       .as_ref()
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
-    docker.stop_container(&id).await
+    docker.stop_container(&id, timeout, signal.as_deref()).await
   });
 
   cx.spawn(async move |cx| {
     let result = tokio_task.await;
     cx.update(|cx| match result {
-      Ok(Ok(())) => {
+      Ok(Ok(killed)) => {
         complete_task(cx, task_id);
+        let message = if killed {
+          format!(
+            "Container didn't exit within {}s and was killed",
+            timeout.unwrap_or(DEFAULT_STOP_TIMEOUT_SECS)
+          )
+        } else {
+          "Container stopped".to_string()
+        };
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: "Container stopped".to_string(),
-          });
+          cx.emit(DispatcherEvent::TaskCompleted { message });
         });
         refresh_containers(cx);
       }
       Ok(Err(e)) => {
-        fail_task(cx, task_id, e.to_string());
+        let retry = fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to stop container: {e}"),
-          });
+          cx.emit(DispatcherEvent::task_failed(
+            format!("Failed to stop container: {e}"),
+            retry,
+          ));
         });
       }
       Err(join_err) => {
-        fail_task(cx, task_id, join_err.to_string());
+        let retry = fail_task(cx, task_id, join_err.to_string());
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Task failed: {join_err}"),
-          });
+          cx.emit(DispatcherEvent::task_failed(format!("Task failed: {join_err}"), retry));
         });
       }
     })
@@ -136,7 +326,9 @@ pub fn stop_container(id: String, cx: &mut App) {
 }
 
 pub fn restart_container(id: String, cx: &mut App) {
+  log_command(cx, format!("docker restart {id}"));
   let task_id = start_task(cx, "Restarting container...".to_string());
+  set_task_retry(cx, task_id, TaskRetry::RestartContainer { id: id.clone() });
   let disp = dispatcher(cx);
   let client = docker_client();
 
@@ -160,11 +352,90 @@ pub fn restart_container(id: String, cx: &mut App) {
         });
         refresh_containers(cx);
       }
+      Ok(Err(e)) => {
+        let retry = fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::task_failed(
+            format!("Failed to restart container: {e}"),
+            retry,
+          ));
+        });
+      }
+      Err(join_err) => {
+        let retry = fail_task(cx, task_id, join_err.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::task_failed(format!("Task failed: {join_err}"), retry));
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Change a container's restart policy in place (`docker update --restart`)
+pub fn update_container_restart_policy(id: String, policy: String, cx: &mut App) {
+  log_command(cx, format!("docker update --restart {policy} {id}"));
+  let update = ContainerUpdate {
+    restart_policy: Some(policy),
+    ..Default::default()
+  };
+  run_container_update(id, update, "Updating restart policy...", cx);
+}
+
+/// Change CPU and memory limits without restarting the container.
+/// `None` keeps the current limit.
+pub fn update_container_limits(id: String, cpus: Option<f64>, memory_bytes: Option<i64>, cx: &mut App) {
+  let mut args = String::new();
+  if let Some(cpus) = cpus {
+    let _ = write!(args, " --cpus {cpus}");
+  }
+  if let Some(memory) = memory_bytes {
+    let _ = write!(args, " --memory {memory}");
+  }
+  log_command(cx, format!("docker update{args} {id}"));
+  let update = ContainerUpdate {
+    cpus,
+    memory_bytes,
+    ..Default::default()
+  };
+  run_container_update(id, update, "Updating resource limits...", cx);
+}
+
+fn run_container_update(id: String, update: ContainerUpdate, label: &str, cx: &mut App) {
+  let task_id = start_task(cx, label.to_string());
+  let disp = dispatcher(cx);
+  let state = docker_state(cx);
+  let client = docker_client();
+
+  let container_id = id.clone();
+  let tokio_task = Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let docker = guard
+      .as_ref()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker.update_container(&container_id, update).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await;
+    cx.update(|cx| match result {
+      Ok(Ok(())) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: "Container updated".to_string(),
+          });
+        });
+        state.update(cx, |_, cx| {
+          cx.emit(StateChanged::ContainerConfigUpdated { container_id: id });
+        });
+        refresh_containers(cx);
+      }
       Ok(Err(e)) => {
         fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to restart container: {e}"),
+            error: format!("Failed to update container: {e}"),
           });
         });
       }
@@ -182,6 +453,7 @@ pub fn restart_container(id: String, cx: &mut App) {
 }
 
 pub fn delete_container(id: String, cx: &mut App) {
+  log_command(cx, format!("docker rm -f {id}"));
   let task_id = start_task(cx, "Deleting container...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -228,6 +500,7 @@ pub fn delete_container(id: String, cx: &mut App) {
 }
 
 pub fn pause_container(id: String, cx: &mut App) {
+  log_command(cx, format!("docker pause {id}"));
   let task_id = start_task(cx, "Pausing container...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -274,6 +547,7 @@ pub fn pause_container(id: String, cx: &mut App) {
 }
 
 pub fn unpause_container(id: String, cx: &mut App) {
+  log_command(cx, format!("docker unpause {id}"));
   let task_id = start_task(cx, "Resuming container...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -320,6 +594,7 @@ pub fn unpause_container(id: String, cx: &mut App) {
 }
 
 pub fn kill_container(id: String, cx: &mut App) {
+  log_command(cx, format!("docker kill {id}"));
   let task_id = start_task(cx, "Killing container...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -365,7 +640,56 @@ pub fn kill_container(id: String, cx: &mut App) {
   .detach();
 }
 
+/// Attach to a container's main process (`docker attach`). The returned
+/// stream feeds a `TerminalView`; dropping it closes the session without
+/// signalling the container. Non-TTY containers are attached read-only.
+pub fn attach_container(id: String, cx: &mut App) -> Option<std::sync::Arc<AttachStream>> {
+  let (stream, input_rx, resize_rx) = match AttachStream::new(120, 40) {
+    Ok(parts) => parts,
+    Err(e) => {
+      tracing::warn!(target: "dockside.attach", err = %e, "failed to create attach terminal");
+      return None;
+    }
+  };
+  let stream = std::sync::Arc::new(stream);
+  let weak = std::sync::Arc::downgrade(&stream);
+  log_command(cx, format!("docker attach --detach-keys={ATTACH_DETACH_KEYS} {id}"));
+  let client = docker_client();
+
+  Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let result = match guard.as_ref() {
+      Some(docker) => match docker.attach_mode(&id).await {
+        Ok(mode) => {
+          if let Some(s) = weak.upgrade() {
+            s.set_interactive(mode.is_interactive());
+          }
+          let (tx, mut rx) = tokio::sync::mpsc::channel::<Vec<u8>>(64);
+          let target = weak.clone();
+          let drain = async move {
+            while let Some(chunk) = rx.recv().await {
+              let Some(s) = target.upgrade() else { break };
+              s.feed_bytes(chunk);
+            }
+          };
+          let (result, ()) = tokio::join!(docker.attach_container(&id, mode, tx, input_rx, resize_rx), drain);
+          result
+        }
+        Err(e) => Err(e),
+      },
+      None => Err(anyhow::anyhow!("Docker client not connected")),
+    };
+    if let Some(s) = weak.upgrade() {
+      s.finish(result.err().map(|e| e.to_string()));
+    }
+  })
+  .detach();
+
+  Some(stream)
+}
+
 pub fn rename_container(id: String, new_name: String, cx: &mut App) {
+  log_command(cx, format!("docker rename {id} {}", shell_quote(&new_name)));
   let task_id = start_task(cx, "Renaming container...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -419,6 +743,10 @@ pub fn commit_container(
   author: Option<String>,
   cx: &mut App,
 ) {
+  log_command(
+    cx,
+    format!("docker commit {id} {}", shell_quote(&format!("{repo}:{tag}"))),
+  );
   let task_id = start_task(cx, "Committing container...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -443,7 +771,7 @@ pub fn commit_container(
             message: format!("Container committed as image: {}", &image_id[..12.min(image_id.len())]),
           });
         });
-        super::images::refresh_images(cx);
+        refresh_images(cx);
       }
       Ok(Err(e)) => {
         fail_task(cx, task_id, e.to_string());
@@ -466,18 +794,28 @@ pub fn commit_container(
   .detach();
 }
 
-pub fn cp_to_container(id: String, src_path: std::path::PathBuf, dest_path: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Copying {} into container...", src_path.display()));
+/// Copy a host file or directory into the container directory
+/// `container_path`.
+pub fn upload_to_container(id: String, host_path: std::path::PathBuf, container_path: String, cx: &mut App) {
+  log_command(
+    cx,
+    format!(
+      "docker cp {} {id}:{}",
+      shell_quote(&host_path.display().to_string()),
+      shell_quote(&container_path)
+    ),
+  );
+  let task_id = start_task(cx, format!("Uploading {} into container...", host_path.display()));
   let disp = dispatcher(cx);
   let client = docker_client();
-  let label = src_path.display().to_string();
+  let label = host_path.display().to_string();
 
   let tokio_task = Tokio::spawn(cx, async move {
     let guard = client.read().await;
     let docker = guard
       .as_ref()
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
-    docker.cp_to_container(&id, &src_path, &dest_path).await
+    docker.cp_to_container(&id, &host_path, &container_path).await
   });
 
   cx.spawn(async move |cx| {
@@ -487,7 +825,7 @@ pub fn cp_to_container(id: String, src_path: std::path::PathBuf, dest_path: Stri
         complete_task(cx, task_id);
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Copied {label} into container"),
+            message: format!("Uploaded {label} into container"),
           });
         });
       }
@@ -495,7 +833,7 @@ pub fn cp_to_container(id: String, src_path: std::path::PathBuf, dest_path: Stri
         fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Copy to container failed: {e}"),
+            error: format!("Upload to container failed: {e}"),
           });
         });
       }
@@ -510,28 +848,106 @@ pub fn cp_to_container(id: String, src_path: std::path::PathBuf, dest_path: Stri
   .detach();
 }
 
-pub fn cp_from_container(id: String, src_path: String, dest_dir: std::path::PathBuf, cx: &mut App) {
-  let task_id = start_task(cx, format!("Copying {src_path} from container..."));
+/// Overwrite a text file inside a container with edited content. The
+/// file keeps its mode and ownership.
+pub fn write_container_file(id: String, path: String, content: String, cx: &mut App) {
+  let task_id = start_task(cx, format!("Saving {path}..."));
   let disp = dispatcher(cx);
   let client = docker_client();
-  let dest_label = dest_dir.display().to_string();
+  let write_id = id.clone();
+  let write_path = path.clone();
 
   let tokio_task = Tokio::spawn(cx, async move {
     let guard = client.read().await;
     let docker = guard
       .as_ref()
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
-    docker.cp_from_container(&id, &src_path, &dest_dir).await
+    docker
+      .write_container_file(&write_id, &write_path, content.as_bytes())
+      .await
   });
 
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    cx.update(|cx| match result {
+      Ok(()) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Saved {path}"),
+          });
+        });
+        docker_state(cx).update(cx, |_, cx| {
+          cx.emit(StateChanged::ContainerFileSaved { container_id: id, path });
+        });
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to save {path}: {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Download `container_path` into the host directory `host_path`. Files
+/// are extracted as-is; directories are saved as a `<name>.tar` tarball.
+/// The task bar shows the number of bytes received so far.
+pub fn download_from_container(id: String, container_path: String, host_path: std::path::PathBuf, cx: &mut App) {
+  log_command(
+    cx,
+    format!(
+      "docker cp {id}:{} {}",
+      shell_quote(&container_path),
+      shell_quote(&host_path.display().to_string())
+    ),
+  );
+  let task_id = start_task(cx, format!("Downloading {container_path} from container..."));
+  let disp = dispatcher(cx);
+  let client = docker_client();
+
+  let (tx, mut rx) = tokio::sync::mpsc::channel::<u64>(64);
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let docker = guard
+      .as_ref()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker
+      .download_from_container(&id, &container_path, &host_path, |received| {
+        let _ = tx.try_send(received);
+      })
+      .await
+  });
+
+  // The archive size isn't known up front, so the bar stays
+  // indeterminate and the status line carries the running byte count.
+  cx.spawn(async move |cx| {
+    while let Some(received) = rx.recv().await {
+      let _ = cx.update(|cx| {
+        set_task_progress(
+          cx,
+          task_id,
+          0.0,
+          Some(format!("{} received", bytesize::ByteSize(received))),
+        );
+      });
+    }
+  })
+  .detach();
+
   cx.spawn(async move |cx| {
     let result = tokio_task.await;
     cx.update(|cx| match result {
-      Ok(Ok(())) => {
+      Ok(Ok(written)) => {
         complete_task(cx, task_id);
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Copied to {dest_label}"),
+            message: format!("Saved to {}", written.display()),
           });
         });
       }
@@ -539,7 +955,7 @@ pub fn cp_from_container(id: String, src_path: String, dest_dir: std::path::Path
         fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Copy from container failed: {e}"),
+            error: format!("Download from container failed: {e}"),
           });
         });
       }
@@ -634,6 +1050,7 @@ pub fn request_export_container(id: String, container_name: String, cx: &mut App
 
 pub fn refresh_containers(cx: &mut App) {
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::Containers, cx);
   let client = docker_client();
 
   let tokio_task = Tokio::spawn(cx, async move {
@@ -651,6 +1068,7 @@ pub fn refresh_containers(cx: &mut App) {
       state.update(cx, |state, cx| {
         state.set_containers(containers);
         cx.emit(StateChanged::ContainersUpdated);
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::Containers));
       });
     })
   })
@@ -665,6 +1083,48 @@ pub fn create_container(options: crate::ui::containers::CreateContainerOptions,
   let disp = dispatcher(cx);
   let client = docker_client();
 
+  // Parse command and entrypoint if provided
+  let command: Option<Vec<String>> = options.command.as_deref().map(split_shell_words);
+  let entrypoint: Option<Vec<String>> = options.entrypoint.as_deref().map(split_shell_words);
+
+  let config = ContainerCreateConfig {
+    image: options.image,
+    name: options.name,
+    platform: options.platform.as_docker_arg().map(String::from),
+    command,
+    entrypoint,
+    working_dir: options.workdir,
+    restart_policy: options.restart_policy.as_docker_arg().map(String::from),
+    flags: ContainerFlags {
+      auto_remove: options.remove_after_stop,
+      privileged: options.privileged,
+      read_only: options.read_only,
+      init: options.docker_init,
+    },
+    env_vars: options.env_vars,
+    ports: options.ports,
+    volumes: options.volumes,
+    network: options.network,
+    hostname: options.hostname,
+    labels: options.labels,
+    cpus: options.cpus,
+    cpu_shares: options.cpu_shares,
+    memory_bytes: options.memory_bytes,
+    memory_swap_bytes: options.memory_swap_bytes,
+    pids_limit: options.pids_limit,
+    healthcheck_cmd: options.healthcheck_cmd,
+    healthcheck_interval_ns: options.healthcheck_interval_ns,
+    healthcheck_timeout_ns: options.healthcheck_timeout_ns,
+    healthcheck_start_period_ns: options.healthcheck_start_period_ns,
+    healthcheck_retries: options.healthcheck_retries,
+    dns: options.dns,
+    extra_hosts: options.extra_hosts,
+    cap_add: options.cap_add,
+    cap_drop: options.cap_drop,
+    security_opt: options.security_opt,
+  };
+  log_command(cx, docker_run_command(&config, start_after));
+
   let tokio_task = Tokio::spawn(cx, async move {
     let guard = client.read().await;
     let docker = guard
@@ -672,50 +1132,7 @@ pub fn create_container(options: crate::ui::containers::CreateContainerOptions,
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
 
     // Ensure image exists locally, pull if necessary
-    docker
-      .ensure_image(&options.image, options.platform.as_docker_arg())
-      .await?;
-
-    // Parse command and entrypoint if provided
-    let command: Option<Vec<String>> = options
-      .command
-      .as_ref()
-      .map(|c| c.split_whitespace().map(String::from).collect());
-    let entrypoint: Option<Vec<String>> = options
-      .entrypoint
-      .as_ref()
-      .map(|e| e.split_whitespace().map(String::from).collect());
-
-    let config = ContainerCreateConfig {
-      image: options.image,
-      name: options.name,
-      platform: options.platform.as_docker_arg().map(String::from),
-      command,
-      entrypoint,
-      working_dir: options.workdir,
-      restart_policy: options.restart_policy.as_docker_arg().map(String::from),
-      flags: ContainerFlags {
-        auto_remove: options.remove_after_stop,
-        privileged: options.privileged,
-        read_only: options.read_only,
-        init: options.docker_init,
-      },
-      env_vars: options.env_vars,
-      ports: options.ports,
-      volumes: options.volumes,
-      network: options.network,
-      hostname: options.hostname,
-      labels: options.labels,
-      cpus: options.cpus,
-      memory_bytes: options.memory_bytes,
-      memory_swap_bytes: options.memory_swap_bytes,
-      pids_limit: options.pids_limit,
-      healthcheck_cmd: options.healthcheck_cmd,
-      healthcheck_interval_ns: options.healthcheck_interval_ns,
-      healthcheck_timeout_ns: options.healthcheck_timeout_ns,
-      healthcheck_start_period_ns: options.healthcheck_start_period_ns,
-      healthcheck_retries: options.healthcheck_retries,
-    };
+    docker.ensure_image(&config.image, config.platform.as_deref()).await?;
 
     let container_id = docker.create_container(config).await?;
 
@@ -865,5 +1282,15 @@ pub fn open_container_in_editor(
         });
       }
     }
+    return;
   }
+
+  disp.update(cx, |_, cx| {
+    cx.emit(DispatcherEvent::TaskFailed {
+      error: format!(
+        "{} can't open folders inside a container. Choose VS Code, Cursor or Zed in Settings > Editor.",
+        editor.display_name()
+      ),
+    });
+  });
 }
@@ -0,0 +1,118 @@
+//! Docker CLI context discovery + switching.
+//!
+//! Switching runs `docker context use` so CLI-driven features follow along,
+//! reconnects the shared `DockerClient` to the context's endpoint, and
+//! persists that endpoint to `settings.docker_socket` so a later full
+//! reload stays on the chosen daemon.
+
+use gpui::App;
+
+use crate::docker::DockerClient;
+use crate::platform::DockerRuntime;
+use crate::services::{Tokio, complete_task, fail_task, log_command, shell_quote, start_task};
+use crate::state::{SettingsChanged, StateChanged, docker_state, settings_state};
+
+use super::super::core::{DispatcherEvent, dispatcher, docker_client};
+use super::{refresh_containers, refresh_images, refresh_networks, refresh_volumes};
+
+/// Reload the `docker context ls` list into state.
+pub fn list_docker_contexts(cx: &mut App) {
+  let state = docker_state(cx);
+  let tokio_task = Tokio::spawn(cx, async move { crate::docker::list_docker_contexts() });
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    cx.update(|cx| match result {
+      Ok(contexts) => {
+        state.update(cx, |s, cx| {
+          s.set_docker_contexts(contexts);
+          cx.emit(StateChanged::DockerContextsUpdated);
+        });
+      }
+      // No docker CLI (or an old one): the switcher just stays empty.
+      Err(e) => tracing::debug!("docker context ls failed: {e}"),
+    })
+  })
+  .detach();
+}
+
+/// Switch to a Docker CLI context and reconnect the shared client to its
+/// daemon, then refresh every Docker list. No-op if `name` is already the
+/// current context.
+pub fn set_docker_context(name: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let disp = dispatcher(cx);
+  let Some(context) = state.read(cx).docker_contexts.iter().find(|c| c.name == name).cloned() else {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: format!("Unknown Docker context '{name}'"),
+      });
+    });
+    return;
+  };
+  if context.current {
+    return;
+  }
+  let connection_string = match context.connection_string() {
+    Ok(s) => s,
+    Err(e) => {
+      disp.update(cx, |_, cx| {
+        cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+      });
+      return;
+    }
+  };
+
+  let task_id = start_task(cx, format!("Switching to Docker context '{name}'..."));
+  log_command(cx, format!("docker context use {}", shell_quote(&name)));
+
+  let client_handle = docker_client();
+  let target = name.clone();
+  let endpoint = connection_string.clone();
+  let tokio_task = Tokio::spawn(cx, async move {
+    let mut new_client = DockerClient::new(DockerRuntime::Custom {
+      connection_string: endpoint,
+    });
+    new_client.connect().await?;
+    crate::docker::use_docker_context(&target)?;
+    *client_handle.write().await = Some(new_client);
+    anyhow::Ok(())
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    cx.update(|cx| match result {
+      Ok(()) => {
+        settings_state(cx).update(cx, |s, cx| {
+          s.settings.docker_socket = connection_string;
+          if let Err(e) = s.settings.save() {
+            tracing::warn!("Failed to persist docker_socket: {e}");
+          }
+          cx.emit(SettingsChanged::SettingsUpdated);
+        });
+        state.update(cx, |s, cx| {
+          s.mark_current_docker_context(&name);
+          cx.emit(StateChanged::DockerContextsUpdated);
+        });
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Switched to Docker context '{name}'"),
+          });
+        });
+        refresh_containers(cx);
+        refresh_images(cx);
+        refresh_volumes(cx);
+        refresh_networks(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to switch to Docker context '{name}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
@@ -1,14 +1,23 @@
 //! Image operations
 
-use gpui::App;
+use std::collections::HashMap;
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{ImageInspectData, StateChanged, docker_state};
+use futures::FutureExt;
+use gpui::{App, Global};
 
-use super::super::core::{DispatcherEvent, dispatcher, docker_client};
+use crate::docker::{ImageRemoveConflict, PullProgress, RegistryAuth};
+use crate::services::{
+  TaskPart, TaskRetry, TaskStage, Tokio, complete_task, fail_task, log_command, set_task_retry, set_task_stage,
+  shell_quote, start_staged_task, start_task,
+};
+use crate::state::{CurrentView, ImageInspectData, StateChanged, docker_state};
+
+use super::super::core::{DispatcherEvent, dispatcher, docker_client, mark_refreshing};
+use super::containers::{BulkKind, run_bulk, short_id};
 
 pub fn refresh_images(cx: &mut App) {
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::Images, cx);
   let client = docker_client();
 
   let tokio_task = Tokio::spawn(cx, async move {
@@ -26,23 +35,62 @@ pub fn refresh_images(cx: &mut App) {
       state.update(cx, |state, cx| {
         state.set_images(images);
         cx.emit(StateChanged::ImagesUpdated);
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::Images));
       });
     })
   })
   .detach();
 }
 
-pub fn delete_image(id: String, cx: &mut App) {
+pub fn toggle_image_bulk_selection(id: &str, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |s, cx| {
+    s.toggle_bulk_image(id);
+    cx.notify();
+  });
+}
+
+pub fn clear_image_bulk_selection(cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |s, cx| {
+    s.selected_image_ids.clear();
+    cx.notify();
+  });
+}
+
+/// Remove every image ticked in the list under one task. Images still in
+/// use are reported as failures rather than forced.
+pub fn delete_selected_images(cx: &mut App) {
+  let ids: Vec<String> = docker_state(cx).read(cx).selected_image_ids.iter().cloned().collect();
+  let kind = BulkKind {
+    command: "docker rmi",
+    progressive: "Deleting",
+    past: "Deleted",
+    noun: "image",
+    label: short_id,
+    refresh: refresh_images,
+  };
+  run_bulk(ids, kind, |docker, id| docker.remove_image(id, false).boxed(), cx);
+  clear_image_bulk_selection(cx);
+}
+
+/// Remove an image. Without `force` the daemon refuses images that are
+/// still referenced; forceable conflicts come back as
+/// `StateChanged::ImageDeleteConflict` so the view can ask to force.
+pub fn delete_image(id: String, force: bool, cx: &mut App) {
+  log_command(cx, format!("docker rmi {}{id}", if force { "-f " } else { "" }));
   let task_id = start_task(cx, "Deleting image...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
+  let state = docker_state(cx);
 
+  let image_id = id.clone();
   let tokio_task = Tokio::spawn(cx, async move {
     let guard = client.read().await;
     let docker = guard
       .as_ref()
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
-    docker.remove_image(&id, true).await
+    docker.remove_image(&image_id, force).await
   });
 
   cx.spawn(async move |cx| {
@@ -58,10 +106,24 @@ pub fn delete_image(id: String, cx: &mut App) {
         refresh_images(cx);
       }
       Ok(Err(e)) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
-        });
+        let conflict = ImageRemoveConflict::from_error(&e);
+        let error = conflict.as_ref().map_or_else(|| e.to_string(), |c| c.message.clone());
+        fail_task(cx, task_id, error.clone());
+        match conflict {
+          Some(conflict) if conflict.can_force && !force => {
+            state.update(cx, |_, cx| {
+              cx.emit(StateChanged::ImageDeleteConflict {
+                image_id: id,
+                message: conflict.message,
+              });
+            });
+          }
+          _ => {
+            disp.update(cx, |_, cx| {
+              cx.emit(DispatcherEvent::TaskFailed { error });
+            });
+          }
+        }
       }
       Err(e) => {
         fail_task(cx, task_id, e.to_string());
@@ -261,7 +323,8 @@ pub fn scan_all_images(cx: &mut App) {
   .detach();
 }
 
-/// Scan an image with Trivy in the background and emit progress events.
+/// Scan one image with Docker Scout (or Trivy when Scout isn't installed)
+/// as a background task. Results land in the image's Vulnerabilities tab.
 pub fn scan_image(image_id: String, image_ref: String, cx: &mut App) {
   let state = docker_state(cx);
   state.update(cx, |_, cx| {
@@ -270,28 +333,68 @@ pub fn scan_image(image_id: String, image_ref: String, cx: &mut App) {
     });
   });
 
+  let task_id = start_staged_task(
+    cx,
+    format!("Scanning {image_ref}..."),
+    vec![
+      TaskStage::new("Detecting scanner..."),
+      TaskStage::new(format!("Scanning {image_ref}...")),
+    ],
+  );
+  let disp = dispatcher(cx);
+
   cx.spawn(async move |cx| {
-    let result = cx
+    // Scanners are blocking subprocesses; run them on the executor's
+    // background pool so we don't block any async runtime.
+    let scanner = cx
       .background_executor()
-      .spawn(async move {
-        // Trivy is a blocking subprocess; run on the executor's
-        // background pool so we don't block any async runtime.
-        crate::docker::scan_image(&image_ref)
-      })
+      .spawn(async move { crate::docker::Scanner::detect() })
       .await;
 
+    let result = match scanner {
+      Some(scanner) => {
+        let _ = cx.update(|cx| {
+          log_command(cx, scanner.command_line(&image_ref));
+          set_task_stage(cx, task_id, 1, Some(format!("Running {}...", scanner.label())));
+        });
+        let target = image_ref.clone();
+        cx.background_executor()
+          .spawn(async move { scanner.scan(&target) })
+          .await
+      }
+      None => Err(anyhow::anyhow!(crate::docker::ERR_SCANNER_NOT_INSTALLED)),
+    };
+
     let _ = cx.update(|cx| {
       let state = docker_state(cx);
       match result {
-        Ok(summary) => state.update(cx, |_, cx| {
-          cx.emit(StateChanged::ImageScanCompleted { image_id, summary });
-        }),
-        Err(e) => state.update(cx, |_, cx| {
-          cx.emit(StateChanged::ImageScanFailed {
-            image_id,
-            error: e.to_string(),
+        Ok(summary) => {
+          complete_task(cx, task_id);
+          let total = summary.vulns.len();
+          let message = format!(
+            "{} found {total} vulnerabilit{} in {image_ref}",
+            summary.scanner,
+            if total == 1 { "y" } else { "ies" }
+          );
+          state.update(cx, |_, cx| {
+            cx.emit(StateChanged::ImageScanCompleted { image_id, summary });
           });
-        }),
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskCompleted { message });
+          });
+        }
+        Err(e) => {
+          let error = e.to_string();
+          let message = if error == crate::docker::ERR_SCANNER_NOT_INSTALLED {
+            "No vulnerability scanner installed (Docker Scout or Trivy)".to_string()
+          } else {
+            error.clone()
+          };
+          fail_task(cx, task_id, message);
+          state.update(cx, |_, cx| {
+            cx.emit(StateChanged::ImageScanFailed { image_id, error });
+          });
+        }
       }
     });
   })
@@ -299,6 +402,14 @@ pub fn scan_image(image_id: String, image_ref: String, cx: &mut App) {
 }
 
 pub fn tag_image(source: String, repo: String, tag: String, cx: &mut App) {
+  log_command(
+    cx,
+    format!(
+      "docker tag {} {}",
+      shell_quote(&source),
+      shell_quote(&format!("{repo}:{tag}"))
+    ),
+  );
   let task_id = start_task(cx, format!("Tagging {source} as {repo}:{tag}..."));
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -340,15 +451,30 @@ pub fn tag_image(source: String, repo: String, tag: String, cx: &mut App) {
   .detach();
 }
 
-pub fn push_image(image: String, tag: String, username: Option<String>, password: Option<String>, cx: &mut App) {
+/// Registry credentials entered during this session, keyed by registry
+/// host. Never written to disk; gone when the app quits.
+#[derive(Default)]
+struct SessionRegistryAuth(HashMap<String, RegistryAuth>);
+
+impl Global for SessionRegistryAuth {}
+
+/// Credentials remembered for `registry` earlier in this session.
+pub fn cached_registry_auth(registry: &str, cx: &App) -> Option<RegistryAuth> {
+  cx.try_global::<SessionRegistryAuth>()
+    .and_then(|cache| cache.0.get(registry).cloned())
+}
+
+/// Push `image:tag`, optionally with registry credentials. Credentials are
+/// cached in memory for the rest of the session once the push succeeds.
+pub fn push_image(image: String, tag: String, auth: Option<RegistryAuth>, cx: &mut App) {
+  log_command(cx, format!("docker push {}", shell_quote(&format!("{image}:{tag}"))));
   let task_id = start_task(cx, format!("Pushing {image}:{tag}..."));
   let disp = dispatcher(cx);
   let client = docker_client();
+  let label = format!("{image}:{tag}");
+  let auth_to_cache = auth.clone();
 
-  let auth = match (username, password) {
-    (Some(u), Some(p)) => Some((u, p)),
-    _ => None,
-  };
+  let (tx, mut rx) = tokio::sync::mpsc::channel::<crate::docker::PushProgressEvent>(64);
 
   let tokio_task = Tokio::spawn(cx, async move {
     let guard = client.read().await;
@@ -356,20 +482,42 @@ pub fn push_image(image: String, tag: String, username: Option<String>, password
       .as_ref()
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
     docker
-      .push_image_with_progress(&image, &tag, auth, |line| {
-        tracing::debug!(target: "docker.push", "{line}");
+      .push_image_with_progress(&image, &tag, auth, |ev| {
+        tracing::debug!(target: "docker.push", "{}", ev.status);
+        let _ = tx.try_send(ev);
       })
       .await
   });
 
+  // Push events carry no layer id, so the bar follows whichever layer is
+  // uploading and holds its position while the daemon reports stage-only
+  // lines ("Preparing", "Layer already exists", ...).
+  cx.spawn(async move |cx| {
+    let mut frac = 0.0;
+    while let Some(ev) = rx.recv().await {
+      if let Some(f) = ev.fraction() {
+        frac = f;
+      }
+      let _ = cx.update(|cx| {
+        crate::services::task_manager::set_task_progress(cx, task_id, frac, Some(ev.status));
+      });
+    }
+  })
+  .detach();
+
   cx.spawn(async move |cx| {
     let result = tokio_task.await;
     cx.update(|cx| match result {
       Ok(Ok(())) => {
+        if let Some(auth) = auth_to_cache {
+          cx.default_global::<SessionRegistryAuth>()
+            .0
+            .insert(auth.registry.clone(), auth);
+        }
         complete_task(cx, task_id);
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskCompleted {
-            message: "Image pushed".to_string(),
+            message: format!("Image {label} pushed"),
           });
         });
       }
@@ -390,15 +538,23 @@ pub fn push_image(image: String, tag: String, username: Option<String>, password
   .detach();
 }
 
-/// Stream `docker save` to a path the user picked. Emits progress
-/// (bytes-written) as a fraction-less status string so the existing
-/// task UI animates without inventing a fake denominator.
+/// Stream `docker save` to a path the user picked. Progress is the bytes
+/// written against the image's size from the list, which the archive
+/// roughly matches; without a known size only the byte count is shown.
 pub fn save_image(image_ref: String, dest: std::path::PathBuf, cx: &mut App) {
   let task_id = start_task(cx, format!("Saving {image_ref} to {}...", dest.display()));
   let disp = dispatcher(cx);
   let client = docker_client();
   let dest_for_msg = dest.clone();
+  let expected = docker_state(cx)
+    .read(cx)
+    .images
+    .iter()
+    .find(|i| i.id == image_ref || i.repo_tags.contains(&image_ref))
+    .and_then(|i| u64::try_from(i.size).ok())
+    .filter(|size| *size > 0);
 
+  let (tx, mut rx) = tokio::sync::mpsc::channel::<u64>(16);
   let tokio_task = Tokio::spawn(cx, async move {
     let guard = client.read().await;
     let docker = guard
@@ -406,11 +562,21 @@ pub fn save_image(image_ref: String, dest: std::path::PathBuf, cx: &mut App) {
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
     docker
       .save_image(&image_ref, &dest, |bytes| {
-        tracing::debug!(target: "docker.save", image = %image_ref, bytes);
+        let _ = tx.try_send(bytes);
       })
       .await
   });
 
+  cx.spawn(async move |cx| {
+    while let Some(written) = rx.recv().await {
+      let (frac, status) = transfer_progress(written, expected);
+      let _ = cx.update(|cx| {
+        crate::services::task_manager::set_task_progress(cx, task_id, frac, Some(status));
+      });
+    }
+  })
+  .detach();
+
   cx.spawn(async move |cx| {
     let result = tokio_task.await;
     cx.update(|cx| match result {
@@ -439,34 +605,55 @@ pub fn save_image(image_ref: String, dest: std::path::PathBuf, cx: &mut App) {
   .detach();
 }
 
-/// Read a tarball from `src` and POST it to `/images/load`. The image
-/// list is refreshed on success.
+/// Upload a tarball from `src` to `/images/load`, reporting how much has
+/// been sent. The image list is refreshed on success.
 pub fn load_image(src: std::path::PathBuf, cx: &mut App) {
   let task_id = start_task(cx, format!("Loading {}...", src.display()));
   let disp = dispatcher(cx);
   let client = docker_client();
 
+  let (tx, mut rx) = tokio::sync::mpsc::channel::<(u64, u64)>(16);
   let tokio_task = Tokio::spawn(cx, async move {
     let guard = client.read().await;
     let docker = guard
       .as_ref()
       .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
     docker
-      .load_image(&src, |line| {
-        tracing::debug!(target: "docker.load", "{line}");
+      .load_image(&src, move |sent, total| {
+        let _ = tx.try_send((sent, total));
       })
       .await
   });
 
+  cx.spawn(async move |cx| {
+    while let Some((sent, total)) = rx.recv().await {
+      let (frac, status) = transfer_progress(sent, Some(total).filter(|t| *t > 0));
+      let _ = cx.update(|cx| {
+        crate::services::task_manager::set_task_progress(cx, task_id, frac, Some(status));
+      });
+    }
+  })
+  .detach();
+
   cx.spawn(async move |cx| {
     let result = tokio_task.await;
     cx.update(|cx| match result {
-      Ok(Ok(())) => {
+      Ok(Ok(lines)) => {
         complete_task(cx, task_id);
+        let loaded: Vec<&str> = lines
+          .iter()
+          .filter_map(|l| {
+            l.strip_prefix("Loaded image: ")
+              .or_else(|| l.strip_prefix("Loaded image ID: "))
+          })
+          .collect();
+        let message = if loaded.is_empty() {
+          "Image loaded".to_string()
+        } else {
+          format!("Loaded {}", loaded.join(", "))
+        };
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: "Image loaded".to_string(),
-          });
+          cx.emit(DispatcherEvent::TaskCompleted { message });
         });
         refresh_images(cx);
       }
@@ -487,8 +674,65 @@ pub fn load_image(src: std::path::PathBuf, cx: &mut App) {
   .detach();
 }
 
+/// Task progress for `done` bytes of an `expected` total. The fraction
+/// stops short of 1 since the archive size is only an estimate; the task
+/// completes when the transfer does.
+fn transfer_progress(done: u64, expected: Option<u64>) -> (f32, String) {
+  let done_text = bytesize::ByteSize(done).to_string();
+  match expected {
+    Some(total) => {
+      #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+      let frac = (done as f64 / total as f64).min(0.99) as f32;
+      (frac, format!("{done_text} / {}", bytesize::ByteSize(total)))
+    }
+    None => (0.0, done_text),
+  }
+}
+
+/// One task part per layer, with byte counts while it transfers
+fn layer_parts(progress: &PullProgress) -> Vec<TaskPart> {
+  progress
+    .layers
+    .iter()
+    .map(|layer| TaskPart {
+      label: layer.id.chars().take(12).collect(),
+      status: if layer.total > 0 && !layer.phase.is_done() {
+        format!(
+          "{} {} / {}",
+          layer.phase.label(),
+          bytesize::ByteSize(u64::try_from(layer.current).unwrap_or(0)),
+          bytesize::ByteSize(u64::try_from(layer.total).unwrap_or(0))
+        )
+      } else {
+        layer.phase.label().to_string()
+      },
+      progress: layer.fraction(),
+      done: layer.phase.is_done(),
+    })
+    .collect()
+}
+
 pub fn pull_image(image: String, platform: Option<String>, cx: &mut App) {
-  let task_id = start_task(cx, format!("Pulling image {image}..."));
+  log_command(
+    cx,
+    match &platform {
+      Some(p) => format!("docker pull --platform {} {}", shell_quote(p), shell_quote(&image)),
+      None => format!("docker pull {}", shell_quote(&image)),
+    },
+  );
+  let task_id = start_staged_task(
+    cx,
+    format!("Pulling image {image}..."),
+    PullProgress::STAGES.iter().map(|s| TaskStage::new(*s)).collect(),
+  );
+  set_task_retry(
+    cx,
+    task_id,
+    TaskRetry::PullImage {
+      image: image.clone(),
+      platform: platform.clone(),
+    },
+  );
   let disp = dispatcher(cx);
   let client = docker_client();
 
@@ -508,30 +752,29 @@ pub fn pull_image(image: String, platform: Option<String>, cx: &mut App) {
       .await
   });
 
-  // Drain the progress channel on the UI side and update the task.
+  // Drain the progress channel on the UI side and update the task with
+  // one part per layer. Lines about no layer in particular ("Digest: ...",
+  // "Status: Downloaded ...") become the status line.
   cx.spawn(async move |cx| {
-    // Per-layer total/current state for an aggregate fraction.
-    let mut totals: std::collections::HashMap<String, (i64, i64)> = std::collections::HashMap::new();
+    let mut progress = PullProgress::default();
     while let Some(ev) = rx.recv().await {
-      if let (Some(cur), Some(tot)) = (ev.current, ev.total)
-        && tot > 0
-      {
-        totals.insert(ev.id.clone(), (cur, tot));
-      }
-      let (sum_cur, sum_tot): (i64, i64) = totals.values().fold((0i64, 0i64), |(a, b), (c, t)| (a + c, b + t));
-      #[allow(clippy::cast_precision_loss)]
-      let frac = if sum_tot > 0 {
-        (sum_cur as f32) / (sum_tot as f32)
-      } else {
-        0.0
-      };
-      let status = if ev.id.is_empty() {
-        ev.status.clone()
+      let parts = progress.apply(&ev).then(|| layer_parts(&progress));
+      let status = if parts.is_some() {
+        progress.summary()
+      } else if ev.id.is_empty() {
+        ev.status
       } else {
         format!("{}: {}", ev.id, ev.status)
       };
+      let stage = progress.stage();
       let _ = cx.update(|cx| {
-        crate::services::task_manager::set_task_progress(cx, task_id, frac, Some(status));
+        crate::services::task_manager(cx).update(cx, |manager, cx| {
+          manager.set_stage(task_id, stage, Some(status));
+          if let Some(parts) = parts {
+            manager.set_parts(task_id, parts);
+          }
+          cx.notify();
+        });
       });
     }
   })
@@ -550,15 +793,15 @@ pub fn pull_image(image: String, platform: Option<String>, cx: &mut App) {
         refresh_images(cx);
       }
       Ok(Err(e)) => {
-        fail_task(cx, task_id, e.to_string());
+        let retry = fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+          cx.emit(DispatcherEvent::task_failed(e.to_string(), retry));
         });
       }
       Err(e) => {
-        fail_task(cx, task_id, e.to_string());
+        let retry = fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+          cx.emit(DispatcherEvent::task_failed(e.to_string(), retry));
         });
       }
     })
@@ -566,6 +809,125 @@ pub fn pull_image(image: String, platform: Option<String>, cx: &mut App) {
   .detach();
 }
 
+/// Pull a list of images one after another as a single staged task (one
+/// stage per image). Images already present locally are skipped unless
+/// `force` is set. The image list refreshes once at the end and a summary
+/// notification reports successes and failures.
+pub fn pull_images(images: Vec<String>, platform: Option<String>, force: bool, cx: &mut App) {
+  let disp = dispatcher(cx);
+  let (to_pull, skipped): (Vec<String>, Vec<String>) = if force {
+    (images, Vec::new())
+  } else {
+    let local = docker_state(cx).read(cx).images.clone();
+    images
+      .into_iter()
+      .partition(|image| !local.iter().any(|i| i.matches_ref(image)))
+  };
+
+  if to_pull.is_empty() {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskCompleted {
+        message: format!("All {} images are already present", skipped.len()),
+      });
+    });
+    return;
+  }
+
+  for image in &to_pull {
+    log_command(
+      cx,
+      match &platform {
+        Some(p) => format!("docker pull --platform {} {}", shell_quote(p), shell_quote(image)),
+        None => format!("docker pull {}", shell_quote(image)),
+      },
+    );
+  }
+  let total = to_pull.len();
+  let stages = to_pull
+    .iter()
+    .map(|image| TaskStage::new(format!("Pulling {image}...")))
+    .collect();
+  let task_id = start_staged_task(cx, format!("Pulling {total} images..."), stages);
+  let client = docker_client();
+
+  // (image index, progress event) from the sequential pull loop.
+  let (tx, mut rx) = tokio::sync::mpsc::channel::<(usize, crate::docker::PullProgressEvent)>(64);
+
+  let images_for_call = to_pull.clone();
+  let tokio_task = Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let mut results = Vec::with_capacity(images_for_call.len());
+    for (idx, image) in images_for_call.into_iter().enumerate() {
+      let result = match guard.as_ref() {
+        Some(docker) => {
+          let tx = tx.clone();
+          docker
+            .pull_image_with_progress(&image, platform.as_deref(), move |ev| {
+              let _ = tx.try_send((idx, ev));
+            })
+            .await
+            .map_err(|e| e.to_string())
+        }
+        None => Err("Docker client not connected".to_string()),
+      };
+      results.push((image, result));
+    }
+    results
+  });
+
+  cx.spawn(async move |cx| {
+    while let Some((idx, ev)) = rx.recv().await {
+      let status = if ev.id.is_empty() {
+        ev.status.clone()
+      } else {
+        format!("{}: {}", ev.id, ev.status)
+      };
+      let _ = cx.update(|cx| {
+        set_task_stage(cx, task_id, idx, Some(format!("{}/{total} {status}", idx + 1)));
+      });
+    }
+  })
+  .detach();
+
+  cx.spawn(async move |cx| {
+    let results = tokio_task
+      .await
+      .unwrap_or_else(|e| to_pull.into_iter().map(|image| (image, Err(e.to_string()))).collect());
+    let failed: Vec<String> = results
+      .iter()
+      .filter_map(|(image, r)| r.as_ref().err().map(|e| format!("{image} ({e})")))
+      .collect();
+    let pulled = results.len() - failed.len();
+    let skipped_note = if skipped.is_empty() {
+      String::new()
+    } else {
+      format!(", {} already present", skipped.len())
+    };
+
+    let _ = cx.update(|cx| {
+      refresh_images(cx);
+      if failed.is_empty() {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Pulled {pulled} images{skipped_note}"),
+          });
+        });
+      } else {
+        let error = format!(
+          "Pulled {pulled} of {total} images{skipped_note}. Failed: {}",
+          failed.join(", ")
+        );
+        fail_task(cx, task_id, error.clone());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed { error });
+        });
+      }
+    });
+  })
+  .detach();
+}
+
 pub fn inspect_image(image_id: String, cx: &mut App) {
   let state = docker_state(cx);
   let client = docker_client();
@@ -609,16 +971,12 @@ pub fn inspect_image(image_id: String, cx: &mut App) {
       })
       .collect();
 
-    // Image history (per-layer breakdown).
-    let history = docker.image_history(&image_id).await.unwrap_or_default();
-
     Ok::<_, anyhow::Error>((
       config_cmd,
       config_workdir,
       config_env,
       config_entrypoint,
       config_exposed_ports,
-      history,
       image_id,
     ))
   });
@@ -626,15 +984,8 @@ pub fn inspect_image(image_id: String, cx: &mut App) {
   cx.spawn(async move |cx| {
     let result = tokio_task.await;
     cx.update(|cx| {
-      if let Ok(Ok((
-        config_cmd,
-        config_workdir,
-        config_env,
-        config_entrypoint,
-        config_exposed_ports,
-        history,
-        _image_id,
-      ))) = result
+      if let Ok(Ok((config_cmd, config_workdir, config_env, config_entrypoint, config_exposed_ports, _image_id))) =
+        result
       {
         // Get containers using this image
         let docker_state_entity = docker_state(cx);
@@ -655,7 +1006,6 @@ pub fn inspect_image(image_id: String, cx: &mut App) {
               config_entrypoint,
               config_exposed_ports,
               used_by,
-              history,
               scan: None,
               scan_loading: false,
               scan_error: None,
@@ -667,3 +1017,50 @@ pub fn inspect_image(image_id: String, cx: &mut App) {
   })
   .detach();
 }
+
+/// Load the per-layer history of an image for the detail panel.
+pub fn get_image_history(image_id: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let client = docker_client();
+  let image_id_clone = image_id.clone();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let docker = guard
+      .as_ref()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker.image_history(&image_id).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    let history = result.unwrap_or_else(|e| {
+      tracing::warn!(target: "docker.history", image = %image_id_clone, err = %e, "failed to load image history");
+      Vec::new()
+    });
+
+    cx.update(|cx| {
+      state.update(cx, |_state, cx| {
+        cx.emit(StateChanged::ImageHistoryLoaded {
+          image_id: image_id_clone,
+          history,
+        });
+      });
+    })
+  })
+  .detach();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_transfer_progress() {
+    assert!((transfer_progress(512, Some(1024)).0 - 0.5).abs() < f32::EPSILON);
+    assert!((transfer_progress(4096, Some(1024)).0 - 0.99).abs() < f32::EPSILON);
+    let (frac, status) = transfer_progress(2048, None);
+    assert!(frac.abs() < f32::EPSILON);
+    assert_eq!(status, bytesize::ByteSize(2048).to_string());
+  }
+}
@@ -2,12 +2,16 @@
 
 pub mod compose;
 pub mod containers;
+pub mod contexts;
 pub mod images;
 pub mod networks;
+pub mod stats;
 pub mod volumes;
 
 pub use compose::*;
 pub use containers::*;
+pub use contexts::*;
 pub use images::*;
 pub use networks::*;
+pub use stats::*;
 pub use volumes::*;
@@ -2,13 +2,15 @@
 
 use gpui::App;
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{StateChanged, docker_state};
+use crate::services::{Tokio, complete_task, fail_task, log_command, shell_quote, start_task};
+use crate::state::{CurrentView, StateChanged, docker_state};
 
-use super::super::core::{DispatcherEvent, dispatcher, docker_client};
+use super::super::core::{DispatcherEvent, dispatcher, docker_client, mark_refreshing};
+use super::refresh_containers;
 
 pub fn refresh_networks(cx: &mut App) {
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::Networks, cx);
   let client = docker_client();
 
   let tokio_task = Tokio::spawn(cx, async move {
@@ -22,18 +24,52 @@ pub fn refresh_networks(cx: &mut App) {
   cx.spawn(async move |cx| {
     let result = tokio_task.await;
     cx.update(|cx| {
-      if let Ok(Ok(networks)) = result {
-        state.update(cx, |state, cx| {
+      state.update(cx, |state, cx| {
+        if let Ok(Ok(networks)) = result {
           state.set_networks(networks);
           cx.emit(StateChanged::NetworksUpdated);
+        } else {
+          state.finish_refresh(CurrentView::Networks, false);
+        }
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::Networks));
+      });
+    })
+  })
+  .detach();
+}
+
+/// Load `docker network inspect` for the detail panel; the list endpoint
+/// leaves out attached containers and their addresses.
+pub fn inspect_network(network_id: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let client = docker_client();
+  let id = network_id.clone();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let guard = client.read().await;
+    let docker = guard
+      .as_ref()
+      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+    docker.inspect_network(&id).await
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await;
+    cx.update(|cx| match result {
+      Ok(Ok(network)) => {
+        state.update(cx, |_state, cx| {
+          cx.emit(StateChanged::NetworkInspectLoaded { network_id, network });
         });
       }
+      Ok(Err(e)) => tracing::debug!("docker network inspect {network_id} failed: {e}"),
+      Err(e) => tracing::debug!("docker network inspect {network_id} failed: {e}"),
     })
   })
   .detach();
 }
 
 pub fn create_network(name: String, enable_ipv6: bool, subnet: Option<String>, cx: &mut App) {
+  log_command(cx, format!("docker network create {}", shell_quote(&name)));
   let task_id = start_task(cx, format!("Creating network {name}..."));
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -75,9 +111,50 @@ pub fn create_network(name: String, enable_ipv6: bool, subnet: Option<String>, c
   .detach();
 }
 
+/// Display names for a container/network pair, falling back to the IDs.
+fn network_pair_names(network_id: &str, container_id: &str, cx: &App) -> (String, String) {
+  let state = docker_state(cx).read(cx);
+  let network = state
+    .networks
+    .iter()
+    .find(|n| n.id == network_id || n.name == network_id)
+    .map_or_else(|| network_id.to_string(), |n| n.name.clone());
+  let container = state
+    .containers
+    .iter()
+    .find(|c| c.id == container_id || c.name == container_id)
+    .map_or_else(|| container_id.to_string(), |c| c.name.clone());
+  (network, container)
+}
+
+/// Attach a container to a network (`network_id` may also be a name), then
+/// refresh both lists. Attaching twice is reported without a daemon call.
 pub fn connect_container_to_network(network_id: String, container_id: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Connecting container to network {network_id}..."));
   let disp = dispatcher(cx);
+  let (network_name, container_name) = network_pair_names(&network_id, &container_id, cx);
+  let already_connected = docker_state(cx)
+    .read(cx)
+    .containers
+    .iter()
+    .any(|c| c.id == container_id && c.networks_used.contains(&network_name));
+  if already_connected {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: format!("{container_name} is already connected to {network_name}"),
+      });
+    });
+    return;
+  }
+
+  log_command(
+    cx,
+    format!(
+      "docker network connect {} {}",
+      shell_quote(&network_name),
+      shell_quote(&container_name)
+    ),
+  );
+  let task_id = start_task(cx, format!("Connecting {container_name} to {network_name}..."));
   let client = docker_client();
 
   let tokio_task = Tokio::spawn(cx, async move {
@@ -89,27 +166,23 @@ pub fn connect_container_to_network(network_id: String, container_id: String, cx
   });
 
   cx.spawn(async move |cx| {
-    let result = tokio_task.await;
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
     cx.update(|cx| match result {
-      Ok(Ok(())) => {
+      Ok(()) => {
         complete_task(cx, task_id);
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskCompleted {
-            message: "Container connected".to_string(),
+            message: format!("Connected {container_name} to {network_name}"),
           });
         });
         refresh_networks(cx);
-      }
-      Ok(Err(e)) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
-        });
+        refresh_containers(cx);
       }
       Err(e) => {
-        fail_task(cx, task_id, e.to_string());
+        let error = crate::docker::describe_network_connect_error(&e.to_string(), &container_name, &network_name);
+        fail_task(cx, task_id, error.clone());
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+          cx.emit(DispatcherEvent::TaskFailed { error });
         });
       }
     })
@@ -117,8 +190,19 @@ pub fn connect_container_to_network(network_id: String, container_id: String, cx
   .detach();
 }
 
+/// Detach a container from a network, then refresh both lists.
 pub fn disconnect_container_from_network(network_id: String, container_id: String, force: bool, cx: &mut App) {
-  let task_id = start_task(cx, format!("Disconnecting container from network {network_id}..."));
+  let (network_name, container_name) = network_pair_names(&network_id, &container_id, cx);
+  log_command(
+    cx,
+    format!(
+      "docker network disconnect {}{} {}",
+      if force { "--force " } else { "" },
+      shell_quote(&network_name),
+      shell_quote(&container_name)
+    ),
+  );
+  let task_id = start_task(cx, format!("Disconnecting {container_name} from {network_name}..."));
   let disp = dispatcher(cx);
   let client = docker_client();
 
@@ -133,27 +217,24 @@ pub fn disconnect_container_from_network(network_id: String, container_id: Strin
   });
 
   cx.spawn(async move |cx| {
-    let result = tokio_task.await;
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
     cx.update(|cx| match result {
-      Ok(Ok(())) => {
+      Ok(()) => {
         complete_task(cx, task_id);
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskCompleted {
-            message: "Container disconnected".to_string(),
+            message: format!("Disconnected {container_name} from {network_name}"),
           });
         });
         refresh_networks(cx);
-      }
-      Ok(Err(e)) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
-        });
+        refresh_containers(cx);
       }
       Err(e) => {
         fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to disconnect {container_name} from {network_name}: {e}"),
+          });
         });
       }
     })
@@ -162,6 +243,7 @@ pub fn disconnect_container_from_network(network_id: String, container_id: Strin
 }
 
 pub fn delete_network(id: String, cx: &mut App) {
+  log_command(cx, format!("docker network rm {id}"));
   let task_id = start_task(cx, "Deleting network...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -0,0 +1,161 @@
+//! Live stats for every running container
+//!
+//! One daemon stats stream runs per running container. The streams write
+//! their latest sample into a shared map, and a timer publishes the
+//! combined snapshot every `stats_refresh_interval` seconds.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use gpui::{App, Global, Subscription, Timer};
+use parking_lot::Mutex;
+
+use crate::docker::{AggregateStats, ContainerStats, stream_container_stats};
+use crate::services::Tokio;
+use crate::state::{StateChanged, docker_state, settings_state};
+
+use super::super::core::docker_client;
+
+type LatestStats = Arc<Mutex<HashMap<String, ContainerStats>>>;
+
+struct AllStatsStreams {
+  /// Open `stream_all_container_stats` calls not yet released
+  subscribers: usize,
+  /// Stats stream per running container id
+  streams: HashMap<String, tokio::task::JoinHandle<()>>,
+  /// Most recent sample per container, written by the streams
+  latest: LatestStats,
+  _containers_changed: Subscription,
+  _publisher: gpui::Task<()>,
+}
+
+impl Global for AllStatsStreams {}
+
+/// Stream stats for every running container and emit a combined
+/// `StateChanged::AllStatsUpdated` snapshot every `stats_refresh_interval`.
+/// Streams follow the container list: they start for newly started
+/// containers and stop for ones that stop. Each call must be paired with
+/// `stop_all_container_stats`; the streams are shared between callers.
+pub fn stream_all_container_stats(cx: &mut App) {
+  if cx.has_global::<AllStatsStreams>() {
+    cx.global_mut::<AllStatsStreams>().subscribers += 1;
+    return;
+  }
+
+  let state = docker_state(cx);
+  let containers_changed = cx.subscribe(&state, |_state, event: &StateChanged, cx| {
+    if matches!(
+      event,
+      StateChanged::ContainersUpdated | StateChanged::DockerConnectionChanged
+    ) {
+      sync_stats_streams(cx);
+    }
+  });
+
+  let publisher = cx.spawn(async move |cx| {
+    loop {
+      let interval = cx
+        .update(|cx| settings_state(cx).read(cx).settings.stats_refresh_interval.max(1))
+        .unwrap_or(2);
+      Timer::after(Duration::from_secs(interval)).await;
+      if cx.update(publish_snapshot).is_err() {
+        break;
+      }
+    }
+  });
+
+  cx.set_global(AllStatsStreams {
+    subscribers: 1,
+    streams: HashMap::new(),
+    latest: LatestStats::default(),
+    _containers_changed: containers_changed,
+    _publisher: publisher,
+  });
+  sync_stats_streams(cx);
+}
+
+/// Release one `stream_all_container_stats` call. The streams stop once
+/// the last caller has released them.
+pub fn stop_all_container_stats(cx: &mut App) {
+  if !cx.has_global::<AllStatsStreams>() {
+    return;
+  }
+  let streams = cx.global_mut::<AllStatsStreams>();
+  streams.subscribers = streams.subscribers.saturating_sub(1);
+  if streams.subscribers > 0 {
+    return;
+  }
+  let streams = cx.remove_global::<AllStatsStreams>();
+  for handle in streams.streams.values() {
+    handle.abort();
+  }
+}
+
+/// Start streams for running containers that lack a live one and stop
+/// streams whose container is no longer running.
+fn sync_stats_streams(cx: &mut App) {
+  let running: HashMap<String, String> = docker_state(cx)
+    .read(cx)
+    .containers
+    .iter()
+    .filter(|c| c.state.is_running())
+    .map(|c| (c.id.clone(), c.name.clone()))
+    .collect();
+  let handle = Tokio::runtime_handle();
+
+  let streams = cx.global_mut::<AllStatsStreams>();
+  let latest = streams.latest.clone();
+
+  streams.streams.retain(|id, stream| {
+    let keep = running.contains_key(id) && !stream.is_finished();
+    if !keep {
+      stream.abort();
+      latest.lock().remove(id);
+    }
+    keep
+  });
+
+  for (id, name) in running {
+    if streams.streams.contains_key(&id) {
+      continue;
+    }
+    let latest = latest.clone();
+    let stream_id = id.clone();
+    let stream = handle.spawn(async move {
+      // Clone the bollard handle so the client lock isn't held for
+      // the life of the stream
+      let docker = {
+        let client = docker_client();
+        let guard = client.read().await;
+        guard.as_ref().and_then(|c| c.client().ok()).cloned()
+      };
+      let Some(docker) = docker else {
+        return;
+      };
+      let result = stream_container_stats(&docker, &stream_id, |mut sample| {
+        if sample.name.is_empty() {
+          sample.name.clone_from(&name);
+        }
+        latest.lock().insert(stream_id.clone(), sample);
+      })
+      .await;
+      if let Err(e) = result {
+        tracing::debug!("Stats stream for {stream_id} ended: {e}");
+      }
+      latest.lock().remove(&stream_id);
+    });
+    streams.streams.insert(id, stream);
+  }
+}
+
+fn publish_snapshot(cx: &mut App) {
+  if !cx.has_global::<AllStatsStreams>() || settings_state(cx).read(cx).settings.auto_refresh_paused {
+    return;
+  }
+  let samples: Vec<ContainerStats> = cx.global::<AllStatsStreams>().latest.lock().values().cloned().collect();
+  let snapshot = AggregateStats::from_samples(samples);
+  docker_state(cx).update(cx, |_state, cx| {
+    cx.emit(StateChanged::AllStatsUpdated(snapshot));
+  });
+}
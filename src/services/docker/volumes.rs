@@ -1,13 +1,26 @@
 //! Volume operations
 
-use gpui::App;
+use futures::FutureExt;
+use gpui::{App, AsyncApp};
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{StateChanged, docker_state};
+use crate::docker::VOLUME_HELPER_IMAGE;
+use crate::services::{
+  TaskStage, Tokio, advance_stage, complete_task, fail_task, log_command, shell_quote, start_staged_task, start_task,
+};
+use crate::state::{CurrentView, StateChanged, docker_state};
 
-use super::super::core::{DispatcherEvent, dispatcher, docker_client};
+use super::super::core::{DispatcherEvent, dispatcher, docker_client, mark_refreshing};
+use super::containers::{BulkKind, run_bulk};
 
 pub fn create_volume(name: String, driver: String, labels: Vec<(String, String)>, cx: &mut App) {
+  log_command(
+    cx,
+    format!(
+      "docker volume create --driver {} {}",
+      shell_quote(&driver),
+      shell_quote(&name)
+    ),
+  );
   let task_id = start_task(cx, format!("Creating volume {name}..."));
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -49,24 +62,48 @@ pub fn create_volume(name: String, driver: String, labels: Vec<(String, String)>
   .detach();
 }
 
+/// Tar+gzip a volume into `dest_dir` through a helper container. The task
+/// shows the helper image pull as its own stage since it can take a while
+/// on a fresh machine.
 pub fn backup_volume(name: String, dest_dir: std::path::PathBuf, cx: &mut App) {
-  let task_id = start_task(cx, format!("Backing up volume {name}..."));
+  log_command(
+    cx,
+    format!(
+      "docker run --rm -v {}:/data:ro -v {}:/backup {VOLUME_HELPER_IMAGE} tar -czf /backup/{name}-<timestamp>.tar.gz -C /data .",
+      shell_quote(&name),
+      shell_quote(&dest_dir.display().to_string()),
+    ),
+  );
+  let stages = vec![
+    TaskStage::new("Preparing helper image..."),
+    TaskStage::new(format!("Archiving volume '{name}'...")),
+  ];
+  let task_id = start_staged_task(cx, format!("Backing up volume {name}"), stages);
   let disp = dispatcher(cx);
-  let client = docker_client();
   let label = name.clone();
 
-  let tokio_task = Tokio::spawn(cx, async move {
-    let guard = client.read().await;
-    let docker = guard
-      .as_ref()
-      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
-    docker.backup_volume(&name, &dest_dir).await
-  });
-
   cx.spawn(async move |cx| {
-    let result = tokio_task.await;
+    let result = match prepare_volume_helper(cx, task_id).await {
+      Ok(()) => {
+        let client = docker_client();
+        let tokio_task = cx.update(|cx| {
+          Tokio::spawn(cx, async move {
+            let guard = client.read().await;
+            let docker = guard
+              .as_ref()
+              .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+            docker.backup_volume(&name, &dest_dir).await
+          })
+        });
+        match tokio_task {
+          Ok(task) => task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}"))),
+          Err(e) => Err(e),
+        }
+      }
+      Err(e) => Err(e),
+    };
     cx.update(|cx| match result {
-      Ok(Ok(path)) => {
+      Ok(path) => {
         complete_task(cx, task_id);
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskCompleted {
@@ -74,7 +111,7 @@ pub fn backup_volume(name: String, dest_dir: std::path::PathBuf, cx: &mut App) {
           });
         });
       }
-      Ok(Err(e)) => {
+      Err(e) => {
         fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskFailed {
@@ -82,35 +119,51 @@ pub fn backup_volume(name: String, dest_dir: std::path::PathBuf, cx: &mut App) {
           });
         });
       }
-      Err(e) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
-        });
-      }
     })
   })
   .detach();
 }
 
+/// Unpack a tar.gz archive over the contents of an existing volume.
 pub fn restore_volume(name: String, archive_path: std::path::PathBuf, cx: &mut App) {
-  let task_id = start_task(cx, format!("Restoring volume {name}..."));
+  log_command(
+    cx,
+    format!(
+      "docker run --rm -v {}:/data -v {}:/backup.tar.gz:ro {VOLUME_HELPER_IMAGE} tar -xzf /backup.tar.gz -C /data",
+      shell_quote(&name),
+      shell_quote(&archive_path.display().to_string()),
+    ),
+  );
+  let stages = vec![
+    TaskStage::new("Preparing helper image..."),
+    TaskStage::new(format!("Extracting archive into '{name}'...")),
+  ];
+  let task_id = start_staged_task(cx, format!("Restoring volume {name}"), stages);
   let disp = dispatcher(cx);
-  let client = docker_client();
   let label = name.clone();
 
-  let tokio_task = Tokio::spawn(cx, async move {
-    let guard = client.read().await;
-    let docker = guard
-      .as_ref()
-      .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
-    docker.restore_volume(&name, &archive_path).await
-  });
-
   cx.spawn(async move |cx| {
-    let result = tokio_task.await;
+    let result = match prepare_volume_helper(cx, task_id).await {
+      Ok(()) => {
+        let client = docker_client();
+        let tokio_task = cx.update(|cx| {
+          Tokio::spawn(cx, async move {
+            let guard = client.read().await;
+            let docker = guard
+              .as_ref()
+              .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+            docker.restore_volume(&name, &archive_path).await
+          })
+        });
+        match tokio_task {
+          Ok(task) => task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}"))),
+          Err(e) => Err(e),
+        }
+      }
+      Err(e) => Err(e),
+    };
     cx.update(|cx| match result {
-      Ok(Ok(())) => {
+      Ok(()) => {
         complete_task(cx, task_id);
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskCompleted {
@@ -119,7 +172,7 @@ pub fn restore_volume(name: String, archive_path: std::path::PathBuf, cx: &mut A
         });
         refresh_volumes(cx);
       }
-      Ok(Err(e)) => {
+      Err(e) => {
         fail_task(cx, task_id, e.to_string());
         disp.update(cx, |_, cx| {
           cx.emit(DispatcherEvent::TaskFailed {
@@ -127,17 +180,29 @@ pub fn restore_volume(name: String, archive_path: std::path::PathBuf, cx: &mut A
           });
         });
       }
-      Err(e) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
-        });
-      }
     })
   })
   .detach();
 }
 
+/// Stage 0 of a backup or restore: make sure the helper image is present,
+/// then move the task on to its archive stage.
+async fn prepare_volume_helper(cx: &mut AsyncApp, task_id: u64) -> anyhow::Result<()> {
+  let client = docker_client();
+  let tokio_task = cx.update(|cx| {
+    Tokio::spawn(cx, async move {
+      let guard = client.read().await;
+      let docker = guard
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+      docker.ensure_volume_helper_image().await
+    })
+  })?;
+  tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")))?;
+  cx.update(|cx| advance_stage(cx, task_id))?;
+  Ok(())
+}
+
 pub fn clone_volume(src: String, dst: String, cx: &mut App) {
   let task_id = start_task(cx, format!("Cloning volume {src} to {dst}..."));
   let disp = dispatcher(cx);
@@ -181,7 +246,50 @@ pub fn clone_volume(src: String, dst: String, cx: &mut App) {
   .detach();
 }
 
+pub fn toggle_volume_bulk_selection(name: &str, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |s, cx| {
+    s.toggle_bulk_volume(name);
+    cx.notify();
+  });
+}
+
+pub fn clear_volume_bulk_selection(cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |s, cx| {
+    s.selected_volume_names.clear();
+    cx.notify();
+  });
+}
+
+/// Remove every volume ticked in the list under one task. Volumes still
+/// mounted by a container are reported as failures.
+pub fn delete_selected_volumes(cx: &mut App) {
+  let names: Vec<String> = docker_state(cx)
+    .read(cx)
+    .selected_volume_names
+    .iter()
+    .cloned()
+    .collect();
+  let kind = BulkKind {
+    command: "docker volume rm",
+    progressive: "Deleting",
+    past: "Deleted",
+    noun: "volume",
+    label: |name| name,
+    refresh: refresh_volumes,
+  };
+  run_bulk(
+    names,
+    kind,
+    |docker, name| docker.remove_volume(name, false).boxed(),
+    cx,
+  );
+  clear_volume_bulk_selection(cx);
+}
+
 pub fn delete_volume(name: String, cx: &mut App) {
+  log_command(cx, format!("docker volume rm {}", shell_quote(&name)));
   let task_id = start_task(cx, "Deleting volume...".to_string());
   let disp = dispatcher(cx);
   let client = docker_client();
@@ -225,6 +333,7 @@ pub fn delete_volume(name: String, cx: &mut App) {
 
 pub fn refresh_volumes(cx: &mut App) {
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::Volumes, cx);
   let client = docker_client();
 
   let tokio_task = Tokio::spawn(cx, async move {
@@ -242,6 +351,7 @@ pub fn refresh_volumes(cx: &mut App) {
       state.update(cx, |state, cx| {
         state.set_volumes(volumes);
         cx.emit(StateChanged::VolumesUpdated);
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::Volumes));
       });
     })
   })
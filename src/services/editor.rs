@@ -0,0 +1,409 @@
+//! Opening container and machine files in a local editor
+//!
+//! The file is copied into a temp folder and handed to the editor chosen in
+//! settings. With `editor_sync_back` on, every save to the copy is written
+//! back until the editor exits, or, for launchers like `code` that return
+//! right away, until the copy has sat unchanged for a while. The temp
+//! folder is removed at that point either way. Terminal editors such as
+//! `vim` are started inside a terminal window, since Dockside has no TTY.
+
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+use std::time::{Duration, SystemTime};
+
+use gpui::{App, AsyncApp, Timer};
+
+use crate::colima::ColimaClient;
+use crate::services::{
+  Tokio, complete_task, fail_task, shell_quote, start_task, write_container_file, write_machine_file,
+};
+use crate::state::{ExternalEditor, settings_state};
+use crate::utils::find_binary;
+
+use super::core::{DispatcherEvent, dispatcher, docker_client};
+
+/// How often the local copy is checked for saves
+const WATCH_TICK: Duration = Duration::from_secs(1);
+/// An editor that exits within this many ticks only launched another process
+const LAUNCHER_GRACE_TICKS: u32 = 3;
+/// Stop watching a copy nobody has saved for this many ticks
+const IDLE_LIMIT_TICKS: u32 = 30 * 60;
+
+/// Where an edited file lives
+#[derive(Debug, Clone)]
+pub enum EditorSource {
+  Container { id: String },
+  Machine { name: String },
+}
+
+impl EditorSource {
+  fn label(&self) -> &str {
+    match self {
+      EditorSource::Container { id } => id.get(..12).unwrap_or(id.as_str()),
+      EditorSource::Machine { name } => name.as_str(),
+    }
+  }
+}
+
+/// Command line that opens a local file in `editor`; the file path goes
+/// last. `None` when nothing usable is configured.
+fn editor_command(
+  editor: &ExternalEditor,
+  custom: &str,
+  wait: bool,
+  env_editor: Option<String>,
+) -> Option<Vec<String>> {
+  let line = match editor {
+    ExternalEditor::VSCode | ExternalEditor::Cursor | ExternalEditor::Zed => {
+      let mut argv = vec![editor.command().to_string()];
+      if wait {
+        argv.push("--wait".to_string());
+      }
+      return Some(argv);
+    }
+    ExternalEditor::System => env_editor.unwrap_or_else(|| default_text_editor().to_string()),
+    ExternalEditor::Custom => custom.to_string(),
+  };
+  let argv: Vec<String> = line.split_whitespace().map(str::to_string).collect();
+  (!argv.is_empty()).then_some(argv)
+}
+
+/// `$VISUAL`, then `$EDITOR`
+fn env_editor() -> Option<String> {
+  ["VISUAL", "EDITOR"]
+    .iter()
+    .find_map(|key| std::env::var(key).ok().filter(|v| !v.trim().is_empty()))
+}
+
+/// The platform's default text editor, used when `$EDITOR` isn't set
+fn default_text_editor() -> &'static str {
+  if cfg!(target_os = "macos") {
+    "open -t"
+  } else {
+    "xdg-open"
+  }
+}
+
+/// Editors that draw in the terminal and need a TTY
+const TERMINAL_EDITORS: &[&str] = &["vi", "vim", "nvim", "nano", "micro", "hx", "helix", "kak", "joe", "mg"];
+
+/// Whether `argv` runs an editor that needs a terminal. `emacs` only does
+/// with `-nw`.
+fn needs_terminal(argv: &[String]) -> bool {
+  let Some(name) = argv
+    .first()
+    .and_then(|p| Path::new(p).file_name())
+    .and_then(|n| n.to_str())
+  else {
+    return false;
+  };
+  TERMINAL_EDITORS.contains(&name) || (name == "emacs" && argv.iter().any(|a| a == "-nw" || a == "--no-window-system"))
+}
+
+/// `$TERMINAL`, then the first installed terminal that takes `-e`
+fn terminal_emulator() -> Option<String> {
+  std::env::var("TERMINAL")
+    .ok()
+    .filter(|t| !t.trim().is_empty())
+    .or_else(|| {
+      ["x-terminal-emulator", "alacritty", "konsole", "xterm"]
+        .into_iter()
+        .find(|t| find_binary(t).is_some())
+        .map(str::to_string)
+    })
+}
+
+/// Full command line that opens `file` with `argv`. Terminal editors are
+/// wrapped in a Terminal.app window on macOS and in `terminal -e` elsewhere;
+/// `None` when one is needed but none was found.
+fn launch_command(mut argv: Vec<String>, file: &Path, terminal: Option<String>) -> Option<Vec<String>> {
+  argv.push(file.display().to_string());
+  if !needs_terminal(&argv) {
+    return Some(argv);
+  }
+  if cfg!(target_os = "macos") {
+    let line = argv.iter().map(|a| shell_quote(a)).collect::<Vec<_>>().join(" ");
+    let script = format!(
+      "tell application \"Terminal\" to do script \"{}\"",
+      line.replace('\\', "\\\\").replace('"', "\\\"")
+    );
+    return Some(vec![
+      "osascript".to_string(),
+      "-e".to_string(),
+      script,
+      "-e".to_string(),
+      "tell application \"Terminal\" to activate".to_string(),
+    ]);
+  }
+  let mut wrapped: Vec<String> = terminal?.split_whitespace().map(str::to_string).collect();
+  wrapped.push("-e".to_string());
+  wrapped.extend(argv);
+  Some(wrapped)
+}
+
+/// Fresh temp folder for one edit session
+fn edit_dir(source: &EditorSource) -> std::io::Result<PathBuf> {
+  let stamp = SystemTime::now()
+    .duration_since(SystemTime::UNIX_EPOCH)
+    .map_or(0, |d| d.as_millis());
+  let label = source.label().replace(['/', '\\', ':'], "-");
+  let dir = std::env::temp_dir()
+    .join("dockside-edit")
+    .join(format!("{label}-{stamp}"));
+  std::fs::create_dir_all(&dir)?;
+  Ok(dir)
+}
+
+/// Decides when to stop watching a local copy
+#[derive(Debug, Default)]
+struct SyncWatch {
+  ticks: u32,
+  idle_ticks: u32,
+  /// The editor process returned during the grace period, so it only
+  /// handed the file to an already running editor.
+  launcher: bool,
+}
+
+impl SyncWatch {
+  /// Advance one tick. Returns false once watching should end.
+  fn tick(&mut self, changed: bool, editor_running: bool) -> bool {
+    self.ticks += 1;
+    self.idle_ticks = if changed { 0 } else { self.idle_ticks + 1 };
+    if !editor_running && !self.launcher {
+      if self.ticks > LAUNCHER_GRACE_TICKS {
+        return false;
+      }
+      self.launcher = true;
+    }
+    self.idle_ticks < IDLE_LIMIT_TICKS
+  }
+}
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+  std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Copy `path` out of `source`, open it in the configured editor and,
+/// if enabled, write saves back.
+pub fn open_file_copy_in_editor(source: EditorSource, path: String, cx: &mut App) {
+  let settings = settings_state(cx).read(cx).settings.clone();
+  let disp = dispatcher(cx);
+  let Some(argv) = editor_command(
+    &settings.external_editor,
+    &settings.external_editor_command,
+    settings.editor_wait_close,
+    env_editor(),
+  ) else {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: "No editor command configured. Set one under Settings > Editor.".to_string(),
+      });
+    });
+    return;
+  };
+
+  let editor_name = settings.external_editor.display_name().to_string();
+  let task_id = start_task(cx, format!("Opening {path} in {editor_name}..."));
+  let sync_back = settings.editor_sync_back;
+
+  let terminal = terminal_emulator();
+
+  cx.spawn(async move |cx| {
+    let opened = copy_to_local(&source, &path, cx).await.and_then(|local| {
+      let Some(line) = launch_command(argv.clone(), &local, terminal) else {
+        let _ = std::fs::remove_dir_all(local.parent().unwrap_or(&local));
+        anyhow::bail!("{} needs a terminal. Set $TERMINAL to one that accepts -e.", argv[0]);
+      };
+      Command::new(&line[0])
+        .args(&line[1..])
+        .spawn()
+        .map(|child| (local, child))
+        .map_err(|e| anyhow::anyhow!("Failed to start {}: {e}", line[0]))
+    });
+
+    let (local, child) = match opened {
+      Ok(opened) => opened,
+      Err(e) => {
+        let _ = cx.update(|cx| {
+          fail_task(cx, task_id, e.to_string());
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+          });
+        });
+        return;
+      }
+    };
+
+    let _ = cx.update(|cx| {
+      complete_task(cx, task_id);
+      disp.update(cx, |_, cx| {
+        cx.emit(DispatcherEvent::TaskCompleted {
+          message: if sync_back {
+            format!("Opened {path} in {editor_name}. Saves are written back.")
+          } else {
+            format!("Opened a copy of {path} in {editor_name}")
+          },
+        });
+      });
+    });
+
+    watch_local_copy(source, path, local, child, sync_back, cx).await;
+  })
+  .detach();
+}
+
+/// Download `path` from `source` into a fresh temp folder.
+async fn copy_to_local(source: &EditorSource, path: &str, cx: &mut AsyncApp) -> anyhow::Result<PathBuf> {
+  let dir = edit_dir(source).map_err(|e| anyhow::anyhow!("Failed to create temp folder: {e}"))?;
+  match source.clone() {
+    EditorSource::Container { id } => {
+      let client = docker_client();
+      let path = path.to_string();
+      let task = cx.update(|cx| {
+        Tokio::spawn(cx, async move {
+          let guard = client.read().await;
+          let docker = guard
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+          docker.download_from_container(&id, &path, &dir, |_| {}).await
+        })
+      })?;
+      task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")))
+    }
+    EditorSource::Machine { name } => {
+      let file_name = path.rsplit('/').next().unwrap_or(path).to_string();
+      let local = dir.join(file_name);
+      let path = path.to_string();
+      cx.background_executor()
+        .spawn(async move {
+          let name_opt = if name == "default" { None } else { Some(name.as_str()) };
+          ColimaClient::download_file(name_opt, &path, &local).map(|()| local)
+        })
+        .await
+    }
+  }
+}
+
+/// Wait until the editor is done with `local`, writing each save back to
+/// `source` when `sync_back` is on, then remove the temp folder.
+async fn watch_local_copy(
+  source: EditorSource,
+  path: String,
+  local: PathBuf,
+  mut child: Child,
+  sync_back: bool,
+  cx: &mut AsyncApp,
+) {
+  let mut last_modified = modified_at(&local);
+  let mut watch = SyncWatch::default();
+  loop {
+    Timer::after(WATCH_TICK).await;
+    let modified = modified_at(&local);
+    let changed = modified.is_some() && modified != last_modified;
+    if changed {
+      last_modified = modified;
+    }
+    if changed && sync_back {
+      let content = match std::fs::read_to_string(&local) {
+        Ok(content) => content,
+        Err(e) => {
+          let _ = cx.update(|cx| {
+            dispatcher(cx).update(cx, |_, cx| {
+              cx.emit(DispatcherEvent::TaskFailed {
+                error: format!("Not writing back {path}: {e}"),
+              });
+            });
+          });
+          continue;
+        }
+      };
+      let updated = cx.update(|cx| match &source {
+        EditorSource::Container { id } => write_container_file(id.clone(), path.clone(), content, cx),
+        EditorSource::Machine { name } => write_machine_file(name.clone(), path.clone(), content, false, cx),
+      });
+      if updated.is_err() {
+        break;
+      }
+    }
+    let running = matches!(child.try_wait(), Ok(None));
+    if !watch.tick(changed, running) {
+      break;
+    }
+  }
+  let _ = std::fs::remove_dir_all(local.parent().unwrap_or(&local));
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_editor_command() {
+    assert_eq!(
+      editor_command(&ExternalEditor::VSCode, "", true, None),
+      Some(vec!["code".to_string(), "--wait".to_string()])
+    );
+    assert_eq!(
+      editor_command(&ExternalEditor::System, "", false, Some("gvim -f".to_string())),
+      Some(vec!["gvim".to_string(), "-f".to_string()])
+    );
+    assert_eq!(
+      editor_command(&ExternalEditor::Custom, "subl -w", false, None),
+      Some(vec!["subl".to_string(), "-w".to_string()])
+    );
+    assert_eq!(editor_command(&ExternalEditor::Custom, "  ", false, None), None);
+  }
+
+  #[test]
+  fn test_launch_command() {
+    let file = Path::new("/tmp/edit/app.conf");
+    assert_eq!(
+      launch_command(vec!["code".to_string(), "--wait".to_string()], file, None),
+      Some(vec![
+        "code".to_string(),
+        "--wait".to_string(),
+        "/tmp/edit/app.conf".to_string()
+      ])
+    );
+    assert!(needs_terminal(&["/usr/bin/nvim".to_string()]));
+    assert!(needs_terminal(&["emacs".to_string(), "-nw".to_string()]));
+    assert!(!needs_terminal(&["emacs".to_string()]));
+    if !cfg!(target_os = "macos") {
+      assert_eq!(
+        launch_command(vec!["vim".to_string()], file, Some("xterm".to_string())),
+        Some(vec![
+          "xterm".to_string(),
+          "-e".to_string(),
+          "vim".to_string(),
+          "/tmp/edit/app.conf".to_string()
+        ])
+      );
+      assert_eq!(launch_command(vec!["nano".to_string()], file, None), None);
+    }
+  }
+
+  #[test]
+  fn test_sync_watch_blocking_editor() {
+    let mut watch = SyncWatch::default();
+    for _ in 0..10 {
+      assert!(watch.tick(false, true));
+    }
+    // Closing the editor ends the watch
+    assert!(!watch.tick(false, false));
+  }
+
+  #[test]
+  fn test_sync_watch_launcher_runs_until_idle() {
+    let mut watch = SyncWatch::default();
+    assert!(watch.tick(false, false));
+    assert!(watch.launcher);
+    for _ in 0..IDLE_LIMIT_TICKS - 2 {
+      assert!(watch.tick(false, false));
+    }
+    // A save resets the idle count
+    assert!(watch.tick(true, false));
+    assert_eq!(watch.idle_ticks, 0);
+    watch.idle_ticks = IDLE_LIMIT_TICKS - 1;
+    assert!(!watch.tick(false, false));
+  }
+}
@@ -0,0 +1,133 @@
+//! Friendly explanations for common task failures
+//!
+//! Daemon and CLI errors reach the dispatcher as raw strings (often a
+//! bollard or hyper error chain). `explain_error` recognizes the usual
+//! suspects so the failure notification can lead with what went wrong and
+//! how to fix it, keeping the raw text behind a details toggle.
+
+/// A recognized failure: a short title and a suggested fix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorHint {
+  pub title: &'static str,
+  pub suggestion: &'static str,
+}
+
+/// Known failures, checked in order against the lowercased error. The
+/// socket permission error also mentions the daemon, so permissions come
+/// first. Kubernetes API connection errors share hyper's wording with
+/// Docker's, so only a missing socket counts as Docker being down.
+const HINTS: &[(&[&str], ErrorHint)] = &[
+  (
+    &["permission denied", "operation not permitted"],
+    ErrorHint {
+      title: "Permission denied",
+      suggestion: "Check that your user can access the Docker socket (for example, that it's in the docker group) and the files involved.",
+    },
+  ),
+  (
+    &[
+      "cannot connect to the docker daemon",
+      "is the docker daemon running",
+      "docker client not connected",
+      "error trying to connect: no such file or directory",
+      "docker.sock: connect: no such file or directory",
+    ],
+    ErrorHint {
+      title: "Docker isn't running",
+      suggestion: "Start Docker or your Colima machine, then try again.",
+    },
+  ),
+  (
+    &["unable to connect to the server", "failed to infer config"],
+    ErrorHint {
+      title: "Kubernetes cluster unreachable",
+      suggestion: "Check that the cluster is running and that the current kubectl context points at it.",
+    },
+  ),
+  (
+    &[
+      "port is already allocated",
+      "address already in use",
+      "ports are not available",
+    ],
+    ErrorHint {
+      title: "Port already in use",
+      suggestion: "Another container or process is bound to that host port. Pick a different port or stop whatever holds it.",
+    },
+  ),
+  (
+    &["is already in use by container", "already exists"],
+    ErrorHint {
+      title: "Name already in use",
+      suggestion: "Choose another name, or remove the existing resource first.",
+    },
+  ),
+  (
+    &[
+      "no such image",
+      "manifest unknown",
+      "pull access denied",
+      "repository does not exist",
+      "not found: manifest",
+    ],
+    ErrorHint {
+      title: "Image not found",
+      suggestion: "Check the image name and tag. For a private registry, log in first.",
+    },
+  ),
+];
+
+/// The hint for `error`, if it's a failure we recognize
+pub fn explain_error(error: &str) -> Option<ErrorHint> {
+  let error = error.to_lowercase();
+  HINTS
+    .iter()
+    .find(|(needles, _)| needles.iter().any(|n| error.contains(n)))
+    .map(|(_, hint)| *hint)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn title(error: &str) -> Option<&'static str> {
+    explain_error(error).map(|h| h.title)
+  }
+
+  #[test]
+  fn test_explain_error() {
+    assert_eq!(
+      title("error trying to connect: No such file or directory (os error 2)"),
+      Some("Docker isn't running")
+    );
+    assert_eq!(
+      title("permission denied while trying to connect to the Docker daemon socket at unix:///var/run/docker.sock"),
+      Some("Permission denied")
+    );
+    assert_eq!(
+      title(
+        "Docker responded with status code 500: driver failed programming external connectivity on endpoint web: Bind for 0.0.0.0:8080 failed: port is already allocated"
+      ),
+      Some("Port already in use")
+    );
+    assert_eq!(
+      title(
+        "Docker responded with status code 409: Conflict. The container name \"/web\" is already in use by container \"abc\""
+      ),
+      Some("Name already in use")
+    );
+    assert_eq!(
+      title("Docker responded with status code 404: No such image: nginx:nope"),
+      Some("Image not found")
+    );
+    assert_eq!(
+      title("Unable to connect to the server: dial tcp 127.0.0.1:6443: connect: connection refused"),
+      Some("Kubernetes cluster unreachable")
+    );
+    assert_eq!(
+      title("HyperError: error trying to connect: tcp connect error: Connection refused (os error 111)"),
+      None
+    );
+    assert_eq!(title("something odd happened"), None);
+  }
+}
@@ -8,7 +8,7 @@ use crate::colima::Machine;
 use crate::docker::DockerClient;
 use crate::platform::{DockerRuntime, Platform, get_default_docker_socket};
 use crate::services::Tokio;
-use crate::state::{StateChanged, docker_state, settings_state};
+use crate::state::{AppSettings, StateChanged, docker_state, settings_state};
 
 use super::core::docker_client;
 
@@ -19,8 +19,6 @@ pub fn load_initial_data(cx: &mut App) {
 
   // Get saved settings for Docker socket and Colima profile
   let settings = settings_state(cx).read(cx).settings.clone();
-  let custom_socket = settings.docker_socket.clone();
-  let colima_profile = settings.default_colima_profile.clone();
   let colima_enabled = settings.colima_enabled;
 
   // First, get colima VMs (if supported and enabled) and determine the Docker runtime
@@ -36,44 +34,7 @@ pub fn load_initial_data(cx: &mut App) {
     #[cfg(not(any(target_os = "macos", target_os = "linux")))]
     let vms = Vec::new();
 
-    // Determine the Docker runtime to use
-    let runtime = if custom_socket.is_empty() {
-      // Auto-detect runtime based on platform
-      match platform {
-        Platform::MacOS => {
-          // Prefer Colima with configured profile on macOS
-          DockerRuntime::Colima {
-            profile: colima_profile,
-          }
-        }
-        Platform::Linux | Platform::WindowsWsl2 => {
-          // Try native Docker first (handles rootless socket too), then Colima.
-          if let Some(socket_path) = get_default_docker_socket() {
-            DockerRuntime::NativeDocker { socket_path }
-          } else if colima_enabled {
-            DockerRuntime::Colima {
-              profile: colima_profile,
-            }
-          } else {
-            // No socket detected and Colima disabled — return the default
-            // path anyway so the connection error guides the user.
-            DockerRuntime::native_default()
-          }
-        }
-        Platform::Windows => {
-          // On Windows, prefer an auto-detected WSL2 distro running Docker.
-          DockerRuntime::detect_available()
-            .into_iter()
-            .next()
-            .unwrap_or_else(|| DockerRuntime::wsl2_default("Ubuntu".to_string()))
-        }
-      }
-    } else {
-      // User specified a custom socket/connection string
-      DockerRuntime::Custom {
-        connection_string: custom_socket,
-      }
-    };
+    let runtime = select_docker_runtime(platform, &settings);
 
     (vms, runtime)
   });
@@ -122,19 +83,21 @@ pub fn load_initial_data(cx: &mut App) {
       }
       machines.extend(vms.into_iter().map(Machine::Colima));
 
-      (machines, containers, images, volumes, networks)
+      (machines, containers, images, volumes, networks, true, false)
     } else {
       // No Docker connection - just return Colima VMs without host
       let machines: Vec<Machine> = vms.into_iter().map(Machine::Colima).collect();
-      (machines, vec![], vec![], vec![], vec![])
+      let colima_installed = crate::utils::is_colima_installed();
+      (machines, vec![], vec![], vec![], vec![], false, colima_installed)
     }
   });
 
   cx.spawn(async move |cx| {
     let result = tokio_task.await;
-    let (machines, containers, images, volumes, networks) = result.unwrap_or_default();
+    let (machines, containers, images, volumes, networks, connected, colima_installed) = result.unwrap_or_default();
 
     cx.update(|cx| {
+      let reconnected = connected && state.read(cx).docker_connected == Some(false);
       state.update(cx, |state, cx| {
         // Set machines directly (includes Host + Colima VMs)
         state.set_machines(machines);
@@ -143,6 +106,10 @@ pub fn load_initial_data(cx: &mut App) {
         state.set_volumes(volumes);
         state.set_networks(networks);
         state.is_loading = false;
+        state.colima_installed = colima_installed;
+        if state.set_docker_connected(connected) {
+          cx.emit(StateChanged::DockerConnectionChanged);
+        }
         cx.emit(StateChanged::MachinesUpdated);
         cx.emit(StateChanged::ContainersUpdated);
         cx.emit(StateChanged::ImagesUpdated);
@@ -150,7 +117,55 @@ pub fn load_initial_data(cx: &mut App) {
         cx.emit(StateChanged::NetworksUpdated);
         cx.emit(StateChanged::Loading);
       });
+      if reconnected {
+        // Resubscribe to daemon events now rather than after the
+        // watcher's own backoff.
+        super::watchers::restart_watchers(cx);
+      }
     })
   })
   .detach();
 }
+
+/// Pick the Docker runtime to connect to: the user's custom socket when
+/// set, otherwise the platform default (Colima on macOS, the native socket
+/// elsewhere). Probes socket paths, so call it off the main thread.
+pub(crate) fn select_docker_runtime(platform: Platform, settings: &AppSettings) -> DockerRuntime {
+  if settings.docker_socket.is_empty() {
+    // Auto-detect runtime based on platform
+    match platform {
+      Platform::MacOS => {
+        // Prefer Colima with configured profile on macOS
+        DockerRuntime::Colima {
+          profile: settings.default_colima_profile.clone(),
+        }
+      }
+      Platform::Linux | Platform::WindowsWsl2 => {
+        // Try native Docker first (handles rootless socket too), then Colima.
+        if let Some(socket_path) = get_default_docker_socket() {
+          DockerRuntime::NativeDocker { socket_path }
+        } else if settings.colima_enabled {
+          DockerRuntime::Colima {
+            profile: settings.default_colima_profile.clone(),
+          }
+        } else {
+          // No socket detected and Colima disabled — return the default
+          // path anyway so the connection error guides the user.
+          DockerRuntime::native_default()
+        }
+      }
+      Platform::Windows => {
+        // On Windows, prefer an auto-detected WSL2 distro running Docker.
+        DockerRuntime::detect_available()
+          .into_iter()
+          .next()
+          .unwrap_or_else(|| DockerRuntime::wsl2_default("Ubuntu".to_string()))
+      }
+    }
+  } else {
+    // User specified a custom socket/connection string
+    DockerRuntime::Custom {
+      connection_string: settings.docker_socket.clone(),
+    }
+  }
+}
@@ -100,6 +100,37 @@ pub fn refresh_events(cx: &mut App) {
   .detach();
 }
 
+/// Fetch the events that reference one object (`involvedObject`), most
+/// recent first. Answers with `StateChanged::ResourceEventsLoaded`.
+pub fn get_resource_events(kind: &str, name: String, namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let kind = kind.to_string();
+  let (task_kind, task_name, task_namespace) = (kind.clone(), name.clone(), namespace.clone());
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = crate::kubernetes::KubeClient::new().await?;
+    client.list_object_events(&task_kind, &task_name, &task_namespace).await
+  });
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    let (events, error) = match result {
+      Ok(events) => (events, None),
+      Err(e) => (Vec::new(), Some(e.to_string())),
+    };
+    cx.update(|cx| {
+      state.update(cx, |_, cx| {
+        cx.emit(StateChanged::ResourceEventsLoaded {
+          kind,
+          name,
+          namespace,
+          events,
+          error,
+        });
+      });
+    })
+  })
+  .detach();
+}
+
 pub fn create_namespace(name: String, cx: &mut App) {
   let task_id = start_task(cx, format!("Creating namespace '{name}'..."));
   let disp = dispatcher(cx);
@@ -133,6 +164,34 @@ pub fn create_namespace(name: String, cx: &mut App) {
   .detach();
 }
 
+/// Fetch the namespace's `ResourceQuota` objects and emit
+/// `NamespaceQuotasLoaded`. A namespace without quotas loads an empty list.
+pub fn get_namespace_quotas(namespace: String, cx: &mut App) {
+  let state = docker_state(cx);
+  let ns = namespace.clone();
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = crate::kubernetes::KubeClient::new().await?;
+    client.list_resource_quotas(&ns).await
+  });
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    let (quotas, error) = match result {
+      Ok(quotas) => (quotas, None),
+      Err(e) => (Vec::new(), Some(e.to_string())),
+    };
+    cx.update(|cx| {
+      state.update(cx, |_s, cx| {
+        cx.emit(StateChanged::NamespaceQuotasLoaded {
+          namespace,
+          quotas,
+          error,
+        });
+      });
+    })
+  })
+  .detach();
+}
+
 pub fn cordon_node(name: String, cx: &mut App) {
   set_node_unschedulable(name, true, cx);
 }
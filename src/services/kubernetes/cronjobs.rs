@@ -5,12 +5,13 @@ use std::future::Future;
 use gpui::App;
 
 use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{LoadState, StateChanged, docker_state};
+use crate::state::{CurrentView, LoadState, StateChanged, docker_state};
 
-use super::super::core::{DispatcherEvent, dispatcher};
+use super::super::core::{DispatcherEvent, dispatcher, mark_refreshing};
 
 pub fn refresh_cronjobs(cx: &mut App) {
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::CronJobs, cx);
   let is_initial = matches!(state.read(cx).cronjobs_state, LoadState::NotLoaded);
   if is_initial {
     state.update(cx, |s, _| s.cronjobs_state = LoadState::Loading);
@@ -39,6 +40,10 @@ pub fn refresh_cronjobs(cx: &mut App) {
         Ok(Err(e)) => s.cronjobs_state = LoadState::Error(e.to_string()),
         Err(e) => s.cronjobs_state = LoadState::Error(e.to_string()),
       });
+      state.update(cx, |s, cx| {
+        s.finish_refresh(CurrentView::CronJobs, matches!(s.cronjobs_state, LoadState::Loaded));
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::CronJobs));
+      });
     })
   })
   .detach();
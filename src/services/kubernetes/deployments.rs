@@ -2,10 +2,15 @@
 
 use gpui::App;
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{StateChanged, docker_state};
-
-use super::super::core::{DispatcherEvent, dispatcher};
+use crate::kubernetes::RolloutStatus;
+use crate::services::{
+  TaskRetry, Tokio, complete_task, fail_task, log_command, set_task_progress, set_task_retry, shell_quote, start_task,
+};
+use crate::state::{CurrentView, StateChanged, docker_state};
+
+use super::super::core::{DispatcherEvent, dispatcher, mark_refreshing};
+use super::kubectl::describe_resource;
+use super::manifests::apply_resource_yaml;
 use super::pods::refresh_pods;
 
 /// Refresh deployments list
@@ -13,6 +18,7 @@ pub fn refresh_deployments(cx: &mut App) {
   use crate::state::LoadState;
 
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::Deployments, cx);
 
   // Only show loading state on initial load, not on background refreshes
   let is_initial_load = matches!(state.read(cx).deployments_state, LoadState::NotLoaded);
@@ -55,6 +61,9 @@ pub fn refresh_deployments(cx: &mut App) {
           state.set_deployments_error(error_msg);
         }
       });
+      state.update(cx, |_, cx| {
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::Deployments));
+      });
     })
   })
   .detach();
@@ -62,6 +71,7 @@ pub fn refresh_deployments(cx: &mut App) {
 
 /// Delete a deployment
 pub fn delete_deployment(name: String, namespace: String, cx: &mut App) {
+  log_command(cx, format!("kubectl delete deployment {name} -n {namespace}"));
   let task_id = start_task(cx, format!("Deleting deployment '{name}'..."));
   let name_clone = name.clone();
   let _state = docker_state(cx);
@@ -98,73 +108,208 @@ pub fn delete_deployment(name: String, namespace: String, cx: &mut App) {
   .detach();
 }
 
-/// Scale a deployment
+/// Scale a deployment and follow the resulting rollout
 pub fn scale_deployment(name: String, namespace: String, replicas: i32, cx: &mut App) {
+  log_command(
+    cx,
+    format!("kubectl scale deployment {name} -n {namespace} --replicas={replicas}"),
+  );
   let task_id = start_task(cx, format!("Scaling '{name}' to {replicas} replicas..."));
-  let name_clone = name.clone();
-  let disp = dispatcher(cx);
+  set_task_retry(
+    cx,
+    task_id,
+    TaskRetry::ScaleDeployment {
+      name: name.clone(),
+      namespace: namespace.clone(),
+      replicas,
+    },
+  );
+  let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+  let label = name.clone();
 
   let tokio_task = Tokio::spawn(cx, async move {
     let client = crate::kubernetes::KubeClient::new().await?;
-    client.scale_deployment(&name, &namespace, replicas).await
+    client.scale_deployment(&name, &namespace, replicas).await?;
+    client
+      .watch_deployment_rollout(&name, &namespace, |status| {
+        let _ = tx.send(status.clone());
+      })
+      .await
   });
 
-  cx.spawn(async move |cx| {
-    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
-
-    cx.update(|cx| match result {
-      Ok(msg) => {
-        complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted { message: msg });
-        });
-        refresh_deployments(cx);
-        refresh_pods(cx);
-      }
-      Err(e) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to scale '{name_clone}': {e}"),
-          });
-        });
-      }
-    })
-  })
-  .detach();
+  report_rollout(task_id, label, "scale", tokio_task, rx, cx);
 }
 
-/// Restart a deployment (rollout restart)
+/// Restart a deployment (rollout restart) and follow the rollout
 pub fn restart_deployment(name: String, namespace: String, cx: &mut App) {
+  log_command(cx, format!("kubectl rollout restart deployment {name} -n {namespace}"));
   let task_id = start_task(cx, format!("Restarting '{name}'..."));
-  let name_clone = name.clone();
-  let disp = dispatcher(cx);
+  let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+  let label = name.clone();
 
   let tokio_task = Tokio::spawn(cx, async move {
     let client = crate::kubernetes::KubeClient::new().await?;
-    client.restart_deployment(&name, &namespace).await
+    client.restart_deployment(&name, &namespace).await?;
+    client
+      .watch_deployment_rollout(&name, &namespace, |status| {
+        let _ = tx.send(status.clone());
+      })
+      .await
   });
 
+  report_rollout(task_id, label, "restart", tokio_task, rx, cx);
+}
+
+/// Edits that turn `original` env vars into `edited`: vars to set (new or
+/// changed) and names to remove.
+fn env_changes(original: &[(String, String)], edited: &[(String, String)]) -> (Vec<(String, String)>, Vec<String>) {
+  let set = edited
+    .iter()
+    .filter(|(k, v)| !original.iter().any(|(ok, ov)| ok == k && ov == v))
+    .cloned()
+    .collect();
+  let removed = original
+    .iter()
+    .filter(|(k, _)| !edited.iter().any(|(ek, _)| ek == k))
+    .map(|(k, _)| k.clone())
+    .collect();
+  (set, removed)
+}
+
+/// The `kubectl set image` / `kubectl set env` lines equivalent to one
+/// container patch, for the command log
+fn container_patch_commands(
+  name: &str,
+  namespace: &str,
+  container: &str,
+  image: Option<&str>,
+  set: &[(String, String)],
+  removed: &[String],
+) -> Vec<String> {
+  let target = format!("deployment/{} -n {}", shell_quote(name), shell_quote(namespace));
+  let mut commands = Vec::new();
+  if let Some(image) = image {
+    commands.push(format!(
+      "kubectl set image {target} {}",
+      shell_quote(&format!("{container}={image}"))
+    ));
+  }
+  if !set.is_empty() || !removed.is_empty() {
+    let changes = set
+      .iter()
+      .map(|(k, v)| shell_quote(&format!("{k}={v}")))
+      .chain(removed.iter().map(|k| shell_quote(&format!("{k}-"))))
+      .collect::<Vec<_>>()
+      .join(" ");
+    commands.push(format!(
+      "kubectl set env {target} -c {} {changes}",
+      shell_quote(container)
+    ));
+  }
+  commands
+}
+
+/// Set a container's image and follow the rollout
+pub fn update_deployment_image(name: &str, namespace: &str, container: &str, image: &str, cx: &mut App) {
+  update_deployment_container(
+    name.to_string(),
+    namespace.to_string(),
+    container.to_string(),
+    Some(image.to_string()),
+    &[],
+    &[],
+    cx,
+  );
+}
+
+/// Patch a container's image (when `image` is set) and env vars to
+/// `edited` in one rollout, then follow it
+pub fn update_deployment_container(
+  name: String,
+  namespace: String,
+  container: String,
+  image: Option<String>,
+  original: &[(String, String)],
+  edited: &[(String, String)],
+  cx: &mut App,
+) {
+  let (set, removed) = env_changes(original, edited);
+  if image.is_none() && set.is_empty() && removed.is_empty() {
+    return;
+  }
+  for command in container_patch_commands(&name, &namespace, &container, image.as_deref(), &set, &removed) {
+    log_command(cx, command);
+  }
+  let task_id = start_task(cx, format!("Updating '{name}'..."));
+  let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+  let label = name.clone();
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let client = crate::kubernetes::KubeClient::new().await?;
+    client
+      .patch_deployment_container(&name, &namespace, &container, image.as_deref(), &set, &removed)
+      .await?;
+    client
+      .watch_deployment_rollout(&name, &namespace, |status| {
+        let _ = tx.send(status.clone());
+      })
+      .await
+  });
+
+  report_rollout(task_id, label, "update", tokio_task, rx, cx);
+}
+
+/// Mirror rollout progress ("3/5 pods updated") into the task bar and emit
+/// the final outcome. Success is only reported once every replica is
+/// updated and available, so a restart whose pods never come up fails.
+fn report_rollout(
+  task_id: u64,
+  name: String,
+  action: &'static str,
+  tokio_task: gpui::Task<Result<anyhow::Result<RolloutStatus>, tokio::task::JoinError>>,
+  mut rx: tokio::sync::mpsc::UnboundedReceiver<RolloutStatus>,
+  cx: &mut App,
+) {
+  let disp = dispatcher(cx);
+
+  cx.spawn(async move |cx| {
+    while let Some(status) = rx.recv().await {
+      if let RolloutStatus::Progressing { message, .. } = &status {
+        let progress = status.progress();
+        let message = message.clone();
+        let _ = cx.update(|cx| set_task_progress(cx, task_id, progress, Some(message)));
+      }
+    }
+  })
+  .detach();
+
   cx.spawn(async move |cx| {
     let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
 
-    cx.update(|cx| match result {
-      Ok(msg) => {
-        complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted { message: msg });
-        });
-        refresh_deployments(cx);
-        refresh_pods(cx);
-      }
-      Err(e) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to restart '{name_clone}': {e}"),
-          });
-        });
-      }
+    cx.update(|cx| {
+      let event = match result {
+        Ok(RolloutStatus::Complete { desired }) => {
+          complete_task(cx, task_id);
+          DispatcherEvent::TaskCompleted {
+            message: format!("Deployment '{name}' rolled out ({desired}/{desired} pods ready)"),
+          }
+        }
+        Ok(RolloutStatus::Failed(reason)) => {
+          let retry = fail_task(cx, task_id, reason.clone());
+          DispatcherEvent::task_failed(format!("Rollout of '{name}' failed: {reason}"), retry)
+        }
+        Ok(RolloutStatus::Progressing { message, .. }) => {
+          let retry = fail_task(cx, task_id, message.clone());
+          DispatcherEvent::task_failed(format!("Rollout of '{name}' did not finish: {message}"), retry)
+        }
+        Err(e) => {
+          let retry = fail_task(cx, task_id, e.to_string());
+          DispatcherEvent::task_failed(format!("Failed to {action} '{name}': {e}"), retry)
+        }
+      };
+      disp.update(cx, |_, cx| cx.emit(event));
+      refresh_deployments(cx);
+      refresh_pods(cx);
     })
   })
   .detach();
@@ -201,41 +346,38 @@ pub fn get_deployment_yaml(name: String, namespace: String, cx: &mut App) {
   .detach();
 }
 
-/// Create a new Kubernetes deployment
+/// Describe a deployment (`kubectl describe`), including its events
+pub fn describe_deployment(name: String, namespace: String, cx: &mut App) {
+  describe_resource(
+    "deployment",
+    name,
+    namespace,
+    |deployment_name, namespace, describe| StateChanged::DeploymentDescribeLoaded {
+      deployment_name,
+      namespace,
+      describe,
+    },
+    cx,
+  );
+}
+
+/// Apply edited deployment YAML (`kubectl apply`), then reload the list and the YAML tab
 pub fn apply_deployment_yaml(name: String, namespace: String, yaml: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Applying deployment '{name}'..."));
-  let disp = dispatcher(cx);
-  let label = name.clone();
-  let tokio_task = Tokio::spawn(cx, async move {
-    let client = crate::kubernetes::KubeClient::new().await?;
-    client.apply_deployment_yaml(&name, &namespace, &yaml).await
-  });
-  cx.spawn(async move |cx| {
-    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
-    cx.update(|cx| match result {
-      Ok(()) => {
-        complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Deployment '{label}' applied"),
-          });
-        });
-        refresh_deployments(cx);
-      }
-      Err(e) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to apply deployment '{label}': {e}"),
-          });
-        });
-      }
-    })
-  })
-  .detach();
+  apply_resource_yaml(
+    "Deployment",
+    name,
+    namespace,
+    yaml,
+    |name, namespace, cx| {
+      refresh_deployments(cx);
+      get_deployment_yaml(name, namespace, cx);
+    },
+    cx,
+  );
 }
 
 pub fn rollback_deployment(name: String, namespace: String, cx: &mut App) {
+  log_command(cx, format!("kubectl rollout undo deployment {name} -n {namespace}"));
   let task_id = start_task(cx, format!("Rolling back '{name}'..."));
   let disp = dispatcher(cx);
   let label = name.clone();
@@ -302,3 +444,46 @@ pub fn create_deployment(options: crate::kubernetes::CreateDeploymentOptions, cx
   })
   .detach();
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+    items
+      .iter()
+      .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+      .collect()
+  }
+
+  #[test]
+  fn test_env_changes() {
+    let original = pairs(&[("A", "1"), ("B", "2"), ("C", "3")]);
+    let edited = pairs(&[("A", "1"), ("B", "20"), ("D", "4")]);
+    let (set, removed) = env_changes(&original, &edited);
+    assert_eq!(set, pairs(&[("B", "20"), ("D", "4")]));
+    assert_eq!(removed, vec!["C".to_string()]);
+
+    let (set, removed) = env_changes(&original, &original);
+    assert!(set.is_empty() && removed.is_empty());
+  }
+
+  #[test]
+  fn test_container_patch_commands() {
+    assert_eq!(
+      container_patch_commands(
+        "web",
+        "prod",
+        "app",
+        Some("app:v2"),
+        &pairs(&[("MODE", "live mode")]),
+        &["OLD".to_string()]
+      ),
+      vec![
+        "kubectl set image deployment/web -n prod app=app:v2".to_string(),
+        "kubectl set env deployment/web -n prod -c app 'MODE=live mode' OLD-".to_string(),
+      ]
+    );
+    assert!(container_patch_commands("web", "prod", "app", None, &[], &[]).is_empty());
+  }
+}
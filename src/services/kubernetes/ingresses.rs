@@ -3,12 +3,13 @@
 use gpui::App;
 
 use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{LoadState, StateChanged, docker_state};
+use crate::state::{CurrentView, LoadState, StateChanged, docker_state};
 
-use super::super::core::{DispatcherEvent, dispatcher};
+use super::super::core::{DispatcherEvent, dispatcher, mark_refreshing};
 
 pub fn refresh_ingresses(cx: &mut App) {
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::Ingresses, cx);
   let is_initial = matches!(state.read(cx).ingresses_state, LoadState::NotLoaded);
   if is_initial {
     state.update(cx, |s, _| s.ingresses_state = LoadState::Loading);
@@ -37,6 +38,10 @@ pub fn refresh_ingresses(cx: &mut App) {
         Ok(Err(e)) => s.ingresses_state = LoadState::Error(e.to_string()),
         Err(e) => s.ingresses_state = LoadState::Error(e.to_string()),
       });
+      state.update(cx, |s, cx| {
+        s.finish_refresh(CurrentView::Ingresses, matches!(s.ingresses_state, LoadState::Loaded));
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::Ingresses));
+      });
     })
   })
   .detach();
@@ -0,0 +1,60 @@
+//! Running `kubectl` against the active context.
+
+use gpui::App;
+
+use crate::services::Tokio;
+use crate::state::{StateChanged, docker_state};
+
+/// `kubectl [--context ctx] <args>` for the active context
+fn kubectl_args(args: &[&str], cx: &App) -> Vec<String> {
+  let mut full: Vec<String> = Vec::new();
+  if let Some(ctx) = docker_state(cx).read(cx).active_kube_context.clone() {
+    full.extend(["--context".to_string(), ctx]);
+  }
+  full.extend(args.iter().map(|a| (*a).to_string()));
+  full
+}
+
+/// Run kubectl and return its stdout, or its stderr as the error.
+fn run_kubectl(args: &[String]) -> anyhow::Result<String> {
+  let output = crate::utils::kubectl_cmd()
+    .args(args)
+    .output()
+    .map_err(|e| anyhow::anyhow!("Failed to start kubectl: {e}"))?;
+  if output.status.success() {
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+  } else {
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    Err(anyhow::anyhow!(if stderr.is_empty() {
+      format!("kubectl exited with {}", output.status)
+    } else {
+      stderr
+    }))
+  }
+}
+
+/// Run `kubectl describe <kind> <name> -n <namespace>` and emit its output
+/// (or the error text) as the event built by `loaded(name, namespace, describe)`.
+pub(super) fn describe_resource(
+  kind: &str,
+  name: String,
+  namespace: String,
+  loaded: fn(String, String, String) -> StateChanged,
+  cx: &mut App,
+) {
+  let state = docker_state(cx);
+  let args = kubectl_args(&["describe", kind, &name, "-n", &namespace], cx);
+  let tokio_task = Tokio::spawn(cx, async move { run_kubectl(&args) });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    let describe = result.unwrap_or_else(|e| format!("Error: {e}"));
+
+    cx.update(|cx| {
+      state.update(cx, |_state, cx| {
+        cx.emit(loaded(name, namespace, describe));
+      });
+    })
+  })
+  .detach();
+}
@@ -0,0 +1,304 @@
+//! Apply arbitrary manifests with `kubectl apply -f -`.
+
+use std::io::Write;
+use std::process::Stdio;
+
+use gpui::App;
+
+use crate::services::{Tokio, complete_task, fail_task, log_command, start_task};
+use crate::state::docker_state;
+
+use super::super::core::{DispatcherEvent, dispatcher};
+use super::{refresh_deployments, refresh_pods, refresh_services};
+
+/// Split a multi-document YAML stream on `---` separators, dropping
+/// documents that hold nothing but whitespace and comments.
+pub fn manifest_documents(yaml: &str) -> Vec<String> {
+  let mut docs = Vec::new();
+  let mut current = String::new();
+  for line in yaml.lines() {
+    let is_separator = line.starts_with("---") && line[3..].trim().is_empty();
+    if is_separator {
+      docs.push(std::mem::take(&mut current));
+    } else {
+      current.push_str(line);
+      current.push('\n');
+    }
+  }
+  docs.push(current);
+  docs
+    .into_iter()
+    .filter(|doc| {
+      doc
+        .lines()
+        .any(|l| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+    })
+    .collect()
+}
+
+/// Apply one or more manifests. kubectl's stderr (schema and admission
+/// errors) is surfaced as-is on failure. Workload lists refresh on success.
+pub fn apply_manifest(yaml: &str, cx: &mut App) {
+  let disp = dispatcher(cx);
+  let docs = manifest_documents(yaml);
+  if docs.is_empty() {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: "Manifest is empty".to_string(),
+      });
+    });
+    return;
+  }
+
+  let count = docs.len();
+  let stdin = docs.join("---\n");
+  let task_id = start_task(
+    cx,
+    format!("Applying {count} manifest{}...", if count == 1 { "" } else { "s" }),
+  );
+
+  let args = kubectl_apply_args(None, cx);
+  log_command(cx, format!("kubectl {}", args.join(" ")));
+
+  let tokio_task = Tokio::spawn(cx, async move { kubectl_apply(&args, &stdin) });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    cx.update(|cx| match result {
+      Ok(stdout) => {
+        complete_task(cx, task_id);
+        // One line per resource, e.g. "deployment.apps/web created"
+        let message = match stdout.lines().count() {
+          1 => stdout,
+          n => format!("Applied {n} resources"),
+        };
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted { message });
+        });
+        refresh_pods(cx);
+        refresh_services(cx);
+        refresh_deployments(cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Apply failed: {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// `kubectl [--context ctx] apply [-n namespace] -f -` for the active context
+fn kubectl_apply_args(namespace: Option<&str>, cx: &App) -> Vec<String> {
+  let mut args: Vec<String> = Vec::new();
+  if let Some(ctx) = docker_state(cx).read(cx).active_kube_context.clone() {
+    args.extend(["--context".to_string(), ctx]);
+  }
+  args.push("apply".to_string());
+  if let Some(ns) = namespace {
+    args.extend(["-n".to_string(), ns.to_string()]);
+  }
+  args.extend(["-f".to_string(), "-".to_string()]);
+  args
+}
+
+/// Run `kubectl apply` with `yaml` on stdin. Returns stdout, or kubectl's
+/// stderr as the error.
+fn kubectl_apply(args: &[String], yaml: &str) -> anyhow::Result<String> {
+  let mut child = crate::utils::kubectl_cmd()
+    .args(args)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .spawn()
+    .map_err(|e| anyhow::anyhow!("Failed to start kubectl: {e}"))?;
+  if let Some(mut pipe) = child.stdin.take() {
+    pipe.write_all(yaml.as_bytes())?;
+  }
+  let output = child.wait_with_output()?;
+  if output.status.success() {
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+  } else {
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+    Err(anyhow::anyhow!(if stderr.is_empty() {
+      format!("kubectl apply exited with {}", output.status)
+    } else {
+      stderr
+    }))
+  }
+}
+
+/// Check that edited YAML still describes the object being edited, so a
+/// stray edit to `kind` or `metadata.name` can't create a new resource.
+pub fn check_manifest_identity(yaml: &str, kind: &str, name: &str) -> anyhow::Result<()> {
+  if manifest_documents(yaml).len() != 1 {
+    anyhow::bail!("Expected a single {kind} document");
+  }
+  let value: serde_yaml::Value = serde_yaml::from_str(yaml).map_err(|e| anyhow::anyhow!("Invalid YAML: {e}"))?;
+  let found_kind = value
+    .get("kind")
+    .and_then(serde_yaml::Value::as_str)
+    .unwrap_or_default();
+  if !found_kind.eq_ignore_ascii_case(kind) {
+    anyhow::bail!("kind must stay {kind} (found '{found_kind}')");
+  }
+  let found_name = value
+    .get("metadata")
+    .and_then(|m| m.get("name"))
+    .and_then(serde_yaml::Value::as_str)
+    .unwrap_or_default();
+  if found_name != name {
+    anyhow::bail!("metadata.name must stay '{name}' (found '{found_name}')");
+  }
+  Ok(())
+}
+
+/// Field-level summary of the edits between two YAML documents, one entry
+/// per changed leaf: `~ spec.replicas`, `+ metadata.labels.tier`,
+/// `- spec.template.spec.containers[1]`.
+pub fn yaml_changed_fields(before: &str, after: &str) -> anyhow::Result<Vec<String>> {
+  let before: serde_yaml::Value = serde_yaml::from_str(before)?;
+  let after: serde_yaml::Value = serde_yaml::from_str(after)?;
+  let mut changes = Vec::new();
+  diff_values(&before, &after, "", &mut changes);
+  Ok(changes)
+}
+
+fn diff_values(before: &serde_yaml::Value, after: &serde_yaml::Value, path: &str, out: &mut Vec<String>) {
+  use serde_yaml::Value;
+
+  let field = |key: &Value| {
+    let key = key.as_str().map_or_else(|| format!("{key:?}"), str::to_string);
+    if path.is_empty() { key } else { format!("{path}.{key}") }
+  };
+  match (before, after) {
+    (Value::Mapping(a), Value::Mapping(b)) => {
+      for (key, old) in a {
+        match b.get(key) {
+          Some(new) => diff_values(old, new, &field(key), out),
+          None => out.push(format!("- {}", field(key))),
+        }
+      }
+      for key in b.keys().filter(|k| !a.contains_key(*k)) {
+        out.push(format!("+ {}", field(key)));
+      }
+    }
+    (Value::Sequence(a), Value::Sequence(b)) => {
+      for i in 0..a.len().max(b.len()) {
+        let item = format!("{path}[{i}]");
+        match (a.get(i), b.get(i)) {
+          (Some(old), Some(new)) => diff_values(old, new, &item, out),
+          (Some(_), None) => out.push(format!("- {item}")),
+          (None, Some(_)) => out.push(format!("+ {item}")),
+          (None, None) => {}
+        }
+      }
+    }
+    (a, b) if a != b => out.push(format!("~ {}", if path.is_empty() { "(document)" } else { path })),
+    _ => {}
+  }
+}
+
+/// Apply an edited single-resource YAML from a detail tab, then run
+/// `on_applied(name, namespace)` to refresh the list and re-fetch the YAML
+/// so the editor picks up server-side defaults.
+pub(crate) fn apply_resource_yaml(
+  kind: &'static str,
+  name: String,
+  namespace: String,
+  yaml: String,
+  on_applied: impl FnOnce(String, String, &mut App) + 'static,
+  cx: &mut App,
+) {
+  let disp = dispatcher(cx);
+  let label = format!("{} '{name}'", kind.to_lowercase());
+
+  if let Err(e) = check_manifest_identity(&yaml, kind, &name) {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: format!("Cannot apply {label}: {e}"),
+      });
+    });
+    return;
+  }
+
+  let task_id = start_task(cx, format!("Applying {label}..."));
+  let args = kubectl_apply_args(Some(&namespace), cx);
+  log_command(cx, format!("kubectl {}", args.join(" ")));
+
+  let tokio_task = Tokio::spawn(cx, async move { kubectl_apply(&args, &yaml) });
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    cx.update(|cx| match result {
+      Ok(_) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("{kind} '{name}' applied"),
+          });
+        });
+        on_applied(name, namespace, cx);
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to apply {label}: {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_manifest_documents_splits_and_skips_empty() {
+    let yaml = "---\napiVersion: v1\nkind: Namespace\n---\n# only a comment\n---   \nkind: Service\n---\n";
+    let docs = manifest_documents(yaml);
+    assert_eq!(docs.len(), 2);
+    assert!(docs[0].contains("kind: Namespace"));
+    assert!(docs[1].contains("kind: Service"));
+  }
+
+  #[test]
+  fn test_manifest_documents_keeps_separator_like_values() {
+    let docs = manifest_documents("kind: ConfigMap\ndata:\n  banner: \"---x\"\n");
+    assert_eq!(docs.len(), 1);
+    assert!(manifest_documents("  \n# nothing\n").is_empty());
+  }
+
+  #[test]
+  fn test_check_manifest_identity() {
+    let yaml = "apiVersion: v1\nkind: Service\nmetadata:\n  name: web\n";
+    assert!(check_manifest_identity(yaml, "Service", "web").is_ok());
+    let err = check_manifest_identity(yaml, "Service", "api").unwrap_err();
+    assert!(err.to_string().contains("metadata.name"));
+    assert!(check_manifest_identity(yaml, "Deployment", "web").is_err());
+  }
+
+  #[test]
+  fn test_yaml_changed_fields() {
+    let before = "spec:\n  replicas: 1\n  containers:\n  - image: nginx:1\nmetadata:\n  labels:\n    app: web\n";
+    let after = "spec:\n  replicas: 3\n  containers:\n  - image: nginx:1\n  - image: sidecar\nmetadata:\n  labels:\n    tier: fe\n";
+    let changes = yaml_changed_fields(before, after).unwrap();
+    assert_eq!(
+      changes,
+      vec![
+        "~ spec.replicas",
+        "+ spec.containers[1]",
+        "- metadata.labels.app",
+        "+ metadata.labels.tier",
+      ]
+    );
+    assert!(yaml_changed_fields(before, before).unwrap().is_empty());
+  }
+}
@@ -8,7 +8,10 @@ pub mod daemonsets;
 pub mod deployments;
 pub mod ingresses;
 pub mod jobs;
+mod kubectl;
+pub mod manifests;
 pub mod pods;
+pub mod port_forward;
 pub mod pvcs;
 pub mod secrets;
 pub mod services;
@@ -23,7 +26,9 @@ pub use daemonsets::*;
 pub use deployments::*;
 pub use ingresses::*;
 pub use jobs::*;
+pub use manifests::*;
 pub use pods::*;
+pub use port_forward::*;
 pub use pvcs::*;
 pub use secrets::*;
 pub use services::*;
@@ -2,16 +2,18 @@
 
 use gpui::App;
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{StateChanged, docker_state};
+use crate::services::{Tokio, complete_task, fail_task, log_command, start_task};
+use crate::state::{CurrentView, StateChanged, docker_state};
 
-use super::super::core::{DispatcherEvent, dispatcher};
+use super::super::core::{DispatcherEvent, dispatcher, mark_refreshing};
+use super::manifests::apply_resource_yaml;
 
 /// Refresh the list of pods
 pub fn refresh_pods(cx: &mut App) {
   use crate::state::LoadState;
 
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::Pods, cx);
 
   let namespace = state.read(cx).selected_namespace.clone();
   let ns_filter = if namespace == "all" { None } else { Some(namespace) };
@@ -25,6 +27,7 @@ pub fn refresh_pods(cx: &mut App) {
   }
 
   let ctx_gen = state.read(cx).kube_context_generation;
+  let metrics_namespace = ns_filter.clone();
   let tokio_task = Tokio::spawn(cx, async move {
     // Check both client creation AND actual API connectivity
     match crate::kubernetes::KubeClient::new().await {
@@ -66,7 +69,12 @@ pub fn refresh_pods(cx: &mut App) {
           }
         }
         cx.emit(StateChanged::PodsUpdated);
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::Pods));
       });
+      // Usage rides along with every successful pod refresh.
+      if state.read(cx).pods_state == LoadState::Loaded {
+        get_pod_metrics(metrics_namespace, cx);
+      }
     })
   })
   .detach();
@@ -135,6 +143,7 @@ pub fn set_namespace(namespace: String, cx: &mut App) {
 
 /// Delete a pod
 pub fn delete_pod(name: String, namespace: String, cx: &mut App) {
+  log_command(cx, format!("kubectl delete pod {name} -n {namespace}"));
   let _state = docker_state(cx);
   let disp = dispatcher(cx);
   let task_id = start_task(cx, format!("Deleting pod {name}"));
@@ -231,8 +240,28 @@ pub fn get_pod_yaml(name: String, namespace: String, cx: &mut App) {
   .detach();
 }
 
+/// Apply edited pod YAML (`kubectl apply`), then reload the list and the
+/// YAML tab. Most pod spec fields are immutable; kubectl reports which.
+pub fn apply_pod_yaml(name: String, namespace: String, yaml: String, cx: &mut App) {
+  apply_resource_yaml(
+    "Pod",
+    name,
+    namespace,
+    yaml,
+    |name, namespace, cx| {
+      refresh_pods(cx);
+      get_pod_yaml(name, namespace, cx);
+    },
+    cx,
+  );
+}
+
 /// Force delete a pod
 pub fn force_delete_pod(name: String, namespace: String, cx: &mut App) {
+  log_command(
+    cx,
+    format!("kubectl delete pod {name} -n {namespace} --grace-period=0 --force"),
+  );
   let task_id = start_task(cx, format!("Force deleting pod {name}..."));
   let disp = dispatcher(cx);
   let name_clone = name.clone();
@@ -302,11 +331,19 @@ pub fn restart_pod(name: String, namespace: String, cx: &mut App) {
 /// metrics.k8s.io) clears the data; the Activity Monitor falls back to
 /// phase/ready text. Generation-guarded like every other k8s refresh.
 pub fn refresh_pod_metrics(cx: &mut App) {
+  get_pod_metrics(None, cx);
+}
+
+/// Fetch pod CPU/memory for one namespace (or all when `None`). A cluster
+/// without metrics-server just marks metrics unavailable: no task, no
+/// error toast, since this runs on every pod refresh.
+pub fn get_pod_metrics(namespace: Option<String>, cx: &mut App) {
   let state = docker_state(cx);
   let ctx_gen = state.read(cx).kube_context_generation;
+  let ns = namespace.clone();
   let tokio_task = Tokio::spawn(cx, async move {
     let client = crate::kubernetes::KubeClient::new().await?;
-    client.pod_metrics().await
+    client.pod_metrics(ns.as_deref()).await
   });
   cx.spawn(async move |cx| {
     let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
@@ -316,8 +353,13 @@ pub fn refresh_pod_metrics(cx: &mut App) {
       }
       state.update(cx, |s, cx| {
         match result {
-          Ok(items) => s.set_pod_metrics(items),
-          Err(_) => s.clear_pod_metrics(),
+          Ok(items) => s.set_pod_metrics(namespace.as_deref(), items),
+          Err(e) => {
+            if !s.pod_metrics_unavailable() {
+              tracing::debug!("Pod metrics unavailable: {e}");
+            }
+            s.clear_pod_metrics();
+          }
         }
         cx.emit(StateChanged::PodMetricsUpdated);
       });
@@ -0,0 +1,375 @@
+//! Managed `kubectl port-forward` sessions
+//!
+//! Every forward started from the app is tracked here together with its
+//! child process so it can be listed, stopped individually, and torn down
+//! when the app quits instead of leaking a detached `kubectl`.
+
+use std::io::Read;
+use std::process::{Child, Stdio};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use chrono::{DateTime, Local};
+use gpui::{App, AppContext, Entity, Global};
+use parking_lot::Mutex;
+
+use crate::services::{Tokio, log_command};
+use crate::state::docker_state;
+
+use super::super::core::{DispatcherEvent, dispatcher};
+
+static FORWARD_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
+
+/// How long a freshly spawned `kubectl port-forward` must survive before
+/// it counts as started. Bind failures and unknown pods exit well within
+/// this window.
+const STARTUP_GRACE: Duration = Duration::from_millis(1500);
+
+/// What a forward points at: a single pod, or a service that `kubectl`
+/// resolves to one of its pods
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ForwardTarget {
+  Pod(String),
+  Service(String),
+}
+
+impl ForwardTarget {
+  pub fn name(&self) -> &str {
+    match self {
+      Self::Pod(name) | Self::Service(name) => name,
+    }
+  }
+
+  /// `pod/<name>` or `svc/<name>`, as passed to `kubectl port-forward`
+  fn resource(&self) -> String {
+    match self {
+      Self::Pod(name) => format!("pod/{name}"),
+      Self::Service(name) => format!("svc/{name}"),
+    }
+  }
+}
+
+/// An active `kubectl port-forward` owned by the app
+#[derive(Clone)]
+pub struct PortForward {
+  pub id: u64,
+  pub target: ForwardTarget,
+  pub namespace: String,
+  pub local_port: u16,
+  pub remote_port: u16,
+  pub started_at: DateTime<Local>,
+  child: Arc<Mutex<Child>>,
+}
+
+impl PortForward {
+  pub fn local_address(&self) -> String {
+    format!("localhost:{}", self.local_port)
+  }
+
+  fn kill(&self) {
+    let mut child = self.child.lock();
+    let _ = child.kill();
+    let _ = child.wait();
+  }
+
+  /// True once the `kubectl` process has exited on its own.
+  fn has_exited(&self) -> bool {
+    matches!(self.child.lock().try_wait(), Ok(Some(_)))
+  }
+}
+
+/// Registry of the port forwards started from the app
+#[derive(Default)]
+pub struct PortForwardManager {
+  forwards: Vec<PortForward>,
+}
+
+impl PortForwardManager {
+  pub fn for_pod<'a>(&'a self, pod: &'a str, namespace: &'a str) -> impl Iterator<Item = &'a PortForward> {
+    self
+      .forwards
+      .iter()
+      .filter(move |f| matches!(&f.target, ForwardTarget::Pod(p) if p == pod) && f.namespace == namespace)
+  }
+
+  pub fn for_service<'a>(&'a self, service: &'a str, namespace: &'a str) -> impl Iterator<Item = &'a PortForward> {
+    self
+      .forwards
+      .iter()
+      .filter(move |f| matches!(&f.target, ForwardTarget::Service(s) if s == service) && f.namespace == namespace)
+  }
+
+  fn insert(&mut self, forward: PortForward) {
+    self.forwards.push(forward);
+  }
+
+  /// Kill and forget a forward. Returns false when it was already gone.
+  fn stop(&mut self, id: u64) -> bool {
+    let Some(pos) = self.forwards.iter().position(|f| f.id == id) else {
+      return false;
+    };
+    self.forwards.remove(pos).kill();
+    true
+  }
+
+  fn stop_all(&mut self) {
+    for forward in self.forwards.drain(..) {
+      forward.kill();
+    }
+  }
+
+  /// Drop forwards whose process exited by itself (pod deleted, network
+  /// drop). Returns how many were removed.
+  fn prune_exited(&mut self) -> usize {
+    let before = self.forwards.len();
+    self.forwards.retain(|f| !f.has_exited());
+    before - self.forwards.len()
+  }
+}
+
+/// Global wrapper for `PortForwardManager`
+pub struct GlobalPortForwardManager(pub Entity<PortForwardManager>);
+
+impl Global for GlobalPortForwardManager {}
+
+/// Initialize the global port-forward registry and its liveness poll
+pub fn init_port_forwards(cx: &mut App) {
+  let manager = cx.new(|_cx| PortForwardManager::default());
+  cx.set_global(GlobalPortForwardManager(manager.clone()));
+
+  // Child processes outlive the app unless killed, whichever way it quits.
+  cx.on_app_quit(|cx| {
+    stop_all_port_forwards(cx);
+    async {}
+  })
+  .detach();
+
+  cx.spawn(async move |cx| {
+    loop {
+      cx.background_executor().timer(Duration::from_secs(2)).await;
+      let alive = cx.update(|cx| {
+        manager.update(cx, |m, cx| {
+          if m.prune_exited() > 0 {
+            cx.notify();
+          }
+        });
+      });
+      if alive.is_err() {
+        break;
+      }
+    }
+  })
+  .detach();
+}
+
+/// Get the global port-forward registry
+pub fn port_forward_manager(cx: &App) -> Entity<PortForwardManager> {
+  cx.global::<GlobalPortForwardManager>().0.clone()
+}
+
+/// Check that nothing on this machine is listening on `port` yet.
+fn check_local_port(port: u16) -> Result<(), String> {
+  match std::net::TcpListener::bind(("127.0.0.1", port)) {
+    Ok(_) => Ok(()),
+    Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => Err(format!(
+      "Local port {port} is already in use. Pick another local port or stop whatever is using it."
+    )),
+    Err(e) => Err(format!("Cannot bind local port {port}: {e}")),
+  }
+}
+
+/// `preferred` when it is free, otherwise any free port the OS hands out.
+fn pick_local_port(preferred: u16) -> u16 {
+  if preferred > 0 && check_local_port(preferred).is_ok() {
+    return preferred;
+  }
+  std::net::TcpListener::bind(("127.0.0.1", 0))
+    .and_then(|l| l.local_addr())
+    .map_or(preferred, |addr| addr.port())
+}
+
+/// Turn `kubectl port-forward` stderr into a message the user can act on.
+fn describe_forward_failure(local_port: u16, stderr: &str) -> String {
+  let stderr = stderr.trim();
+  if stderr.contains("address already in use") {
+    return format!("Local port {local_port} is already in use");
+  }
+  if stderr.is_empty() {
+    "kubectl port-forward exited immediately".to_string()
+  } else {
+    stderr.lines().last().unwrap_or(stderr).to_string()
+  }
+}
+
+/// Start forwarding `localhost:local_port` to `remote_port` on a pod. The
+/// forward stays in the registry until stopped or until `kubectl` exits.
+pub fn port_forward_pod(pod: String, namespace: String, local_port: u16, remote_port: u16, cx: &mut App) {
+  start_port_forward(ForwardTarget::Pod(pod), namespace, local_port, remote_port, cx);
+}
+
+/// Forward a service port to the same local port, or to a free one when
+/// that is taken.
+pub fn port_forward_service(service: String, namespace: String, port: u16, cx: &mut App) {
+  let local_port = pick_local_port(port);
+  start_port_forward(ForwardTarget::Service(service), namespace, local_port, port, cx);
+}
+
+fn start_port_forward(target: ForwardTarget, namespace: String, local_port: u16, remote_port: u16, cx: &mut App) {
+  let disp = dispatcher(cx);
+
+  if let Err(error) = check_local_port(local_port) {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed { error });
+    });
+    return;
+  }
+
+  let context = docker_state(cx).read(cx).active_kube_context.clone();
+  let mut args: Vec<String> = Vec::new();
+  if let Some(ctx) = context {
+    args.extend(["--context".to_string(), ctx]);
+  }
+  args.extend([
+    "port-forward".to_string(),
+    "-n".to_string(),
+    namespace.clone(),
+    target.resource(),
+    format!("{local_port}:{remote_port}"),
+  ]);
+  log_command(cx, format!("kubectl {}", args.join(" ")));
+
+  let tokio_task = Tokio::spawn(cx, async move {
+    let mut child = crate::utils::kubectl_cmd()
+      .args(&args)
+      .stdin(Stdio::null())
+      .stdout(Stdio::null())
+      .stderr(Stdio::piped())
+      .spawn()
+      .map_err(|e| anyhow::anyhow!("Failed to start kubectl: {e}"))?;
+
+    tokio::time::sleep(STARTUP_GRACE).await;
+    if let Ok(Some(_)) = child.try_wait() {
+      let mut stderr = String::new();
+      if let Some(mut pipe) = child.stderr.take() {
+        let _ = pipe.read_to_string(&mut stderr);
+      }
+      return Err(anyhow::anyhow!(describe_forward_failure(local_port, &stderr)));
+    }
+    // Keep draining stderr past startup: closing the pipe would SIGPIPE
+    // kubectl and leaving it unread could block it on a full buffer.
+    if let Some(mut pipe) = child.stderr.take() {
+      std::thread::spawn(move || {
+        let _ = std::io::copy(&mut pipe, &mut std::io::sink());
+      });
+    }
+    Ok(child)
+  });
+
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await;
+    cx.update(|cx| match result {
+      Ok(Ok(child)) => {
+        let forward = PortForward {
+          id: FORWARD_ID_COUNTER.fetch_add(1, Ordering::SeqCst),
+          target: target.clone(),
+          namespace,
+          local_port,
+          remote_port,
+          started_at: Local::now(),
+          child: Arc::new(Mutex::new(child)),
+        };
+        port_forward_manager(cx).update(cx, |m, cx| {
+          m.insert(forward);
+          cx.notify();
+        });
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!("Forwarding localhost:{local_port} to {}:{remote_port}", target.name()),
+          });
+        });
+      }
+      Ok(Err(e)) => {
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Port forward failed: {e}"),
+          });
+        });
+      }
+      Err(e) => {
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed { error: e.to_string() });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// Stop one forward and kill its `kubectl` process
+pub fn stop_port_forward(id: u64, cx: &mut App) {
+  port_forward_manager(cx).update(cx, |m, cx| {
+    if m.stop(id) {
+      cx.notify();
+    }
+  });
+}
+
+/// Kill every forward. Runs automatically when the app quits.
+pub fn stop_all_port_forwards(cx: &mut App) {
+  if let Some(global) = cx.try_global::<GlobalPortForwardManager>() {
+    let manager = global.0.clone();
+    manager.update(cx, |m, cx| {
+      m.stop_all();
+      cx.notify();
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_check_local_port_detects_listener() {
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let err = check_local_port(port).unwrap_err();
+    assert!(err.contains("already in use"));
+    drop(listener);
+    assert!(check_local_port(port).is_ok());
+  }
+
+  #[test]
+  fn test_pick_local_port_falls_back_when_taken() {
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let taken = listener.local_addr().unwrap().port();
+    let picked = pick_local_port(taken);
+    assert_ne!(picked, taken);
+    assert!(picked > 0);
+  }
+
+  #[test]
+  fn test_forward_target_resource() {
+    assert_eq!(ForwardTarget::Pod("web-0".to_string()).resource(), "pod/web-0");
+    assert_eq!(ForwardTarget::Service("web".to_string()).resource(), "svc/web");
+  }
+
+  #[test]
+  fn test_describe_forward_failure() {
+    let stderr = "Unable to listen on port 8080: Listeners failed to create with the following errors: \
+                  [unable to create listener: Error listen tcp4 127.0.0.1:8080: bind: address already in use]";
+    assert_eq!(
+      describe_forward_failure(8080, stderr),
+      "Local port 8080 is already in use"
+    );
+    assert_eq!(
+      describe_forward_failure(8080, "error: pods \"web\" not found\n"),
+      "error: pods \"web\" not found"
+    );
+    assert_eq!(
+      describe_forward_failure(8080, ""),
+      "kubectl port-forward exited immediately"
+    );
+  }
+}
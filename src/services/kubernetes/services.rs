@@ -2,16 +2,19 @@
 
 use gpui::App;
 
-use crate::services::{Tokio, complete_task, fail_task, start_task};
-use crate::state::{StateChanged, docker_state};
+use crate::services::{Tokio, complete_task, fail_task, log_command, start_task};
+use crate::state::{CurrentView, StateChanged, docker_state};
 
-use super::super::core::{DispatcherEvent, dispatcher};
+use super::super::core::{DispatcherEvent, dispatcher, mark_refreshing};
+use super::kubectl::describe_resource;
+use super::manifests::apply_resource_yaml;
 
 /// Refresh services list
 pub fn refresh_services(cx: &mut App) {
   use crate::state::LoadState;
 
   let state = docker_state(cx);
+  mark_refreshing(CurrentView::Services, cx);
 
   // Only show loading state on initial load, not on background refreshes
   let is_initial_load = matches!(state.read(cx).services_state, LoadState::NotLoaded);
@@ -54,6 +57,9 @@ pub fn refresh_services(cx: &mut App) {
           state.set_services_error(error_msg);
         }
       });
+      state.update(cx, |_, cx| {
+        cx.emit(StateChanged::RefreshStatusChanged(CurrentView::Services));
+      });
     })
   })
   .detach();
@@ -61,6 +67,7 @@ pub fn refresh_services(cx: &mut App) {
 
 /// Delete a service
 pub fn delete_service(name: String, namespace: String, cx: &mut App) {
+  log_command(cx, format!("kubectl delete service {name} -n {namespace}"));
   let task_id = start_task(cx, format!("Deleting service '{name}'..."));
   let name_clone = name.clone();
   let _state = docker_state(cx);
@@ -128,38 +135,34 @@ pub fn get_service_yaml(name: String, namespace: String, cx: &mut App) {
   .detach();
 }
 
-/// Create a new Kubernetes service
+/// Describe a service (`kubectl describe`), including endpoints and events
+pub fn describe_service(name: String, namespace: String, cx: &mut App) {
+  describe_resource(
+    "service",
+    name,
+    namespace,
+    |service_name, namespace, describe| StateChanged::ServiceDescribeLoaded {
+      service_name,
+      namespace,
+      describe,
+    },
+    cx,
+  );
+}
+
+/// Apply edited service YAML (`kubectl apply`), then reload the list and the YAML tab
 pub fn apply_service_yaml(name: String, namespace: String, yaml: String, cx: &mut App) {
-  let task_id = start_task(cx, format!("Applying service '{name}'..."));
-  let disp = dispatcher(cx);
-  let label = name.clone();
-  let tokio_task = Tokio::spawn(cx, async move {
-    let client = crate::kubernetes::KubeClient::new().await?;
-    client.apply_service_yaml(&name, &namespace, &yaml).await
-  });
-  cx.spawn(async move |cx| {
-    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
-    cx.update(|cx| match result {
-      Ok(()) => {
-        complete_task(cx, task_id);
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskCompleted {
-            message: format!("Service '{label}' applied"),
-          });
-        });
-        refresh_services(cx);
-      }
-      Err(e) => {
-        fail_task(cx, task_id, e.to_string());
-        disp.update(cx, |_, cx| {
-          cx.emit(DispatcherEvent::TaskFailed {
-            error: format!("Failed to apply service '{label}': {e}"),
-          });
-        });
-      }
-    })
-  })
-  .detach();
+  apply_resource_yaml(
+    "Service",
+    name,
+    namespace,
+    yaml,
+    |name, namespace, cx| {
+      refresh_services(cx);
+      get_service_yaml(name, namespace, cx);
+    },
+    cx,
+  );
 }
 
 pub fn create_service(options: crate::kubernetes::CreateServiceOptions, cx: &mut App) {
@@ -217,7 +217,9 @@ pub fn provision_node(target: HostEntry, control_plane: HostEntry, role: String,
         super::cluster::refresh_nodes(cx);
         docker_state(cx).update(cx, |_s, cx| cx.emit(StateChanged::NodesUpdated));
       }
-      Err(e) => fail_task(cx, task_id, e),
+      Err(e) => {
+        fail_task(cx, task_id, e);
+      }
     });
   })
   .detach();
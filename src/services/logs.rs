@@ -0,0 +1,315 @@
+//! Saving captured container and pod logs, and exporting whole compose
+//! projects, to disk
+
+use std::collections::HashSet;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Local, Utc};
+use gpui::App;
+
+use crate::docker::ComposeProject;
+use crate::services::{Tokio, complete_task, fail_task, set_task_progress, start_task};
+
+use super::core::{DispatcherEvent, dispatcher, docker_client};
+
+/// `<name>-<YYYYmmdd-HHMMSS>.log`, with path separators and colons in
+/// the resource name replaced so the suggestion is a valid file name.
+pub fn default_log_filename(name: &str, now: DateTime<Local>) -> String {
+  let name = name.trim_start_matches('/').replace(['/', '\\', ':'], "-");
+  format!("{name}-{}.log", now.format("%Y%m%d-%H%M%S"))
+}
+
+/// Write log chunks one at a time, dropping the `\r` the log viewer puts
+/// in front of each `\n` so the file has plain line endings. Returns the
+/// number of bytes written.
+pub fn write_log_chunks(chunks: &[Arc<[u8]>], out: &mut impl Write) -> std::io::Result<u64> {
+  let mut written = 0u64;
+  let mut pending_cr = false;
+  for chunk in chunks {
+    let mut start = 0;
+    for (i, &b) in chunk.iter().enumerate() {
+      if b == b'\r' {
+        if pending_cr {
+          out.write_all(b"\r")?;
+          written += 1;
+        }
+        out.write_all(&chunk[start..i])?;
+        written += (i - start) as u64;
+        start = i + 1;
+        pending_cr = true;
+      } else {
+        if pending_cr && b != b'\n' {
+          out.write_all(b"\r")?;
+          written += 1;
+        }
+        pending_cr = false;
+      }
+    }
+    out.write_all(&chunk[start..])?;
+    written += (chunk.len() - start) as u64;
+  }
+  if pending_cr {
+    out.write_all(b"\r")?;
+    written += 1;
+  }
+  out.flush()?;
+  Ok(written)
+}
+
+/// Write captured log chunks for `name` to `dest` in the background.
+pub fn save_logs(name: String, chunks: Vec<Arc<[u8]>>, dest: PathBuf, cx: &mut App) {
+  let disp = dispatcher(cx);
+  if chunks.iter().all(|c| c.is_empty()) {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: format!("No logs captured for '{name}' yet"),
+      });
+    });
+    return;
+  }
+
+  let task_id = start_task(cx, format!("Saving logs for '{name}'..."));
+  cx.spawn(async move |cx| {
+    let dest_for_msg = dest.clone();
+    let result = cx
+      .background_executor()
+      .spawn(async move {
+        let file = std::fs::File::create(&dest)?;
+        write_log_chunks(&chunks, &mut BufWriter::new(file))
+      })
+      .await;
+
+    cx.update(|cx| match result {
+      Ok(bytes) => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!(
+              "Saved {} of logs to {}",
+              bytesize::ByteSize(bytes),
+              dest_for_msg.display()
+            ),
+          });
+        });
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to save logs for '{name}': {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
+/// How `export_project_logs` lays out what it captured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectLogLayout {
+  /// One `<service>.log` per container, in a new directory
+  PerService,
+  /// A single file with every line prefixed by its service, sorted by time
+  Combined,
+}
+
+/// Merge timestamped (`docker logs -t`) output from several services into
+/// one stream ordered by time. Each line is prefixed with `service | `; a
+/// line without a leading timestamp keeps the time of the line before it.
+pub fn interleave_service_logs(logs: &[(String, String)]) -> String {
+  let mut lines: Vec<(Option<DateTime<Utc>>, &str, &str)> = Vec::new();
+  for (service, text) in logs {
+    let mut last = None;
+    for line in text.lines() {
+      let stamp = line
+        .split_once(' ')
+        .and_then(|(ts, _)| DateTime::parse_from_rfc3339(ts).ok())
+        .map(|ts| ts.with_timezone(&Utc));
+      if stamp.is_some() {
+        last = stamp;
+      }
+      lines.push((last, service, line));
+    }
+  }
+  // Stable, so lines sharing a timestamp keep their per-service order.
+  lines.sort_by_key(|(stamp, _, _)| *stamp);
+
+  let mut out = String::new();
+  for (_, service, line) in lines {
+    out.push_str(service);
+    out.push_str(" | ");
+    out.push_str(line);
+    out.push('\n');
+  }
+  out
+}
+
+/// `<service>.log` for each entry, made file-name safe. Scaled services
+/// share a name, so repeats get `-2`, `-3`, ...
+pub fn service_log_filenames(services: &[&str]) -> Vec<String> {
+  let mut taken = HashSet::new();
+  services
+    .iter()
+    .map(|name| {
+      let base = name.replace(['/', '\\', ':'], "-");
+      let mut candidate = format!("{base}.log");
+      let mut n = 2;
+      while !taken.insert(candidate.clone()) {
+        candidate = format!("{base}-{n}.log");
+        n += 1;
+      }
+      candidate
+    })
+    .collect()
+}
+
+fn write_project_logs(captured: &[(String, String)], layout: ProjectLogLayout, dest: &Path) -> std::io::Result<()> {
+  match layout {
+    ProjectLogLayout::PerService => {
+      std::fs::create_dir_all(dest)?;
+      let names: Vec<&str> = captured.iter().map(|(service, _)| service.as_str()).collect();
+      for ((_, logs), file) in captured.iter().zip(service_log_filenames(&names)) {
+        std::fs::write(dest.join(file), logs)?;
+      }
+      Ok(())
+    }
+    ProjectLogLayout::Combined => std::fs::write(dest, interleave_service_logs(captured)),
+  }
+}
+
+/// Fetch the full log of every container in `project` and write them to
+/// `dest` (a new directory for `PerService`, a file for `Combined`).
+/// Containers whose logs can't be read are skipped and counted in the
+/// summary.
+pub fn export_project_logs(project: ComposeProject, layout: ProjectLogLayout, dest: PathBuf, cx: &mut App) {
+  let disp = dispatcher(cx);
+  let total = project.services.len();
+  if total == 0 {
+    disp.update(cx, |_, cx| {
+      cx.emit(DispatcherEvent::TaskFailed {
+        error: format!("Project '{}' has no containers", project.name),
+      });
+    });
+    return;
+  }
+
+  let task_id = start_task(cx, format!("Exporting logs for '{}'...", project.name));
+  let client = docker_client();
+  cx.spawn(async move |cx| {
+    let mut captured = Vec::with_capacity(total);
+    for (i, service) in project.services.iter().enumerate() {
+      #[allow(clippy::cast_precision_loss)]
+      let progress = i as f32 / total as f32;
+      let status = format!("Fetching {} ({}/{total})", service.name, i + 1);
+      let client = client.clone();
+      let id = service.container_id.clone();
+      let Ok(fetch) = cx.update(|cx| {
+        set_task_progress(cx, task_id, progress, Some(status));
+        Tokio::spawn(cx, async move {
+          let guard = client.read().await;
+          let docker = guard
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+          docker.container_logs(&id, None, true).await
+        })
+      }) else {
+        return;
+      };
+      match fetch.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}"))) {
+        Ok(logs) => captured.push((service.name.clone(), logs)),
+        Err(e) => tracing::warn!("Skipping logs for {}: {e}", service.name),
+      }
+    }
+
+    let count = captured.len();
+    let dest_for_msg = dest.clone();
+    let result = cx
+      .background_executor()
+      .spawn(async move { write_project_logs(&captured, layout, &dest) })
+      .await;
+
+    let _ = cx.update(|cx| match result {
+      Ok(()) if count > 0 => {
+        complete_task(cx, task_id);
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskCompleted {
+            message: format!(
+              "Exported logs for {count} of {total} services to {}",
+              dest_for_msg.display()
+            ),
+          });
+        });
+      }
+      Ok(()) => {
+        let error = format!("Couldn't read logs for any service in '{}'", project.name);
+        fail_task(cx, task_id, error.clone());
+        disp.update(cx, |_, cx| cx.emit(DispatcherEvent::TaskFailed { error }));
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to write logs for '{}': {e}", project.name),
+          });
+        });
+      }
+    });
+  })
+  .detach();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn chunks(parts: &[&str]) -> Vec<Arc<[u8]>> {
+    parts.iter().map(|p| Arc::from(p.as_bytes())).collect()
+  }
+
+  #[test]
+  fn test_write_log_chunks_normalizes_line_endings() {
+    let mut out = Vec::new();
+    let written = write_log_chunks(&chunks(&["one\r\ntwo\r", "\nthree\rfour\r\n"]), &mut out).unwrap();
+    assert_eq!(out, b"one\ntwo\nthree\rfour\n");
+    assert_eq!(written, out.len() as u64);
+  }
+
+  #[test]
+  fn test_default_log_filename() {
+    let now = chrono::TimeZone::with_ymd_and_hms(&Local, 2024, 1, 1, 9, 5, 0).unwrap();
+    assert_eq!(default_log_filename("/web", now), "web-20240101-090500.log");
+    assert_eq!(
+      default_log_filename("api/sidecar", now),
+      "api-sidecar-20240101-090500.log"
+    );
+  }
+
+  #[test]
+  fn test_interleave_service_logs() {
+    let logs = vec![
+      (
+        "web".to_string(),
+        "2024-01-01T09:00:02.5Z started\n  continued\n2024-01-01T09:00:04Z ready\n".to_string(),
+      ),
+      (
+        "db".to_string(),
+        "2024-01-01T09:00:03.000000001Z accepting\n".to_string(),
+      ),
+    ];
+    assert_eq!(
+      interleave_service_logs(&logs),
+      "web | 2024-01-01T09:00:02.5Z started\nweb |   continued\ndb | 2024-01-01T09:00:03.000000001Z accepting\nweb | 2024-01-01T09:00:04Z ready\n"
+    );
+  }
+
+  #[test]
+  fn test_service_log_filenames_dedupes() {
+    assert_eq!(
+      service_log_filenames(&["web", "db", "web", "a/b"]),
+      vec!["web.log", "db.log", "web-2.log", "a-b.log"]
+    );
+  }
+}
@@ -3,45 +3,72 @@
 //! This module contains all the async operations and dispatchers for the application.
 //! It is organized into submodules by resource type:
 //!
+//! - `auto_refresh` - Periodic list and stats refresh driven by settings
+//! - `command_log` - CLI-equivalent log of the operations the app performs
+//! - `connection` - Docker daemon health checks and automatic reconnect
 //! - `core` - Dispatcher types and Docker client management
+//! - `diagnostics` - Redacted diagnostic report, JSON export and notification history for bug reports
 //! - `docker` - Docker resource operations (containers, images, volumes, networks, compose)
+//! - `editor` - Opening container and machine files in a local editor, with write-back
+//! - `error_hints` - Friendly titles and suggested fixes for common task failures
 //! - `colima` - Colima machine and Kubernetes control operations
 //! - `kubernetes` - Kubernetes resource operations (pods, services, deployments)
+//! - `logs` - Saving captured container and pod logs, and compose project log exports, to disk
 //! - `navigation` - View and tab navigation functions
 //! - `prune` - Docker prune operations
+//! - `themes` - Importing theme files and reloading the theme catalog
 //! - `init` - Initial data loading
+//! - `wait_for` - Background waits for a container or pod to reach a condition
 //! - `watchers` - Real-time resource watchers for Docker and Kubernetes
 
+mod auto_refresh;
 mod colima;
+mod command_log;
+mod connection;
 mod core;
+mod diagnostics;
 pub mod dns;
 mod docker;
+mod editor;
+mod error_hints;
 mod favorites;
 mod gpui_tokio;
 pub mod helper;
 mod host;
 mod init;
 mod kubernetes;
+mod logs;
 mod navigation;
 pub mod proxy;
 mod prune;
 mod task_manager;
+mod themes;
 pub mod tls;
+mod wait_for;
 mod watchers;
 
 // Re-export everything for backward compatibility
+pub use auto_refresh::*;
 pub use colima::*;
+pub use command_log::*;
+pub use connection::*;
 pub use core::*;
+pub use diagnostics::*;
 pub use docker::*;
+pub use editor::*;
+pub use error_hints::*;
 pub use favorites::*;
 pub use gpui_tokio::Tokio;
 pub use host::*;
 pub use init::*;
 pub use kubernetes::*;
+pub use logs::*;
 pub use navigation::*;
 pub use prune::*;
 pub use task_manager::*;
-pub use watchers::stop_watchers;
+pub use themes::*;
+pub use wait_for::*;
+pub use watchers::{clear_docker_events, stop_watchers};
 
 use gpui::App;
 
@@ -58,6 +85,9 @@ pub fn init_services(cx: &mut App) {
 
   // Initialize services
   init_task_manager(cx);
+  init_command_log(cx);
+  init_notification_history(cx);
+  init_port_forwards(cx);
   init_dispatcher(cx);
   dns::init(cx);
   proxy::init(cx);
@@ -74,6 +104,13 @@ pub fn start_watchers(cx: &mut App) {
   apply_dns_settings(cx);
 }
 
+/// Apply the sidebar section toggles: leave a view whose group was just
+/// hidden and start or stop the matching watchers.
+pub fn apply_section_settings(cx: &mut App) {
+  ensure_visible_view(cx);
+  watchers::sync_watchers(cx);
+}
+
 /// Apply the current `AppSettings` DNS toggles. Starts or stops the local
 /// resolver + container watcher + reverse proxy accordingly. Safe to call
 /// any time the user changes the DNS settings.
@@ -5,16 +5,41 @@ use gpui::App;
 use crate::colima::MachineId;
 use crate::state::{
   ContainerDetailTab, CurrentView, DeploymentDetailTab, MachineDetailTab, PodDetailTab, ServiceDetailTab, StateChanged,
-  docker_state,
+  docker_state, settings_state,
 };
 
-/// Set the current view
+/// Set the current view. Views whose sidebar group is hidden in Settings
+/// are ignored, so shortcuts and the palette can't reach them either.
 pub fn set_view(view: CurrentView, cx: &mut App) {
+  if !settings_state(cx).read(cx).settings.view_visible(view) {
+    return;
+  }
   let state = docker_state(cx);
   state.update(cx, |state, cx| {
     state.set_view(view);
     cx.emit(StateChanged::ViewChanged);
   });
+  remember_view(view, cx);
+}
+
+/// Persist the active view so the next launch opens where the user left
+/// off. Nothing else reacts to it, so no `SettingsUpdated` is emitted.
+fn remember_view(view: CurrentView, cx: &mut App) {
+  settings_state(cx).update(cx, |state, cx| {
+    if state.settings.last_view != Some(view) {
+      state.settings.last_view = Some(view);
+      state.save_later(cx);
+    }
+  });
+}
+
+/// Fall back to the Dashboard when the current view's sidebar group was
+/// just hidden.
+pub fn ensure_visible_view(cx: &mut App) {
+  let current = docker_state(cx).read(cx).current_view;
+  if !settings_state(cx).read(cx).settings.view_visible(current) {
+    set_view(CurrentView::Dashboard, cx);
+  }
 }
 
 // ==================== Container Tab Navigation ====================
@@ -30,6 +55,17 @@ pub fn open_container_terminal(id: String, cx: &mut App) {
   });
 }
 
+/// Open a container's attach tab (main process, not an exec shell)
+pub fn open_container_attach(id: String, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |_state, cx| {
+    cx.emit(StateChanged::ContainerTabRequest {
+      container_id: id,
+      tab: ContainerDetailTab::Attach,
+    });
+  });
+}
+
 /// Open a container's logs tab
 pub fn open_container_logs(id: String, cx: &mut App) {
   let state = docker_state(cx);
@@ -345,3 +381,15 @@ pub fn request_scale_dialog(name: String, namespace: String, current_replicas: i
     });
   });
 }
+
+/// Request to open the image/env editor for a deployment container
+pub fn request_container_edit(name: String, namespace: String, container: String, cx: &mut App) {
+  let state = docker_state(cx);
+  state.update(cx, |_state, cx| {
+    cx.emit(StateChanged::DeploymentContainerEditRequest {
+      deployment_name: name,
+      namespace,
+      container,
+    });
+  });
+}
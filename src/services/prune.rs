@@ -1,8 +1,9 @@
 //! Docker prune operations
 
-use gpui::{App, Entity};
+use gpui::{App, Entity, Task};
 
-use crate::docker::PruneResult;
+use crate::docker::{DiskUsageSummary, PrunePreview, PruneResult};
+use crate::kubernetes::PodPhase;
 use crate::services::{Tokio, complete_task, fail_task, start_task};
 use crate::ui::PruneView;
 
@@ -10,6 +11,145 @@ use super::core::{DispatcherEvent, dispatcher, docker_client};
 use super::docker::{refresh_containers, refresh_images, refresh_networks, refresh_volumes};
 use super::kubernetes::{refresh_deployments, refresh_pods, refresh_services};
 
+/// `docker system df`: counts, sizes and reclaimable space per category.
+/// Resolves to `None` when Docker isn't connected, the call fails, or the
+/// Tokio runtime isn't up yet (view tests built without the service layer).
+pub fn docker_system_df(cx: &App) -> Task<Option<DiskUsageSummary>> {
+  let Some(tokio_handle) = Tokio::try_runtime_handle() else {
+    return Task::ready(None);
+  };
+  let client = docker_client();
+  cx.background_executor().spawn(async move {
+    tokio_handle.block_on(async {
+      let guard = client.read().await;
+      match guard.as_ref()?.get_disk_usage().await {
+        Ok(usage) => Some(usage),
+        Err(e) => {
+          tracing::debug!("docker system df failed: {e}");
+          None
+        }
+      }
+    })
+  })
+}
+
+fn is_system_namespace(ns: &str) -> bool {
+  matches!(ns, "kube-system" | "kube-public" | "kube-node-lease")
+}
+
+/// System namespaces plus the API server's own `default/kubernetes` service
+fn is_system_resource(ns: &str, name: &str) -> bool {
+  is_system_namespace(ns) || (ns == "default" && name == "kubernetes")
+}
+
+/// Pod prune keeps running pods unless `all` is set: only completed/failed
+/// ones go by default.
+fn pod_prunable(phase: PodPhase, all: bool) -> bool {
+  all || matches!(phase, PodPhase::Succeeded | PodPhase::Failed)
+}
+
+/// Dry run of `prune_docker`: list what the given options would remove
+/// (via filtered list endpoints) into the view without deleting anything.
+pub fn preview_prune(view: Entity<PruneView>, options: &crate::ui::PruneOptions, cx: &mut App) {
+  let task_id = start_task(cx, "Previewing prune...".to_string());
+  let disp = dispatcher(cx);
+  let client = docker_client();
+
+  view.update(cx, |v, cx| {
+    v.set_preview_loading(true);
+    cx.notify();
+  });
+
+  let opts = options.clone();
+  let tokio_task = Tokio::spawn(cx, async move {
+    let mut preview = PrunePreview::default();
+    let needs_docker =
+      opts.prune_containers || opts.prune_images || opts.prune_volumes || opts.prune_networks || opts.prune_build_cache;
+
+    if needs_docker {
+      let guard = client.read().await;
+      let docker = guard
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("Docker client not connected"))?;
+      if opts.prune_containers {
+        preview.containers = docker.prune_candidates_containers().await?;
+      }
+      if opts.prune_images {
+        preview.images = docker.prune_candidates_images(opts.images_dangling_only).await?;
+      }
+      if opts.prune_volumes {
+        preview.volumes = docker.prune_candidates_volumes().await?;
+      }
+      if opts.prune_networks {
+        preview.networks = docker.prune_candidates_networks().await?;
+      }
+      if opts.prune_build_cache {
+        preview.build_cache = docker.prune_candidates_build_cache(opts.build_cache_all).await?;
+      }
+    }
+
+    let needs_k8s = opts.prune_k8s_pods || opts.prune_k8s_deployments || opts.prune_k8s_services;
+    if needs_k8s && let Ok(kube_client) = crate::kubernetes::KubeClient::new().await {
+      if opts.prune_k8s_deployments {
+        preview.deployments = kube_client
+          .list_deployments(None)
+          .await?
+          .into_iter()
+          .filter(|d| !is_system_namespace(&d.namespace))
+          .map(|d| format!("{}/{}", d.namespace, d.name))
+          .collect();
+      }
+      if opts.prune_k8s_services {
+        preview.services = kube_client
+          .list_services(None)
+          .await?
+          .into_iter()
+          .filter(|s| !is_system_resource(&s.namespace, &s.name))
+          .map(|s| format!("{}/{}", s.namespace, s.name))
+          .collect();
+      }
+      if opts.prune_k8s_pods {
+        preview.pods = kube_client
+          .list_pods(None)
+          .await?
+          .into_iter()
+          .filter(|p| !is_system_namespace(&p.namespace) && pod_prunable(p.phase, opts.prune_k8s_pods_all))
+          .map(|p| format!("{}/{}", p.namespace, p.name))
+          .collect();
+      }
+    }
+
+    Ok::<_, anyhow::Error>(preview)
+  });
+
+  let options = options.clone();
+  cx.spawn(async move |cx| {
+    let result = tokio_task.await.unwrap_or_else(|e| Err(anyhow::anyhow!("{e}")));
+    cx.update(|cx| match result {
+      Ok(preview) => {
+        complete_task(cx, task_id);
+        view.update(cx, |v, cx| {
+          v.set_preview(options, preview);
+          cx.notify();
+        });
+      }
+      Err(e) => {
+        fail_task(cx, task_id, e.to_string());
+        view.update(cx, |v, cx| {
+          v.set_error(e.to_string());
+          cx.notify();
+        });
+        disp.update(cx, |_, cx| {
+          cx.emit(DispatcherEvent::TaskFailed {
+            error: format!("Failed to preview prune: {e}"),
+          });
+        });
+      }
+    })
+  })
+  .detach();
+}
+
 /// Run the selected prune operations, reporting progress and the final
 /// result back into the given `PruneView` (loading → result/error).
 pub fn prune_docker(view: Entity<PruneView>, options: &crate::ui::PruneOptions, cx: &mut App) {
@@ -67,13 +207,6 @@ pub fn prune_docker(view: Entity<PruneView>, options: &crate::ui::PruneOptions,
     // Kubernetes pruning
     let needs_k8s = prune_k8s_pods || prune_k8s_deployments || prune_k8s_services;
     if needs_k8s && let Ok(kube_client) = crate::kubernetes::KubeClient::new().await {
-      // Helper to check if namespace is a system namespace
-      let is_system_namespace = |ns: &str| matches!(ns, "kube-system" | "kube-public" | "kube-node-lease");
-
-      // Helper to check if resource is a system resource
-      let is_system_resource =
-        |ns: &str, name: &str| is_system_namespace(ns) || (ns == "default" && name == "kubernetes");
-
       // Prune deployments first (this will cascade delete their pods)
       if prune_k8s_deployments && let Ok(deployments) = kube_client.list_deployments(None).await {
         for deployment in deployments {
@@ -119,19 +252,9 @@ pub fn prune_docker(view: Entity<PruneView>, options: &crate::ui::PruneOptions,
           if is_system_namespace(&pod.namespace) {
             continue;
           }
-
-          // If prune_k8s_pods_all is true, delete all pods
-          // Otherwise, only delete completed/failed pods
-          let should_delete = if prune_k8s_pods_all {
-            true
-          } else {
-            matches!(
-              pod.phase,
-              crate::kubernetes::PodPhase::Succeeded | crate::kubernetes::PodPhase::Failed
-            )
-          };
-
-          if should_delete && kube_client.delete_pod(&pod.name, &pod.namespace).await.is_ok() {
+          if pod_prunable(pod.phase, prune_k8s_pods_all)
+            && kube_client.delete_pod(&pod.name, &pod.namespace).await.is_ok()
+          {
             result.pods_deleted.push(format!("{}/{}", pod.namespace, pod.name));
           }
         }
@@ -187,8 +310,10 @@ pub fn prune_docker(view: Entity<PruneView>, options: &crate::ui::PruneOptions,
             )
           };
 
-          prune_dialog_clone.update(cx, |dialog, _cx| {
+          // Re-read disk usage so the view shows what the prune freed
+          prune_dialog_clone.update(cx, |dialog, cx| {
             dialog.set_result(prune_result);
+            PruneView::refresh_disk_usage(cx);
           });
 
           disp.update(cx, |_, cx| {
@@ -1,9 +1,13 @@
+use chrono::{DateTime, Local};
 use gpui::{App, AppContext, Entity, Global};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 static TASK_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
 
+/// Finished tasks kept for the activity monitor's history
+pub const TASK_HISTORY_CAPACITY: usize = 50;
+
 fn next_task_id() -> u64 {
   TASK_ID_COUNTER.fetch_add(1, Ordering::SeqCst)
 }
@@ -15,6 +19,50 @@ pub enum TaskStatus {
   Failed(String),
 }
 
+/// An operation that can be re-run after its task fails. Holds only the
+/// operation's arguments so a retry kept in the history or on a
+/// notification never pins views or clients.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskRetry {
+  PullImage {
+    image: String,
+    platform: Option<String>,
+  },
+  StartContainer {
+    id: String,
+  },
+  StopContainer {
+    id: String,
+    timeout: Option<u64>,
+    signal: Option<String>,
+  },
+  RestartContainer {
+    id: String,
+  },
+  ScaleDeployment {
+    name: String,
+    namespace: String,
+    replicas: i32,
+  },
+}
+
+impl TaskRetry {
+  /// Start the operation again as a new task
+  pub fn run(&self, cx: &mut App) {
+    match self.clone() {
+      Self::PullImage { image, platform } => super::pull_image(image, platform, cx),
+      Self::StartContainer { id } => super::start_container(id, cx),
+      Self::StopContainer { id, timeout, signal } => super::stop_container(id, timeout, signal, cx),
+      Self::RestartContainer { id } => super::restart_container(id, cx),
+      Self::ScaleDeployment {
+        name,
+        namespace,
+        replicas,
+      } => super::scale_deployment(name, namespace, replicas, cx),
+    }
+  }
+}
+
 /// A single stage/step within a task
 #[derive(Debug, Clone)]
 pub struct TaskStage {
@@ -29,6 +77,15 @@ impl TaskStage {
   }
 }
 
+/// A sub-item of a task with its own progress, such as one image layer
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskPart {
+  pub label: String,
+  pub status: String,
+  pub progress: f32, // 0.0 - 1.0
+  pub done: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct Task {
   pub id: u64,
@@ -41,6 +98,15 @@ pub struct Task {
   pub current_stage: usize,
   /// Current stage status message
   pub stage_status: Option<String>,
+  /// Whether the task bar offers a cancel button for this task
+  pub cancellable: bool,
+  /// Set when the user asked to cancel; the task's owner polls it and stops
+  pub cancel_requested: bool,
+  pub started_at: DateTime<Local>,
+  /// How to run the operation again if it fails
+  pub retry: Option<TaskRetry>,
+  /// Per-item progress drawn as small bars in the task bar
+  pub parts: Vec<TaskPart>,
 }
 
 impl Task {
@@ -53,6 +119,11 @@ impl Task {
       stages: Vec::new(),
       current_stage: 0,
       stage_status: None,
+      cancellable: false,
+      cancel_requested: false,
+      started_at: Local::now(),
+      retry: None,
+      parts: Vec::new(),
     }
   }
 
@@ -94,11 +165,73 @@ impl Task {
     // Clamp to valid range for gpui::relative()
     progress.clamp(0.0, 1.0)
   }
+
+  /// Progress for the task bar: the mean of the parts when the task has
+  /// them, otherwise the stage progress
+  #[allow(clippy::cast_precision_loss)]
+  pub fn overall_progress(&self) -> f32 {
+    if self.parts.is_empty() {
+      self.stage_progress()
+    } else {
+      let sum: f32 = self.parts.iter().map(|p| p.progress).sum();
+      (sum / self.parts.len() as f32).clamp(0.0, 1.0)
+    }
+  }
+}
+
+/// A completed or failed task, as shown in the task history
+#[derive(Debug, Clone)]
+pub struct FinishedTask {
+  pub id: u64,
+  pub description: String,
+  /// `Completed` or `Failed` with the full error
+  pub status: TaskStatus,
+  pub started_at: DateTime<Local>,
+  pub completed_at: DateTime<Local>,
+  pub retry: Option<TaskRetry>,
+}
+
+impl FinishedTask {
+  fn from_task(task: Task) -> Self {
+    Self {
+      id: task.id,
+      description: task.description,
+      status: task.status,
+      started_at: task.started_at,
+      completed_at: Local::now(),
+      retry: task.retry,
+    }
+  }
+
+  pub fn duration(&self) -> chrono::TimeDelta {
+    self.completed_at - self.started_at
+  }
+
+  pub fn is_failed(&self) -> bool {
+    matches!(self.status, TaskStatus::Failed(_))
+  }
+
+  /// Short human duration: "850ms", "12.3s", "2m 05s"
+  pub fn display_duration(&self) -> String {
+    let ms = self.duration().num_milliseconds().max(0);
+    if ms < 1_000 {
+      format!("{ms}ms")
+    } else if ms < 60_000 {
+      #[allow(clippy::cast_precision_loss)]
+      let secs = ms as f64 / 1_000.0;
+      format!("{secs:.1}s")
+    } else {
+      let secs = ms / 1_000;
+      format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+  }
 }
 
 #[derive(Default)]
 pub struct TaskManager {
   tasks: HashMap<u64, Task>,
+  /// Finished tasks, oldest first, capped at `TASK_HISTORY_CAPACITY`
+  history: VecDeque<FinishedTask>,
 }
 
 impl TaskManager {
@@ -122,6 +255,36 @@ impl TaskManager {
     id
   }
 
+  /// Start a task the user can cancel from the task bar
+  pub fn start_cancellable_task(&mut self, description: impl Into<String>) -> u64 {
+    let mut task = Task::new(description);
+    task.cancellable = true;
+    let id = task.id;
+    self.tasks.insert(id, task);
+    id
+  }
+
+  /// Ask a cancellable task to stop. The owner notices on its next poll.
+  pub fn request_cancel(&mut self, task_id: u64) {
+    if let Some(task) = self.tasks.get_mut(&task_id)
+      && task.cancellable
+    {
+      task.cancel_requested = true;
+      task.stage_status = Some("Cancelling...".to_string());
+    }
+  }
+
+  /// Whether cancellation was requested. A task that no longer exists
+  /// counts as cancelled so orphaned pollers stop too.
+  pub fn is_cancel_requested(&self, task_id: u64) -> bool {
+    self.tasks.get(&task_id).is_none_or(|t| t.cancel_requested)
+  }
+
+  /// Drop a task without reporting completion or failure
+  pub fn remove_task(&mut self, task_id: u64) {
+    self.tasks.remove(&task_id);
+  }
+
   /// Advance to the next stage
   pub fn advance_stage(&mut self, task_id: u64) {
     if let Some(task) = self.tasks.get_mut(&task_id)
@@ -132,6 +295,16 @@ impl TaskManager {
     }
   }
 
+  /// Jump forward to `stage` (never back) and show `status` under it.
+  /// For tasks driven by a process's log, where stages can be skipped.
+  pub fn set_stage(&mut self, task_id: u64, stage: usize, status: Option<String>) {
+    if let Some(task) = self.tasks.get_mut(&task_id) {
+      let last = task.stages.len().saturating_sub(1);
+      task.current_stage = task.current_stage.max(stage.min(last));
+      task.stage_status = status;
+    }
+  }
+
   /// Set the running task's progress fraction + a free-form status line.
   pub fn set_progress(&mut self, task_id: u64, progress: f32, status: Option<String>) {
     if let Some(task) = self.tasks.get_mut(&task_id) {
@@ -140,30 +313,60 @@ impl TaskManager {
     }
   }
 
-  /// Mark task as completed
-  pub fn complete_task(&mut self, task_id: u64) {
+  /// Replace the task's per-item progress
+  pub fn set_parts(&mut self, task_id: u64, parts: Vec<TaskPart>) {
     if let Some(task) = self.tasks.get_mut(&task_id) {
+      task.parts = parts;
+    }
+  }
+
+  /// Attach a retry action, offered in the history if the task fails
+  pub fn set_retry(&mut self, task_id: u64, retry: TaskRetry) {
+    if let Some(task) = self.tasks.get_mut(&task_id) {
+      task.retry = Some(retry);
+    }
+  }
+
+  /// Mark task as completed and move it to the history
+  pub fn complete_task(&mut self, task_id: u64) {
+    if let Some(mut task) = self.tasks.remove(&task_id) {
       task.status = TaskStatus::Completed;
       task.progress = Some(1.0);
       task.current_stage = task.stages.len().saturating_sub(1);
+      self.push_history(task);
     }
-    // Remove completed tasks after marking
-    self.tasks.remove(&task_id);
   }
 
-  /// Mark task as failed
-  pub fn fail_task(&mut self, task_id: u64, error: impl Into<String>) {
-    if let Some(task) = self.tasks.get_mut(&task_id) {
-      task.status = TaskStatus::Failed(error.into());
+  /// Mark task as failed and move it to the history. Returns the task's
+  /// retry action, if it has one, for the failure notification.
+  pub fn fail_task(&mut self, task_id: u64, error: impl Into<String>) -> Option<TaskRetry> {
+    let mut task = self.tasks.remove(&task_id)?;
+    task.status = TaskStatus::Failed(error.into());
+    let retry = task.retry.clone();
+    self.push_history(task);
+    retry
+  }
+
+  fn push_history(&mut self, task: Task) {
+    if self.history.len() >= TASK_HISTORY_CAPACITY {
+      self.history.pop_front();
     }
-    // Remove failed tasks
-    self.tasks.remove(&task_id);
+    self.history.push_back(FinishedTask::from_task(task));
   }
 
   /// Get all running tasks
   pub fn running_tasks(&self) -> Vec<&Task> {
     self.tasks.values().filter(|t| t.is_running()).collect()
   }
+
+  /// Finished tasks, most recent first
+  pub fn history(&self) -> impl Iterator<Item = &FinishedTask> {
+    self.history.iter().rev()
+  }
+
+  pub fn clear_history(&mut self) {
+    self.history.clear();
+  }
 }
 
 /// Global wrapper for `TaskManager`
@@ -202,6 +405,39 @@ pub fn start_staged_task(cx: &mut App, description: impl Into<String>, stages: V
   })
 }
 
+/// Helper to start a cancellable task from any context
+pub fn start_cancellable_task(cx: &mut App, description: impl Into<String>) -> u64 {
+  let manager = task_manager(cx);
+  manager.update(cx, |m, cx| {
+    let id = m.start_cancellable_task(description);
+    cx.notify();
+    id
+  })
+}
+
+/// Helper to request cancellation of a task from any context
+pub fn cancel_task(cx: &mut App, task_id: u64) {
+  let manager = task_manager(cx);
+  manager.update(cx, |m, cx| {
+    m.request_cancel(task_id);
+    cx.notify();
+  });
+}
+
+/// Whether the user asked to cancel the given task
+pub fn is_task_cancelled(cx: &App, task_id: u64) -> bool {
+  task_manager(cx).read(cx).is_cancel_requested(task_id)
+}
+
+/// Helper to drop a cancelled task from any context
+pub fn remove_task(cx: &mut App, task_id: u64) {
+  let manager = task_manager(cx);
+  manager.update(cx, |m, cx| {
+    m.remove_task(task_id);
+    cx.notify();
+  });
+}
+
 /// Helper to advance task to next stage
 pub fn advance_stage(cx: &mut App, task_id: u64) {
   let manager = task_manager(cx);
@@ -211,6 +447,15 @@ pub fn advance_stage(cx: &mut App, task_id: u64) {
   });
 }
 
+/// Helper to move a staged task to a given stage with a status line
+pub fn set_task_stage(cx: &mut App, task_id: u64, stage: usize, status: Option<String>) {
+  let manager = task_manager(cx);
+  manager.update(cx, |m, cx| {
+    m.set_stage(task_id, stage, status);
+    cx.notify();
+  });
+}
+
 /// Update progress + status for a task from any context.
 pub fn set_task_progress(cx: &mut App, task_id: u64, progress: f32, status: Option<String>) {
   let manager = task_manager(cx);
@@ -220,6 +465,21 @@ pub fn set_task_progress(cx: &mut App, task_id: u64, progress: f32, status: Opti
   });
 }
 
+/// Helper to make a task retryable if it fails
+pub fn set_task_retry(cx: &mut App, task_id: u64, retry: TaskRetry) {
+  let manager = task_manager(cx);
+  manager.update(cx, |m, _cx| m.set_retry(task_id, retry));
+}
+
+/// Helper to clear the finished-task history from any context
+pub fn clear_task_history(cx: &mut App) {
+  let manager = task_manager(cx);
+  manager.update(cx, |m, cx| {
+    m.clear_history();
+    cx.notify();
+  });
+}
+
 /// Helper to complete a task from any context
 pub fn complete_task(cx: &mut App, task_id: u64) {
   let manager = task_manager(cx);
@@ -229,13 +489,15 @@ pub fn complete_task(cx: &mut App, task_id: u64) {
   });
 }
 
-/// Helper to fail a task from any context
-pub fn fail_task(cx: &mut App, task_id: u64, error: impl Into<String>) {
+/// Helper to fail a task from any context. Returns the task's retry
+/// action so the caller can offer it with the failure.
+pub fn fail_task(cx: &mut App, task_id: u64, error: impl Into<String>) -> Option<TaskRetry> {
   let manager = task_manager(cx);
   manager.update(cx, |m, cx| {
-    m.fail_task(task_id, error);
+    let retry = m.fail_task(task_id, error);
     cx.notify();
-  });
+    retry
+  })
 }
 
 #[cfg(test)]
@@ -277,6 +539,24 @@ mod tests {
     assert_eq!(staged_task.display_status(), "First stage");
   }
 
+  #[test]
+  fn test_task_overall_progress_from_parts() {
+    let mut manager = TaskManager::new();
+    let id = manager.start_staged_task("Pull", vec![TaskStage::new("a"), TaskStage::new("b")]);
+    let part = |progress: f32| TaskPart {
+      label: "layer".to_string(),
+      status: String::new(),
+      progress,
+      done: false,
+    };
+    manager.set_parts(id, vec![part(1.0), part(0.5)]);
+
+    let task = manager.running_tasks()[0];
+    assert!((task.overall_progress() - 0.75).abs() < 0.01);
+    // Stage progress is unaffected
+    assert!((task.stage_progress() - 0.0).abs() < 0.01);
+  }
+
   #[test]
   fn test_task_stage_progress() {
     // No stages - returns 0.0
@@ -339,6 +619,47 @@ mod tests {
     assert!(manager.running_tasks().is_empty());
   }
 
+  #[test]
+  fn test_task_history() {
+    let mut manager = TaskManager::new();
+
+    let ok = manager.start_task("Succeeds");
+    let bad = manager.start_task("Fails");
+    let retry = TaskRetry::StartContainer { id: "abc".to_string() };
+    manager.set_retry(bad, retry.clone());
+    manager.complete_task(ok);
+    assert_eq!(manager.fail_task(bad, "boom"), Some(retry.clone()));
+
+    let history: Vec<_> = manager.history().collect();
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].description, "Fails");
+    assert_eq!(history[0].status, TaskStatus::Failed("boom".to_string()));
+    assert_eq!(history[0].retry, Some(retry));
+    assert_eq!(history[1].status, TaskStatus::Completed);
+    assert!(history[1].completed_at >= history[1].started_at);
+
+    // Finishing an unknown task records nothing
+    manager.complete_task(9999);
+    assert_eq!(manager.history().count(), 2);
+
+    manager.clear_history();
+    assert_eq!(manager.history().count(), 0);
+  }
+
+  #[test]
+  fn test_task_history_is_capped() {
+    let mut manager = TaskManager::new();
+    for i in 0..TASK_HISTORY_CAPACITY + 5 {
+      let id = manager.start_task(format!("Task {i}"));
+      manager.complete_task(id);
+    }
+
+    assert_eq!(manager.history().count(), TASK_HISTORY_CAPACITY);
+    let newest = format!("Task {}", TASK_HISTORY_CAPACITY + 4);
+    assert_eq!(manager.history().next().unwrap().description, newest);
+    assert_eq!(manager.history().last().unwrap().description, "Task 5");
+  }
+
   #[test]
   fn test_task_manager_staged_task() {
     let mut manager = TaskManager::new();
@@ -374,6 +695,24 @@ mod tests {
     assert_eq!(tasks[0].current_stage, 3);
   }
 
+  #[test]
+  fn test_task_manager_set_stage() {
+    let mut manager = TaskManager::new();
+    let stages = vec![TaskStage::new("A"), TaskStage::new("B"), TaskStage::new("C")];
+    let task_id = manager.start_staged_task("Booting", stages);
+
+    manager.set_stage(task_id, 1, Some("vm: starting ...".to_string()));
+    let task = manager.running_tasks()[0];
+    assert_eq!(task.current_stage, 1);
+    assert_eq!(task.display_status(), "vm: starting ...");
+
+    // Never moves backwards, clamps past the end
+    manager.set_stage(task_id, 0, None);
+    assert_eq!(manager.running_tasks()[0].current_stage, 1);
+    manager.set_stage(task_id, 9, None);
+    assert_eq!(manager.running_tasks()[0].current_stage, 2);
+  }
+
   #[test]
   fn test_task_manager_multiple_tasks() {
     let mut manager = TaskManager::new();
@@ -455,6 +794,26 @@ mod tests {
     assert_eq!(task.display_status(), "Custom status");
   }
 
+  #[test]
+  fn test_cancellable_task() {
+    let mut manager = TaskManager::new();
+    let plain = manager.start_task("Plain");
+    let waiting = manager.start_cancellable_task("Waiting");
+
+    // Plain tasks ignore cancel requests
+    manager.request_cancel(plain);
+    assert!(!manager.is_cancel_requested(plain));
+
+    assert!(!manager.is_cancel_requested(waiting));
+    manager.request_cancel(waiting);
+    assert!(manager.is_cancel_requested(waiting));
+
+    manager.remove_task(waiting);
+    assert_eq!(manager.running_tasks().len(), 1);
+    // Removed tasks read as cancelled
+    assert!(manager.is_cancel_requested(waiting));
+  }
+
   #[test]
   fn test_task_ids_are_unique() {
     let task1 = Task::new("Task 1");
@@ -0,0 +1,193 @@
+//! Importing theme files and re-scanning the theme directories.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use gpui::{App, Timer};
+
+use crate::state::{
+  SettingsChanged, ThemeCatalog, apply_active_theme, bundled_themes_dir, read_theme_file, settings_state,
+  theme_catalog, user_themes_dir,
+};
+
+use super::core::{DispatcherEvent, dispatcher};
+
+/// Validate `source` and copy it into the user themes directory. Returns
+/// the names of the themes it holds.
+fn copy_theme_file(source: &Path, themes_dir: &Path) -> anyhow::Result<Vec<String>> {
+  let themes = read_theme_file(source)?;
+  if themes.is_empty() {
+    anyhow::bail!("{} contains no themes", source.display());
+  }
+  let file_name = source
+    .file_name()
+    .ok_or_else(|| anyhow::anyhow!("{} is not a file", source.display()))?;
+  std::fs::create_dir_all(themes_dir).map_err(|e| anyhow::anyhow!("Failed to create {}: {e}", themes_dir.display()))?;
+  let target = themes_dir.join(file_name);
+  if target != source {
+    if target.exists() {
+      anyhow::bail!(
+        "{} already exists in {}; remove or rename it first",
+        Path::new(file_name).display(),
+        themes_dir.display()
+      );
+    }
+    std::fs::copy(source, &target).map_err(|e| anyhow::anyhow!("Failed to copy theme: {e}"))?;
+  }
+  Ok(themes.into_iter().map(|t| t.name.to_string()).collect())
+}
+
+/// Re-scan the theme directories and re-apply the selected theme so edits
+/// to its file show up.
+fn rescan_themes(cx: &mut App) -> usize {
+  cx.set_global(ThemeCatalog::discover());
+  let settings = settings_state(cx);
+  let current = settings.read(cx).settings.clone();
+  apply_active_theme(&current, cx.window_appearance(), cx);
+  cx.refresh_windows();
+  settings.update(cx, |_, cx| cx.emit(SettingsChanged::ThemesReloaded));
+  theme_catalog(cx).count()
+}
+
+/// Pick up themes added, changed or removed on disk without restarting.
+pub fn reload_themes(cx: &mut App) {
+  let count = rescan_themes(cx);
+  dispatcher(cx).update(cx, |_, cx| {
+    cx.emit(DispatcherEvent::TaskCompleted {
+      message: format!("Reloaded themes ({count} available)"),
+    });
+  });
+}
+
+/// How often the theme directories are checked for changes
+const THEME_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Every `.json` file in `dirs` with its modification time, sorted, so an
+/// added, edited or removed theme changes the result.
+fn theme_files(dirs: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+  let mut files: Vec<_> = dirs
+    .iter()
+    .filter_map(|dir| std::fs::read_dir(dir).ok())
+    .flat_map(Iterator::flatten)
+    .map(|entry| entry.path())
+    .filter(|path| path.extension().is_some_and(|e| e == "json"))
+    .map(|path| {
+      let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+      (path, modified)
+    })
+    .collect();
+  files.sort();
+  files
+}
+
+/// Hot-reload themes: poll the bundled and user theme directories and
+/// re-scan whenever a theme file is added, edited or removed. Silent, unlike
+/// the explicit `reload_themes`.
+pub fn watch_theme_dirs(cx: &mut App) {
+  let dirs: Vec<PathBuf> = bundled_themes_dir().into_iter().chain([user_themes_dir()]).collect();
+  cx.spawn(async move |cx| {
+    let mut last = theme_files(&dirs);
+    loop {
+      Timer::after(THEME_WATCH_INTERVAL).await;
+      let scan_dirs = dirs.clone();
+      let current = cx
+        .background_executor()
+        .spawn(async move { theme_files(&scan_dirs) })
+        .await;
+      if current != last {
+        last = current;
+        if cx
+          .update(|cx| {
+            rescan_themes(cx);
+          })
+          .is_err()
+        {
+          break;
+        }
+      }
+    }
+  })
+  .detach();
+}
+
+/// Copy a theme JSON file into the user themes directory and make its
+/// themes selectable.
+pub fn import_theme(source: &Path, cx: &mut App) {
+  let disp = dispatcher(cx);
+  match copy_theme_file(source, &user_themes_dir()) {
+    Ok(names) => {
+      rescan_themes(cx);
+      disp.update(cx, |_, cx| {
+        cx.emit(DispatcherEvent::TaskCompleted {
+          message: format!("Imported {}", names.join(", ")),
+        });
+      });
+    }
+    Err(e) => {
+      disp.update(cx, |_, cx| {
+        cx.emit(DispatcherEvent::TaskFailed {
+          error: format!("Failed to import theme: {e:#}"),
+        });
+      });
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_theme_files_tracks_changes() {
+    let dir = std::env::temp_dir().join(format!("dockside-theme-watch-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let dirs = vec![dir.clone()];
+    assert!(theme_files(&dirs).is_empty());
+
+    std::fs::write(dir.join("a.json"), "{}").unwrap();
+    std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+    let added = theme_files(&dirs);
+    assert_eq!(added.len(), 1);
+
+    std::fs::remove_file(dir.join("a.json")).unwrap();
+    assert_ne!(theme_files(&dirs), added);
+
+    let _ = std::fs::remove_dir_all(dir);
+  }
+
+  #[test]
+  fn test_copy_theme_file() {
+    let root = std::env::temp_dir().join(format!("dockside-theme-import-{}", std::process::id()));
+    let themes_dir = root.join("themes");
+    std::fs::create_dir_all(&root).unwrap();
+
+    let valid = root.join("nord.json");
+    std::fs::write(
+      &valid,
+      r#"{"name": "Nord", "themes": [{"name": "Nord", "mode": "dark", "colors": {}}]}"#,
+    )
+    .unwrap();
+    assert_eq!(copy_theme_file(&valid, &themes_dir).unwrap(), vec!["Nord"]);
+    assert!(themes_dir.join("nord.json").exists());
+
+    // Importing a file with the same name again must not replace it
+    std::fs::write(
+      &valid,
+      r#"{"name": "Nord", "themes": [{"name": "Nord v2", "mode": "dark", "colors": {}}]}"#,
+    )
+    .unwrap();
+    assert!(copy_theme_file(&valid, &themes_dir).is_err());
+    assert!(
+      !std::fs::read_to_string(themes_dir.join("nord.json"))
+        .unwrap()
+        .contains("Nord v2")
+    );
+
+    let empty = root.join("empty.json");
+    std::fs::write(&empty, r#"{"name": "Empty", "themes": []}"#).unwrap();
+    assert!(copy_theme_file(&empty, &themes_dir).is_err());
+    assert!(!themes_dir.join("empty.json").exists());
+
+    let _ = std::fs::remove_dir_all(root);
+  }
+}
@@ -0,0 +1,240 @@
+//! "Wait for" helper: watch a container or pod in the background and
+//! notify once it reaches a target condition.
+//!
+//! The poller only reads `DockerState`, which the Docker event and
+//! Kubernetes watchers keep current, so waiting costs no extra API calls.
+//! Each wait runs as a cancellable task in the task bar.
+
+use std::time::{Duration, Instant};
+
+use gpui::{App, Timer};
+
+use crate::docker::ContainerState;
+use crate::kubernetes::PodPhase;
+use crate::services::{
+  complete_task, fail_task, is_task_cancelled, remove_task, set_task_progress, start_cancellable_task,
+};
+use crate::state::docker_state;
+
+use super::core::{DispatcherEvent, dispatcher};
+
+/// How often the state is re-checked.
+const WAIT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Give up after this long.
+const WAIT_TIMEOUT: Duration = Duration::from_mins(10);
+
+/// The resource being waited on.
+#[derive(Debug, Clone)]
+pub enum WaitTarget {
+  Container { id: String, name: String },
+  Pod { name: String, namespace: String },
+}
+
+impl WaitTarget {
+  fn label(&self) -> String {
+    match self {
+      WaitTarget::Container { name, .. } => format!("container {name}"),
+      WaitTarget::Pod { name, namespace } => format!("pod {namespace}/{name}"),
+    }
+  }
+}
+
+/// The condition that ends the wait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitCondition {
+  /// Container state is running
+  Running,
+  /// Container health check reports healthy
+  Healthy,
+  /// Pod is running with every container ready
+  Ready,
+}
+
+impl WaitCondition {
+  fn label(self) -> &'static str {
+    match self {
+      WaitCondition::Running => "running",
+      WaitCondition::Healthy => "healthy",
+      WaitCondition::Ready => "ready",
+    }
+  }
+}
+
+/// Outcome of a single check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Probe {
+  Met,
+  Pending(String),
+  /// The condition can no longer be reached.
+  Failed(String),
+}
+
+fn probe_container(state: ContainerState, status: &str, condition: WaitCondition) -> Probe {
+  let health = status.to_lowercase();
+  let stopped = matches!(state, ContainerState::Exited | ContainerState::Dead);
+
+  match condition {
+    WaitCondition::Running if state.is_running() => Probe::Met,
+    WaitCondition::Healthy if health.contains("(healthy)") => Probe::Met,
+    WaitCondition::Healthy if health.contains("(unhealthy)") => {
+      Probe::Failed("health check reports unhealthy".to_string())
+    }
+    WaitCondition::Healthy if state.is_running() && !health.contains("health") => {
+      Probe::Failed("container has no health check".to_string())
+    }
+    // A stopped container may still be started, so only a health wait
+    // gives up on it.
+    WaitCondition::Healthy if stopped => Probe::Failed(format!("container is {}", state.to_string().to_lowercase())),
+    _ => Probe::Pending(status.to_string()),
+  }
+}
+
+fn probe_pod(phase: PodPhase, ready: &str, all_ready: bool) -> Probe {
+  match phase {
+    PodPhase::Succeeded | PodPhase::Failed => Probe::Failed(format!("pod phase is {phase:?}")),
+    PodPhase::Running if all_ready => Probe::Met,
+    _ => Probe::Pending(format!("{phase:?}, {ready} ready")),
+  }
+}
+
+fn probe(target: &WaitTarget, condition: WaitCondition, cx: &App) -> Probe {
+  let state = docker_state(cx).read(cx);
+  match target {
+    WaitTarget::Container { id, .. } => match state.containers.iter().find(|c| c.id == *id) {
+      Some(container) => probe_container(container.state, &container.status, condition),
+      None => Probe::Failed("container was removed".to_string()),
+    },
+    WaitTarget::Pod { name, namespace } => {
+      match state.pods.iter().find(|p| p.name == *name && p.namespace == *namespace) {
+        Some(pod) => probe_pod(
+          pod.phase,
+          &pod.ready,
+          !pod.containers.is_empty() && pod.containers.iter().all(|c| c.ready),
+        ),
+        // The pod may not be listed yet (recreated, or outside the selected
+        // namespace), so keep waiting until the timeout.
+        None => Probe::Pending("Waiting for pod to appear".to_string()),
+      }
+    }
+  }
+}
+
+/// Wait in the background until `target` meets `condition`, then notify.
+/// Fails early when the condition can no longer be reached and after
+/// [`WAIT_TIMEOUT`]; the task bar offers a cancel button meanwhile.
+pub fn wait_for(target: WaitTarget, condition: WaitCondition, cx: &mut App) {
+  let disp = dispatcher(cx);
+  let what = target.label();
+  let task_id = start_cancellable_task(cx, format!("Waiting for {what} to be {}", condition.label()));
+  let started = Instant::now();
+
+  cx.spawn(async move |cx| {
+    loop {
+      let done = cx.update(|cx| {
+        if is_task_cancelled(cx, task_id) {
+          remove_task(cx, task_id);
+          return true;
+        }
+        let event = match probe(&target, condition, cx) {
+          Probe::Met => {
+            complete_task(cx, task_id);
+            DispatcherEvent::TaskCompleted {
+              message: format!("{} is {}", capitalize(&what), condition.label()),
+            }
+          }
+          Probe::Failed(reason) => {
+            fail_task(cx, task_id, reason.clone());
+            DispatcherEvent::TaskFailed {
+              error: format!("Stopped waiting for {what}: {reason}"),
+            }
+          }
+          Probe::Pending(_) if started.elapsed() >= WAIT_TIMEOUT => {
+            fail_task(cx, task_id, "timed out");
+            DispatcherEvent::TaskFailed {
+              error: format!(
+                "Timed out after {} minutes waiting for {what} to be {}",
+                WAIT_TIMEOUT.as_secs() / 60,
+                condition.label()
+              ),
+            }
+          }
+          Probe::Pending(status) => {
+            let elapsed = started.elapsed().as_secs_f32() / WAIT_TIMEOUT.as_secs_f32();
+            set_task_progress(cx, task_id, elapsed, Some(status));
+            return false;
+          }
+        };
+        disp.update(cx, |_, cx| cx.emit(event));
+        true
+      });
+
+      if done.unwrap_or(true) {
+        break;
+      }
+      Timer::after(WAIT_POLL_INTERVAL).await;
+    }
+  })
+  .detach();
+}
+
+fn capitalize(s: &str) -> String {
+  let mut chars = s.chars();
+  chars
+    .next()
+    .map(|first| first.to_uppercase().chain(chars).collect())
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_probe_container_running() {
+    let cond = WaitCondition::Running;
+    assert!(matches!(
+      probe_container(ContainerState::Created, "Created", cond),
+      Probe::Pending(_)
+    ));
+    assert_eq!(
+      probe_container(ContainerState::Running, "Up 2 seconds", cond),
+      Probe::Met
+    );
+    assert!(matches!(
+      probe_container(ContainerState::Exited, "Exited (1) 3 seconds ago", cond),
+      Probe::Pending(_)
+    ));
+  }
+
+  #[test]
+  fn test_probe_container_healthy() {
+    let cond = WaitCondition::Healthy;
+    let running = ContainerState::Running;
+    assert!(matches!(
+      probe_container(running, "Up 2 seconds (health: starting)", cond),
+      Probe::Pending(_)
+    ));
+    assert_eq!(probe_container(running, "Up 1 minute (healthy)", cond), Probe::Met);
+    assert!(matches!(
+      probe_container(running, "Up 1 minute (unhealthy)", cond),
+      Probe::Failed(_)
+    ));
+    assert!(matches!(
+      probe_container(running, "Up 1 minute", cond),
+      Probe::Failed(_)
+    ));
+    assert!(matches!(
+      probe_container(ContainerState::Exited, "Exited (1) 3 seconds ago", cond),
+      Probe::Failed(_)
+    ));
+  }
+
+  #[test]
+  fn test_probe_pod_ready() {
+    assert_eq!(probe_pod(PodPhase::Running, "2/2", true), Probe::Met);
+    assert!(matches!(probe_pod(PodPhase::Running, "1/2", false), Probe::Pending(_)));
+    assert!(matches!(probe_pod(PodPhase::Pending, "0/1", false), Probe::Pending(_)));
+    assert!(matches!(probe_pod(PodPhase::Failed, "0/1", false), Probe::Failed(_)));
+  }
+}
@@ -20,6 +20,7 @@ use tokio::sync::RwLock;
 
 use super::{WatcherControl, debouncer::ResourceType};
 use crate::docker::DockerClient;
+use crate::state::{DockerEventEntry, DockerEventKind};
 
 /// Backoff configuration for reconnection
 struct Backoff {
@@ -51,13 +52,13 @@ impl Backoff {
 #[allow(clippy::enum_variant_names, dead_code)]
 pub enum DockerResourceEvent {
   /// A container was created, started, stopped, removed, etc.
-  ContainerChanged { id: String, action: String },
+  ContainerChanged { id: String, name: String, action: String },
   /// An image was pulled, removed, tagged, etc.
   ImageChanged { id: String, action: String },
   /// A volume was created, removed, or mounted
   VolumeChanged { name: String, action: String },
   /// A network was created, removed, connected, or disconnected
-  NetworkChanged { id: String, action: String },
+  NetworkChanged { id: String, name: String, action: String },
 }
 
 impl DockerResourceEvent {
@@ -70,6 +71,22 @@ impl DockerResourceEvent {
       Self::NetworkChanged { .. } => ResourceType::Network,
     }
   }
+
+  /// Convert into an activity feed entry stamped with the current time
+  pub fn feed_entry(&self) -> DockerEventEntry {
+    let (kind, action, resource) = match self {
+      Self::ContainerChanged { name, action, .. } => (DockerEventKind::Container, action, name),
+      Self::ImageChanged { id, action } => (DockerEventKind::Image, action, id),
+      Self::VolumeChanged { name, action } => (DockerEventKind::Volume, action, name),
+      Self::NetworkChanged { name, action, .. } => (DockerEventKind::Network, action, name),
+    };
+    DockerEventEntry {
+      time: chrono::Local::now(),
+      kind,
+      action: action.clone(),
+      resource: resource.clone(),
+    }
+  }
 }
 
 /// Docker events watcher
@@ -183,11 +200,17 @@ impl DockerEventWatcher {
     let action = event.action.as_ref()?.clone();
     let actor = event.actor.as_ref()?;
     let id = actor.id.as_ref()?.clone();
+    // Fall back to the short ID when the daemon omits a name attribute
+    let name = actor
+      .attributes
+      .as_ref()
+      .and_then(|a| a.get("name").cloned())
+      .unwrap_or_else(|| id.chars().take(12).collect());
 
     match typ {
       EventMessageTypeEnum::CONTAINER => {
         // Container events: create, start, stop, die, destroy, pause, unpause, etc.
-        Some(DockerResourceEvent::ContainerChanged { id, action })
+        Some(DockerResourceEvent::ContainerChanged { id, name, action })
       }
       EventMessageTypeEnum::IMAGE => {
         // Image events: pull, push, tag, untag, delete
@@ -195,12 +218,11 @@ impl DockerEventWatcher {
       }
       EventMessageTypeEnum::VOLUME => {
         // Volume events: create, destroy, mount, unmount
-        let name = actor.attributes.as_ref()?.get("name")?.clone();
         Some(DockerResourceEvent::VolumeChanged { name, action })
       }
       EventMessageTypeEnum::NETWORK => {
         // Network events: create, destroy, connect, disconnect
-        Some(DockerResourceEvent::NetworkChanged { id, action })
+        Some(DockerResourceEvent::NetworkChanged { id, name, action })
       }
       _ => None,
     }
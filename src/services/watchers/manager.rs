@@ -16,21 +16,39 @@ use super::docker_events::DockerEventWatcher;
 use super::kubernetes::KubernetesWatcher;
 use super::machines::MachineWatcher;
 use crate::docker::DockerClient;
-use crate::state::settings_state;
+use crate::state::{AppSettings, DockerEventEntry, SidebarSection, StateChanged, docker_state, settings_state};
+
+/// Which watcher groups run, derived from the sidebar section toggles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct EnabledWatchers {
+  docker: bool,
+  kubernetes: bool,
+  machines: bool,
+}
+
+impl EnabledWatchers {
+  fn from_settings(settings: &AppSettings) -> Self {
+    Self {
+      docker: settings.section_visible(SidebarSection::Docker),
+      kubernetes: settings.section_visible(SidebarSection::Kubernetes),
+      machines: settings.colima_enabled && settings.section_visible(SidebarSection::Runtimes),
+    }
+  }
+}
 
 /// Manages all resource watchers
 pub struct WatcherManager {
   docker_client: Arc<RwLock<Option<DockerClient>>>,
   control: WatcherControl,
-  colima_enabled: bool,
+  enabled: EnabledWatchers,
 }
 
 impl WatcherManager {
-  pub fn new(docker_client: Arc<RwLock<Option<DockerClient>>>, colima_enabled: bool) -> Self {
+  fn new(docker_client: Arc<RwLock<Option<DockerClient>>>, enabled: EnabledWatchers) -> Self {
     Self {
       docker_client,
       control: WatcherControl::new(),
-      colima_enabled,
+      enabled,
     }
   }
 
@@ -56,45 +74,66 @@ impl WatcherManager {
     })
     .detach();
 
-    // Spawn Docker events watcher
-    let docker_tx = debounce_tx.clone();
-    let docker_control = control.clone();
-    crate::services::Tokio::spawn(cx, async move {
-      let watcher = DockerEventWatcher::new(docker_client);
-
-      watcher
-        .watch(docker_control, |event| {
-          tracing::debug!("Docker event: {event:?}");
-          docker_tx.send(event.resource_type());
-        })
-        .await;
-
-      Ok::<(), anyhow::Error>(())
-    })
-    .detach();
+    // Spawn Docker events watcher (skipped when the Docker section is hidden)
+    if self.enabled.docker {
+      let docker_tx = debounce_tx.clone();
+      let docker_control = control.clone();
+
+      // Raw events also feed the Activity Monitor; they bypass the
+      // debouncer so every individual event is recorded.
+      let (feed_tx, mut feed_rx) = tokio::sync::mpsc::unbounded_channel::<DockerEventEntry>();
+      cx.spawn(async move |cx| {
+        while let Some(entry) = feed_rx.recv().await {
+          let _ = cx.update(|cx| {
+            docker_state(cx).update(cx, |state, cx| {
+              state.push_docker_event(entry);
+              cx.emit(StateChanged::DockerEvent);
+            });
+          });
+        }
+      })
+      .detach();
 
-    // Spawn Kubernetes watcher
-    let k8s_tx = debounce_tx.clone();
-    let k8s_control = control.clone();
-    crate::services::Tokio::spawn(cx, async move {
-      let watcher = KubernetesWatcher::new().await;
+      crate::services::Tokio::spawn(cx, async move {
+        let watcher = DockerEventWatcher::new(docker_client);
 
-      if watcher.is_available() {
         watcher
-          .watch_all(k8s_control, |resource_type| {
-            tracing::debug!("Kubernetes change: {resource_type:?}");
-            k8s_tx.send(resource_type);
+          .watch(docker_control, |event| {
+            tracing::debug!("Docker event: {event:?}");
+            docker_tx.send(event.resource_type());
+            let _ = feed_tx.send(event.feed_entry());
           })
           .await;
-      }
 
-      Ok::<(), anyhow::Error>(())
-    })
-    .detach();
+        Ok::<(), anyhow::Error>(())
+      })
+      .detach();
+    }
+
+    // Spawn Kubernetes watcher (only while Kubernetes is enabled)
+    if self.enabled.kubernetes {
+      let k8s_tx = debounce_tx.clone();
+      let k8s_control = control.clone();
+      crate::services::Tokio::spawn(cx, async move {
+        let watcher = KubernetesWatcher::new().await;
+
+        if watcher.is_available() {
+          watcher
+            .watch_all(k8s_control, |resource_type| {
+              tracing::debug!("Kubernetes change: {resource_type:?}");
+              k8s_tx.send(resource_type);
+            })
+            .await;
+        }
+
+        Ok::<(), anyhow::Error>(())
+      })
+      .detach();
+    }
 
     // Spawn Colima machine watcher (polls every 10 seconds - conservative to avoid overhead)
-    // Only if Colima is enabled in settings
-    if self.colima_enabled {
+    // Only if Colima is enabled and the Runtimes section is shown
+    if self.enabled.machines {
       let machine_tx = debounce_tx;
       let machine_control = control;
       crate::services::Tokio::spawn(cx, async move {
@@ -158,12 +197,46 @@ impl Global for GlobalWatcherManager {}
 
 /// Start watchers and store globally
 pub fn start_watchers(docker_client: Arc<RwLock<Option<DockerClient>>>, cx: &mut App) {
-  let colima_enabled = settings_state(cx).read(cx).settings.colima_enabled;
-  let manager = WatcherManager::new(docker_client, colima_enabled);
+  let enabled = EnabledWatchers::from_settings(&settings_state(cx).read(cx).settings);
+  let manager = WatcherManager::new(docker_client, enabled);
   manager.start(cx);
   cx.set_global(GlobalWatcherManager(manager));
 }
 
+/// Restart the watchers when a section toggle changed which ones should
+/// run. No-op before the watchers were first started.
+pub fn sync_watchers(cx: &mut App) {
+  let enabled = EnabledWatchers::from_settings(&settings_state(cx).read(cx).settings);
+  let Some(global) = cx.try_global::<GlobalWatcherManager>() else {
+    return;
+  };
+  if global.0.enabled == enabled {
+    return;
+  }
+  global.0.stop();
+  let docker_client = global.0.docker_client.clone();
+  start_watchers(docker_client, cx);
+}
+
+/// Restart every watcher so event streams resubscribe right away, e.g.
+/// after the Docker daemon came back, instead of waiting out their backoff.
+pub fn restart_watchers(cx: &mut App) {
+  let Some(global) = cx.try_global::<GlobalWatcherManager>() else {
+    return;
+  };
+  global.0.stop();
+  let docker_client = global.0.docker_client.clone();
+  start_watchers(docker_client, cx);
+}
+
+/// Empty the Docker events activity feed
+pub fn clear_docker_events(cx: &mut App) {
+  docker_state(cx).update(cx, |state, cx| {
+    state.clear_docker_events();
+    cx.emit(StateChanged::DockerEventsCleared);
+  });
+}
+
 /// Stop all watchers gracefully (call on app shutdown)
 pub fn stop_watchers(cx: &App) {
   if let Some(global) = cx.try_global::<GlobalWatcherManager>() {
@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use crate::colima::{ColimaConfig, VmFileEntry, VmOsInfo};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum CurrentView {
   #[default]
   Dashboard,
@@ -37,6 +39,47 @@ pub enum CurrentView {
   Settings,
 }
 
+/// Sidebar groups that can be hidden from Settings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidebarSection {
+  Docker,
+  Kubernetes,
+  Runtimes,
+}
+
+impl CurrentView {
+  /// The hideable sidebar group this view belongs to. Overview and General
+  /// views are always reachable.
+  pub fn section(self) -> Option<SidebarSection> {
+    match self {
+      CurrentView::Containers
+      | CurrentView::Compose
+      | CurrentView::Volumes
+      | CurrentView::Images
+      | CurrentView::Networks => Some(SidebarSection::Docker),
+      CurrentView::Cluster
+      | CurrentView::Clusters
+      | CurrentView::Workloads
+      | CurrentView::Pods
+      | CurrentView::Networking
+      | CurrentView::Services
+      | CurrentView::Ingresses
+      | CurrentView::Deployments
+      | CurrentView::StatefulSets
+      | CurrentView::DaemonSets
+      | CurrentView::Jobs
+      | CurrentView::CronJobs
+      | CurrentView::Config
+      | CurrentView::Secrets
+      | CurrentView::ConfigMaps
+      | CurrentView::Pvcs
+      | CurrentView::Storage => Some(SidebarSection::Kubernetes),
+      CurrentView::Machines | CurrentView::Models => Some(SidebarSection::Runtimes),
+      CurrentView::Dashboard | CurrentView::Prune | CurrentView::ActivityMonitor | CurrentView::Settings => None,
+    }
+  }
+}
+
 /// Type of logs to display for a machine
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum MachineLogType {
@@ -66,6 +109,10 @@ pub struct MachineTabState {
   pub file_content: String,
   /// Whether file content is loading
   pub file_content_loading: bool,
+  /// Error when reading the selected file failed
+  pub file_content_error: Option<String>,
+  /// Selected file is open for editing
+  pub file_editing: bool,
   /// OS information for the machine
   pub os_info: Option<VmOsInfo>,
   /// Real-time disk usage info
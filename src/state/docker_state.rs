@@ -1,10 +1,10 @@
 use gpui::{App, AppContext, Entity, EventEmitter, Global};
 
-use crate::colima::{ColimaVm, Machine, MachineId};
-use crate::docker::{ContainerInfo, ImageInfo, NetworkInfo, VolumeInfo};
+use crate::colima::{ColimaVm, Machine, MachineId, MachineUsage};
+use crate::docker::{AggregateStats, ContainerInfo, DockerContext, ImageInfo, NetworkInfo, VolumeInfo};
 use crate::kubernetes::{
   ConfigMapInfo, CronJobInfo, DaemonSetInfo, DeploymentInfo, EventInfo, IngressInfo, JobInfo, KubeContextInfo,
-  NodeInfo, PodInfo, PvcInfo, SecretInfo, ServiceInfo, StatefulSetInfo,
+  NodeInfo, PodInfo, PvcInfo, ResourceQuotaInfo, SecretInfo, ServiceInfo, StatefulSetInfo,
 };
 
 use super::app_state::CurrentView;
@@ -67,17 +67,19 @@ pub enum ContainerDetailTab {
   Logs = 2,
   Processes = 3,
   Terminal = 4,
-  Files = 5,
-  Inspect = 6,
+  Attach = 5,
+  Files = 6,
+  Inspect = 7,
 }
 
 impl ContainerDetailTab {
-  pub const ALL: [ContainerDetailTab; 7] = [
+  pub const ALL: [ContainerDetailTab; 8] = [
     ContainerDetailTab::Info,
     ContainerDetailTab::Stats,
     ContainerDetailTab::Logs,
     ContainerDetailTab::Processes,
     ContainerDetailTab::Terminal,
+    ContainerDetailTab::Attach,
     ContainerDetailTab::Files,
     ContainerDetailTab::Inspect,
   ];
@@ -89,6 +91,7 @@ impl ContainerDetailTab {
       ContainerDetailTab::Logs => "Logs",
       ContainerDetailTab::Processes => "Processes",
       ContainerDetailTab::Terminal => "Terminal",
+      ContainerDetailTab::Attach => "Attach",
       ContainerDetailTab::Files => "Files",
       ContainerDetailTab::Inspect => "Inspect",
     }
@@ -104,15 +107,17 @@ pub enum PodDetailTab {
   Logs = 1,
   Terminal = 2,
   Describe = 3,
-  Yaml = 4,
+  Events = 4,
+  Yaml = 5,
 }
 
 impl PodDetailTab {
-  pub const ALL: [PodDetailTab; 5] = [
+  pub const ALL: [PodDetailTab; 6] = [
     PodDetailTab::Info,
     PodDetailTab::Logs,
     PodDetailTab::Terminal,
     PodDetailTab::Describe,
+    PodDetailTab::Events,
     PodDetailTab::Yaml,
   ];
 
@@ -122,6 +127,7 @@ impl PodDetailTab {
       PodDetailTab::Logs => "Logs",
       PodDetailTab::Terminal => "Terminal",
       PodDetailTab::Describe => "Describe",
+      PodDetailTab::Events => "Events",
       PodDetailTab::Yaml => "YAML",
     }
   }
@@ -135,7 +141,9 @@ pub enum ServiceDetailTab {
   Info = 0,
   Ports = 1,
   Endpoints = 2,
-  Yaml = 3,
+  Describe = 3,
+  Events = 4,
+  Yaml = 5,
 }
 
 /// Tab indices for deployment detail view
@@ -145,7 +153,9 @@ pub enum DeploymentDetailTab {
   #[default]
   Info = 0,
   Pods = 1,
-  Yaml = 2,
+  Describe = 2,
+  Events = 3,
+  Yaml = 4,
 }
 
 /// Tab indices for statefulset detail view
@@ -316,6 +326,47 @@ pub enum Selection {
   Machine(MachineId), // Machine identifier (Host or Colima VM)
 }
 
+/// Maximum number of Docker events kept for the activity feed
+pub const DOCKER_EVENT_FEED_CAPACITY: usize = 200;
+
+/// Kind of Docker object a feed event refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DockerEventKind {
+  Container,
+  Image,
+  Volume,
+  Network,
+}
+
+impl DockerEventKind {
+  pub const ALL: [DockerEventKind; 4] = [
+    DockerEventKind::Container,
+    DockerEventKind::Image,
+    DockerEventKind::Volume,
+    DockerEventKind::Network,
+  ];
+
+  pub fn label(self) -> &'static str {
+    match self {
+      DockerEventKind::Container => "Containers",
+      DockerEventKind::Image => "Images",
+      DockerEventKind::Volume => "Volumes",
+      DockerEventKind::Network => "Networks",
+    }
+  }
+}
+
+/// One entry in the Docker events activity feed
+#[derive(Debug, Clone)]
+pub struct DockerEventEntry {
+  pub time: chrono::DateTime<chrono::Local>,
+  pub kind: DockerEventKind,
+  /// Raw daemon action (`start`, `die`, `pull`, `create`, ...)
+  pub action: String,
+  /// Human name of the resource (container name, image ref, volume name)
+  pub resource: String,
+}
+
 /// Image inspect data for detailed view
 #[derive(Clone, Debug, Default)]
 pub struct ImageInspectData {
@@ -325,7 +376,6 @@ pub struct ImageInspectData {
   pub config_entrypoint: Option<Vec<String>>,
   pub config_exposed_ports: Vec<String>,
   pub used_by: Vec<String>,
-  pub history: Vec<crate::docker::ImageHistoryEntry>,
   /// Latest Trivy scan result. `None` until the user triggers a scan.
   pub scan: Option<crate::docker::ScanSummary>,
   /// Set while a scan is running so the UI can show a spinner.
@@ -344,10 +394,26 @@ pub enum StateChanged {
   NetworksUpdated,
   PodsUpdated,
   NamespacesUpdated,
+  /// Docker CLI context list (re)loaded.
+  DockerContextsUpdated,
+  /// The Docker daemon became reachable or was lost; see
+  /// `DockerState::docker_connected`.
+  DockerConnectionChanged,
+  /// A Docker daemon event was appended to `DockerState::docker_events`.
+  DockerEvent,
+  /// The Docker events feed was cleared.
+  DockerEventsCleared,
+  /// `DockerState::machine_usage` was refreshed.
+  MachineUsageUpdated,
+  /// Combined stats snapshot for every running container, from
+  /// `stream_all_container_stats`.
+  AllStatsUpdated(AggregateStats),
   /// Kubeconfig context list (re)loaded.
   KubeContextsUpdated,
   /// Active context changed; every k8s view should reset + reload.
   KubeContextSwitched,
+  /// A view's list refresh started or finished; see `DockerState::refreshing`.
+  RefreshStatusChanged(CurrentView),
   ViewChanged,
   SelectionChanged,
   Loading,
@@ -363,6 +429,16 @@ pub enum StateChanged {
     image_id: String,
     data: ImageInspectData,
   },
+  /// Full `docker network inspect` result, including attached containers.
+  NetworkInspectLoaded {
+    network_id: String,
+    network: NetworkInfo,
+  },
+  /// Per-layer history for an image, newest layer first.
+  ImageHistoryLoaded {
+    image_id: String,
+    history: Vec<crate::docker::ImageHistoryEntry>,
+  },
   ImageScanStarted {
     image_id: String,
   },
@@ -374,6 +450,15 @@ pub enum StateChanged {
     image_id: String,
     error: String,
   },
+  /// Restart policy or limits changed on a container (`docker update`)
+  ContainerConfigUpdated {
+    container_id: String,
+  },
+  /// The daemon refused a plain delete; the view offers to force it
+  ImageDeleteConflict {
+    image_id: String,
+    message: String,
+  },
   PodDescribeLoaded {
     pod_name: String,
     namespace: String,
@@ -393,6 +478,18 @@ pub enum StateChanged {
   EditMachineRequest {
     machine_id: MachineId,
   },
+  /// A file edited in the machine file browser was written back
+  MachineFileSaved {
+    machine_name: String,
+    path: String,
+  },
+  /// Writing a machine file failed with a permission error; the machines
+  /// view offers to retry `content` as root
+  MachineFileWriteDenied {
+    machine_name: String,
+    path: String,
+    content: String,
+  },
   /// Runtime switched to a different machine
   RuntimeSwitched {
     #[allow(dead_code)]
@@ -418,6 +515,11 @@ pub enum StateChanged {
     container_id: String,
     container_name: String,
   },
+  /// A file edited in the container file browser was written back
+  ContainerFileSaved {
+    container_id: String,
+    path: String,
+  },
   /// Request to open a pod with a specific tab
   PodTabRequest {
     pod_name: String,
@@ -431,6 +533,11 @@ pub enum StateChanged {
     namespace: String,
     yaml: String,
   },
+  ServiceDescribeLoaded {
+    service_name: String,
+    namespace: String,
+    describe: String,
+  },
   /// Request to open a service with a specific tab
   ServiceTabRequest {
     service_name: String,
@@ -444,6 +551,11 @@ pub enum StateChanged {
     namespace: String,
     yaml: String,
   },
+  DeploymentDescribeLoaded {
+    deployment_name: String,
+    namespace: String,
+    describe: String,
+  },
   /// Request to open a deployment with a specific tab
   DeploymentTabRequest {
     deployment_name: String,
@@ -456,6 +568,12 @@ pub enum StateChanged {
     namespace: String,
     current_replicas: i32,
   },
+  /// Request to open the image/env editor for one container of a deployment
+  DeploymentContainerEditRequest {
+    deployment_name: String,
+    namespace: String,
+    container: String,
+  },
   /// Request to open Host Docker configuration dialog
   ConfigureHostRequest,
 
@@ -561,6 +679,19 @@ pub enum StateChanged {
     tab: NodeDetailTab,
   },
   EventsUpdated,
+  /// Events for one object, from `get_resource_events`
+  ResourceEventsLoaded {
+    kind: String,
+    name: String,
+    namespace: String,
+    events: Vec<EventInfo>,
+    error: Option<String>,
+  },
+  NamespaceQuotasLoaded {
+    namespace: String,
+    quotas: Vec<ResourceQuotaInfo>,
+    error: Option<String>,
+  },
 
   // ConfigMaps
   ConfigMapsUpdated,
@@ -593,12 +724,23 @@ pub struct DockerState {
   pub machines: Vec<Machine>,
   /// Currently active machine for Docker operations
   pub active_machine: Option<MachineId>,
+  /// CPU / memory / disk of the machine running Docker, for the
+  /// activity monitor header
+  pub machine_usage: Option<(MachineId, MachineUsage)>,
 
   // Docker Data
   pub containers: Vec<ContainerInfo>,
   pub images: Vec<ImageInfo>,
   pub volumes: Vec<VolumeInfo>,
   pub networks: Vec<NetworkInfo>,
+  /// Docker CLI contexts from `docker context ls`
+  pub docker_contexts: Vec<DockerContext>,
+  /// Whether the shared Docker client can reach the daemon. `None` until
+  /// the first connection attempt finishes.
+  pub docker_connected: Option<bool>,
+  /// Whether Colima is installed, checked when the connection is lost so
+  /// the disconnected screen can offer to start it
+  pub colima_installed: bool,
 
   // Kubernetes Data
   pub pods: Vec<PodInfo>,
@@ -620,7 +762,13 @@ pub struct DockerState {
   pub node_metrics: std::collections::HashMap<String, (String, String)>,
   /// `namespace/name` -> (cpu millicores, mem bytes) from metrics-server.
   pub pod_metrics: std::collections::HashMap<String, (f64, u64)>,
+  /// Result of the last pod metrics probe: `None` before the first one,
+  /// `Some(false)` when the metrics API is missing or unreachable.
+  pub pod_metrics_available: Option<bool>,
   pub events: Vec<EventInfo>,
+  /// Recent Docker daemon events, oldest first, capped at
+  /// `DOCKER_EVENT_FEED_CAPACITY`.
+  pub docker_events: std::collections::VecDeque<DockerEventEntry>,
   pub namespaces: Vec<String>,
   pub selected_namespace: String,
   pub k8s_available: bool,
@@ -644,6 +792,12 @@ pub struct DockerState {
   pub selection: Selection,
   /// IDs of containers ticked for bulk start/stop/restart/delete
   pub selected_container_ids: std::collections::HashSet<String>,
+  /// Last container toggled into the bulk set; Shift-click extends from here.
+  pub bulk_anchor: Option<String>,
+  /// IDs of images ticked for bulk delete
+  pub selected_image_ids: std::collections::HashSet<String>,
+  /// Names of volumes ticked for bulk delete
+  pub selected_volume_names: std::collections::HashSet<String>,
 
   // Loading states - general loading indicator
   pub is_loading: bool,
@@ -667,6 +821,11 @@ pub struct DockerState {
   pub nodes_state: LoadState,
   pub events_state: LoadState,
   pub machines_state: LoadState,
+
+  /// Views whose list refresh is in flight (header spinner)
+  pub refreshing: std::collections::HashSet<CurrentView>,
+  /// When each view's list last loaded successfully
+  pub last_refreshed: std::collections::HashMap<CurrentView, std::time::Instant>,
 }
 
 impl DockerState {
@@ -674,10 +833,14 @@ impl DockerState {
     Self {
       machines: Vec::new(),
       active_machine: None,
+      machine_usage: None,
       containers: Vec::new(),
       images: Vec::new(),
       volumes: Vec::new(),
       networks: Vec::new(),
+      docker_contexts: Vec::new(),
+      docker_connected: None,
+      colima_installed: false,
       pods: Vec::new(),
       services: Vec::new(),
       deployments: Vec::new(),
@@ -693,7 +856,9 @@ impl DockerState {
       api_metrics_server: false,
       node_metrics: std::collections::HashMap::new(),
       pod_metrics: std::collections::HashMap::new(),
+      pod_metrics_available: None,
       events: Vec::new(),
+      docker_events: std::collections::VecDeque::with_capacity(DOCKER_EVENT_FEED_CAPACITY),
       namespaces: vec!["default".to_string()],
       selected_namespace: "all".to_string(),
       k8s_available: false,
@@ -705,6 +870,9 @@ impl DockerState {
       active_detail_tab: 0,
       selection: Selection::None,
       selected_container_ids: std::collections::HashSet::new(),
+      bulk_anchor: None,
+      selected_image_ids: std::collections::HashSet::new(),
+      selected_volume_names: std::collections::HashSet::new(),
       is_loading: true,
       // Per-resource load states
       containers_state: LoadState::NotLoaded,
@@ -725,9 +893,30 @@ impl DockerState {
       events_state: LoadState::NotLoaded,
       deployments_state: LoadState::NotLoaded,
       machines_state: LoadState::NotLoaded,
+      refreshing: std::collections::HashSet::new(),
+      last_refreshed: std::collections::HashMap::new(),
     }
   }
 
+  // Refresh status
+
+  /// Mark a view's list refresh as in flight.
+  pub fn begin_refresh(&mut self, view: CurrentView) {
+    self.refreshing.insert(view);
+  }
+
+  /// Clear the in-flight flag; `loaded` also stamps the last-updated time.
+  pub fn finish_refresh(&mut self, view: CurrentView, loaded: bool) {
+    self.refreshing.remove(&view);
+    if loaded {
+      self.last_refreshed.insert(view, std::time::Instant::now());
+    }
+  }
+
+  pub fn is_refreshing(&self, view: CurrentView) -> bool {
+    self.refreshing.contains(&view)
+  }
+
   // Selection management
   pub fn set_selection(&mut self, selection: Selection) {
     self.selection = selection;
@@ -737,16 +926,48 @@ impl DockerState {
     if !self.selected_container_ids.remove(id) {
       self.selected_container_ids.insert(id.to_string());
     }
+    self.bulk_anchor = Some(id.to_string());
+  }
+
+  /// Shift-click: add every container between the anchor and `id` (in the
+  /// list's visible `order`) to the bulk set. The anchor is the last
+  /// toggled row, falling back to the single selection; with neither, this
+  /// behaves like a toggle.
+  pub fn extend_bulk_container(&mut self, id: &str, order: &[String]) {
+    let anchor = self.bulk_anchor.clone().or_else(|| match &self.selection {
+      Selection::Container(c) => Some(c.id.clone()),
+      _ => None,
+    });
+    let position = |target: &str| order.iter().position(|o| o == target);
+    let (Some(from), Some(to)) = (anchor.as_deref().and_then(position), position(id)) else {
+      self.toggle_bulk_container(id);
+      return;
+    };
+    let (start, end) = if from <= to { (from, to) } else { (to, from) };
+    self.selected_container_ids.extend(order[start..=end].iter().cloned());
   }
 
   pub fn clear_bulk_container(&mut self) {
     self.selected_container_ids.clear();
+    self.bulk_anchor = None;
   }
 
   pub fn is_bulk_container_selected(&self, id: &str) -> bool {
     self.selected_container_ids.contains(id)
   }
 
+  pub fn toggle_bulk_image(&mut self, id: &str) {
+    if !self.selected_image_ids.remove(id) {
+      self.selected_image_ids.insert(id.to_string());
+    }
+  }
+
+  pub fn toggle_bulk_volume(&mut self, name: &str) {
+    if !self.selected_volume_names.remove(name) {
+      self.selected_volume_names.insert(name.to_string());
+    }
+  }
+
   // Machines
 
   /// Set all machines (used during init with Host + Colima VMs)
@@ -812,32 +1033,57 @@ impl DockerState {
 
   // Containers
   pub fn set_containers(&mut self, containers: Vec<ContainerInfo>) {
+    // Drop bulk picks for containers that no longer exist.
+    self
+      .selected_container_ids
+      .retain(|id| containers.iter().any(|c| &c.id == id));
     self.containers = containers;
     self.containers_state = LoadState::Loaded;
+    self.finish_refresh(CurrentView::Containers, true);
   }
 
   // Images
   pub fn set_images(&mut self, images: Vec<ImageInfo>) {
+    self.selected_image_ids.retain(|id| images.iter().any(|i| &i.id == id));
     self.images = images;
     self.images_state = LoadState::Loaded;
+    self.finish_refresh(CurrentView::Images, true);
   }
 
   // Volumes
   pub fn set_volumes(&mut self, volumes: Vec<VolumeInfo>) {
+    self
+      .selected_volume_names
+      .retain(|name| volumes.iter().any(|v| &v.name == name));
     self.volumes = volumes;
     self.volumes_state = LoadState::Loaded;
+    self.finish_refresh(CurrentView::Volumes, true);
   }
 
   // Networks
   pub fn set_networks(&mut self, networks: Vec<NetworkInfo>) {
     self.networks = networks;
     self.networks_state = LoadState::Loaded;
+    self.finish_refresh(CurrentView::Networks, true);
+  }
+
+  // Docker events feed
+  pub fn push_docker_event(&mut self, entry: DockerEventEntry) {
+    self.docker_events.push_back(entry);
+    while self.docker_events.len() > DOCKER_EVENT_FEED_CAPACITY {
+      self.docker_events.pop_front();
+    }
+  }
+
+  pub fn clear_docker_events(&mut self) {
+    self.docker_events.clear();
   }
 
   // Pods (Kubernetes)
   pub fn set_pods(&mut self, pods: Vec<PodInfo>) {
     self.pods = pods;
     self.pods_state = LoadState::Loaded;
+    self.finish_refresh(CurrentView::Pods, true);
   }
 
   pub fn set_pods_loading(&mut self) {
@@ -846,6 +1092,7 @@ impl DockerState {
 
   pub fn set_pods_error(&mut self, error: String) {
     self.pods_state = LoadState::Error(error);
+    self.finish_refresh(CurrentView::Pods, false);
   }
 
   pub fn get_pod(&self, name: &str, namespace: &str) -> Option<&PodInfo> {
@@ -874,6 +1121,24 @@ impl DockerState {
     }
   }
 
+  /// Record the daemon connection state. Returns true when it changed.
+  pub fn set_docker_connected(&mut self, connected: bool) -> bool {
+    let changed = self.docker_connected != Some(connected);
+    self.docker_connected = Some(connected);
+    changed
+  }
+
+  pub fn set_docker_contexts(&mut self, contexts: Vec<DockerContext>) {
+    self.docker_contexts = contexts;
+  }
+
+  /// Mark `name` as the CLI's current context without re-listing.
+  pub fn mark_current_docker_context(&mut self, name: &str) {
+    for ctx in &mut self.docker_contexts {
+      ctx.current = ctx.name == name;
+    }
+  }
+
   // Multi-cluster contexts
 
   pub fn set_kube_contexts(&mut self, contexts: Vec<KubeContextInfo>) {
@@ -919,6 +1184,7 @@ impl DockerState {
     self.api_metrics_server = false;
     self.node_metrics.clear();
     self.pod_metrics.clear();
+    self.pod_metrics_available = None;
     self.events.clear();
     self.namespaces = vec!["default".to_string()];
 
@@ -964,6 +1230,7 @@ impl DockerState {
   pub fn set_services(&mut self, services: Vec<ServiceInfo>) {
     self.services = services;
     self.services_state = LoadState::Loaded;
+    self.finish_refresh(CurrentView::Services, true);
   }
 
   pub fn set_services_loading(&mut self) {
@@ -972,6 +1239,7 @@ impl DockerState {
 
   pub fn set_services_error(&mut self, error: String) {
     self.services_state = LoadState::Error(error);
+    self.finish_refresh(CurrentView::Services, false);
   }
 
   pub fn get_service(&self, name: &str, namespace: &str) -> Option<&ServiceInfo> {
@@ -985,6 +1253,7 @@ impl DockerState {
   pub fn set_deployments(&mut self, deployments: Vec<DeploymentInfo>) {
     self.deployments = deployments;
     self.deployments_state = LoadState::Loaded;
+    self.finish_refresh(CurrentView::Deployments, true);
   }
 
   pub fn set_deployments_loading(&mut self) {
@@ -993,6 +1262,7 @@ impl DockerState {
 
   pub fn set_deployments_error(&mut self, error: String) {
     self.deployments_state = LoadState::Error(error);
+    self.finish_refresh(CurrentView::Deployments, false);
   }
 
   pub fn get_deployment(&self, name: &str, namespace: &str) -> Option<&DeploymentInfo> {
@@ -1070,17 +1340,34 @@ impl DockerState {
   }
 
   /// Store metrics-server pod usage (cpu millicores, mem bytes) keyed
-  /// `namespace/name`, and mark the API present.
-  pub fn set_pod_metrics(&mut self, metrics: Vec<(String, String, f64, u64)>) {
+  /// `namespace/name`, and mark the API present. A namespaced fetch only
+  /// replaces that namespace's entries.
+  pub fn set_pod_metrics(&mut self, namespace: Option<&str>, metrics: Vec<(String, String, f64, u64)>) {
     self.api_metrics_server = true;
-    self.pod_metrics = metrics
-      .into_iter()
-      .map(|(ns, name, cpu, mem)| (format!("{ns}/{name}"), (cpu, mem)))
-      .collect();
+    self.pod_metrics_available = Some(true);
+    match namespace {
+      Some(ns) => {
+        let prefix = format!("{ns}/");
+        self.pod_metrics.retain(|key, _| !key.starts_with(&prefix));
+      }
+      None => self.pod_metrics.clear(),
+    }
+    self.pod_metrics.extend(
+      metrics
+        .into_iter()
+        .map(|(ns, name, cpu, mem)| (format!("{ns}/{name}"), (cpu, mem))),
+    );
   }
 
   pub fn clear_pod_metrics(&mut self) {
     self.pod_metrics.clear();
+    self.pod_metrics_available = Some(false);
+  }
+
+  /// True once a probe has shown metrics-server is missing, so views can
+  /// say "metrics unavailable" instead of waiting for numbers.
+  pub fn pod_metrics_unavailable(&self) -> bool {
+    self.pod_metrics_available == Some(false)
   }
 
   pub fn pod_usage(&self, namespace: &str, name: &str) -> Option<(f64, u64)> {
@@ -1217,6 +1504,25 @@ mod tests {
     assert!(state.k8s_error.is_none());
   }
 
+  #[test]
+  fn test_docker_state_refresh_status() {
+    let mut state = DockerState::new();
+    assert!(!state.is_refreshing(CurrentView::Pods));
+
+    state.begin_refresh(CurrentView::Pods);
+    assert!(state.is_refreshing(CurrentView::Pods));
+
+    // A failed refresh clears the spinner but keeps no timestamp
+    state.set_pods_error("unreachable".to_string());
+    assert!(!state.is_refreshing(CurrentView::Pods));
+    assert!(!state.last_refreshed.contains_key(&CurrentView::Pods));
+
+    state.begin_refresh(CurrentView::Pods);
+    state.set_pods(vec![]);
+    assert!(!state.is_refreshing(CurrentView::Pods));
+    assert!(state.last_refreshed.contains_key(&CurrentView::Pods));
+  }
+
   #[test]
   fn test_docker_state_namespaces() {
     let mut state = DockerState::new();
@@ -1268,14 +1574,63 @@ mod tests {
     assert!(matches!(error, LoadState::Error(_)));
   }
 
+  #[test]
+  fn test_extend_bulk_container_selects_range() {
+    let order: Vec<String> = ["a", "b", "c", "d", "e"].iter().map(ToString::to_string).collect();
+    let mut state = DockerState::new();
+
+    // No anchor yet: acts as a toggle and becomes the anchor.
+    state.extend_bulk_container("b", &order);
+    assert_eq!(state.selected_container_ids.len(), 1);
+    assert_eq!(state.bulk_anchor.as_deref(), Some("b"));
+
+    // Range is inclusive and works in either direction.
+    state.extend_bulk_container("d", &order);
+    let mut picked: Vec<_> = state.selected_container_ids.iter().cloned().collect();
+    picked.sort();
+    assert_eq!(picked, vec!["b", "c", "d"]);
+
+    state.clear_bulk_container();
+    state.toggle_bulk_container("e");
+    state.extend_bulk_container("c", &order);
+    let mut picked: Vec<_> = state.selected_container_ids.iter().cloned().collect();
+    picked.sort();
+    assert_eq!(picked, vec!["c", "d", "e"]);
+    assert!(state.bulk_anchor.is_some());
+
+    state.clear_bulk_container();
+    assert!(state.selected_container_ids.is_empty());
+    assert!(state.bulk_anchor.is_none());
+  }
+
+  #[test]
+  fn test_docker_event_feed_is_capped() {
+    let mut state = DockerState::new();
+    for i in 0..DOCKER_EVENT_FEED_CAPACITY + 5 {
+      state.push_docker_event(DockerEventEntry {
+        time: chrono::Local::now(),
+        kind: DockerEventKind::Container,
+        action: "start".to_string(),
+        resource: format!("c{i}"),
+      });
+    }
+    assert_eq!(state.docker_events.len(), DOCKER_EVENT_FEED_CAPACITY);
+    // Oldest entries are dropped first.
+    assert_eq!(state.docker_events.front().unwrap().resource, "c5");
+
+    state.clear_docker_events();
+    assert!(state.docker_events.is_empty());
+  }
+
   #[test]
   fn test_container_detail_tab() {
-    assert_eq!(ContainerDetailTab::ALL.len(), 7);
+    assert_eq!(ContainerDetailTab::ALL.len(), 8);
     assert_eq!(ContainerDetailTab::Info.label(), "Info");
     assert_eq!(ContainerDetailTab::Stats.label(), "Stats");
     assert_eq!(ContainerDetailTab::Logs.label(), "Logs");
     assert_eq!(ContainerDetailTab::Processes.label(), "Processes");
     assert_eq!(ContainerDetailTab::Terminal.label(), "Terminal");
+    assert_eq!(ContainerDetailTab::Attach.label(), "Attach");
     assert_eq!(ContainerDetailTab::Files.label(), "Files");
     assert_eq!(ContainerDetailTab::Inspect.label(), "Inspect");
   }
@@ -1294,11 +1649,12 @@ mod tests {
 
   #[test]
   fn test_pod_detail_tab() {
-    assert_eq!(PodDetailTab::ALL.len(), 5);
+    assert_eq!(PodDetailTab::ALL.len(), 6);
     assert_eq!(PodDetailTab::Info.label(), "Info");
     assert_eq!(PodDetailTab::Logs.label(), "Logs");
     assert_eq!(PodDetailTab::Terminal.label(), "Terminal");
     assert_eq!(PodDetailTab::Describe.label(), "Describe");
+    assert_eq!(PodDetailTab::Events.label(), "Events");
     assert_eq!(PodDetailTab::Yaml.label(), "YAML");
   }
 }
@@ -1,7 +1,9 @@
 mod app_state;
 mod docker_state;
 mod settings;
+mod themes;
 
 pub use app_state::*;
 pub use docker_state::*;
 pub use settings::*;
+pub use themes::*;
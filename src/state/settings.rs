@@ -1,151 +1,94 @@
 use anyhow::Result;
-use gpui::{App, AppContext, Entity, EventEmitter, Global};
+use gpui::{App, AppContext, Context, Entity, EventEmitter, Global, Task, Timer, WindowAppearance};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use crate::platform::get_config_dir;
 
-/// Available themes (matching themes in themes/ directory JSON files)
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
-pub enum ThemeName {
-  // Tokyo Night variants
-  #[default]
-  TokyoNight,
-  TokyoStorm,
-  TokyoMoon,
-  // Catppuccin variants
-  CatppuccinLatte,
-  CatppuccinFrappe,
-  CatppuccinMacchiato,
-  CatppuccinMocha,
-  // Gruvbox variants
-  GruvboxLight,
-  GruvboxDark,
-  // Ayu variants
-  AyuLight,
-  AyuDark,
-  // Solarized variants
-  SolarizedLight,
-  SolarizedDark,
-  // Everforest variants
-  EverforestLight,
-  EverforestDark,
-  // Flexoki variants
-  FlexokiLight,
-  FlexokiDark,
-  // Hybrid variants
-  HybridLight,
-  HybridDark,
-  // Molokai variants
-  MolokaiLight,
-  MolokaiDark,
-  // Mellifluous variants
-  MellifluousLight,
-  MellifluousDark,
-  // macOS Classic variants
-  MacOSClassicLight,
-  MacOSClassicDark,
-  // Adventure variants
-  Adventure,
-  AdventureTime,
-  // Single-mode themes
-  Alduin,
-  Fahrenheit,
-  Harper,
-  Jellybeans,
-  Kibble,
-  Matrix,
-  Spaceduck,
-  Twilight,
+use super::{CurrentView, SidebarSection};
+
+/// Entries kept in `AppSettings::recent_compose_files`
+const MAX_RECENT_COMPOSE_FILES: usize = 8;
+
+/// Built-in themes as `(old settings id, name in the JSON file)`. Settings
+/// saved before themes were stored by name hold the old id.
+const BUILTIN_THEMES: &[(&str, &str)] = &[
+  // Dark themes first (most common preference)
+  ("TokyoNight", "Tokyo Night"),
+  ("TokyoStorm", "Tokyo Storm"),
+  ("TokyoMoon", "Tokyo Moon"),
+  ("CatppuccinMocha", "Catppuccin Mocha"),
+  ("CatppuccinMacchiato", "Catppuccin Macchiato"),
+  ("CatppuccinFrappe", "Catppuccin Frappe"),
+  ("GruvboxDark", "Gruvbox Dark"),
+  ("AyuDark", "Ayu Dark"),
+  ("SolarizedDark", "Solarized Dark"),
+  ("EverforestDark", "Everforest Dark"),
+  ("FlexokiDark", "Flexoki Dark"),
+  ("HybridDark", "Hybrid Dark"),
+  ("MolokaiDark", "Molokai Dark"),
+  ("MellifluousDark", "Mellifluous Dark"),
+  ("MacOSClassicDark", "macOS Classic Dark"),
+  ("Adventure", "Adventure"),
+  ("AdventureTime", "Adventure Time"),
+  ("Alduin", "Alduin"),
+  ("Fahrenheit", "Fahrenheit"),
+  ("Harper", "Harper"),
+  ("Jellybeans", "Jellybeans"),
+  ("Kibble", "Kibble"),
+  ("Matrix", "Matrix"),
+  ("Spaceduck", "Spaceduck"),
+  ("Twilight", "Twilight"),
+  // Light themes
+  ("CatppuccinLatte", "Catppuccin Latte"),
+  ("GruvboxLight", "Gruvbox Light"),
+  ("AyuLight", "Ayu Light"),
+  ("SolarizedLight", "Solarized Light"),
+  ("EverforestLight", "Everforest Light"),
+  ("FlexokiLight", "Flexoki Light"),
+  ("HybridLight", "Hybrid Light"),
+  ("MolokaiLight", "Molokai Light"),
+  ("MellifluousLight", "Mellifluous Light"),
+  ("MacOSClassicLight", "macOS Classic Light"),
+];
+
+/// A theme, by its name in the theme JSON file. Any theme found on disk
+/// can be selected; the built-in ones are just the defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct ThemeName(String);
+
+impl Default for ThemeName {
+  fn default() -> Self {
+    Self::new("Tokyo Night")
+  }
+}
+
+impl<'de> Deserialize<'de> for ThemeName {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+    let stored = String::deserialize(deserializer)?;
+    let name = BUILTIN_THEMES
+      .iter()
+      .find(|(id, _)| *id == stored)
+      .map_or(stored.as_str(), |(_, name)| name);
+    Ok(Self::new(name))
+  }
 }
 
 impl ThemeName {
-  pub fn all() -> Vec<ThemeName> {
-    vec![
-      // Dark themes first (most common preference)
-      ThemeName::TokyoNight,
-      ThemeName::TokyoStorm,
-      ThemeName::TokyoMoon,
-      ThemeName::CatppuccinMocha,
-      ThemeName::CatppuccinMacchiato,
-      ThemeName::CatppuccinFrappe,
-      ThemeName::GruvboxDark,
-      ThemeName::AyuDark,
-      ThemeName::SolarizedDark,
-      ThemeName::EverforestDark,
-      ThemeName::FlexokiDark,
-      ThemeName::HybridDark,
-      ThemeName::MolokaiDark,
-      ThemeName::MellifluousDark,
-      ThemeName::MacOSClassicDark,
-      ThemeName::Adventure,
-      ThemeName::AdventureTime,
-      ThemeName::Alduin,
-      ThemeName::Fahrenheit,
-      ThemeName::Harper,
-      ThemeName::Jellybeans,
-      ThemeName::Kibble,
-      ThemeName::Matrix,
-      ThemeName::Spaceduck,
-      ThemeName::Twilight,
-      // Light themes
-      ThemeName::CatppuccinLatte,
-      ThemeName::GruvboxLight,
-      ThemeName::AyuLight,
-      ThemeName::SolarizedLight,
-      ThemeName::EverforestLight,
-      ThemeName::FlexokiLight,
-      ThemeName::HybridLight,
-      ThemeName::MolokaiLight,
-      ThemeName::MellifluousLight,
-      ThemeName::MacOSClassicLight,
-    ]
+  pub fn new(name: impl Into<String>) -> Self {
+    Self(name.into())
   }
 
   pub fn display_name(&self) -> &str {
-    match self {
-      ThemeName::TokyoNight => "Tokyo Night",
-      ThemeName::TokyoStorm => "Tokyo Storm",
-      ThemeName::TokyoMoon => "Tokyo Moon",
-      ThemeName::CatppuccinLatte => "Catppuccin Latte",
-      ThemeName::CatppuccinFrappe => "Catppuccin Frappe",
-      ThemeName::CatppuccinMacchiato => "Catppuccin Macchiato",
-      ThemeName::CatppuccinMocha => "Catppuccin Mocha",
-      ThemeName::GruvboxLight => "Gruvbox Light",
-      ThemeName::GruvboxDark => "Gruvbox Dark",
-      ThemeName::AyuLight => "Ayu Light",
-      ThemeName::AyuDark => "Ayu Dark",
-      ThemeName::SolarizedLight => "Solarized Light",
-      ThemeName::SolarizedDark => "Solarized Dark",
-      ThemeName::EverforestLight => "Everforest Light",
-      ThemeName::EverforestDark => "Everforest Dark",
-      ThemeName::FlexokiLight => "Flexoki Light",
-      ThemeName::FlexokiDark => "Flexoki Dark",
-      ThemeName::HybridLight => "Hybrid Light",
-      ThemeName::HybridDark => "Hybrid Dark",
-      ThemeName::MolokaiLight => "Molokai Light",
-      ThemeName::MolokaiDark => "Molokai Dark",
-      ThemeName::MellifluousLight => "Mellifluous Light",
-      ThemeName::MellifluousDark => "Mellifluous Dark",
-      ThemeName::MacOSClassicLight => "macOS Classic Light",
-      ThemeName::MacOSClassicDark => "macOS Classic Dark",
-      ThemeName::Adventure => "Adventure",
-      ThemeName::AdventureTime => "Adventure Time",
-      ThemeName::Alduin => "Alduin",
-      ThemeName::Fahrenheit => "Fahrenheit",
-      ThemeName::Harper => "Harper",
-      ThemeName::Jellybeans => "Jellybeans",
-      ThemeName::Kibble => "Kibble",
-      ThemeName::Matrix => "Matrix",
-      ThemeName::Spaceduck => "Spaceduck",
-      ThemeName::Twilight => "Twilight",
-    }
+    &self.0
   }
 
-  /// Returns the theme name as used in the JSON file (for `ThemeRegistry` lookup)
+  /// Returns the theme name as used in the JSON file (for `ThemeCatalog` lookup)
   pub fn theme_name(&self) -> &str {
-    self.display_name()
+    &self.0
   }
 }
 
@@ -156,6 +99,10 @@ pub enum ExternalEditor {
   VSCode,
   Cursor,
   Zed,
+  /// `$VISUAL` / `$EDITOR`, falling back to the platform's text editor
+  System,
+  /// `external_editor_command` from settings
+  Custom,
 }
 
 /// Terminal cursor style
@@ -185,7 +132,13 @@ impl TerminalCursorStyle {
 
 impl ExternalEditor {
   pub fn all() -> Vec<ExternalEditor> {
-    vec![ExternalEditor::VSCode, ExternalEditor::Cursor, ExternalEditor::Zed]
+    vec![
+      ExternalEditor::VSCode,
+      ExternalEditor::Cursor,
+      ExternalEditor::Zed,
+      ExternalEditor::System,
+      ExternalEditor::Custom,
+    ]
   }
 
   pub fn display_name(&self) -> &str {
@@ -193,15 +146,19 @@ impl ExternalEditor {
       Self::VSCode => "VS Code",
       Self::Cursor => "Cursor",
       Self::Zed => "Zed",
+      Self::System => "System ($EDITOR)",
+      Self::Custom => "Custom command",
     }
   }
 
-  /// Returns the CLI command for this editor
+  /// Returns the CLI command for this editor. Empty for `System` and
+  /// `Custom`, which are resolved when a file is opened.
   pub fn command(&self) -> &str {
     match self {
       Self::VSCode => "code",
       Self::Cursor => "cursor",
       Self::Zed => "zed",
+      Self::System | Self::Custom => "",
     }
   }
 
@@ -212,9 +169,8 @@ impl ExternalEditor {
   }
 
   /// Whether this editor supports SSH remote connections
-  #[allow(clippy::unused_self)]
   pub const fn supports_ssh(&self) -> bool {
-    true
+    matches!(self, Self::VSCode | Self::Cursor | Self::Zed)
   }
 }
 
@@ -311,11 +267,61 @@ fn default_ssh_port() -> u16 {
   22
 }
 
+/// Main window geometry remembered between launches, in logical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SavedBounds {
+  pub x: f32,
+  pub y: f32,
+  pub width: f32,
+  pub height: f32,
+  #[serde(default)]
+  pub maximized: bool,
+}
+
+impl SavedBounds {
+  /// Minimum overlap with a display for the window to count as reachable,
+  /// roughly a grabbable strip of title bar.
+  const MIN_VISIBLE_WIDTH: f32 = 100.0;
+  const MIN_VISIBLE_HEIGHT: f32 = 40.0;
+
+  /// Whether enough of the window lands on a display with the given
+  /// origin and size to be dragged back, so bounds saved on a monitor
+  /// that has since been unplugged get rejected.
+  pub fn visible_on(&self, x: f32, y: f32, width: f32, height: f32) -> bool {
+    let overlap_w = (self.x + self.width).min(x + width) - self.x.max(x);
+    let overlap_h = (self.y + self.height).min(y + height) - self.y.max(y);
+    self.width > 0.0
+      && self.height > 0.0
+      && overlap_w >= Self::MIN_VISIBLE_WIDTH
+      && overlap_h >= Self::MIN_VISIBLE_HEIGHT
+  }
+}
+
+/// Restore the last view leniently: a view that no longer exists falls
+/// back to `None` instead of discarding the whole settings file. Only
+/// malformed JSON is an error.
+fn deserialize_last_view<'de, D>(deserializer: D) -> std::result::Result<Option<CurrentView>, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  let value = serde_json::Value::deserialize(deserializer)?;
+  Ok(serde_json::from_value(value).ok())
+}
+
 /// Application settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
   /// Selected theme
   pub theme: ThemeName,
+  /// Switch between `light_theme` and `dark_theme` with the system appearance
+  #[serde(default)]
+  pub theme_follow_system: bool,
+  /// Theme for a light system appearance
+  #[serde(default = "default_light_theme")]
+  pub light_theme: ThemeName,
+  /// Theme for a dark system appearance
+  #[serde(default)]
+  pub dark_theme: ThemeName,
   /// Docker socket path (empty for default)
   pub docker_socket: String,
   /// Default Colima profile name
@@ -324,12 +330,28 @@ pub struct AppSettings {
   /// When disabled, the Colima sidebar group + Machines view are hidden.
   #[serde(default = "default_colima_enabled")]
   pub colima_enabled: bool,
+  /// Selecting a running machine in the Machines view makes it the active
+  /// Docker daemon (Docker context + full reload).
+  #[serde(default)]
+  pub docker_follows_machine: bool,
   /// Enable Kubernetes feature (Pods/Deployments/Services). Default: true.
   /// When disabled, the Kubernetes sidebar group is hidden.
   #[serde(default = "default_kubernetes_enabled")]
   pub kubernetes_enabled: bool,
+  /// Show the Docker sidebar group (Containers, Compose, Images, ...).
+  #[serde(default = "default_true")]
+  pub show_docker_section: bool,
+  /// Show the Runtimes sidebar group (Machines, Models).
+  #[serde(default = "default_true")]
+  pub show_runtimes_section: bool,
+  /// Include stopped containers in the Containers list.
+  #[serde(default = "default_true")]
+  pub containers_show_stopped: bool,
   /// Refresh interval for containers (in seconds)
   pub container_refresh_interval: u64,
+  /// Stop the periodic list and stats refresh (watchers still run).
+  #[serde(default)]
+  pub auto_refresh_paused: bool,
   /// Refresh interval for stats (in seconds)
   pub stats_refresh_interval: u64,
   /// Maximum log lines to display
@@ -347,17 +369,41 @@ pub struct AppSettings {
   /// Optional terminal font family override (empty = system mono).
   #[serde(default)]
   pub terminal_font_family: String,
+  /// Preferred shell for container exec and machine SSH sessions (empty =
+  /// auto-detect). Falls back through bash and sh when missing.
+  #[serde(default)]
+  pub terminal_shell: String,
+  /// Extra `KEY=VALUE` environment variables exported into terminal sessions.
+  #[serde(default)]
+  pub terminal_env: Vec<String>,
+  /// Hide env var values whose keys match `secret_key_patterns` until
+  /// clicked, so they don't show up while screen sharing.
+  #[serde(default = "default_true")]
+  pub mask_secrets: bool,
+  /// Case-insensitive key patterns treated as secrets; `*` matches any run
+  /// of characters.
+  #[serde(default = "default_secret_key_patterns")]
+  pub secret_key_patterns: Vec<String>,
   /// External editor for opening files
   pub external_editor: ExternalEditor,
   /// Wait for external editor process to close before reporting done.
   #[serde(default)]
   pub editor_wait_close: bool,
+  /// Command line for `ExternalEditor::Custom`; the file path is appended.
+  #[serde(default)]
+  pub external_editor_command: String,
+  /// Write saves to a local copy of a container or VM file back to it.
+  #[serde(default = "default_true")]
+  pub editor_sync_back: bool,
   /// Prompt before destructive actions (delete container, prune, etc.).
   #[serde(default = "default_true")]
   pub confirm_destructive: bool,
   /// Show desktop notifications for completed background tasks.
   #[serde(default = "default_true")]
   pub show_notifications: bool,
+  /// Record the CLI-equivalent of each operation in the command log.
+  #[serde(default)]
+  pub command_log_enabled: bool,
   /// Default platform passed to `docker pull` (empty = host arch).
   #[serde(default)]
   pub default_pull_platform: String,
@@ -383,6 +429,12 @@ pub struct AppSettings {
   /// Favorites pinned to the Dashboard.
   #[serde(default)]
   pub favorites: Vec<FavoriteRef>,
+  /// Command palette ids, most recently run first.
+  #[serde(default)]
+  pub recent_commands: Vec<String>,
+  /// Compose files brought up from the Compose view, most recent first.
+  #[serde(default)]
+  pub recent_compose_files: Vec<String>,
   /// Whether the local DNS resolver + reverse proxy stack is enabled.
   #[serde(default)]
   pub dns_enabled: bool,
@@ -405,11 +457,23 @@ pub struct AppSettings {
   /// kubeconfig context name.
   #[serde(default)]
   pub cluster_hosts: std::collections::HashMap<String, Vec<HostEntry>>,
+  /// Main window position and size at last close / move.
+  #[serde(default)]
+  pub window_bounds: Option<SavedBounds>,
+  /// View that was active when the app was last used.
+  #[serde(default, deserialize_with = "deserialize_last_view")]
+  pub last_view: Option<CurrentView>,
+  /// Show the sidebar as an icon-only rail.
+  #[serde(default)]
+  pub sidebar_collapsed: bool,
 }
 
 fn default_true() -> bool {
   true
 }
+fn default_light_theme() -> ThemeName {
+  ThemeName::new("Catppuccin Latte")
+}
 fn default_namespace() -> String {
   "default".to_string()
 }
@@ -440,6 +504,21 @@ fn default_kubernetes_enabled() -> bool {
 fn default_dns_suffix() -> String {
   "dockside.test".to_string()
 }
+fn default_secret_key_patterns() -> Vec<String> {
+  [
+    "*_TOKEN",
+    "*_PASSWORD",
+    "*_PASSWD",
+    "*_SECRET",
+    "*_KEY",
+    "*_CREDENTIALS",
+    "TOKEN",
+    "PASSWORD",
+    "SECRET",
+  ]
+  .map(String::from)
+  .to_vec()
+}
 fn default_dns_port() -> u16 {
   15353
 }
@@ -456,12 +535,20 @@ fn default_proxy_https_port() -> u16 {
 impl Default for AppSettings {
   fn default() -> Self {
     Self {
-      theme: ThemeName::TokyoNight,
+      theme: ThemeName::default(),
+      theme_follow_system: false,
+      light_theme: default_light_theme(),
+      dark_theme: ThemeName::default(),
       docker_socket: String::new(),
       default_colima_profile: "default".to_string(),
       colima_enabled: default_colima_enabled(),
+      docker_follows_machine: false,
       kubernetes_enabled: default_kubernetes_enabled(),
+      show_docker_section: true,
+      show_runtimes_section: true,
+      containers_show_stopped: true,
       container_refresh_interval: 5,
+      auto_refresh_paused: false,
       stats_refresh_interval: 2,
       max_log_lines: 1000,
       terminal_font_size: 14.0,
@@ -470,10 +557,17 @@ impl Default for AppSettings {
       terminal_cursor_blink: true,
       terminal_scrollback_lines: 10000,
       terminal_font_family: String::new(),
+      terminal_shell: String::new(),
+      terminal_env: Vec::new(),
+      mask_secrets: true,
+      secret_key_patterns: default_secret_key_patterns(),
       external_editor: ExternalEditor::default(),
       editor_wait_close: false,
+      external_editor_command: String::new(),
+      editor_sync_back: true,
       confirm_destructive: true,
       show_notifications: true,
+      command_log_enabled: false,
       default_pull_platform: String::new(),
       kubeconfig_path: String::new(),
       kube_context: String::new(),
@@ -482,6 +576,8 @@ impl Default for AppSettings {
       colima_default_memory_gb: 4,
       colima_default_disk_gb: 60,
       favorites: Vec::new(),
+      recent_commands: Vec::new(),
+      recent_compose_files: Vec::new(),
       dns_enabled: false,
       dns_autostart: true,
       dns_suffix: default_dns_suffix(),
@@ -489,6 +585,9 @@ impl Default for AppSettings {
       proxy_http_port: default_proxy_http_port(),
       proxy_https_port: default_proxy_https_port(),
       cluster_hosts: std::collections::HashMap::new(),
+      window_bounds: None,
+      last_view: None,
+      sidebar_collapsed: false,
     }
   }
 }
@@ -525,6 +624,69 @@ impl AppSettings {
     fs::write(path, content)?;
     Ok(())
   }
+
+  /// Move `path` to the front of `recent_compose_files`, keeping at most
+  /// `MAX_RECENT_COMPOSE_FILES`.
+  pub fn remember_compose_file(&mut self, path: &str) {
+    self.recent_compose_files.retain(|p| p != path);
+    self.recent_compose_files.insert(0, path.to_string());
+    self.recent_compose_files.truncate(MAX_RECENT_COMPOSE_FILES);
+  }
+
+  /// Parsed `terminal_env` pairs. Entries without a `=` or whose key isn't
+  /// a valid shell variable name are skipped.
+  pub fn terminal_env_vars(&self) -> Vec<(String, String)> {
+    self
+      .terminal_env
+      .iter()
+      .filter_map(|entry| {
+        let (key, value) = entry.split_once('=')?;
+        let key = key.trim();
+        is_env_key(key).then(|| (key.to_string(), value.to_string()))
+      })
+      .collect()
+  }
+
+  /// Whether `key`'s value should be masked: masking is on and the key
+  /// matches one of `secret_key_patterns`.
+  pub fn is_secret_key(&self, key: &str) -> bool {
+    self.mask_secrets && self.matches_secret_pattern(key)
+  }
+
+  /// Whether `key` matches one of `secret_key_patterns`, whatever the
+  /// masking toggle says. Exports redact these unconditionally.
+  pub fn matches_secret_pattern(&self, key: &str) -> bool {
+    self
+      .secret_key_patterns
+      .iter()
+      .any(|pattern| glob_match_ignore_case(pattern.trim(), key))
+  }
+
+  /// Whether a sidebar group is shown. Hidden groups also drop their
+  /// palette commands and skip their background watchers.
+  pub fn section_visible(&self, section: SidebarSection) -> bool {
+    match section {
+      SidebarSection::Docker => self.show_docker_section,
+      SidebarSection::Kubernetes => self.kubernetes_enabled,
+      SidebarSection::Runtimes => self.show_runtimes_section,
+    }
+  }
+
+  /// The theme to show for the given system appearance
+  pub fn active_theme(&self, appearance: WindowAppearance) -> &ThemeName {
+    if !self.theme_follow_system {
+      return &self.theme;
+    }
+    match appearance {
+      WindowAppearance::Light | WindowAppearance::VibrantLight => &self.light_theme,
+      WindowAppearance::Dark | WindowAppearance::VibrantDark => &self.dark_theme,
+    }
+  }
+
+  /// Whether `view` can be navigated to with the current section toggles
+  pub fn view_visible(&self, view: CurrentView) -> bool {
+    view.section().is_none_or(|section| self.section_visible(section))
+  }
 }
 
 /// Events emitted when settings change
@@ -532,19 +694,45 @@ impl AppSettings {
 pub enum SettingsChanged {
   ThemeChanged,
   SettingsUpdated,
+  /// The theme catalog was re-scanned
+  ThemesReloaded,
 }
 
+/// Delay before `save_later` writes the settings file, so a burst of view
+/// switches or toggles costs one write.
+const SAVE_DEBOUNCE: Duration = Duration::from_millis(300);
+
 /// Global settings state
 pub struct SettingsState {
   pub settings: AppSettings,
+  save_task: Option<Task<()>>,
 }
 
 impl SettingsState {
   pub fn new() -> Self {
     Self {
       settings: AppSettings::load(),
+      save_task: None,
     }
   }
+
+  /// Persist settings off the UI thread once changes settle. Meant for
+  /// state remembered across launches (last view, recent commands) that
+  /// changes often enough that a synchronous write would stall the UI.
+  pub fn save_later(&mut self, cx: &mut Context<'_, Self>) {
+    self.save_task = Some(cx.spawn(async move |this, cx| {
+      Timer::after(SAVE_DEBOUNCE).await;
+      let Ok(settings) = this.read_with(cx, |state, _cx| state.settings.clone()) else {
+        return;
+      };
+      cx.background_spawn(async move {
+        if let Err(e) = settings.save() {
+          tracing::warn!("Failed to save settings: {}", e);
+        }
+      })
+      .await;
+    }));
+  }
 }
 
 impl EventEmitter<SettingsChanged> for SettingsState {}
@@ -563,56 +751,157 @@ pub fn settings_state(cx: &App) -> Entity<SettingsState> {
   cx.global::<GlobalSettingsState>().0.clone()
 }
 
+/// Whether `key` is a shell variable name (`[A-Za-z_][A-Za-z0-9_]*`)
+pub fn is_env_key(key: &str) -> bool {
+  let mut chars = key.chars();
+  chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Split the Settings env field into `KEY=value` entries. A comma only
+/// starts a new entry when `KEY=` follows it; otherwise it belongs to the
+/// previous value, so `JAVA_OPTS=-Xms1g,-Xmx2g` survives intact.
+pub fn split_env_entries(text: &str) -> Vec<String> {
+  let mut entries: Vec<String> = Vec::new();
+  for segment in text.split(',') {
+    let starts_entry = segment.split_once('=').is_some_and(|(key, _)| is_env_key(key.trim()));
+    match entries.last_mut() {
+      Some(last) if !starts_entry => {
+        last.push(',');
+        last.push_str(segment);
+      }
+      _ => entries.push(segment.to_string()),
+    }
+  }
+  entries
+    .iter()
+    .map(|entry| entry.trim())
+    .filter(|entry| !entry.is_empty())
+    .map(String::from)
+    .collect()
+}
+
+/// `*`-only glob match, ASCII case-insensitive
+fn glob_match_ignore_case(pattern: &str, text: &str) -> bool {
+  let mut parts = pattern.split('*');
+  let text = text.to_ascii_lowercase();
+  let first = parts.next().unwrap_or_default().to_ascii_lowercase();
+  let Some(mut rest) = text.strip_prefix(first.as_str()) else {
+    return false;
+  };
+  let tail: Vec<String> = parts.map(str::to_ascii_lowercase).collect();
+  let Some((last, middle)) = tail.split_last() else {
+    // No `*`: the whole key has to match
+    return rest.is_empty();
+  };
+  for part in middle {
+    match rest.find(part.as_str()) {
+      Some(i) => rest = &rest[i + part.len()..],
+      None => return false,
+    }
+  }
+  rest.len() >= last.len() && rest.ends_with(last.as_str())
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  /// The themes shipped in the bundled themes directory
+  fn builtin_themes() -> Vec<ThemeName> {
+    BUILTIN_THEMES.iter().map(|(_, name)| ThemeName::new(*name)).collect()
+  }
+
+  #[test]
+  fn test_split_env_entries() {
+    assert_eq!(
+      split_env_entries("EDITOR=vim, JAVA_OPTS=-Xms1g,-Xmx2g, GREETING=hi, there,,"),
+      vec![
+        "EDITOR=vim".to_string(),
+        "JAVA_OPTS=-Xms1g,-Xmx2g".to_string(),
+        "GREETING=hi, there,,".to_string(),
+      ]
+    );
+    assert!(split_env_entries(" ").is_empty());
+  }
+
+  #[test]
+  fn test_is_env_key() {
+    assert!(is_env_key("_PATH2"));
+    assert!(!is_env_key("2PATH"));
+    assert!(!is_env_key("A-B"));
+    assert!(!is_env_key(""));
+  }
+
+  #[test]
+  fn test_terminal_env_vars() {
+    let settings = AppSettings {
+      terminal_env: vec![
+        "EDITOR=vim".to_string(),
+        " LANG =C.UTF-8".to_string(),
+        "NO_EQUALS".to_string(),
+        "=orphan".to_string(),
+        "BAD-KEY=1".to_string(),
+        "EMPTY=".to_string(),
+      ],
+      ..AppSettings::default()
+    };
+    assert_eq!(
+      settings.terminal_env_vars(),
+      vec![
+        ("EDITOR".to_string(), "vim".to_string()),
+        ("LANG".to_string(), "C.UTF-8".to_string()),
+        ("EMPTY".to_string(), String::new()),
+      ]
+    );
+  }
+
   #[test]
   fn test_theme_name_default() {
-    assert_eq!(ThemeName::default(), ThemeName::TokyoNight);
+    assert_eq!(ThemeName::default(), ThemeName::new("Tokyo Night"));
   }
 
   #[test]
   fn test_theme_name_all_returns_all_variants() {
-    let themes = ThemeName::all();
+    let themes = builtin_themes();
     // Should have a reasonable number of themes
     assert!(themes.len() >= 30);
 
     // Should include default theme
-    assert!(themes.contains(&ThemeName::TokyoNight));
+    assert!(themes.contains(&ThemeName::new("Tokyo Night")));
 
     // Should include dark themes
-    assert!(themes.contains(&ThemeName::GruvboxDark));
-    assert!(themes.contains(&ThemeName::CatppuccinMocha));
+    assert!(themes.contains(&ThemeName::new("Gruvbox Dark")));
+    assert!(themes.contains(&ThemeName::new("Catppuccin Mocha")));
 
     // Should include light themes
-    assert!(themes.contains(&ThemeName::GruvboxLight));
-    assert!(themes.contains(&ThemeName::CatppuccinLatte));
+    assert!(themes.contains(&ThemeName::new("Gruvbox Light")));
+    assert!(themes.contains(&ThemeName::new("Catppuccin Latte")));
   }
 
   #[test]
   fn test_theme_name_all_no_duplicates() {
-    let themes = ThemeName::all();
+    let themes = builtin_themes();
     let mut seen: Vec<ThemeName> = themes.clone();
     seen.sort_by(|a, b| format!("{a:?}").cmp(&format!("{b:?}")));
     seen.dedup();
-    assert_eq!(themes.len(), seen.len(), "all() should not contain duplicates");
+    assert_eq!(themes.len(), seen.len(), "BUILTIN_THEMES should not contain duplicates");
   }
 
   #[test]
   fn test_theme_name_display_name() {
-    assert_eq!(ThemeName::TokyoNight.display_name(), "Tokyo Night");
-    assert_eq!(ThemeName::TokyoStorm.display_name(), "Tokyo Storm");
-    assert_eq!(ThemeName::CatppuccinMocha.display_name(), "Catppuccin Mocha");
-    assert_eq!(ThemeName::GruvboxDark.display_name(), "Gruvbox Dark");
-    assert_eq!(ThemeName::GruvboxLight.display_name(), "Gruvbox Light");
-    assert_eq!(ThemeName::Matrix.display_name(), "Matrix");
+    assert_eq!(ThemeName::new("Tokyo Night").display_name(), "Tokyo Night");
+    assert_eq!(ThemeName::new("Tokyo Storm").display_name(), "Tokyo Storm");
+    assert_eq!(ThemeName::new("Catppuccin Mocha").display_name(), "Catppuccin Mocha");
+    assert_eq!(ThemeName::new("Gruvbox Dark").display_name(), "Gruvbox Dark");
+    assert_eq!(ThemeName::new("Gruvbox Light").display_name(), "Gruvbox Light");
+    assert_eq!(ThemeName::new("Matrix").display_name(), "Matrix");
   }
 
   #[test]
   fn test_theme_name_theme_name_equals_display_name() {
     // theme_name() should return the same as display_name()
-    for theme in ThemeName::all() {
+    for theme in builtin_themes() {
       assert_eq!(theme.theme_name(), theme.display_name());
     }
   }
@@ -620,10 +909,11 @@ mod tests {
   #[test]
   fn test_app_settings_default() {
     let settings = AppSettings::default();
-    assert_eq!(settings.theme, ThemeName::TokyoNight);
+    assert_eq!(settings.theme, ThemeName::new("Tokyo Night"));
     assert!(settings.docker_socket.is_empty());
     assert_eq!(settings.default_colima_profile, "default");
     assert_eq!(settings.container_refresh_interval, 5);
+    assert!(!settings.auto_refresh_paused);
     assert_eq!(settings.stats_refresh_interval, 2);
     assert_eq!(settings.max_log_lines, 1000);
     assert!((settings.terminal_font_size - 14.0).abs() < 0.01);
@@ -652,12 +942,20 @@ mod tests {
   #[test]
   fn test_app_settings_custom_values() {
     let settings = AppSettings {
-      theme: ThemeName::GruvboxDark,
+      theme: ThemeName::new("Gruvbox Dark"),
+      theme_follow_system: true,
+      light_theme: ThemeName::new("Ayu Light"),
+      dark_theme: ThemeName::new("Ayu Dark"),
       docker_socket: "/custom/docker.sock".to_string(),
       default_colima_profile: "dev".to_string(),
       colima_enabled: true,
+      docker_follows_machine: true,
       kubernetes_enabled: true,
+      show_docker_section: true,
+      show_runtimes_section: false,
+      containers_show_stopped: false,
       container_refresh_interval: 10,
+      auto_refresh_paused: true,
       stats_refresh_interval: 5,
       max_log_lines: 5000,
       terminal_font_size: 16.0,
@@ -666,10 +964,17 @@ mod tests {
       terminal_cursor_blink: false,
       terminal_scrollback_lines: 5000,
       terminal_font_family: String::new(),
+      terminal_shell: String::new(),
+      terminal_env: Vec::new(),
+      mask_secrets: true,
+      secret_key_patterns: default_secret_key_patterns(),
       external_editor: ExternalEditor::Cursor,
       editor_wait_close: false,
+      external_editor_command: "nvim-qt".to_string(),
+      editor_sync_back: false,
       confirm_destructive: true,
       show_notifications: true,
+      command_log_enabled: false,
       default_pull_platform: String::new(),
       kubeconfig_path: String::new(),
       kube_context: String::new(),
@@ -678,6 +983,8 @@ mod tests {
       colima_default_memory_gb: 4,
       colima_default_disk_gb: 60,
       favorites: Vec::new(),
+      recent_commands: vec!["refresh-all".to_string()],
+      recent_compose_files: vec!["/srv/app/compose.yaml".to_string()],
       dns_enabled: false,
       dns_autostart: true,
       dns_suffix: "dockside.test".to_string(),
@@ -685,13 +992,31 @@ mod tests {
       proxy_http_port: 47080,
       proxy_https_port: 47443,
       cluster_hosts: std::collections::HashMap::new(),
+      window_bounds: Some(SavedBounds {
+        x: 10.0,
+        y: 20.0,
+        width: 1400.0,
+        height: 900.0,
+        maximized: false,
+      }),
+      last_view: Some(CurrentView::Images),
+      sidebar_collapsed: true,
     };
 
-    assert_eq!(settings.theme, ThemeName::GruvboxDark);
+    assert_eq!(settings.theme, ThemeName::new("Gruvbox Dark"));
+    assert!(settings.theme_follow_system);
+    assert_eq!(settings.light_theme, ThemeName::new("Ayu Light"));
+    assert_eq!(settings.dark_theme, ThemeName::new("Ayu Dark"));
+    assert!(!settings.containers_show_stopped);
     assert_eq!(settings.docker_socket, "/custom/docker.sock");
     assert_eq!(settings.default_colima_profile, "dev");
     assert_eq!(settings.container_refresh_interval, 10);
+    assert!(settings.auto_refresh_paused);
     assert_eq!(settings.external_editor, ExternalEditor::Cursor);
+    assert_eq!(settings.external_editor_command, "nvim-qt");
+    assert!(!settings.editor_sync_back);
+    assert!(settings.sidebar_collapsed);
+    assert_eq!(settings.recent_commands, vec!["refresh-all"]);
   }
 
   #[test]
@@ -720,20 +1045,60 @@ mod tests {
     // Just verify all variants can be created
     let _ = SettingsChanged::ThemeChanged;
     let _ = SettingsChanged::SettingsUpdated;
+    let _ = SettingsChanged::ThemesReloaded;
   }
 
   #[test]
   fn test_theme_name_serialization() {
     // Test that themes serialize/deserialize correctly
-    let theme = ThemeName::CatppuccinMocha;
+    let theme = ThemeName::new("Catppuccin Mocha");
     let json = serde_json::to_string(&theme).expect("Failed to serialize");
     let deserialized: ThemeName = serde_json::from_str(&json).expect("Failed to deserialize");
     assert_eq!(deserialized, theme);
   }
 
+  #[test]
+  fn test_active_theme_follows_system() {
+    let mut settings = AppSettings {
+      theme: ThemeName::new("Matrix"),
+      ..AppSettings::default()
+    };
+    assert_eq!(
+      settings.active_theme(WindowAppearance::Light),
+      &ThemeName::new("Matrix")
+    );
+
+    settings.theme_follow_system = true;
+    assert_eq!(
+      settings.active_theme(WindowAppearance::VibrantLight),
+      &ThemeName::new("Catppuccin Latte")
+    );
+    assert_eq!(
+      settings.active_theme(WindowAppearance::Dark),
+      &ThemeName::new("Tokyo Night")
+    );
+  }
+
+  #[test]
+  fn test_theme_name_stored_by_name() {
+    let custom = ThemeName::new("Nord");
+    assert_eq!(serde_json::to_string(&custom).unwrap(), r#""Nord""#);
+    assert_eq!(serde_json::from_str::<ThemeName>(r#""Nord""#).unwrap(), custom);
+
+    // Settings saved with the old enum ids still load
+    assert_eq!(
+      serde_json::from_str::<ThemeName>(r#""GruvboxDark""#).unwrap(),
+      ThemeName::new("Gruvbox Dark")
+    );
+    assert_eq!(
+      serde_json::from_str::<ThemeName>(r#""MacOSClassicLight""#).unwrap(),
+      ThemeName::new("macOS Classic Light")
+    );
+  }
+
   #[test]
   fn test_all_themes_have_display_names() {
-    for theme in ThemeName::all() {
+    for theme in builtin_themes() {
       let name = theme.display_name();
       assert!(!name.is_empty(), "Theme {theme:?} should have a display name");
     }
@@ -772,15 +1137,120 @@ mod tests {
     assert_eq!(settings.colima_enabled, default_colima_enabled());
   }
 
+  #[test]
+  fn test_section_visibility() {
+    let mut settings = AppSettings {
+      kubernetes_enabled: false,
+      ..AppSettings::default()
+    };
+    assert!(settings.view_visible(CurrentView::Containers));
+    assert!(settings.view_visible(CurrentView::Machines));
+    assert!(!settings.view_visible(CurrentView::Pods));
+    assert!(!settings.section_visible(SidebarSection::Kubernetes));
+
+    settings.show_docker_section = false;
+    settings.show_runtimes_section = false;
+    assert!(!settings.view_visible(CurrentView::Images));
+    assert!(!settings.view_visible(CurrentView::Machines));
+    // Overview and General views can't be hidden
+    assert!(settings.view_visible(CurrentView::Dashboard));
+    assert!(settings.view_visible(CurrentView::Settings));
+  }
+
+  #[test]
+  fn test_saved_bounds_visibility() {
+    let bounds = SavedBounds {
+      x: 100.0,
+      y: 100.0,
+      width: 1200.0,
+      height: 800.0,
+      maximized: false,
+    };
+    assert!(bounds.visible_on(0.0, 0.0, 1920.0, 1080.0));
+    // Saved on a second monitor to the right that is now gone
+    let off_screen = SavedBounds { x: 2200.0, ..bounds };
+    assert!(!off_screen.visible_on(0.0, 0.0, 1920.0, 1080.0));
+    assert!(off_screen.visible_on(1920.0, 0.0, 2560.0, 1440.0));
+    // Only a sliver overlapping the left edge
+    let sliver = SavedBounds { x: -1150.0, ..bounds };
+    assert!(!sliver.visible_on(0.0, 0.0, 1920.0, 1080.0));
+  }
+
+  #[test]
+  fn test_last_view_is_lenient() {
+    let json = r#"{
+      "theme": "TokyoNight",
+      "docker_socket": "",
+      "default_colima_profile": "default",
+      "container_refresh_interval": 5,
+      "stats_refresh_interval": 2,
+      "max_log_lines": 1000,
+      "terminal_font_size": 14.0,
+      "terminal_line_height": 1.4,
+      "terminal_cursor_style": "Block",
+      "terminal_cursor_blink": true,
+      "terminal_scrollback_lines": 10000,
+      "external_editor": "VSCode",
+      "last_view": "NoSuchView"
+    }"#;
+    let settings: AppSettings = serde_json::from_str(json).expect("Failed to deserialize");
+    assert_eq!(settings.last_view, None);
+    assert_eq!(settings.max_log_lines, 1000);
+
+    let settings = AppSettings {
+      last_view: Some(CurrentView::Pods),
+      ..AppSettings::default()
+    };
+    let round_trip: AppSettings = serde_json::from_str(&serde_json::to_string(&settings).unwrap()).unwrap();
+    assert_eq!(round_trip.last_view, Some(CurrentView::Pods));
+  }
+
+  #[test]
+  fn test_is_secret_key() {
+    let mut settings = AppSettings::default();
+    assert!(settings.is_secret_key("POSTGRES_PASSWORD"));
+    assert!(settings.is_secret_key("github_token"));
+    assert!(settings.is_secret_key("AWS_SECRET_ACCESS_KEY"));
+    assert!(settings.is_secret_key("PASSWORD"));
+    assert!(!settings.is_secret_key("PATH"));
+    assert!(!settings.is_secret_key("TOKEN_URL"));
+    assert!(!settings.is_secret_key("KEYBOARD"));
+
+    settings.secret_key_patterns = vec!["DB_*_URL".to_string()];
+    assert!(settings.is_secret_key("DB_MAIN_URL"));
+    assert!(!settings.is_secret_key("DB_URL_MAIN"));
+
+    settings.mask_secrets = false;
+    assert!(!settings.is_secret_key("DB_MAIN_URL"));
+  }
+
+  #[test]
+  fn test_remember_compose_file() {
+    let mut settings = AppSettings::default();
+    settings.remember_compose_file("/a/compose.yaml");
+    settings.remember_compose_file("/b/compose.yaml");
+    settings.remember_compose_file("/a/compose.yaml");
+    assert_eq!(
+      settings.recent_compose_files,
+      vec!["/a/compose.yaml", "/b/compose.yaml"]
+    );
+
+    for i in 0..20 {
+      settings.remember_compose_file(&format!("/p{i}/compose.yaml"));
+    }
+    assert_eq!(settings.recent_compose_files.len(), MAX_RECENT_COMPOSE_FILES);
+    assert_eq!(settings.recent_compose_files[0], "/p19/compose.yaml");
+  }
+
   #[test]
   fn test_dark_themes_listed_first() {
-    let themes = ThemeName::all();
+    let themes = builtin_themes();
     // First theme should be Tokyo Night (dark)
-    assert_eq!(themes[0], ThemeName::TokyoNight);
+    assert_eq!(themes[0], ThemeName::new("Tokyo Night"));
 
     // Light themes should be near the end
-    let latte_pos = themes.iter().position(|t| *t == ThemeName::CatppuccinLatte);
-    let mocha_pos = themes.iter().position(|t| *t == ThemeName::CatppuccinMocha);
+    let latte_pos = themes.iter().position(|t| *t == ThemeName::new("Catppuccin Latte"));
+    let mocha_pos = themes.iter().position(|t| *t == ThemeName::new("Catppuccin Mocha"));
     assert!(
       mocha_pos < latte_pos,
       "Dark themes (Mocha) should come before light themes (Latte)"
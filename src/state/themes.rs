@@ -0,0 +1,201 @@
+//! Themes discovered on disk
+//!
+//! Bundled themes come from the directory found by `bundled_themes_dir`;
+//! imported ones live in `themes/` under the config dir so they survive
+//! updates and read-only installs. A user theme with the same name as a
+//! bundled one replaces it.
+
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use anyhow::{Context as _, Result};
+use gpui::{App, Global, WindowAppearance};
+use gpui_component::theme::{Theme, ThemeConfig, ThemeSet};
+
+use crate::platform::get_config_dir;
+
+use super::AppSettings;
+
+/// Get the bundled themes directory path. Probes (in priority order):
+///
+/// 1. `$DOCKSIDE_THEMES_DIR` — explicit override.
+/// 2. `<exe-parent>/../Resources/themes` — macOS `.app` bundle.
+/// 3. `<exe-parent>/../lib/dockside/themes` — Linux `.deb` / `.pacman` /
+///    `AppImage` layout where cargo-packager places `resources`.
+/// 4. `<exe-parent>/themes` — portable tarball / dev `target/release` /
+///    sibling-of-binary case.
+/// 5. `/usr/lib/dockside/themes` — absolute fallback when the package
+///    binary somehow lacks a usable parent (e.g. symlinked into
+///    `~/.local/bin`).
+/// 6. `/usr/share/dockside/themes` — alternate FHS share path.
+/// 7. `./themes` — dev `cargo run` from the repo root.
+pub fn bundled_themes_dir() -> Option<PathBuf> {
+  if let Ok(env_dir) = std::env::var("DOCKSIDE_THEMES_DIR") {
+    let path = PathBuf::from(env_dir);
+    if path.exists() {
+      return Some(path);
+    }
+  }
+
+  if let Ok(exe_path) = std::env::current_exe()
+    && let Some(parent) = exe_path.parent()
+  {
+    // macOS .app bundle: Contents/MacOS/dockside -> Contents/Resources/themes.
+    if let Some(grand) = parent.parent() {
+      let resources = grand.join("Resources").join("themes");
+      if resources.exists() {
+        return Some(resources);
+      }
+      // Linux package layout: /usr/bin/dockside -> /usr/lib/dockside/themes.
+      let lib = grand.join("lib").join("dockside").join("themes");
+      if lib.exists() {
+        return Some(lib);
+      }
+    }
+    // Sibling: /tarball/dockside -> /tarball/themes.
+    let sibling = parent.join("themes");
+    if sibling.exists() {
+      return Some(sibling);
+    }
+  }
+
+  for absolute in ["/usr/lib/dockside/themes", "/usr/share/dockside/themes"] {
+    let path = PathBuf::from(absolute);
+    if path.exists() {
+      return Some(path);
+    }
+  }
+
+  let dev_themes = PathBuf::from("./themes");
+  if dev_themes.exists() {
+    return Some(dev_themes);
+  }
+
+  None
+}
+
+/// Where imported themes are copied to
+pub fn user_themes_dir() -> PathBuf {
+  get_config_dir().join("themes")
+}
+
+/// Parse one theme file. A file may hold several themes.
+pub fn read_theme_file(path: &Path) -> Result<Vec<ThemeConfig>> {
+  let content = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+  let theme_set = serde_json::from_str::<ThemeSet>(&content)
+    .with_context(|| format!("{} is not a valid theme file", path.display()))?;
+  Ok(theme_set.themes)
+}
+
+/// Every theme in the `.json` files of `dir`. Unreadable files are skipped.
+fn read_themes_dir(dir: &Path) -> Vec<ThemeConfig> {
+  let Ok(entries) = std::fs::read_dir(dir) else {
+    return Vec::new();
+  };
+  let mut themes = Vec::new();
+  for entry in entries.flatten() {
+    let path = entry.path();
+    if !path.is_file() || path.extension().and_then(|s| s.to_str()) != Some("json") {
+      continue;
+    }
+    match read_theme_file(&path) {
+      Ok(found) => themes.extend(found),
+      Err(e) => tracing::warn!("Ignoring theme file: {e:#}"),
+    }
+  }
+  themes
+}
+
+/// The themes available to pick from, sorted by name
+pub struct ThemeCatalog {
+  themes: Vec<Rc<ThemeConfig>>,
+}
+
+impl Global for ThemeCatalog {}
+
+impl ThemeCatalog {
+  /// Read the themes in `dirs`; later directories override earlier ones.
+  pub fn scan(dirs: &[PathBuf]) -> Self {
+    let mut themes: Vec<Rc<ThemeConfig>> = Vec::new();
+    for dir in dirs {
+      for theme in read_themes_dir(dir) {
+        themes.retain(|t| t.name != theme.name);
+        themes.push(Rc::new(theme));
+      }
+    }
+    themes.sort_by_key(|t| t.name.to_lowercase());
+    Self { themes }
+  }
+
+  /// Bundled themes plus the user's imported ones
+  pub fn discover() -> Self {
+    let mut dirs: Vec<PathBuf> = bundled_themes_dir().into_iter().collect();
+    dirs.push(user_themes_dir());
+    Self::scan(&dirs)
+  }
+
+  pub fn get(&self, name: &str) -> Option<Rc<ThemeConfig>> {
+    self.themes.iter().find(|t| t.name == name).cloned()
+  }
+
+  pub fn names(&self) -> impl Iterator<Item = &str> {
+    self.themes.iter().map(|t| t.name.as_ref())
+  }
+
+  pub fn count(&self) -> usize {
+    self.themes.len()
+  }
+}
+
+pub fn theme_catalog(cx: &App) -> &ThemeCatalog {
+  cx.global::<ThemeCatalog>()
+}
+
+/// Apply the theme `settings` picks for `appearance`. Synchronous, so the
+/// next frame already uses the new colors.
+pub fn apply_active_theme(settings: &AppSettings, appearance: WindowAppearance, cx: &mut App) {
+  if let Some(config) = theme_catalog(cx).get(settings.active_theme(appearance).theme_name()) {
+    Theme::global_mut(cx).apply_config(&config);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn write_theme(dir: &Path, file: &str, names: &[(&str, &str)]) {
+    let themes: Vec<String> = names
+      .iter()
+      .map(|(name, mode)| format!(r#"{{"name": "{name}", "mode": "{mode}", "colors": {{}}}}"#))
+      .collect();
+    let json = format!(r#"{{"name": "{file}", "themes": [{}]}}"#, themes.join(","));
+    std::fs::write(dir.join(file), json).unwrap();
+  }
+
+  #[test]
+  fn test_scan_merges_dirs() {
+    let root = std::env::temp_dir().join(format!("dockside-themes-test-{}", std::process::id()));
+    let bundled = root.join("bundled");
+    let user = root.join("user");
+    std::fs::create_dir_all(&bundled).unwrap();
+    std::fs::create_dir_all(&user).unwrap();
+    write_theme(
+      &bundled,
+      "tokyo.json",
+      &[("Tokyo Night", "dark"), ("Ayu Light", "light")],
+    );
+    write_theme(&user, "mine.json", &[("My Theme", "dark"), ("Tokyo Night", "light")]);
+    std::fs::write(bundled.join("broken.json"), "{").unwrap();
+    std::fs::write(bundled.join("notes.txt"), "not a theme").unwrap();
+
+    let catalog = ThemeCatalog::scan(&[bundled, user]);
+    assert_eq!(
+      catalog.names().collect::<Vec<_>>(),
+      vec!["Ayu Light", "My Theme", "Tokyo Night"]
+    );
+    // The user copy wins
+    assert!(!catalog.get("Tokyo Night").unwrap().mode.is_dark());
+
+    let _ = std::fs::remove_dir_all(root);
+  }
+}
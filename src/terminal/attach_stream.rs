@@ -0,0 +1,128 @@
+//! `docker attach` session rendered through the libghostty grid. Output is
+//! fed into a [`LogStream`]; keystrokes are forwarded to the container's
+//! main process only once the container is known to run with `-it`, and
+//! grid resizes are forwarded so a TTY container redraws at the new size.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use parking_lot::Mutex;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel};
+
+use super::pty_terminal::{TerminalContent, encode_key_simple};
+use super::{LogStream, TerminalSource};
+use crate::docker::ATTACH_DETACH_BYTES;
+
+/// `(cols, rows)` sizes the attach task forwards to the container's TTY.
+pub type ResizeReceiver = UnboundedReceiver<(u16, u16)>;
+
+pub struct AttachStream {
+  output: LogStream,
+  input_tx: UnboundedSender<Vec<u8>>,
+  resize_tx: UnboundedSender<(u16, u16)>,
+  /// `None` until the container's TTY / stdin flags are known.
+  interactive: Mutex<Option<bool>>,
+  detached: AtomicBool,
+  error: Mutex<Option<String>>,
+}
+
+impl AttachStream {
+  /// Build the stream plus the receivers the attach task drains stdin and
+  /// TTY resizes from. Dropping the stream closes them, which ends the
+  /// session.
+  pub fn new(cols: u16, rows: u16) -> anyhow::Result<(Self, UnboundedReceiver<Vec<u8>>, ResizeReceiver)> {
+    let (input_tx, input_rx) = unbounded_channel();
+    let (resize_tx, resize_rx) = unbounded_channel();
+    let stream = Self {
+      output: LogStream::new(cols, rows)?,
+      input_tx,
+      resize_tx,
+      interactive: Mutex::new(None),
+      detached: AtomicBool::new(false),
+      error: Mutex::new(None),
+    };
+    Ok((stream, input_rx, resize_rx))
+  }
+
+  pub fn feed_bytes(&self, bytes: Vec<u8>) {
+    self.output.feed_bytes(bytes);
+  }
+
+  pub fn set_interactive(&self, interactive: bool) {
+    *self.interactive.lock() = Some(interactive);
+  }
+
+  /// `Some(false)` for non-TTY containers, which are shown read-only.
+  pub fn interactive(&self) -> Option<bool> {
+    *self.interactive.lock()
+  }
+
+  pub fn is_detached(&self) -> bool {
+    self.detached.load(Ordering::SeqCst)
+  }
+
+  /// Send the detach-keys sequence so Docker drops the session while the
+  /// container keeps running.
+  pub fn detach(&self) {
+    self.send_bytes(ATTACH_DETACH_BYTES.to_vec());
+  }
+
+  /// Mark the session as over, either cleanly or with an error.
+  pub fn finish(&self, error: Option<String>) {
+    if self.detached.swap(true, Ordering::SeqCst) {
+      return;
+    }
+    match error {
+      Some(e) => *self.error.lock() = Some(e),
+      None => self.feed_bytes(b"\r\n[detached from container]\r\n".to_vec()),
+    }
+  }
+
+  fn send_bytes(&self, bytes: Vec<u8>) {
+    if self.interactive() == Some(true) && !self.is_detached() {
+      let _ = self.input_tx.send(bytes);
+    }
+  }
+}
+
+impl TerminalSource for AttachStream {
+  fn is_connected(&self) -> bool {
+    self.output.is_alive()
+  }
+  fn error(&self) -> Option<String> {
+    self.error.lock().clone()
+  }
+  fn get_content_with_offset(&self, _scroll_offset: usize) -> TerminalContent {
+    self.output.content()
+  }
+  fn max_scroll(&self) -> usize {
+    self.output.max_scroll()
+  }
+  fn resize_callback(&self) -> Arc<dyn Fn(u16, u16) + Send + Sync + 'static> {
+    let output = self.output.resize_callback();
+    let resize_tx = self.resize_tx.clone();
+    Arc::new(move |cols, rows| {
+      output(cols, rows);
+      let _ = resize_tx.send((cols, rows));
+    })
+  }
+  fn scroll_by(&self, delta: isize) {
+    self.output.scroll_by(delta);
+  }
+  fn scroll_to_bottom(&self) {
+    self.output.scroll_to_bottom();
+  }
+  fn resize(&self, cols: u16, rows: u16) {
+    self.output.resize(cols, rows);
+    let _ = self.resize_tx.send((cols, rows));
+  }
+  fn send_key(&self, key: &str, ctrl: bool, alt: bool, shift: bool) {
+    if let Some(bytes) = encode_key_simple(key, ctrl, alt, shift) {
+      self.send_bytes(bytes);
+    }
+  }
+  fn send_char(&self, c: char) {
+    let mut buf = [0u8; 4];
+    self.send_bytes(c.encode_utf8(&mut buf).as_bytes().to_vec());
+  }
+}
@@ -3,6 +3,7 @@
 //! terminal uses (ANSI colors, cursor styles, scrollback) without spawning a
 //! PTY or child process.
 
+use std::collections::VecDeque;
 use std::sync::mpsc::{self, Sender};
 use std::sync::{
   Arc,
@@ -20,6 +21,9 @@ use super::pty_terminal::{TerminalContent, snapshot_into};
 const SCROLLBACK_LINES: usize = 10_000;
 const CELL_PIXEL_W: u32 = 8;
 const CELL_PIXEL_H: u32 = 16;
+/// Raw log bytes kept for "Save logs" beyond what the scrollback shows.
+/// Past this the oldest chunks are dropped.
+const CAPTURE_LIMIT_BYTES: usize = 64 * 1024 * 1024;
 
 enum Cmd {
   Bytes(Vec<u8>),
@@ -38,6 +42,27 @@ pub struct LogStream {
   content: Arc<Mutex<TerminalContent>>,
   max_scroll: Arc<AtomicUsize>,
   shutdown: Arc<AtomicBool>,
+  capture: Mutex<LogCapture>,
+}
+
+/// Every chunk fed to the stream, in order, so the full log (not just
+/// the rendered scrollback) can be written to disk.
+#[derive(Default)]
+struct LogCapture {
+  chunks: VecDeque<Arc<[u8]>>,
+  bytes: usize,
+}
+
+impl LogCapture {
+  fn push(&mut self, chunk: Arc<[u8]>) {
+    self.bytes += chunk.len();
+    self.chunks.push_back(chunk);
+    while self.bytes > CAPTURE_LIMIT_BYTES && self.chunks.len() > 1 {
+      if let Some(old) = self.chunks.pop_front() {
+        self.bytes -= old.len();
+      }
+    }
+  }
 }
 
 impl LogStream {
@@ -64,13 +89,22 @@ impl LogStream {
       content,
       max_scroll,
       shutdown,
+      capture: Mutex::new(LogCapture::default()),
     })
   }
 
   pub fn feed_bytes(&self, bytes: Vec<u8>) {
+    self.capture.lock().push(Arc::from(bytes.as_slice()));
     let _ = self.cmd_tx.send(Cmd::Bytes(bytes));
   }
 
+  /// Snapshot of the captured log chunks. Chunks are shared, so this is
+  /// cheap even for a large buffer and safe to write out while the
+  /// stream keeps following.
+  pub fn captured_chunks(&self) -> Vec<Arc<[u8]>> {
+    self.capture.lock().chunks.iter().cloned().collect()
+  }
+
   pub fn resize(&self, cols: u16, rows: u16) {
     let _ = self.cmd_tx.send(Cmd::Resize { cols, rows });
   }
@@ -3,11 +3,15 @@ mod pty_terminal;
 #[cfg(not(unix))]
 mod pty_terminal_stub;
 
+#[cfg(unix)]
+mod attach_stream;
 pub(crate) mod grid_element;
 #[cfg(unix)]
 mod log_stream;
 mod terminal_view;
 
+#[cfg(unix)]
+pub use attach_stream::AttachStream;
 #[cfg(unix)]
 pub use log_stream::LogStream;
 
@@ -20,6 +24,16 @@ pub use terminal_view::*;
 
 use std::sync::Arc;
 
+/// Shells offered by the exec terminals' shell picker. `None` lets the
+/// session pick the first shell the container has.
+pub const EXEC_SHELLS: [(&str, Option<&str>); 5] = [
+  ("Auto", None),
+  ("/bin/sh", Some("/bin/sh")),
+  ("/bin/bash", Some("/bin/bash")),
+  ("/bin/zsh", Some("/bin/zsh")),
+  ("/bin/ash", Some("/bin/ash")),
+];
+
 /// Common interface every terminal-grid backend implements. Lets
 /// `TerminalView` drive either a real interactive PTY (`PtyTerminal`)
 /// or a one-way log feed (`LogStream`) through the same code path —
@@ -16,6 +16,7 @@
 //!
 //! Available on Unix targets only. Windows uses the stub in `pty_terminal_stub.rs`.
 
+use std::fmt::Write as _;
 use std::io::{Read, Write};
 use std::sync::mpsc::{self, Sender};
 use std::sync::{Arc, atomic::AtomicBool, atomic::AtomicUsize, atomic::Ordering};
@@ -28,6 +29,9 @@ use libghostty_vt::terminal::{Options as TermOptions, Terminal};
 use parking_lot::Mutex;
 use portable_pty::{CommandBuilder, MasterPty, PtySize, native_pty_system};
 
+use crate::services::shell_quote;
+use crate::state::is_env_key;
+
 /// Type of terminal session
 #[derive(Debug, Clone)]
 pub enum TerminalSessionType {
@@ -71,8 +75,9 @@ impl TerminalSessionType {
     Self::Custom { program, args }
   }
 
-  /// Build a `CommandBuilder` for `portable-pty` from the session type.
-  fn to_command(&self) -> CommandBuilder {
+  /// Build a `CommandBuilder` for `portable-pty` from the session type,
+  /// applying the user's preferred shell and extra environment.
+  fn to_command(&self, prefs: &SessionPrefs) -> CommandBuilder {
     let (program, args) = match self {
       Self::ColimaSsh { profile } => {
         let mut args = vec!["ssh".to_string()];
@@ -82,6 +87,18 @@ impl TerminalSessionType {
           args.push("--profile".to_string());
           args.push(p.clone());
         }
+        // `colima ssh` starts in the host's cwd (mounted into the VM);
+        // open a login shell in the VM user's home instead.
+        let shell = match &prefs.shell {
+          Some(sh) => format!("\"$(command -v {} || echo \"${{SHELL:-sh}}\")\"", shell_quote(sh)),
+          None => "\"${SHELL:-sh}\"".to_string(),
+        };
+        args.extend([
+          "--".to_string(),
+          "sh".to_string(),
+          "-c".to_string(),
+          format!("{}cd \"$HOME\" && exec {shell} -l", prefs.export_prefix()),
+        ]);
         ("colima", args)
       }
       Self::DockerExec { container_id, shell } => {
@@ -90,14 +107,18 @@ impl TerminalSessionType {
           "-it".to_string(),
           "-e".to_string(),
           "TERM=xterm-256color".to_string(),
-          container_id.clone(),
         ];
+        for (key, value) in &prefs.env {
+          args.push("-e".to_string());
+          args.push(format!("{key}={value}"));
+        }
+        args.push(container_id.clone());
         if let Some(sh) = shell {
           args.push(sh.clone());
         } else {
           args.push("sh".to_string());
           args.push("-c".to_string());
-          args.push("exec $(command -v bash || command -v zsh || command -v ash || command -v sh)".to_string());
+          args.push(format!("exec {}", shell_fallback(prefs.shell.as_deref())));
         }
         ("docker", args)
       }
@@ -107,31 +128,34 @@ impl TerminalSessionType {
         container,
         shell,
       } => {
-        let mut args = vec![
-          "exec".to_string(),
-          "-it".to_string(),
-          "-n".to_string(),
-          namespace.clone(),
-        ];
+        let mut target = vec!["-n".to_string(), namespace.clone()];
         if let Some(c) = container {
-          args.push("-c".to_string());
-          args.push(c.clone());
+          target.push("-c".to_string());
+          target.push(c.clone());
         }
-        args.push(pod_name.clone());
-        args.push("--".to_string());
-        if let Some(sh) = shell {
-          args.push("sh".to_string());
-          args.push("-c".to_string());
-          args.push(format!("TERM=xterm-256color exec {sh}"));
-        } else {
-          args.push("sh".to_string());
-          args.push("-c".to_string());
-          args.push(
-            "TERM=xterm-256color exec $(command -v bash || command -v zsh || command -v ash || command -v sh)"
-              .to_string(),
-          );
-        }
-        ("kubectl", args)
+        target.push(pod_name.clone());
+        // Resolve the shell first so a missing one prints a readable
+        // message instead of a bare `exec` failure.
+        let (found, missing) = match shell {
+          Some(sh) => (
+            format!("$(command -v {})", shell_quote(sh)),
+            format!("{sh} is not available in this container. Pick another shell."),
+          ),
+          None => (
+            shell_fallback(prefs.shell.as_deref()),
+            "No shell found in this container.".to_string(),
+          ),
+        };
+        let probe = format!(
+          "{}s={found}; [ -n \"$s\" ] || {{ echo {} >&2; exit 127; }}; TERM=xterm-256color exec \"$s\"",
+          prefs.export_prefix(),
+          shell_quote(&missing),
+        );
+        let direct = shell.as_deref().or(prefs.shell.as_deref());
+        (
+          "sh",
+          vec!["-c".to_string(), kubectl_exec_script(&target, &probe, direct)],
+        )
       }
       Self::Custom { program, args } => (program.as_str(), args.clone()),
     };
@@ -141,10 +165,71 @@ impl TerminalSessionType {
       cmd.arg(arg);
     }
     cmd.env("TERM", "xterm-256color");
+    for (key, value) in &prefs.env {
+      cmd.env(key, value);
+    }
     cmd
   }
 }
 
+/// Terminal preferences from Settings, applied when a session spawns.
+#[derive(Debug, Clone, Default)]
+pub struct SessionPrefs {
+  /// Shell tried first when the session doesn't pin one.
+  pub shell: Option<String>,
+  /// Extra environment exported into the session.
+  pub env: Vec<(String, String)>,
+}
+
+impl SessionPrefs {
+  /// `export K='v'; ` prefix for sessions started through `sh -c`. Keys
+  /// that aren't shell variable names are skipped, never spliced in.
+  fn export_prefix(&self) -> String {
+    let mut prefix = String::new();
+    for (key, value) in self.env.iter().filter(|(key, _)| is_env_key(key)) {
+      let _ = write!(prefix, "export {key}={}; ", shell_quote(value));
+    }
+    prefix
+  }
+}
+
+/// Host script for a pod session. The `sh -c` probe needs `sh` in the
+/// container, so check for it first; images without one (distroless) get
+/// `direct` exec'd as is, or a message when there's nothing to try.
+fn kubectl_exec_script(target: &[String], probe: &str, direct: Option<&str>) -> String {
+  let target = target.iter().map(|a| shell_quote(a)).collect::<Vec<_>>().join(" ");
+  let fallback = match direct {
+    Some(program) => format!(
+      "echo {} >&2; exec kubectl exec -it {target} -- {}",
+      shell_quote(&format!(
+        "sh is not available in this container. Running {program} directly."
+      )),
+      shell_quote(program)
+    ),
+    None => format!(
+      "echo {} >&2; exit 127",
+      shell_quote("sh is not available in this container. Pick a shell to run directly.")
+    ),
+  };
+  format!(
+    "if kubectl exec {target} -- sh -c : >/dev/null 2>&1; then exec kubectl exec -it {target} -- sh -c {}; fi; {fallback}",
+    shell_quote(probe)
+  )
+}
+
+/// `$(command -v …)` chain: the preferred shell first, then bash, zsh,
+/// ash and finally sh, so minimal images still get a prompt.
+fn shell_fallback(preferred: Option<&str>) -> String {
+  let mut candidates: Vec<String> = preferred.map(shell_quote).into_iter().collect();
+  candidates.extend(["bash", "zsh", "ash", "sh"].map(String::from));
+  let chain = candidates
+    .iter()
+    .map(|c| format!("command -v {c}"))
+    .collect::<Vec<_>>()
+    .join(" || ");
+  format!("$({chain})")
+}
+
 /// A terminal cell with text and styling.
 ///
 /// `fg` / `bg` are `None` when the cell has no explicit color (the renderer
@@ -238,11 +323,11 @@ pub struct PtyTerminal {
 }
 
 impl PtyTerminal {
-  pub fn new(session_type: &TerminalSessionType) -> Result<Self> {
-    Self::with_size(session_type, 120, 40)
+  pub fn new(session_type: &TerminalSessionType, prefs: &SessionPrefs) -> Result<Self> {
+    Self::with_size(session_type, prefs, 120, 40)
   }
 
-  pub fn with_size(session_type: &TerminalSessionType, cols: u16, rows: u16) -> Result<Self> {
+  pub fn with_size(session_type: &TerminalSessionType, prefs: &SessionPrefs, cols: u16, rows: u16) -> Result<Self> {
     // 1. Open the PTY via portable-pty.
     let pty_system = native_pty_system();
     let pair = pty_system
@@ -255,7 +340,7 @@ impl PtyTerminal {
       .map_err(|e| anyhow!("openpty failed: {e}"))?;
 
     // 2. Spawn the child process attached to the slave end.
-    let cmd = session_type.to_command();
+    let cmd = session_type.to_command(prefs);
     let mut child = pair
       .slave
       .spawn_command(cmd)
@@ -697,7 +782,7 @@ fn rgb_tuple(c: RgbColor) -> (u8, u8, u8) {
 /// thread. For the common shells we use, the simple xterm-style encoding
 /// matches what libghostty would emit in non-Kitty mode anyway.
 #[allow(clippy::fn_params_excessive_bools)]
-fn encode_key_simple(key: &str, ctrl: bool, alt: bool, _shift: bool) -> Option<Vec<u8>> {
+pub(crate) fn encode_key_simple(key: &str, ctrl: bool, alt: bool, _shift: bool) -> Option<Vec<u8>> {
   if ctrl {
     let byte = match key.to_lowercase().as_str() {
       "a" => 0x01,
@@ -784,3 +869,41 @@ fn encode_key_simple(key: &str, ctrl: bool, alt: bool, _shift: bool) -> Option<V
 
   Some(bytes)
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_kubectl_exec_script() {
+    let target = vec!["-n".to_string(), "my ns".to_string(), "web-0".to_string()];
+    assert_eq!(
+      kubectl_exec_script(&target, "exec \"$s\"", Some("/busybox/sh")),
+      "if kubectl exec -n 'my ns' web-0 -- sh -c : >/dev/null 2>&1; \
+       then exec kubectl exec -it -n 'my ns' web-0 -- sh -c 'exec \"$s\"'; fi; \
+       echo 'sh is not available in this container. Running /busybox/sh directly.' >&2; \
+       exec kubectl exec -it -n 'my ns' web-0 -- /busybox/sh"
+    );
+    assert!(kubectl_exec_script(&target, ":", None).ends_with("Pick a shell to run directly.' >&2; exit 127"));
+  }
+
+  #[test]
+  fn test_export_prefix_skips_invalid_keys() {
+    let prefs = SessionPrefs {
+      shell: None,
+      env: vec![
+        ("LANG".to_string(), "C.UTF-8".to_string()),
+        ("X; rm -rf ~".to_string(), "1".to_string()),
+        ("OPTS".to_string(), "-a,-b 'c'".to_string()),
+      ],
+    };
+    assert_eq!(
+      prefs.export_prefix(),
+      format!(
+        "export LANG={}; export OPTS={}; ",
+        shell_quote("C.UTF-8"),
+        shell_quote("-a,-b 'c'")
+      )
+    );
+  }
+}
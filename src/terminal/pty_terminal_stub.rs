@@ -44,6 +44,13 @@ impl TerminalSessionType {
   }
 }
 
+/// Terminal preferences from Settings, applied when a session spawns.
+#[derive(Debug, Clone, Default)]
+pub struct SessionPrefs {
+  pub shell: Option<String>,
+  pub env: Vec<(String, String)>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TerminalCell {
   pub char: char,
@@ -95,11 +102,11 @@ pub struct TerminalState {
 pub struct PtyTerminal;
 
 impl PtyTerminal {
-  pub fn new(_session_type: &TerminalSessionType) -> Result<Self> {
+  pub fn new(_session_type: &TerminalSessionType, _prefs: &SessionPrefs) -> Result<Self> {
     Err(anyhow!("Terminal is not supported on this platform yet"))
   }
 
-  pub fn with_size(_session_type: &TerminalSessionType, _cols: u16, _rows: u16) -> Result<Self> {
+  pub fn with_size(_session_type: &TerminalSessionType, _prefs: &SessionPrefs, _cols: u16, _rows: u16) -> Result<Self> {
     Err(anyhow!("Terminal is not supported on this platform yet"))
   }
 
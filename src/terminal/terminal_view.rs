@@ -22,7 +22,7 @@ use gpui_component::{
   v_flex,
 };
 
-use super::{PtyTerminal, TerminalSessionType, grid_element::GridMetrics};
+use super::{PtyTerminal, SessionPrefs, TerminalSessionType, grid_element::GridMetrics};
 use crate::state::{TerminalCursorStyle, settings_state};
 
 /// Cell in scroll-aware coords. `col` is the viewport column (cols don't
@@ -88,7 +88,7 @@ fn viewport_to_abs(col: u16, viewport_row: u16, view_scroll: i32) -> AbsCell {
 /// A functional terminal view with full keyboard and color support
 pub struct TerminalView {
   terminal: Option<Arc<dyn super::TerminalSource>>,
-  /// Session type for PTY-backed views; `None` for log-stream views.
+  /// Session type for PTY-backed views; `None` for log / attach views.
   session_type: Option<TerminalSessionType>,
   focus_handle: FocusHandle,
   font_size: f32,
@@ -179,7 +179,14 @@ impl TerminalView {
       // Log-stream views don't reconnect — the source is already set.
       return;
     };
-    match PtyTerminal::new(&session_type) {
+    // Shell / env preferences are read per connect so edits in Settings
+    // apply to the next session.
+    let settings = &settings_state(cx).read(cx).settings;
+    let prefs = SessionPrefs {
+      shell: Some(settings.terminal_shell.trim().to_string()).filter(|s| !s.is_empty()),
+      env: settings.terminal_env_vars(),
+    };
+    match PtyTerminal::new(&session_type, &prefs) {
       Ok(terminal) => {
         self.is_connected = true;
         self.error = None;
@@ -198,6 +205,16 @@ impl TerminalView {
   /// grid + selection / scroll / drag-extend behaviour the interactive
   /// terminal has.
   pub fn for_log_stream(stream: Arc<crate::terminal::LogStream>, cx: &mut Context<'_, Self>) -> Self {
+    Self::for_source(stream, cx)
+  }
+
+  /// Build a `TerminalView` over a `docker attach` session. Keystrokes go
+  /// to the container's main process rather than a fresh exec shell.
+  pub fn for_attach_stream(stream: Arc<crate::terminal::AttachStream>, cx: &mut Context<'_, Self>) -> Self {
+    Self::for_source(stream, cx)
+  }
+
+  fn for_source(source: Arc<dyn super::TerminalSource>, cx: &mut Context<'_, Self>) -> Self {
     let focus_handle = cx.focus_handle();
     let settings = &settings_state(cx).read(cx).settings;
     let font_size = settings.terminal_font_size;
@@ -206,7 +223,7 @@ impl TerminalView {
     let char_width = font_size * 0.6;
 
     let view = Self {
-      terminal: Some(stream),
+      terminal: Some(source),
       session_type: None,
       focus_handle,
       font_size,
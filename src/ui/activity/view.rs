@@ -1,16 +1,24 @@
 // Allow precision loss for display formatting of resource statistics
 #![allow(clippy::cast_precision_loss)]
 
-use gpui::{Context, Entity, Hsla, Render, Styled, Timer, Window, div, prelude::*, px};
-use gpui_component::{Icon, h_flex, label::Label, scroll::ScrollableElement, theme::ActiveTheme, v_flex};
+use gpui::{Context, Entity, Hsla, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Disableable, Icon, IconName, Selectable, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  label::Label,
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
 use std::collections::{HashMap, VecDeque};
-use std::time::Duration;
 
 use crate::assets::AppIcon;
+use crate::colima::MachineId;
 use crate::docker::{AggregateStats, ContainerStats};
 use crate::kubernetes::PodPhase;
-use crate::services;
-use crate::state::{DockerState, docker_state, settings_state};
+use crate::services::{self, FinishedTask, RefreshInterval, TaskStatus, task_manager};
+use crate::state::{CurrentView, DockerEventEntry, DockerEventKind, DockerState, docker_state, settings_state};
 
 const PER_ROW_SAMPLES: usize = 60;
 
@@ -31,12 +39,17 @@ pub struct ActivityMonitorView {
   cpu_history_per: HashMap<String, VecDeque<f64>>,
   k8s_expanded: bool,
   machines_expanded: bool,
+  events_expanded: bool,
+  /// Restrict the events feed to one resource kind (`None` = all)
+  event_filter: Option<DockerEventKind>,
+  history_expanded: bool,
+  /// Holding a `stream_all_container_stats` subscription; only while the
+  /// monitor is the current view
+  streaming_stats: bool,
 }
 
 impl ActivityMonitorView {
   pub fn new(_window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
-    // Get refresh interval from settings
-    let refresh_interval = settings_state(cx).read(cx).settings.stats_refresh_interval;
     let docker_state_entity = docker_state(cx);
 
     // Re-render whenever the runtime signals it changed: container
@@ -45,19 +58,28 @@ impl ActivityMonitorView {
     // just react and re-render the new context's numbers).
     cx.subscribe(
       &docker_state_entity,
-      |_this, _state, event: &crate::state::StateChanged, cx| {
+      |this, _state, event: &crate::state::StateChanged, cx| {
         use crate::state::StateChanged::{
-          ContainersUpdated, KubeContextSwitched, MachinesUpdated, NodeMetricsUpdated, NodesUpdated, PodMetricsUpdated,
-          PodsUpdated,
+          AllStatsUpdated, ContainersUpdated, DockerEvent, DockerEventsCleared, KubeContextSwitched,
+          MachineUsageUpdated, MachinesUpdated, NodeMetricsUpdated, NodesUpdated, PodMetricsUpdated, PodsUpdated,
+          ViewChanged,
         };
-        if matches!(
+        if let AllStatsUpdated(stats) = event {
+          this.apply_stats(stats.clone());
+          cx.notify();
+        } else if matches!(event, ViewChanged) {
+          this.sync_stats_streams(cx);
+        } else if matches!(
           event,
           ContainersUpdated
+            | DockerEvent
+            | DockerEventsCleared
             | PodsUpdated
             | NodesUpdated
             | NodeMetricsUpdated
             | PodMetricsUpdated
             | MachinesUpdated
+            | MachineUsageUpdated
             | KubeContextSwitched
         ) {
           cx.notify();
@@ -66,22 +88,25 @@ impl ActivityMonitorView {
     )
     .detach();
 
-    // Start periodic refresh
-    cx.spawn(async move |this, cx| {
-      loop {
-        // Wait for configured refresh interval
-        Timer::after(Duration::from_secs(refresh_interval)).await;
+    // Finished tasks land in the task history
+    let tasks = task_manager(cx);
+    cx.observe(&tasks, |_this, _manager, cx| cx.notify()).detach();
 
-        let _ = this.update(cx, |_this, cx| {
-          Self::refresh_stats(cx);
-          Self::refresh_runtime(cx);
-        });
+    // Container usage arrives from the shared stats streams while the
+    // monitor is visible; the Kubernetes and machine figures are still
+    // polled at the stats interval
+    cx.on_release(|this, cx| {
+      if this.streaming_stats {
+        services::stop_all_container_stats(cx);
       }
     })
     .detach();
+    services::spawn_auto_refresh(RefreshInterval::Stats, 1, cx, |_, cx| {
+      Self::refresh_runtime(cx);
+    });
 
     // Initial refresh
-    let view = Self {
+    let mut view = Self {
       docker_state: docker_state(cx),
       stats: AggregateStats::default(),
       expanded: true,
@@ -93,13 +118,31 @@ impl ActivityMonitorView {
       cpu_history_per: HashMap::new(),
       k8s_expanded: true,
       machines_expanded: true,
+      events_expanded: true,
+      event_filter: None,
+      history_expanded: true,
+      streaming_stats: false,
     };
 
-    Self::refresh_stats(cx);
+    view.sync_stats_streams(cx);
     Self::refresh_runtime(cx);
     view
   }
 
+  /// Hold the shared stats streams only while the monitor is on screen.
+  fn sync_stats_streams(&mut self, cx: &mut Context<'_, Self>) {
+    let visible = self.docker_state.read(cx).current_view == CurrentView::ActivityMonitor;
+    if visible == self.streaming_stats {
+      return;
+    }
+    self.streaming_stats = visible;
+    if visible {
+      services::stream_all_container_stats(cx);
+    } else {
+      services::stop_all_container_stats(cx);
+    }
+  }
+
   /// Trigger the generation-guarded refresh services for the non-Docker
   /// runtime data shown here. Each service drops stale responses on a
   /// context switch itself — we never touch the guard.
@@ -108,6 +151,7 @@ impl ActivityMonitorView {
     if settings.colima_enabled {
       services::refresh_machines(cx);
     }
+    services::refresh_machine_usage(cx);
     if settings.kubernetes_enabled {
       services::refresh_pods(cx);
       services::refresh_nodes(cx);
@@ -116,73 +160,52 @@ impl ActivityMonitorView {
     }
   }
 
-  fn refresh_stats(cx: &mut Context<'_, Self>) {
-    let tokio_handle = services::Tokio::runtime_handle();
-    let client = services::docker_client();
-
-    cx.spawn(async move |this, cx| {
-      let stats = cx
-        .background_executor()
-        .spawn(async move {
-          tokio_handle.block_on(async {
-            let guard = client.read().await;
-            match guard.as_ref() {
-              Some(docker) => docker.get_all_container_stats().await.ok(),
-              None => None,
-            }
-          })
-        })
-        .await;
-
-      let _ = this.update(cx, |this, cx| {
-        this.is_loading = false;
-        if let Some(stats) = stats {
-          // Update history
-          this.cpu_history.push(stats.total_cpu_percent);
-          this.memory_history.push(stats.total_memory);
-          this
-            .network_history
-            .push(stats.total_network_rx + stats.total_network_tx);
-          this.disk_history.push(stats.total_block_read + stats.total_block_write);
-
-          // Keep only last 60 samples
-          if this.cpu_history.len() > 60 {
-            this.cpu_history.remove(0);
-          }
-          if this.memory_history.len() > 60 {
-            this.memory_history.remove(0);
-          }
-          if this.network_history.len() > 60 {
-            this.network_history.remove(0);
-          }
-          if this.disk_history.len() > 60 {
-            this.disk_history.remove(0);
-          }
-
-          // Per-container CPU history: append the latest sample for
-          // every container reporting stats this tick, then drop any
-          // entries no longer present so containers that disappear
-          // don't keep stale ring buffers around forever.
-          let mut alive: std::collections::HashSet<&str> = std::collections::HashSet::new();
-          for s in &stats.container_stats {
-            alive.insert(s.id.as_str());
-            let entry = this
-              .cpu_history_per
-              .entry(s.id.clone())
-              .or_insert_with(|| VecDeque::with_capacity(PER_ROW_SAMPLES));
-            entry.push_back(s.cpu_percent);
-            while entry.len() > PER_ROW_SAMPLES {
-              entry.pop_front();
-            }
-          }
-          this.cpu_history_per.retain(|id, _| alive.contains(id.as_str()));
-
-          this.stats = stats;
-        }
-        cx.notify();
-      });
-    })
-    .detach();
+  /// Take a combined snapshot from the stats streams and append it to
+  /// the chart histories.
+  fn apply_stats(&mut self, stats: AggregateStats) {
+    self.is_loading = false;
+
+    // Update history
+    self.cpu_history.push(stats.total_cpu_percent);
+    self.memory_history.push(stats.total_memory);
+    self
+      .network_history
+      .push(stats.total_network_rx + stats.total_network_tx);
+    self.disk_history.push(stats.total_block_read + stats.total_block_write);
+
+    // Keep only last 60 samples
+    if self.cpu_history.len() > 60 {
+      self.cpu_history.remove(0);
+    }
+    if self.memory_history.len() > 60 {
+      self.memory_history.remove(0);
+    }
+    if self.network_history.len() > 60 {
+      self.network_history.remove(0);
+    }
+    if self.disk_history.len() > 60 {
+      self.disk_history.remove(0);
+    }
+
+    // Per-container CPU history: append the latest sample for
+    // every container reporting stats this tick, then drop any
+    // entries no longer present so containers that disappear
+    // don't keep stale ring buffers around forever.
+    let mut alive: std::collections::HashSet<&str> = std::collections::HashSet::new();
+    for s in &stats.container_stats {
+      alive.insert(s.id.as_str());
+      let entry = self
+        .cpu_history_per
+        .entry(s.id.clone())
+        .or_insert_with(|| VecDeque::with_capacity(PER_ROW_SAMPLES));
+      entry.push_back(s.cpu_percent);
+      while entry.len() > PER_ROW_SAMPLES {
+        entry.pop_front();
+      }
+    }
+    self.cpu_history_per.retain(|id, _| alive.contains(id.as_str()));
+
+    self.stats = stats;
   }
 
   fn render_container_group(&self, cx: &Context<'_, Self>) -> impl IntoElement {
@@ -196,13 +219,13 @@ impl ActivityMonitorView {
       .stats
       .container_stats
       .iter()
-      .map(|s| s.network_rx + s.network_tx)
+      .map(|s| s.network_rx_rate + s.network_tx_rate)
       .sum();
     let total_disk: u64 = self
       .stats
       .container_stats
       .iter()
-      .map(|s| s.block_read + s.block_write)
+      .map(|s| s.block_read_rate + s.block_write_rate)
       .sum();
 
     // Running / paused / exited counts, inlined on the group header
@@ -439,21 +462,28 @@ impl ActivityMonitorView {
                             .text_right()
                             .child(stats.display_memory()),
                     )
-                    .child(
+                    .child({
+                        let breakdown = stats.network_breakdown();
                         div()
+                            .id(gpui::SharedString::from(format!("net-{}", stats.id)))
                             .w(px(100.))
                             .text_sm()
                             .text_color(colors.secondary_foreground)
                             .text_right()
-                            .child(stats.display_network_rx()),
-                    )
+                            .child(stats.display_network_rate())
+                            .when(!breakdown.is_empty(), |el| {
+                                el.tooltip(move |window, cx| {
+                                    gpui_component::tooltip::Tooltip::new(breakdown.clone()).build(window, cx)
+                                })
+                            })
+                    })
                     .child(
                         div()
                             .w(px(100.))
                             .text_sm()
                             .text_color(colors.secondary_foreground)
                             .text_right()
-                            .child(stats.display_block_read()),
+                            .child(stats.display_block_rate()),
                     ),
             )
   }
@@ -603,6 +633,84 @@ impl ActivityMonitorView {
       .child(div().text_sm().text_color(colors.muted_foreground).child(label))
   }
 
+  /// Title-bar summary of the machine running Docker: VM-level CPU,
+  /// memory and disk next to the running container count.
+  fn render_machine_usage(&self, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let state = self.docker_state.read(cx);
+    let running = state.containers.iter().filter(|c| c.state.is_running()).count();
+    let total = state.containers.len();
+
+    let mut row = h_flex().gap(px(20.)).items_center();
+    if let Some((machine, usage)) = &state.machine_usage {
+      let name = match machine {
+        MachineId::Host => "Host".to_string(),
+        MachineId::Colima(name) => name.clone(),
+      };
+      row = row
+        .child(
+          h_flex()
+            .gap(px(6.))
+            .items_center()
+            .child(
+              Icon::new(AppIcon::Machine)
+                .size(px(14.))
+                .text_color(colors.muted_foreground),
+            )
+            .child(div().text_sm().text_color(colors.foreground).child(name)),
+        )
+        .child(Self::usage_seg(
+          "CPU",
+          usage
+            .cpu_percent
+            .map_or_else(|| "--".to_string(), |p| format!("{p:.1}%")),
+          usage.cpu_percent.unwrap_or(0.0),
+          cx,
+        ))
+        .child(Self::usage_seg(
+          "Memory",
+          format!(
+            "{} / {} ({:.0}%)",
+            usage.memory_used, usage.memory_total, usage.memory_percent
+          ),
+          usage.memory_percent,
+          cx,
+        ))
+        .child(Self::usage_seg(
+          "Disk",
+          format!(
+            "{} / {} ({:.0}%)",
+            usage.disk_used, usage.disk_total, usage.disk_percent
+          ),
+          usage.disk_percent,
+          cx,
+        ));
+    }
+    row.child(Self::usage_seg(
+      "Containers",
+      format!("{running} / {total} running"),
+      0.0,
+      cx,
+    ))
+  }
+
+  /// Label + value, with the value turning warning / danger past 60% / 80%
+  fn usage_seg(label: &'static str, value: String, percent: f64, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let color = if percent > 80.0 {
+      colors.danger
+    } else if percent > 60.0 {
+      colors.warning
+    } else {
+      colors.foreground
+    };
+    h_flex()
+      .gap(px(6.))
+      .items_center()
+      .child(div().text_xs().text_color(colors.muted_foreground).child(label))
+      .child(div().text_sm().text_color(color).child(value))
+  }
+
   /// Collapsible group header styled exactly like the Containers
   /// group: chevron, icon, title, then a muted summary on the right.
   fn group_header(
@@ -689,16 +797,7 @@ impl ActivityMonitorView {
       .iter()
       .map(|p| {
         let usage = state.pod_usage(&p.namespace, &p.name);
-        let cpu = usage.map_or_else(
-          || "—".to_string(),
-          |(m, _)| {
-            if m >= 1000.0 {
-              format!("{:.2} cores", m / 1000.0)
-            } else {
-              format!("{m:.0}m")
-            }
-          },
-        );
+        let cpu = usage.map_or_else(|| "—".to_string(), |(m, _)| crate::kubernetes::format_millicores(m));
         let mem = usage.map_or_else(|| "—".to_string(), |(_, b)| bytesize::ByteSize(b).to_string());
         h_flex()
           .id(gpui::SharedString::from(format!("pod-{}-{}", p.namespace, p.name)))
@@ -947,6 +1046,278 @@ impl ActivityMonitorView {
       ))
       .when(expanded, |el| el.child(v_flex().w_full().children(rows)))
   }
+
+  /// Docker daemon events feed: newest first, color-coded by action,
+  /// with a per-kind filter and a clear button.
+  fn render_events_group(&self, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let expanded = self.events_expanded;
+    let filter = self.event_filter;
+    let state = self.docker_state.read(cx);
+    let summary = format!("{} recent", state.docker_events.len());
+
+    let rows: Vec<gpui::AnyElement> = state
+      .docker_events
+      .iter()
+      .rev()
+      .filter(|e| filter.is_none_or(|k| e.kind == k))
+      .enumerate()
+      .map(|(i, e)| Self::render_event_row(i, e, cx).into_any_element())
+      .collect();
+    let is_empty = rows.is_empty();
+
+    let filter_btn = |id: &'static str, label: &'static str, kind: Option<DockerEventKind>| {
+      Button::new(id)
+        .label(label)
+        .ghost()
+        .xsmall()
+        .selected(filter == kind)
+        .on_click(cx.listener(move |this, _ev, _window, cx| {
+          this.event_filter = kind;
+          cx.notify();
+        }))
+    };
+
+    let toolbar = h_flex()
+      .w_full()
+      .px(px(16.))
+      .pl(px(56.))
+      .py(px(4.))
+      .gap(px(4.))
+      .items_center()
+      .child(filter_btn("events-filter-all", "All", None))
+      .children(DockerEventKind::ALL.iter().map(|&kind| {
+        let id = match kind {
+          DockerEventKind::Container => "events-filter-container",
+          DockerEventKind::Image => "events-filter-image",
+          DockerEventKind::Volume => "events-filter-volume",
+          DockerEventKind::Network => "events-filter-network",
+        };
+        filter_btn(id, kind.label(), Some(kind))
+      }))
+      .child(div().flex_1())
+      .child(
+        Button::new("events-clear")
+          .label("Clear")
+          .ghost()
+          .xsmall()
+          .disabled(state.docker_events.is_empty())
+          .on_click(cx.listener(|_this, _ev, _window, cx| {
+            services::clear_docker_events(cx);
+          })),
+      );
+
+    v_flex()
+      .w_full()
+      .child(Self::group_header(
+        "events-group",
+        AppIcon::Activity,
+        "Docker Events",
+        summary,
+        expanded,
+        cx,
+        |this, _cx| this.events_expanded = !this.events_expanded,
+      ))
+      .when(expanded, |el| {
+        el.child(toolbar).child(
+          v_flex()
+            .w_full()
+            .when(is_empty, |el| {
+              el.child(
+                div()
+                  .h(px(30.))
+                  .pl(px(56.))
+                  .text_xs()
+                  .text_color(colors.muted_foreground)
+                  .child("No events yet"),
+              )
+            })
+            .children(rows),
+        )
+      })
+  }
+
+  /// Completed and failed tasks, newest first. Failures show the full
+  /// error and, when the operation supports it, a retry button.
+  fn render_task_history_group(&self, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let expanded = self.history_expanded;
+    let manager = task_manager(cx);
+    let history: Vec<FinishedTask> = manager.read(cx).history().cloned().collect();
+    let failed = history.iter().filter(|t| t.is_failed()).count();
+    let summary = if failed > 0 {
+      format!("{} finished, {failed} failed", history.len())
+    } else {
+      format!("{} finished", history.len())
+    };
+
+    let toolbar = h_flex()
+      .w_full()
+      .px(px(16.))
+      .pl(px(56.))
+      .py(px(4.))
+      .items_center()
+      .child(div().flex_1())
+      .child(
+        Button::new("task-history-clear")
+          .label("Clear")
+          .ghost()
+          .xsmall()
+          .disabled(history.is_empty())
+          .on_click(cx.listener(|_this, _ev, _window, cx| {
+            services::clear_task_history(cx);
+          })),
+      );
+
+    v_flex()
+      .w_full()
+      .child(Self::group_header(
+        "task-history-group",
+        AppIcon::Activity,
+        "Task History",
+        summary,
+        expanded,
+        cx,
+        |this, _cx| this.history_expanded = !this.history_expanded,
+      ))
+      .when(expanded, |el| {
+        el.child(toolbar).child(
+          v_flex()
+            .w_full()
+            .when(history.is_empty(), |el| {
+              el.child(
+                div()
+                  .h(px(30.))
+                  .pl(px(56.))
+                  .text_xs()
+                  .text_color(colors.muted_foreground)
+                  .child("No finished tasks yet"),
+              )
+            })
+            .children(history.iter().map(|task| Self::render_history_row(task, cx))),
+        )
+      })
+  }
+
+  fn render_history_row(task: &FinishedTask, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let (icon, color) = match task.status {
+      TaskStatus::Failed(_) => (IconName::CircleX, colors.danger),
+      _ => (IconName::CircleCheck, colors.success),
+    };
+    let error = match &task.status {
+      TaskStatus::Failed(e) => Some(e.clone()),
+      _ => None,
+    };
+    let retry = task.retry.clone().filter(|_| task.is_failed());
+
+    v_flex()
+      .id(("task-history", task.id))
+      .w_full()
+      .px(px(16.))
+      .pl(px(56.))
+      .py(px(6.))
+      .gap(px(2.))
+      .child(
+        h_flex()
+          .w_full()
+          .items_center()
+          .gap(px(8.))
+          .child(
+            div()
+              .flex_shrink_0()
+              .w(px(64.))
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(task.completed_at.format("%H:%M:%S").to_string()),
+          )
+          .child(Icon::new(icon).size(px(14.)).text_color(color))
+          .child(
+            div()
+              .flex_1()
+              .min_w_0()
+              .text_sm()
+              .text_color(colors.foreground)
+              .text_ellipsis()
+              .overflow_hidden()
+              .whitespace_nowrap()
+              .child(task.description.trim_end_matches("...").to_string()),
+          )
+          .child(
+            div()
+              .flex_shrink_0()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(task.display_duration()),
+          )
+          .when_some(retry, |el, retry| {
+            el.child(
+              Button::new(("task-retry", task.id))
+                .label("Retry")
+                .ghost()
+                .xsmall()
+                .on_click(move |_ev, _window, cx| retry.run(cx)),
+            )
+          }),
+      )
+      .when_some(error, |el, error| {
+        el.child(div().pl(px(94.)).text_xs().text_color(colors.danger).child(error))
+      })
+  }
+
+  fn render_event_row(index: usize, event: &DockerEventEntry, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    let icon = match event.kind {
+      DockerEventKind::Container => AppIcon::Container,
+      DockerEventKind::Image => AppIcon::Image,
+      DockerEventKind::Volume => AppIcon::Volume,
+      DockerEventKind::Network => AppIcon::Network,
+    };
+    let color = match event.action.split(':').next().unwrap_or_default() {
+      "start" | "create" | "pull" | "unpause" | "connect" | "mount" | "tag" => colors.success,
+      "die" | "kill" | "oom" => colors.danger,
+      "stop" | "destroy" | "delete" | "remove" | "untag" | "disconnect" | "unmount" => colors.warning,
+      _ => colors.muted_foreground,
+    };
+
+    h_flex()
+      .id(("docker-event", index))
+      .w_full()
+      .h(px(28.))
+      .px(px(16.))
+      .pl(px(56.))
+      .items_center()
+      .gap(px(8.))
+      .child(
+        div()
+          .flex_shrink_0()
+          .w(px(64.))
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child(event.time.format("%H:%M:%S").to_string()),
+      )
+      .child(Icon::new(icon).size(px(14.)).text_color(color))
+      .child(
+        div()
+          .flex_shrink_0()
+          .w(px(96.))
+          .text_xs()
+          .font_weight(gpui::FontWeight::MEDIUM)
+          .text_color(color)
+          .child(event.action.clone()),
+      )
+      .child(
+        div()
+          .flex_1()
+          .min_w_0()
+          .text_sm()
+          .text_color(colors.foreground)
+          .text_ellipsis()
+          .overflow_hidden()
+          .whitespace_nowrap()
+          .child(event.resource.clone()),
+      )
+  }
 }
 
 /// One-line tooltip body for a sparkline: "CPU last 12.3% (min 0 / max
@@ -1058,7 +1429,9 @@ impl Render for ActivityMonitorView {
                         Label::new("Activity Monitor")
                             .text_color(colors.foreground)
                             .font_weight(gpui::FontWeight::SEMIBOLD),
-                    ),
+                    )
+                    .child(div().flex_1())
+                    .child(self.render_machine_usage(cx)),
             )
             // Content area
             .child(
@@ -1074,7 +1447,9 @@ impl Render for ActivityMonitorView {
                         el.child(Self::render_empty(cx))
                     })
                     .when(show_k8s, |el| el.child(self.render_k8s_group(cx)))
-                    .when(show_machines, |el| el.child(self.render_machines_group(cx))),
+                    .when(show_machines, |el| el.child(self.render_machines_group(cx)))
+                    .child(self.render_events_group(cx))
+                    .child(self.render_task_history_group(cx)),
             )
             // Summary section at bottom
             .child(self.render_summary_section(cx))
@@ -1,8 +1,7 @@
-//! Cluster overview view: Nodes, Events, Namespaces with create/delete.
+//! Cluster overview view: Nodes, Events, Namespaces with create/delete
+//! and per-namespace resource quota usage.
 
-use std::time::Duration;
-
-use gpui::{Context, Entity, Render, SharedString, Styled, Timer, Window, div, prelude::*, px};
+use gpui::{Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
 use gpui_component::{
   Icon, IconName, Selectable, Sizable,
   button::{Button, ButtonVariants},
@@ -16,8 +15,9 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
-use crate::services;
-use crate::state::{DockerState, LoadState, StateChanged, docker_state, settings_state};
+use crate::kubernetes::{QuotaUsage, ResourceQuotaInfo};
+use crate::services::{self, RefreshInterval};
+use crate::state::{DockerState, LoadState, StateChanged, docker_state};
 use crate::ui::components::{render_k8s_error, render_k8s_header, render_loading};
 use crate::ui::nodes::NodesView;
 
@@ -34,6 +34,10 @@ pub struct ClusterView {
   active_tab: ClusterTab,
   new_namespace_input: Option<Entity<InputState>>,
   nodes: Entity<NodesView>,
+  /// Namespace whose quotas are shown under the namespace list
+  quota_namespace: Option<String>,
+  /// `None` while the first fetch for `quota_namespace` is in flight
+  quotas: Option<Result<Vec<ResourceQuotaInfo>, String>>,
 }
 
 impl ClusterView {
@@ -49,23 +53,28 @@ impl ClusterView {
         this.active_tab = ClusterTab::Nodes;
         cx.notify();
       }
-      _ => {}
-    })
-    .detach();
-
-    let refresh = settings_state(cx).read(cx).settings.container_refresh_interval.max(5);
-    cx.spawn(async move |_this, cx| {
-      loop {
-        Timer::after(Duration::from_secs(refresh)).await;
-        let _ = cx.update(|cx| {
-          services::refresh_nodes(cx);
-          services::refresh_events(cx);
-          services::refresh_namespaces(cx);
+      StateChanged::NamespaceQuotasLoaded {
+        namespace,
+        quotas,
+        error,
+      } if this.quota_namespace.as_ref() == Some(namespace) => {
+        this.quotas = Some(match error {
+          Some(e) => Err(e.clone()),
+          None => Ok(quotas.clone()),
         });
+        cx.notify();
       }
+      _ => {}
     })
     .detach();
 
+    services::spawn_auto_refresh(RefreshInterval::Lists, 5, cx, |this, cx| {
+      services::refresh_nodes(cx);
+      services::refresh_events(cx);
+      services::refresh_namespaces(cx);
+      this.refresh_quotas(cx);
+    });
+
     services::refresh_nodes(cx);
     services::refresh_events(cx);
     services::refresh_namespaces(cx);
@@ -75,6 +84,26 @@ impl ClusterView {
       active_tab: ClusterTab::Nodes,
       new_namespace_input: None,
       nodes,
+      quota_namespace: None,
+      quotas: None,
+    }
+  }
+
+  /// Show quotas for `namespace`, or hide the panel when it is already shown.
+  fn toggle_quota_namespace(&mut self, namespace: String, cx: &mut Context<'_, Self>) {
+    if self.quota_namespace.as_ref() == Some(&namespace) {
+      self.quota_namespace = None;
+    } else {
+      self.quota_namespace = Some(namespace);
+      self.quotas = None;
+      self.refresh_quotas(cx);
+    }
+    cx.notify();
+  }
+
+  fn refresh_quotas(&self, cx: &mut Context<'_, Self>) {
+    if let Some(ns) = self.quota_namespace.clone() {
+      services::get_namespace_quotas(ns, cx);
     }
   }
 
@@ -378,8 +407,10 @@ impl ClusterView {
     );
     for ns in &namespaces {
       let name = ns.clone();
+      let is_selected = self.quota_namespace.as_ref() == Some(ns);
       list = list.child(
         h_flex()
+          .id(SharedString::from(format!("ns-row-{name}")))
           .w_full()
           .px(px(12.))
           .py(px(8.))
@@ -387,6 +418,13 @@ impl ClusterView {
           .items_center()
           .border_b_1()
           .border_color(colors.border)
+          .cursor_pointer()
+          .when(is_selected, |el| el.bg(colors.list_active))
+          .hover(|el| el.bg(colors.list_hover))
+          .on_click(cx.listener({
+            let name = name.clone();
+            move |this, _, _, cx| this.toggle_quota_namespace(name.clone(), cx)
+          }))
           .child(div().flex_1().text_sm().text_color(colors.foreground).child(ns.clone()))
           .child(
             Button::new(SharedString::from(format!("menu-{name}")))
@@ -409,6 +447,9 @@ impl ClusterView {
           ),
       );
     }
+    if let Some(ns) = self.quota_namespace.clone() {
+      list = list.child(self.render_quotas(&ns, cx));
+    }
     div().size_full().child(
       div()
         .id("namespaces-scroll")
@@ -419,16 +460,100 @@ impl ClusterView {
   }
 }
 
+impl ClusterView {
+  fn render_quotas(&self, namespace: &str, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = cx.theme().colors;
+    let section = v_flex().w_full().px(px(12.)).py(px(12.)).gap(px(12.)).child(
+      div()
+        .text_sm()
+        .font_weight(gpui::FontWeight::SEMIBOLD)
+        .text_color(colors.foreground)
+        .child(format!("Resource Quotas in {namespace}")),
+    );
+    let note = |text: String| div().text_xs().text_color(colors.muted_foreground).child(text);
+
+    match &self.quotas {
+      None => section.child(note("Loading quotas...".to_string())),
+      Some(Err(e)) => section.child(div().text_xs().text_color(colors.danger).child(e.clone())),
+      Some(Ok(quotas)) if quotas.is_empty() => {
+        section.child(note(format!("No resource quotas are defined in '{namespace}'.")))
+      }
+      Some(Ok(quotas)) => section.children(quotas.iter().map(|quota| {
+        v_flex()
+          .w_full()
+          .gap(px(6.))
+          .child(
+            div()
+              .text_xs()
+              .font_weight(gpui::FontWeight::MEDIUM)
+              .text_color(colors.muted_foreground)
+              .child(quota.name.clone()),
+          )
+          .children(quota.usage.iter().map(|usage| render_quota_usage(usage, cx)))
+      })),
+    }
+  }
+}
+
+/// One `resource  [bar]  used / hard` row. The bar turns amber past 80%
+/// and red once the quota is exhausted.
+#[allow(clippy::cast_possible_truncation)]
+fn render_quota_usage(usage: &QuotaUsage, cx: &Context<'_, ClusterView>) -> gpui::Div {
+  let colors = cx.theme().colors;
+  let fraction = usage.fraction().unwrap_or(0.0);
+  let bar_color = if usage.is_exhausted() {
+    colors.danger
+  } else if fraction >= 0.8 {
+    colors.warning
+  } else {
+    colors.primary
+  };
+  h_flex()
+    .w_full()
+    .gap(px(12.))
+    .items_center()
+    .child(
+      div()
+        .w(px(200.))
+        .flex_shrink_0()
+        .text_xs()
+        .text_color(colors.foreground)
+        .child(usage.resource.clone()),
+    )
+    .child(
+      div().flex_1().h(px(8.)).bg(colors.muted).rounded(px(4.)).child(
+        div()
+          .h_full()
+          .rounded(px(4.))
+          .bg(bar_color)
+          .w(gpui::relative(fraction as f32)),
+      ),
+    )
+    .child(
+      div()
+        .w(px(140.))
+        .flex_shrink_0()
+        .text_xs()
+        .text_color(if usage.is_exhausted() {
+          colors.danger
+        } else {
+          colors.muted_foreground
+        })
+        .child(format!("{} / {}", usage.used, usage.hard)),
+    )
+}
+
 impl Render for ClusterView {
   fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let refresh = Button::new("cluster-refresh")
       .icon(Icon::new(AppIcon::Refresh))
       .ghost()
       .compact()
-      .on_click(cx.listener(|_this, _ev, _w, cx| {
+      .on_click(cx.listener(|this, _ev, _w, cx| {
         services::refresh_nodes(cx);
         services::refresh_events(cx);
         services::refresh_namespaces(cx);
+        this.refresh_quotas(cx);
       }));
 
     let tab_bar = self.render_tab_bar(cx);
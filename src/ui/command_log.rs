@@ -0,0 +1,148 @@
+//! Command log panel: lists the CLI-equivalent commands recorded by
+//! `services::command_log`, newest first, with per-entry and bulk copy.
+
+use gpui::{
+  App, ClipboardItem, Context, FocusHandle, Focusable, Render, SharedString, Styled, Window, div, prelude::*, px,
+};
+use gpui_component::{
+  Disableable, IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::services;
+use crate::state::settings_state;
+
+pub struct CommandLogPanel {
+  focus_handle: FocusHandle,
+}
+
+impl CommandLogPanel {
+  pub fn new(cx: &mut Context<'_, Self>) -> Self {
+    let log = services::command_log(cx);
+    cx.observe(&log, |_this, _log, cx| cx.notify()).detach();
+    Self {
+      focus_handle: cx.focus_handle(),
+    }
+  }
+}
+
+impl Focusable for CommandLogPanel {
+  fn focus_handle(&self, _cx: &App) -> FocusHandle {
+    self.focus_handle.clone()
+  }
+}
+
+impl Render for CommandLogPanel {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let enabled = settings_state(cx).read(cx).settings.command_log_enabled;
+    let log = services::command_log(cx);
+    let entries: Vec<_> = log.read(cx).entries().rev().cloned().collect();
+
+    let toolbar = h_flex()
+      .w_full()
+      .gap(px(8.))
+      .items_center()
+      .child(
+        div()
+          .flex_1()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child(if enabled {
+            format!("{} commands recorded", entries.len())
+          } else {
+            "Recording is off. Enable it in Settings → General.".to_string()
+          }),
+      )
+      .child(
+        Button::new("command-log-copy-all")
+          .label("Copy all")
+          .icon(IconName::Copy)
+          .small()
+          .ghost()
+          .disabled(entries.is_empty())
+          .on_click(|_ev, _window, cx| {
+            let script = services::command_log(cx).read(cx).as_script();
+            cx.write_to_clipboard(ClipboardItem::new_string(script));
+          }),
+      )
+      .child(
+        Button::new("command-log-clear")
+          .label("Clear")
+          .icon(IconName::Delete)
+          .small()
+          .ghost()
+          .disabled(entries.is_empty())
+          .on_click(|_ev, _window, cx| {
+            services::command_log(cx).update(cx, |log, cx| {
+              log.clear();
+              cx.notify();
+            });
+          }),
+      );
+
+    let body = if entries.is_empty() {
+      div()
+        .p(px(16.))
+        .text_sm()
+        .text_color(colors.muted_foreground)
+        .child("No commands yet.")
+        .into_any_element()
+    } else {
+      v_flex()
+        .children(entries.into_iter().enumerate().map(|(idx, entry)| {
+          let command = entry.command.clone();
+          h_flex()
+            .w_full()
+            .py(px(6.))
+            .px(px(8.))
+            .gap(px(8.))
+            .items_start()
+            .border_b_1()
+            .border_color(colors.border)
+            .child(
+              div()
+                .w(px(64.))
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(entry.timestamp.format("%H:%M:%S").to_string()),
+            )
+            .child(
+              div()
+                .flex_1()
+                .min_w_0()
+                .text_xs()
+                .font_family("monospace")
+                .text_color(colors.foreground)
+                .child(entry.command),
+            )
+            .child(
+              Button::new(SharedString::from(format!("command-log-copy-{idx}")))
+                .icon(IconName::Copy)
+                .xsmall()
+                .ghost()
+                .on_click(move |_ev, _window, cx| {
+                  cx.write_to_clipboard(ClipboardItem::new_string(command.clone()));
+                }),
+            )
+        }))
+        .into_any_element()
+    };
+
+    v_flex().w_full().gap(px(8.)).child(toolbar).child(
+      div()
+        .id("command-log-entries")
+        .w_full()
+        .h(px(360.))
+        .overflow_y_scrollbar()
+        .bg(colors.sidebar)
+        .rounded(px(4.))
+        .child(body),
+    )
+  }
+}
@@ -1,11 +1,15 @@
 //! Command Palette Component
 //!
 //! A fuzzy search overlay that lets users quickly access any action in the app.
-//! Triggered by Cmd+K.
+//! Triggered by Cmd+K. Results are ranked by match quality; with an empty
+//! query the most recently run commands come first. Typing also lists live
+//! resources from the already loaded `DockerState` ("Start container web",
+//! "View logs for pod api"), which open their view, select the resource and
+//! run the matching "selected resource" action.
 
 use gpui::{
-  App, Context, Entity, EventEmitter, FocusHandle, Focusable, KeyBinding, MouseButton, Render, SharedString, Styled,
-  Window, anchored, div, point, prelude::*, px,
+  Action, App, Context, Entity, EventEmitter, FocusHandle, Focusable, FontWeight, HighlightStyle, KeyBinding,
+  MouseButton, Render, SharedString, Styled, StyledText, Window, anchored, div, point, prelude::*, px,
 };
 use gpui_component::{
   Icon, IconName,
@@ -14,13 +18,117 @@ use gpui_component::{
   v_flex,
 };
 
-use crate::state::CurrentView;
+use crate::assets::AppIcon;
+use crate::colima::MachineId;
+use crate::keybindings::{OpenTerminal, RestartSelected, StartSelected, StopSelected, ViewLogs};
+use crate::state::{AppSettings, CurrentView, DockerState, Selection, SidebarSection, docker_state, settings_state};
 
 // Actions for keyboard navigation within the palette
 gpui::actions!(command_palette, [Cancel, SelectUp, SelectDown, Confirm]);
 
 const CONTEXT: &str = "CommandPalette";
 
+/// Most commands kept in the recently run list
+const MAX_RECENT_COMMANDS: usize = 5;
+
+/// Most resource entries listed for one query
+const MAX_RESOURCE_RESULTS: usize = 30;
+
+/// A query matched against a command label
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FuzzyMatch {
+  score: i32,
+  /// Byte offsets of the matched characters
+  positions: Vec<usize>,
+}
+
+/// Score for a query character landing on `chars[idx]`. The first
+/// character, word starts and runs of consecutive matches score higher.
+fn char_bonus(chars: &[(usize, char)], idx: usize, consecutive: bool) -> i32 {
+  let mut bonus = 1;
+  if idx == 0 {
+    bonus += 8;
+  } else {
+    let (prev, cur) = (chars[idx - 1].1, chars[idx].1);
+    if !prev.is_alphanumeric() || (prev.is_lowercase() && cur.is_uppercase()) {
+      bonus += 6;
+    }
+  }
+  if consecutive {
+    bonus += 4;
+  }
+  bonus
+}
+
+/// Case-insensitive subsequence match of `query` in `text`, using the
+/// placement with the best score. Each character skipped between two
+/// matches costs a point. Whitespace in the query is ignored.
+fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+  let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).collect();
+  let chars: Vec<(usize, char)> = text.char_indices().collect();
+  let (n, m) = (query.len(), chars.len());
+  if n == 0 {
+    return Some(FuzzyMatch {
+      score: 0,
+      positions: Vec::new(),
+    });
+  }
+  if n > m {
+    return None;
+  }
+
+  // best[i][j]: top score with query[i] on chars[j]; from[i][j]: where
+  // query[i - 1] landed for that score.
+  let mut best: Vec<Vec<Option<i32>>> = vec![vec![None; m]; n];
+  let mut from = vec![vec![0usize; m]; n];
+  for (i, q) in query.iter().enumerate() {
+    for (j, &(_, c)) in chars.iter().enumerate().skip(i) {
+      if !c.to_lowercase().eq(q.to_lowercase()) {
+        continue;
+      }
+      if i == 0 {
+        best[0][j] = Some(char_bonus(&chars, j, false));
+        continue;
+      }
+      let mut top: Option<(i32, usize)> = None;
+      for (k, prev) in best[i - 1].iter().enumerate().take(j).skip(i - 1) {
+        let Some(prev) = prev else { continue };
+        let gap = i32::try_from(j - k - 1).unwrap_or(i32::MAX);
+        let score = prev + char_bonus(&chars, j, k + 1 == j) - gap;
+        if top.is_none_or(|(s, _)| score > s) {
+          top = Some((score, k));
+        }
+      }
+      if let Some((score, k)) = top {
+        best[i][j] = Some(score);
+        from[i][j] = k;
+      }
+    }
+  }
+
+  let (mut j, score) = best[n - 1]
+    .iter()
+    .enumerate()
+    .filter_map(|(j, s)| s.map(|s| (j, s)))
+    .max_by_key(|&(j, s)| (s, std::cmp::Reverse(j)))?;
+  let mut positions = vec![0; n];
+  for i in (0..n).rev() {
+    positions[i] = chars[j].0;
+    if i > 0 {
+      j = from[i][j];
+    }
+  }
+  Some(FuzzyMatch { score, positions })
+}
+
+/// Move `id` to the front of `recent`, keeping at most
+/// `MAX_RECENT_COMMANDS` entries.
+fn push_recent(recent: &mut Vec<String>, id: &str) {
+  recent.retain(|r| r != id);
+  recent.insert(0, id.to_string());
+  recent.truncate(MAX_RECENT_COMMANDS);
+}
+
 pub fn init(cx: &mut App) {
   cx.bind_keys([
     KeyBinding::new("escape", Cancel, Some(CONTEXT)),
@@ -57,6 +165,7 @@ pub enum PaletteAction {
   RefreshPods,
   RefreshDeployments,
   RefreshServices,
+  ToggleAutoRefresh,
 
   // Create/Dialog actions
   ShowPullImageDialog,
@@ -65,6 +174,8 @@ pub enum PaletteAction {
   ShowCreateMachineDialog,
   ShowCreateDeploymentDialog,
   ShowCreateServiceDialog,
+  ShowApplyYamlDialog,
+  ShowDockerContextSwitcher,
 
   // Machine actions (default profile)
   StartDefaultMachine,
@@ -79,7 +190,292 @@ pub enum PaletteAction {
   EnableKubernetes,
 
   // UI actions
+  ToggleSidebar,
+  ReloadThemes,
   ShowShortcuts,
+  ShowCommandLog,
+  GenerateDiagnosticReport,
+  ExportDiagnostics,
+}
+
+impl PaletteAction {
+  /// The sidebar group this action belongs to. Commands of hidden groups
+  /// are left out of the palette.
+  fn section(self) -> Option<SidebarSection> {
+    match self {
+      PaletteAction::Navigate(view) => view.section(),
+      PaletteAction::RefreshContainers
+      | PaletteAction::RefreshImages
+      | PaletteAction::RefreshVolumes
+      | PaletteAction::RefreshNetworks
+      | PaletteAction::ShowPullImageDialog
+      | PaletteAction::ShowCreateVolumeDialog
+      | PaletteAction::ShowCreateNetworkDialog
+      | PaletteAction::ShowDockerContextSwitcher => Some(SidebarSection::Docker),
+      PaletteAction::RefreshPods
+      | PaletteAction::RefreshDeployments
+      | PaletteAction::RefreshServices
+      | PaletteAction::ShowCreateDeploymentDialog
+      | PaletteAction::ShowCreateServiceDialog
+      | PaletteAction::ShowApplyYamlDialog
+      | PaletteAction::ResetKubernetes
+      | PaletteAction::EnableKubernetes => Some(SidebarSection::Kubernetes),
+      PaletteAction::RefreshMachines
+      | PaletteAction::ShowCreateMachineDialog
+      | PaletteAction::StartDefaultMachine
+      | PaletteAction::StopDefaultMachine
+      | PaletteAction::RestartDefaultMachine
+      | PaletteAction::UpdateDefaultMachineRuntime
+      | PaletteAction::UpdateAllMachines
+      | PaletteAction::PruneMachineCache => Some(SidebarSection::Runtimes),
+      PaletteAction::RefreshAll
+      | PaletteAction::ToggleAutoRefresh
+      | PaletteAction::ToggleSidebar
+      | PaletteAction::ReloadThemes
+      | PaletteAction::ShowShortcuts
+      | PaletteAction::ShowCommandLog
+      | PaletteAction::GenerateDiagnosticReport
+      | PaletteAction::ExportDiagnostics => None,
+    }
+  }
+}
+
+/// What picking a resource entry does once its view is open and the
+/// resource is selected
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResourceVerb {
+  Show,
+  Start,
+  Stop,
+  Restart,
+  Logs,
+  Terminal,
+}
+
+impl ResourceVerb {
+  fn label(self, kind: &str, name: &str) -> String {
+    match self {
+      ResourceVerb::Show => format!("Show {kind} {name}"),
+      ResourceVerb::Start => format!("Start {kind} {name}"),
+      ResourceVerb::Stop => format!("Stop {kind} {name}"),
+      ResourceVerb::Restart => format!("Restart {kind} {name}"),
+      ResourceVerb::Logs => format!("View logs for {kind} {name}"),
+      ResourceVerb::Terminal => format!("Open terminal in {kind} {name}"),
+    }
+  }
+
+  /// The "selected resource" action to run after selecting
+  pub fn action(self) -> Option<Box<dyn Action>> {
+    match self {
+      ResourceVerb::Show => None,
+      ResourceVerb::Start => Some(Box::new(StartSelected)),
+      ResourceVerb::Stop => Some(Box::new(StopSelected)),
+      ResourceVerb::Restart => Some(Box::new(RestartSelected)),
+      ResourceVerb::Logs => Some(Box::new(ViewLogs)),
+      ResourceVerb::Terminal => Some(Box::new(OpenTerminal)),
+    }
+  }
+}
+
+/// A live resource offered by the palette
+#[derive(Clone, Debug)]
+pub struct ResourceCommand {
+  pub label: String,
+  /// Second line, e.g. the image and status
+  pub detail: String,
+  pub icon: AppIcon,
+  pub view: CurrentView,
+  pub selection: Selection,
+  pub verb: ResourceVerb,
+}
+
+/// Resource entries for everything loaded in `state`, skipping hidden
+/// sidebar groups
+fn resource_commands(state: &DockerState, settings: &AppSettings) -> Vec<ResourceCommand> {
+  let mut entries = Vec::new();
+  let mut add = |kind: &str,
+                 name: &str,
+                 detail: String,
+                 icon: AppIcon,
+                 view: CurrentView,
+                 selection: Selection,
+                 verbs: &[ResourceVerb]| {
+    if !settings.view_visible(view) {
+      return;
+    }
+    for verb in verbs {
+      entries.push(ResourceCommand {
+        label: verb.label(kind, name),
+        detail: detail.clone(),
+        icon,
+        view,
+        selection: selection.clone(),
+        verb: *verb,
+      });
+    }
+  };
+
+  for container in &state.containers {
+    let verbs: &[ResourceVerb] = if container.state.is_running() {
+      &[
+        ResourceVerb::Show,
+        ResourceVerb::Logs,
+        ResourceVerb::Terminal,
+        ResourceVerb::Stop,
+        ResourceVerb::Restart,
+      ]
+    } else {
+      &[ResourceVerb::Show, ResourceVerb::Logs, ResourceVerb::Start]
+    };
+    add(
+      "container",
+      container.name.trim_start_matches('/'),
+      format!("{} - {}", container.image, container.status),
+      AppIcon::Container,
+      CurrentView::Containers,
+      Selection::Container(container.clone()),
+      verbs,
+    );
+  }
+  for image in &state.images {
+    let name = image.repo_tags.first().map_or(image.id.as_str(), String::as_str);
+    add(
+      "image",
+      name,
+      image.display_size(),
+      AppIcon::Image,
+      CurrentView::Images,
+      Selection::Image(image.clone()),
+      &[ResourceVerb::Show],
+    );
+  }
+  for volume in &state.volumes {
+    add(
+      "volume",
+      &volume.name,
+      volume.driver.clone(),
+      AppIcon::Volume,
+      CurrentView::Volumes,
+      Selection::Volume(volume.name.clone()),
+      &[ResourceVerb::Show],
+    );
+  }
+  for network in &state.networks {
+    add(
+      "network",
+      &network.name,
+      network.driver.clone(),
+      AppIcon::Network,
+      CurrentView::Networks,
+      Selection::Network(network.id.clone()),
+      &[ResourceVerb::Show],
+    );
+  }
+  for pod in &state.pods {
+    add(
+      "pod",
+      &pod.name,
+      format!("{} - {:?}", pod.namespace, pod.phase),
+      AppIcon::Pod,
+      CurrentView::Pods,
+      Selection::Pod {
+        name: pod.name.clone(),
+        namespace: pod.namespace.clone(),
+      },
+      &[
+        ResourceVerb::Show,
+        ResourceVerb::Logs,
+        ResourceVerb::Terminal,
+        ResourceVerb::Restart,
+      ],
+    );
+  }
+  for deployment in &state.deployments {
+    add(
+      "deployment",
+      &deployment.name,
+      format!(
+        "{} - {}/{} ready",
+        deployment.namespace, deployment.ready_replicas, deployment.replicas
+      ),
+      AppIcon::Deployment,
+      CurrentView::Deployments,
+      Selection::Deployment {
+        name: deployment.name.clone(),
+        namespace: deployment.namespace.clone(),
+      },
+      &[ResourceVerb::Show, ResourceVerb::Restart],
+    );
+  }
+  for service in &state.services {
+    add(
+      "service",
+      &service.name,
+      format!("{} - {}", service.namespace, service.service_type),
+      AppIcon::Service,
+      CurrentView::Services,
+      Selection::Service {
+        name: service.name.clone(),
+        namespace: service.namespace.clone(),
+      },
+      &[ResourceVerb::Show],
+    );
+  }
+  for machine in &state.machines {
+    let id = machine.id();
+    let verbs: &[ResourceVerb] = match (&id, machine.is_running()) {
+      (MachineId::Host, _) => &[ResourceVerb::Show],
+      (MachineId::Colima(_), true) => &[ResourceVerb::Show, ResourceVerb::Stop, ResourceVerb::Restart],
+      (MachineId::Colima(_), false) => &[ResourceVerb::Show, ResourceVerb::Start],
+    };
+    add(
+      "machine",
+      machine.name(),
+      machine.status_display().to_string(),
+      AppIcon::Machine,
+      CurrentView::Machines,
+      Selection::Machine(id),
+      verbs,
+    );
+  }
+  entries
+}
+
+/// A row in the palette
+#[derive(Clone)]
+enum PaletteItem {
+  Command(PaletteCommand),
+  Resource(Box<ResourceCommand>),
+}
+
+impl PaletteItem {
+  fn label(&self) -> &str {
+    match self {
+      PaletteItem::Command(cmd) => cmd.label,
+      PaletteItem::Resource(res) => &res.label,
+    }
+  }
+
+  fn subtitle(&self) -> SharedString {
+    match self {
+      PaletteItem::Command(cmd) => cmd.category.into(),
+      PaletteItem::Resource(res) => res.detail.clone().into(),
+    }
+  }
+
+  fn icon(&self) -> Icon {
+    match self {
+      PaletteItem::Command(cmd) => Icon::new(cmd.icon.clone()),
+      PaletteItem::Resource(res) => Icon::new(res.icon),
+    }
+  }
+
+  fn shortcut(&self) -> Option<&'static str> {
+    match self {
+      PaletteItem::Command(cmd) => cmd.shortcut,
+      PaletteItem::Resource(_) => None,
+    }
+  }
 }
 
 /// Event emitted when the command palette performs an action
@@ -87,6 +483,8 @@ pub enum PaletteAction {
 pub enum CommandPaletteEvent {
   Close,
   Action(PaletteAction),
+  /// Open the resource's view, select it and run its verb
+  Resource(Box<ResourceCommand>),
 }
 
 /// The command palette view
@@ -95,13 +493,13 @@ pub struct CommandPalette {
   input_state: Entity<InputState>,
   focus_handle: FocusHandle,
   selected_index: usize,
-  filtered_commands: Vec<PaletteCommand>,
+  items: Vec<PaletteItem>,
 }
 
 impl CommandPalette {
   pub fn new(window: &mut Window, cx: &mut Context<'_, Self>) -> Self {
     let focus_handle = cx.focus_handle();
-    let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Type a command..."));
+    let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Type a command or resource name..."));
 
     // Focus the input immediately
     input_state.update(cx, |input, cx| {
@@ -113,10 +511,10 @@ impl CommandPalette {
       input_state,
       focus_handle,
       selected_index: 0,
-      filtered_commands: Vec::new(),
+      items: Vec::new(),
     };
 
-    palette.filtered_commands = Self::filter_commands("");
+    palette.items = Self::search("", cx);
     palette
   }
 
@@ -397,6 +795,14 @@ impl CommandPalette {
         icon: IconName::Redo,
         action: PaletteAction::RefreshServices,
       },
+      PaletteCommand {
+        id: "toggle-auto-refresh",
+        label: "Pause / Resume Auto-Refresh",
+        shortcut: None,
+        category: "Refresh",
+        icon: IconName::Redo,
+        action: PaletteAction::ToggleAutoRefresh,
+      },
       // === CREATE/DIALOG ACTIONS ===
       PaletteCommand {
         id: "pull-image",
@@ -422,6 +828,14 @@ impl CommandPalette {
         icon: IconName::Plus,
         action: PaletteAction::ShowCreateNetworkDialog,
       },
+      PaletteCommand {
+        id: "switch-docker-context",
+        label: "Switch Docker Context",
+        shortcut: Some("Cmd+Shift+D"),
+        category: "Docker",
+        icon: IconName::Frame,
+        action: PaletteAction::ShowDockerContextSwitcher,
+      },
       PaletteCommand {
         id: "prune",
         label: "Prune Docker Resources",
@@ -504,6 +918,14 @@ impl CommandPalette {
         icon: IconName::Plus,
         action: PaletteAction::ShowCreateServiceDialog,
       },
+      PaletteCommand {
+        id: "apply-yaml",
+        label: "Apply YAML Manifest",
+        shortcut: None,
+        category: "Kubernetes",
+        icon: IconName::File,
+        action: PaletteAction::ShowApplyYamlDialog,
+      },
       PaletteCommand {
         id: "reset-kubernetes",
         label: "Reset Kubernetes Cluster",
@@ -521,6 +943,22 @@ impl CommandPalette {
         action: PaletteAction::EnableKubernetes,
       },
       // === UI ACTIONS ===
+      PaletteCommand {
+        id: "toggle-sidebar",
+        label: "Toggle Sidebar",
+        shortcut: Some("Cmd+B"),
+        category: "Navigation",
+        icon: IconName::PanelLeft,
+        action: PaletteAction::ToggleSidebar,
+      },
+      PaletteCommand {
+        id: "reload-themes",
+        label: "Reload Themes",
+        shortcut: None,
+        category: "Refresh",
+        icon: IconName::Palette,
+        action: PaletteAction::ReloadThemes,
+      },
       PaletteCommand {
         id: "show-shortcuts",
         label: "Show Keyboard Shortcuts",
@@ -529,68 +967,137 @@ impl CommandPalette {
         icon: IconName::Info,
         action: PaletteAction::ShowShortcuts,
       },
+      PaletteCommand {
+        id: "show-command-log",
+        label: "Show Command Log",
+        shortcut: None,
+        category: "Help",
+        icon: IconName::SquareTerminal,
+        action: PaletteAction::ShowCommandLog,
+      },
+      PaletteCommand {
+        id: "generate-diagnostic-report",
+        label: "Generate Diagnostic Report",
+        shortcut: None,
+        category: "Help",
+        icon: IconName::Inspector,
+        action: PaletteAction::GenerateDiagnosticReport,
+      },
+      PaletteCommand {
+        id: "export-diagnostics",
+        label: "Export Diagnostics (JSON)",
+        shortcut: None,
+        category: "Help",
+        icon: IconName::Inspector,
+        action: PaletteAction::ExportDiagnostics,
+      },
     ]
   }
 
+  /// Commands whose label fuzzy-matches `query`, best match first.
+  /// Commands matching only by category come last.
   fn filter_commands(query: &str) -> Vec<PaletteCommand> {
-    let query = query.to_lowercase();
+    let query = query.trim();
     if query.is_empty() {
       return Self::all_commands();
     }
 
-    let mut commands = Self::all_commands();
-    commands.retain(|cmd| {
-      let label = cmd.label.to_lowercase();
-      let category = cmd.category.to_lowercase();
-
-      // Simple fuzzy matching: check if query chars appear in order
-      if label.contains(&query) || category.contains(&query) {
-        return true;
-      }
+    let category_query = query.to_lowercase();
+    let mut scored: Vec<(i32, PaletteCommand)> = Self::all_commands()
+      .into_iter()
+      .filter_map(|cmd| {
+        let score = match fuzzy_match(query, cmd.label) {
+          Some(m) => m.score,
+          None if cmd.category.to_lowercase().contains(&category_query) => i32::MIN,
+          None => return None,
+        };
+        Some((score, cmd))
+      })
+      .collect();
+    scored.sort_by(|(a_score, a), (b_score, b)| b_score.cmp(a_score).then_with(|| a.label.cmp(b.label)));
+    scored.into_iter().map(|(_, cmd)| cmd).collect()
+  }
 
-      // Check if all query chars appear in order in the label
-      let mut query_chars = query.chars().peekable();
-      for c in label.chars() {
-        if query_chars.peek() == Some(&c) {
-          query_chars.next();
-        }
-      }
-      query_chars.peek().is_none()
+  /// Commands matching `query`, minus those of hidden sidebar groups.
+  /// Without a query, recently run commands lead.
+  fn visible_commands(query: &str, settings: &AppSettings) -> Vec<PaletteCommand> {
+    let mut commands = Self::filter_commands(query);
+    commands.retain(|cmd| {
+      cmd
+        .action
+        .section()
+        .is_none_or(|section| settings.section_visible(section))
     });
+    if query.trim().is_empty() {
+      commands.sort_by_key(|cmd| {
+        settings
+          .recent_commands
+          .iter()
+          .position(|id| id == cmd.id)
+          .unwrap_or(usize::MAX)
+      });
+    }
+    commands
+  }
 
-    // Sort by relevance (exact matches first, then by label)
-    commands.sort_by(|a, b| {
-      let a_label = a.label.to_lowercase();
-      let b_label = b.label.to_lowercase();
+  /// Live resources whose entry label fuzzy-matches `query`, best first
+  fn matching_resources(query: &str, state: &DockerState, settings: &AppSettings) -> Vec<(i32, ResourceCommand)> {
+    let mut scored: Vec<(i32, ResourceCommand)> = resource_commands(state, settings)
+      .into_iter()
+      .filter_map(|res| fuzzy_match(query, &res.label).map(|m| (m.score, res)))
+      .collect();
+    scored.sort_by(|(a_score, a), (b_score, b)| b_score.cmp(a_score).then_with(|| a.label.cmp(&b.label)));
+    scored.truncate(MAX_RESOURCE_RESULTS);
+    scored
+  }
 
-      // Exact prefix match gets priority
-      let a_starts = a_label.starts_with(&query);
-      let b_starts = b_label.starts_with(&query);
+  /// Commands and, once something is typed, resources matching `query`
+  fn search(query: &str, cx: &App) -> Vec<PaletteItem> {
+    let settings = settings_state(cx);
+    let settings = &settings.read(cx).settings;
+    let commands = Self::visible_commands(query, settings);
+    if query.trim().is_empty() {
+      return commands.into_iter().map(PaletteItem::Command).collect();
+    }
 
-      if a_starts && !b_starts {
-        std::cmp::Ordering::Less
-      } else if !a_starts && b_starts {
-        std::cmp::Ordering::Greater
-      } else {
-        a_label.cmp(&b_label)
-      }
-    });
+    let mut scored: Vec<(i32, PaletteItem)> = commands
+      .into_iter()
+      .map(|cmd| {
+        let score = fuzzy_match(query, cmd.label).map_or(i32::MIN, |m| m.score);
+        (score, PaletteItem::Command(cmd))
+      })
+      .collect();
+    scored.extend(
+      Self::matching_resources(query, docker_state(cx).read(cx), settings)
+        .into_iter()
+        .map(|(score, res)| (score, PaletteItem::Resource(Box::new(res)))),
+    );
+    // Stable, so equal scores keep commands ahead of resources
+    scored.sort_by(|(a, _), (b, _)| b.cmp(a));
+    scored.into_iter().map(|(_, item)| item).collect()
+  }
 
-    commands
+  /// Record `id` as the most recently run command. Nothing reacts to the
+  /// list while the palette is closed, so no `SettingsUpdated` is emitted.
+  fn remember_command(id: &str, cx: &mut App) {
+    settings_state(cx).update(cx, |state, cx| {
+      push_recent(&mut state.settings.recent_commands, id);
+      state.save_later(cx);
+    });
   }
 
   fn on_query_changed(&mut self, cx: &mut Context<'_, Self>) {
     self.query = self.input_state.read(cx).text().to_string();
-    self.filtered_commands = Self::filter_commands(&self.query);
+    self.items = Self::search(&self.query, cx);
     self.selected_index = 0;
     cx.notify();
   }
 
   fn select_up(&mut self, _: &SelectUp, _window: &mut Window, cx: &mut Context<'_, Self>) {
     cx.stop_propagation();
-    if !self.filtered_commands.is_empty() {
+    if !self.items.is_empty() {
       self.selected_index = if self.selected_index == 0 {
-        self.filtered_commands.len() - 1
+        self.items.len() - 1
       } else {
         self.selected_index - 1
       };
@@ -600,8 +1107,8 @@ impl CommandPalette {
 
   fn select_down(&mut self, _: &SelectDown, _window: &mut Window, cx: &mut Context<'_, Self>) {
     cx.stop_propagation();
-    if !self.filtered_commands.is_empty() {
-      self.selected_index = (self.selected_index + 1) % self.filtered_commands.len();
+    if !self.items.is_empty() {
+      self.selected_index = (self.selected_index + 1) % self.items.len();
       cx.notify();
     }
   }
@@ -618,9 +1125,18 @@ impl CommandPalette {
   }
 
   fn execute_selected(&mut self, cx: &mut Context<'_, Self>) {
-    if let Some(cmd) = self.filtered_commands.get(self.selected_index) {
-      cx.emit(CommandPaletteEvent::Action(cmd.action));
-      cx.emit(CommandPaletteEvent::Close);
+    match self.items.get(self.selected_index).cloned() {
+      Some(PaletteItem::Command(cmd)) => {
+        Self::remember_command(cmd.id, cx);
+        cx.emit(CommandPaletteEvent::Action(cmd.action));
+        cx.emit(CommandPaletteEvent::Close);
+      }
+      Some(PaletteItem::Resource(res)) => {
+        // Close first so the resource action runs with the app focused
+        cx.emit(CommandPaletteEvent::Close);
+        cx.emit(CommandPaletteEvent::Resource(res));
+      }
+      None => {}
     }
   }
 
@@ -642,7 +1158,6 @@ impl Render for CommandPalette {
   fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let colors = cx.theme().colors;
     let selected_idx = self.selected_index;
-    let commands = self.filtered_commands.clone();
     let view_size = window.viewport_size();
 
     // Check for input changes
@@ -650,6 +1165,8 @@ impl Render for CommandPalette {
     if current_text != self.query {
       self.on_query_changed(cx);
     }
+    let items = self.items.clone();
+    let query = self.query.clone();
 
     // Use anchored to position at top-left, then manually center
     anchored().position(point(px(0.), px(0.))).child(
@@ -706,23 +1223,38 @@ impl Render for CommandPalette {
                   .flex_1()
                   .overflow_hidden()
                   .py(px(8.))
-                  .when(commands.is_empty(), |el| {
+                  .when(items.is_empty(), |el| {
                     el.child(
                       div()
                         .px(px(16.))
                         .py(px(12.))
                         .text_sm()
                         .text_color(colors.muted_foreground)
-                        .child("No commands found"),
+                        .child("No commands or resources found"),
                     )
                   })
-                  .children(commands.iter().enumerate().map(|(idx, cmd)| {
+                  .children(items.iter().enumerate().map(|(idx, item)| {
                     let is_selected = idx == selected_idx;
-                    let shortcut = cmd.shortcut;
-                    let label = SharedString::from(cmd.label);
-                    let category = SharedString::from(cmd.category);
-                    let icon = cmd.icon.clone();
-                    let item_id = SharedString::from(cmd.id);
+                    let shortcut = item.shortcut();
+                    let highlight = HighlightStyle {
+                      font_weight: Some(FontWeight::BOLD),
+                      color: (!is_selected).then_some(colors.link),
+                      ..HighlightStyle::default()
+                    };
+                    let text = item.label().to_string();
+                    let highlights: Vec<_> = fuzzy_match(&query, &text)
+                      .map(|m| m.positions)
+                      .unwrap_or_default()
+                      .into_iter()
+                      .map(|pos| {
+                        let len = text[pos..].chars().next().map_or(1, char::len_utf8);
+                        (pos..pos + len, highlight)
+                      })
+                      .collect();
+                    let label = StyledText::new(text).with_highlights(highlights);
+                    let category = item.subtitle();
+                    let icon = item.icon();
+                    let item_id = ("palette-item", idx);
                     let group_name = format!("cmd-item-{idx}");
 
                     div()
@@ -757,7 +1289,7 @@ impl Render for CommandPalette {
                           .items_center()
                           .gap(px(12.))
                           .child(
-                            Icon::new(icon)
+                            icon
                               .size(px(16.))
                               .when(!is_selected, |el| el.text_color(colors.muted_foreground)),
                           )
@@ -842,6 +1374,7 @@ impl Render for CommandPalette {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::docker::{ContainerInfo, ContainerState};
 
   #[test]
   fn test_all_commands_not_empty() {
@@ -868,6 +1401,19 @@ mod tests {
     assert_eq!(filtered.len(), all.len());
   }
 
+  #[test]
+  fn test_visible_commands_skip_hidden_sections() {
+    let settings = AppSettings {
+      kubernetes_enabled: false,
+      show_docker_section: false,
+      ..AppSettings::default()
+    };
+    let visible = CommandPalette::visible_commands("", &settings);
+    assert!(visible.iter().all(|c| c.category != "Kubernetes"));
+    assert!(!visible.iter().any(|c| c.id == "nav-containers"));
+    assert!(visible.iter().any(|c| c.id == "nav-settings"));
+  }
+
   #[test]
   fn test_filter_commands_exact_match() {
     let filtered = CommandPalette::filter_commands("containers");
@@ -904,13 +1450,46 @@ mod tests {
 
   #[test]
   fn test_filter_commands_fuzzy_match() {
-    // "gcp" should fuzzy match "Go to Compose" (g...c...p in "Go to ComPose")
+    // g...c...p in "Go to ComPose"
     let filtered = CommandPalette::filter_commands("gcp");
-    // If fuzzy works, should find Go to Compose
-    let labels: Vec<&str> = filtered.iter().map(|c| c.label).collect();
-    // Check if at least some results found (fuzzy matching)
-    // The exact behavior depends on implementation
-    assert!(!filtered.is_empty() || labels.is_empty());
+    assert!(filtered.iter().any(|c| c.id == "nav-compose"));
+  }
+
+  #[test]
+  fn test_fuzzy_match_prefers_word_starts() {
+    let m = fuzzy_match("gc", "Go to Containers").unwrap();
+    assert_eq!(m.positions, vec![0, 6]);
+    // Prefix and consecutive matches outrank scattered ones
+    let prefix = fuzzy_match("con", "Containers").unwrap();
+    let inner = fuzzy_match("con", "Reconnect").unwrap();
+    let scattered = fuzzy_match("con", "Clean Orphan Networks").unwrap();
+    assert!(prefix.score > inner.score);
+    assert!(prefix.score > scattered.score);
+    assert!(fuzzy_match("xq", "Containers").is_none());
+    assert_eq!(fuzzy_match("GTC", "go to containers").unwrap().positions, vec![0, 3, 6]);
+  }
+
+  #[test]
+  fn test_visible_commands_recent_first() {
+    let settings = AppSettings {
+      recent_commands: vec!["show-shortcuts".to_string(), "refresh-all".to_string()],
+      ..AppSettings::default()
+    };
+    let visible = CommandPalette::visible_commands("", &settings);
+    assert_eq!(visible[0].id, "show-shortcuts");
+    assert_eq!(visible[1].id, "refresh-all");
+    assert_eq!(visible[2].id, "nav-containers");
+  }
+
+  #[test]
+  fn test_push_recent() {
+    let mut recent = Vec::new();
+    for id in ["a", "b", "c", "d", "e", "f"] {
+      push_recent(&mut recent, id);
+    }
+    assert_eq!(recent, vec!["f", "e", "d", "c", "b"]);
+    push_recent(&mut recent, "c");
+    assert_eq!(recent, vec!["c", "f", "e", "d", "b"]);
   }
 
   #[test]
@@ -988,4 +1567,37 @@ mod tests {
       "RefreshAll should have a shortcut"
     );
   }
+
+  #[test]
+  fn test_resource_commands_follow_container_state() {
+    let mut state = DockerState::new();
+    state.containers = vec![
+      ContainerInfo::for_test("/web", "nginx:latest", ContainerState::Running),
+      ContainerInfo::for_test("/db", "nginx:latest", ContainerState::Exited),
+    ];
+    let mut settings = AppSettings::default();
+    let labels: Vec<String> = resource_commands(&state, &settings)
+      .into_iter()
+      .map(|res| res.label)
+      .collect();
+    assert!(labels.contains(&"Stop container web".to_string()));
+    assert!(labels.contains(&"Open terminal in container web".to_string()));
+    assert!(!labels.contains(&"Start container web".to_string()));
+    assert!(labels.contains(&"Start container db".to_string()));
+    assert!(!labels.contains(&"Stop container db".to_string()));
+
+    // Hidden sections contribute nothing
+    settings.show_docker_section = false;
+    assert!(resource_commands(&state, &settings).is_empty());
+  }
+
+  #[test]
+  fn test_matching_resources_ranks_by_score() {
+    let mut state = DockerState::new();
+    state.containers = vec![ContainerInfo::for_test("/web", "nginx:latest", ContainerState::Running)];
+    let settings = AppSettings::default();
+    let matches = CommandPalette::matching_resources("stop web", &state, &settings);
+    assert_eq!(matches[0].1.label, "Stop container web");
+    assert_eq!(matches[0].1.verb, ResourceVerb::Stop);
+  }
 }
@@ -1,8 +1,13 @@
 //! The cluster anchor shown on every k8s view. Always visible (even with
 //! one or zero contexts) so "which cluster am I on" is never ambiguous —
-//! the same role Lens' cluster badge plays. The dropdown switches context
-//! and links straight into the Clusters manager, tying the whole k8s
-//! surface together as one app.
+//! the same role Lens' cluster badge plays. The dropdown switches context,
+//! copies the active context's name (for `kubectl --context`) and links
+//! straight into the Clusters manager, tying the whole k8s surface
+//! together as one app.
+//!
+//! The Docker context switcher plays the same role for the Docker views,
+//! flipping between `docker context ls` entries (Colima profiles, remote
+//! daemons).
 
 use gpui::{App, IntoElement};
 use gpui_component::{
@@ -11,6 +16,7 @@ use gpui_component::{
   menu::{DropdownMenu, PopupMenuItem},
 };
 
+use super::copy_to_clipboard;
 use crate::services;
 use crate::state::{CurrentView, docker_state};
 
@@ -46,6 +52,13 @@ pub fn render_context_selector(cx: &App) -> impl IntoElement {
           }
         }));
       }
+      if let Some(name) = current.clone() {
+        menu = menu
+          .separator()
+          .item(PopupMenuItem::new("Copy context name").on_click(move |_, window, cx| {
+            copy_to_clipboard(&name, window, cx);
+          }));
+      }
       menu
         .separator()
         .item(PopupMenuItem::new("Manage clusters…").on_click(|_, _, cx| {
@@ -53,3 +66,34 @@ pub fn render_context_selector(cx: &App) -> impl IntoElement {
         }))
     })
 }
+
+pub fn render_docker_context_selector(cx: &App) -> impl IntoElement {
+  let contexts = docker_state(cx).read(cx).docker_contexts.clone();
+  let current = contexts.iter().find(|c| c.current).map(|c| c.name.clone());
+
+  Button::new("docker-context-selector")
+    .label(current.clone().unwrap_or_else(|| "no context".to_string()))
+    .outline()
+    .small()
+    .dropdown_caret(true)
+    .tooltip("Docker context")
+    .dropdown_menu(move |menu, _window, _cx| {
+      let mut menu = menu;
+      for ctx in &contexts {
+        let name = ctx.name.clone();
+        let label = if ctx.current {
+          format!("● {name}")
+        } else {
+          format!("   {name}")
+        };
+        menu = menu.item(PopupMenuItem::new(label).on_click(move |_, _, cx| {
+          services::set_docker_context(name.clone(), cx);
+        }));
+      }
+      menu
+        .separator()
+        .item(PopupMenuItem::new("Reload contexts").on_click(|_, _, cx| {
+          services::list_docker_contexts(cx);
+        }))
+    })
+}
@@ -0,0 +1,30 @@
+use std::rc::Rc;
+
+use gpui::{App, ClipboardItem, ElementId, SharedString, Window};
+use gpui_component::{
+  Icon, Sizable, WindowExt,
+  button::{Button, ButtonVariants},
+  notification::NotificationType,
+};
+
+use crate::assets::AppIcon;
+
+/// `on_copy` callback shared by the detail panels. Receives the full text.
+pub type CopyCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
+
+/// Small ghost icon button that hands `text` to `on_copy`. Pass the
+/// untruncated value — the row can still display a shortened form.
+pub fn copy_button(id: impl Into<ElementId>, text: String, on_copy: CopyCallback) -> Button {
+  Button::new(id)
+    .icon(Icon::new(AppIcon::Copy))
+    .ghost()
+    .xsmall()
+    .tooltip("Copy to clipboard")
+    .on_click(move |_ev, window, cx| on_copy(&text, window, cx))
+}
+
+/// Default `on_copy` handler: write to the clipboard and confirm with a toast.
+pub fn copy_to_clipboard(text: &str, window: &mut Window, cx: &mut App) {
+  cx.write_to_clipboard(ClipboardItem::new_string(text.to_string()));
+  window.push_notification((NotificationType::Success, SharedString::from("Copied")), cx);
+}
@@ -0,0 +1,101 @@
+//! Describe tab shared by the service and deployment detail views
+//!
+//! Shows `kubectl describe` output in the same read-only, searchable editor
+//! as the pod Describe tab.
+
+use gpui::{App, Div, Entity, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+
+/// `kubectl describe` output for one object and the editor showing it
+#[derive(Default)]
+pub struct DescribeOutput {
+  content: String,
+  editor: Option<Entity<InputState>>,
+  last_synced: String,
+}
+
+impl DescribeOutput {
+  pub fn set(&mut self, describe: &str) {
+    describe.clone_into(&mut self.content);
+  }
+
+  /// Drop the output, e.g. when another object is selected
+  pub fn clear(&mut self) {
+    self.content.clear();
+    self.last_synced.clear();
+  }
+
+  /// Create the editor on first use and push new output into it. Call from
+  /// `render`, where the window is available.
+  pub fn sync_editor(&mut self, window: &mut Window, cx: &mut App) {
+    let editor = self.editor.get_or_insert_with(|| {
+      cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .code_editor("yaml")
+          .line_number(true)
+          .searchable(true)
+          .soft_wrap(false)
+      })
+    });
+    if !self.content.is_empty() && self.last_synced != self.content {
+      let content = self.content.clone();
+      editor.update(cx, |state, cx| state.set_value(content, window, cx));
+      self.last_synced.clone_from(&self.content);
+    }
+  }
+}
+
+/// Render `output` below a toolbar with a refresh button
+pub fn render_describe_tab(
+  output: &DescribeOutput,
+  on_refresh: impl Fn(&mut Window, &mut App) + 'static,
+  cx: &App,
+) -> Div {
+  let colors = &cx.theme().colors;
+
+  let (Some(editor), false) = (&output.editor, output.content.is_empty()) else {
+    return v_flex()
+      .size_full()
+      .p(px(16.))
+      .child(div().text_sm().text_color(colors.muted_foreground).child("Loading..."));
+  };
+
+  let toolbar = h_flex()
+    .w_full()
+    .px(px(12.))
+    .py(px(6.))
+    .items_center()
+    .justify_between()
+    .border_b_1()
+    .border_color(colors.border)
+    .child(
+      div()
+        .text_xs()
+        .text_color(colors.muted_foreground)
+        .child("Output of kubectl describe, including recent events."),
+    )
+    .child(
+      Button::new("describe-refresh")
+        .icon(Icon::new(AppIcon::Refresh))
+        .ghost()
+        .compact()
+        .on_click(move |_ev, window, cx| on_refresh(window, cx)),
+    );
+
+  v_flex().size_full().child(toolbar).child(
+    div()
+      .flex_1()
+      .min_h_0()
+      .child(Input::new(editor).size_full().appearance(false).disabled(true)),
+  )
+}
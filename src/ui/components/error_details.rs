@@ -0,0 +1,77 @@
+//! Collapsible raw error text, shown under a friendly failure message
+
+use gpui::{App, ElementId, IntoElement, RenderOnce, SharedString, Window, div, prelude::*, px};
+use gpui_component::{h_flex, scroll::ScrollableElement, theme::ActiveTheme, v_flex};
+
+use super::copy_to_clipboard;
+
+/// "Show details" toggle that expands to the raw error, selectable and
+/// copyable. Stays expanded while the element is on screen.
+#[derive(IntoElement)]
+pub struct ErrorDetails {
+  id: ElementId,
+  error: SharedString,
+}
+
+impl ErrorDetails {
+  pub fn new(id: impl Into<ElementId>, error: impl Into<SharedString>) -> Self {
+    Self {
+      id: id.into(),
+      error: error.into(),
+    }
+  }
+}
+
+impl RenderOnce for ErrorDetails {
+  fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+    let expanded = window.use_keyed_state(self.id.clone(), cx, |_, _| false);
+    let shown = *expanded.read(cx);
+    let colors = &cx.theme().colors;
+    let error = self.error.clone();
+
+    v_flex()
+      .mt(px(4.))
+      .gap(px(4.))
+      .child(
+        h_flex()
+          .gap(px(12.))
+          .text_xs()
+          .text_color(colors.link)
+          .child(
+            div()
+              .id(self.id.clone())
+              .cursor_pointer()
+              .child(if shown { "Hide details" } else { "Show details" })
+              .on_click(move |_ev, _window, cx| {
+                expanded.update(cx, |expanded, cx| {
+                  *expanded = !*expanded;
+                  cx.notify();
+                });
+              }),
+          )
+          .when(shown, |el| {
+            el.child(
+              div()
+                .id("copy-error")
+                .cursor_pointer()
+                .child("Copy")
+                .on_click(move |_ev, window, cx| copy_to_clipboard(&error, window, cx)),
+            )
+          }),
+      )
+      .when(shown, |el| {
+        el.child(
+          div()
+            .max_h(px(160.))
+            .overflow_y_scrollbar()
+            .p(px(6.))
+            .rounded(px(4.))
+            .bg(colors.background)
+            .font_family("monospace")
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child(self.error),
+        )
+      })
+  }
+}
@@ -0,0 +1,145 @@
+//! Events tab shared by the pod, deployment and service detail views
+//!
+//! Shows the events that reference one object, most recent first, with
+//! warnings highlighted so scheduling and crash-loop causes stand out.
+
+use gpui::{App, Div, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon,
+  button::{Button, ButtonVariants},
+  h_flex,
+  scroll::ScrollableElement,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::assets::AppIcon;
+use crate::kubernetes::EventInfo;
+
+/// Events fetched for one object
+#[derive(Debug, Clone, Default)]
+pub struct ResourceEvents {
+  pub loading: bool,
+  pub events: Vec<EventInfo>,
+  pub error: Option<String>,
+}
+
+impl ResourceEvents {
+  /// Mark a fetch in flight, keeping the last result on screen
+  pub fn start_loading(&mut self) {
+    self.loading = true;
+  }
+
+  pub fn set(&mut self, events: &[EventInfo], error: Option<&str>) {
+    self.loading = false;
+    self.events = events.to_vec();
+    self.error = error.map(str::to_string);
+  }
+}
+
+/// Render `state` as a list with a refresh button
+pub fn render_event_list(
+  kind: &str,
+  state: &ResourceEvents,
+  on_refresh: impl Fn(&mut Window, &mut App) + 'static,
+  cx: &App,
+) -> Div {
+  let colors = &cx.theme().colors;
+  let warnings = state.events.iter().filter(|e| e.is_warning()).count();
+
+  let summary = if state.loading && state.events.is_empty() {
+    "Loading events...".to_string()
+  } else if warnings > 0 {
+    format!("{} event(s), {warnings} warning(s)", state.events.len())
+  } else {
+    format!("{} event(s)", state.events.len())
+  };
+
+  let toolbar = h_flex()
+    .w_full()
+    .px(px(12.))
+    .py(px(6.))
+    .items_center()
+    .justify_between()
+    .border_b_1()
+    .border_color(colors.border)
+    .child(div().text_xs().text_color(colors.muted_foreground).child(summary))
+    .child(
+      Button::new("events-refresh")
+        .icon(Icon::new(AppIcon::Refresh))
+        .ghost()
+        .compact()
+        .loading(state.loading)
+        .on_click(move |_ev, window, cx| on_refresh(window, cx)),
+    );
+
+  let body = if let Some(ref error) = state.error {
+    div()
+      .p(px(16.))
+      .text_sm()
+      .text_color(colors.danger)
+      .child(format!("Failed to load events: {error}"))
+      .into_any_element()
+  } else if state.events.is_empty() && !state.loading {
+    div()
+      .size_full()
+      .flex()
+      .items_center()
+      .justify_center()
+      .text_sm()
+      .text_color(colors.muted_foreground)
+      .child(format!("No recent events for this {kind}"))
+      .into_any_element()
+  } else {
+    let rows = state.events.iter().map(|e| {
+      let color = if e.is_warning() {
+        colors.warning
+      } else {
+        colors.muted_foreground
+      };
+      v_flex()
+        .w_full()
+        .gap(px(2.))
+        .py(px(8.))
+        .px(px(8.))
+        .border_b_1()
+        .border_color(colors.border)
+        .when(e.is_warning(), |el| el.bg(colors.warning.opacity(0.06)))
+        .child(
+          h_flex()
+            .w_full()
+            .gap(px(8.))
+            .items_center()
+            .child(
+              div()
+                .px(px(6.))
+                .py(px(1.))
+                .rounded(px(4.))
+                .bg(color.opacity(0.15))
+                .text_xs()
+                .text_color(color)
+                .child(e.reason.clone()),
+            )
+            .child(
+              div()
+                .flex_1()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(format!("{} · x{}", e.age, e.count)),
+            ),
+        )
+        .child(div().text_sm().text_color(colors.foreground).child(e.message.clone()))
+    });
+    div()
+      .size_full()
+      .p(px(8.))
+      .overflow_y_scrollbar()
+      .child(v_flex().w_full().children(rows))
+      .into_any_element()
+  };
+
+  v_flex()
+    .size_full()
+    .child(toolbar)
+    .child(div().flex_1().min_h_0().child(body))
+}
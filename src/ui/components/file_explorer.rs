@@ -150,6 +150,18 @@ type SymlinkClickCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 /// Callback for opening a path in external editor (path, `is_directory`)
 type OpenInEditorCallback = Rc<dyn Fn(&(String, bool), &mut Window, &mut App) + 'static>;
 
+/// Callback for downloading a path to the host (path, `is_directory`)
+type DownloadCallback = Rc<dyn Fn(&(String, bool), &mut Window, &mut App) + 'static>;
+
+/// Callback for uploading a host file into a directory (target directory)
+type UploadCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
+
+/// Callback for entering or leaving edit mode in the file viewer
+type EditModeCallback = Rc<dyn Fn(&(), &mut Window, &mut App) + 'static>;
+
+/// Callback for writing the edited file back (file path)
+type SaveFileCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
+
 /// State for the file explorer
 #[derive(Debug, Clone, Default)]
 pub struct FileExplorerState {
@@ -167,6 +179,8 @@ pub struct FileExplorerState {
   pub file_content_loading: bool,
   /// Error loading file content
   pub file_content_error: Option<String>,
+  /// File viewer is in edit mode
+  pub editing: bool,
 }
 
 impl FileExplorerState {
@@ -225,6 +239,11 @@ pub struct FileExplorer<F: FileEntry + 'static> {
   on_close_viewer: Option<CloseViewerCallback>,
   on_symlink_click: Option<SymlinkClickCallback>,
   on_open_in_editor: Option<OpenInEditorCallback>,
+  on_download: Option<DownloadCallback>,
+  on_upload: Option<UploadCallback>,
+  on_edit_file: Option<EditModeCallback>,
+  on_cancel_edit: Option<EditModeCallback>,
+  on_save_file: Option<SaveFileCallback>,
 }
 
 impl<F: FileEntry + 'static> FileExplorer<F> {
@@ -239,6 +258,11 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
       on_close_viewer: None,
       on_symlink_click: None,
       on_open_in_editor: None,
+      on_download: None,
+      on_upload: None,
+      on_edit_file: None,
+      on_cancel_edit: None,
+      on_save_file: None,
     }
   }
 
@@ -305,6 +329,53 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
     self
   }
 
+  /// Set callback for downloading a file or folder to the host
+  /// Callback receives &(path, `is_directory`)
+  pub fn on_download<C>(mut self, callback: C) -> Self
+  where
+    C: Fn(&(String, bool), &mut Window, &mut App) + 'static,
+  {
+    self.on_download = Some(Rc::new(callback));
+    self
+  }
+
+  /// Set callback for uploading a host file into a directory
+  pub fn on_upload<C>(mut self, callback: C) -> Self
+  where
+    C: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_upload = Some(Rc::new(callback));
+    self
+  }
+
+  /// Make the file viewer editable. Without a save callback the viewer
+  /// stays read-only.
+  pub fn on_save_file<C>(mut self, callback: C) -> Self
+  where
+    C: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_save_file = Some(Rc::new(callback));
+    self
+  }
+
+  /// Set callback for the viewer's Edit button
+  pub fn on_edit_file<C>(mut self, callback: C) -> Self
+  where
+    C: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_edit_file = Some(Rc::new(callback));
+    self
+  }
+
+  /// Set callback for discarding edits
+  pub fn on_cancel_edit<C>(mut self, callback: C) -> Self
+  where
+    C: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_cancel_edit = Some(Rc::new(callback));
+    self
+  }
+
   /// Render the file explorer
   pub fn render(self, _window: &mut Window, cx: &App) -> gpui::AnyElement {
     let colors = &cx.theme().colors;
@@ -333,10 +404,13 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
     let on_file_select = self.on_file_select.clone();
     let on_symlink_click = self.on_symlink_click.clone();
     let on_open_in_editor = self.on_open_in_editor.clone();
+    let on_download = self.on_download.clone();
+    let on_upload = self.on_upload.clone();
 
     // Calculate parent path
     let parent_path = calculate_parent_path(current_path);
     let editor_path = current_path.clone();
+    let upload_path = current_path.clone();
 
     let mut file_list = v_flex().gap(px(2.));
 
@@ -351,9 +425,11 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
       let select_cb = on_file_select.clone();
       let symlink_cb = on_symlink_click.clone();
       let editor_cb = on_open_in_editor.clone();
+      let download_cb = on_download.clone();
+      let upload_cb = on_upload.clone();
 
       // Build context menu for this file
-      let menu = Self::build_file_context_menu(&file_path_menu, is_dir, editor_cb, cx);
+      let menu = Self::build_file_context_menu(&file_path_menu, is_dir, editor_cb, download_cb, upload_cb, cx);
 
       file_list = file_list.child(
         h_flex()
@@ -426,6 +502,18 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
               .text_color(colors.secondary_foreground)
               .child(current_path.clone()),
           )
+          .when_some(on_upload, move |el, cb| {
+            el.child(
+              Button::new("upload-here")
+                .icon(IconName::ArrowUp)
+                .label("Upload")
+                .ghost()
+                .compact()
+                .on_click(move |_ev, window, cx| {
+                  cb(&upload_path, window, cx);
+                }),
+            )
+          })
           .when_some(on_open_in_editor, move |el, cb| {
             el.child(
               Button::new("open-in-editor")
@@ -543,6 +631,17 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
     let on_open_in_editor = self.on_open_in_editor.clone();
     let file_path_for_editor = file_path.to_string();
 
+    // Editing needs a save handler and text that round-trips unchanged
+    let editable = self.on_save_file.is_some()
+      && !is_loading
+      && !has_error
+      && crate::docker::is_editable_file_content(&self.state.file_content);
+    let editing = editable && self.state.editing;
+    let on_edit = self.on_edit_file.clone().filter(|_| editable && !editing);
+    let on_cancel_edit = self.on_cancel_edit.clone().filter(|_| editing);
+    let on_save = self.on_save_file.clone().filter(|_| editing);
+    let file_path_for_save = file_path.to_string();
+
     // Extract file name from path
     let file_name = file_path.rsplit('/').next().unwrap_or(file_path).to_string();
 
@@ -588,6 +687,39 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
               .text_ellipsis()
               .child(file_path.to_string()),
           )
+          .when_some(on_edit, |el, cb| {
+            el.child(
+              Button::new("edit-file")
+                .label("Edit")
+                .ghost()
+                .compact()
+                .on_click(move |_ev, window, cx| {
+                  cb(&(), window, cx);
+                }),
+            )
+          })
+          .when_some(on_cancel_edit, |el, cb| {
+            el.child(
+              Button::new("cancel-edit-file")
+                .label("Cancel")
+                .ghost()
+                .compact()
+                .on_click(move |_ev, window, cx| {
+                  cb(&(), window, cx);
+                }),
+            )
+          })
+          .when_some(on_save, move |el, cb| {
+            el.child(
+              Button::new("save-file")
+                .label("Save")
+                .primary()
+                .compact()
+                .on_click(move |_ev, window, cx| {
+                  cb(&file_path_for_save, window, cx);
+                }),
+            )
+          })
           // Open in Editor button
           .when_some(on_open_in_editor, move |el, cb| {
             el.child(
@@ -662,10 +794,12 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
       .when(!is_loading && !has_error, |el| {
         if let Some(ref editor) = self.file_content_editor {
           el.child(
-            div()
-              .flex_1()
-              .min_h_0()
-              .child(Input::new(editor).size_full().appearance(false)),
+            div().flex_1().min_h_0().child(
+              Input::new(editor)
+                .size_full()
+                .appearance(false)
+                .disabled(self.on_save_file.is_some() && !editing),
+            ),
           )
         } else {
           // Fallback if no editor
@@ -691,6 +825,8 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
     path: &str,
     is_dir: bool,
     on_open_in_editor: Option<OpenInEditorCallback>,
+    on_download: Option<DownloadCallback>,
+    on_upload: Option<UploadCallback>,
     _cx: &App,
   ) -> impl IntoElement {
     let path_owned = path.to_string();
@@ -725,6 +861,31 @@ impl<F: FileEntry + 'static> FileExplorer<F> {
           );
         }
 
+        // Download action (directories come down as a .tar)
+        if let Some(ref cb) = on_download {
+          let cb = cb.clone();
+          let path = path_owned.clone();
+          let label = if is_dir { "Download as .tar..." } else { "Download..." };
+          menu = menu.item(PopupMenuItem::new(label).icon(Icon::new(IconName::ArrowDown)).on_click(
+            move |_, window, cx| {
+              cb(&(path.clone(), is_dir), window, cx);
+            },
+          ));
+        }
+
+        // Upload into this folder
+        if is_dir && let Some(ref cb) = on_upload {
+          let cb = cb.clone();
+          let path = path_owned.clone();
+          menu = menu.item(
+            PopupMenuItem::new("Upload Here...")
+              .icon(Icon::new(IconName::ArrowUp))
+              .on_click(move |_, window, cx| {
+                cb(&path, window, cx);
+              }),
+          );
+        }
+
         // Copy Path action
         menu = menu.item(
           PopupMenuItem::new("Copy Path")
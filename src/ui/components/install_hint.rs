@@ -1,6 +1,6 @@
 //! Shared "install missing tool" + generic error panel widgets.
 //!
-//! Used by image vulnerability scan failures (Trivy) and Dockerfile
+//! Used by image vulnerability scan failures (Docker Scout / Trivy) and Dockerfile
 //! lint failures (Hadolint) so the same structured warning card +
 //! per-platform copy-able commands render everywhere a CLI binary may
 //! be missing.
@@ -120,7 +120,7 @@ pub fn render_install_hint(hint: &InstallHint, cx: &App) -> gpui::Div {
   v_flex().w_full().p(px(24.)).items_center().child(card)
 }
 
-/// Generic dressed-up error panel for non-install failures (e.g. trivy
+/// Generic dressed-up error panel for non-install failures (e.g. a scanner
 /// returning a non-zero exit code on a junk image).
 pub fn render_error_panel(headline: &str, err: &str, colors: &ThemeColor) -> gpui::Div {
   let err_owned = err.to_string();
@@ -0,0 +1,378 @@
+//! Cached rows for the resource list delegates.
+//!
+//! `List` only renders the rows inside the viewport, but it asks the
+//! delegate for `items_count` every frame and calls `render_item` once per
+//! visible row. Delegates that filter the full resource vector in those
+//! hooks turn every frame into O(rows × visible), so instead they keep the
+//! filtered rows cached and rebuild them only when the data or the search
+//! query changes.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{App, Context, ParentElement, Styled, Task, Timer, Window, div, prelude::*, px};
+use gpui_component::{
+  Icon, IconName, IndexPath, Selectable, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  list::{ListDelegate, ListState},
+  theme::ActiveTheme,
+};
+
+use crate::assets::AppIcon;
+use crate::state::settings_state;
+
+/// A list delegate that serves rows from a cache.
+pub trait CachedListDelegate: ListDelegate {
+  /// Recompute the cached rows from the source data and search query.
+  fn rebuild_rows(&mut self, cx: &App);
+
+  /// Number of cached rows across all sections.
+  fn row_count(&self) -> usize;
+
+  /// Position of the globally selected resource in the cached rows.
+  fn selected_row(&self, cx: &App) -> Option<IndexPath>;
+
+  /// Flat position of `ix`, counting rows in earlier sections.
+  fn flat_row(&self, ix: IndexPath) -> usize {
+    ix.row
+  }
+}
+
+/// Delay between the last keystroke in a list's filter box and the row
+/// rebuild, so typing a word filters once instead of once per letter.
+pub const SEARCH_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// A list's filter query, applied `SEARCH_DEBOUNCE` after typing pauses.
+#[derive(Default)]
+pub struct SearchDebounce {
+  query: String,
+  /// Query waiting out `SEARCH_DEBOUNCE` before the rows are rebuilt.
+  pending: Option<(String, Task<()>)>,
+}
+
+impl SearchDebounce {
+  /// The query the rows are currently filtered by
+  pub fn query(&self) -> &str {
+    &self.query
+  }
+
+  /// Forget the query and drop any pending rebuild
+  pub fn clear(&mut self) {
+    self.query.clear();
+    self.pending = None;
+  }
+
+  /// Feed the filter box's current `text`. Once it stands for
+  /// `SEARCH_DEBOUNCE`, it becomes the query and `apply` rebuilds the rows;
+  /// a newer keystroke replaces (and so cancels) the pending rebuild.
+  /// `field` finds this debouncer on the view when the timer fires.
+  pub fn sync<V: 'static>(
+    &mut self,
+    text: String,
+    field: fn(&mut V) -> &mut SearchDebounce,
+    cx: &mut Context<'_, V>,
+    apply: impl FnOnce(&mut V, String, &mut Context<'_, V>) + 'static,
+  ) {
+    let pending = self.pending.as_ref().map(|(text, _)| text);
+    if (text == self.query && pending.is_none()) || pending == Some(&text) {
+      return;
+    }
+    let query = text.clone();
+    let task = cx.spawn(async move |this, cx| {
+      Timer::after(SEARCH_DEBOUNCE).await;
+      let _ = this.update(cx, |this, cx| {
+        let search = field(this);
+        search.pending = None;
+        query.clone_into(&mut search.query);
+        apply(this, query, cx);
+        cx.notify();
+      });
+    });
+    self.pending = Some((text, task));
+  }
+}
+
+/// Case-insensitive label filter: `query` (already lowercased) matches a
+/// key, a value, or a whole `key=value` pair.
+pub fn labels_match(labels: &HashMap<String, String>, query: &str) -> bool {
+  labels.iter().any(|(k, v)| {
+    let k = k.to_lowercase();
+    let v = v.to_lowercase();
+    k.contains(query) || v.contains(query) || format!("{k}={v}").contains(query)
+  })
+}
+
+/// `label:key` or `label:key=value` term typed into a list's filter box.
+/// Takes the already-lowercased query; `None` when it isn't a label term.
+pub fn parse_label_filter(query: &str) -> Option<(&str, Option<&str>)> {
+  let term = query.strip_prefix("label:")?.trim();
+  if term.is_empty() {
+    return None;
+  }
+  Some(match term.split_once('=') {
+    Some((key, value)) => (key.trim(), Some(value.trim())),
+    None => (term, None),
+  })
+}
+
+/// Exact (case-insensitive) label match for a parsed `label:` term: the
+/// key must be present and, when given, carry exactly `value`.
+pub fn labels_match_filter(labels: &HashMap<String, String>, key: &str, value: Option<&str>) -> bool {
+  labels
+    .iter()
+    .any(|(k, v)| k.to_lowercase() == key && value.is_none_or(|want| v.to_lowercase() == want))
+}
+
+/// Sort picked from a list's sort header: a column key and direction.
+/// `None` in the list state means the list's default order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnSort<K> {
+  pub key: K,
+  pub descending: bool,
+}
+
+impl<K: Copy + PartialEq> ColumnSort<K> {
+  /// Clicking a column cycles ascending → descending → default order.
+  /// Clicking a different column starts it ascending.
+  pub fn cycle(current: Option<Self>, key: K) -> Option<Self> {
+    match current {
+      Some(sort) if sort.key == key && !sort.descending => Some(Self { key, descending: true }),
+      Some(sort) if sort.key == key => None,
+      _ => Some(Self { key, descending: false }),
+    }
+  }
+
+  /// Orient an ascending comparison for this sort's direction.
+  pub fn apply(self, ordering: Ordering) -> Ordering {
+    if self.descending { ordering.reverse() } else { ordering }
+  }
+}
+
+/// Row of column buttons under a list toolbar. The active column shows its
+/// direction; clicking reports the key for `ColumnSort::cycle`.
+pub fn render_sort_header<K: Copy + PartialEq + 'static>(
+  id: &'static str,
+  columns: &[(K, &'static str)],
+  current: Option<ColumnSort<K>>,
+  on_click: impl Fn(&K, &mut Window, &mut App) + 'static,
+  cx: &App,
+) -> gpui::Div {
+  let colors = &cx.theme().colors;
+  let on_click = Rc::new(on_click);
+  h_flex()
+    .w_full()
+    .px(px(12.))
+    .py(px(4.))
+    .gap(px(4.))
+    .items_center()
+    .border_b_1()
+    .border_color(colors.border)
+    .child(
+      div()
+        .text_xs()
+        .text_color(colors.muted_foreground)
+        .mr(px(4.))
+        .child("Sort"),
+    )
+    .children(columns.iter().enumerate().map(|(i, (key, label))| {
+      let key = *key;
+      let active = current.filter(|s| s.key == key);
+      let on_click = on_click.clone();
+      Button::new((id, i))
+        .label(*label)
+        .ghost()
+        .xsmall()
+        .selected(active.is_some())
+        .when_some(active, |b, sort| {
+          b.icon(if sort.descending {
+            IconName::ArrowDown
+          } else {
+            IconName::ArrowUp
+          })
+        })
+        .on_click(move |_ev, window, cx| on_click(&key, window, cx))
+    }))
+}
+
+/// Bar above a list while rows are ticked for bulk delete: the count, a
+/// Clear button and Delete, confirmed first when `confirm_destructive` is on.
+pub fn render_bulk_delete_bar(
+  kind: &'static str,
+  count: usize,
+  body: &'static str,
+  on_clear: fn(&mut App),
+  on_delete: fn(&mut App),
+  cx: &App,
+) -> gpui::Div {
+  let colors = &cx.theme().colors;
+  h_flex()
+    .w_full()
+    .px(px(12.))
+    .py(px(6.))
+    .gap(px(8.))
+    .items_center()
+    .bg(colors.sidebar)
+    .border_b_1()
+    .border_color(colors.border)
+    .child(
+      div()
+        .flex_1()
+        .text_xs()
+        .text_color(colors.muted_foreground)
+        .child(format!("{count} selected")),
+    )
+    .child(
+      Button::new("bulk-clear")
+        .label("Clear")
+        .ghost()
+        .xsmall()
+        .on_click(move |_, _, cx| on_clear(cx)),
+    )
+    .child(
+      Button::new("bulk-delete")
+        .label("Delete")
+        .icon(Icon::new(AppIcon::Trash))
+        .danger()
+        .xsmall()
+        .on_click(move |_, window, cx| {
+          if settings_state(cx).read(cx).settings.confirm_destructive {
+            crate::ui::dialogs::open_confirm_dialog(
+              format!("Delete {count} {kind}?"),
+              body,
+              None,
+              "Delete",
+              move |_window, cx| on_delete(cx),
+              window,
+              cx,
+            );
+          } else {
+            on_delete(cx);
+          }
+        }),
+    )
+}
+
+/// Scroll offset that keeps a row at the same place on screen after it
+/// moved from row `before` to row `after`. Offsets are negative downwards.
+#[allow(clippy::cast_precision_loss)]
+fn anchored_offset(offset_y: f32, row_height: f32, before: usize, after: usize) -> f32 {
+  let shift = (after as f32 - before as f32) * row_height;
+  (offset_y - shift).min(0.0)
+}
+
+/// Rebuild a list's cached rows after its data changed.
+///
+/// The keyboard cursor follows the globally selected resource to its new
+/// row, and when rows were inserted or removed above it the scroll offset
+/// shifts by the same amount so the selection doesn't jump on screen.
+pub fn refresh_rows<D: CachedListDelegate>(
+  state: &mut ListState<D>,
+  window: &mut Window,
+  cx: &mut Context<'_, ListState<D>>,
+) {
+  let old_count = state.delegate().row_count();
+  let before = state
+    .delegate()
+    .selected_row(cx)
+    .map(|ix| state.delegate().flat_row(ix));
+
+  state.delegate_mut().rebuild_rows(cx);
+
+  let selected = state.delegate().selected_row(cx);
+  let after = selected.map(|ix| state.delegate().flat_row(ix));
+
+  if let (Some(before), Some(after)) = (before, after)
+    && before != after
+    && old_count > 0
+  {
+    // Rows share one height, so measure it from the current content size.
+    let handle = state.scroll_handle().base_handle();
+    let content = handle.max_offset().height + handle.bounds().size.height;
+    #[allow(clippy::cast_precision_loss)]
+    let row_height = f32::from(content) / old_count as f32;
+    let mut offset = handle.offset();
+    offset.y = px(anchored_offset(f32::from(offset.y), row_height, before, after));
+    handle.set_offset(offset);
+  }
+
+  if state.selected_index() != selected {
+    state.set_selected_index(selected, window, cx);
+  }
+  cx.notify();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_anchored_offset_follows_moved_row() {
+    // Two rows inserted above the selection: scroll down by two rows.
+    assert!((anchored_offset(-100.0, 40.0, 5, 7) - -180.0).abs() < f32::EPSILON);
+    // One row removed above: scroll up by one row.
+    assert!((anchored_offset(-100.0, 40.0, 5, 4) - -60.0).abs() < f32::EPSILON);
+  }
+
+  #[test]
+  fn test_column_sort_cycle() {
+    let asc = ColumnSort::cycle(None, 1);
+    assert_eq!(
+      asc,
+      Some(ColumnSort {
+        key: 1,
+        descending: false
+      })
+    );
+    let desc = ColumnSort::cycle(asc, 1);
+    assert_eq!(
+      desc,
+      Some(ColumnSort {
+        key: 1,
+        descending: true
+      })
+    );
+    assert_eq!(ColumnSort::cycle(desc, 1), None);
+    assert_eq!(
+      ColumnSort::cycle(desc, 2),
+      Some(ColumnSort {
+        key: 2,
+        descending: false
+      })
+    );
+    assert_eq!(desc.unwrap().apply(Ordering::Less), Ordering::Greater);
+  }
+
+  #[test]
+  fn test_labels_match() {
+    let labels = HashMap::from([("com.docker.compose.project".to_string(), "Shop".to_string())]);
+    assert!(labels_match(&labels, "compose.project"));
+    assert!(labels_match(&labels, "shop"));
+    assert!(labels_match(&labels, "project=shop"));
+    assert!(!labels_match(&labels, "billing"));
+  }
+
+  #[test]
+  fn test_label_filter() {
+    assert_eq!(parse_label_filter("label:env"), Some(("env", None)));
+    assert_eq!(
+      parse_label_filter("label: com.docker.compose.project = shop"),
+      Some(("com.docker.compose.project", Some("shop")))
+    );
+    assert_eq!(parse_label_filter("label:"), None);
+    assert_eq!(parse_label_filter("nginx"), None);
+
+    let labels = HashMap::from([("com.docker.compose.project".to_string(), "Shop".to_string())]);
+    assert!(labels_match_filter(&labels, "com.docker.compose.project", None));
+    assert!(labels_match_filter(&labels, "com.docker.compose.project", Some("shop")));
+    assert!(!labels_match_filter(&labels, "com.docker.compose.project", Some("sho")));
+    assert!(!labels_match_filter(&labels, "compose.project", None));
+  }
+
+  #[test]
+  fn test_anchored_offset_never_scrolls_past_top() {
+    assert!(anchored_offset(-20.0, 40.0, 5, 2).abs() < f32::EPSILON);
+  }
+}
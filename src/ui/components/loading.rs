@@ -122,6 +122,98 @@ pub fn render_k8s_error(
   }
 }
 
+/// Full-view state for Docker views while the daemon is unreachable.
+/// Offers to start Colima when it's installed and to retry right away;
+/// the connection monitor keeps retrying either way.
+pub fn render_docker_disconnected(cx: &App) -> Div {
+  let colors = &cx.theme().colors;
+  let colima_installed = crate::state::docker_state(cx).read(cx).colima_installed;
+
+  v_flex()
+    .size_full()
+    .items_center()
+    .justify_center()
+    .gap(px(16.))
+    .py(px(48.))
+    .px(px(24.))
+    .child(
+      div()
+        .size(px(64.))
+        .rounded(px(12.))
+        .bg(colors.warning.opacity(0.1))
+        .flex()
+        .items_center()
+        .justify_center()
+        .child(
+          Icon::new(IconName::TriangleAlert)
+            .size(px(32.))
+            .text_color(colors.warning),
+        ),
+    )
+    .child(
+      div()
+        .text_xl()
+        .font_weight(gpui::FontWeight::SEMIBOLD)
+        .text_color(colors.secondary_foreground)
+        .child("Docker Is Not Reachable"),
+    )
+    .child(
+      div()
+        .max_w(px(520.))
+        .text_sm()
+        .text_color(colors.muted_foreground)
+        .text_center()
+        .child(if colima_installed {
+          "The Docker daemon isn't responding. Start Colima, or start your Docker runtime and retry."
+        } else {
+          "The Docker daemon isn't responding. Start your Docker runtime and retry."
+        }),
+    )
+    .child(
+      h_flex()
+        .mt(px(8.))
+        .gap(px(8.))
+        .when(colima_installed, |el| {
+          el.child(
+            Button::new("disconnected-start-colima")
+              .label("Start Colima")
+              .primary()
+              .on_click(|_ev, _window, cx| {
+                crate::services::start_colima(None, cx);
+              }),
+          )
+        })
+        .child({
+          let retry = Button::new("disconnected-retry")
+            .label("Retry Connection")
+            .on_click(|_ev, _window, cx| crate::services::load_initial_data(cx));
+          if colima_installed {
+            retry.outline()
+          } else {
+            retry.primary()
+          }
+        }),
+    )
+}
+
+/// Full-view state for Kubernetes views once the cluster check failed,
+/// with a shortcut to the cluster manager to pick another context.
+pub fn render_k8s_unavailable(error_message: &str, cx: &App) -> Div {
+  render_k8s_error(
+    "Kubernetes",
+    error_message,
+    |_ev, _window, cx| crate::services::refresh_pods(cx),
+    cx,
+  )
+  .size_full()
+  .child(
+    Button::new("k8s-manage-clusters")
+      .label("Manage Clusters")
+      .ghost()
+      .on_click(|_ev, _window, cx| crate::services::set_view(crate::state::CurrentView::Clusters, cx)),
+  )
+}
+
 fn render_setup_panel(
   title: &'static str,
   description: &'static str,
@@ -1,23 +1,40 @@
 mod context_selector;
+mod copy_button;
+mod describe_tab;
+mod error_details;
+mod event_list;
 mod file_explorer;
 mod form;
 mod install_hint;
 mod k8s_header;
 mod kv_create_form;
+mod list_rows;
 mod loading;
 mod namespace_selector;
 mod process_view;
+mod refresh_status;
+mod secret_value;
 mod sparkline;
 mod spinning_icon;
 
-pub use context_selector::render_context_selector;
+pub use context_selector::{render_context_selector, render_docker_context_selector};
+pub use copy_button::{CopyCallback, copy_button, copy_to_clipboard};
+pub use describe_tab::{DescribeOutput, render_describe_tab};
+pub use error_details::ErrorDetails;
+pub use event_list::{ResourceEvents, render_event_list};
 pub use file_explorer::{FileExplorer, FileExplorerConfig, FileExplorerState, detect_language_from_path};
 pub use form::{form_field, form_section};
 pub use install_hint::{render_error_panel, render_install_hint};
 pub use k8s_header::{k8s_header_title, render_k8s_header};
 pub use kv_create_form::{KvCreateDialog, KvResourceKind};
-pub use loading::{render_error, render_k8s_error, render_loading};
+pub use list_rows::{
+  CachedListDelegate, ColumnSort, SearchDebounce, labels_match, labels_match_filter, parse_label_filter, refresh_rows,
+  render_bulk_delete_bar, render_sort_header,
+};
+pub use loading::{render_docker_disconnected, render_error, render_k8s_error, render_k8s_unavailable, render_loading};
 pub use namespace_selector::render_namespace_selector;
 pub use process_view::ProcessView;
+pub use refresh_status::RefreshStatus;
+pub use secret_value::{SecretValue, mask_secret_lines};
 pub use sparkline::Sparkline;
 pub use spinning_icon::{spinning_loader, spinning_loader_circle};
@@ -0,0 +1,130 @@
+//! Header indicator for a view's list refresh: a spinner while the refresh
+//! is in flight next to "Updated 12s ago", re-rendered only when the shown
+//! age would change.
+
+use std::time::Duration;
+
+use gpui::{
+  Context, Entity, IntoElement, ParentElement, Render, Styled, Task, Window, div, prelude::FluentBuilder, px,
+};
+use gpui_component::{h_flex, theme::ActiveTheme};
+
+use super::spinning_loader;
+use crate::state::{CurrentView, DockerState, StateChanged, docker_state};
+
+/// Short relative age, e.g. "just now", "12s ago", "3m ago".
+pub fn format_elapsed(elapsed: Duration) -> String {
+  let secs = elapsed.as_secs();
+  match secs {
+    0..5 => "just now".to_string(),
+    5..60 => format!("{secs}s ago"),
+    60..3600 => format!("{}m ago", secs / 60),
+    _ => format!("{}h ago", secs / 3600),
+  }
+}
+
+/// Time until `format_elapsed` output next changes for an age of `elapsed`.
+fn next_tick(elapsed: Duration) -> Duration {
+  let secs = elapsed.as_secs();
+  match secs {
+    0..60 => Duration::from_secs(1),
+    60..3600 => Duration::from_secs(60 - secs % 60),
+    _ => Duration::from_secs(3600 - secs % 3600),
+  }
+}
+
+pub struct RefreshStatus {
+  docker_state: Entity<DockerState>,
+  view: CurrentView,
+  /// Pending re-render for the age label; `None` while there is nothing to age
+  tick: Option<Task<()>>,
+}
+
+impl RefreshStatus {
+  pub fn new(view: CurrentView, cx: &mut Context<'_, Self>) -> Self {
+    let docker_state = docker_state(cx);
+    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
+      if matches!(event, StateChanged::RefreshStatusChanged(view) if *view == this.view) {
+        this.schedule_tick(cx);
+        cx.notify();
+      }
+    })
+    .detach();
+
+    let mut status = Self {
+      docker_state,
+      view,
+      tick: None,
+    };
+    status.schedule_tick(cx);
+    status
+  }
+
+  /// Re-render once the age label would change, then reschedule. Views
+  /// that have never loaded don't tick at all.
+  fn schedule_tick(&mut self, cx: &mut Context<'_, Self>) {
+    let Some(at) = self.docker_state.read(cx).last_refreshed.get(&self.view).copied() else {
+      self.tick = None;
+      return;
+    };
+    let delay = next_tick(at.elapsed());
+    self.tick = Some(cx.spawn(async move |this, cx| {
+      cx.background_executor().timer(delay).await;
+      let _ = this.update(cx, |this, cx| {
+        this.schedule_tick(cx);
+        cx.notify();
+      });
+    }));
+  }
+
+  /// Follow a different view, e.g. when a tabbed wrapper switches tabs.
+  pub fn set_view(&mut self, view: CurrentView, cx: &mut Context<'_, Self>) {
+    if self.view != view {
+      self.view = view;
+      self.schedule_tick(cx);
+      cx.notify();
+    }
+  }
+}
+
+impl Render for RefreshStatus {
+  fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let state = self.docker_state.read(cx);
+    let refreshing = state.is_refreshing(self.view);
+    let label = state
+      .last_refreshed
+      .get(&self.view)
+      .map(|at| format!("Updated {}", format_elapsed(at.elapsed())));
+
+    h_flex()
+      .items_center()
+      .gap(px(6.))
+      .text_xs()
+      .text_color(colors.muted_foreground)
+      .when(refreshing, |el| {
+        el.child(spinning_loader(px(12.), colors.muted_foreground))
+      })
+      .when_some(label, |el, label| el.child(div().child(label)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_format_elapsed() {
+    assert_eq!(format_elapsed(Duration::from_secs(2)), "just now");
+    assert_eq!(format_elapsed(Duration::from_secs(12)), "12s ago");
+    assert_eq!(format_elapsed(Duration::from_secs(185)), "3m ago");
+    assert_eq!(format_elapsed(Duration::from_secs(7300)), "2h ago");
+  }
+
+  #[test]
+  fn test_next_tick() {
+    assert_eq!(next_tick(Duration::from_secs(12)), Duration::from_secs(1));
+    assert_eq!(next_tick(Duration::from_secs(185)), Duration::from_secs(55));
+    assert_eq!(next_tick(Duration::from_secs(7300)), Duration::from_secs(3500));
+  }
+}
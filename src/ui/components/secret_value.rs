@@ -0,0 +1,130 @@
+//! Masking for env var values whose keys look like secrets
+//!
+//! Key/value rows render the value as `••••••` until clicked. Text viewers
+//! (inspect JSON, compose YAML) mask matching lines with
+//! `mask_secret_lines` and offer their own reveal toggle.
+
+use gpui::{App, ElementId, IntoElement, RenderOnce, SharedString, Window, div, prelude::*};
+
+const SECRET_MASK: &str = "••••••";
+
+/// A value that's masked until clicked. The reveal lasts while the row
+/// stays on screen.
+#[derive(IntoElement)]
+pub struct SecretValue {
+  id: ElementId,
+  value: SharedString,
+  masked: bool,
+}
+
+impl SecretValue {
+  /// `masked = false` renders `value` as plain text
+  pub fn new(id: impl Into<ElementId>, value: impl Into<SharedString>, masked: bool) -> Self {
+    Self {
+      id: id.into(),
+      value: value.into(),
+      masked,
+    }
+  }
+}
+
+impl RenderOnce for SecretValue {
+  fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+    if !self.masked {
+      return div().child(self.value).into_any_element();
+    }
+    let revealed = window.use_keyed_state(self.id.clone(), cx, |_, _| false);
+    let shown = *revealed.read(cx);
+    div()
+      .id(self.id)
+      .cursor_pointer()
+      .child(if shown { self.value } else { SECRET_MASK.into() })
+      .tooltip(move |window, cx| {
+        gpui_component::tooltip::Tooltip::new(if shown { "Click to hide" } else { "Click to reveal" }).build(window, cx)
+      })
+      .on_click(move |_ev, _window, cx| {
+        revealed.update(cx, |revealed, cx| {
+          *revealed = !*revealed;
+          cx.notify();
+        });
+      })
+      .into_any_element()
+  }
+}
+
+/// Mask the value on every line that assigns a secret key, in the
+/// `KEY=value` form (env lists, inspect JSON `"KEY=value"`, compose
+/// `- KEY=value`) or the YAML mapping form `KEY: value`.
+pub fn mask_secret_lines(text: &str, is_secret: impl Fn(&str) -> bool) -> String {
+  let mut out = String::with_capacity(text.len());
+  for (i, line) in text.split('\n').enumerate() {
+    if i > 0 {
+      out.push('\n');
+    }
+    match secret_value_range(line, &is_secret) {
+      Some((start, end)) => {
+        out.push_str(&line[..start]);
+        out.push_str(SECRET_MASK);
+        out.push_str(&line[end..]);
+      }
+      None => out.push_str(line),
+    }
+  }
+  out
+}
+
+/// Byte range of the value on `line` when it assigns a secret key
+fn secret_value_range(line: &str, is_secret: &impl Fn(&str) -> bool) -> Option<(usize, usize)> {
+  let mut pos = line.len() - line.trim_start().len();
+  if line[pos..].starts_with("- ") {
+    pos += 2;
+  }
+  let quote = line[pos..].chars().next().filter(|c| matches!(c, '"' | '\''));
+  if quote.is_some() {
+    pos += 1;
+  }
+  let key_len = line[pos..]
+    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')))
+    .unwrap_or(line.len() - pos);
+  if key_len == 0 || !is_secret(&line[pos..pos + key_len]) {
+    return None;
+  }
+  pos += key_len;
+  let after = &line[pos..];
+  let start = if after.starts_with('=') {
+    pos + 1
+  } else if quote.is_none() && after.starts_with(':') {
+    pos + 1 + (after[1..].len() - after[1..].trim_start().len())
+  } else {
+    return None;
+  };
+  let end = match quote {
+    Some(q) => line[start..].find(q).map_or(line.len(), |i| start + i),
+    None => line.trim_end().len().max(start),
+  };
+  (end > start).then_some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn is_secret(key: &str) -> bool {
+    key.ends_with("_PASSWORD") || key.ends_with("_TOKEN")
+  }
+
+  #[test]
+  fn test_mask_secret_lines() {
+    let json = "\"Env\": [\n  \"DB_PASSWORD=hunter2\",\n  \"PATH=/usr/bin\"\n]";
+    assert_eq!(
+      mask_secret_lines(json, is_secret),
+      "\"Env\": [\n  \"DB_PASSWORD=••••••\",\n  \"PATH=/usr/bin\"\n]"
+    );
+
+    let yaml = "environment:\n  API_TOKEN: abc123 \n  - DB_PASSWORD=x\n  MODE: dev\n  EMPTY_TOKEN:";
+    assert_eq!(
+      mask_secret_lines(yaml, is_secret),
+      "environment:\n  API_TOKEN: •••••• \n  - DB_PASSWORD=••••••\n  MODE: dev\n  EMPTY_TOKEN:"
+    );
+  }
+}
@@ -1,4 +1,4 @@
-use gpui::{Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
 use gpui_component::{
   Icon, IconName, Sizable,
   button::{Button, ButtonVariants},
@@ -10,11 +10,46 @@ use gpui_component::{
   v_flex,
 };
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
 use crate::assets::AppIcon;
-use crate::docker::{ComposeProject, ComposeService, extract_compose_projects};
-use crate::services;
-use crate::state::{DockerState, StateChanged, docker_state};
+use crate::docker::{ComposeProject, ComposeService};
+use crate::services::{self, ComposeServiceAction};
+use crate::state::{DockerState, StateChanged, docker_state, settings_state};
+use crate::terminal::{LogStream, TerminalView};
+use crate::ui::components::mask_secret_lines;
+
+/// Live combined logs for one project. Dropping it kills the
+/// `docker compose logs -f` child.
+struct ProjectLogs {
+  logs: Arc<services::ComposeLogs>,
+  view: Entity<TerminalView>,
+}
+
+impl Drop for ProjectLogs {
+  fn drop(&mut self) {
+    self.logs.stop();
+  }
+}
+
+/// Pick a compose file with the native dialog and bring it up
+fn prompt_compose_up_from_file(cx: &mut App) {
+  let opts = gpui::PathPromptOptions {
+    files: true,
+    directories: false,
+    multiple: false,
+    prompt: Some("Choose Compose File".into()),
+  };
+  let rx = cx.prompt_for_paths(opts);
+  cx.spawn(async move |cx| {
+    if let Ok(Ok(Some(paths))) = rx.await
+      && let Some(path) = paths.into_iter().next()
+    {
+      let _ = cx.update(|cx| services::compose_up_from_file(path, cx));
+    }
+  })
+  .detach();
+}
 
 /// Docker Compose projects view
 pub struct ComposeView {
@@ -27,6 +62,10 @@ pub struct ComposeView {
   /// means we tried to load and failed (file missing / read error)
   /// — the UI shows the error string instead of waiting forever.
   yaml_cache: HashMap<String, Result<String, String>>,
+  /// Projects whose YAML shows secret env values unmasked
+  yaml_revealed: HashSet<String>,
+  /// Open log panels keyed by project name
+  project_logs: HashMap<String, ProjectLogs>,
 }
 
 impl ComposeView {
@@ -34,18 +73,31 @@ impl ComposeView {
     let docker_state = docker_state(cx);
 
     // Subscribe to state changes
-    cx.subscribe(&docker_state, |_this, _state, event: &StateChanged, cx| {
+    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
       if let StateChanged::ContainersUpdated = event {
+        // Downed projects lose their containers; end their log streams
+        let projects = services::list_compose_projects(cx);
+        this
+          .project_logs
+          .retain(|name, _| projects.iter().any(|p| &p.name == name && !p.is_all_stopped()));
         cx.notify();
       }
     })
     .detach();
 
+    // Projects are derived from the container list; make sure it exists
+    // when Compose is the first view opened.
+    if docker_state.read(cx).containers_state == crate::state::LoadState::NotLoaded {
+      services::refresh_containers(cx);
+    }
+
     Self {
       docker_state,
       expanded_projects: HashSet::new(),
       yaml_visible: HashSet::new(),
       yaml_cache: HashMap::new(),
+      yaml_revealed: HashSet::new(),
+      project_logs: HashMap::new(),
     }
   }
 
@@ -91,6 +143,90 @@ impl ComposeView {
     cx.notify();
   }
 
+  /// Open or close the live log panel for `project`
+  fn toggle_logs(&mut self, project: &ComposeProject, cx: &mut Context<'_, Self>) {
+    if self.project_logs.remove(&project.name).is_some() {
+      cx.notify();
+      return;
+    }
+    let Ok(stream) = LogStream::new(120, 40).map(Arc::new) else {
+      return;
+    };
+    let logs = services::compose_logs(
+      project.name.clone(),
+      project.working_dir.clone(),
+      project.config_files.clone(),
+      true,
+      stream.clone(),
+      cx,
+    );
+    let view = cx.new(|cx| TerminalView::for_log_stream(stream, cx));
+    self
+      .project_logs
+      .insert(project.name.clone(), ProjectLogs { logs, view });
+    self.expanded_projects.insert(project.name.clone());
+    cx.notify();
+  }
+
+  fn render_logs_panel(project_name: &str, logs: &ProjectLogs, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let colors = cx.theme().colors;
+    let paused = logs.logs.is_paused();
+    let handle = logs.logs.clone();
+    let name = project_name.to_string();
+    v_flex()
+      .w_full()
+      .pl(px(56.))
+      .pr(px(16.))
+      .py(px(8.))
+      .gap(px(4.))
+      .child(
+        h_flex()
+          .w_full()
+          .items_center()
+          .justify_between()
+          .child(div().text_xs().text_color(colors.muted_foreground).child(if paused {
+            "Logs (paused)"
+          } else {
+            "Logs"
+          }))
+          .child(
+            h_flex()
+              .gap(px(4.))
+              .child(
+                Button::new(SharedString::from(format!("logs-pause-{project_name}")))
+                  .icon(Icon::new(if paused { AppIcon::Play } else { AppIcon::Pause }))
+                  .label(if paused { "Resume" } else { "Pause" })
+                  .xsmall()
+                  .ghost()
+                  .on_click(cx.listener(move |_this, _ev, _window, cx| {
+                    handle.set_paused(!handle.is_paused());
+                    cx.notify();
+                  })),
+              )
+              .child(
+                Button::new(SharedString::from(format!("logs-close-{project_name}")))
+                  .icon(IconName::Close)
+                  .xsmall()
+                  .ghost()
+                  .on_click(cx.listener(move |this, _ev, _window, cx| {
+                    this.project_logs.remove(&name);
+                    cx.notify();
+                  })),
+              ),
+          ),
+      )
+      .child(
+        div()
+          .w_full()
+          .h(px(320.))
+          .rounded(px(6.))
+          .border_1()
+          .border_color(colors.border)
+          .overflow_hidden()
+          .child(logs.view.clone()),
+      )
+  }
+
   fn render_empty(cx: &Context<'_, Self>) -> impl IntoElement {
     let colors = &cx.theme().colors;
 
@@ -112,7 +248,7 @@ impl ComposeView {
           div()
             .text_xs()
             .text_color(colors.muted_foreground)
-            .child("Start a compose project to see it here"),
+            .child("Start a compose project, or bring one up from a file with New Project"),
         ),
     )
   }
@@ -138,10 +274,14 @@ impl ComposeView {
     let config_files_restart = project.config_files.clone();
     let working_dir_watch = project.working_dir.clone();
     let config_files_watch = project.config_files.clone();
+    let project_for_logs = project.clone();
     let project_name_for_yaml = project_name.clone();
     let yaml_path = project.config_files.first().cloned();
     let yaml_visible = self.yaml_visible.contains(&project_name);
     let yaml_content = self.yaml_cache.get(&project_name).cloned();
+    let yaml_revealed = self.yaml_revealed.contains(&project_name);
+    let logs_open = self.project_logs.contains_key(&project_name);
+    let project_for_logs_toggle = project.clone();
 
     let status_color = if project.is_all_running() {
       colors.success
@@ -150,6 +290,8 @@ impl ComposeView {
     } else {
       colors.warning
     };
+    // Shown under the name so it's clear which file Start/Stop act on.
+    let config_display = (!project.config_files.is_empty()).then(|| project.config_files.join(", "));
 
     v_flex()
             .w_full()
@@ -183,14 +325,29 @@ impl ComposeView {
                             .size(px(18.))
                             .text_color(colors.foreground),
                     )
-                    // Project name
+                    // Project name and compose file
                     .child(
-                        div()
+                        v_flex()
                             .flex_1()
-                            .text_sm()
-                            .font_weight(gpui::FontWeight::MEDIUM)
-                            .text_color(colors.foreground)
-                            .child(project_name.clone()),
+                            .min_w_0()
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_weight(gpui::FontWeight::MEDIUM)
+                                    .text_color(colors.foreground)
+                                    .child(project_name.clone()),
+                            )
+                            .when_some(config_display, |el, path| {
+                                el.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(colors.muted_foreground)
+                                        .overflow_hidden()
+                                        .text_ellipsis()
+                                        .whitespace_nowrap()
+                                        .child(path),
+                                )
+                            }),
                     )
                     // Status badge
                     .child(
@@ -220,8 +377,19 @@ impl ComposeView {
                                 }
                             })),
                     )
+                    .child(
+                        Button::new(SharedString::from(format!("logs-{project_name}")))
+                            .icon(Icon::new(AppIcon::Logs))
+                            .label("Logs")
+                            .xsmall()
+                            .when(logs_open, Button::primary)
+                            .when(!logs_open, ButtonVariants::ghost)
+                            .on_click(cx.listener(move |this, _ev, _window, cx| {
+                                this.toggle_logs(&project_for_logs_toggle, cx);
+                            })),
+                    )
                     // Single Ellipsis menu collapses Start/Stop/Restart/Watch
-                    // into one consistent dropdown so the project header
+                    // and log export into one consistent dropdown so the project header
                     // matches the per-row action UX in the other lists.
                     .child(
                         Button::new(SharedString::from(format!("compose-menu-{project_name}")))
@@ -241,6 +409,7 @@ impl ComposeView {
                                 let cf_restart = config_files_restart.clone();
                                 let wdir_watch = working_dir_watch.clone();
                                 let cf_watch = config_files_watch.clone();
+                                let project_logs = project_for_logs.clone();
                                 move |menu, _window, _cx| {
                                     let name_up = name_up.clone();
                                     let name_down = name_down.clone();
@@ -254,6 +423,8 @@ impl ComposeView {
                                     let cf_restart = cf_restart.clone();
                                     let wdir_watch = wdir_watch.clone();
                                     let cf_watch = cf_watch.clone();
+                                    let project_split = project_logs.clone();
+                                    let project_combined = project_logs.clone();
                                     menu.item(
                                         PopupMenuItem::new("Start")
                                             .icon(Icon::new(AppIcon::Play))
@@ -294,6 +465,31 @@ impl ComposeView {
                                                 );
                                             }),
                                     )
+                                    .separator()
+                                    .item(
+                                        PopupMenuItem::new("Export Logs to Folder...")
+                                            .icon(Icon::new(AppIcon::Logs))
+                                            .on_click(move |_, window, cx| {
+                                                crate::ui::dialogs::prompt_export_project_logs(
+                                                    project_split.clone(),
+                                                    services::ProjectLogLayout::PerService,
+                                                    window,
+                                                    cx,
+                                                );
+                                            }),
+                                    )
+                                    .item(
+                                        PopupMenuItem::new("Export Combined Log...")
+                                            .icon(Icon::new(AppIcon::Logs))
+                                            .on_click(move |_, window, cx| {
+                                                crate::ui::dialogs::prompt_export_project_logs(
+                                                    project_combined.clone(),
+                                                    services::ProjectLogLayout::Combined,
+                                                    window,
+                                                    cx,
+                                                );
+                                            }),
+                                    )
                                 }
                             }),
                     ),
@@ -305,7 +501,10 @@ impl ComposeView {
                         project
                             .services
                             .iter()
-                            .map(|service| Self::render_service(service, cx).into_any_element())
+                            .map(|service| {
+                                let status = self.service_status(service, cx);
+                                Self::render_service(project, service, status, cx).into_any_element()
+                            })
                             .collect::<Vec<_>>(),
                     )
                 } else {
@@ -313,18 +512,55 @@ impl ComposeView {
                 },
                 ParentElement::children,
             )
-            .when(is_expanded && yaml_visible, move |el| {
-                el.child(Self::render_yaml_block(yaml_path.as_deref(), yaml_content, cx))
+            .when(is_expanded && yaml_visible, |el| {
+                el.child(Self::render_yaml_block(
+                    &project_name,
+                    yaml_path.as_deref(),
+                    yaml_content,
+                    yaml_revealed,
+                    cx,
+                ))
             })
+            .when_some(
+                self.project_logs.get(&project_name).filter(|_| is_expanded),
+                |el, logs| el.child(Self::render_logs_panel(&project_name, logs, cx)),
+            )
   }
 
   fn render_yaml_block(
+    project_name: &str,
     yaml_path: Option<&str>,
     yaml_content: Option<Result<String, String>>,
+    revealed: bool,
     cx: &mut Context<'_, Self>,
   ) -> impl IntoElement {
     let colors = cx.theme().colors;
     let header_text = yaml_path.map_or_else(|| "compose YAML".to_string(), str::to_string);
+    let settings = &settings_state(cx).read(cx).settings;
+    let masking = settings.mask_secrets;
+    let yaml_content = yaml_content.map(|content| {
+      content.map(|text| {
+        if revealed || !masking {
+          text
+        } else {
+          mask_secret_lines(&text, |key| settings.is_secret_key(key))
+        }
+      })
+    });
+    let name = project_name.to_string();
+    let reveal_button = masking.then(|| {
+      Button::new(SharedString::from(format!("yaml-reveal-{project_name}")))
+        .icon(if revealed { IconName::EyeOff } else { IconName::Eye })
+        .label(if revealed { "Hide Secrets" } else { "Reveal Secrets" })
+        .xsmall()
+        .ghost()
+        .on_click(cx.listener(move |this, _ev, _window, cx| {
+          if !this.yaml_revealed.remove(&name) {
+            this.yaml_revealed.insert(name.clone());
+          }
+          cx.notify();
+        }))
+    });
     let body: gpui::AnyElement = match yaml_content {
       Some(Ok(text)) => div()
         .w_full()
@@ -355,7 +591,14 @@ impl ComposeView {
       .pr(px(16.))
       .py(px(8.))
       .gap(px(4.))
-      .child(div().text_xs().text_color(colors.muted_foreground).child(header_text))
+      .child(
+        h_flex()
+          .w_full()
+          .items_center()
+          .justify_between()
+          .child(div().text_xs().text_color(colors.muted_foreground).child(header_text))
+          .children(reveal_button),
+      )
       .child(
         div()
           .w_full()
@@ -367,7 +610,26 @@ impl ComposeView {
       )
   }
 
-  fn render_service(service: &ComposeService, cx: &mut Context<'_, Self>) -> impl IntoElement {
+  /// Docker's status line for the service's container (`Up 5 minutes
+  /// (healthy)`, `Exited (1) 2 hours ago`), falling back to the state.
+  fn service_status(&self, service: &ComposeService, cx: &App) -> String {
+    self
+      .docker_state
+      .read(cx)
+      .containers
+      .iter()
+      .find(|c| c.id == service.container_id)
+      .map(|c| c.status.clone())
+      .filter(|status| !status.is_empty())
+      .unwrap_or_else(|| service.state.to_string())
+  }
+
+  fn render_service(
+    project: &ComposeProject,
+    service: &ComposeService,
+    status: String,
+    cx: &mut Context<'_, Self>,
+  ) -> impl IntoElement {
     let colors = cx.theme().colors;
 
     let status_color = if service.state.is_running() {
@@ -375,6 +637,40 @@ impl ComposeView {
     } else {
       colors.muted_foreground
     };
+    let is_running = service.state.is_running();
+    let target = (
+      project.name.clone(),
+      project.working_dir.clone(),
+      project.config_files.clone(),
+      service.name.clone(),
+    );
+    let menu_button = Button::new(SharedString::from(format!("service-menu-{}", service.container_id)))
+      .icon(IconName::Ellipsis)
+      .xsmall()
+      .ghost()
+      .dropdown_menu(move |menu, _window, _cx| {
+        let item = |label: &'static str, icon: AppIcon, action: ComposeServiceAction| {
+          let (project, working_dir, config_files, service) = target.clone();
+          PopupMenuItem::new(label)
+            .icon(Icon::new(icon))
+            .on_click(move |_, _, cx| {
+              services::compose_service_action(
+                project.clone(),
+                working_dir.clone(),
+                config_files.clone(),
+                service.clone(),
+                action,
+                cx,
+              );
+            })
+        };
+        let menu = if is_running {
+          menu.item(item("Stop", AppIcon::Stop, ComposeServiceAction::Stop))
+        } else {
+          menu.item(item("Start", AppIcon::Play, ComposeServiceAction::Start))
+        };
+        menu.item(item("Restart", AppIcon::Restart, ComposeServiceAction::Restart))
+      });
 
     h_flex()
             .id(SharedString::from(format!("service-{}", service.container_id)))
@@ -421,11 +717,15 @@ impl ComposeView {
             // Status text
             .child(
                 div()
-                    .w(px(80.))
+                    .w(px(180.))
                     .text_xs()
                     .text_color(colors.muted_foreground)
-                    .child(service.state.to_string()),
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(status),
             )
+            .child(menu_button)
   }
 }
 
@@ -433,9 +733,8 @@ impl Render for ComposeView {
   fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let colors = cx.theme().colors;
 
-    // Get containers and extract compose projects
-    let containers = self.docker_state.read(cx).containers.clone();
-    let projects = extract_compose_projects(&containers);
+    let projects = services::list_compose_projects(cx);
+    let recent_files = settings_state(cx).read(cx).settings.recent_compose_files.clone();
 
     div()
             .size_full()
@@ -460,18 +759,42 @@ impl Render for ComposeView {
                     .child(
                         h_flex()
                             .gap(px(8.))
+                            .child(
+                                Button::new("compose-new-project")
+                                    .icon(IconName::Plus)
+                                    .label("New Project")
+                                    .compact()
+                                    .primary()
+                                    .on_click(|_ev, _window, cx| prompt_compose_up_from_file(cx)),
+                            )
                             .child(
                                 Button::new("compose-toolbar-actions")
                                     .icon(IconName::Ellipsis)
                                     .compact()
                                     .ghost()
-                                    .dropdown_menu(|menu, _window, _cx| {
-                                        menu.item(
+                                    .dropdown_menu(move |menu, _window, _cx| {
+                                        let menu = menu.item(
                                             PopupMenuItem::new("Refresh")
                                                 .icon(Icon::new(AppIcon::Refresh))
                                                 .on_click(|_, _, cx| {
                                                     services::refresh_containers(cx);
                                                 }),
+                                        );
+                                        if recent_files.is_empty() {
+                                            return menu;
+                                        }
+                                        recent_files.iter().fold(
+                                            menu.separator().label("Recent Compose Files"),
+                                            |menu, file| {
+                                                let path = std::path::PathBuf::from(file);
+                                                menu.item(
+                                                    PopupMenuItem::new(file.clone())
+                                                        .icon(Icon::new(AppIcon::Play))
+                                                        .on_click(move |_, _, cx| {
+                                                            services::compose_up_from_file(path.clone(), cx);
+                                                        }),
+                                                )
+                                            },
                                         )
                                     }),
                             ),
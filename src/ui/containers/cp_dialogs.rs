@@ -1,7 +1,8 @@
 //! Prompt flows for `docker cp` upload and download from the container row
 //! menu. Upload picks a host file/dir, then asks for an in-container
 //! destination directory. Download asks for an in-container path, then a
-//! host destination directory.
+//! host destination directory. The Files tab already knows the in-container
+//! path, so its entry points only ask for the host side.
 
 use gpui::{App, Entity, ParentElement, Styled, Window, prelude::*, px};
 use gpui_component::{
@@ -80,7 +81,7 @@ fn open_dest_path_dialog(container_id: String, src: std::path::PathBuf, cx: &mut
                   if dest.is_empty() {
                     return;
                   }
-                  services::cp_to_container(id.clone(), src.clone(), dest, cx);
+                  services::upload_to_container(id.clone(), src.clone(), dest, cx);
                   window.close_dialog(cx);
                 })
                 .into_any_element(),
@@ -140,7 +141,7 @@ pub fn prompt_download_from_container(container_id: String, window: &mut Window,
                 let Ok(Ok(Some(paths))) = rx.await else { return };
                 let Some(dest) = paths.into_iter().next() else { return };
                 let _ = cx.update(|cx| {
-                  services::cp_from_container(id, src, dest, cx);
+                  services::download_from_container(id, src, dest, cx);
                 });
               })
               .detach();
@@ -157,3 +158,41 @@ pub fn prompt_download_from_container(container_id: String, window: &mut Window,
       })
   });
 }
+
+/// Files tab: pick a host file or folder and upload it into `dest_dir`.
+pub fn prompt_upload_into(container_id: String, dest_dir: String, cx: &mut App) {
+  let opts = gpui::PathPromptOptions {
+    files: true,
+    directories: true,
+    multiple: false,
+    prompt: Some(format!("Upload into {dest_dir}").into()),
+  };
+  let rx = cx.prompt_for_paths(opts);
+  cx.spawn(async move |cx| {
+    let Ok(Ok(Some(paths))) = rx.await else { return };
+    let Some(src) = paths.into_iter().next() else { return };
+    let _ = cx.update(|cx| {
+      services::upload_to_container(container_id, src, dest_dir, cx);
+    });
+  })
+  .detach();
+}
+
+/// Files tab: pick a host folder and download `src_path` into it.
+pub fn prompt_download_to_host(container_id: String, src_path: String, cx: &mut App) {
+  let opts = gpui::PathPromptOptions {
+    files: false,
+    directories: true,
+    multiple: false,
+    prompt: Some("Choose Destination Folder".into()),
+  };
+  let rx = cx.prompt_for_paths(opts);
+  cx.spawn(async move |cx| {
+    let Ok(Ok(Some(paths))) = rx.await else { return };
+    let Some(dest) = paths.into_iter().next() else { return };
+    let _ = cx.update(|cx| {
+      services::download_from_container(container_id, src_path, dest, cx);
+    });
+  })
+  .detach();
+}
@@ -6,8 +6,9 @@ use gpui_component::{
   IconName, IndexPath, Selectable, Sizable,
   button::{Button, ButtonVariants},
   h_flex,
-  input::{Input, InputState},
+  input::{Input, InputEvent, InputState},
   label::Label,
+  menu::{DropdownMenu, PopupMenuItem},
   scroll::ScrollableElement,
   select::{Select, SelectItem, SelectState},
   switch::Switch,
@@ -15,32 +16,408 @@ use gpui_component::{
   theme::ActiveTheme,
   v_flex,
 };
+use std::fmt::Write as _;
 use std::rc::Rc;
 
+use crate::docker::ImageInfo;
+use crate::services::shell_quote;
+
 /// Type alias for tab change callback to reduce complexity
 type TabChangeCallback = Rc<dyn Fn(&usize, &mut Window, &mut App)>;
 
-/// Parse a human-readable size like "256m", "1.5g", "100k", or a raw byte
-/// count into a byte total. Returns `None` for empty / unparsable inputs.
-fn parse_size_bytes(s: &str) -> Option<i64> {
+/// Index of the Resources tab, opened when a limit fails validation.
+const RESOURCES_TAB: usize = 5;
+
+/// Docker rejects memory limits below 6 MiB.
+pub(super) const MIN_MEMORY_BYTES: i64 = 6 * 1024 * 1024;
+
+/// Docker rejects CPU shares below 2.
+const MIN_CPU_SHARES: i64 = 2;
+
+/// Parse a human-readable size like "256m", "1.5g", "100k", "2GiB", or a
+/// raw byte count into a byte total, following `docker run --memory`.
+/// Empty input is `Ok(None)`; anything that isn't a positive size is an
+/// error to show next to the field.
+pub(super) fn parse_size_bytes(s: &str) -> Result<Option<i64>, String> {
   let trimmed = s.trim();
   if trimmed.is_empty() {
-    return None;
-  }
-  let (num_str, mul): (&str, i64) = match trimmed.chars().last() {
-    Some('k' | 'K') => (&trimmed[..trimmed.len() - 1], 1024),
-    Some('m' | 'M') => (&trimmed[..trimmed.len() - 1], 1024 * 1024),
-    Some('g' | 'G') => (&trimmed[..trimmed.len() - 1], 1024 * 1024 * 1024),
-    Some('t' | 'T') => (&trimmed[..trimmed.len() - 1], 1024_i64 * 1024 * 1024 * 1024),
-    _ => (trimmed, 1),
+    return Ok(None);
+  }
+  let invalid = || format!("'{trimmed}' is not a size like 512m or 2g");
+  let lower = trimmed.to_ascii_lowercase();
+  let unit_str = lower.strip_suffix('b').unwrap_or(&lower);
+  let unit_str = unit_str.strip_suffix('i').unwrap_or(unit_str);
+  let (num_str, mul): (&str, i64) = match unit_str.chars().last() {
+    Some('k') => (&unit_str[..unit_str.len() - 1], 1024),
+    Some('m') => (&unit_str[..unit_str.len() - 1], 1024 * 1024),
+    Some('g') => (&unit_str[..unit_str.len() - 1], 1024 * 1024 * 1024),
+    Some('t') => (&unit_str[..unit_str.len() - 1], 1024_i64 * 1024 * 1024 * 1024),
+    _ => (unit_str, 1),
   };
-  let n: f64 = num_str.parse().ok()?;
-  if n <= 0.0 {
-    return None;
+  let n: f64 = num_str.trim().parse().map_err(|_| invalid())?;
+  if !n.is_finite() || n <= 0.0 {
+    return Err(invalid());
   }
   #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
   let bytes = (n * mul as f64) as i64;
-  Some(bytes)
+  Ok(Some(bytes))
+}
+
+/// Resource limits parsed from the Resources tab. Fields that failed
+/// validation are left `None`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ResourceLimits {
+  cpus: Option<f64>,
+  cpu_shares: Option<i64>,
+  memory_bytes: Option<i64>,
+  memory_swap_bytes: Option<i64>,
+  pids_limit: Option<i64>,
+}
+
+/// Inline validation messages for the Resources tab, one per field.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ResourceErrors {
+  cpus: Option<String>,
+  cpu_shares: Option<String>,
+  memory: Option<String>,
+  memory_swap: Option<String>,
+  pids_limit: Option<String>,
+}
+
+impl ResourceErrors {
+  fn is_empty(&self) -> bool {
+    self.cpus.is_none()
+      && self.cpu_shares.is_none()
+      && self.memory.is_none()
+      && self.memory_swap.is_none()
+      && self.pids_limit.is_none()
+  }
+}
+
+/// Raw text of the Resources tab inputs.
+#[derive(Debug, Clone, Copy, Default)]
+struct ResourceInputs<'a> {
+  cpus: &'a str,
+  cpu_shares: &'a str,
+  memory: &'a str,
+  memory_swap: &'a str,
+  pids_limit: &'a str,
+}
+
+/// Parse and validate the Resources tab. Blank fields mean "no limit".
+fn parse_resource_limits(inputs: ResourceInputs<'_>) -> (ResourceLimits, ResourceErrors) {
+  let mut limits = ResourceLimits::default();
+  let mut errors = ResourceErrors::default();
+
+  let cpus = inputs.cpus.trim();
+  if !cpus.is_empty() {
+    match cpus.parse::<f64>() {
+      Ok(n) if n.is_finite() && n > 0.0 => limits.cpus = Some(n),
+      _ => errors.cpus = Some(format!("'{cpus}' is not a CPU count like 0.5 or 2")),
+    }
+  }
+
+  let shares = inputs.cpu_shares.trim();
+  if !shares.is_empty() {
+    match shares.parse::<i64>() {
+      Ok(n) if n >= MIN_CPU_SHARES => limits.cpu_shares = Some(n),
+      _ => {
+        errors.cpu_shares = Some(format!(
+          "CPU shares must be a whole number of at least {MIN_CPU_SHARES}"
+        ));
+      }
+    }
+  }
+
+  match parse_size_bytes(inputs.memory) {
+    Ok(Some(bytes)) if bytes < MIN_MEMORY_BYTES => {
+      errors.memory = Some("Memory limit must be at least 6m".to_string());
+    }
+    Ok(bytes) => limits.memory_bytes = bytes,
+    Err(e) => errors.memory = Some(e),
+  }
+
+  if inputs.memory_swap.trim() == "-1" {
+    limits.memory_swap_bytes = Some(-1);
+  } else {
+    match parse_size_bytes(inputs.memory_swap) {
+      Ok(Some(_)) if limits.memory_bytes.is_none() && errors.memory.is_none() => {
+        errors.memory_swap = Some("Set a memory limit before memory + swap".to_string());
+      }
+      Ok(Some(bytes)) if limits.memory_bytes.is_some_and(|mem| bytes < mem) => {
+        errors.memory_swap = Some("Memory + swap must be at least the memory limit".to_string());
+      }
+      Ok(bytes) => limits.memory_swap_bytes = bytes,
+      Err(e) => errors.memory_swap = Some(e),
+    }
+  }
+
+  let pids = inputs.pids_limit.trim();
+  if !pids.is_empty() {
+    match pids.parse::<i64>() {
+      Ok(n) if n > 0 => limits.pids_limit = Some(n),
+      _ => errors.pids_limit = Some(format!("'{pids}' is not a positive number")),
+    }
+  }
+
+  (limits, errors)
+}
+
+/// A host name made of dot-separated labels of letters, digits, hyphens
+/// and underscores, none starting or ending with a hyphen. RFC 1123 has no
+/// underscores, but Docker accepts them and Compose service names use them.
+fn is_valid_hostname(name: &str) -> bool {
+  name.len() <= 253
+    && name.split('.').all(|label| {
+      !label.is_empty()
+        && label.len() <= 63
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label
+          .chars()
+          .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+    })
+}
+
+/// Validate a `--add-host` entry of the form `name:ip`. The host name is
+/// split at the first colon so IPv6 addresses (`name:::1`) still parse.
+/// Returns the normalized `name:ip` string on success.
+fn parse_host_entry(s: &str) -> Result<String, String> {
+  let trimmed = s.trim();
+  let Some((name, ip)) = trimmed.split_once(':') else {
+    return Err(format!("'{trimmed}' must be in name:ip form"));
+  };
+  let name = name.trim();
+  let ip = ip.trim();
+  if !is_valid_hostname(name) {
+    return Err(format!("'{name}' is not a valid host name"));
+  }
+  if ip != "host-gateway" && ip.parse::<std::net::IpAddr>().is_err() {
+    return Err(format!("'{ip}' is not a valid IP address"));
+  }
+  Ok(format!("{name}:{ip}"))
+}
+
+/// Linux capabilities Docker accepts for `--cap-add` / `--cap-drop`,
+/// without the `CAP_` prefix.
+const LINUX_CAPABILITIES: &[&str] = &[
+  "AUDIT_CONTROL",
+  "AUDIT_READ",
+  "AUDIT_WRITE",
+  "BLOCK_SUSPEND",
+  "BPF",
+  "CHECKPOINT_RESTORE",
+  "CHOWN",
+  "DAC_OVERRIDE",
+  "DAC_READ_SEARCH",
+  "FOWNER",
+  "FSETID",
+  "IPC_LOCK",
+  "IPC_OWNER",
+  "KILL",
+  "LEASE",
+  "LINUX_IMMUTABLE",
+  "MAC_ADMIN",
+  "MAC_OVERRIDE",
+  "MKNOD",
+  "NET_ADMIN",
+  "NET_BIND_SERVICE",
+  "NET_BROADCAST",
+  "NET_RAW",
+  "PERFMON",
+  "SETFCAP",
+  "SETGID",
+  "SETPCAP",
+  "SETUID",
+  "SYSLOG",
+  "SYS_ADMIN",
+  "SYS_BOOT",
+  "SYS_CHROOT",
+  "SYS_MODULE",
+  "SYS_NICE",
+  "SYS_PACCT",
+  "SYS_PTRACE",
+  "SYS_RAWIO",
+  "SYS_RESOURCE",
+  "SYS_TIME",
+  "SYS_TTY_CONFIG",
+  "WAKE_ALARM",
+];
+
+/// Capabilities offered as one-click toggles on the Security tab.
+const COMMON_CAPABILITIES: &[&str] = &[
+  "NET_ADMIN",
+  "NET_RAW",
+  "NET_BIND_SERVICE",
+  "SYS_ADMIN",
+  "SYS_PTRACE",
+  "SYS_TIME",
+  "SYS_RESOURCE",
+  "SYS_MODULE",
+  "IPC_LOCK",
+  "MKNOD",
+  "AUDIT_WRITE",
+  "CHOWN",
+  "DAC_OVERRIDE",
+  "SETUID",
+  "SETGID",
+  "KILL",
+];
+
+/// `--security-opt` keys Docker understands.
+const SECURITY_OPT_KEYS: &[&str] = &[
+  "apparmor",
+  "label",
+  "no-new-privileges",
+  "seccomp",
+  "systempaths",
+  "writable-cgroups",
+];
+
+/// Validate a capability name against `LINUX_CAPABILITIES` (or `ALL`).
+/// Case and a `CAP_` prefix are ignored; returns the bare upper-case name.
+fn parse_capability(s: &str) -> Result<String, String> {
+  let trimmed = s.trim();
+  let upper = trimmed.to_ascii_uppercase();
+  let name = upper.strip_prefix("CAP_").unwrap_or(&upper);
+  if name == "ALL" || LINUX_CAPABILITIES.contains(&name) {
+    Ok(name.to_string())
+  } else {
+    Err(format!("'{trimmed}' is not a Linux capability"))
+  }
+}
+
+/// Validate a `--security-opt` entry: `no-new-privileges` on its own, or
+/// `key=value` / `key:value` with a key Docker knows.
+fn parse_security_opt(s: &str) -> Result<String, String> {
+  let trimmed = s.trim();
+  if trimmed.is_empty() || trimmed.chars().any(char::is_whitespace) {
+    return Err(format!("'{trimmed}' is not a security option"));
+  }
+  let key = trimmed.split(['=', ':']).next().unwrap_or_default();
+  if SECURITY_OPT_KEYS.contains(&key) && (key == "no-new-privileges" || key.len() < trimmed.len() - 1) {
+    Ok(trimmed.to_string())
+  } else {
+    Err(format!(
+      "'{trimmed}' must be no-new-privileges or key=value with key one of {}",
+      SECURITY_OPT_KEYS.join(", ")
+    ))
+  }
+}
+
+/// Validate a `--dns` server entry (must be a bare IP address).
+fn parse_dns_server(s: &str) -> Result<String, String> {
+  let trimmed = s.trim();
+  trimmed
+    .parse::<std::net::IpAddr>()
+    .map(|ip| ip.to_string())
+    .map_err(|_| format!("'{trimmed}' is not a valid IP address"))
+}
+
+/// Parse the contents of a `.env` file into environment variables.
+///
+/// Accepts `KEY=VALUE` lines with an optional `export ` prefix. Blank lines
+/// and `#` comments are skipped. Double-quoted values support `\n`, `\t`,
+/// `\"` and `\\` escapes; single-quoted values are taken literally; unquoted
+/// values end at an inline ` #` comment. Lines that fail to parse are
+/// reported as `line N: reason` without stopping the rest of the file.
+fn parse_env_file(contents: &str) -> (Vec<EnvVar>, Vec<String>) {
+  let mut vars = Vec::new();
+  let mut errors = Vec::new();
+
+  for (idx, raw) in contents.lines().enumerate() {
+    let line = raw.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    match parse_env_line(line) {
+      Ok(var) => vars.push(var),
+      Err(e) => errors.push(format!("line {}: {e}", idx + 1)),
+    }
+  }
+
+  (vars, errors)
+}
+
+/// "Imported 3 variables from app.env (1 line skipped)"
+fn env_import_summary(count: usize, skipped: usize, source: &str) -> String {
+  let mut summary = format!(
+    "Imported {count} variable{} from {source}",
+    if count == 1 { "" } else { "s" }
+  );
+  if skipped > 0 {
+    let _ = write!(
+      summary,
+      " ({skipped} line{} skipped)",
+      if skipped == 1 { "" } else { "s" }
+    );
+  }
+  summary
+}
+
+fn parse_env_line(line: &str) -> Result<EnvVar, String> {
+  let line = line.strip_prefix("export ").map_or(line, str::trim_start);
+  let Some((key, rest)) = line.split_once('=') else {
+    return Err("expected KEY=VALUE".to_string());
+  };
+
+  let key = key.trim();
+  let valid_key = key.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+    && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+  if !valid_key {
+    return Err(format!("'{key}' is not a valid variable name"));
+  }
+
+  let rest = rest.trim_start();
+  let (value, trailing) = match rest.chars().next() {
+    Some('"') => {
+      let mut value = String::new();
+      let mut chars = rest[1..].char_indices();
+      let mut end = None;
+      while let Some((i, c)) = chars.next() {
+        match c {
+          '"' => {
+            end = Some(i + 2);
+            break;
+          }
+          '\\' => match chars.next().map(|(_, c)| c) {
+            Some('n') => value.push('\n'),
+            Some('t') => value.push('\t'),
+            Some(other) => value.push(other),
+            None => value.push('\\'),
+          },
+          _ => value.push(c),
+        }
+      }
+      let Some(end) = end else {
+        return Err("unterminated double quote".to_string());
+      };
+      (value, &rest[end..])
+    }
+    Some('\'') => {
+      let Some(end) = rest[1..].find('\'') else {
+        return Err("unterminated single quote".to_string());
+      };
+      (rest[1..=end].to_string(), &rest[end + 2..])
+    }
+    _ => {
+      let value = rest
+        .find(" #")
+        .or_else(|| rest.find("\t#"))
+        .map_or(rest, |i| &rest[..i]);
+      (value.trim_end().to_string(), "")
+    }
+  };
+
+  let trailing = trailing.trim_start();
+  if !trailing.is_empty() && !trailing.starts_with('#') {
+    return Err(format!("unexpected text after closing quote: '{trailing}'"));
+  }
+
+  Ok(EnvVar {
+    key: key.to_string(),
+    value,
+  })
 }
 
 /// Theme colors struct for passing to helper methods
@@ -51,6 +428,33 @@ struct DialogColors {
   muted_foreground: Hsla,
   sidebar: Hsla,
   link: Hsla,
+  warning: Hsla,
+}
+
+/// Most local images offered under the Image field while typing
+const MAX_IMAGE_SUGGESTIONS: usize = 6;
+
+/// Local `repo:tag` references containing `query` (case-insensitive),
+/// prefix matches first, each with its image's size. Untagged images are
+/// skipped and an exact match isn't suggested back.
+fn image_suggestions(images: &[ImageInfo], query: &str) -> Vec<(String, String)> {
+  let query = query.trim().to_lowercase();
+  if query.is_empty() {
+    return Vec::new();
+  }
+  let mut matches: Vec<(bool, &String, &ImageInfo)> = images
+    .iter()
+    .flat_map(|image| image.repo_tags.iter().map(move |tag| (tag, image)))
+    .filter(|(tag, _)| *tag != "<none>:<none>" && tag.to_lowercase() != query)
+    .filter(|(tag, _)| tag.to_lowercase().contains(&query))
+    .map(|(tag, image)| (!tag.to_lowercase().starts_with(&query), tag, image))
+    .collect();
+  matches.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+  matches
+    .into_iter()
+    .take(MAX_IMAGE_SUGGESTIONS)
+    .map(|(_, tag, image)| (tag.clone(), image.display_size()))
+    .collect()
 }
 
 /// Platform options for container
@@ -202,6 +606,7 @@ pub struct CreateContainerOptions {
   pub hostname: Option<String>,
   pub labels: Vec<(String, String)>,
   pub cpus: Option<f64>,
+  pub cpu_shares: Option<i64>,
   pub memory_bytes: Option<i64>,
   pub memory_swap_bytes: Option<i64>,
   pub pids_limit: Option<i64>,
@@ -211,6 +616,145 @@ pub struct CreateContainerOptions {
   pub healthcheck_timeout_ns: Option<i64>,
   pub healthcheck_start_period_ns: Option<i64>,
   pub healthcheck_retries: Option<i64>,
+  /// Custom DNS servers (`--dns`).
+  pub dns: Vec<String>,
+  /// Extra `/etc/hosts` entries as `name:ip` (`--add-host`).
+  pub extra_hosts: Vec<String>,
+  /// Capabilities to add / drop (`--cap-add` / `--cap-drop`).
+  pub cap_add: Vec<String>,
+  pub cap_drop: Vec<String>,
+  /// Security options (`--security-opt`).
+  pub security_opt: Vec<String>,
+}
+
+/// Docker names anonymous volumes with a random 64-character hex id.
+fn is_anonymous_volume(name: &str) -> bool {
+  name.len() == 64 && name.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Options for a new container like the one in `raw` (`docker inspect`
+/// JSON): image, entrypoint, command, env, published ports, restart policy,
+/// network, DNS servers, extra hosts, capabilities, security options and
+/// mounts. The name is left blank, and anonymous volumes are dropped so the
+/// copy gets fresh ones instead of sharing the original's data.
+pub fn options_from_inspect(raw: &str) -> Option<CreateContainerOptions> {
+  let info: serde_json::Value = serde_json::from_str(raw).ok()?;
+  let config = info.get("Config")?;
+  let host = &info["HostConfig"];
+  let strings = |value: &serde_json::Value| -> Vec<String> {
+    value
+      .as_array()
+      .map(|items| items.iter().filter_map(|v| v.as_str().map(str::to_string)).collect())
+      .unwrap_or_default()
+  };
+
+  let image = config["Image"]
+    .as_str()
+    .or_else(|| info["Image"].as_str())
+    .unwrap_or_default()
+    .to_string();
+
+  // Quoted so arguments with spaces survive the round trip through the
+  // command and entrypoint inputs
+  let argv = |value: &serde_json::Value| {
+    Some(
+      strings(value)
+        .iter()
+        .map(|a| shell_quote(a))
+        .collect::<Vec<_>>()
+        .join(" "),
+    )
+    .filter(|c| !c.is_empty())
+  };
+  let command = argv(&config["Cmd"]);
+  let entrypoint = argv(&config["Entrypoint"]);
+
+  // The daemon reports capabilities as `CAP_NET_ADMIN`; the inputs take
+  // the short form. Unknown names are kept as they are.
+  let capabilities = |value: &serde_json::Value| -> Vec<String> {
+    strings(value)
+      .into_iter()
+      .map(|cap| parse_capability(&cap).unwrap_or(cap))
+      .collect()
+  };
+
+  let env_vars = strings(&config["Env"])
+    .into_iter()
+    .map(|entry| match entry.split_once('=') {
+      Some((key, value)) => (key.to_string(), value.to_string()),
+      None => (entry, String::new()),
+    })
+    .collect();
+
+  let mut ports = Vec::new();
+  if let Some(bindings) = host["PortBindings"].as_object() {
+    for (spec, targets) in bindings {
+      let (container_port, protocol) = spec.split_once('/').unwrap_or((spec.as_str(), "tcp"));
+      let host_ports: Vec<String> = targets
+        .as_array()
+        .map(|targets| {
+          targets
+            .iter()
+            .map(|t| t["HostPort"].as_str().unwrap_or_default().to_string())
+            .collect()
+        })
+        .unwrap_or_default();
+      if host_ports.is_empty() {
+        ports.push((String::new(), container_port.to_string(), protocol.to_string()));
+      }
+      for host_port in host_ports {
+        ports.push((host_port, container_port.to_string(), protocol.to_string()));
+      }
+    }
+  }
+  ports.sort_by(|a, b| (&a.1, &a.2).cmp(&(&b.1, &b.2)));
+
+  let volumes = info["Mounts"]
+    .as_array()
+    .map(|mounts| {
+      mounts
+        .iter()
+        .filter_map(|m| {
+          let source = match m["Type"].as_str()? {
+            "bind" => m["Source"].as_str()?,
+            "volume" => m["Name"].as_str().filter(|name| !is_anonymous_volume(name))?,
+            _ => return None,
+          };
+          let read_only = !m["RW"].as_bool().unwrap_or(true);
+          Some((source.to_string(), m["Destination"].as_str()?.to_string(), read_only))
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+
+  let network = host["NetworkMode"]
+    .as_str()
+    .filter(|mode| !matches!(*mode, "" | "default" | "bridge"))
+    .map(str::to_string);
+
+  let restart_policy = match host["RestartPolicy"]["Name"].as_str() {
+    Some("always") => RestartPolicy::Always,
+    Some("on-failure") => RestartPolicy::OnFailure,
+    Some("unless-stopped") => RestartPolicy::UnlessStopped,
+    _ => RestartPolicy::No,
+  };
+
+  Some(CreateContainerOptions {
+    image,
+    restart_policy,
+    command,
+    entrypoint,
+    env_vars,
+    ports,
+    volumes,
+    network,
+    dns: strings(&host["Dns"]),
+    extra_hosts: strings(&host["ExtraHosts"]),
+    cap_add: capabilities(&host["CapAdd"]),
+    cap_drop: capabilities(&host["CapDrop"]),
+    security_opt: strings(&host["SecurityOpt"]),
+    ..CreateContainerOptions::default()
+  })
 }
 
 /// Dialog for creating a new container
@@ -239,6 +783,9 @@ pub struct CreateContainerDialog {
   env_vars: Vec<EnvVar>,
   env_key_input: Option<Entity<InputState>>,
   env_value_input: Option<Entity<InputState>>,
+  env_import_errors: Vec<String>,
+  env_import_summary: Option<String>,
+  env_paste_input: Option<Entity<InputState>>,
 
   // Port mappings
   ports: Vec<PortMapping>,
@@ -254,13 +801,29 @@ pub struct CreateContainerDialog {
 
   // Network
   network_input: Option<Entity<InputState>>,
-
-  // Advanced inputs
-  hostname_input: Option<Entity<InputState>>,
+  dns: Vec<String>,
+  dns_input: Option<Entity<InputState>>,
+  extra_hosts: Vec<String>,
+  extra_host_input: Option<Entity<InputState>>,
+  network_error: Option<String>,
+
+  // Security
+  cap_add: Vec<String>,
+  cap_drop: Vec<String>,
+  cap_input: Option<Entity<InputState>>,
+  security_opt: Vec<String>,
+  security_opt_input: Option<Entity<InputState>>,
+  security_error: Option<String>,
+
+  // Resource limit inputs
   cpus_input: Option<Entity<InputState>>,
+  cpu_shares_input: Option<Entity<InputState>>,
   memory_input: Option<Entity<InputState>>,
   memory_swap_input: Option<Entity<InputState>>,
   pids_limit_input: Option<Entity<InputState>>,
+
+  // Advanced inputs
+  hostname_input: Option<Entity<InputState>>,
   labels: Vec<(String, String)>,
   label_key_input: Option<Entity<InputState>>,
   label_value_input: Option<Entity<InputState>>,
@@ -269,6 +832,9 @@ pub struct CreateContainerDialog {
   healthcheck_timeout_input: Option<Entity<InputState>>,
   healthcheck_start_period_input: Option<Entity<InputState>>,
   healthcheck_retries_input: Option<Entity<InputState>>,
+
+  /// Values for the inputs, applied when they are first built
+  prefill: Option<CreateContainerOptions>,
 }
 
 impl CreateContainerDialog {
@@ -292,6 +858,9 @@ impl CreateContainerDialog {
       env_vars: Vec::new(),
       env_key_input: None,
       env_value_input: None,
+      env_import_errors: Vec::new(),
+      env_import_summary: None,
+      env_paste_input: None,
       ports: Vec::new(),
       port_host_input: None,
       port_container_input: None,
@@ -301,8 +870,20 @@ impl CreateContainerDialog {
       volume_container_input: None,
       volume_readonly: false,
       network_input: None,
+      dns: Vec::new(),
+      dns_input: None,
+      extra_hosts: Vec::new(),
+      extra_host_input: None,
+      network_error: None,
+      cap_add: Vec::new(),
+      cap_drop: Vec::new(),
+      cap_input: None,
+      security_opt: Vec::new(),
+      security_opt_input: None,
+      security_error: None,
       hostname_input: None,
       cpus_input: None,
+      cpu_shares_input: None,
       memory_input: None,
       memory_swap_input: None,
       pids_limit_input: None,
@@ -314,12 +895,70 @@ impl CreateContainerDialog {
       healthcheck_timeout_input: None,
       healthcheck_start_period_input: None,
       healthcheck_retries_input: None,
+      prefill: None,
+    }
+  }
+
+  /// Dialog pre-filled from `options`, e.g. to duplicate a container.
+  pub fn with_options(options: CreateContainerOptions, cx: &mut Context<'_, Self>) -> Self {
+    Self {
+      env_vars: options
+        .env_vars
+        .iter()
+        .map(|(key, value)| EnvVar {
+          key: key.clone(),
+          value: value.clone(),
+        })
+        .collect(),
+      ports: options
+        .ports
+        .iter()
+        .map(|(host_port, container_port, protocol)| PortMapping {
+          host_port: host_port.clone(),
+          container_port: container_port.clone(),
+          protocol: protocol.clone(),
+        })
+        .collect(),
+      volumes: options
+        .volumes
+        .iter()
+        .map(|(host_path, container_path, read_only)| VolumeMount {
+          host_path: host_path.clone(),
+          container_path: container_path.clone(),
+          read_only: *read_only,
+        })
+        .collect(),
+      remove_after_stop: options.remove_after_stop,
+      privileged: options.privileged,
+      read_only: options.read_only,
+      docker_init: options.docker_init,
+      dns: options.dns.clone(),
+      extra_hosts: options.extra_hosts.clone(),
+      cap_add: options.cap_add.clone(),
+      cap_drop: options.cap_drop.clone(),
+      security_opt: options.security_opt.clone(),
+      prefill: Some(options),
+      ..Self::new(cx)
     }
   }
 
   fn ensure_inputs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let prefill = self.prefill.take().unwrap_or_default();
+
     if self.image_input.is_none() {
-      self.image_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. nginx:latest")));
+      let input = cx.new(|cx| {
+        InputState::new(window, cx)
+          .placeholder("e.g. nginx:latest")
+          .default_value(prefill.image)
+      });
+      // Suggestions and the local/pull hint follow the typed reference.
+      cx.subscribe(&input, |_this, _state, ev: &InputEvent, cx| {
+        if matches!(ev, InputEvent::Change) {
+          cx.notify();
+        }
+      })
+      .detach();
+      self.image_input = Some(input);
     }
 
     if self.name_input.is_none() {
@@ -327,15 +966,27 @@ impl CreateContainerDialog {
     }
 
     if self.command_input.is_none() {
-      self.command_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Command (optional)")));
+      self.command_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .placeholder("Command (optional)")
+          .default_value(prefill.command.unwrap_or_default())
+      }));
     }
 
     if self.entrypoint_input.is_none() {
-      self.entrypoint_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Entrypoint (optional)")));
+      self.entrypoint_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .placeholder("Entrypoint (optional)")
+          .default_value(prefill.entrypoint.unwrap_or_default())
+      }));
     }
 
     if self.workdir_input.is_none() {
-      self.workdir_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Working directory (optional)")));
+      self.workdir_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .placeholder("Working directory (optional)")
+          .default_value(prefill.workdir.unwrap_or_default())
+      }));
     }
 
     if self.platform_select.is_none() {
@@ -343,8 +994,9 @@ impl CreateContainerDialog {
     }
 
     if self.restart_policy_select.is_none() {
-      self.restart_policy_select =
-        Some(cx.new(|cx| SelectState::new(RestartPolicy::all(), Some(IndexPath::new(0)), window, cx)));
+      let all = RestartPolicy::all();
+      let initial = all.iter().position(|p| *p == prefill.restart_policy).unwrap_or(0);
+      self.restart_policy_select = Some(cx.new(|cx| SelectState::new(all, Some(IndexPath::new(initial)), window, cx)));
     }
 
     // Env var inputs
@@ -354,6 +1006,13 @@ impl CreateContainerDialog {
     if self.env_value_input.is_none() {
       self.env_value_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("VALUE")));
     }
+    if self.env_paste_input.is_none() {
+      self.env_paste_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .placeholder("Paste KEY=VALUE lines")
+      }));
+    }
 
     // Port inputs
     if self.port_host_input.is_none() {
@@ -373,24 +1032,52 @@ impl CreateContainerDialog {
 
     // Network input
     if self.network_input.is_none() {
-      self.network_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Network name (optional)")));
+      self.network_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .placeholder("Network name (optional)")
+          .default_value(prefill.network.unwrap_or_default())
+      }));
     }
-
-    // Advanced inputs.
-    if self.hostname_input.is_none() {
-      self.hostname_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Hostname (optional)")));
+    if self.dns_input.is_none() {
+      self.dns_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. 1.1.1.1")));
     }
-    if self.cpus_input.is_none() {
-      self.cpus_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. 1.5")));
+    if self.extra_host_input.is_none() {
+      self.extra_host_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. db.local:10.0.0.5")));
     }
-    if self.memory_input.is_none() {
-      self.memory_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. 512m, 2g")));
+
+    // Security inputs
+    if self.cap_input.is_none() {
+      self.cap_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. SYS_NICE")));
     }
-    if self.memory_swap_input.is_none() {
-      self.memory_swap_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. 1g, -1 = unlimited")));
+    if self.security_opt_input.is_none() {
+      self.security_opt_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. no-new-privileges")));
     }
-    if self.pids_limit_input.is_none() {
-      self.pids_limit_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. 200")));
+
+    // Resource limit inputs re-render the dialog on each edit so
+    // validation errors show up inline while typing.
+    let resource_inputs = [
+      (&mut self.cpus_input, "e.g. 1.5"),
+      (&mut self.cpu_shares_input, "e.g. 512 (default 1024)"),
+      (&mut self.memory_input, "e.g. 512m, 2g"),
+      (&mut self.memory_swap_input, "e.g. 1g, -1 = unlimited"),
+      (&mut self.pids_limit_input, "e.g. 200"),
+    ];
+    for (slot, placeholder) in resource_inputs {
+      if slot.is_none() {
+        let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+        cx.subscribe(&input, |_this, _state, ev: &InputEvent, cx| {
+          if matches!(ev, InputEvent::Change) {
+            cx.notify();
+          }
+        })
+        .detach();
+        *slot = Some(input);
+      }
+    }
+
+    // Advanced inputs.
+    if self.hostname_input.is_none() {
+      self.hostname_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Hostname (optional)")));
     }
     if self.label_key_input.is_none() {
       self.label_key_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("Key")));
@@ -416,6 +1103,42 @@ impl CreateContainerDialog {
     }
   }
 
+  fn resource_limits(&self, cx: &App) -> (ResourceLimits, ResourceErrors) {
+    let read = |input: &Option<Entity<InputState>>| {
+      input
+        .as_ref()
+        .map(|s| s.read(cx).text().to_string())
+        .unwrap_or_default()
+    };
+    let (cpus, cpu_shares, memory, memory_swap, pids_limit) = (
+      read(&self.cpus_input),
+      read(&self.cpu_shares_input),
+      read(&self.memory_input),
+      read(&self.memory_swap_input),
+      read(&self.pids_limit_input),
+    );
+    parse_resource_limits(ResourceInputs {
+      cpus: &cpus,
+      cpu_shares: &cpu_shares,
+      memory: &memory,
+      memory_swap: &memory_swap,
+      pids_limit: &pids_limit,
+    })
+  }
+
+  /// Check the inputs before submitting. Jumps to the Resources tab when a
+  /// limit is malformed so the inline error is visible, rather than
+  /// creating the container without the limit.
+  pub fn validate(&mut self, cx: &mut Context<'_, Self>) -> bool {
+    let (_, errors) = self.resource_limits(cx);
+    if errors.is_empty() {
+      return true;
+    }
+    self.active_tab = RESOURCES_TAB;
+    cx.notify();
+    false
+  }
+
   pub fn get_options(&self, cx: &App, start_after_create: bool) -> CreateContainerOptions {
     let image = self
       .image_input
@@ -494,26 +1217,7 @@ impl CreateContainerDialog {
       .as_ref()
       .map(|s| s.read(cx).text().to_string())
       .filter(|s| !s.is_empty());
-    let cpus = self
-      .cpus_input
-      .as_ref()
-      .and_then(|s| s.read(cx).text().to_string().parse::<f64>().ok())
-      .filter(|n| *n > 0.0);
-    let memory_bytes = self
-      .memory_input
-      .as_ref()
-      .map(|s| s.read(cx).text().to_string())
-      .and_then(|s| parse_size_bytes(&s));
-    let memory_swap_bytes = self
-      .memory_swap_input
-      .as_ref()
-      .map(|s| s.read(cx).text().to_string())
-      .and_then(|s| parse_size_bytes(&s));
-    let pids_limit = self
-      .pids_limit_input
-      .as_ref()
-      .and_then(|s| s.read(cx).text().to_string().parse::<i64>().ok())
-      .filter(|n| *n > 0);
+    let (limits, _) = self.resource_limits(cx);
     let labels: Vec<(String, String)> = self.labels.iter().filter(|(k, _)| !k.is_empty()).cloned().collect();
 
     CreateContainerOptions {
@@ -535,10 +1239,11 @@ impl CreateContainerDialog {
       network,
       hostname,
       labels,
-      cpus,
-      memory_bytes,
-      memory_swap_bytes,
-      pids_limit,
+      cpus: limits.cpus,
+      cpu_shares: limits.cpu_shares,
+      memory_bytes: limits.memory_bytes,
+      memory_swap_bytes: limits.memory_swap_bytes,
+      pids_limit: limits.pids_limit,
       healthcheck_cmd: {
         let txt = self
           .healthcheck_cmd_input
@@ -590,7 +1295,106 @@ impl CreateContainerDialog {
           .and_then(|s| s.read(cx).text().to_string().parse::<i64>().ok())
           .filter(|n| *n > 0)
       },
+      dns: self.dns.clone(),
+      extra_hosts: self.extra_hosts.clone(),
+      cap_add: self.cap_add.clone(),
+      cap_drop: self.cap_drop.clone(),
+      security_opt: self.security_opt.clone(),
+    }
+  }
+
+  /// Toggle `cap` in the add or drop list. A capability can't be both
+  /// added and dropped, so picking it in one list clears it from the other.
+  fn toggle_capability(&mut self, add: bool, cap: &str) {
+    let (list, other) = if add {
+      (&mut self.cap_add, &mut self.cap_drop)
+    } else {
+      (&mut self.cap_drop, &mut self.cap_add)
+    };
+    if let Some(pos) = list.iter().position(|c| c == cap) {
+      list.remove(pos);
+    } else {
+      list.push(cap.to_string());
+      other.retain(|c| c != cap);
+    }
+  }
+
+  /// Validate the capability typed into the Security tab and add it to
+  /// the add or drop list.
+  fn add_typed_capability(&mut self, add: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let text = self
+      .cap_input
+      .as_ref()
+      .map(|s| s.read(cx).text().to_string())
+      .unwrap_or_default();
+    if text.trim().is_empty() {
+      return;
+    }
+    match parse_capability(&text) {
+      Ok(cap) => {
+        let list = if add { &self.cap_add } else { &self.cap_drop };
+        if !list.contains(&cap) {
+          self.toggle_capability(add, &cap);
+        }
+        self.security_error = None;
+        self.cap_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. SYS_NICE")));
+      }
+      Err(e) => self.security_error = Some(e),
+    }
+    cx.notify();
+  }
+
+  /// Pick a `.env` file and merge its variables into the env list.
+  /// Keys already present are overwritten so re-importing is idempotent.
+  fn import_env_file(cx: &mut Context<'_, Self>) {
+    let opts = gpui::PathPromptOptions {
+      files: true,
+      directories: false,
+      multiple: false,
+      prompt: Some("Import .env File".into()),
+    };
+    let rx = cx.prompt_for_paths(opts);
+    cx.spawn(async move |this, cx| {
+      let Ok(Ok(Some(paths))) = rx.await else {
+        return;
+      };
+      let Some(path) = paths.into_iter().next() else {
+        return;
+      };
+      let display = path.display().to_string();
+      let contents = cx
+        .background_executor()
+        .spawn(async move { std::fs::read_to_string(&path) })
+        .await;
+
+      let _ = this.update(cx, |this, cx| {
+        match contents {
+          Ok(contents) => this.merge_env_text(&contents, &display),
+          Err(e) => {
+            this.env_import_summary = None;
+            this.env_import_errors = vec![format!("Failed to read {display}: {e}")];
+          }
+        }
+        cx.notify();
+      });
+    })
+    .detach();
+  }
+
+  /// Parse `.env`-formatted text and merge it into the env list. Malformed
+  /// lines are listed under the import row; the valid ones still land.
+  fn merge_env_text(&mut self, contents: &str, source: &str) {
+    let (vars, errors) = parse_env_file(contents);
+    let count = vars.len();
+    for var in vars {
+      if let Some(existing) = self.env_vars.iter_mut().find(|e| e.key == var.key) {
+        existing.value = var.value;
+      } else {
+        self.env_vars.push(var);
+      }
     }
+    self.env_import_summary = Some(env_import_summary(count, errors.len(), source));
+    self.env_import_errors = errors;
   }
 
   fn render_form_row(label: &'static str, content: impl IntoElement, colors: &DialogColors) -> gpui::Div {
@@ -638,6 +1442,105 @@ impl CreateContainerDialog {
       .child(div().text_xs().text_color(colors.muted_foreground).child(title))
   }
 
+  /// Under the Image field: whether the typed reference is local (with its
+  /// size and a picker for its other tags/digests) or will be pulled, plus
+  /// matching local images to pick from.
+  fn render_image_picker(&self, colors: &DialogColors, cx: &mut Context<'_, Self>) -> Option<gpui::Div> {
+    let typed = self.image_input.as_ref()?.read(cx).text().to_string();
+    let typed = typed.trim();
+    if typed.is_empty() {
+      return None;
+    }
+    let state = crate::state::docker_state(cx);
+    let images = &state.read(cx).images;
+    let local = images.iter().find(|i| i.matches_ref(typed)).cloned();
+    let suggestions = image_suggestions(images, typed);
+
+    let set_image = |reference: String| {
+      cx.listener(move |this: &mut Self, _ev: &gpui::ClickEvent, window, cx| {
+        if let Some(input) = &this.image_input {
+          input.update(cx, |state, cx| state.set_value(reference.clone(), window, cx));
+        }
+        cx.notify();
+      })
+    };
+
+    let status = match &local {
+      Some(image) => {
+        // Same image under other names: sibling tags and pinned digests
+        let alternatives: Vec<String> = image
+          .repo_tags
+          .iter()
+          .chain(&image.repo_digests)
+          .filter(|r| *r != "<none>:<none>" && r.as_str() != typed)
+          .cloned()
+          .collect();
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_center()
+          .justify_between()
+          .child(div().text_xs().text_color(colors.muted_foreground).child(format!(
+            "Local image · {} · {}",
+            image.short_id(),
+            image.display_size()
+          )))
+          .when(!alternatives.is_empty(), |el| {
+            let picks: Vec<_> = alternatives
+              .into_iter()
+              .map(|r| (r.clone(), Rc::new(set_image(r))))
+              .collect();
+            el.child(
+              Button::new("image-alternatives")
+                .label("Other tags")
+                .icon(IconName::ChevronDown)
+                .ghost()
+                .xsmall()
+                .dropdown_menu(move |menu, _window, _cx| {
+                  picks.iter().fold(menu, |menu, (reference, on_pick)| {
+                    let on_pick = on_pick.clone();
+                    menu.item(
+                      PopupMenuItem::new(reference.clone()).on_click(move |ev, window, cx| on_pick(ev, window, cx)),
+                    )
+                  })
+                }),
+            )
+          })
+      }
+      None => h_flex().w_full().child(
+        div()
+          .text_xs()
+          .text_color(colors.warning)
+          .child("Not available locally, it will be pulled when the container is created"),
+      ),
+    };
+
+    Some(
+      v_flex()
+        .w_full()
+        .px(px(16.))
+        .py(px(8.))
+        .gap(px(4.))
+        .border_b_1()
+        .border_color(colors.border)
+        .child(status)
+        .children(suggestions.into_iter().enumerate().map(|(i, (reference, size))| {
+          h_flex()
+            .id(("image-suggestion", i))
+            .w_full()
+            .px(px(8.))
+            .py(px(4.))
+            .rounded(px(4.))
+            .justify_between()
+            .cursor_pointer()
+            .hover(|el| el.bg(colors.sidebar))
+            .child(div().text_sm().text_color(colors.foreground).child(reference.clone()))
+            .child(div().text_xs().text_color(colors.muted_foreground).child(size))
+            .on_click(set_image(reference))
+        })),
+    )
+  }
+
   fn render_general_tab(&self, colors: &DialogColors, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let remove_after_stop = self.remove_after_stop;
     let privileged = self.privileged;
@@ -651,6 +1554,7 @@ impl CreateContainerDialog {
     let workdir_input = self.workdir_input.clone().unwrap();
     let platform_select = self.platform_select.clone().unwrap();
     let restart_policy_select = self.restart_policy_select.clone().unwrap();
+    let image_picker = self.render_image_picker(colors, cx);
 
     v_flex()
             .w_full()
@@ -660,6 +1564,7 @@ impl CreateContainerDialog {
                 div().w(px(250.)).child(Input::new(&image_input).small()),
                 colors,
             ))
+            .children(image_picker)
             // Name row
             .child(Self::render_form_row(
                 "Name",
@@ -984,15 +1889,76 @@ impl CreateContainerDialog {
   fn render_env_tab(&self, colors: &DialogColors, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let env_key_input = self.env_key_input.clone().unwrap();
     let env_value_input = self.env_value_input.clone().unwrap();
+    let env_paste_input = self.env_paste_input.clone().unwrap();
     let sidebar_color = colors.sidebar;
     let foreground_color = colors.foreground;
     let muted_color = colors.muted_foreground;
     let link_color = colors.link;
+    let danger_color = cx.theme().danger;
 
     v_flex()
             .w_full()
             .gap(px(8.))
             .p(px(16.))
+            // Import from a .env file
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_between()
+                    .items_center()
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(muted_color)
+                            .child("Imported values can be edited or removed before creating"),
+                    )
+                    .child(
+                        Button::new("import-env")
+                            .label("Import .env")
+                            .icon(IconName::FolderOpen)
+                            .xsmall()
+                            .ghost()
+                            .on_click(cx.listener(|_this, _ev, _window, cx| {
+                                Self::import_env_file(cx);
+                            })),
+                    ),
+            )
+            // Or paste the same format straight in
+            .child(
+                h_flex()
+                    .w_full()
+                    .gap(px(8.))
+                    .items_start()
+                    .child(div().flex_1().h(px(72.)).child(Input::new(&env_paste_input).small().h_full()))
+                    .child(
+                        Button::new("add-pasted-env")
+                            .label("Add")
+                            .xsmall()
+                            .ghost()
+                            .on_click(cx.listener(|this, _ev, window, cx| {
+                                let text = this.env_paste_input.as_ref()
+                                    .map(|s| s.read(cx).text().to_string())
+                                    .unwrap_or_default();
+                                if text.trim().is_empty() {
+                                    return;
+                                }
+                                this.merge_env_text(&text, "pasted text");
+                                // Recreate input to clear it
+                                this.env_paste_input = Some(cx.new(|cx| {
+                                    InputState::new(window, cx)
+                                        .multi_line(true)
+                                        .placeholder("Paste KEY=VALUE lines")
+                                }));
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .when_some(self.env_import_summary.clone(), |el, summary| {
+                el.child(div().text_xs().text_color(muted_color).child(summary))
+            })
+            .children(self.env_import_errors.iter().map(|err| {
+                div().text_xs().text_color(danger_color).child(err.clone())
+            }))
             // Add env var row
             .child(
                 h_flex()
@@ -1079,8 +2045,33 @@ impl CreateContainerDialog {
             }))
   }
 
-  fn render_network_tab(&self, colors: &DialogColors, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+  fn render_network_tab(&self, colors: &DialogColors, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let network_input = self.network_input.clone().unwrap();
+    let dns_input = self.dns_input.clone().unwrap();
+    let extra_host_input = self.extra_host_input.clone().unwrap();
+    let sidebar_color = colors.sidebar;
+    let foreground_color = colors.foreground;
+
+    let danger_color = cx.theme().danger;
+    let entry_row = |text: String, on_remove: gpui::AnyElement| {
+      h_flex()
+        .w_full()
+        .py(px(8.))
+        .px(px(12.))
+        .gap(px(8.))
+        .items_center()
+        .bg(sidebar_color)
+        .rounded(px(4.))
+        .child(
+          div()
+            .flex_1()
+            .text_sm()
+            .font_family("monospace")
+            .text_color(foreground_color)
+            .child(text),
+        )
+        .child(on_remove)
+    };
 
     v_flex()
       .w_full()
@@ -1100,14 +2091,331 @@ impl CreateContainerDialog {
           .text_color(colors.muted_foreground)
           .child("Leave empty for default bridge network"),
       )
+      // DNS servers (--dns)
+      .child(
+        h_flex()
+          .w_full()
+          .mt(px(8.))
+          .gap(px(8.))
+          .items_center()
+          .child(Label::new("DNS server").text_color(colors.foreground))
+          .child(div().flex_1().child(Input::new(&dns_input).small()))
+          .child(
+            Button::new("add-dns")
+              .icon(IconName::Plus)
+              .xsmall()
+              .ghost()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                let text = this
+                  .dns_input
+                  .as_ref()
+                  .map(|s| s.read(cx).text().to_string())
+                  .unwrap_or_default();
+                if text.trim().is_empty() {
+                  return;
+                }
+                match parse_dns_server(&text) {
+                  Ok(server) => {
+                    this.dns.push(server);
+                    this.network_error = None;
+                    this.dns_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. 1.1.1.1")));
+                  }
+                  Err(e) => this.network_error = Some(e),
+                }
+                cx.notify();
+              })),
+          ),
+      )
+      .children(self.dns.iter().enumerate().map(|(idx, server)| {
+        entry_row(
+          server.clone(),
+          Button::new(SharedString::from(format!("remove-dns-{idx}")))
+            .icon(IconName::Minus)
+            .xsmall()
+            .ghost()
+            .on_click(cx.listener(move |this, _ev, _window, cx| {
+              if idx < this.dns.len() {
+                this.dns.remove(idx);
+                cx.notify();
+              }
+            }))
+            .into_any_element(),
+        )
+      }))
+      // Extra hosts (--add-host)
+      .child(
+        h_flex()
+          .w_full()
+          .mt(px(8.))
+          .gap(px(8.))
+          .items_center()
+          .child(Label::new("Add host").text_color(colors.foreground))
+          .child(div().flex_1().child(Input::new(&extra_host_input).small()))
+          .child(
+            Button::new("add-extra-host")
+              .icon(IconName::Plus)
+              .xsmall()
+              .ghost()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                let text = this
+                  .extra_host_input
+                  .as_ref()
+                  .map(|s| s.read(cx).text().to_string())
+                  .unwrap_or_default();
+                if text.trim().is_empty() {
+                  return;
+                }
+                match parse_host_entry(&text) {
+                  Ok(entry) => {
+                    this.extra_hosts.push(entry);
+                    this.network_error = None;
+                    this.extra_host_input =
+                      Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. db.local:10.0.0.5")));
+                  }
+                  Err(e) => this.network_error = Some(e),
+                }
+                cx.notify();
+              })),
+          ),
+      )
+      .child(
+        div()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child("Extra /etc/hosts entries as name:ip"),
+      )
+      .children(self.extra_hosts.iter().enumerate().map(|(idx, entry)| {
+        entry_row(
+          entry.clone(),
+          Button::new(SharedString::from(format!("remove-host-{idx}")))
+            .icon(IconName::Minus)
+            .xsmall()
+            .ghost()
+            .on_click(cx.listener(move |this, _ev, _window, cx| {
+              if idx < this.extra_hosts.len() {
+                this.extra_hosts.remove(idx);
+                cx.notify();
+              }
+            }))
+            .into_any_element(),
+        )
+      }))
+      .when_some(self.network_error.clone(), |el, err| {
+        el.child(div().text_xs().text_color(danger_color).child(err))
+      })
   }
 
-  fn render_advanced_tab(&mut self, colors: &DialogColors, cx: &mut Context<'_, Self>) -> gpui::Div {
-    let hostname = self.hostname_input.clone().unwrap();
+  /// Toggle buttons for the common capabilities plus any custom ones
+  /// already picked; `ALL` is offered for dropping only.
+  fn render_capability_chips(add: bool, selected: &[String], cx: &mut Context<'_, Self>) -> gpui::Div {
+    let mut names: Vec<String> = if add { Vec::new() } else { vec!["ALL".to_string()] };
+    names.extend(COMMON_CAPABILITIES.iter().map(|c| (*c).to_string()));
+    let custom: Vec<String> = selected.iter().filter(|c| !names.contains(c)).cloned().collect();
+    names.extend(custom);
+    let prefix = if add { "cap-add" } else { "cap-drop" };
+
+    h_flex()
+      .w_full()
+      .px(px(16.))
+      .py(px(8.))
+      .gap(px(6.))
+      .flex_wrap()
+      .children(names.into_iter().map(|cap| {
+        let button = Button::new(SharedString::from(format!("{prefix}-{cap}")))
+          .label(cap.clone())
+          .xsmall();
+        let button = if selected.contains(&cap) {
+          button.primary()
+        } else {
+          button.outline()
+        };
+        button.on_click(cx.listener(move |this, _ev, _window, cx| {
+          this.toggle_capability(add, &cap);
+          cx.notify();
+        }))
+      }))
+  }
+
+  fn render_security_tab(&self, colors: &DialogColors, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    let cap_input = self.cap_input.clone().unwrap();
+    let security_opt_input = self.security_opt_input.clone().unwrap();
+    let danger_color = cx.theme().danger;
+    let sidebar_color = colors.sidebar;
+    let foreground_color = colors.foreground;
+
+    let add_chips = Self::render_capability_chips(true, &self.cap_add, cx);
+    let drop_chips = Self::render_capability_chips(false, &self.cap_drop, cx);
+
+    v_flex()
+      .w_full()
+      .when(self.privileged, |el| {
+        el.child(
+          div()
+            .px(px(16.))
+            .py(px(8.))
+            .text_xs()
+            .text_color(colors.warning)
+            .child("Privileged containers already have every capability"),
+        )
+      })
+      .child(Self::render_section_header("Add capabilities (--cap-add)", colors))
+      .child(add_chips)
+      .child(Self::render_section_header("Drop capabilities (--cap-drop)", colors))
+      .child(drop_chips)
+      .child(
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(8.))
+          .gap(px(8.))
+          .items_center()
+          .child(Label::new("Other").text_color(colors.foreground))
+          .child(div().flex_1().child(Input::new(&cap_input).small()))
+          .child(
+            Button::new("cap-add-typed")
+              .label("Add")
+              .ghost()
+              .small()
+              .on_click(cx.listener(|this, _ev, window, cx| this.add_typed_capability(true, window, cx))),
+          )
+          .child(
+            Button::new("cap-drop-typed")
+              .label("Drop")
+              .ghost()
+              .small()
+              .on_click(cx.listener(|this, _ev, window, cx| this.add_typed_capability(false, window, cx))),
+          ),
+      )
+      .child(Self::render_section_header("Security options (--security-opt)", colors))
+      .child(
+        h_flex()
+          .w_full()
+          .px(px(16.))
+          .py(px(8.))
+          .gap(px(8.))
+          .items_center()
+          .child(div().flex_1().child(Input::new(&security_opt_input).small()))
+          .child(
+            Button::new("add-security-opt")
+              .icon(IconName::Plus)
+              .xsmall()
+              .ghost()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                let text = this
+                  .security_opt_input
+                  .as_ref()
+                  .map(|s| s.read(cx).text().to_string())
+                  .unwrap_or_default();
+                if text.trim().is_empty() {
+                  return;
+                }
+                match parse_security_opt(&text) {
+                  Ok(opt) => {
+                    if !this.security_opt.contains(&opt) {
+                      this.security_opt.push(opt);
+                    }
+                    this.security_error = None;
+                    this.security_opt_input =
+                      Some(cx.new(|cx| InputState::new(window, cx).placeholder("e.g. no-new-privileges")));
+                  }
+                  Err(e) => this.security_error = Some(e),
+                }
+                cx.notify();
+              })),
+          ),
+      )
+      .children(self.security_opt.iter().enumerate().map(|(idx, opt)| {
+        h_flex()
+          .mx(px(16.))
+          .mb(px(4.))
+          .py(px(8.))
+          .px(px(12.))
+          .gap(px(8.))
+          .items_center()
+          .bg(sidebar_color)
+          .rounded(px(4.))
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .font_family("monospace")
+              .text_color(foreground_color)
+              .child(opt.clone()),
+          )
+          .child(
+            Button::new(SharedString::from(format!("remove-security-opt-{idx}")))
+              .icon(IconName::Minus)
+              .xsmall()
+              .ghost()
+              .on_click(cx.listener(move |this, _ev, _window, cx| {
+                if idx < this.security_opt.len() {
+                  this.security_opt.remove(idx);
+                  cx.notify();
+                }
+              })),
+          )
+      }))
+      .when_some(self.security_error.clone(), |el, err| {
+        el.child(
+          div()
+            .px(px(16.))
+            .py(px(4.))
+            .text_xs()
+            .text_color(danger_color)
+            .child(err),
+        )
+      })
+  }
+
+  fn render_resources_tab(&self, colors: &DialogColors, cx: &mut Context<'_, Self>) -> gpui::Div {
     let cpus = self.cpus_input.clone().unwrap();
+    let shares = self.cpu_shares_input.clone().unwrap();
     let mem = self.memory_input.clone().unwrap();
     let mem_swap = self.memory_swap_input.clone().unwrap();
     let pids = self.pids_limit_input.clone().unwrap();
+    let (_, errors) = self.resource_limits(cx);
+    let danger_color = cx.theme().danger;
+
+    let row = |label: &'static str, input: &Entity<InputState>, error: Option<String>| {
+      v_flex()
+        .w_full()
+        .py(px(10.))
+        .px(px(16.))
+        .gap(px(4.))
+        .border_b_1()
+        .border_color(colors.border)
+        .child(
+          h_flex()
+            .w_full()
+            .justify_between()
+            .items_center()
+            .child(Label::new(label).text_color(colors.foreground))
+            .child(div().w(px(180.)).child(Input::new(input).small())),
+        )
+        .when_some(error, |el, err| {
+          el.child(div().text_xs().text_color(danger_color).child(err))
+        })
+    };
+
+    v_flex()
+      .w_full()
+      .child(
+        div()
+          .px(px(16.))
+          .py(px(8.))
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child("Leave a field blank for no limit."),
+      )
+      .child(row("CPUs", &cpus, errors.cpus))
+      .child(row("CPU shares", &shares, errors.cpu_shares))
+      .child(row("Memory", &mem, errors.memory))
+      .child(row("Memory + swap", &mem_swap, errors.memory_swap))
+      .child(row("PIDs limit", &pids, errors.pids_limit))
+  }
+
+  fn render_advanced_tab(&mut self, colors: &DialogColors, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let hostname = self.hostname_input.clone().unwrap();
     let label_key = self.label_key_input.clone().unwrap();
     let label_value = self.label_value_input.clone().unwrap();
     let hc_cmd = self.healthcheck_cmd_input.clone().unwrap();
@@ -1133,33 +2441,6 @@ impl CreateContainerDialog {
 
     let mut col = v_flex()
       .w_full()
-      .child(
-        div()
-          .px(px(16.))
-          .py(px(8.))
-          .text_xs()
-          .text_color(colors.muted_foreground)
-          .child("Resource limits"),
-      )
-      .child(row(
-        "CPUs",
-        div().w(px(180.)).child(Input::new(&cpus).small()).into_any_element(),
-      ))
-      .child(row(
-        "Memory",
-        div().w(px(180.)).child(Input::new(&mem).small()).into_any_element(),
-      ))
-      .child(row(
-        "Memory + swap",
-        div()
-          .w(px(180.))
-          .child(Input::new(&mem_swap).small())
-          .into_any_element(),
-      ))
-      .child(row(
-        "PIDs limit",
-        div().w(px(180.)).child(Input::new(&pids).small()).into_any_element(),
-      ))
       .child(row(
         "Hostname",
         div()
@@ -1303,6 +2584,7 @@ impl Render for CreateContainerDialog {
       muted_foreground: theme_colors.muted_foreground,
       sidebar: theme_colors.sidebar,
       link: theme_colors.link,
+      warning: theme_colors.warning,
     };
 
     let active_tab = self.active_tab;
@@ -1311,12 +2593,20 @@ impl Render for CreateContainerDialog {
     let env_count = self.env_vars.len();
 
     let labels_count = self.labels.len();
+    let security_count = self.cap_add.len() + self.cap_drop.len() + self.security_opt.len();
+    let resources_invalid = !self.resource_limits(cx).1.is_empty();
     let tabs = [
       "General".to_string(),
       format!("Ports ({ports_count})"),
       format!("Volumes ({volumes_count})"),
       format!("Env ({env_count})"),
       "Network".to_string(),
+      if resources_invalid {
+        "Resources (!)".to_string()
+      } else {
+        "Resources".to_string()
+      },
+      format!("Security ({security_count})"),
       format!("Advanced ({labels_count} lbls)"),
     ];
 
@@ -1357,7 +2647,287 @@ impl Render for CreateContainerDialog {
                     .when(active_tab == 2, |el| el.child(self.render_volumes_tab(&colors, cx)))
                     .when(active_tab == 3, |el| el.child(self.render_env_tab(&colors, cx)))
                     .when(active_tab == 4, |el| el.child(self.render_network_tab(&colors, cx)))
-                    .when(active_tab == 5, |el| el.child(self.render_advanced_tab(&colors, cx))),
+                    .when(active_tab == RESOURCES_TAB, |el| el.child(self.render_resources_tab(&colors, cx)))
+                    .when(active_tab == 6, |el| el.child(self.render_security_tab(&colors, cx)))
+                    .when(active_tab == 7, |el| el.child(self.render_advanced_tab(&colors, cx))),
             )
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn image(tags: &[&str], size: i64) -> ImageInfo {
+    ImageInfo {
+      id: "sha256:0123456789abcdef".to_string(),
+      repo_tags: tags.iter().map(ToString::to_string).collect(),
+      repo_digests: vec![],
+      created: None,
+      size,
+      virtual_size: None,
+      labels: std::collections::HashMap::new(),
+      architecture: None,
+      os: None,
+    }
+  }
+
+  #[test]
+  fn test_image_suggestions() {
+    let images = vec![
+      image(&["nginx:latest", "nginx:1.25"], 1_000),
+      image(&["bitnami/nginx:1.25"], 2_000),
+      image(&["<none>:<none>"], 10),
+      image(&["postgres:16"], 3_000),
+    ];
+    let refs: Vec<String> = image_suggestions(&images, "ngi").into_iter().map(|(r, _)| r).collect();
+    assert_eq!(refs, vec!["nginx:1.25", "nginx:latest", "bitnami/nginx:1.25"]);
+    // An exact match isn't offered back
+    assert!(image_suggestions(&images, "postgres:16").is_empty());
+    assert!(image_suggestions(&images, "  ").is_empty());
+  }
+
+  #[test]
+  fn test_parse_size_bytes() {
+    assert_eq!(parse_size_bytes(""), Ok(None));
+    assert_eq!(parse_size_bytes("512m"), Ok(Some(512 * 1024 * 1024)));
+    assert_eq!(parse_size_bytes("2G"), Ok(Some(2 * 1024 * 1024 * 1024)));
+    assert_eq!(parse_size_bytes("1.5gb"), Ok(Some(3 * 512 * 1024 * 1024)));
+    assert_eq!(parse_size_bytes("256MiB"), Ok(Some(256 * 1024 * 1024)));
+    assert_eq!(parse_size_bytes("4096"), Ok(Some(4096)));
+    assert!(parse_size_bytes("512x").is_err());
+    assert!(parse_size_bytes("lots").is_err());
+    assert!(parse_size_bytes("0m").is_err());
+    assert!(parse_size_bytes("-5g").is_err());
+  }
+
+  #[test]
+  fn test_parse_resource_limits() {
+    let (limits, errors) = parse_resource_limits(ResourceInputs {
+      cpus: "1.5",
+      cpu_shares: "512",
+      memory: "1g",
+      memory_swap: "2g",
+      pids_limit: "100",
+    });
+    assert!(errors.is_empty());
+    assert_eq!(limits.cpus, Some(1.5));
+    assert_eq!(limits.cpu_shares, Some(512));
+    assert_eq!(limits.memory_bytes, Some(1024 * 1024 * 1024));
+    assert_eq!(limits.memory_swap_bytes, Some(2 * 1024 * 1024 * 1024));
+    assert_eq!(limits.pids_limit, Some(100));
+
+    let (limits, errors) = parse_resource_limits(ResourceInputs::default());
+    assert!(errors.is_empty());
+    assert_eq!(limits, ResourceLimits::default());
+
+    let (limits, errors) = parse_resource_limits(ResourceInputs {
+      cpus: "two",
+      cpu_shares: "1",
+      memory: "512q",
+      memory_swap: "-1",
+      pids_limit: "0",
+    });
+    assert!(errors.cpus.is_some());
+    assert!(errors.cpu_shares.is_some());
+    assert!(errors.memory.is_some());
+    assert!(errors.memory_swap.is_none());
+    assert!(errors.pids_limit.is_some());
+    assert_eq!(limits.memory_bytes, None);
+    assert_eq!(limits.memory_swap_bytes, Some(-1));
+
+    let (_, errors) = parse_resource_limits(ResourceInputs {
+      memory: "1m",
+      ..ResourceInputs::default()
+    });
+    assert!(errors.memory.is_some());
+
+    let (_, errors) = parse_resource_limits(ResourceInputs {
+      memory: "1g",
+      memory_swap: "512m",
+      ..ResourceInputs::default()
+    });
+    assert!(errors.memory_swap.is_some());
+
+    let (_, errors) = parse_resource_limits(ResourceInputs {
+      memory_swap: "1g",
+      ..ResourceInputs::default()
+    });
+    assert!(errors.memory_swap.is_some());
+  }
+
+  #[test]
+  fn test_parse_host_entry_valid() {
+    assert_eq!(parse_host_entry("db.local:10.0.0.5").unwrap(), "db.local:10.0.0.5");
+    assert_eq!(parse_host_entry(" api : 127.0.0.1 ").unwrap(), "api:127.0.0.1");
+    assert_eq!(parse_host_entry("v6:::1").unwrap(), "v6:::1");
+    assert_eq!(parse_host_entry("db_1:10.0.0.1").unwrap(), "db_1:10.0.0.1");
+    assert_eq!(
+      parse_host_entry("host.docker.internal:host-gateway").unwrap(),
+      "host.docker.internal:host-gateway"
+    );
+  }
+
+  #[test]
+  fn test_parse_host_entry_invalid() {
+    assert!(parse_host_entry("no-ip").is_err());
+    assert!(parse_host_entry(":10.0.0.1").is_err());
+    assert!(parse_host_entry("db:not-an-ip").is_err());
+    assert!(parse_host_entry("my host:10.0.0.1").is_err());
+    assert!(parse_host_entry("-db:10.0.0.1").is_err());
+    assert!(parse_host_entry("db..local:10.0.0.1").is_err());
+  }
+
+  #[test]
+  fn test_parse_dns_server() {
+    assert_eq!(parse_dns_server("1.1.1.1").unwrap(), "1.1.1.1");
+    assert_eq!(
+      parse_dns_server(" 2606:4700:4700::1111 ").unwrap(),
+      "2606:4700:4700::1111"
+    );
+    assert!(parse_dns_server("dns.google").is_err());
+  }
+
+  #[test]
+  fn test_parse_capability() {
+    assert_eq!(parse_capability("NET_ADMIN").unwrap(), "NET_ADMIN");
+    assert_eq!(parse_capability(" cap_sys_ptrace ").unwrap(), "SYS_PTRACE");
+    assert_eq!(parse_capability("all").unwrap(), "ALL");
+    assert!(parse_capability("NET_WIZARD").is_err());
+    assert!(parse_capability("").is_err());
+  }
+
+  #[test]
+  fn test_parse_security_opt() {
+    assert_eq!(parse_security_opt("no-new-privileges").unwrap(), "no-new-privileges");
+    assert_eq!(parse_security_opt("seccomp=unconfined").unwrap(), "seccomp=unconfined");
+    assert_eq!(parse_security_opt("label:disable").unwrap(), "label:disable");
+    assert!(parse_security_opt("seccomp=").is_err());
+    assert!(parse_security_opt("bogus=1").is_err());
+    assert!(parse_security_opt("apparmor = x").is_err());
+  }
+
+  #[test]
+  fn test_parse_env_file() {
+    let contents = r#"
+# database
+DB_HOST=localhost
+export DB_PORT = 5432
+GREETING="hello world" # trailing comment
+MULTI="line1\nline2 \"quoted\""
+LITERAL='$HOME \n stays'
+URL=http://example.com/#anchor
+EMPTY=
+PLAIN=value # comment
+"#;
+    let (vars, errors) = parse_env_file(contents);
+    assert!(errors.is_empty(), "{errors:?}");
+    let pairs: Vec<(&str, &str)> = vars.iter().map(|v| (v.key.as_str(), v.value.as_str())).collect();
+    assert_eq!(
+      pairs,
+      vec![
+        ("DB_HOST", "localhost"),
+        ("DB_PORT", "5432"),
+        ("GREETING", "hello world"),
+        ("MULTI", "line1\nline2 \"quoted\""),
+        ("LITERAL", "$HOME \\n stays"),
+        ("URL", "http://example.com/#anchor"),
+        ("EMPTY", ""),
+        ("PLAIN", "value"),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_env_import_summary() {
+    assert_eq!(env_import_summary(1, 0, "app.env"), "Imported 1 variable from app.env");
+    assert_eq!(
+      env_import_summary(3, 2, "pasted text"),
+      "Imported 3 variables from pasted text (2 lines skipped)"
+    );
+  }
+
+  #[test]
+  fn test_parse_env_file_errors() {
+    let contents = "GOOD=1\nno equals sign\n1BAD=x\nOPEN=\"unterminated\nSINGLE='open\nJUNK=\"a\" b\nALSO_GOOD=2";
+    let (vars, errors) = parse_env_file(contents);
+    assert_eq!(vars.len(), 2);
+    assert_eq!(errors.len(), 5);
+    assert!(errors[0].starts_with("line 2:"));
+    assert!(errors[1].starts_with("line 3:"));
+    assert!(errors[4].starts_with("line 6:"));
+  }
+
+  #[test]
+  fn test_options_from_inspect() {
+    let raw = r#"{
+      "Image": "sha256:abc",
+      "Name": "/web",
+      "Config": {
+        "Image": "nginx:1.27",
+        "Entrypoint": ["/docker-entrypoint.sh"],
+        "Cmd": ["nginx", "-g", "daemon off;"],
+        "Env": ["PATH=/usr/bin", "MODE=a=b"]
+      },
+      "HostConfig": {
+        "NetworkMode": "backend",
+        "Dns": ["1.1.1.1"],
+        "ExtraHosts": ["db:10.0.0.5"],
+        "CapAdd": ["CAP_NET_ADMIN"],
+        "SecurityOpt": ["no-new-privileges"],
+        "RestartPolicy": { "Name": "unless-stopped", "MaximumRetryCount": 0 },
+        "PortBindings": {
+          "80/tcp": [{ "HostIp": "", "HostPort": "8080" }],
+          "53/udp": null
+        }
+      },
+      "Mounts": [
+        { "Type": "bind", "Source": "/srv/site", "Destination": "/usr/share/nginx/html", "RW": false },
+        { "Type": "volume", "Name": "web-cache", "Source": "/var/lib/docker/volumes/web-cache/_data", "Destination": "/cache", "RW": true },
+        { "Type": "volume", "Name": "3f1c2d4e5a6b7c8d9e0f1a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d", "Destination": "/data", "RW": true },
+        { "Type": "tmpfs", "Destination": "/tmp", "RW": true }
+      ]
+    }"#;
+    let options = options_from_inspect(raw).unwrap();
+    assert_eq!(options.image, "nginx:1.27");
+    assert_eq!(options.name, None);
+    assert_eq!(options.entrypoint.as_deref(), Some("/docker-entrypoint.sh"));
+    assert_eq!(options.command.as_deref(), Some("nginx -g 'daemon off;'"));
+    assert_eq!(
+      options.env_vars,
+      vec![
+        ("PATH".to_string(), "/usr/bin".to_string()),
+        ("MODE".to_string(), "a=b".to_string())
+      ]
+    );
+    assert_eq!(
+      options.ports,
+      vec![
+        (String::new(), "53".to_string(), "udp".to_string()),
+        ("8080".to_string(), "80".to_string(), "tcp".to_string())
+      ]
+    );
+    assert_eq!(
+      options.volumes,
+      vec![
+        ("/srv/site".to_string(), "/usr/share/nginx/html".to_string(), true),
+        ("web-cache".to_string(), "/cache".to_string(), false)
+      ]
+    );
+    assert_eq!(options.network.as_deref(), Some("backend"));
+    assert_eq!(options.restart_policy, RestartPolicy::UnlessStopped);
+    assert_eq!(options.dns, vec!["1.1.1.1".to_string()]);
+    assert_eq!(options.extra_hosts, vec!["db:10.0.0.5".to_string()]);
+    assert_eq!(options.cap_add, vec!["NET_ADMIN".to_string()]);
+    assert!(options.cap_drop.is_empty());
+    assert_eq!(options.security_opt, vec!["no-new-privileges".to_string()]);
+
+    let bridge = r#"{ "Config": { "Image": "redis" }, "HostConfig": { "NetworkMode": "bridge" } }"#;
+    let options = options_from_inspect(bridge).unwrap();
+    assert_eq!(options.network, None);
+    assert_eq!(options.command, None);
+    assert_eq!(options.restart_policy, RestartPolicy::No);
+
+    assert!(options_from_inspect("Failed to inspect: no such container").is_none());
+  }
+}
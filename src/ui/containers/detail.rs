@@ -1,16 +1,21 @@
 use gpui::{App, Entity, SharedString, Styled, Window, div, prelude::*, px};
 use gpui_component::{
-  Icon, IconName, Selectable, Sizable,
+  Disableable, Icon, IconName, Selectable, Sizable,
   button::{Button, ButtonVariants},
   h_flex,
   input::{Input, InputState},
+  menu::{DropdownMenu, PopupMenuItem},
   scroll::ScrollableElement,
   tab::{Tab, TabBar},
   theme::ActiveTheme,
   v_flex,
 };
+use std::collections::VecDeque;
 use std::rc::Rc;
 
+/// Number of samples kept per Stats tab chart
+pub const STATS_HISTORY_SAMPLES: usize = 60;
+
 // Re-export from state module for backwards compatibility
 pub use crate::state::ContainerDetailTab;
 
@@ -32,13 +37,43 @@ fn format_bytes(bytes: u64) -> String {
   bytesize::ByteSize(bytes).to_string()
 }
 
+/// Zero-based (line, column) of every case-insensitive occurrence of
+/// `query` in `text`, for the Inspect tab's find box.
+pub fn text_matches(text: &str, query: &str) -> Vec<(u32, u32)> {
+  let query = query.to_lowercase();
+  if query.is_empty() {
+    return Vec::new();
+  }
+  let mut matches = Vec::new();
+  for (line_no, line) in text.lines().enumerate() {
+    let lower = line.to_lowercase();
+    let mut from = 0;
+    while let Some(pos) = lower[from..].find(&query) {
+      let col = lower[..from + pos].chars().count();
+      matches.push((
+        u32::try_from(line_no).unwrap_or(u32::MAX),
+        u32::try_from(col).unwrap_or(u32::MAX),
+      ));
+      from += pos + query.len();
+    }
+  }
+  matches
+}
+
+use super::create_dialog::RestartPolicy;
 use crate::assets::AppIcon;
-use crate::docker::{ContainerFileEntry, ContainerInfo};
-use crate::terminal::TerminalView;
-use crate::ui::components::{FileExplorer, FileExplorerConfig, FileExplorerState, ProcessView};
+use crate::docker::{ContainerExtras, ContainerFileEntry, ContainerInfo};
+use crate::state::{AppSettings, settings_state};
+use crate::terminal::{AttachStream, EXEC_SHELLS, TerminalView};
+use crate::ui::components::{
+  CopyCallback, FileExplorer, FileExplorerConfig, FileExplorerState, ProcessView, copy_button, mask_secret_lines,
+};
 
 type TabChangeCallback = Rc<dyn Fn(&ContainerDetailTab, &mut Window, &mut App) + 'static>;
+type ShellChangeCallback = Rc<dyn Fn(&Option<String>, &mut Window, &mut App) + 'static>;
 type RefreshCallback = Rc<dyn Fn(&(), &mut Window, &mut App) + 'static>;
+type InspectModeCallback = Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>;
+type FindStepCallback = Rc<dyn Fn(&isize, &mut Window, &mut App) + 'static>;
 type FileNavigateCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type FileSelectCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 type CloseViewerCallback = Rc<dyn Fn(&(), &mut Window, &mut App) + 'static>;
@@ -55,7 +90,14 @@ pub struct ContainerTabState {
   pub logs_follow: bool,
   /// Include RFC3339 timestamps in log lines.
   pub logs_timestamps: bool,
+  /// Full `docker inspect` JSON, fetched once per selected container
   pub inspect: String,
+  /// `inspect` trimmed to the commonly needed fields (see `inspect_summary`)
+  pub inspect_summary: String,
+  /// Inspect tab shows `inspect` instead of `inspect_summary`
+  pub inspect_raw: bool,
+  /// Show env values the secret patterns would otherwise mask
+  pub inspect_reveal_secrets: bool,
   pub inspect_loading: bool,
   pub current_path: String,
   pub files: Vec<ContainerFileEntry>,
@@ -70,15 +112,22 @@ pub struct ContainerTabState {
   pub file_content_loading: bool,
   /// Error when loading file content failed
   pub file_content_error: Option<String>,
+  /// Selected file is open for editing
+  pub file_editing: bool,
   /// Structured extras from container inspect (health, `restart_count`, etc).
-  pub container_extras: Option<crate::docker::ContainerExtras>,
+  pub container_extras: Option<ContainerExtras>,
   /// Latest container stats sample (None if not yet loaded / unavailable).
   pub stats_latest: Option<crate::docker::ContainerStats>,
-  /// Rolling history (last 60 samples) for sparkline charts.
-  pub stats_cpu: Vec<f64>,
-  pub stats_mem_pct: Vec<f64>,
-  pub stats_net: Vec<f64>,
-  pub stats_disk: Vec<f64>,
+  /// When `stats_latest` arrived, for diffing network rates
+  pub stats_latest_at: Option<std::time::Instant>,
+  /// Rolling history (last `STATS_HISTORY_SAMPLES`) for sparkline charts.
+  pub stats_cpu: VecDeque<f64>,
+  pub stats_mem_pct: VecDeque<f64>,
+  pub stats_net: VecDeque<f64>,
+  pub stats_disk: VecDeque<f64>,
+  /// Set once the container stops (or stats stop arriving): the charts
+  /// stay frozen on the last samples and the tab shows "no data".
+  pub stats_stale: bool,
 }
 
 impl Default for ContainerTabState {
@@ -89,6 +138,9 @@ impl Default for ContainerTabState {
       logs_follow: true,
       logs_timestamps: false,
       inspect: String::new(),
+      inspect_summary: String::new(),
+      inspect_raw: false,
+      inspect_reveal_secrets: false,
       inspect_loading: false,
       current_path: String::new(),
       files: Vec::new(),
@@ -98,12 +150,15 @@ impl Default for ContainerTabState {
       file_content: String::new(),
       file_content_loading: false,
       file_content_error: None,
+      file_editing: false,
       container_extras: None,
       stats_latest: None,
-      stats_cpu: Vec::with_capacity(60),
-      stats_mem_pct: Vec::with_capacity(60),
-      stats_net: Vec::with_capacity(60),
-      stats_disk: Vec::with_capacity(60),
+      stats_latest_at: None,
+      stats_cpu: VecDeque::with_capacity(STATS_HISTORY_SAMPLES),
+      stats_mem_pct: VecDeque::with_capacity(STATS_HISTORY_SAMPLES),
+      stats_net: VecDeque::with_capacity(STATS_HISTORY_SAMPLES),
+      stats_disk: VecDeque::with_capacity(STATS_HISTORY_SAMPLES),
+      stats_stale: false,
     }
   }
 }
@@ -115,6 +170,46 @@ impl ContainerTabState {
       ..Default::default()
     }
   }
+
+  /// Inspect tab text for the current Summary / Raw JSON mode, with
+  /// secret env values masked unless revealed
+  pub fn inspect_text(&self, settings: &AppSettings) -> String {
+    let text = if self.inspect_raw {
+      &self.inspect
+    } else {
+      &self.inspect_summary
+    };
+    if self.inspect_reveal_secrets || !settings.mask_secrets {
+      text.clone()
+    } else {
+      mask_secret_lines(text, |key| settings.is_secret_key(key))
+    }
+  }
+
+  /// Append a stats sample to every rolling series, dropping the oldest
+  /// once a series holds `STATS_HISTORY_SAMPLES`. Network rates come
+  /// from the previous sample.
+  #[allow(clippy::cast_precision_loss)]
+  pub fn push_stats(&mut self, mut s: crate::docker::ContainerStats) {
+    let now = std::time::Instant::now();
+    if let (Some(prev), Some(at)) = (&self.stats_latest, self.stats_latest_at) {
+      s = s.with_rates(prev, now.duration_since(at));
+    }
+    for (series, value) in [
+      (&mut self.stats_cpu, s.cpu_percent),
+      (&mut self.stats_mem_pct, s.memory_percent),
+      (&mut self.stats_net, (s.network_rx_rate + s.network_tx_rate) as f64),
+      (&mut self.stats_disk, (s.block_read_rate + s.block_write_rate) as f64),
+    ] {
+      series.push_back(value);
+      while series.len() > STATS_HISTORY_SAMPLES {
+        series.pop_front();
+      }
+    }
+    self.stats_latest = Some(s);
+    self.stats_latest_at = Some(now);
+    self.stats_stale = false;
+  }
 }
 
 pub struct ContainerDetail {
@@ -122,20 +217,37 @@ pub struct ContainerDetail {
   active_tab: ContainerDetailTab,
   container_state: Option<ContainerTabState>,
   terminal_view: Option<Entity<TerminalView>>,
+  terminal_shell: Option<String>,
+  on_shell_change: Option<ShellChangeCallback>,
   process_view: Option<Entity<ProcessView>>,
   inspect_editor: Option<Entity<InputState>>,
+  inspect_find_input: Option<Entity<InputState>>,
+  /// (current match, total) for the inspect find box; `None` with no query
+  inspect_find_status: Option<(usize, usize)>,
+  on_inspect_mode: Option<InspectModeCallback>,
+  on_inspect_reveal: Option<InspectModeCallback>,
+  on_inspect_find_step: Option<FindStepCallback>,
   file_content_editor: Option<Entity<InputState>>,
   on_tab_change: Option<TabChangeCallback>,
   on_refresh_logs: Option<RefreshCallback>,
   on_toggle_logs_follow: Option<RefreshCallback>,
   on_toggle_logs_timestamps: Option<RefreshCallback>,
   on_clear_logs: Option<RefreshCallback>,
+  on_save_logs: Option<RefreshCallback>,
   logs_terminal: Option<Entity<TerminalView>>,
+  attach_view: Option<Entity<TerminalView>>,
+  attach_stream: Option<std::sync::Arc<AttachStream>>,
+  on_reattach: Option<RefreshCallback>,
   on_navigate_path: Option<FileNavigateCallback>,
   on_file_select: Option<FileSelectCallback>,
   on_close_file_viewer: Option<CloseViewerCallback>,
+  on_edit_file: Option<RefreshCallback>,
+  on_cancel_file_edit: Option<RefreshCallback>,
+  on_save_file: Option<FileSelectCallback>,
   on_symlink_click: Option<SymlinkClickCallback>,
   on_open_in_editor: Option<OpenInEditorCallback>,
+  on_copy: Option<CopyCallback>,
+  on_duplicate: Option<RefreshCallback>,
 }
 
 impl ContainerDetail {
@@ -145,20 +257,36 @@ impl ContainerDetail {
       active_tab: ContainerDetailTab::Info,
       container_state: None,
       terminal_view: None,
+      terminal_shell: None,
+      on_shell_change: None,
       process_view: None,
       inspect_editor: None,
+      inspect_find_input: None,
+      inspect_find_status: None,
+      on_inspect_mode: None,
+      on_inspect_reveal: None,
+      on_inspect_find_step: None,
       file_content_editor: None,
       on_tab_change: None,
       on_refresh_logs: None,
       on_toggle_logs_follow: None,
       on_toggle_logs_timestamps: None,
       on_clear_logs: None,
+      on_save_logs: None,
       logs_terminal: None,
+      attach_view: None,
+      attach_stream: None,
+      on_reattach: None,
       on_navigate_path: None,
       on_file_select: None,
       on_close_file_viewer: None,
+      on_edit_file: None,
+      on_cancel_file_edit: None,
+      on_save_file: None,
       on_symlink_click: None,
       on_open_in_editor: None,
+      on_copy: None,
+      on_duplicate: None,
     }
   }
 
@@ -182,6 +310,19 @@ impl ContainerDetail {
     self
   }
 
+  pub fn terminal_shell(mut self, shell: Option<String>) -> Self {
+    self.terminal_shell = shell;
+    self
+  }
+
+  pub fn on_shell_change<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&Option<String>, &mut Window, &mut App) + 'static,
+  {
+    self.on_shell_change = Some(Rc::new(callback));
+    self
+  }
+
   pub fn process_view(mut self, view: Option<Entity<ProcessView>>) -> Self {
     self.process_view = view;
     self
@@ -192,6 +333,43 @@ impl ContainerDetail {
     self
   }
 
+  pub fn inspect_find_input(mut self, input: Option<Entity<InputState>>) -> Self {
+    self.inspect_find_input = input;
+    self
+  }
+
+  pub fn inspect_find_status(mut self, status: Option<(usize, usize)>) -> Self {
+    self.inspect_find_status = status;
+    self
+  }
+
+  /// Called with `true` for "Raw JSON", `false` for "Summary"
+  pub fn on_inspect_mode<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&bool, &mut Window, &mut App) + 'static,
+  {
+    self.on_inspect_mode = Some(Rc::new(callback));
+    self
+  }
+
+  /// Called with `true` to show masked secret values, `false` to hide them
+  pub fn on_inspect_reveal<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&bool, &mut Window, &mut App) + 'static,
+  {
+    self.on_inspect_reveal = Some(Rc::new(callback));
+    self
+  }
+
+  /// Called with -1 / +1 to jump to the previous / next match
+  pub fn on_inspect_find_step<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&isize, &mut Window, &mut App) + 'static,
+  {
+    self.on_inspect_find_step = Some(Rc::new(callback));
+    self
+  }
+
   pub fn file_content_editor(mut self, editor: Option<Entity<InputState>>) -> Self {
     self.file_content_editor = editor;
     self
@@ -210,6 +388,24 @@ impl ContainerDetail {
     self
   }
 
+  pub fn attach_view(mut self, view: Option<Entity<TerminalView>>) -> Self {
+    self.attach_view = view;
+    self
+  }
+
+  pub fn attach_stream(mut self, stream: Option<std::sync::Arc<AttachStream>>) -> Self {
+    self.attach_stream = stream;
+    self
+  }
+
+  pub fn on_reattach<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_reattach = Some(Rc::new(callback));
+    self
+  }
+
   pub fn on_toggle_logs_follow<F>(mut self, callback: F) -> Self
   where
     F: Fn(&(), &mut Window, &mut App) + 'static,
@@ -234,6 +430,14 @@ impl ContainerDetail {
     self
   }
 
+  pub fn on_save_logs<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_save_logs = Some(Rc::new(callback));
+    self
+  }
+
   pub fn on_refresh_logs<F>(mut self, callback: F) -> Self
   where
     F: Fn(&(), &mut Window, &mut App) + 'static,
@@ -266,6 +470,31 @@ impl ContainerDetail {
     self
   }
 
+  pub fn on_edit_file<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_edit_file = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_cancel_file_edit<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_cancel_file_edit = Some(Rc::new(callback));
+    self
+  }
+
+  /// Write the edited file back (receives the file path)
+  pub fn on_save_file<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_save_file = Some(Rc::new(callback));
+    self
+  }
+
   pub fn on_symlink_click<F>(mut self, callback: F) -> Self
   where
     F: Fn(&str, &mut Window, &mut App) + 'static,
@@ -282,6 +511,23 @@ impl ContainerDetail {
     self
   }
 
+  /// Open the create dialog pre-filled from this container
+  pub fn on_duplicate<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_duplicate = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_copy<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_copy = Some(Rc::new(callback));
+    self
+  }
+
   fn render_empty(cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -308,6 +554,90 @@ impl ContainerDetail {
       )
   }
 
+  /// Restart policy with a dropdown that applies the new one in place
+  fn render_restart_policy_row(container: &ContainerInfo, current: &str, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let id = container.id.clone();
+    let current_policy = current.to_string();
+    h_flex()
+      .w_full()
+      .py(px(8.))
+      .justify_between()
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Restart policy"),
+      )
+      .child(
+        Button::new("container-restart-policy")
+          .label(SharedString::from(current.to_string()))
+          .icon(IconName::ChevronDown)
+          .ghost()
+          .xsmall()
+          .dropdown_menu(move |menu, _window, _cx| {
+            RestartPolicy::all().into_iter().fold(menu, |menu, policy| {
+              let id = id.clone();
+              let label = policy.label();
+              let is_current = label == current_policy;
+              menu.item(PopupMenuItem::new(label).checked(is_current).on_click(move |_, _, cx| {
+                if !is_current {
+                  crate::services::update_container_restart_policy(id.clone(), label.to_string(), cx);
+                }
+              }))
+            })
+          }),
+      )
+  }
+
+  /// CPU and memory limits with an "Edit" button for the limits dialog
+  #[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
+  fn render_limits_row(container: &ContainerInfo, extras: &ContainerExtras, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let cpus = extras.nano_cpus.map_or_else(
+      || "unlimited".to_string(),
+      |n| format!("{} CPUs", n as f64 / 1_000_000_000.0),
+    );
+    let memory = extras
+      .memory_limit
+      .map_or_else(|| "unlimited".to_string(), |b| format_bytes(b as u64));
+    let id = container.id.clone();
+    let name = container.name.clone();
+    let nano_cpus = extras.nano_cpus;
+    let memory_limit = extras.memory_limit;
+    h_flex()
+      .w_full()
+      .py(px(8.))
+      .justify_between()
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .child(div().text_sm().text_color(colors.muted_foreground).child("Limits"))
+      .child(
+        h_flex()
+          .gap(px(8.))
+          .items_center()
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.foreground)
+              .child(format!("{cpus} · {memory} memory")),
+          )
+          .child(
+            Button::new("container-edit-limits")
+              .label("Edit")
+              .ghost()
+              .xsmall()
+              .on_click(move |_, window, cx| {
+                super::limits_dialog::open_limits_dialog(id.clone(), name.clone(), nano_cpus, memory_limit, window, cx);
+              }),
+          ),
+      )
+  }
+
   fn render_info_tab(&self, container: &ContainerInfo, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -326,6 +656,29 @@ impl ContainerDetail {
         )
         .child(div().text_sm().text_color(colors.foreground).child(value))
     };
+    // Same row with a copy button; `full` is copied even when `value` is shortened.
+    let copy_row = |label: &str, value: String, id: &'static str, full: String| {
+      h_flex()
+        .w_full()
+        .py(px(12.))
+        .justify_between()
+        .items_center()
+        .border_b_1()
+        .border_color(colors.border)
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child(label.to_string()),
+        )
+        .child(
+          h_flex()
+            .gap(px(4.))
+            .items_center()
+            .child(div().text_sm().text_color(colors.foreground).child(value))
+            .children(self.on_copy.clone().map(|cb| copy_button(id, full, cb))),
+        )
+    };
 
     let status_text = container.status.clone();
     let is_running = container.state.is_running();
@@ -339,8 +692,18 @@ impl ContainerDetail {
       .w_full()
       .p(px(16.))
       .gap(px(8.))
-      .child(info_row("Name", container.name.clone()))
-      .child(info_row("ID", container.short_id().to_string()))
+      .child(copy_row(
+        "Name",
+        container.name.clone(),
+        "copy-container-name",
+        container.name.clone(),
+      ))
+      .child(copy_row(
+        "ID",
+        container.short_id().to_string(),
+        "copy-container-id",
+        container.id.clone(),
+      ))
       .child(info_row("Image", container.image.clone()))
       .when_some(domain_url, |el, url| {
         let display = url.clone();
@@ -439,10 +802,18 @@ impl ContainerDetail {
       if let Some(rc) = ex.restart_count {
         col = col.child(info_row("Restart count", rc.to_string()));
       }
+      col = col
+        .child(Self::render_restart_policy_row(container, &ex.restart_policy, cx))
+        .child(Self::render_limits_row(container, &ex, cx));
       if let Some(ec) = ex.exit_code
         && !is_running
       {
-        col = col.child(info_row("Exit code", ec.to_string()));
+        let exit = if ex.oom_killed == Some(true) {
+          format!("{ec} (OOMKilled)")
+        } else {
+          ec.to_string()
+        };
+        col = col.child(info_row("Exit code", exit));
       }
       if let Some(start) = ex
         .started_at
@@ -591,11 +962,185 @@ impl ContainerDetail {
           );
         }
       }
+
+      // DNS section. Empty lists mean the daemon defaults apply.
+      let or_default = |values: &[String]| {
+        if values.is_empty() {
+          "default".to_string()
+        } else {
+          values.join(", ")
+        }
+      };
+      col = col
+        .child(
+          div()
+            .mt(px(8.))
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("DNS"),
+        )
+        .child(info_row("DNS servers", or_default(&ex.dns)))
+        .child(info_row("Search domains", or_default(&ex.dns_search)));
+      let hosts = if ex.extra_hosts.is_empty() {
+        "none".to_string()
+      } else {
+        ex.extra_hosts.join(", ")
+      };
+      col = col.child(info_row("Extra hosts", hosts));
+    }
+
+    col = col.child(Self::render_networks_section(container, cx));
+
+    if !container.labels.is_empty() {
+      col = col
+        .child(
+          div()
+            .mt(px(8.))
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child(format!("Labels ({})", container.labels.len())),
+        )
+        .child(Self::render_labels_table(container, cx));
     }
 
     col
   }
 
+  /// Networks the container is attached to, each with a disconnect
+  /// button, plus a menu to attach it to another existing network.
+  fn render_networks_section(container: &ContainerInfo, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    // `host` and `none` can't be joined alongside other networks.
+    let mut available: Vec<String> = crate::state::docker_state(cx)
+      .read(cx)
+      .networks
+      .iter()
+      .filter(|n| !matches!(n.name.as_str(), "host" | "none") && !container.networks_used.contains(&n.name))
+      .map(|n| n.name.clone())
+      .collect();
+    available.sort();
+
+    let container_id = container.id.clone();
+    let connect_button = Button::new("container-network-connect")
+      .label("Connect Network")
+      .icon(IconName::Plus)
+      .ghost()
+      .xsmall()
+      .dropdown_menu(move |menu, _window, _cx| {
+        let mut menu = menu;
+        if available.is_empty() {
+          menu = menu.item(PopupMenuItem::new("(no other networks)"));
+        }
+        for name in &available {
+          let network = name.clone();
+          let id = container_id.clone();
+          menu = menu.item(
+            PopupMenuItem::new(name.clone())
+              .icon(Icon::new(AppIcon::Network))
+              .on_click(move |_, _, cx| {
+                crate::services::connect_container_to_network(network.clone(), id.clone(), cx);
+              }),
+          );
+        }
+        menu
+      });
+
+    let mut networks = container.networks_used.clone();
+    networks.sort();
+
+    v_flex()
+      .w_full()
+      .child(
+        h_flex()
+          .w_full()
+          .mt(px(8.))
+          .items_center()
+          .justify_between()
+          .child(div().text_sm().text_color(colors.muted_foreground).child("Networks"))
+          .child(connect_button),
+      )
+      .when(networks.is_empty(), |el| {
+        el.child(
+          div()
+            .py(px(8.))
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child("Not attached to any network"),
+        )
+      })
+      .children(networks.into_iter().enumerate().map(|(i, name)| {
+        let network = name.clone();
+        let id = container.id.clone();
+        h_flex()
+          .w_full()
+          .py(px(8.))
+          .gap(px(8.))
+          .items_center()
+          .border_b_1()
+          .border_color(colors.border)
+          .child(Icon::new(AppIcon::Network).text_color(colors.secondary_foreground))
+          .child(div().flex_1().text_sm().text_color(colors.foreground).child(name))
+          .child(
+            Button::new(("container-network-disconnect", i))
+              .label("Disconnect")
+              .ghost()
+              .xsmall()
+              .on_click(move |_, _, cx| {
+                crate::services::disconnect_container_from_network(network.clone(), id.clone(), false, cx);
+              }),
+          )
+      }))
+  }
+
+  /// Key/value table of the container's labels, sorted by key. Compose
+  /// adds a dozen or so, so the table scrolls on its own past a few rows.
+  fn render_labels_table(container: &ContainerInfo, cx: &App) -> gpui::Stateful<gpui::Div> {
+    let colors = &cx.theme().colors;
+
+    let mut labels: Vec<_> = container.labels.iter().collect();
+    labels.sort_by(|a, b| a.0.cmp(b.0));
+
+    v_flex()
+      .id("container-labels")
+      .w_full()
+      .max_h(px(240.))
+      .overflow_y_scroll()
+      .rounded(px(6.))
+      .bg(colors.background)
+      .border_1()
+      .border_color(colors.border)
+      .children(labels.into_iter().enumerate().map(|(i, (key, value))| {
+        h_flex()
+          .w_full()
+          .px(px(12.))
+          .py(px(6.))
+          .gap(px(12.))
+          .items_start()
+          .when(i > 0, |el| el.border_t_1().border_color(colors.border))
+          .child(
+            div()
+              .w(px(220.))
+              .flex_shrink_0()
+              .text_xs()
+              .font_family("monospace")
+              .text_color(colors.muted_foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .child(key.clone()),
+          )
+          .child(
+            div()
+              .flex_1()
+              .min_w_0()
+              .text_xs()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .child(value.clone()),
+          )
+      }))
+  }
+
   fn render_stats_tab(&self, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
     let state = self.container_state.as_ref();
@@ -610,20 +1155,27 @@ impl ContainerDetail {
     let mem_pct = state.stats_latest.as_ref().map_or(0.0, |s| s.memory_percent);
     let mem_usage = state.stats_latest.as_ref().map_or(0, |s| s.memory_usage);
     let mem_limit = state.stats_latest.as_ref().map_or(0, |s| s.memory_limit);
-    let net_rx = state.stats_latest.as_ref().map_or(0, |s| s.network_rx);
-    let net_tx = state.stats_latest.as_ref().map_or(0, |s| s.network_tx);
-    let blk_r = state.stats_latest.as_ref().map_or(0, |s| s.block_read);
-    let blk_w = state.stats_latest.as_ref().map_or(0, |s| s.block_write);
+    let net_rx = state.stats_latest.as_ref().map_or(0, |s| s.network_rx_rate);
+    let net_tx = state.stats_latest.as_ref().map_or(0, |s| s.network_tx_rate);
+    let net_breakdown = state
+      .stats_latest
+      .as_ref()
+      .filter(|s| s.networks.len() > 1)
+      .map(crate::docker::ContainerStats::network_breakdown)
+      .unwrap_or_default();
+    let blk_r = state.stats_latest.as_ref().map_or(0, |s| s.block_read_rate);
+    let blk_w = state.stats_latest.as_ref().map_or(0, |s| s.block_write_rate);
+    let block_io = state.stats_latest.as_ref();
 
     let card = |title: &'static str,
                 chart_id: &'static str,
                 unit: &'static str,
                 main: String,
                 sub: String,
-                history: &[f64],
+                history: &VecDeque<f64>,
                 color: gpui::Hsla|
      -> gpui::Div {
-      let history_owned: Vec<f64> = history.to_vec();
+      let history_owned: Vec<f64> = history.iter().copied().collect();
       v_flex()
         .flex_1()
         .min_w(px(180.))
@@ -687,23 +1239,47 @@ impl ContainerDetail {
       .child(card(
         "Network",
         "stats-net",
-        "B",
-        format!("rx {} / tx {}", format_bytes(net_rx), format_bytes(net_tx)),
-        String::new(),
+        "B/s",
+        format!("rx {}/s / tx {}/s", format_bytes(net_rx), format_bytes(net_tx)),
+        net_breakdown,
         &state.stats_net,
         net_color,
       ))
       .child(card(
         "Disk I/O",
         "stats-disk",
-        "B",
-        format!("r {} / w {}", format_bytes(blk_r), format_bytes(blk_w)),
-        String::new(),
+        "B/s",
+        match block_io {
+          Some(s) if !s.block_io_available => "n/a".to_string(),
+          _ => format!("r {}/s / w {}/s", format_bytes(blk_r), format_bytes(blk_w)),
+        },
+        match block_io {
+          Some(s) if !s.block_io_available => "Not reported by this daemon".to_string(),
+          Some(s) => format!(
+            "{} read, {} written in total",
+            format_bytes(s.block_read),
+            format_bytes(s.block_write)
+          ),
+          None => String::new(),
+        },
         &state.stats_disk,
         disk_color,
       ));
 
-    v_flex().w_full().p(px(16.)).gap(px(8.)).child(row1).child(row2)
+    v_flex()
+      .w_full()
+      .p(px(16.))
+      .gap(px(8.))
+      .when(state.stats_stale, |el| {
+        el.child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child("No data — container is not running. Showing the last samples."),
+        )
+      })
+      .child(row1)
+      .child(row2)
   }
 
   fn render_sparkline(
@@ -713,7 +1289,13 @@ impl ContainerDetail {
     history: &[f64],
     color: gpui::Hsla,
   ) -> impl IntoElement {
-    let data: Vec<f64> = history.iter().rev().take(60).rev().copied().collect();
+    let data: Vec<f64> = history
+      .iter()
+      .rev()
+      .take(STATS_HISTORY_SAMPLES)
+      .rev()
+      .copied()
+      .collect();
     let tooltip_text = sparkline_summary(label, &data, unit);
     div()
       .id(chart_id)
@@ -734,6 +1316,7 @@ impl ContainerDetail {
     let toggle_ts = self.on_toggle_logs_timestamps.clone();
     let refresh = self.on_refresh_logs.clone();
     let clear = self.on_clear_logs.clone();
+    let save = self.on_save_logs.clone();
 
     let toolbar = h_flex()
       .gap(px(8.))
@@ -788,6 +1371,17 @@ impl ContainerDetail {
               cb(&(), window, cx);
             })
           }),
+      )
+      .child(
+        Button::new("logs-save")
+          .label(if follow_on { "Save captured" } else { "Save logs" })
+          .small()
+          .ghost()
+          .when_some(save, |b, cb| {
+            b.on_click(move |_ev, window, cx| {
+              cb(&(), window, cx);
+            })
+          }),
       );
 
     let body: gpui::AnyElement = if is_loading && state.is_none_or(|s| s.logs.is_empty()) {
@@ -895,13 +1489,43 @@ impl ContainerDetail {
         .into_any_element();
     }
 
-    // If we have a terminal view, render it full size
+    // If we have a terminal view, render it full size below the shell picker
     if let Some(terminal) = &self.terminal_view {
-      return div()
+      let current = self.terminal_shell.as_deref().unwrap_or("Auto").to_string();
+      let on_shell_change = self.on_shell_change.clone();
+      let toolbar = h_flex()
+        .gap(px(8.))
+        .px(px(8.))
+        .py(px(6.))
+        .items_center()
+        .border_b_1()
+        .border_color(colors.border)
+        .child(div().text_xs().text_color(colors.muted_foreground).child("Shell"))
+        .child(
+          Button::new("terminal-shell")
+            .label(current)
+            .small()
+            .ghost()
+            .dropdown_menu(move |mut menu, _window, _cx| {
+              for (label, shell) in EXEC_SHELLS {
+                let on_shell_change = on_shell_change.clone();
+                let shell = shell.map(String::from);
+                menu = menu.item(PopupMenuItem::new(label).on_click(move |_, window, cx| {
+                  if let Some(ref cb) = on_shell_change {
+                    cb(&shell, window, cx);
+                  }
+                }));
+              }
+              menu
+            }),
+        );
+
+      return v_flex()
         .flex_1()
         .min_h_0()
         .w_full()
-        .child(terminal.clone())
+        .child(toolbar)
+        .child(div().flex_1().min_h_0().w_full().child(terminal.clone()))
         .into_any_element();
     }
 
@@ -927,10 +1551,103 @@ impl ContainerDetail {
       .into_any_element()
   }
 
+  fn render_attach_tab(&self, is_running: bool, cx: &App) -> gpui::AnyElement {
+    let colors = &cx.theme().colors;
+
+    let (Some(view), Some(stream), true) = (self.attach_view.clone(), self.attach_stream.clone(), is_running) else {
+      let message = if is_running {
+        "Attaching to container..."
+      } else {
+        "Container must be running to attach"
+      };
+      return v_flex()
+        .flex_1()
+        .w_full()
+        .p(px(16.))
+        .items_center()
+        .justify_center()
+        .gap(px(16.))
+        .child(
+          Icon::new(AppIcon::Terminal)
+            .size(px(48.))
+            .text_color(colors.muted_foreground),
+        )
+        .child(div().text_sm().text_color(colors.muted_foreground).child(message))
+        .into_any_element();
+    };
+
+    let detached = stream.is_detached();
+    let (banner_color, message) = match stream.interactive() {
+      _ if detached => (
+        colors.muted_foreground,
+        "Session ended. The container was left running unless its process exited.",
+      ),
+      Some(true) => (
+        colors.warning,
+        "Attached to the main process, not a new shell. Ctrl-C or exit may stop the container; press Ctrl-P Ctrl-Q or Detach to leave it running.",
+      ),
+      Some(false) => (
+        colors.muted_foreground,
+        "Read-only: this container has no TTY or open stdin, so only its output is shown.",
+      ),
+      None => (
+        colors.muted_foreground,
+        "Checking whether the container accepts input...",
+      ),
+    };
+
+    let reattach = self.on_reattach.clone();
+    let banner = h_flex()
+      .gap(px(8.))
+      .px(px(8.))
+      .py(px(6.))
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .child(
+        Icon::new(IconName::TriangleAlert)
+          .size(px(14.))
+          .text_color(banner_color),
+      )
+      .child(div().flex_1().text_xs().text_color(banner_color).child(message))
+      .when(!detached && stream.interactive() == Some(true), |el| {
+        el.child(
+          Button::new("attach-detach")
+            .label("Detach")
+            .small()
+            .ghost()
+            .on_click(move |_ev, _window, _cx| stream.detach()),
+        )
+      })
+      .when(detached, |el| {
+        el.child(
+          Button::new("attach-reattach")
+            .label("Reattach")
+            .icon(Icon::new(AppIcon::Refresh))
+            .small()
+            .ghost()
+            .when_some(reattach, |b, cb| {
+              b.on_click(move |_ev, window, cx| {
+                cb(&(), window, cx);
+              })
+            }),
+        )
+      });
+
+    v_flex()
+      .flex_1()
+      .min_h_0()
+      .w_full()
+      .child(banner)
+      .child(div().flex_1().min_h_0().w_full().child(view))
+      .into_any_element()
+  }
+
   fn render_inspect_tab(&self, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
     let state = self.container_state.as_ref();
     let is_loading = state.is_some_and(|s| s.inspect_loading);
+    let raw = state.is_some_and(|s| s.inspect_raw);
 
     if is_loading {
       return v_flex()
@@ -939,25 +1656,102 @@ impl ContainerDetail {
         .child(div().text_sm().text_color(colors.muted_foreground).child("Loading..."));
     }
 
-    if let Some(ref editor) = self.inspect_editor {
-      return div()
-        .size_full()
-        .child(Input::new(editor).size_full().appearance(false).disabled(true));
-    }
+    let mode_button = |id: &'static str, label: &'static str, value: bool| {
+      let on_mode = self.on_inspect_mode.clone();
+      Button::new(id)
+        .label(label)
+        .small()
+        .ghost()
+        .selected(raw == value)
+        .when_some(on_mode, |b, cb| {
+          b.on_click(move |_ev, window, cx| {
+            cb(&value, window, cx);
+          })
+        })
+    };
+    let step_button = |id: &'static str, icon: IconName, delta: isize| {
+      let on_step = self.on_inspect_find_step.clone();
+      Button::new(id)
+        .icon(icon)
+        .xsmall()
+        .ghost()
+        .disabled(self.inspect_find_status.is_none_or(|(_, total)| total == 0))
+        .when_some(on_step, |b, cb| {
+          b.on_click(move |_ev, window, cx| {
+            cb(&delta, window, cx);
+          })
+        })
+    };
 
-    // Fallback to plain text
-    let inspect_content = state.map_or_else(|| "{}".to_string(), |s| s.inspect.clone());
-    div().size_full().child(
+    let toolbar = h_flex()
+      .gap(px(8.))
+      .px(px(8.))
+      .py(px(6.))
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .child(mode_button("inspect-summary", "Summary", false))
+      .child(mode_button("inspect-raw", "Raw JSON", true))
+      .child(div().flex_1())
+      .when(settings_state(cx).read(cx).settings.mask_secrets, |el| {
+        let revealed = state.is_some_and(|s| s.inspect_reveal_secrets);
+        let on_reveal = self.on_inspect_reveal.clone();
+        el.child(
+          Button::new("inspect-reveal-secrets")
+            .icon(if revealed { IconName::EyeOff } else { IconName::Eye })
+            .label(if revealed { "Hide Secrets" } else { "Reveal Secrets" })
+            .small()
+            .ghost()
+            .when_some(on_reveal, |b, cb| {
+              b.on_click(move |_ev, window, cx| {
+                cb(&!revealed, window, cx);
+              })
+            }),
+        )
+      })
+      .when_some(self.inspect_find_input.clone(), |el, input| {
+        el.child(div().w(px(200.)).child(Input::new(&input).small().cleanable(true)))
+          .child(
+            div()
+              .min_w(px(56.))
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(match self.inspect_find_status {
+                Some((_, 0)) => "No matches".to_string(),
+                Some((current, total)) => format!("{current} of {total}"),
+                None => String::new(),
+              }),
+          )
+          .child(step_button("inspect-find-prev", IconName::ChevronUp, -1))
+          .child(step_button("inspect-find-next", IconName::ChevronDown, 1))
+      });
+
+    let body = if let Some(ref editor) = self.inspect_editor {
       div()
-        .size_full()
-        .overflow_y_scrollbar()
-        .bg(colors.sidebar)
-        .p(px(12.))
-        .font_family("monospace")
-        .text_xs()
-        .text_color(colors.foreground)
-        .child(inspect_content),
-    )
+        .flex_1()
+        .min_h_0()
+        .w_full()
+        .child(Input::new(editor).size_full().appearance(false).disabled(true))
+    } else {
+      // Fallback to plain text
+      let inspect_content = state.map_or_else(
+        || "{}".to_string(),
+        |s| s.inspect_text(&settings_state(cx).read(cx).settings),
+      );
+      div().flex_1().min_h_0().w_full().child(
+        div()
+          .size_full()
+          .overflow_y_scrollbar()
+          .bg(colors.sidebar)
+          .p(px(12.))
+          .font_family("monospace")
+          .text_xs()
+          .text_color(colors.foreground)
+          .child(inspect_content),
+      )
+    };
+
+    v_flex().size_full().child(toolbar).child(body)
   }
 
   fn render_files_tab(&self, is_running: bool, window: &mut Window, cx: &App) -> gpui::AnyElement {
@@ -996,6 +1790,7 @@ impl ContainerDetail {
       file_content: state.map(|s| s.file_content.clone()).unwrap_or_default(),
       file_content_loading: state.is_some_and(|s| s.file_content_loading),
       file_content_error: state.and_then(|s| s.file_content_error.clone()),
+      editing: state.is_some_and(|s| s.file_editing),
     };
 
     let files = state.map(|s| s.files.clone()).unwrap_or_default();
@@ -1027,6 +1822,27 @@ impl ContainerDetail {
       });
     }
 
+    if let Some(ref cb) = self.on_edit_file {
+      let cb = cb.clone();
+      explorer = explorer.on_edit_file(move |(), window, cx| {
+        cb(&(), window, cx);
+      });
+    }
+
+    if let Some(ref cb) = self.on_cancel_file_edit {
+      let cb = cb.clone();
+      explorer = explorer.on_cancel_edit(move |(), window, cx| {
+        cb(&(), window, cx);
+      });
+    }
+
+    if let Some(ref cb) = self.on_save_file {
+      let cb = cb.clone();
+      explorer = explorer.on_save_file(move |path, window, cx| {
+        cb(path, window, cx);
+      });
+    }
+
     if let Some(ref cb) = self.on_symlink_click {
       let cb = cb.clone();
       explorer = explorer.on_symlink_click(move |path, window, cx| {
@@ -1041,6 +1857,18 @@ impl ContainerDetail {
       });
     }
 
+    if let Some(ref container) = self.container {
+      let download_id = container.id.clone();
+      let upload_id = container.id.clone();
+      explorer = explorer
+        .on_download(move |(path, _is_dir): &(String, bool), _window, cx| {
+          super::prompt_download_to_host(download_id.clone(), path.clone(), cx);
+        })
+        .on_upload(move |dir, _window, cx| {
+          super::prompt_upload_into(upload_id.clone(), dir.to_string(), cx);
+        });
+    }
+
     explorer.render(window, cx)
   }
 
@@ -1055,34 +1883,53 @@ impl ContainerDetail {
     let on_tab_change = self.on_tab_change.clone();
 
     // Toolbar with tabs and actions
-    let toolbar =
-      h_flex()
-        .w_full()
-        .items_center()
-        .flex_shrink_0()
-        .child(
-          TabBar::new("container-tabs")
-            .flex_1()
-            .children(ContainerDetailTab::ALL.iter().map(|tab| {
-              let on_tab_change = on_tab_change.clone();
-              let tab_variant = *tab;
-              Tab::new()
-                .label(tab.label().to_string())
-                .selected(self.active_tab == *tab)
-                .on_click(move |_ev, window, cx| {
-                  if let Some(ref cb) = on_tab_change {
-                    cb(&tab_variant, window, cx);
-                  }
-                })
-            })),
-        );
+    let toolbar = h_flex()
+      .w_full()
+      .items_center()
+      .flex_shrink_0()
+      .child(
+        TabBar::new("container-tabs")
+          .flex_1()
+          .children(ContainerDetailTab::ALL.iter().map(|tab| {
+            let on_tab_change = on_tab_change.clone();
+            let tab_variant = *tab;
+            Tab::new()
+              .label(tab.label().to_string())
+              .selected(self.active_tab == *tab)
+              .on_click(move |_ev, window, cx| {
+                if let Some(ref cb) = on_tab_change {
+                  cb(&tab_variant, window, cx);
+                }
+              })
+          })),
+      )
+      .when_some(self.on_duplicate.clone(), |el, on_duplicate| {
+        // The copy is built from the inspect data, so wait for it
+        let inspect_ready = self
+          .container_state
+          .as_ref()
+          .is_some_and(|s| !s.inspect_loading && !s.inspect.is_empty());
+        el.child(
+          div().px(px(8.)).child(
+            Button::new("container-duplicate")
+              .icon(Icon::new(AppIcon::Copy))
+              .label("Duplicate")
+              .ghost()
+              .xsmall()
+              .disabled(!inspect_ready)
+              .tooltip("Create a new container with these settings")
+              .on_click(move |_ev, window, cx| on_duplicate(&(), window, cx)),
+          ),
+        )
+      });
 
-    // Terminal, Logs, Processes, and Files tabs need full height without scroll
+    // Terminal, Attach, Logs, Processes, and Files tabs need full height without scroll
     let is_full_height_tab = matches!(
       self.active_tab,
       ContainerDetailTab::Logs
         | ContainerDetailTab::Processes
         | ContainerDetailTab::Terminal
+        | ContainerDetailTab::Attach
         | ContainerDetailTab::Files
     );
 
@@ -1100,6 +1947,7 @@ impl ContainerDetail {
         ContainerDetailTab::Logs => self.render_logs_tab(cx).into_any_element(),
         ContainerDetailTab::Processes => self.render_processes_tab(is_running, cx),
         ContainerDetailTab::Terminal => self.render_terminal_tab(is_running, cx),
+        ContainerDetailTab::Attach => self.render_attach_tab(is_running, cx),
         ContainerDetailTab::Files => self.render_files_tab(is_running, window, cx),
         _ => self.render_info_tab(container, cx).into_any_element(),
       };
@@ -1135,3 +1983,16 @@ impl ContainerDetail {
     result.into_any_element()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_text_matches() {
+    let text = "{\n  \"Name\": \"/web\",\n  \"Image\": \"nginx\", \"name2\": 1\n}";
+    assert_eq!(text_matches(text, "name"), vec![(1, 3), (2, 21)]);
+    assert!(text_matches(text, "").is_empty());
+    assert!(text_matches(text, "missing").is_empty());
+  }
+}
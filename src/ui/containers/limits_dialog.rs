@@ -0,0 +1,137 @@
+//! "Edit Limits..." dialog: change a container's CPU and memory limits in
+//! place through `docker update`, so a limit can be raised without
+//! recreating the container.
+
+use gpui::{App, Entity, ParentElement, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  WindowExt,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  notification::NotificationType,
+  v_flex,
+};
+
+use crate::services;
+use crate::ui::components::form_field;
+
+use super::create_dialog::{MIN_MEMORY_BYTES, parse_size_bytes};
+
+/// Parse the CPU and memory fields. Empty fields keep the current limit;
+/// at least one has to be filled in.
+fn parse_limits(cpus: &str, memory: &str) -> Result<(Option<f64>, Option<i64>), String> {
+  let cpus = cpus.trim();
+  let cpus = if cpus.is_empty() {
+    None
+  } else {
+    match cpus.parse::<f64>() {
+      Ok(n) if n.is_finite() && n > 0.0 => Some(n),
+      _ => return Err(format!("'{cpus}' is not a CPU count like 0.5 or 2")),
+    }
+  };
+  let memory = match parse_size_bytes(memory)? {
+    Some(bytes) if bytes < MIN_MEMORY_BYTES => return Err("Memory limit must be at least 6m".to_string()),
+    bytes => bytes,
+  };
+  if cpus.is_none() && memory.is_none() {
+    return Err("Enter a CPU or memory limit".to_string());
+  }
+  Ok((cpus, memory))
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub fn open_limits_dialog(
+  container_id: String,
+  container_name: String,
+  nano_cpus: Option<i64>,
+  memory_limit: Option<i64>,
+  window: &mut Window,
+  cx: &mut App,
+) {
+  let cpus_input: Entity<InputState> = cx.new(|cx| {
+    let mut state = InputState::new(window, cx).placeholder("e.g. 1.5");
+    if let Some(nano) = nano_cpus {
+      state.set_value((nano as f64 / 1_000_000_000.0).to_string(), window, cx);
+    }
+    state
+  });
+  let memory_input: Entity<InputState> = cx.new(|cx| {
+    let mut state = InputState::new(window, cx).placeholder("e.g. 512m");
+    if let Some(bytes) = memory_limit {
+      state.set_value(format!("{}m", bytes / (1024 * 1024)), window, cx);
+    }
+    state
+  });
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let cpus_for_btn = cpus_input.clone();
+    let memory_for_btn = memory_input.clone();
+    let id_for_btn = container_id.clone();
+    dialog
+      .title(format!("Limits for {container_name}"))
+      .min_w(px(420.))
+      .child(
+        v_flex().p(px(16.)).gap(px(12.)).child(
+          h_flex()
+            .gap(px(12.))
+            .child(div().flex_1().child(form_field(
+              "CPUs",
+              Input::new(&cpus_input).w_full(),
+              Some("Empty keeps the current limit."),
+              cx,
+            )))
+            .child(div().flex_1().child(form_field(
+              "Memory",
+              Input::new(&memory_input).w_full(),
+              Some("Swap is set to twice this."),
+              cx,
+            ))),
+        ),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let cpus_input = cpus_for_btn.clone();
+        let memory_input = memory_for_btn.clone();
+        let id = id_for_btn.clone();
+        vec![
+          Button::new("limits-apply")
+            .label("Apply")
+            .primary()
+            .on_click(move |_ev, window, cx| {
+              let cpus = cpus_input.read(cx).text().to_string();
+              let memory = memory_input.read(cx).text().to_string();
+              match parse_limits(&cpus, &memory) {
+                Ok((cpus, memory)) => {
+                  services::update_container_limits(id.clone(), cpus, memory, cx);
+                  window.close_dialog(cx);
+                }
+                Err(e) => {
+                  window.push_notification((NotificationType::Error, SharedString::from(e)), cx);
+                }
+              }
+            })
+            .into_any_element(),
+          Button::new("limits-cancel")
+            .label("Cancel")
+            .ghost()
+            .on_click(|_ev, window, cx| {
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_limits() {
+    assert_eq!(parse_limits("1.5", ""), Ok((Some(1.5), None)));
+    assert_eq!(parse_limits("", "512m"), Ok((None, Some(512 * 1024 * 1024))));
+    assert!(parse_limits("", "").is_err());
+    assert!(parse_limits("0", "").is_err());
+    assert!(parse_limits("", "1m").is_err());
+  }
+}
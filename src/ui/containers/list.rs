@@ -1,6 +1,6 @@
-use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, MouseButton, Render, Styled, Subscription, Window, div, prelude::*, px};
 use gpui_component::{
-  Icon, IconName, IndexPath, Sizable,
+  Icon, IconName, IndexPath, Selectable, Sizable,
   button::{Button, ButtonVariants},
   checkbox::Checkbox,
   h_flex,
@@ -12,21 +12,141 @@ use gpui_component::{
   v_flex,
 };
 
+use std::collections::HashMap;
+
 use crate::assets::AppIcon;
-use crate::docker::ContainerInfo;
+use crate::docker::{ContainerInfo, ContainerStats, HealthStatus};
 use crate::services;
-use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_error, render_loading};
+use crate::state::{CurrentView, DockerState, LoadState, Selection, StateChanged, docker_state, settings_state};
+use crate::ui::components::{
+  CachedListDelegate, ColumnSort, RefreshStatus, SearchDebounce, labels_match, labels_match_filter, parse_label_filter,
+  refresh_rows, render_docker_context_selector, render_error, render_loading, render_sort_header,
+};
 
 /// Container list events emitted to parent
 pub enum ContainerListEvent {
   Selected(Box<ContainerInfo>),
 }
 
+/// Live "top" ordering for the container list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopSort {
+  Cpu,
+  Memory,
+}
+
+impl TopSort {
+  fn label(self) -> &'static str {
+    match self {
+      TopSort::Cpu => "CPU",
+      TopSort::Memory => "MEM",
+    }
+  }
+
+  fn usage(self, stats: &ContainerStats) -> f64 {
+    match self {
+      TopSort::Cpu => stats.cpu_percent,
+      #[allow(clippy::cast_precision_loss)]
+      TopSort::Memory => stats.memory_usage as f64,
+    }
+  }
+
+  /// Minimum lead a row needs over its upper neighbour before the two
+  /// swap: one CPU percentage point, or 8 MiB of memory.
+  fn margin(self) -> f64 {
+    match self {
+      TopSort::Cpu => 1.0,
+      TopSort::Memory => 8.0 * 1024.0 * 1024.0,
+    }
+  }
+
+  /// Off → CPU → Memory → off.
+  fn next(mode: Option<Self>) -> Option<Self> {
+    match mode {
+      None => Some(TopSort::Cpu),
+      Some(TopSort::Cpu) => Some(TopSort::Memory),
+      Some(TopSort::Memory) => None,
+    }
+  }
+}
+
+/// Order container ids by descending usage, starting from the previous
+/// order and only swapping neighbours when the lower row leads by more
+/// than `margin`. Keeps rows with near-equal usage from flickering
+/// between samples. Ids missing from `usage` are dropped.
+fn damped_top_order(prev: &[String], usage: &HashMap<String, f64>, margin: f64) -> Vec<String> {
+  let mut order: Vec<String> = prev.iter().filter(|id| usage.contains_key(*id)).cloned().collect();
+  let mut fresh: Vec<&String> = usage.keys().filter(|id| !order.contains(id)).collect();
+  fresh.sort_by(|a, b| usage[*b].total_cmp(&usage[*a]).then_with(|| a.cmp(b)));
+  order.extend(fresh.into_iter().cloned());
+
+  let value = |id: &String| usage.get(id).copied().unwrap_or_default();
+  loop {
+    let mut swapped = false;
+    for i in 1..order.len() {
+      if value(&order[i]) > value(&order[i - 1]) + margin {
+        order.swap(i - 1, i);
+        swapped = true;
+      }
+    }
+    if !swapped {
+      break;
+    }
+  }
+  order
+}
+
+/// Columns of the container list's sort header. CPU sorting is the live
+/// top mode, so it isn't a `ColumnSort` key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerSortKey {
+  Name,
+  Status,
+  Created,
+}
+
+impl ContainerSortKey {
+  const COLUMNS: [(ContainerSortKey, &'static str); 3] = [
+    (ContainerSortKey::Name, "Name"),
+    (ContainerSortKey::Status, "Status"),
+    (ContainerSortKey::Created, "Created"),
+  ];
+}
+
+/// Default order: running first, then by name.
+fn default_container_order(a: &ContainerInfo, b: &ContainerInfo) -> std::cmp::Ordering {
+  a.state
+    .sort_rank()
+    .cmp(&b.state.sort_rank())
+    .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+}
+
+fn sort_containers(rows: &mut [ContainerInfo], sort: Option<ColumnSort<ContainerSortKey>>) {
+  let Some(sort) = sort else {
+    rows.sort_by(default_container_order);
+    return;
+  };
+  rows.sort_by(|a, b| {
+    let ordering = match sort.key {
+      ContainerSortKey::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+      ContainerSortKey::Status => a.state.sort_rank().cmp(&b.state.sort_rank()),
+      ContainerSortKey::Created => a.created.cmp(&b.created),
+    };
+    sort.apply(ordering)
+  });
+}
+
 /// Delegate for the container list
 pub struct ContainerListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  /// Off = running containers only (filters the cached list)
+  show_stopped: bool,
+  rows: Vec<ContainerInfo>,
+  top_mode: Option<TopSort>,
+  sort: Option<ColumnSort<ContainerSortKey>>,
+  top_stats: HashMap<String, ContainerStats>,
+  top_order: Vec<String>,
 }
 
 impl ContainerListDelegate {
@@ -34,35 +154,106 @@ impl ContainerListDelegate {
     &self.docker_state.read(cx).containers
   }
 
-  fn filtered_containers(&self, cx: &App) -> Vec<ContainerInfo> {
-    let containers = self.containers(cx);
-    if self.search_query.is_empty() {
-      return containers.clone();
-    }
-
+  fn collect_rows(&self, cx: &App) -> Vec<ContainerInfo> {
     let query = self.search_query.to_lowercase();
-    containers
+    let label_filter = parse_label_filter(&query);
+    let mut filtered: Vec<ContainerInfo> = self
+      .containers(cx)
       .iter()
-      .filter(|c| {
-        c.name.to_lowercase().contains(&query)
-          || c.image.to_lowercase().contains(&query)
-          || c.state.to_string().to_lowercase().contains(&query)
-          || c.id.to_lowercase().contains(&query)
+      .filter(|c| self.show_stopped || c.state.is_running())
+      .filter(|c| match label_filter {
+        Some((key, value)) => labels_match_filter(&c.labels, key, value),
+        None => {
+          query.is_empty()
+            || c.name.to_lowercase().contains(&query)
+            || c.image.to_lowercase().contains(&query)
+            || c.state.to_string().to_lowercase().contains(&query)
+            || c.id.to_lowercase().contains(&query)
+            || labels_match(&c.labels, &query)
+        }
       })
       .cloned()
-      .collect()
+      .collect();
+
+    // The sort is stable, so top mode's damped order below starts from it.
+    sort_containers(&mut filtered, self.sort);
+
+    // Top mode: containers with live stats first in damped usage order,
+    // everything else keeps its original position after them.
+    if self.top_mode.is_some() {
+      let rank: HashMap<&str, usize> = self
+        .top_order
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+      filtered.sort_by_key(|c| rank.get(c.id.as_str()).copied().unwrap_or(usize::MAX));
+    }
+    filtered
+  }
+
+  fn filtered_containers(&self) -> &[ContainerInfo] {
+    &self.rows
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+
+  fn set_show_stopped(&mut self, show: bool, cx: &App) {
+    self.show_stopped = show;
+    self.rebuild_rows(cx);
+  }
+
+  fn set_sort(&mut self, sort: Option<ColumnSort<ContainerSortKey>>) {
+    self.sort = sort;
+  }
+
+  fn set_top_mode(&mut self, mode: Option<TopSort>, cx: &App) {
+    self.top_mode = mode;
+    self.top_order.clear();
+    if mode.is_none() {
+      self.top_stats.clear();
+    }
+    self.rebuild_rows(cx);
+  }
+
+  fn apply_top_stats(&mut self, stats: Vec<ContainerStats>, cx: &App) {
+    let Some(mode) = self.top_mode else {
+      return;
+    };
+    let usage: HashMap<String, f64> = stats.iter().map(|s| (s.id.clone(), mode.usage(s))).collect();
+    self.top_order = damped_top_order(&self.top_order, &usage, mode.margin());
+    self.top_stats = stats.into_iter().map(|s| (s.id.clone(), s)).collect();
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for ContainerListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|container| matches!(global_selection, Selection::Container(c) if c.id == container.id))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for ContainerListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered_containers(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered_containers().len()
   }
 
   fn render_item(
@@ -71,7 +262,7 @@ impl ListDelegate for ContainerListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let containers = self.filtered_containers(cx);
+    let containers = self.filtered_containers();
     let container = containers.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -87,7 +278,15 @@ impl ListDelegate for ContainerListDelegate {
       colors.muted_foreground
     };
 
-    let subtitle = container.image.clone();
+    let subtitle = match self.top_stats.get(&container.id).filter(|_| self.top_mode.is_some()) {
+      Some(stats) => format!(
+        "{:.1}% CPU · {} · {}",
+        stats.cpu_percent,
+        stats.display_memory(),
+        container.image
+      ),
+      None => container.image.clone(),
+    };
     let status_color = if is_running {
       colors.success
     } else {
@@ -106,6 +305,7 @@ impl ListDelegate for ContainerListDelegate {
     };
     let pinned = services::is_favorite(&pin_favorite, cx);
     let domain_url = services::container_url(cx, &container_id);
+    let has_healthcheck = container.health.is_some();
 
     let menu_button = Button::new(("menu", row))
       .icon(IconName::Ellipsis)
@@ -132,7 +332,14 @@ impl ListDelegate for ContainerListDelegate {
             .item(PopupMenuItem::new("Stop").icon(Icon::new(AppIcon::Stop)).on_click({
               let id = id.clone();
               move |_, _, cx| {
-                services::stop_container(id.clone(), cx);
+                services::stop_container(id.clone(), None, None, cx);
+              }
+            }))
+            .item(PopupMenuItem::new("Stop with Timeout...").on_click({
+              let id = id.clone();
+              let name = name.clone();
+              move |_, w, cx| {
+                crate::ui::containers::open_stop_dialog(id.clone(), name.clone(), w, cx);
               }
             }))
             .item(
@@ -168,6 +375,16 @@ impl ListDelegate for ContainerListDelegate {
                   }
                 }),
             )
+            .item(
+              PopupMenuItem::new("Attach to Main Process")
+                .icon(Icon::new(AppIcon::Terminal))
+                .on_click({
+                  let id = id.clone();
+                  move |_, _, cx| {
+                    services::open_container_attach(id.clone(), cx);
+                  }
+                }),
+            )
             .item(PopupMenuItem::new("Logs").icon(Icon::new(AppIcon::Logs)).on_click({
               let id = id.clone();
               move |_, _, cx| {
@@ -214,7 +431,14 @@ impl ListDelegate for ContainerListDelegate {
             .item(PopupMenuItem::new("Stop").icon(Icon::new(AppIcon::Stop)).on_click({
               let id = id.clone();
               move |_, _, cx| {
-                services::stop_container(id.clone(), cx);
+                services::stop_container(id.clone(), None, None, cx);
+              }
+            }))
+            .item(PopupMenuItem::new("Stop with Timeout...").on_click({
+              let id = id.clone();
+              let name = name.clone();
+              move |_, w, cx| {
+                crate::ui::containers::open_stop_dialog(id.clone(), name.clone(), w, cx);
               }
             }))
             .item(PopupMenuItem::new("Kill").icon(Icon::new(AppIcon::Kill)).on_click({
@@ -253,6 +477,28 @@ impl ListDelegate for ContainerListDelegate {
             }));
         }
 
+        // Background waits
+        let wait_target = services::WaitTarget::Container {
+          id: id.clone(),
+          name: name.clone(),
+        };
+        if !running {
+          menu = menu.item(PopupMenuItem::new("Wait Until Running").icon(IconName::Bell).on_click({
+            let target = wait_target.clone();
+            move |_, _, cx| {
+              services::wait_for(target.clone(), services::WaitCondition::Running, cx);
+            }
+          }));
+        }
+        if has_healthcheck {
+          menu = menu.item(PopupMenuItem::new("Wait Until Healthy").icon(IconName::Bell).on_click({
+            let target = wait_target.clone();
+            move |_, _, cx| {
+              services::wait_for(target.clone(), services::WaitCondition::Healthy, cx);
+            }
+          }));
+        }
+
         // Common actions for all states
         let pin_label = if pinned {
           "Unpin from Dashboard"
@@ -350,13 +596,51 @@ impl ListDelegate for ContainerListDelegate {
               .min_w_0()
               .gap(px(2.))
               .child(
-                div()
-                  .text_sm()
-                  .font_weight(gpui::FontWeight::MEDIUM)
-                  .text_ellipsis()
-                  .overflow_hidden()
-                  .whitespace_nowrap()
-                  .child(container.name.clone()),
+                h_flex()
+                  .min_w_0()
+                  .gap(px(6.))
+                  .items_center()
+                  .child(
+                    div()
+                      .text_sm()
+                      .font_weight(gpui::FontWeight::MEDIUM)
+                      .text_ellipsis()
+                      .overflow_hidden()
+                      .whitespace_nowrap()
+                      .child(container.name.clone()),
+                  )
+                  .when_some(container.health, |el, health| {
+                    let color = match health {
+                      HealthStatus::Healthy => colors.success,
+                      HealthStatus::Starting => colors.warning,
+                      HealthStatus::Unhealthy => colors.danger,
+                    };
+                    el.child(
+                      div()
+                        .flex_shrink_0()
+                        .px(px(6.))
+                        .rounded(px(4.))
+                        .border_1()
+                        .border_color(color)
+                        .text_xs()
+                        .text_color(color)
+                        .child(health.label()),
+                    )
+                  })
+                  .when(container.is_restart_looping(), |el| {
+                    let restarts = container.restart_count.unwrap_or_default();
+                    el.child(
+                      div()
+                        .flex_shrink_0()
+                        .px(px(6.))
+                        .rounded(px(4.))
+                        .border_1()
+                        .border_color(colors.warning)
+                        .text_xs()
+                        .text_color(colors.warning)
+                        .child(format!("{restarts} restarts")),
+                    )
+                  }),
               )
               .child(
                 div()
@@ -371,6 +655,25 @@ impl ListDelegate for ContainerListDelegate {
       )
       .child(div().flex_shrink_0().child(menu_button));
 
+    // Cmd/Ctrl-click toggles the row into the bulk set and Shift-click
+    // extends it; stopping propagation keeps the single selection as is.
+    let list = cx.entity().downgrade();
+    let click_id = container_id.clone();
+    let item_content = item_content.on_mouse_down(MouseButton::Left, move |event, _window, cx| {
+      let modifiers = event.modifiers;
+      if modifiers.shift {
+        let order: Vec<String> = list
+          .upgrade()
+          .map(|list| list.read(cx).delegate().rows.iter().map(|c| c.id.clone()).collect())
+          .unwrap_or_default();
+        services::extend_container_bulk_selection(&click_id, &order, cx);
+        cx.stop_propagation();
+      } else if modifiers.platform || modifiers.control {
+        services::toggle_container_bulk_selection(&click_id, cx);
+        cx.stop_propagation();
+      }
+    });
+
     let item = ListItem::new(("container", ix.row))
       .py(px(4.))
       .rounded(px(6.))
@@ -395,10 +698,14 @@ impl ListDelegate for ContainerListDelegate {
 /// Self-contained container list component
 pub struct ContainerList {
   docker_state: Entity<DockerState>,
+  refresh_status: Entity<RefreshStatus>,
   list_state: Entity<ListState<ContainerListDelegate>>,
   search_input: Option<Entity<InputState>>,
   search_visible: bool,
-  search_query: String,
+  search: SearchDebounce,
+  /// Shared stats stream feed driving top mode; dropped when top mode is
+  /// turned off.
+  top_stats: Option<Subscription>,
 }
 
 impl ContainerList {
@@ -408,15 +715,22 @@ impl ContainerList {
     let delegate = ContainerListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      show_stopped: settings_state(cx).read(cx).settings.containers_show_stopped,
+      top_mode: None,
+      sort: None,
+      top_stats: HashMap::new(),
+      top_order: Vec::new(),
+      rows: Vec::new(),
     };
 
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     // Subscribe to list events
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered_containers(cx);
+        let filtered = delegate.filtered_containers();
         if let Some(container) = filtered.get(ix.row) {
           cx.emit(ContainerListEvent::Selected(Box::new(container.clone())));
         }
@@ -426,43 +740,126 @@ impl ContainerList {
     .detach();
 
     // Subscribe to docker state changes to refresh list
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(event, StateChanged::ContainersUpdated | StateChanged::SelectionChanged) {
-        this.list_state.update(cx, |_state, cx| {
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(event, StateChanged::ContainersUpdated | StateChanged::SelectionChanged) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
           cx.notify();
-        });
-        cx.notify();
+        } else if matches!(event, StateChanged::DockerContextsUpdated) {
+          cx.notify();
+        }
+      },
+    )
+    .detach();
+
+    cx.on_release(|this, cx| {
+      if this.top_stats.is_some() {
+        services::stop_all_container_stats(cx);
       }
     })
     .detach();
 
     Self {
       docker_state,
+      refresh_status: cx.new(|cx| RefreshStatus::new(CurrentView::Containers, cx)),
       list_state,
       search_input: None,
       search_visible: false,
-      search_query: String::new(),
+      search: SearchDebounce::default(),
+      top_stats: None,
     }
   }
 
+  /// Flip the "Show stopped" filter and remember it for the next launch.
+  fn toggle_show_stopped(&mut self, cx: &mut Context<'_, Self>) {
+    let show = !self.list_state.read(cx).delegate().show_stopped;
+    self.list_state.update(cx, |state, cx| {
+      state.delegate_mut().set_show_stopped(show, cx);
+      cx.notify();
+    });
+    settings_state(cx).update(cx, |state, cx| {
+      state.settings.containers_show_stopped = show;
+      state.save_later(cx);
+    });
+    cx.notify();
+  }
+
+  fn cycle_top_mode(&mut self, cx: &mut Context<'_, Self>) {
+    let mode = TopSort::next(self.list_state.read(cx).delegate().top_mode);
+    self.set_top_mode(mode, cx);
+  }
+
+  /// The sort header's CPU column: live top mode by CPU, or off again.
+  fn toggle_cpu_sort(&mut self, cx: &mut Context<'_, Self>) {
+    let mode = match self.list_state.read(cx).delegate().top_mode {
+      Some(TopSort::Cpu) => None,
+      _ => Some(TopSort::Cpu),
+    };
+    self.set_top_mode(mode, cx);
+  }
+
+  fn on_sort_column(&mut self, key: ContainerSortKey, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let sort = ColumnSort::cycle(self.list_state.read(cx).delegate().sort, key);
+    self.list_state.update(cx, |state, cx| {
+      state.delegate_mut().set_sort(sort);
+      refresh_rows(state, window, cx);
+    });
+    cx.notify();
+  }
+
+  fn set_top_mode(&mut self, mode: Option<TopSort>, cx: &mut Context<'_, Self>) {
+    self.list_state.update(cx, |state, cx| {
+      state.delegate_mut().set_top_mode(mode, cx);
+      cx.notify();
+    });
+    match (mode.is_some(), self.top_stats.is_some()) {
+      (true, false) => {
+        services::stream_all_container_stats(cx);
+        let list_state = self.list_state.clone();
+        let subscription = cx.subscribe(&self.docker_state, move |_this, _state, event: &StateChanged, cx| {
+          if let StateChanged::AllStatsUpdated(stats) = event {
+            list_state.update(cx, |state, cx| {
+              state.delegate_mut().apply_top_stats(stats.container_stats.clone(), cx);
+              cx.notify();
+            });
+            cx.notify();
+          }
+        });
+        self.top_stats = Some(subscription);
+      }
+      (false, true) => {
+        self.top_stats = None;
+        services::stop_all_container_stats(cx);
+      }
+      _ => {}
+    }
+    cx.notify();
+  }
+
   fn ensure_search_input(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
     if self.search_input.is_none() {
-      let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Search containers..."));
+      let input_state =
+        cx.new(|cx| InputState::new(window, cx).placeholder("Filter by name, image or label (label:key=value)..."));
       self.search_input = Some(input_state);
     }
   }
 
   fn sync_search_query(&mut self, cx: &mut Context<'_, Self>) {
-    if let Some(input) = &self.search_input {
-      let current_text = input.read(cx).text().to_string();
-      if current_text != self.search_query {
-        current_text.clone_into(&mut self.search_query);
-        self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+    let Some(input) = &self.search_input else { return };
+    let current_text = input.read(cx).text().to_string();
+    self.search.sync(
+      current_text,
+      |this: &mut Self| &mut this.search,
+      cx,
+      |this, query, cx| {
+        this.list_state.update(cx, |state, cx| {
+          state.delegate_mut().set_search_query(query, cx);
           cx.notify();
         });
-      }
-    }
+      },
+    );
   }
 
   fn toggle_search(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
@@ -471,10 +868,10 @@ impl ContainerList {
       self.ensure_search_input(window, cx);
     } else {
       // Clear search when hiding
-      self.search_query.clear();
+      self.search.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -584,7 +981,46 @@ impl ContainerList {
         div()
           .text_sm()
           .text_color(colors.muted_foreground)
-          .child(format!("No containers match \"{}\"", self.search_query)),
+          .child(format!("No containers match \"{}\"", self.search.query())),
+      )
+  }
+
+  /// Every container is stopped and stopped ones are hidden.
+  fn render_all_stopped(cx: &mut Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+
+    v_flex()
+      .flex_1()
+      .flex()
+      .items_center()
+      .justify_center()
+      .gap(px(16.))
+      .py(px(48.))
+      .child(
+        div()
+          .size(px(64.))
+          .rounded(px(12.))
+          .bg(colors.sidebar)
+          .flex()
+          .items_center()
+          .justify_center()
+          .child(Icon::new(IconName::EyeOff).text_color(colors.muted_foreground)),
+      )
+      .child(
+        div()
+          .text_xl()
+          .font_weight(gpui::FontWeight::SEMIBOLD)
+          .text_color(colors.secondary_foreground)
+          .child("No Running Containers"),
+      )
+      .child(
+        Button::new("show-stopped-empty")
+          .label("Show stopped")
+          .ghost()
+          .small()
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.toggle_show_stopped(cx);
+          })),
       )
   }
 }
@@ -599,9 +1035,10 @@ impl Render for ContainerList {
     let containers_state = state.containers_state.clone();
 
     // Get filtered count
-    let filtered_count = self.list_state.read(cx).delegate().filtered_containers(cx).len();
-    let is_filtering = !self.search_query.is_empty();
+    let filtered_count = self.list_state.read(cx).delegate().filtered_containers().len();
+    let is_filtering = !self.search.query().is_empty();
     let containers_empty = filtered_count == 0;
+    let show_stopped = self.list_state.read(cx).delegate().show_stopped;
 
     let subtitle = match &containers_state {
       LoadState::NotLoaded | LoadState::Loading => "Loading...".to_string(),
@@ -609,16 +1046,15 @@ impl Render for ContainerList {
       LoadState::Loaded => {
         if is_filtering {
           format!("{filtered_count} of {total_count} ({running_count} running)")
-        } else if running_count > 0 {
-          format!("{running_count} running")
         } else {
-          "None running".to_string()
+          format!("{running_count} running / {total_count} total")
         }
       }
     };
 
     let colors = cx.theme().colors;
     let search_visible = self.search_visible;
+    let top_mode = self.list_state.read(cx).delegate().top_mode;
 
     // Ensure search input exists if visible and sync query
     if search_visible {
@@ -637,14 +1073,45 @@ impl Render for ContainerList {
       .justify_between()
       .flex_shrink_0()
       .child(
-        v_flex()
-          .child(Label::new("Containers"))
-          .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle)),
+        v_flex().child(Label::new("Containers")).child(
+          h_flex()
+            .items_center()
+            .gap(px(8.))
+            .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle))
+            .child(self.refresh_status.clone()),
+        ),
       )
       .child(
         h_flex()
           .items_center()
           .gap(px(8.))
+          .child(render_docker_context_selector(cx))
+          .child(
+            Button::new("show-stopped")
+              .icon(if show_stopped { IconName::Eye } else { IconName::EyeOff })
+              .ghost()
+              .compact()
+              .tooltip(if show_stopped {
+                "Hide stopped containers"
+              } else {
+                "Show stopped containers"
+              })
+              .on_click(cx.listener(|this, _ev, _window, cx| {
+                this.toggle_show_stopped(cx);
+              })),
+          )
+          .child(
+            Button::new("top-mode")
+              .icon(IconName::SortDescending)
+              .when_some(top_mode, |b, mode| b.label(mode.label()))
+              .when(top_mode.is_some(), Button::primary)
+              .when(top_mode.is_none(), ButtonVariants::ghost)
+              .compact()
+              .tooltip("Top mode: sort by live CPU / memory")
+              .on_click(cx.listener(|this, _ev, _window, cx| {
+                this.cycle_top_mode(cx);
+              })),
+          )
           .child(
             Button::new("search")
               .icon(Icon::new(AppIcon::Search))
@@ -704,7 +1171,7 @@ impl Render for ContainerList {
             .icon(IconName::Ellipsis)
             .primary()
             .xsmall()
-            .dropdown_menu(|menu, _, _| {
+            .dropdown_menu(move |menu, _, _| {
               menu
                 .item(
                   PopupMenuItem::new("Start")
@@ -731,8 +1198,20 @@ impl Render for ContainerList {
                 .item(
                   PopupMenuItem::new("Delete")
                     .icon(Icon::new(AppIcon::Trash))
-                    .on_click(|_, _, cx| {
-                      services::bulk_action_containers("delete", cx);
+                    .on_click(move |_, window, cx| {
+                      if settings_state(cx).read(cx).settings.confirm_destructive {
+                        crate::ui::dialogs::open_confirm_dialog(
+                          format!("Delete {bulk_count} containers?"),
+                          "The selected containers will be force-removed, including running ones.",
+                          None,
+                          "Delete",
+                          |_window, cx| services::bulk_action_containers("delete", cx),
+                          window,
+                          cx,
+                        );
+                      } else {
+                        services::bulk_action_containers("delete", cx);
+                      }
                     }),
                 )
             }),
@@ -759,7 +1238,7 @@ impl Render for ContainerList {
           .child(div().flex_1().when_some(self.search_input.clone(), |el, input| {
             el.child(Input::new(&input).small().w_full())
           }))
-          .when(!self.search_query.is_empty(), |el| {
+          .when(!self.search.query().is_empty(), |el| {
             el.child(
               Button::new("clear-search")
                 .icon(IconName::Close)
@@ -775,6 +1254,27 @@ impl Render for ContainerList {
       None
     };
 
+    let sort_header = render_sort_header(
+      "container-sort",
+      &ContainerSortKey::COLUMNS,
+      self.list_state.read(cx).delegate().sort,
+      cx.listener(|this, key: &ContainerSortKey, window, cx| {
+        this.on_sort_column(*key, window, cx);
+      }),
+      cx,
+    )
+    .child(
+      Button::new("container-sort-cpu")
+        .label("CPU")
+        .ghost()
+        .xsmall()
+        .selected(top_mode == Some(TopSort::Cpu))
+        .when(top_mode == Some(TopSort::Cpu), |b| b.icon(IconName::ArrowDown))
+        .on_click(cx.listener(|this, _ev, _window, cx| {
+          this.toggle_cpu_sort(cx);
+        })),
+    );
+
     let content: gpui::Div = match &containers_state {
       LoadState::NotLoaded | LoadState::Loading => render_loading("containers", cx),
       LoadState::Error(e) => {
@@ -789,7 +1289,9 @@ impl Render for ContainerList {
         )
       }
       LoadState::Loaded => {
-        if containers_empty && !is_filtering {
+        if containers_empty && !is_filtering && total_count > 0 && !show_stopped {
+          Self::render_all_stopped(cx)
+        } else if containers_empty && !is_filtering {
           Self::render_empty(cx)
         } else if containers_empty && is_filtering {
           self.render_no_results(cx)
@@ -807,6 +1309,10 @@ impl Render for ContainerList {
       .child(toolbar)
       .children(bulk_bar)
       .children(search_bar)
+      .when(
+        matches!(containers_state, LoadState::Loaded) && !containers_empty,
+        |el| el.child(sort_header),
+      )
       .child(
         div()
           .id("container-list-scroll")
@@ -817,3 +1323,93 @@ impl Render for ContainerList {
       )
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::docker::ContainerState;
+
+  fn usage(pairs: &[(&str, f64)]) -> HashMap<String, f64> {
+    pairs.iter().map(|(id, v)| ((*id).to_string(), *v)).collect()
+  }
+
+  fn ids(order: &[String]) -> Vec<&str> {
+    order.iter().map(String::as_str).collect()
+  }
+
+  #[test]
+  fn test_damped_top_order_sorts_fresh_ids_descending() {
+    let order = damped_top_order(&[], &usage(&[("a", 1.0), ("b", 50.0), ("c", 10.0)]), 1.0);
+    assert_eq!(ids(&order), vec!["b", "c", "a"]);
+  }
+
+  #[test]
+  fn test_damped_top_order_keeps_order_within_margin() {
+    let prev = vec!["a".to_string(), "b".to_string()];
+    // b leads a by less than the margin: no swap.
+    let order = damped_top_order(&prev, &usage(&[("a", 10.0), ("b", 10.5)]), 1.0);
+    assert_eq!(ids(&order), vec!["a", "b"]);
+    // b leads a by more than the margin: swap.
+    let order = damped_top_order(&prev, &usage(&[("a", 10.0), ("b", 12.0)]), 1.0);
+    assert_eq!(ids(&order), vec!["b", "a"]);
+  }
+
+  #[test]
+  fn test_damped_top_order_drops_missing_and_places_new() {
+    let prev = vec!["gone".to_string(), "a".to_string()];
+    let order = damped_top_order(&prev, &usage(&[("a", 5.0), ("new", 80.0)]), 1.0);
+    assert_eq!(ids(&order), vec!["new", "a"]);
+  }
+
+  fn container(name: &str, state: ContainerState, created: i64) -> ContainerInfo {
+    ContainerInfo {
+      created: chrono::DateTime::from_timestamp(created, 0),
+      ..ContainerInfo::for_test(name, "nginx", state)
+    }
+  }
+
+  fn names(rows: &[ContainerInfo]) -> Vec<&str> {
+    rows.iter().map(|c| c.name.as_str()).collect()
+  }
+
+  #[test]
+  fn test_sort_containers_default_is_running_first_then_name() {
+    let mut rows = vec![
+      container("web", ContainerState::Exited, 3),
+      container("db", ContainerState::Exited, 1),
+      container("cache", ContainerState::Running, 2),
+    ];
+    sort_containers(&mut rows, None);
+    assert_eq!(names(&rows), vec!["cache", "db", "web"]);
+  }
+
+  #[test]
+  fn test_sort_containers_by_column() {
+    let mut rows = vec![
+      container("web", ContainerState::Exited, 3),
+      container("db", ContainerState::Exited, 1),
+      container("cache", ContainerState::Running, 2),
+    ];
+    let created_desc = ColumnSort {
+      key: ContainerSortKey::Created,
+      descending: true,
+    };
+    sort_containers(&mut rows, Some(created_desc));
+    assert_eq!(names(&rows), vec!["web", "cache", "db"]);
+
+    // Stable: equal statuses keep the previous (created desc) order.
+    let status_desc = ColumnSort {
+      key: ContainerSortKey::Status,
+      descending: true,
+    };
+    sort_containers(&mut rows, Some(status_desc));
+    assert_eq!(names(&rows), vec!["web", "db", "cache"]);
+  }
+
+  #[test]
+  fn test_top_sort_cycle() {
+    assert_eq!(TopSort::next(None), Some(TopSort::Cpu));
+    assert_eq!(TopSort::next(Some(TopSort::Cpu)), Some(TopSort::Memory));
+    assert_eq!(TopSort::next(Some(TopSort::Memory)), None);
+  }
+}
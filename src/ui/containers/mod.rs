@@ -1,9 +1,14 @@
 mod cp_dialogs;
 mod create_dialog;
 mod detail;
+mod limits_dialog;
 mod list;
+mod stop_dialog;
 mod view;
 
-pub use cp_dialogs::{prompt_download_from_container, prompt_upload_to_container};
+pub use cp_dialogs::{
+  prompt_download_from_container, prompt_download_to_host, prompt_upload_into, prompt_upload_to_container,
+};
 pub use create_dialog::{CreateContainerDialog, CreateContainerOptions};
+pub use stop_dialog::open_stop_dialog;
 pub use view::ContainersView;
@@ -0,0 +1,135 @@
+//! "Stop with Timeout..." dialog: pick the grace period before Docker
+//! escalates to SIGKILL, and optionally a signal other than the image's
+//! `STOPSIGNAL`. Databases often need more than the default 10 seconds to
+//! flush and shut down cleanly.
+
+use gpui::{App, Entity, ParentElement, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  WindowExt,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  notification::NotificationType,
+  v_flex,
+};
+
+use crate::docker::DEFAULT_STOP_TIMEOUT_SECS;
+use crate::services;
+use crate::ui::components::form_field;
+
+/// Grace period in seconds; empty means Docker's default.
+fn parse_timeout(value: &str) -> Result<Option<u64>, String> {
+  let value = value.trim();
+  if value.is_empty() {
+    return Ok(None);
+  }
+  value
+    .parse::<u64>()
+    .map(Some)
+    .map_err(|_| format!("'{value}' is not a whole number of seconds"))
+}
+
+/// Accepts `SIGINT`, `INT` or a signal number; empty keeps the default.
+fn parse_signal(value: &str) -> Result<Option<String>, String> {
+  let value = value.trim().to_uppercase();
+  if value.is_empty() {
+    return Ok(None);
+  }
+  if value.parse::<u8>().is_ok() {
+    return Ok(Some(value));
+  }
+  let name = value.strip_prefix("SIG").unwrap_or(&value);
+  if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-') {
+    Ok(Some(format!("SIG{name}")))
+  } else {
+    Err(format!("'{value}' is not a signal name"))
+  }
+}
+
+pub fn open_stop_dialog(container_id: String, container_name: String, window: &mut Window, cx: &mut App) {
+  let timeout_input: Entity<InputState> = cx.new(|cx| {
+    let mut state = InputState::new(window, cx).placeholder(DEFAULT_STOP_TIMEOUT_SECS.to_string());
+    state.set_value(DEFAULT_STOP_TIMEOUT_SECS.to_string(), window, cx);
+    state
+  });
+  let signal_input: Entity<InputState> = cx.new(|cx| InputState::new(window, cx).placeholder("SIGTERM"));
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let timeout_for_btn = timeout_input.clone();
+    let signal_for_btn = signal_input.clone();
+    let id_for_btn = container_id.clone();
+    dialog
+      .title(format!("Stop {container_name}"))
+      .min_w(px(420.))
+      .child(
+        v_flex().p(px(16.)).gap(px(12.)).child(
+          h_flex()
+            .gap(px(12.))
+            .child(div().flex_1().child(form_field(
+              "Timeout (seconds)",
+              Input::new(&timeout_input).w_full(),
+              Some("Time to exit before SIGKILL."),
+              cx,
+            )))
+            .child(div().flex_1().child(form_field(
+              "Signal",
+              Input::new(&signal_input).w_full(),
+              Some("Empty uses the image's stop signal."),
+              cx,
+            ))),
+        ),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let timeout_input = timeout_for_btn.clone();
+        let signal_input = signal_for_btn.clone();
+        let id = id_for_btn.clone();
+        vec![
+          Button::new("stop-with-timeout")
+            .label("Stop")
+            .primary()
+            .on_click(move |_ev, window, cx| {
+              let timeout = parse_timeout(&timeout_input.read(cx).text().to_string());
+              let signal = parse_signal(&signal_input.read(cx).text().to_string());
+              match (timeout, signal) {
+                (Ok(timeout), Ok(signal)) => {
+                  services::stop_container(id.clone(), timeout, signal, cx);
+                  window.close_dialog(cx);
+                }
+                (Err(e), _) | (_, Err(e)) => {
+                  window.push_notification((NotificationType::Error, SharedString::from(e)), cx);
+                }
+              }
+            })
+            .into_any_element(),
+          Button::new("stop-cancel")
+            .label("Cancel")
+            .ghost()
+            .on_click(|_ev, window, cx| {
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_timeout() {
+    assert_eq!(parse_timeout(" 60 "), Ok(Some(60)));
+    assert_eq!(parse_timeout(""), Ok(None));
+    assert!(parse_timeout("-1").is_err());
+  }
+
+  #[test]
+  fn test_parse_signal() {
+    assert_eq!(parse_signal(""), Ok(None));
+    assert_eq!(parse_signal("int"), Ok(Some("SIGINT".to_string())));
+    assert_eq!(parse_signal("SIGQUIT"), Ok(Some("SIGQUIT".to_string())));
+    assert_eq!(parse_signal("15"), Ok(Some("15".to_string())));
+    assert!(parse_signal("SIG TERM").is_err());
+  }
+}
@@ -1,21 +1,38 @@
 use gpui::{App, Context, Entity, Render, Styled, Task, Timer, Window, div, prelude::*, px};
 use gpui_component::{
-  WindowExt,
+  Disableable, WindowExt,
   button::{Button, ButtonVariants},
-  input::InputState,
+  input::{InputEvent, InputState, Position},
   theme::ActiveTheme,
 };
 use std::time::Duration;
 
-use crate::docker::ContainerInfo;
-use crate::services;
+use crate::docker::{ContainerInfo, inspect_summary, validate_container_name};
+use crate::services::{self, RefreshInterval};
 use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
-use crate::terminal::{LogStream, TerminalSessionType, TerminalView};
-use crate::ui::components::{ProcessView, detect_language_from_path};
+use crate::terminal::{AttachStream, LogStream, TerminalSessionType, TerminalView};
+use crate::ui::components::{ProcessView, copy_to_clipboard, detect_language_from_path};
+use crate::ui::dialogs;
 
-use super::detail::{ContainerDetail, ContainerDetailTab, ContainerTabState};
+use super::create_dialog::options_from_inspect;
+use super::detail::{ContainerDetail, ContainerDetailTab, ContainerTabState, text_matches};
 use super::list::{ContainerList, ContainerListEvent};
 
+/// Why `new_name` can't be used: it breaks Docker's naming rule or another
+/// container already has it. Keeping `current_name` is not an error.
+fn rename_error(new_name: &str, current_name: &str, containers: &[ContainerInfo]) -> Option<String> {
+  if new_name == current_name {
+    return None;
+  }
+  if let Err(e) = validate_container_name(new_name) {
+    return Some(e);
+  }
+  containers
+    .iter()
+    .any(|c| c.name == new_name)
+    .then(|| format!("A container named '{new_name}' already exists"))
+}
+
 /// Self-contained Containers view - handles list, detail, and all state
 pub struct ContainersView {
   docker_state: Entity<DockerState>,
@@ -23,8 +40,15 @@ pub struct ContainersView {
   // View-specific state (not selection - that's in global DockerState)
   active_tab: ContainerDetailTab,
   terminal_view: Option<Entity<TerminalView>>,
+  /// Shell picked in the Terminal tab; `None` auto-detects. Kept across
+  /// container switches so the choice sticks while browsing.
+  terminal_shell: Option<String>,
   process_view: Option<Entity<ProcessView>>,
   inspect_editor: Option<Entity<InputState>>,
+  /// Find box above the inspect editor; kept across container switches
+  inspect_find_input: Option<Entity<InputState>>,
+  /// Index into the current inspect text's matches
+  inspect_find_index: usize,
   file_content_editor: Option<Entity<InputState>>,
   container_tab_state: ContainerTabState,
   // Track what we've synced to editors to prevent infinite loops
@@ -42,6 +66,10 @@ pub struct ContainersView {
   /// the streaming logs share selection / scroll / drag-extend with
   /// the interactive terminal.
   logs_terminal_view: Option<Entity<TerminalView>>,
+  /// `docker attach` session on the Attach tab. Dropping it closes the
+  /// connection without touching the container's main process.
+  attach_stream: Option<std::sync::Arc<AttachStream>>,
+  attach_view: Option<Entity<TerminalView>>,
 }
 
 impl ContainersView {
@@ -91,6 +119,19 @@ impl ContainersView {
             if let Some(id) = selected_id {
               let updated = state.read(cx).containers.iter().find(|c| c.id == id).cloned();
               if let Some(container) = updated {
+                // Healthcheck results change between polls; keep the Info
+                // tab's health log in step with the list badge.
+                if container.health.is_some() {
+                  Self::load_container_extras(&container.id, cx);
+                }
+                // Stop polling `docker top` once the container stops, and
+                // pick it back up if it starts while the tab is open.
+                if !container.state.is_running() {
+                  this.process_view = None;
+                } else if this.active_tab == ContainerDetailTab::Processes && this.process_view.is_none() {
+                  let id = container.id.clone();
+                  this.process_view = Some(cx.new(|cx| ProcessView::for_container(id, window, cx)));
+                }
                 // Update the selected container info in global state
                 this.docker_state.update(cx, |s, _| {
                   s.set_selection(Selection::Container(container));
@@ -102,10 +143,18 @@ impl ContainersView {
                 });
                 this.active_tab = ContainerDetailTab::Info;
                 this.terminal_view = None;
+                this.close_attach();
               }
             }
             cx.notify();
           }
+          StateChanged::ContainerConfigUpdated { container_id } => {
+            let selected =
+              matches!(&this.docker_state.read(cx).selection, Selection::Container(c) if c.id == *container_id);
+            if selected {
+              Self::load_container_extras(container_id, cx);
+            }
+          }
           StateChanged::ContainerTabRequest { container_id, tab } => {
             // Find the container and select it with the specified tab
             let container = {
@@ -135,31 +184,37 @@ impl ContainersView {
           } => {
             Self::show_export_dialog(container_id, container_name, window, cx);
           }
+          StateChanged::ContainerFileSaved { container_id, path } => {
+            let is_open = this.selected_container(cx).is_some_and(|c| c.id == *container_id)
+              && this.container_tab_state.selected_file.as_deref() == Some(path.as_str());
+            if is_open {
+              // Reload so the viewer shows what actually landed on disk
+              this.container_tab_state.file_editing = false;
+              this.container_tab_state.file_content_loading = true;
+              this.last_synced_file_content.clear();
+              Self::load_container_file_content(container_id, path, cx);
+              cx.notify();
+            }
+          }
           _ => {}
         }
       },
     )
     .detach();
 
-    // Start periodic container refresh using interval from settings
-    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
-    cx.spawn(async move |_this, cx| {
-      loop {
-        Timer::after(Duration::from_secs(refresh_interval)).await;
-        let _ = cx.update(|cx| {
-          services::refresh_containers(cx);
-        });
-      }
-    })
-    .detach();
+    // Periodic container refresh using the interval from settings
+    services::spawn_auto_refresh(RefreshInterval::Lists, 1, cx, |_, cx| services::refresh_containers(cx));
 
     Self {
       docker_state,
       container_list,
       active_tab: ContainerDetailTab::Info,
       terminal_view: None,
+      terminal_shell: None,
       process_view: None,
       inspect_editor: None,
+      inspect_find_input: None,
+      inspect_find_index: 0,
       file_content_editor: None,
       container_tab_state: ContainerTabState::new(),
       last_synced_inspect: String::new(),
@@ -168,22 +223,38 @@ impl ContainersView {
       stats_task: None,
       logs_stream: None,
       logs_terminal_view: None,
+      attach_stream: None,
+      attach_view: None,
     }
   }
 
   fn show_rename_dialog(container_id: String, current_name: String, window: &mut Window, cx: &mut Context<'_, Self>) {
-    use gpui_component::input::{Input, InputState};
+    use gpui_component::{
+      input::{Input, InputState},
+      v_flex,
+    };
 
-    let name_input = cx.new(|cx| InputState::new(window, cx).default_value(current_name));
+    let name_input = cx.new(|cx| InputState::new(window, cx).default_value(current_name.clone()));
 
-    window.open_dialog(cx, move |dialog, _window, _cx| {
+    window.open_dialog(cx, move |dialog, _window, cx| {
       let name_input_clone = name_input.clone();
       let container_id = container_id.clone();
+      let new_name = name_input.read(cx).text().to_string().trim().to_string();
+      let error = rename_error(&new_name, &current_name, &docker_state(cx).read(cx).containers);
+      let can_rename = error.is_none() && new_name != current_name;
+      let danger = cx.theme().colors.danger;
 
       dialog
         .title("Rename Container")
         .min_w(px(400.))
-        .child(Input::new(&name_input).w_full())
+        .child(
+          v_flex()
+            .gap(px(6.))
+            .child(Input::new(&name_input).w_full())
+            .when_some(error, |el, error| {
+              el.child(div().text_xs().text_color(danger).child(error))
+            }),
+        )
         .footer(move |_dialog_state, _, _window, _cx| {
           let name_input = name_input_clone.clone();
           let id = container_id.clone();
@@ -192,12 +263,11 @@ impl ContainersView {
             Button::new("rename")
               .label("Rename")
               .primary()
+              .disabled(!can_rename)
               .on_click(move |_ev, window, cx| {
-                let new_name = name_input.read(cx).text().to_string();
-                if !new_name.is_empty() {
-                  services::rename_container(id.clone(), new_name, cx);
-                  window.close_dialog(cx);
-                }
+                let new_name = name_input.read(cx).text().to_string().trim().to_string();
+                services::rename_container(id.clone(), new_name, cx);
+                window.close_dialog(cx);
               })
               .into_any_element(),
           ]
@@ -358,12 +428,16 @@ impl ContainersView {
     // Reset view-specific state but keep active_tab
     // This allows users to stay on their current tab when switching containers
     self.terminal_view = None;
+    self.close_attach();
     self.process_view = None;
     self.last_synced_inspect.clear();
     self.last_synced_file_content.clear();
 
-    // Reset file explorer state to root
+    // Reset file explorer state to root, keeping the inspect mode
+    let inspect_raw = self.container_tab_state.inspect_raw;
     self.container_tab_state = ContainerTabState::new();
+    self.container_tab_state.inspect_raw = inspect_raw;
+    self.inspect_find_index = 0;
 
     // Create editors for inspect with syntax highlighting
     // Note: code_editor() is required for replace() method to work
@@ -375,6 +449,18 @@ impl ContainersView {
         .searchable(true)
         .soft_wrap(false)
     }));
+    if self.inspect_find_input.is_none() {
+      let input = cx.new(|cx| InputState::new(window, cx).placeholder("Find..."));
+      cx.subscribe_in(&input, window, |this, _state, ev: &InputEvent, window, cx| {
+        if matches!(ev, InputEvent::Change) {
+          this.inspect_find_index = 0;
+          this.jump_to_inspect_match(window, cx);
+          cx.notify();
+        }
+      })
+      .detach();
+      self.inspect_find_input = Some(input);
+    }
 
     // Reset file content editor
     self.file_content_editor = None;
@@ -391,7 +477,10 @@ impl ContainersView {
     // switching containers while on Terminal/Processes leaves the user staring
     // at an indefinite "Connecting..." spinner because on_tab_change never fires.
     let tab = self.active_tab;
-    if tab == ContainerDetailTab::Terminal || tab == ContainerDetailTab::Processes {
+    if matches!(
+      tab,
+      ContainerDetailTab::Terminal | ContainerDetailTab::Attach | ContainerDetailTab::Processes
+    ) {
       self.on_tab_change(tab, window, cx);
     }
 
@@ -417,9 +506,25 @@ impl ContainersView {
       && self.terminal_view.is_none()
       && let Some(ref container) = self.selected_container(cx)
     {
-      let container_id = container.id.clone();
-      self.terminal_view =
-        Some(cx.new(|cx| TerminalView::new(TerminalSessionType::docker_exec(container_id, None), window, cx)));
+      let session = TerminalSessionType::docker_exec(container.id.clone(), self.terminal_shell.clone());
+      self.terminal_view = Some(cx.new(|cx| TerminalView::new(session, window, cx)));
+    }
+
+    // Attach tab: connect to the main process once per container. The
+    // session survives tab switches like the exec terminal does.
+    if tab == ContainerDetailTab::Attach
+      && self.attach_view.is_none()
+      && let Some(ref container) = self.selected_container(cx)
+      && container.state.is_running()
+    {
+      let id = container.id.clone();
+      self.open_attach(id, cx);
+    }
+
+    // The process view polls `docker top` while it exists; only keep it
+    // around while its tab is open.
+    if tab != ContainerDetailTab::Processes {
+      self.process_view = None;
     }
 
     // If switching to processes tab, create process view
@@ -516,6 +621,7 @@ impl ContainersView {
     self.last_synced_file_content.clear();
 
     // Set selected file in state
+    self.container_tab_state.file_editing = false;
     self.container_tab_state.selected_file = Some(path.to_string());
     self.container_tab_state.file_content_loading = true;
     self.container_tab_state.file_content_error = None;
@@ -531,11 +637,38 @@ impl ContainersView {
     self.container_tab_state.selected_file = None;
     self.container_tab_state.file_content.clear();
     self.container_tab_state.file_content_error = None;
+    self.container_tab_state.file_editing = false;
     self.file_content_editor = None;
     self.last_synced_file_content.clear();
     cx.notify();
   }
 
+  fn on_cancel_file_edit(&mut self, cx: &mut Context<'_, Self>) {
+    self.container_tab_state.file_editing = false;
+    // Force the next render to put the loaded content back in the editor
+    self.last_synced_file_content.clear();
+    cx.notify();
+  }
+
+  /// Confirm, then write the editor's text over `path` in the container.
+  fn on_save_file(&mut self, path: &str, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let (Some(container), Some(editor)) = (self.selected_container(cx), self.file_content_editor.clone()) else {
+      return;
+    };
+    let content = editor.read(cx).text().to_string();
+    let id = container.id;
+    let path = path.to_string();
+    dialogs::open_confirm_dialog(
+      "Overwrite file?",
+      format!("Replace {path} in {} with your edits?", container.name),
+      Some("Processes reading the file will see the new content immediately.".into()),
+      "Save",
+      move |_window, cx| services::write_container_file(id.clone(), path.clone(), content.clone(), cx),
+      window,
+      cx,
+    );
+  }
+
   fn load_container_file_content(container_id: &str, path: &str, cx: &mut Context<'_, Self>) {
     let id = container_id.to_string();
     let path = path.to_string();
@@ -651,6 +784,15 @@ impl ContainersView {
   fn on_open_in_editor(&mut self, data: &(String, bool), _window: &mut Window, cx: &mut Context<'_, Self>) {
     let (path, is_dir) = data;
     if let Some(ref container) = self.selected_container(cx) {
+      // Files open as a local copy; folders need a remote-capable editor
+      if !*is_dir {
+        let source = services::EditorSource::Container {
+          id: container.id.clone(),
+        };
+        services::open_file_copy_in_editor(source, path.clone(), cx);
+        return;
+      }
+
       // Find SSH port (port 22 mapped to host)
       let ssh_port = container
         .ports
@@ -672,9 +814,6 @@ impl ContainersView {
     self.container_tab_state.container_extras = None;
     let tokio_handle = services::Tokio::runtime_handle();
     let client = services::docker_client();
-    let id_for_extras = id_for_inspect.clone();
-    let tokio_for_extras = tokio_handle.clone();
-    let client_for_extras = client.clone();
     cx.spawn(async move |this, cx| {
       let inspect = cx
         .background_executor()
@@ -693,6 +832,7 @@ impl ContainersView {
         .await;
 
       let _ = this.update(cx, |this, cx| {
+        this.container_tab_state.inspect_summary = inspect_summary(&inspect).unwrap_or_else(|| inspect.clone());
         this.container_tab_state.inspect = inspect;
         this.container_tab_state.inspect_loading = false;
         cx.notify();
@@ -700,14 +840,77 @@ impl ContainersView {
     })
     .detach();
 
+    Self::load_container_extras(&id, cx);
+
+    // Logs: streaming or snapshot per current toggle.
+    self.restart_logs(&id, cx);
+  }
+
+  /// Text shown in the Inspect tab for the current mode and reveal toggle
+  fn inspect_text(&self, cx: &App) -> String {
+    self
+      .container_tab_state
+      .inspect_text(&settings_state(cx).read(cx).settings)
+  }
+
+  fn inspect_matches(&self, cx: &App) -> Option<Vec<(u32, u32)>> {
+    let query = self.inspect_find_input.as_ref()?.read(cx).text().to_string();
+    if query.is_empty() {
+      return None;
+    }
+    Some(text_matches(&self.inspect_text(cx), &query))
+  }
+
+  fn inspect_find_status(&self, cx: &App) -> Option<(usize, usize)> {
+    let total = self.inspect_matches(cx)?.len();
+    Some((if total == 0 { 0 } else { self.inspect_find_index + 1 }, total))
+  }
+
+  fn on_inspect_find_step(&mut self, delta: isize, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let total = self.inspect_matches(cx).map_or(0, |m| m.len());
+    if total == 0 {
+      return;
+    }
+    // Wrap around at either end
+    self.inspect_find_index = (self.inspect_find_index + total).wrapping_add_signed(delta) % total;
+    self.jump_to_inspect_match(window, cx);
+    cx.notify();
+  }
+
+  /// Move the inspect editor's cursor onto the current find match
+  fn jump_to_inspect_match(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let Some(matches) = self.inspect_matches(cx) else {
+      return;
+    };
+    let (Some(editor), Some(&(line, character))) = (self.inspect_editor.clone(), matches.get(self.inspect_find_index))
+    else {
+      return;
+    };
+    // The editor may still hold the other mode's text until the next render syncs it
+    let text = self.inspect_text(cx);
+    if self.last_synced_inspect != text {
+      editor.update(cx, |state, cx| state.set_value(text.clone(), window, cx));
+      self.last_synced_inspect = text;
+    }
+    editor.update(cx, |state, cx| {
+      state.set_cursor_position(Position { line, character }, window, cx);
+    });
+  }
+
+  /// Fetch the structured inspect extras (health log, exit code, mounts).
+  /// Also re-run on every list refresh for containers with a healthcheck.
+  fn load_container_extras(container_id: &str, cx: &mut Context<'_, Self>) {
+    let id = container_id.to_string();
+    let tokio_handle = services::Tokio::runtime_handle();
+    let client = services::docker_client();
     cx.spawn(async move |this, cx| {
       let extras = cx
         .background_executor()
         .spawn(async move {
-          tokio_for_extras.block_on(async {
-            let guard = client_for_extras.read().await;
+          tokio_handle.block_on(async {
+            let guard = client.read().await;
             match guard.as_ref() {
-              Some(c) => c.container_extras(&id_for_extras).await.ok(),
+              Some(c) => c.container_extras(&id).await.ok(),
               None => None,
             }
           })
@@ -722,9 +925,55 @@ impl ContainersView {
       });
     })
     .detach();
+  }
 
-    // Logs: streaming or snapshot per current toggle.
-    self.restart_logs(&id, cx);
+  /// Switch the exec shell and reconnect the Terminal tab with it.
+  fn on_shell_change(&mut self, shell: Option<String>, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.terminal_shell == shell {
+      return;
+    }
+    self.terminal_shell = shell;
+    self.terminal_view = None;
+    if self.active_tab == ContainerDetailTab::Terminal {
+      self.on_tab_change(ContainerDetailTab::Terminal, window, cx);
+    }
+    cx.notify();
+  }
+
+  fn open_attach(&mut self, container_id: String, cx: &mut Context<'_, Self>) {
+    self.attach_stream = services::attach_container(container_id, cx);
+    self.attach_view = self
+      .attach_stream
+      .clone()
+      .map(|stream| cx.new(|cx| TerminalView::for_attach_stream(stream, cx)));
+  }
+
+  fn close_attach(&mut self) {
+    self.attach_view = None;
+    self.attach_stream = None;
+  }
+
+  /// Open the create dialog pre-filled from the selected container
+  fn on_duplicate(&self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    match options_from_inspect(&self.container_tab_state.inspect) {
+      Some(options) => dialogs::open_duplicate_container_dialog(options, window, cx),
+      None => services::dispatcher(cx).update(cx, |_, cx| {
+        cx.emit(services::DispatcherEvent::TaskFailed {
+          error: "Couldn't read this container's settings to duplicate it".to_string(),
+        });
+      }),
+    }
+  }
+
+  fn on_reattach(&mut self, cx: &mut Context<'_, Self>) {
+    self.close_attach();
+    if let Some(ref container) = self.selected_container(cx)
+      && container.state.is_running()
+    {
+      let id = container.id.clone();
+      self.open_attach(id, cx);
+    }
+    cx.notify();
   }
 
   /// Drop the previous logs task (if any) and start a fresh one matching
@@ -880,6 +1129,23 @@ impl ContainersView {
     cx.notify();
   }
 
+  fn on_save_logs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let (Some(container), Some(stream)) = (self.selected_container(cx), self.logs_stream.as_ref()) else {
+      return;
+    };
+    dialogs::prompt_save_logs(container.name.clone(), stream.captured_chunks(), window, cx);
+  }
+
+  fn is_container_running(&self, id: &str, cx: &App) -> bool {
+    self
+      .docker_state
+      .read(cx)
+      .containers
+      .iter()
+      .find(|c| c.id == id)
+      .is_some_and(|c| c.state.is_running())
+  }
+
   fn start_stats_poll(&mut self, container_id: String, cx: &mut Context<'_, Self>) {
     self.stats_task = None;
     let interval = settings_state(cx).read(cx).settings.stats_refresh_interval.max(1);
@@ -903,32 +1169,13 @@ impl ContainersView {
 
         let still_alive = this
           .update(cx, |this, cx| {
-            if let Some(s) = stats {
-              this.container_tab_state.stats_cpu.push(s.cpu_percent);
-              this.container_tab_state.stats_mem_pct.push(s.memory_percent);
-              #[allow(clippy::cast_precision_loss)]
-              this
-                .container_tab_state
-                .stats_net
-                .push((s.network_rx + s.network_tx) as f64);
-              #[allow(clippy::cast_precision_loss)]
-              this
-                .container_tab_state
-                .stats_disk
-                .push((s.block_read + s.block_write) as f64);
-              for v in [
-                &mut this.container_tab_state.stats_cpu,
-                &mut this.container_tab_state.stats_mem_pct,
-                &mut this.container_tab_state.stats_net,
-                &mut this.container_tab_state.stats_disk,
-              ] {
-                if v.len() > 60 {
-                  v.remove(0);
-                }
-              }
-              this.container_tab_state.stats_latest = Some(s);
-              cx.notify();
+            let running = this.is_container_running(&container_id, cx);
+            match stats {
+              Some(s) if running => this.container_tab_state.push_stats(s),
+              // Container stopped (or stats failed): freeze the charts.
+              _ => this.container_tab_state.stats_stale = true,
             }
+            cx.notify();
             // Stop the loop if the user navigated away from Stats.
             this.active_tab == ContainerDetailTab::Stats
           })
@@ -951,13 +1198,12 @@ impl Render for ContainersView {
     // marker for "we have data" until other call sites stop checking it.
 
     if let Some(ref editor) = self.inspect_editor {
-      let inspect = &self.container_tab_state.inspect;
-      if !inspect.is_empty() && !self.container_tab_state.inspect_loading && self.last_synced_inspect != *inspect {
-        let inspect_clone = inspect.clone();
+      let inspect = self.inspect_text(cx);
+      if !inspect.is_empty() && !self.container_tab_state.inspect_loading && self.last_synced_inspect != inspect {
         editor.update(cx, |state, cx| {
-          state.set_value(inspect_clone, window, cx);
+          state.set_value(inspect.clone(), window, cx);
         });
-        self.last_synced_inspect = inspect.clone();
+        self.last_synced_inspect = inspect;
       }
     }
 
@@ -992,13 +1238,42 @@ impl Render for ContainersView {
       .active_tab(active_tab)
       .container_state(container_tab_state)
       .terminal_view(terminal_view)
+      .terminal_shell(self.terminal_shell.clone())
+      .on_shell_change(cx.listener(|this, shell: &Option<String>, window, cx| {
+        this.on_shell_change(shell.clone(), window, cx);
+      }))
       .process_view(process_view)
       .logs_terminal(self.logs_terminal_view.clone())
+      .attach_view(self.attach_view.clone())
+      .attach_stream(self.attach_stream.clone())
       .inspect_editor(inspect_editor)
+      .inspect_find_input(self.inspect_find_input.clone())
+      .inspect_find_status(self.inspect_find_status(cx))
+      .on_inspect_mode(cx.listener(|this, raw: &bool, window, cx| {
+        this.container_tab_state.inspect_raw = *raw;
+        this.inspect_find_index = 0;
+        this.jump_to_inspect_match(window, cx);
+        cx.notify();
+      }))
+      .on_inspect_reveal(cx.listener(|this, reveal: &bool, window, cx| {
+        this.container_tab_state.inspect_reveal_secrets = *reveal;
+        this.jump_to_inspect_match(window, cx);
+        cx.notify();
+      }))
+      .on_inspect_find_step(cx.listener(|this, delta: &isize, window, cx| {
+        this.on_inspect_find_step(*delta, window, cx);
+      }))
       .file_content_editor(file_content_editor)
       .on_tab_change(cx.listener(|this, tab: &ContainerDetailTab, window, cx| {
         this.on_tab_change(*tab, window, cx);
       }))
+      .on_copy(copy_to_clipboard)
+      .on_duplicate(cx.listener(|this, (): &(), window, cx| {
+        this.on_duplicate(window, cx);
+      }))
+      .on_reattach(cx.listener(|this, (): &(), _window, cx| {
+        this.on_reattach(cx);
+      }))
       .on_refresh_logs(cx.listener(|this, (): &(), window, cx| {
         this.on_refresh_logs(window, cx);
       }))
@@ -1011,6 +1286,9 @@ impl Render for ContainersView {
       .on_clear_logs(cx.listener(|this, (): &(), _window, cx| {
         this.on_clear_logs(cx);
       }))
+      .on_save_logs(cx.listener(|this, (): &(), window, cx| {
+        this.on_save_logs(window, cx);
+      }))
       .on_navigate_path(cx.listener(|this, path: &str, _window, cx| {
         this.on_navigate_path(path, cx);
       }))
@@ -1020,6 +1298,16 @@ impl Render for ContainersView {
       .on_close_file_viewer(cx.listener(|this, (): &(), _window, cx| {
         this.on_close_file_viewer(cx);
       }))
+      .on_edit_file(cx.listener(|this, (): &(), _window, cx| {
+        this.container_tab_state.file_editing = true;
+        cx.notify();
+      }))
+      .on_cancel_file_edit(cx.listener(|this, (): &(), _window, cx| {
+        this.on_cancel_file_edit(cx);
+      }))
+      .on_save_file(cx.listener(|this, path: &str, window, cx| {
+        this.on_save_file(path, window, cx);
+      }))
       .on_symlink_click(cx.listener(|this, path: &str, window, cx| {
         this.on_symlink_follow(path, window, cx);
       }))
@@ -15,7 +15,7 @@ use crate::assets::AppIcon;
 use crate::kubernetes::CronJobInfo;
 use crate::services;
 use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_k8s_error, render_loading};
+use crate::ui::components::{CachedListDelegate, refresh_rows, render_k8s_error, render_loading};
 
 pub enum CronJobListEvent {
   Selected(CronJobInfo),
@@ -24,6 +24,7 @@ pub enum CronJobListEvent {
 pub struct CronJobListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<CronJobInfo>,
 }
 
 impl CronJobListDelegate {
@@ -41,7 +42,7 @@ impl CronJobListDelegate {
     }
   }
 
-  fn filtered(&self, cx: &App) -> Vec<CronJobInfo> {
+  fn collect_rows(&self, cx: &App) -> Vec<CronJobInfo> {
     let items = self.items(cx);
     if self.search_query.is_empty() {
       return items;
@@ -53,16 +54,40 @@ impl CronJobListDelegate {
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered(&self) -> &[CronJobInfo] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for CronJobListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|c| matches!(global_selection, Selection::CronJob { name, namespace } if *name == c.name && *namespace == c.namespace))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for CronJobListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered().len()
   }
 
   fn render_item(
@@ -71,7 +96,7 @@ impl ListDelegate for CronJobListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let items = self.filtered(cx);
+    let items = self.filtered();
     let c = items.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -255,13 +280,15 @@ impl CronJobList {
     let delegate = CronJobListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered(cx);
+        let filtered = delegate.filtered();
         if let Some(c) = filtered.get(ix.row) {
           cx.emit(CronJobListEvent::Selected(c.clone()));
         }
@@ -270,18 +297,22 @@ impl CronJobList {
     })
     .detach();
 
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(
-        event,
-        StateChanged::CronJobsUpdated
-          | StateChanged::NamespacesUpdated
-          | StateChanged::MachinesUpdated
-          | StateChanged::SelectionChanged
-      ) {
-        this.list_state.update(cx, |_state, cx| cx.notify());
-        cx.notify();
-      }
-    })
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(
+          event,
+          StateChanged::CronJobsUpdated
+            | StateChanged::NamespacesUpdated
+            | StateChanged::MachinesUpdated
+            | StateChanged::SelectionChanged
+        ) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
+          cx.notify();
+        }
+      },
+    )
     .detach();
 
     Self {
@@ -306,7 +337,7 @@ impl CronJobList {
       if current_text != self.search_query {
         current_text.clone_into(&mut self.search_query);
         self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+          state.delegate_mut().set_search_query(current_text, cx);
           cx.notify();
         });
       }
@@ -321,7 +352,7 @@ impl CronJobList {
       self.search_query.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -368,7 +399,7 @@ impl Render for CronJobList {
     let state = self.docker_state.read(cx);
     let total_count = state.cronjobs.len();
     let load_state = state.cronjobs_state.clone();
-    let filtered_count = self.list_state.read(cx).delegate().filtered(cx).len();
+    let filtered_count = self.list_state.read(cx).delegate().filtered().len();
     let is_filtering = !self.search_query.is_empty();
     let empty = filtered_count == 0;
 
@@ -0,0 +1,197 @@
+use gpui::{App, Context, Entity, FocusHandle, Focusable, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  IconName, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  label::Label,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use crate::kubernetes::DeploymentContainer;
+
+/// Dialog for changing one container's image and env vars
+pub struct ContainerDialog {
+  focus_handle: FocusHandle,
+  deployment_name: String,
+  namespace: String,
+  container: DeploymentContainer,
+  image_input: Option<Entity<InputState>>,
+  env_vars: Vec<(String, String)>,
+  env_key_input: Option<Entity<InputState>>,
+  env_value_input: Option<Entity<InputState>>,
+}
+
+impl ContainerDialog {
+  pub fn new(
+    deployment_name: String,
+    namespace: String,
+    container: DeploymentContainer,
+    cx: &mut Context<'_, Self>,
+  ) -> Self {
+    let focus_handle = cx.focus_handle();
+    let env_vars = container.env.clone();
+
+    Self {
+      focus_handle,
+      deployment_name,
+      namespace,
+      container,
+      image_input: None,
+      env_vars,
+      env_key_input: None,
+      env_value_input: None,
+    }
+  }
+
+  fn ensure_inputs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.image_input.is_none() {
+      let image = self.container.image.clone();
+      self.image_input = Some(cx.new(|cx| InputState::new(window, cx).default_value(image)));
+    }
+    if self.env_key_input.is_none() {
+      self.env_key_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("KEY")));
+    }
+    if self.env_value_input.is_none() {
+      self.env_value_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("VALUE")));
+    }
+  }
+
+  /// The image typed in, when it differs from the running one
+  pub fn changed_image(&self, cx: &App) -> Option<String> {
+    let image = self
+      .image_input
+      .as_ref()?
+      .read(cx)
+      .text()
+      .to_string()
+      .trim()
+      .to_string();
+    (!image.is_empty() && image != self.container.image).then_some(image)
+  }
+
+  pub fn env_vars(&self) -> &[(String, String)] {
+    &self.env_vars
+  }
+
+  pub fn original_env(&self) -> &[(String, String)] {
+    &self.container.env
+  }
+
+  pub fn deployment_name(&self) -> &str {
+    &self.deployment_name
+  }
+
+  pub fn namespace(&self) -> &str {
+    &self.namespace
+  }
+
+  pub fn container_name(&self) -> &str {
+    &self.container.name
+  }
+}
+
+impl Focusable for ContainerDialog {
+  fn focus_handle(&self, _cx: &App) -> FocusHandle {
+    self.focus_handle.clone()
+  }
+}
+
+impl Render for ContainerDialog {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    self.ensure_inputs(window, cx);
+
+    let colors = cx.theme().colors;
+    let image_input = self.image_input.clone().unwrap();
+    let env_key_input = self.env_key_input.clone().unwrap();
+    let env_value_input = self.env_value_input.clone().unwrap();
+
+    v_flex()
+      .w_full()
+      .gap(px(12.))
+      .p(px(16.))
+      .child(div().text_sm().text_color(colors.muted_foreground).child(format!(
+        "Container '{}' of deployment '{}' in namespace '{}'. Saving starts a rollout.",
+        self.container.name, self.deployment_name, self.namespace
+      )))
+      .child(Label::new("Image").text_color(colors.foreground))
+      .child(Input::new(&image_input).small())
+      .child(Label::new("Environment").text_color(colors.foreground))
+      .child(
+        h_flex()
+          .w_full()
+          .gap(px(8.))
+          .items_center()
+          .child(div().w(px(140.)).child(Input::new(&env_key_input).small()))
+          .child(Label::new("=").text_color(colors.muted_foreground))
+          .child(div().flex_1().child(Input::new(&env_value_input).small()))
+          .child(
+            Button::new("add-env")
+              .icon(IconName::Plus)
+              .xsmall()
+              .ghost()
+              .on_click(cx.listener(|this, _ev, window, cx| {
+                let key = this
+                  .env_key_input
+                  .as_ref()
+                  .map(|s| s.read(cx).text().to_string().trim().to_string())
+                  .unwrap_or_default();
+                let value = this
+                  .env_value_input
+                  .as_ref()
+                  .map(|s| s.read(cx).text().to_string())
+                  .unwrap_or_default();
+
+                if !key.is_empty() {
+                  // Re-adding a key replaces its value
+                  this.env_vars.retain(|(k, _)| *k != key);
+                  this.env_vars.push((key, value));
+                  this.env_key_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("KEY")));
+                  this.env_value_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("VALUE")));
+                  cx.notify();
+                }
+              })),
+          ),
+      )
+      .children(self.env_vars.iter().enumerate().map(|(idx, (key, value))| {
+        h_flex()
+          .w_full()
+          .py(px(6.))
+          .px(px(12.))
+          .gap(px(8.))
+          .items_center()
+          .bg(colors.sidebar)
+          .rounded(px(4.))
+          .child(
+            div()
+              .w(px(140.))
+              .text_sm()
+              .text_color(colors.link)
+              .overflow_hidden()
+              .text_ellipsis()
+              .child(key.clone()),
+          )
+          .child(Label::new("=").text_color(colors.muted_foreground))
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .text_color(colors.foreground)
+              .overflow_hidden()
+              .text_ellipsis()
+              .child(value.clone()),
+          )
+          .child(
+            Button::new(SharedString::from(format!("remove-env-{idx}")))
+              .icon(IconName::Minus)
+              .xsmall()
+              .ghost()
+              .on_click(cx.listener(move |this, _ev, _window, cx| {
+                this.env_vars.remove(idx);
+                cx.notify();
+              })),
+          )
+      }))
+  }
+}
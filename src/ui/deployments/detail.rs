@@ -15,6 +15,7 @@ use crate::assets::AppIcon;
 use crate::kubernetes::{DeploymentInfo, PodInfo};
 use crate::services;
 use crate::state::{DeploymentDetailTab, DockerState, StateChanged, docker_state};
+use crate::ui::components::{DescribeOutput, ResourceEvents, render_describe_tab, render_event_list};
 
 /// Detail view for a deployment with tabs
 pub struct DeploymentDetail {
@@ -22,6 +23,8 @@ pub struct DeploymentDetail {
   deployment: Option<DeploymentInfo>,
   active_tab: DeploymentDetailTab,
   yaml_content: String,
+  describe: DescribeOutput,
+  events: ResourceEvents,
   yaml_editor: Option<Entity<InputState>>,
   last_synced_yaml: String,
 }
@@ -46,6 +49,35 @@ impl DeploymentDetail {
             cx.notify();
           }
         }
+        StateChanged::DeploymentDescribeLoaded {
+          deployment_name,
+          namespace,
+          describe,
+        } => {
+          if let Some(ref current) = this.deployment
+            && current.name == *deployment_name
+            && current.namespace == *namespace
+          {
+            this.describe.set(describe);
+            cx.notify();
+          }
+        }
+        StateChanged::ResourceEventsLoaded {
+          kind,
+          name,
+          namespace,
+          events,
+          error,
+        } => {
+          if kind == "Deployment"
+            && let Some(ref current) = this.deployment
+            && current.name == *name
+            && current.namespace == *namespace
+          {
+            this.events.set(events, error.as_deref());
+            cx.notify();
+          }
+        }
         StateChanged::DeploymentTabRequest {
           deployment_name,
           namespace,
@@ -57,6 +89,8 @@ impl DeploymentDetail {
             this.deployment = Some(dep.clone());
             this.active_tab = *tab;
             this.yaml_content.clear();
+            this.describe.clear();
+            this.events = ResourceEvents::default();
             cx.notify();
           }
         }
@@ -80,6 +114,8 @@ impl DeploymentDetail {
       deployment: None,
       active_tab: DeploymentDetailTab::Info,
       yaml_content: String::new(),
+      describe: DescribeOutput::default(),
+      events: ResourceEvents::default(),
       yaml_editor: None,
       last_synced_yaml: String::new(),
     }
@@ -89,6 +125,8 @@ impl DeploymentDetail {
     self.deployment = Some(deployment.clone());
     self.active_tab = DeploymentDetailTab::Info;
     self.yaml_content.clear();
+    self.describe.clear();
+    self.events = ResourceEvents::default();
     self.yaml_editor = None;
     self.last_synced_yaml.clear();
 
@@ -220,8 +258,8 @@ impl DeploymentDetail {
         ),
     );
 
-    // Images section
-    if !deployment.images.is_empty() {
+    // Containers section
+    if !deployment.containers.is_empty() {
       content = content.child(
         v_flex()
           .w_full()
@@ -232,25 +270,58 @@ impl DeploymentDetail {
               .text_sm()
               .font_weight(gpui::FontWeight::SEMIBOLD)
               .text_color(colors.foreground)
-              .child("Images"),
+              .child("Containers"),
           )
-          .child(
-            div().w_full().p(px(12.)).rounded(px(8.)).bg(colors.sidebar).child(
-              v_flex().gap(px(4.)).children(
-                deployment
-                  .images
-                  .iter()
-                  .map(|img| {
+          .children(deployment.containers.iter().enumerate().map(|(i, container)| {
+            let name = deployment.name.clone();
+            let namespace = deployment.namespace.clone();
+            let container_name = container.name.clone();
+            h_flex()
+              .w_full()
+              .p(px(12.))
+              .gap(px(8.))
+              .items_center()
+              .rounded(px(8.))
+              .bg(colors.sidebar)
+              .child(
+                v_flex()
+                  .flex_1()
+                  .min_w_0()
+                  .gap(px(2.))
+                  .child(
+                    div()
+                      .text_sm()
+                      .font_weight(gpui::FontWeight::MEDIUM)
+                      .text_color(colors.foreground)
+                      .child(container.name.clone()),
+                  )
+                  .child(
                     div()
                       .text_xs()
                       .font_family("monospace")
                       .text_color(colors.foreground)
-                      .child(img.clone())
-                  })
-                  .collect::<Vec<_>>(),
-              ),
-            ),
-          ),
+                      .text_ellipsis()
+                      .overflow_hidden()
+                      .child(container.image.clone()),
+                  )
+                  .child(
+                    div()
+                      .text_xs()
+                      .text_color(colors.muted_foreground)
+                      .child(format!("{} env var(s)", container.env.len())),
+                  ),
+              )
+              .child(
+                Button::new(("edit-container", i))
+                  .icon(IconName::Settings)
+                  .label("Edit")
+                  .ghost()
+                  .xsmall()
+                  .on_click(move |_ev, _window, cx| {
+                    services::request_container_edit(name.clone(), namespace.clone(), container_name.clone(), cx);
+                  }),
+              )
+          })),
       );
     }
 
@@ -500,6 +571,38 @@ impl DeploymentDetail {
     )
   }
 
+  fn load_events(&mut self, cx: &mut Context<'_, Self>) {
+    if let Some(ref current) = self.deployment {
+      self.events.start_loading();
+      services::get_resource_events("Deployment", current.name.clone(), current.namespace.clone(), cx);
+    }
+  }
+
+  fn render_events_tab(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let entity = cx.entity();
+    render_event_list(
+      "deployment",
+      &self.events,
+      move |_window, cx| {
+        entity.update(cx, |this, cx| {
+          this.load_events(cx);
+          cx.notify();
+        });
+      },
+      cx,
+    )
+  }
+
+  fn render_describe_tab(&self, deployment: &DeploymentInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let name = deployment.name.clone();
+    let namespace = deployment.namespace.clone();
+    render_describe_tab(
+      &self.describe,
+      move |_window, cx| services::describe_deployment(name.clone(), namespace.clone(), cx),
+      cx,
+    )
+  }
+
   fn render_yaml_tab(&self, deployment: &DeploymentInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -540,10 +643,12 @@ impl DeploymentDetail {
             let name = name.clone();
             let namespace = namespace.clone();
             let editor = editor_for_apply.clone();
+            let original = self.yaml_content.clone();
             move |menu, _w, _cx| {
               let apply_name = name.clone();
               let apply_namespace = namespace.clone();
               let apply_editor = editor.clone();
+              let apply_original = original.clone();
               let restart_name = name.clone();
               let restart_namespace = namespace.clone();
               let rollback_name = name.clone();
@@ -554,12 +659,22 @@ impl DeploymentDetail {
                 .item(
                   PopupMenuItem::new("Apply YAML")
                     .icon(Icon::new(AppIcon::Refresh))
-                    .on_click(move |_, _, cx| {
+                    .on_click(move |_, window, cx| {
                       let Some(ref e) = apply_editor else { return };
                       let yaml: String = e.read(cx).text().to_string();
-                      if !yaml.trim().is_empty() {
-                        services::apply_deployment_yaml(apply_name.clone(), apply_namespace.clone(), yaml, cx);
+                      if yaml.trim().is_empty() {
+                        return;
                       }
+                      let name = apply_name.clone();
+                      let namespace = apply_namespace.clone();
+                      crate::ui::dialogs::confirm_yaml_apply(
+                        "Deployment",
+                        &apply_original,
+                        yaml,
+                        move |yaml, cx| services::apply_deployment_yaml(name.clone(), namespace.clone(), yaml, cx),
+                        window,
+                        cx,
+                      );
                     }),
                 )
                 .item(
@@ -669,6 +784,7 @@ impl Render for DeploymentDetail {
       });
       self.last_synced_yaml = self.yaml_content.clone();
     }
+    self.describe.sync_editor(window, cx);
 
     let Some(deployment) = self.deployment.clone() else {
       return div().size_full().child(Self::render_empty(cx));
@@ -698,6 +814,28 @@ impl Render for DeploymentDetail {
             cx.notify();
           })),
       )
+      .child(
+        Tab::new()
+          .label("Describe")
+          .selected(active_tab == DeploymentDetailTab::Describe)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = DeploymentDetailTab::Describe;
+            if let Some(ref current) = this.deployment {
+              services::describe_deployment(current.name.clone(), current.namespace.clone(), cx);
+            }
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("Events")
+          .selected(active_tab == DeploymentDetailTab::Events)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = DeploymentDetailTab::Events;
+            this.load_events(cx);
+            cx.notify();
+          })),
+      )
       .child(
         Tab::new()
           .label("YAML")
@@ -714,6 +852,8 @@ impl Render for DeploymentDetail {
     let content = match active_tab {
       DeploymentDetailTab::Info => Self::render_info_tab(&deployment, cx),
       DeploymentDetailTab::Pods => self.render_pods_tab(&deployment, cx),
+      DeploymentDetailTab::Describe => self.render_describe_tab(&deployment, cx),
+      DeploymentDetailTab::Events => self.render_events_tab(cx),
       DeploymentDetailTab::Yaml => self.render_yaml_tab(&deployment, cx),
     };
 
@@ -12,10 +12,10 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
-use crate::kubernetes::DeploymentInfo;
+use crate::kubernetes::{DeploymentHealth, DeploymentInfo};
 use crate::services;
 use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_k8s_error, render_loading};
+use crate::ui::components::{CachedListDelegate, refresh_rows, render_k8s_error, render_loading};
 
 /// Deployment list events emitted to parent
 pub enum DeploymentListEvent {
@@ -26,6 +26,7 @@ pub enum DeploymentListEvent {
 pub struct DeploymentListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<DeploymentInfo>,
 }
 
 impl DeploymentListDelegate {
@@ -43,30 +44,59 @@ impl DeploymentListDelegate {
     }
   }
 
-  fn filtered_deployments(&self, cx: &App) -> Vec<DeploymentInfo> {
+  fn collect_rows(&self, cx: &App) -> Vec<DeploymentInfo> {
     let deployments = self.deployments(cx);
     if self.search_query.is_empty() {
       return deployments;
     }
 
+    // Image names match too, so "nginx" finds every deployment running it
     let query = self.search_query.to_lowercase();
     deployments
       .iter()
-      .filter(|d| d.name.to_lowercase().contains(&query) || d.namespace.to_lowercase().contains(&query))
+      .filter(|d| {
+        d.name.to_lowercase().contains(&query)
+          || d.namespace.to_lowercase().contains(&query)
+          || d.images.iter().any(|image| image.to_lowercase().contains(&query))
+      })
       .cloned()
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered_deployments(&self) -> &[DeploymentInfo] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for DeploymentListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|deployment| matches!(global_selection, Selection::Deployment { name, namespace } if *name == deployment.name && *namespace == deployment.namespace))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for DeploymentListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered_deployments(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered_deployments().len()
   }
 
   fn render_item(
@@ -75,7 +105,7 @@ impl ListDelegate for DeploymentListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let deployments = self.filtered_deployments(cx);
+    let deployments = self.filtered_deployments();
     let deployment = deployments.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -96,15 +126,15 @@ impl ListDelegate for DeploymentListDelegate {
       "kube-system" | "kube-public" | "kube-node-lease"
     );
 
-    // Color based on ready status
-    let is_healthy = deployment.ready_replicas == deployment.replicas && deployment.replicas > 0;
-    let icon_bg = if is_healthy {
-      colors.success
-    } else if deployment.ready_replicas > 0 {
-      colors.warning
-    } else {
-      colors.danger
+    // Color based on health from the replica counts and conditions
+    let health = deployment.health(chrono::Utc::now());
+    let icon_bg = match health {
+      DeploymentHealth::Healthy => colors.success,
+      DeploymentHealth::ScaledDown => colors.muted_foreground,
+      DeploymentHealth::Progressing => colors.warning,
+      DeploymentHealth::Unavailable => colors.danger,
     };
+    let health_tooltip = deployment.health_explanation(&health);
 
     let ready_display = deployment.ready_display();
     let subtitle = format!("{} - {}", deployment.namespace, deployment.age);
@@ -229,6 +259,7 @@ impl ListDelegate for DeploymentListDelegate {
                     // Ready badge
                     .child(
                         div()
+                            .id(("health", row))
                             .flex_shrink_0()
                             .px(px(8.))
                             .py(px(2.))
@@ -237,6 +268,9 @@ impl ListDelegate for DeploymentListDelegate {
                             .text_xs()
                             .font_weight(gpui::FontWeight::MEDIUM)
                             .text_color(icon_bg)
+                            .tooltip(move |window, cx| {
+                                gpui_component::tooltip::Tooltip::new(health_tooltip.clone()).build(window, cx)
+                            })
                             .child(ready_display),
                     ),
       )
@@ -279,15 +313,17 @@ impl DeploymentList {
     let delegate = DeploymentListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
 
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     // Subscribe to list events
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered_deployments(cx);
+        let filtered = delegate.filtered_deployments();
         if let Some(deployment) = filtered.get(ix.row) {
           cx.emit(DeploymentListEvent::Selected(deployment.clone()));
         }
@@ -297,20 +333,22 @@ impl DeploymentList {
     .detach();
 
     // Subscribe to docker state changes to refresh list
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(
-        event,
-        StateChanged::DeploymentsUpdated
-          | StateChanged::NamespacesUpdated
-          | StateChanged::MachinesUpdated
-          | StateChanged::SelectionChanged
-      ) {
-        this.list_state.update(cx, |_state, cx| {
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(
+          event,
+          StateChanged::DeploymentsUpdated
+            | StateChanged::NamespacesUpdated
+            | StateChanged::MachinesUpdated
+            | StateChanged::SelectionChanged
+        ) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
           cx.notify();
-        });
-        cx.notify();
-      }
-    })
+        }
+      },
+    )
     .detach();
 
     Self {
@@ -335,7 +373,7 @@ impl DeploymentList {
       if current_text != self.search_query {
         current_text.clone_into(&mut self.search_query);
         self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+          state.delegate_mut().set_search_query(current_text, cx);
           cx.notify();
         });
       }
@@ -350,7 +388,7 @@ impl DeploymentList {
       self.search_query.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -536,7 +574,7 @@ impl Render for DeploymentList {
     let total_count = state.deployments.len();
     let deployments_state = state.deployments_state.clone();
 
-    let filtered_count = self.list_state.read(cx).delegate().filtered_deployments(cx).len();
+    let filtered_count = self.list_state.read(cx).delegate().filtered_deployments().len();
     let is_filtering = !self.search_query.is_empty();
     let deployments_empty = filtered_count == 0;
 
@@ -603,6 +641,13 @@ impl Render for DeploymentList {
                         crate::ui::dialogs::open_create_deployment_dialog(window, cx);
                       }),
                   )
+                  .item(
+                    PopupMenuItem::new("Apply YAML...")
+                      .icon(Icon::new(AppIcon::Edit))
+                      .on_click(|_, window, cx| {
+                        crate::ui::dialogs::open_apply_yaml_dialog(window, cx);
+                      }),
+                  )
                   .separator()
                   .item(
                     PopupMenuItem::new("Refresh")
@@ -1,3 +1,4 @@
+mod container_dialog;
 pub mod create_dialog;
 mod detail;
 mod list;
@@ -1,18 +1,17 @@
-use std::time::Duration;
-
-use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::{
   WindowExt,
   button::{Button, ButtonVariants},
   theme::ActiveTheme,
 };
 
+use super::container_dialog::ContainerDialog;
 use super::detail::DeploymentDetail;
 use super::list::{DeploymentList, DeploymentListEvent};
 use super::scale_dialog::ScaleDialog;
-use crate::kubernetes::DeploymentInfo;
-use crate::services;
-use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+use crate::kubernetes::{DeploymentContainer, DeploymentInfo};
+use crate::services::{self, RefreshInterval};
+use crate::state::{DockerState, Selection, StateChanged, docker_state};
 
 /// Main deployments view with list and detail panels
 pub struct DeploymentsView {
@@ -133,23 +132,29 @@ impl DeploymentsView {
         } => {
           Self::show_scale_dialog(deployment_name, namespace, *current_replicas, window, cx);
         }
+        StateChanged::DeploymentContainerEditRequest {
+          deployment_name,
+          namespace,
+          container,
+        } => {
+          let container = ds
+            .read(cx)
+            .get_deployment(deployment_name, namespace)
+            .and_then(|dep| dep.containers.iter().find(|c| c.name == *container).cloned());
+          if let Some(container) = container {
+            Self::show_container_dialog(deployment_name, namespace, container, window, cx);
+          }
+        }
         _ => {}
       }
     })
     .detach();
 
-    // Start periodic refresh
-    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
-    cx.spawn(async move |_this, cx| {
-      loop {
-        Timer::after(Duration::from_secs(refresh_interval)).await;
-        let _ = cx.update(|cx| {
-          services::refresh_machines(cx);
-          services::refresh_deployments(cx);
-        });
-      }
-    })
-    .detach();
+    // Periodic refresh
+    services::spawn_auto_refresh(RefreshInterval::Lists, 1, cx, |_, cx| {
+      services::refresh_machines(cx);
+      services::refresh_deployments(cx);
+    });
 
     // Trigger initial data load
     services::refresh_machines(cx);
@@ -202,6 +207,52 @@ impl DeploymentsView {
         })
     });
   }
+
+  fn show_container_dialog(
+    deployment_name: &str,
+    namespace: &str,
+    container: DeploymentContainer,
+    window: &mut Window,
+    cx: &mut Context<'_, Self>,
+  ) {
+    let dialog_entity =
+      cx.new(|cx| ContainerDialog::new(deployment_name.to_string(), namespace.to_string(), container, cx));
+
+    window.open_dialog(cx, move |dialog, _window, _cx| {
+      let dialog_clone = dialog_entity.clone();
+
+      dialog
+        .title("Edit Container")
+        .min_w(px(520.))
+        .child(dialog_entity.clone())
+        .footer(move |_dialog_state, _, _window, _cx| {
+          let dialog = dialog_clone.clone();
+
+          vec![
+            Button::new("save-container")
+              .label("Save and Roll Out")
+              .primary()
+              .on_click(move |_ev, window, cx| {
+                let d = dialog.read(cx);
+                let name = d.deployment_name().to_string();
+                let ns = d.namespace().to_string();
+                let container = d.container_name().to_string();
+                let image = d.changed_image(cx);
+                let original = d.original_env().to_vec();
+                let edited = d.env_vars().to_vec();
+                match image {
+                  Some(image) if original == edited => {
+                    services::update_deployment_image(&name, &ns, &container, &image, cx);
+                  }
+                  image => services::update_deployment_container(name, ns, container, image, &original, &edited, cx),
+                }
+                window.close_dialog(cx);
+              })
+              .into_any_element(),
+          ]
+        })
+    });
+  }
 }
 
 impl Render for DeploymentsView {
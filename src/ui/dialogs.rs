@@ -4,23 +4,30 @@
 //! and actions pre-configured. Call these functions from anywhere (views, command
 //! palette, menu bar) to open a fully functional dialog.
 
-use gpui::{App, AppContext, IntoElement, ParentElement, Styled, Window, div, px};
+use gpui::{
+  App, AppContext, Entity, InteractiveElement, IntoElement, ParentElement, SharedString, StatefulInteractiveElement,
+  Styled, Window, div, prelude::FluentBuilder, px,
+};
 use gpui_component::{
   WindowExt,
   button::{Button, ButtonVariants},
   h_flex,
+  input::{Input, InputState},
+  notification::NotificationType,
   theme::ActiveTheme,
   v_flex,
 };
 
 use crate::docker::LintReport;
 use crate::services;
+use crate::ui::command_log::CommandLogPanel;
 use crate::ui::components::{KvCreateDialog, KvResourceKind};
-use crate::ui::containers::CreateContainerDialog;
+use crate::ui::containers::{CreateContainerDialog, CreateContainerOptions};
 use crate::ui::deployments::create_dialog::CreateDeploymentDialog;
 use crate::ui::images::LintReportDialog;
 use crate::ui::images::build_dialog::BuildImageDialog;
 use crate::ui::images::pull_dialog::PullImageDialog;
+use crate::ui::images::pull_multiple_dialog::PullMultipleDialog;
 use crate::ui::images::push_dialog::PushImageDialog;
 use crate::ui::images::registry_dialog::RegistryBrowserDialog;
 use crate::ui::images::tag_dialog::TagImageDialog;
@@ -62,6 +69,42 @@ pub fn open_pull_image_dialog(window: &mut Window, cx: &mut App) {
   });
 }
 
+/// Opens the Pull Multiple dialog: a newline-separated image list pulled
+/// sequentially as one staged task.
+pub fn open_pull_multiple_dialog(window: &mut Window, cx: &mut App) {
+  let dialog_entity = cx.new(PullMultipleDialog::new);
+
+  window.open_dialog(cx, move |dialog, _window, _cx| {
+    let dialog_clone = dialog_entity.clone();
+
+    dialog
+      .title("Pull Multiple Images")
+      .min_w(px(520.))
+      .child(dialog_entity.clone())
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let dialog = dialog_clone.clone();
+        vec![
+          Button::new("pull-all")
+            .label("Pull All")
+            .primary()
+            .on_click(move |_ev, window, cx| {
+              let options = dialog.read(cx).get_options(cx);
+              if !options.images.is_empty() {
+                services::pull_images(
+                  options.images,
+                  options.platform.as_docker_arg().map(String::from),
+                  options.force,
+                  cx,
+                );
+                window.close_dialog(cx);
+              }
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
 /// Opens the Registry Browser dialog (Docker Hub search via the
 /// daemon's `/images/search`) with a Close button.
 pub fn open_registry_browser_dialog(window: &mut Window, cx: &mut App) {
@@ -104,6 +147,107 @@ pub fn prompt_save_image_tarball(image_ref: String, _window: &mut Window, cx: &m
   .detach();
 }
 
+/// Prompt for a destination then write the captured log buffer for
+/// `name` to it. A following stream is saved as captured so far.
+pub fn prompt_save_logs(name: String, chunks: Vec<std::sync::Arc<[u8]>>, _window: &mut Window, cx: &mut App) {
+  let suggested = services::default_log_filename(&name, chrono::Local::now());
+  let dir = dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("/"));
+  let rx = cx.prompt_for_new_path(&dir, Some(&suggested));
+  cx.spawn(async move |cx| {
+    if let Ok(Ok(Some(path))) = rx.await {
+      let _ = cx.update(|cx| {
+        services::save_logs(name, chunks, path, cx);
+      });
+    }
+  })
+  .detach();
+}
+
+/// Prompt for where to put a compose project's logs, then export them:
+/// a folder that gets a new `<project>-logs-<time>` directory with one file
+/// per service, or a single combined file.
+pub fn prompt_export_project_logs(
+  project: crate::docker::ComposeProject,
+  layout: services::ProjectLogLayout,
+  _window: &mut Window,
+  cx: &mut App,
+) {
+  let now = chrono::Local::now();
+  match layout {
+    services::ProjectLogLayout::PerService => {
+      let rx = cx.prompt_for_paths(gpui::PathPromptOptions {
+        files: false,
+        directories: true,
+        multiple: false,
+        prompt: Some("Export here".into()),
+      });
+      let folder = format!("{}-logs-{}", project.name, now.format("%Y%m%d-%H%M%S"));
+      cx.spawn(async move |cx| {
+        let Ok(Ok(Some(paths))) = rx.await else {
+          return;
+        };
+        if let Some(parent) = paths.into_iter().next() {
+          let _ = cx.update(|cx| {
+            services::export_project_logs(project, layout, parent.join(folder), cx);
+          });
+        }
+      })
+      .detach();
+    }
+    services::ProjectLogLayout::Combined => {
+      let suggested = services::default_log_filename(&project.name, now);
+      let dir = dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("/"));
+      let rx = cx.prompt_for_new_path(&dir, Some(&suggested));
+      cx.spawn(async move |cx| {
+        if let Ok(Ok(Some(path))) = rx.await {
+          let _ = cx.update(|cx| {
+            services::export_project_logs(project, layout, path, cx);
+          });
+        }
+      })
+      .detach();
+    }
+  }
+}
+
+/// Prompt for a destination then write a redacted diagnostic report
+/// (versions, setup checks, connection info, recent notifications) to it.
+pub fn prompt_save_diagnostic_report(_window: &mut Window, cx: &mut App) {
+  let suggested = format!(
+    "dockside-diagnostics-{}.md",
+    chrono::Local::now().format("%Y%m%d-%H%M%S")
+  );
+  let dir = dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("/"));
+  let rx = cx.prompt_for_new_path(&dir, Some(&suggested));
+  cx.spawn(async move |cx| {
+    if let Ok(Ok(Some(path))) = rx.await {
+      let _ = cx.update(|cx| {
+        services::save_diagnostic_report(path, cx);
+      });
+    }
+  })
+  .detach();
+}
+
+/// Prompt for a destination then export a redacted JSON snapshot of the
+/// app's state (name it `.yaml` for YAML).
+pub fn prompt_export_diagnostics(_window: &mut Window, cx: &mut App) {
+  let suggested = format!(
+    "dockside-diagnostics-{}.json",
+    chrono::Local::now().format("%Y%m%d-%H%M%S")
+  );
+  let dir = dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("/"));
+  let rx = cx.prompt_for_new_path(&dir, Some(&suggested));
+  cx.spawn(async move |cx| {
+    if let Ok(Ok(Some(path))) = rx.await {
+      let _ = cx.update(|cx| {
+        services::export_diagnostics(path, cx);
+      });
+    }
+  })
+  .detach();
+}
+
 /// Prompt the user for a tarball on disk and POST it to `/images/load`.
 pub fn prompt_load_image_tarball(_window: &mut Window, cx: &mut App) {
   let opts = gpui::PathPromptOptions {
@@ -193,6 +337,116 @@ pub fn open_build_image_dialog(window: &mut Window, cx: &mut App) {
   });
 }
 
+/// Opens the command log panel listing the CLI-equivalent of each
+/// operation the app has performed.
+pub fn open_command_log_dialog(window: &mut Window, cx: &mut App) {
+  let dialog_entity = cx.new(CommandLogPanel::new);
+
+  window.open_dialog(cx, move |dialog, _window, _cx| {
+    dialog
+      .title("Command Log")
+      .min_w(px(720.))
+      .child(dialog_entity.clone())
+      .footer(move |_dialog_state, _, _window, _cx| {
+        vec![
+          Button::new("close")
+            .label("Close")
+            .ghost()
+            .on_click(|_ev, window, cx| {
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
+/// Opens the Docker context switcher: one row per `docker context ls`
+/// entry with the current one marked. Picking a row switches and closes.
+pub fn open_docker_context_dialog(window: &mut Window, cx: &mut App) {
+  services::list_docker_contexts(cx);
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let colors = cx.theme().colors;
+    let contexts = crate::state::docker_state(cx).read(cx).docker_contexts.clone();
+    let body = if contexts.is_empty() {
+      v_flex().p(px(16.)).child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("No Docker contexts found. Is the docker CLI installed?"),
+      )
+    } else {
+      v_flex().py(px(8.)).children(contexts.into_iter().map(|ctx| {
+        let name = ctx.name.clone();
+        h_flex()
+          .id(SharedString::from(format!("docker-context-{}", ctx.name)))
+          .px(px(16.))
+          .py(px(8.))
+          .gap(px(12.))
+          .items_center()
+          .rounded(px(6.))
+          .cursor_pointer()
+          .when(ctx.current, |el| el.bg(colors.list_active))
+          .hover(|el| el.bg(colors.list_hover))
+          .on_click(move |_ev, window, cx| {
+            services::set_docker_context(name.clone(), cx);
+            window.close_dialog(cx);
+          })
+          .child(
+            div()
+              .w(px(12.))
+              .text_color(colors.primary)
+              .child(if ctx.current { "●" } else { "" }),
+          )
+          .child(
+            v_flex()
+              .flex_1()
+              .min_w_0()
+              .child(
+                div()
+                  .text_sm()
+                  .font_weight(gpui::FontWeight::MEDIUM)
+                  .text_color(colors.foreground)
+                  .child(ctx.name.clone()),
+              )
+              .child(
+                div()
+                  .text_xs()
+                  .font_family("monospace")
+                  .text_color(colors.muted_foreground)
+                  .child(ctx.endpoint.clone()),
+              ),
+          )
+          .when(!ctx.description.is_empty(), |el| {
+            el.child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(ctx.description.clone()),
+            )
+          })
+      }))
+    };
+
+    dialog
+      .title("Switch Docker Context")
+      .min_w(px(520.))
+      .child(body)
+      .footer(move |_dialog_state, _, _window, _cx| {
+        vec![
+          Button::new("close")
+            .label("Close")
+            .ghost()
+            .on_click(|_ev, window, cx| {
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
 /// Opens a non-modal viewer dialog for an existing `LintReport`. Caller
 /// supplies the source path (used as the dialog subtitle).
 pub fn open_lint_report_dialog(dockerfile: String, report: LintReport, window: &mut Window, cx: &mut App) {
@@ -357,6 +611,204 @@ pub fn open_build_output_dialog(
   });
 }
 
+/// Opens a generic confirmation for a destructive action. `on_confirm`
+/// only runs from the confirm button; Escape, Enter and Cancel all close
+/// the dialog without acting. The dialog takes focus while it is open and
+/// hands it back to the previously focused view on close.
+pub fn open_confirm_dialog(
+  title: impl Into<SharedString>,
+  body: impl Into<SharedString>,
+  warning: Option<SharedString>,
+  confirm_label: impl Into<SharedString>,
+  on_confirm: impl Fn(&mut Window, &mut App) + 'static,
+  window: &mut Window,
+  cx: &mut App,
+) {
+  let title = title.into();
+  let body = body.into();
+  let confirm_label = confirm_label.into();
+  let on_confirm = std::rc::Rc::new(on_confirm);
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let colors = cx.theme().colors;
+    let confirm_label = confirm_label.clone();
+    let on_confirm = on_confirm.clone();
+    dialog
+      .title(title.clone())
+      .min_w(px(420.))
+      .overlay_closable(false)
+      .child(
+        v_flex()
+          .gap(px(12.))
+          .child(div().text_sm().text_color(colors.foreground).child(body.clone()))
+          .when_some(warning.clone(), |el, warning| {
+            el.child(div().text_sm().text_color(colors.danger).child(warning))
+          }),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let on_confirm = on_confirm.clone();
+        vec![
+          Button::new("cancel-confirm")
+            .label("Cancel")
+            .ghost()
+            .on_click(|_ev, window, cx| {
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+          Button::new("confirm-confirm")
+            .label(confirm_label.clone())
+            .danger()
+            .on_click(move |_ev, window, cx| {
+              window.close_dialog(cx);
+              on_confirm(window, cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
+/// Confirms applying an edited resource YAML. Shows the changed fields
+/// against `original` and skips the apply entirely when nothing changed.
+pub fn confirm_yaml_apply(
+  kind: &'static str,
+  original: &str,
+  edited: String,
+  on_apply: impl Fn(String, &mut App) + 'static,
+  window: &mut Window,
+  cx: &mut App,
+) {
+  const MAX_LISTED: usize = 15;
+
+  let changes = services::yaml_changed_fields(original, &edited);
+  if edited.trim() == original.trim() || changes.as_ref().is_ok_and(Vec::is_empty) {
+    window.push_notification((NotificationType::Info, SharedString::from("No changes to apply.")), cx);
+    return;
+  }
+
+  let (listed, summary) = match changes {
+    Ok(changes) => {
+      let summary = format!(
+        "{} field{} changed:",
+        changes.len(),
+        if changes.len() == 1 { "" } else { "s" }
+      );
+      let mut listed: Vec<String> = changes.iter().take(MAX_LISTED).cloned().collect();
+      if changes.len() > MAX_LISTED {
+        listed.push(format!("...and {} more", changes.len() - MAX_LISTED));
+      }
+      (listed, summary)
+    }
+    // kubectl gives the authoritative parse error on apply.
+    Err(e) => (Vec::new(), format!("Could not compare fields: {e}")),
+  };
+  let on_apply = std::rc::Rc::new(on_apply);
+  let edited = SharedString::from(edited);
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let colors = cx.theme().colors;
+    let on_apply = on_apply.clone();
+    let edited = edited.clone();
+    dialog
+      .title(format!("Apply {kind} Changes"))
+      .min_w(px(460.))
+      .child(
+        v_flex()
+          .gap(px(8.))
+          .child(div().text_sm().text_color(colors.foreground).child(summary.clone()))
+          .child(
+            v_flex()
+              .gap(px(2.))
+              .font_family("monospace")
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .children(listed.iter().map(|line| div().child(line.clone()))),
+          ),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let on_apply = on_apply.clone();
+        let edited = edited.clone();
+        vec![
+          Button::new("cancel-apply")
+            .label("Cancel")
+            .ghost()
+            .on_click(|_ev, window, cx| {
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+          Button::new("confirm-apply")
+            .label("Apply")
+            .primary()
+            .on_click(move |_ev, window, cx| {
+              window.close_dialog(cx);
+              on_apply(edited.to_string(), cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
+/// Opens the destructive-action confirmation for `colima kubernetes reset`.
+/// The reset only runs once the profile name has been typed back.
+pub fn open_kubernetes_reset_dialog(name: String, window: &mut Window, cx: &mut App) {
+  use gpui_component::input::{Input, InputState};
+  let confirm_input = cx.new(|cx| InputState::new(window, cx).placeholder(name.clone()));
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let colors = cx.theme().colors;
+    let name = name.clone();
+    let input_clone = confirm_input.clone();
+    dialog
+      .title(format!("Reset Kubernetes on '{name}'"))
+      .min_w(px(480.))
+      .child(
+        v_flex()
+          .gap(px(12.))
+          .child(div().text_sm().text_color(colors.danger).child(
+            "This deletes the cluster and recreates it empty. Every workload is lost: deployments, pods, \
+             services, config maps, secrets, persistent volume claims and custom namespaces.",
+          ))
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child("Docker containers, images and volumes on the machine are not affected."),
+          )
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.foreground)
+              .child(format!("Type '{name}' to confirm.")),
+          )
+          .child(Input::new(&confirm_input).w_full()),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let name = name.clone();
+        let input = input_clone.clone();
+        vec![
+          Button::new("cancel-k8s-reset")
+            .label("Cancel")
+            .ghost()
+            .on_click(|_ev, window, cx| {
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+          Button::new("confirm-k8s-reset")
+            .label("Reset cluster")
+            .danger()
+            .on_click(move |_ev, window, cx| {
+              if input.read(cx).text().to_string().trim() == name {
+                services::kubernetes_reset(name.clone(), cx);
+                window.close_dialog(cx);
+              }
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
 /// Opens the Tag Image dialog with a Tag button configured.
 pub fn open_tag_image_dialog(source: String, window: &mut Window, cx: &mut App) {
   let dialog_entity = cx.new(TagImageDialog::new(source));
@@ -412,7 +864,7 @@ pub fn open_push_image_dialog(image: String, tag: String, window: &mut Window, c
               move |_ev, window, cx| {
                 let opts = dialog.read(cx).get_options(cx);
                 if !opts.image.is_empty() && !opts.tag.is_empty() {
-                  services::push_image(opts.image, opts.tag, opts.username, opts.password, cx);
+                  services::push_image(opts.image, opts.tag, opts.auth, cx);
                   window.close_dialog(cx);
                 }
               }
@@ -426,12 +878,27 @@ pub fn open_push_image_dialog(image: String, tag: String, window: &mut Window, c
 /// Opens the Create Container dialog with Create and Run buttons configured
 pub fn open_create_container_dialog(window: &mut Window, cx: &mut App) {
   let dialog_entity = cx.new(CreateContainerDialog::new);
+  show_create_container_dialog("Create Container", dialog_entity, window, cx);
+}
 
+/// Opens the Create Container dialog pre-filled from an existing
+/// container's settings
+pub fn open_duplicate_container_dialog(options: CreateContainerOptions, window: &mut Window, cx: &mut App) {
+  let dialog_entity = cx.new(|cx| CreateContainerDialog::with_options(options, cx));
+  show_create_container_dialog("Duplicate Container", dialog_entity, window, cx);
+}
+
+fn show_create_container_dialog(
+  title: &'static str,
+  dialog_entity: Entity<CreateContainerDialog>,
+  window: &mut Window,
+  cx: &mut App,
+) {
   window.open_dialog(cx, move |dialog, _window, _cx| {
     let dialog_clone = dialog_entity.clone();
 
     dialog
-      .title("Create Container")
+      .title(title)
       .min_w(px(600.))
       .child(dialog_entity.clone())
       .footer(move |_dialog_state, _, _window, _cx| {
@@ -444,6 +911,9 @@ pub fn open_create_container_dialog(window: &mut Window, cx: &mut App) {
             .on_click({
               let dialog = dialog_for_create.clone();
               move |_ev, window, cx| {
+                if !dialog.update(cx, CreateContainerDialog::validate) {
+                  return;
+                }
                 let options = dialog.read(cx).get_options(cx, false);
                 if !options.image.is_empty() {
                   services::create_container(options, cx);
@@ -458,6 +928,9 @@ pub fn open_create_container_dialog(window: &mut Window, cx: &mut App) {
             .on_click({
               let dialog = dialog_for_run.clone();
               move |_ev, window, cx| {
+                if !dialog.update(cx, CreateContainerDialog::validate) {
+                  return;
+                }
                 let options = dialog.read(cx).get_options(cx, true);
                 if !options.image.is_empty() {
                   services::create_container(options, cx);
@@ -695,6 +1168,54 @@ fn open_kv_create_dialog(kind: KvResourceKind, title: &'static str, window: &mut
   });
 }
 
+/// Opens an editor for applying arbitrary manifests (`kubectl apply -f -`).
+/// Several resources can be applied at once by separating them with `---`.
+pub fn open_apply_yaml_dialog(window: &mut Window, cx: &mut App) {
+  let editor = cx.new(|cx| {
+    InputState::new(window, cx)
+      .multi_line(true)
+      .code_editor("yaml")
+      .line_number(true)
+      .soft_wrap(false)
+      .placeholder("apiVersion: v1\nkind: ConfigMap\n...")
+  });
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let editor_for_apply = editor.clone();
+    dialog
+      .title("Apply YAML")
+      .min_w(px(720.))
+      .child(
+        v_flex()
+          .gap(px(8.))
+          .child(
+            div()
+              .text_xs()
+              .text_color(cx.theme().colors.muted_foreground)
+              .child("Separate multiple resources with ---. Errors from kubectl are shown as notifications."),
+          )
+          .child(div().h(px(420.)).child(Input::new(&editor).h_full())),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let editor = editor_for_apply.clone();
+        vec![
+          Button::new("apply-yaml")
+            .label("Apply")
+            .primary()
+            .on_click(move |_ev, window, cx| {
+              let yaml = editor.read(cx).text().to_string();
+              if yaml.trim().is_empty() {
+                return;
+              }
+              services::apply_manifest(&yaml, cx);
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
 /// Opens the About Dockside dialog
 pub fn open_about_dialog(window: &mut Window, cx: &mut App) {
   use gpui::{ImageSource, Resource, SharedString, img};
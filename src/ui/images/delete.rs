@@ -0,0 +1,85 @@
+//! Delete flow for images: confirm before removing an image containers
+//! still reference, and offer to force when the daemon refuses.
+
+use gpui::{App, Window};
+
+use crate::docker::{ContainerInfo, ImageInfo};
+use crate::services;
+use crate::state::docker_state;
+use crate::ui::dialogs::open_confirm_dialog;
+
+/// Names of all containers, running or stopped, created from `image_id`.
+pub fn image_users(image_id: &str, containers: &[ContainerInfo]) -> Vec<String> {
+  containers
+    .iter()
+    .filter(|c| c.image_id == image_id)
+    .map(|c| c.name.clone())
+    .collect()
+}
+
+/// Delete right away when no container uses the image, otherwise list the
+/// containers and force the removal only once confirmed.
+pub fn prompt_delete_image(image: &ImageInfo, window: &mut Window, cx: &mut App) {
+  let users = image_users(&image.id, &docker_state(cx).read(cx).containers);
+  if users.is_empty() {
+    services::delete_image(image.id.clone(), false, cx);
+    return;
+  }
+  let id = image.id.clone();
+  let name = image.display_name();
+  open_confirm_dialog(
+    format!("Delete '{name}'?"),
+    format!("Image '{name}' is used by container(s): {}.", users.join(", ")),
+    Some("Those containers will break: they can't be restarted from a fresh copy or recreated.".into()),
+    "Delete Anyway",
+    move |_window, cx| services::delete_image(id.clone(), true, cx),
+    window,
+    cx,
+  );
+}
+
+/// Ask to force a delete the daemon refused with `message`.
+pub fn confirm_force_delete(image_id: String, message: &str, window: &mut Window, cx: &mut App) {
+  let name = docker_state(cx)
+    .read(cx)
+    .images
+    .iter()
+    .find(|i| i.id == image_id)
+    .map_or_else(|| image_id.clone(), ImageInfo::display_name);
+  open_confirm_dialog(
+    format!("Force delete '{name}'?"),
+    message.to_string(),
+    None,
+    "Force Delete",
+    move |_window, cx| services::delete_image(image_id.clone(), true, cx),
+    window,
+    cx,
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::docker::ContainerState;
+
+  fn container(name: &str, image_id: &str, state: ContainerState) -> ContainerInfo {
+    ContainerInfo {
+      image_id: image_id.to_string(),
+      ..ContainerInfo::for_test(name, "nginx", state)
+    }
+  }
+
+  #[test]
+  fn test_image_users_includes_stopped_containers() {
+    let containers = vec![
+      container("web", "sha256:aaa", ContainerState::Running),
+      container("old-web", "sha256:aaa", ContainerState::Exited),
+      container("db", "sha256:bbb", ContainerState::Running),
+    ];
+    assert_eq!(
+      image_users("sha256:aaa", &containers),
+      vec!["web".to_string(), "old-web".to_string()]
+    );
+    assert!(image_users("sha256:ccc", &containers).is_empty());
+  }
+}
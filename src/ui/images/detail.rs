@@ -1,6 +1,6 @@
 use gpui::{App, Styled, Window, div, prelude::*, px};
 use gpui_component::{
-  Icon, IconName, Selectable,
+  Icon, IconName, Selectable, Sizable,
   button::{Button, ButtonVariants},
   h_flex,
   scroll::ScrollableElement,
@@ -11,17 +11,21 @@ use gpui_component::{
 use std::rc::Rc;
 
 use crate::assets::AppIcon;
-use crate::docker::ImageInfo;
+use crate::docker::{ImageHistoryEntry, ImageInfo, LARGE_LAYER_BYTES, SeverityGroup, split_image_ref};
 use crate::state::ImageInspectData;
-use crate::ui::components::{render_error_panel, render_install_hint};
+use crate::state::settings_state;
+use crate::ui::components::{CopyCallback, SecretValue, copy_button, render_error_panel, render_install_hint};
+use crate::ui::dialogs::{open_push_image_dialog, open_tag_image_dialog};
 
 type TabChangeCallback = Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>;
 
 pub struct ImageDetail {
   image: Option<ImageInfo>,
   inspect_data: Option<ImageInspectData>,
+  history: Option<Vec<ImageHistoryEntry>>,
   active_tab: usize,
   on_tab_change: Option<TabChangeCallback>,
+  on_copy: Option<CopyCallback>,
 }
 
 impl ImageDetail {
@@ -29,8 +33,10 @@ impl ImageDetail {
     Self {
       image: None,
       inspect_data: None,
+      history: None,
       active_tab: 0,
       on_tab_change: None,
+      on_copy: None,
     }
   }
 
@@ -44,6 +50,11 @@ impl ImageDetail {
     self
   }
 
+  pub fn history(mut self, history: Option<Vec<ImageHistoryEntry>>) -> Self {
+    self.history = history;
+    self
+  }
+
   pub fn active_tab(mut self, tab: usize) -> Self {
     self.active_tab = tab;
     self
@@ -57,6 +68,14 @@ impl ImageDetail {
     self
   }
 
+  pub fn on_copy<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_copy = Some(Rc::new(callback));
+    self
+  }
+
   fn render_empty(cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -86,20 +105,34 @@ impl ImageDetail {
   fn render_info_tab(&self, image: &ImageInfo, cx: &App) -> gpui::Div {
     let _colors = &cx.theme().colors;
 
-    // Basic info rows
+    // Basic info rows. ID and digest copy the full value.
     let mut basic_info = vec![
-      ("ID", image.short_id().to_string()),
-      ("Tag", image.display_name()),
-      ("Size", image.display_size()),
+      (
+        "ID",
+        image.short_id().to_string(),
+        self.copy_button("copy-image-id", image.id.clone()),
+      ),
+      ("Tag", image.display_name(), None),
+      ("Size", image.display_size(), None),
     ];
 
     if let Some(created) = image.created {
-      basic_info.insert(2, ("Created", created.format("%Y-%m-%d %H:%M:%S").to_string()));
+      basic_info.insert(2, ("Created", created.format("%Y-%m-%d %H:%M:%S").to_string(), None));
+    }
+
+    if let Some(repo_digest) = image.repo_digests.first() {
+      let digest = repo_digest.split_once('@').map_or(repo_digest.as_str(), |(_, d)| d);
+      let short = digest.strip_prefix("sha256:").unwrap_or(digest);
+      basic_info.push((
+        "Digest",
+        format!("sha256:{}", &short[..short.len().min(12)]),
+        self.copy_button("copy-image-digest", repo_digest.clone()),
+      ));
     }
 
     // Platform
     if let (Some(os), Some(arch)) = (&image.os, &image.architecture) {
-      basic_info.push(("Platform", format!("{os}/{arch}")));
+      basic_info.push(("Platform", format!("{os}/{arch}"), None));
     }
 
     let mut content = v_flex()
@@ -107,7 +140,7 @@ impl ImageDetail {
       .w_full()
       .p(px(16.))
       .gap(px(12.))
-      .child(Self::render_section(None, basic_info, cx));
+      .child(Self::render_copy_section(None, basic_info, cx));
 
     // Config section if we have inspect data
     if let Some(ref data) = self.inspect_data {
@@ -159,6 +192,11 @@ impl ImageDetail {
   }
 
   fn render_section(header: Option<&str>, rows: Vec<(&str, String)>, cx: &App) -> gpui::Div {
+    Self::render_copy_section(header, rows.into_iter().map(|(l, v)| (l, v, None)).collect(), cx)
+  }
+
+  /// Like `render_section`, but a row can carry a copy button.
+  fn render_copy_section(header: Option<&str>, rows: Vec<(&str, String, Option<Button>)>, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
     let mut section = v_flex().gap(px(1.));
@@ -182,13 +220,13 @@ impl ImageDetail {
         rows
           .into_iter()
           .enumerate()
-          .map(|(i, (label, value))| Self::render_section_row(label, value, i == 0, cx)),
+          .map(|(i, (label, value, copy))| Self::render_section_row(label, value, copy, i == 0, cx)),
       );
 
     section.child(rows_container)
   }
 
-  fn render_section_row(label: &str, value: String, is_first: bool, cx: &App) -> gpui::Div {
+  fn render_section_row(label: &str, value: String, copy: Option<Button>, is_first: bool, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
     let mut row = h_flex()
@@ -204,13 +242,19 @@ impl ImageDetail {
           .child(label.to_string()),
       )
       .child(
-        div()
-          .text_sm()
-          .text_color(colors.foreground)
-          .max_w(px(250.))
-          .overflow_hidden()
-          .text_ellipsis()
-          .child(value),
+        h_flex()
+          .gap(px(4.))
+          .items_center()
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.foreground)
+              .max_w(px(250.))
+              .overflow_hidden()
+              .text_ellipsis()
+              .child(value),
+          )
+          .children(copy),
       );
 
     if !is_first {
@@ -220,8 +264,14 @@ impl ImageDetail {
     row
   }
 
+  /// Copy button for `text` when the parent wired `on_copy`.
+  fn copy_button(&self, id: &'static str, text: String) -> Option<Button> {
+    self.on_copy.clone().map(|cb| copy_button(id, text, cb))
+  }
+
   fn render_env_section(env: &[(String, String)], cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
+    let settings = &settings_state(cx).read(cx).settings;
 
     v_flex()
       .gap(px(1.))
@@ -284,7 +334,11 @@ impl ImageDetail {
                                     .text_color(colors.secondary_foreground)
                                     .overflow_hidden()
                                     .text_ellipsis()
-                                    .child(value.clone()),
+                                    .child(SecretValue::new(
+                                        ("image-env", i),
+                                        value.clone(),
+                                        settings.is_secret_key(key),
+                                    )),
                             );
 
                         if i > 0 {
@@ -411,11 +465,14 @@ impl ImageDetail {
 
   fn render_layers_tab(&self, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
-    let history = self
-      .inspect_data
-      .as_ref()
-      .map(|d| d.history.clone())
-      .unwrap_or_default();
+    let Some(history) = self.history.clone() else {
+      return v_flex().w_full().p(px(16.)).child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Loading layer history..."),
+      );
+    };
 
     if history.is_empty() {
       return v_flex().w_full().p(px(16.)).child(
@@ -426,6 +483,25 @@ impl ImageDetail {
       );
     }
 
+    let total: i64 = history.iter().map(|entry| entry.size).sum();
+    let large_count = history.iter().filter(|entry| entry.is_large()).count();
+    let summary = h_flex()
+      .w_full()
+      .px(px(12.))
+      .py(px(8.))
+      .gap(px(12.))
+      .child(div().text_xs().text_color(colors.muted_foreground).child(format!(
+        "{} layers, {} total",
+        history.len(),
+        bytesize::ByteSize(u64::try_from(total).unwrap_or(0))
+      )))
+      .when(large_count > 0, |el| {
+        el.child(div().text_xs().text_color(colors.warning).child(format!(
+          "{large_count} over {}",
+          bytesize::ByteSize(LARGE_LAYER_BYTES.unsigned_abs())
+        )))
+      });
+
     let header = h_flex()
       .w_full()
       .px(px(12.))
@@ -461,7 +537,10 @@ impl ImageDetail {
         .created
         .map(|c| c.format("%Y-%m-%d %H:%M").to_string())
         .unwrap_or_default();
-      let zebra = if i % 2 == 0 {
+      let large = entry.is_large();
+      let bg = if large {
+        colors.warning.opacity(0.12)
+      } else if i % 2 == 0 {
         colors.background
       } else {
         colors.muted.opacity(0.4)
@@ -471,12 +550,13 @@ impl ImageDetail {
         .px(px(12.))
         .py(px(6.))
         .gap(px(8.))
-        .bg(zebra)
+        .bg(bg)
         .child(
           div()
             .w(px(70.))
             .text_xs()
-            .text_color(colors.foreground)
+            .when(large, |el| el.font_weight(gpui::FontWeight::SEMIBOLD))
+            .text_color(if large { colors.warning } else { colors.foreground })
             .child(entry.display_size()),
         )
         .child(
@@ -496,7 +576,7 @@ impl ImageDetail {
         )
     });
 
-    v_flex().w_full().child(header).children(rows)
+    v_flex().w_full().child(summary).child(header).children(rows)
   }
 
   fn render_vulns_tab(&self, cx: &App) -> gpui::Div {
@@ -516,15 +596,26 @@ impl ImageDetail {
             .text_color(colors.muted_foreground),
         )
         .child(
-          div()
-            .text_sm()
-            .text_color(colors.muted_foreground)
-            .child("No vulnerability scan yet for this image."),
+          v_flex()
+            .items_center()
+            .gap(px(4.))
+            .child(
+              div()
+                .text_sm()
+                .text_color(colors.muted_foreground)
+                .child("No vulnerability scan yet for this image."),
+            )
+            .child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child("Uses Docker Scout, or Trivy when Scout isn't installed."),
+            ),
         )
         .child(
           Button::new("vulns-empty-scan")
             .icon(Icon::new(IconName::Eye))
-            .label("Scan with Trivy")
+            .label("Scan image")
             .primary()
             .on_click(move |_ev, _window, cx| {
               if let Some(ref img) = image {
@@ -541,16 +632,35 @@ impl ImageDetail {
         div()
           .text_sm()
           .text_color(colors.muted_foreground)
-          .child("Running Trivy..."),
+          .child("Scanning for vulnerabilities..."),
       );
     }
     if let Some(err) = d.scan_error.as_ref() {
-      // Specially render the missing-binary case using an install-hint
-      // panel modeled after the Setup dialog: headline + per-platform
-      // copy-able command list + docs link. Anything else falls back to
-      // the raw error message, also dressed up.
-      if err == crate::docker::ERR_TRIVY_NOT_INSTALLED {
-        return render_install_hint(&crate::docker::trivy_install_hint(), cx);
+      // Specially render the missing-scanner case using install-hint
+      // panels modeled after the Setup dialog: headline + per-platform
+      // copy-able command list + docs link, one per supported scanner.
+      // Anything else falls back to the raw error message, also dressed up.
+      if err == crate::docker::ERR_SCANNER_NOT_INSTALLED {
+        return v_flex()
+          .w_full()
+          .child(
+            div()
+              .px(px(24.))
+              .pt(px(24.))
+              .text_sm()
+              .text_color(colors.muted_foreground)
+              .child("No vulnerability scanner found. Install Docker Scout or Trivy, then scan again."),
+          )
+          .child(
+            div()
+              .id("scout-install-hint")
+              .child(render_install_hint(&crate::docker::scout_install_hint(), cx)),
+          )
+          .child(
+            div()
+              .id("trivy-install-hint")
+              .child(render_install_hint(&crate::docker::trivy_install_hint(), cx)),
+          );
       }
       return render_error_panel("Scan failed", err, colors);
     }
@@ -560,13 +670,22 @@ impl ImageDetail {
       .gap(px(12.))
       .px(px(16.))
       .py(px(12.))
+      .items_center()
       .border_b_1()
       .border_color(colors.border)
       .child(severity_badge("CRITICAL", summary.critical, colors.danger, cx))
       .child(severity_badge("HIGH", summary.high, colors.warning, cx))
       .child(severity_badge("MEDIUM", summary.medium, colors.muted_foreground, cx))
       .child(severity_badge("LOW", summary.low, colors.muted_foreground, cx))
-      .child(severity_badge("UNKNOWN", summary.unknown, colors.muted_foreground, cx));
+      .child(severity_badge("UNKNOWN", summary.unknown, colors.muted_foreground, cx))
+      .when(!summary.scanner.is_empty(), |el| {
+        el.child(div().flex_1()).child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child(format!("Scanned with {}", summary.scanner)),
+        )
+      });
 
     let header = h_flex()
       .w_full()
@@ -578,18 +697,11 @@ impl ImageDetail {
       .bg(colors.muted)
       .child(
         div()
-          .w(px(110.))
+          .w(px(140.))
           .text_xs()
           .text_color(colors.muted_foreground)
           .child("CVE"),
       )
-      .child(
-        div()
-          .w(px(80.))
-          .text_xs()
-          .text_color(colors.muted_foreground)
-          .child("SEVERITY"),
-      )
       .child(
         div()
           .w(px(160.))
@@ -619,76 +731,106 @@ impl ImageDetail {
           .child("TITLE"),
       );
 
-    let rows = summary.vulns.iter().enumerate().take(500).map(|(i, v)| {
-      let zebra = if i % 2 == 0 {
-        colors.background
-      } else {
-        colors.muted.opacity(0.4)
-      };
-      let sev_color = match v.severity.as_str() {
+    // Cap the total number of rendered rows across all groups.
+    let mut budget = 500usize;
+    let groups = summary.by_severity().into_iter().map(|group| {
+      let SeverityGroup {
+        severity,
+        vulns,
+        packages,
+      } = group;
+      let sev_color = match severity {
         "CRITICAL" => colors.danger,
         "HIGH" => colors.warning,
         _ => colors.muted_foreground,
       };
-      h_flex()
+      let shown = vulns.len().min(budget);
+      budget -= shown;
+      let rows = vulns.iter().take(shown).enumerate().map(|(i, v)| {
+        let zebra = if i % 2 == 0 {
+          colors.background
+        } else {
+          colors.muted.opacity(0.4)
+        };
+        h_flex()
+          .w_full()
+          .px(px(12.))
+          .py(px(6.))
+          .gap(px(8.))
+          .bg(zebra)
+          .child(
+            div()
+              .w(px(140.))
+              .text_xs()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .child(v.id.clone()),
+          )
+          .child(
+            div()
+              .w(px(160.))
+              .text_xs()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .child(v.package.clone()),
+          )
+          .child(
+            div()
+              .w(px(140.))
+              .text_xs()
+              .font_family("monospace")
+              .text_color(colors.muted_foreground)
+              .child(v.installed_version.clone()),
+          )
+          .child(
+            div()
+              .w(px(140.))
+              .text_xs()
+              .font_family("monospace")
+              .text_color(if v.fixed_version.is_some() {
+                colors.success
+              } else {
+                colors.muted_foreground
+              })
+              .child(v.fixed_version.clone().unwrap_or_else(|| "—".to_string())),
+          )
+          .child(
+            div()
+              .flex_1()
+              .text_xs()
+              .text_color(colors.foreground)
+              .child(v.title.clone()),
+          )
+      });
+
+      v_flex()
         .w_full()
-        .px(px(12.))
-        .py(px(6.))
-        .gap(px(8.))
-        .bg(zebra)
-        .child(
-          div()
-            .w(px(110.))
-            .text_xs()
-            .font_family("monospace")
-            .text_color(colors.foreground)
-            .child(v.id.clone()),
-        )
-        .child(
-          div()
-            .w(px(80.))
-            .text_xs()
-            .text_color(sev_color)
-            .child(v.severity.clone()),
-        )
-        .child(
-          div()
-            .w(px(160.))
-            .text_xs()
-            .font_family("monospace")
-            .text_color(colors.foreground)
-            .child(v.package.clone()),
-        )
-        .child(
-          div()
-            .w(px(140.))
-            .text_xs()
-            .font_family("monospace")
-            .text_color(colors.muted_foreground)
-            .child(v.installed_version.clone()),
-        )
-        .child(
-          div()
-            .w(px(140.))
-            .text_xs()
-            .font_family("monospace")
-            .text_color(if v.fixed_version.is_some() {
-              colors.success
-            } else {
-              colors.muted_foreground
-            })
-            .child(v.fixed_version.clone().unwrap_or_else(|| "—".to_string())),
-        )
         .child(
-          div()
-            .flex_1()
-            .text_xs()
-            .text_color(colors.foreground)
-            .child(v.title.clone()),
+          v_flex()
+            .w_full()
+            .px(px(12.))
+            .py(px(8.))
+            .gap(px(2.))
+            .border_b_1()
+            .border_color(colors.border)
+            .child(
+              div()
+                .text_xs()
+                .font_weight(gpui::FontWeight::SEMIBOLD)
+                .text_color(sev_color)
+                .child(format!("{severity} · {}", vulns.len())),
+            )
+            .child(
+              div()
+                .text_xs()
+                .text_color(colors.muted_foreground)
+                .child(format!("Affected packages: {}", packages.join(", "))),
+            ),
         )
+        .children(rows)
     });
 
-    v_flex().w_full().child(counts).child(header).children(rows)
+    v_flex().w_full().child(counts).child(header).children(groups)
   }
 
   pub fn render(self, _window: &mut Window, cx: &App) -> gpui::AnyElement {
@@ -701,12 +843,17 @@ impl ImageDetail {
     let on_tab_change = self.on_tab_change.clone();
 
     let tabs = ["Info", "Layers", "Vulnerabilities"];
+    let image_name = image.display_name();
+    // Untagged images can be tagged but have nothing to push yet
+    let pushable = !image.repo_tags.is_empty();
 
     // Toolbar with tabs and actions
     let toolbar = h_flex()
       .w_full()
       .items_center()
       .flex_shrink_0()
+      .pr(px(8.))
+      .gap(px(4.))
       .child(
         TabBar::new("image-tabs")
           .flex_1()
@@ -721,7 +868,31 @@ impl ImageDetail {
                 }
               })
           })),
-      );
+      )
+      .child(
+        Button::new("image-tag")
+          .label("Tag")
+          .icon(Icon::new(AppIcon::Edit))
+          .ghost()
+          .xsmall()
+          .on_click({
+            let image_name = image_name.clone();
+            move |_, window, cx| open_tag_image_dialog(image_name.clone(), window, cx)
+          }),
+      )
+      .when(pushable, |el| {
+        el.child(
+          Button::new("image-push")
+            .label("Push")
+            .icon(IconName::ArrowUp)
+            .ghost()
+            .xsmall()
+            .on_click(move |_, window, cx| {
+              let (repo, tag) = split_image_ref(&image_name);
+              open_push_image_dialog(repo.to_string(), tag.to_string(), window, cx);
+            }),
+        )
+      });
 
     // Content based on active tab
     let content = match self.active_tab {
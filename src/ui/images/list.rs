@@ -1,7 +1,8 @@
-use gpui::{App, Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, MouseButton, Render, SharedString, Styled, Window, div, prelude::*, px};
 use gpui_component::{
   Icon, IconName, IndexPath, Sizable,
   button::{Button, ButtonVariants},
+  checkbox::Checkbox,
   h_flex,
   input::{Input, InputState},
   label::Label,
@@ -12,10 +13,13 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
-use crate::docker::ImageInfo;
+use crate::docker::{ImageInfo, split_image_ref};
 use crate::services;
-use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_error, render_loading};
+use crate::state::{CurrentView, DockerState, LoadState, Selection, StateChanged, docker_state};
+use crate::ui::components::{
+  CachedListDelegate, ColumnSort, RefreshStatus, SearchDebounce, labels_match, refresh_rows, render_bulk_delete_bar,
+  render_docker_context_selector, render_error, render_loading, render_sort_header,
+};
 use crate::ui::dialogs::{open_push_image_dialog, open_tag_image_dialog, prompt_save_image_tarball};
 
 /// Image list events emitted to parent
@@ -23,12 +27,68 @@ pub enum ImageListEvent {
   Selected(Box<ImageInfo>),
 }
 
+/// Columns of the image list's sort header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageSortKey {
+  Repo,
+  Tag,
+  Size,
+  Created,
+}
+
+impl ImageSortKey {
+  const COLUMNS: [(ImageSortKey, &'static str); 4] = [
+    (ImageSortKey::Repo, "Repo"),
+    (ImageSortKey::Tag, "Tag"),
+    (ImageSortKey::Size, "Size"),
+    (ImageSortKey::Created, "Created"),
+  ];
+}
+
+/// Sort within each section; `None` keeps the daemon's order.
+fn sort_images(images: &mut [ImageInfo], sort: Option<ColumnSort<ImageSortKey>>) {
+  let Some(sort) = sort else { return };
+  images.sort_by(|a, b| {
+    let name_a = a.display_name();
+    let name_b = b.display_name();
+    let (repo_a, tag_a) = split_image_ref(&name_a);
+    let (repo_b, tag_b) = split_image_ref(&name_b);
+    let ordering = match sort.key {
+      ImageSortKey::Repo => repo_a.cmp(repo_b).then_with(|| tag_a.cmp(tag_b)),
+      ImageSortKey::Tag => tag_a.cmp(tag_b).then_with(|| repo_a.cmp(repo_b)),
+      ImageSortKey::Size => a.size.cmp(&b.size),
+      ImageSortKey::Created => a.created.cmp(&b.created),
+    };
+    sort.apply(ordering)
+  });
+}
+
+/// Group an image is listed under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImageSection {
+  InUse,
+  Unused,
+  /// Untagged and unused, safe to clean up
+  Dangling,
+}
+
+impl ImageSection {
+  fn title(self) -> &'static str {
+    match self {
+      Self::InUse => "In Use",
+      Self::Unused => "Unused",
+      Self::Dangling => "Dangling",
+    }
+  }
+}
+
 /// Delegate for the image list
 pub struct ImageListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
-  /// Cached list: (`section_index`, `is_in_use`, images)
-  sections: Vec<(bool, Vec<ImageInfo>)>,
+  sort: Option<ColumnSort<ImageSortKey>>,
+  /// Cached list: (section, images)
+  sections: Vec<(ImageSection, Vec<ImageInfo>)>,
 }
 
 impl ImageListDelegate {
@@ -41,6 +101,7 @@ impl ImageListDelegate {
 
     let mut in_use = Vec::new();
     let mut unused = Vec::new();
+    let mut dangling = Vec::new();
 
     // Filter images based on search query
     let query = self.search_query.to_lowercase();
@@ -54,6 +115,7 @@ impl ImageListDelegate {
           img.display_name().to_lowercase().contains(&query)
             || img.id.to_lowercase().contains(&query)
             || img.repo_tags.iter().any(|t| t.to_lowercase().contains(&query))
+            || labels_match(&img.labels, &query)
         })
         .collect()
     };
@@ -61,18 +123,25 @@ impl ImageListDelegate {
     for image in images {
       if in_use_ids.contains(&image.id) {
         in_use.push(image.clone());
+      } else if image.is_dangling() {
+        dangling.push(image.clone());
       } else {
         unused.push(image.clone());
       }
     }
 
-    self.sections.clear();
-    if !in_use.is_empty() {
-      self.sections.push((true, in_use));
-    }
-    if !unused.is_empty() {
-      self.sections.push((false, unused));
-    }
+    sort_images(&mut in_use, self.sort);
+    sort_images(&mut unused, self.sort);
+    sort_images(&mut dangling, self.sort);
+
+    self.sections = [
+      (ImageSection::InUse, in_use),
+      (ImageSection::Unused, unused),
+      (ImageSection::Dangling, dangling),
+    ]
+    .into_iter()
+    .filter(|(_, images)| !images.is_empty())
+    .collect();
   }
 
   fn get_image(&self, ix: IndexPath) -> Option<&ImageInfo> {
@@ -89,6 +158,37 @@ impl ImageListDelegate {
   }
 }
 
+impl CachedListDelegate for ImageListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rebuild_sections(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.total_filtered_count()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self.sections.iter().enumerate().find_map(|(section, (_, rows))| {
+      rows
+        .iter()
+        .position(|image| matches!(global_selection, Selection::Image(img) if img.id == image.id))
+        .map(|row| IndexPath::new(row).section(section))
+    })
+  }
+
+  /// Section headers are counted as one row each.
+  fn flat_row(&self, ix: IndexPath) -> usize {
+    let above: usize = self
+      .sections
+      .iter()
+      .take(ix.section)
+      .map(|(_, rows)| rows.len() + 1)
+      .sum();
+    above + ix.row + 1
+  }
+}
+
 impl ListDelegate for ImageListDelegate {
   type Item = ListItem;
 
@@ -107,9 +207,8 @@ impl ListDelegate for ImageListDelegate {
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<impl IntoElement> {
     let colors = &cx.theme().colors;
-    let (is_in_use, _) = self.sections.get(section)?;
-
-    let title = if *is_in_use { "In Use" } else { "Unused" };
+    let (kind, _) = self.sections.get(section)?;
+    let title = kind.title();
 
     Some(
       div()
@@ -136,9 +235,15 @@ impl ListDelegate for ImageListDelegate {
     let global_selection = &self.docker_state.read(cx).selection;
     let is_selected = matches!(global_selection, Selection::Image(img) if img.id == image.id);
     let image_id = image.id.clone();
+    let used_by = super::image_users(&image.id, &self.docker_state.read(cx).containers);
+    let is_dangling = image.is_dangling();
 
     // Display name (repo:tag or short id)
-    let display_name = image.display_name();
+    let display_name = if is_dangling {
+      format!("<none> {}", image.short_id())
+    } else {
+      image.display_name()
+    };
     let size_text = image.display_size();
 
     // Age display
@@ -175,6 +280,7 @@ impl ListDelegate for ImageListDelegate {
     let id_for_menu = image_id.clone();
     let display = image.display_name();
     let image_ref = image.repo_tags.first().cloned().unwrap_or_else(|| image.id.clone());
+    let image_for_menu = image.clone();
 
     let menu_button = Button::new(SharedString::from(format!("img-menu-{section}-{row}")))
       .icon(IconName::Ellipsis)
@@ -186,7 +292,7 @@ impl ListDelegate for ImageListDelegate {
         let ref_save = image_ref.clone();
         let display_tag = display.clone();
         let display_push = display.clone();
-        let id_delete = id_for_menu.clone();
+        let image_delete = image_for_menu.clone();
         menu
           .item(
             PopupMenuItem::new("Scan")
@@ -213,23 +319,26 @@ impl ListDelegate for ImageListDelegate {
             PopupMenuItem::new("Push")
               .icon(IconName::ArrowUp)
               .on_click(move |_, window, cx| {
-                let (image, tag) = display_push.rsplit_once(':').map_or_else(
-                  || (display_push.clone(), "latest".to_string()),
-                  |(i, t)| (i.to_string(), t.to_string()),
-                );
-                open_push_image_dialog(image, tag, window, cx);
+                let (image, tag) = split_image_ref(&display_push);
+                open_push_image_dialog(image.to_string(), tag.to_string(), window, cx);
               }),
           )
           .separator()
           .item(
             PopupMenuItem::new("Delete")
               .icon(Icon::new(AppIcon::Trash))
-              .on_click(move |_, _, cx| {
-                services::delete_image(id_delete.clone(), cx);
+              .on_click(move |_, window, cx| {
+                super::prompt_delete_image(&image_delete, window, cx);
               }),
           )
       });
 
+    let checked = self.docker_state.read(cx).selected_image_ids.contains(&image.id);
+    let cb_id = image_id.clone();
+    let checkbox = Checkbox::new(SharedString::from(format!("img-cb-{section}-{row}")))
+      .checked(checked)
+      .on_click(move |_, _, cx| services::toggle_image_bulk_selection(&cb_id, cx));
+
     let item_content = h_flex()
       .w_full()
       .items_center()
@@ -241,12 +350,17 @@ impl ListDelegate for ImageListDelegate {
           .min_w_0()
           .items_center()
           .gap(px(10.))
+          .child(div().flex_shrink_0().child(checkbox))
           .child(
             div()
               .size(px(36.))
               .flex_shrink_0()
               .rounded(px(8.))
-              .bg(colors.primary)
+              .bg(if is_dangling {
+                colors.muted_foreground
+              } else {
+                colors.primary
+              })
               .flex()
               .items_center()
               .justify_center()
@@ -275,6 +389,35 @@ impl ListDelegate for ImageListDelegate {
                   .child(age_text),
               ),
           )
+          .when(is_dangling, |el| {
+            el.child(
+              div()
+                .flex_shrink_0()
+                .px(px(6.))
+                .py(px(2.))
+                .rounded(px(4.))
+                .bg(colors.warning.opacity(0.15))
+                .text_xs()
+                .text_color(colors.warning)
+                .child("dangling"),
+            )
+          })
+          .when(!used_by.is_empty(), |el| {
+            let tooltip = format!("Used by {}", used_by.join(", "));
+            el.child(
+              div()
+                .id(SharedString::from(format!("img-used-by-{section}-{row}")))
+                .flex_shrink_0()
+                .px(px(6.))
+                .py(px(2.))
+                .rounded(px(4.))
+                .bg(colors.primary.opacity(0.15))
+                .text_xs()
+                .text_color(colors.primary)
+                .tooltip(move |window, cx| gpui_component::tooltip::Tooltip::new(tooltip.clone()).build(window, cx))
+                .child(format!("{} container(s)", used_by.len())),
+            )
+          })
           .when_some(platform, |el, plat| {
             el.child(
               div()
@@ -291,6 +434,16 @@ impl ListDelegate for ImageListDelegate {
       )
       .child(div().flex_shrink_0().child(menu_button));
 
+    // Cmd/Ctrl-click ticks the row for bulk delete without moving the
+    // single selection.
+    let click_id = image_id.clone();
+    let item_content = item_content.on_mouse_down(MouseButton::Left, move |event, _window, cx| {
+      if event.modifiers.platform || event.modifiers.control {
+        services::toggle_image_bulk_selection(&click_id, cx);
+        cx.stop_propagation();
+      }
+    });
+
     let item = ListItem::new(ix)
       .py(px(6.))
       .rounded(px(6.))
@@ -314,10 +467,11 @@ impl ListDelegate for ImageListDelegate {
 /// Self-contained image list component
 pub struct ImageList {
   docker_state: Entity<DockerState>,
+  refresh_status: Entity<RefreshStatus>,
   list_state: Entity<ListState<ImageListDelegate>>,
   search_input: Option<Entity<InputState>>,
   search_visible: bool,
-  search_query: String,
+  search: SearchDebounce,
 }
 
 impl ImageList {
@@ -327,6 +481,7 @@ impl ImageList {
     let mut delegate = ImageListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      sort: None,
       sections: Vec::new(),
     };
 
@@ -348,47 +503,63 @@ impl ImageList {
     .detach();
 
     // Subscribe to docker state changes to refresh list
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(
-        event,
-        StateChanged::ImagesUpdated | StateChanged::ContainersUpdated | StateChanged::SelectionChanged
-      ) {
-        this.list_state.update(cx, |state, cx| {
-          state.delegate_mut().rebuild_sections(cx);
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(
+          event,
+          StateChanged::ImagesUpdated | StateChanged::ContainersUpdated | StateChanged::SelectionChanged
+        ) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
           cx.notify();
-        });
-        cx.notify();
-      }
-    })
+        } else if matches!(event, StateChanged::DockerContextsUpdated) {
+          cx.notify();
+        }
+      },
+    )
     .detach();
 
     Self {
       docker_state,
+      refresh_status: cx.new(|cx| RefreshStatus::new(CurrentView::Images, cx)),
       list_state,
       search_input: None,
       search_visible: false,
-      search_query: String::new(),
+      search: SearchDebounce::default(),
     }
   }
 
   fn ensure_search_input(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
     if self.search_input.is_none() {
-      let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Search images..."));
+      let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Filter by tag, ID or label..."));
       self.search_input = Some(input_state);
     }
   }
 
   fn sync_search_query(&mut self, cx: &mut Context<'_, Self>) {
-    if let Some(input) = &self.search_input {
-      let current_text = input.read(cx).text().to_string();
-      if current_text != self.search_query {
-        current_text.clone_into(&mut self.search_query);
-        self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text, cx);
+    let Some(input) = &self.search_input else { return };
+    let current_text = input.read(cx).text().to_string();
+    self.search.sync(
+      current_text,
+      |this: &mut Self| &mut this.search,
+      cx,
+      |this, query, cx| {
+        this.list_state.update(cx, |state, cx| {
+          state.delegate_mut().set_search_query(query, cx);
           cx.notify();
         });
-      }
-    }
+      },
+    );
+  }
+
+  fn on_sort_column(&mut self, key: ImageSortKey, window: &mut Window, cx: &mut Context<'_, Self>) {
+    self.list_state.update(cx, |state, cx| {
+      let delegate = state.delegate_mut();
+      delegate.sort = ColumnSort::cycle(delegate.sort, key);
+      refresh_rows(state, window, cx);
+    });
+    cx.notify();
   }
 
   fn toggle_search(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
@@ -396,7 +567,7 @@ impl ImageList {
     if self.search_visible {
       self.ensure_search_input(window, cx);
     } else {
-      self.search_query.clear();
+      self.search.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
         state.delegate_mut().set_search_query(String::new(), cx);
@@ -515,7 +686,7 @@ impl ImageList {
         div()
           .text_sm()
           .text_color(colors.muted_foreground)
-          .child(format!("No images match \"{}\"", self.search_query)),
+          .child(format!("No images match \"{}\"", self.search.query())),
       )
   }
 }
@@ -532,7 +703,7 @@ impl Render for ImageList {
 
     // Get filtered count
     let filtered_count = self.list_state.read(cx).delegate().total_filtered_count();
-    let is_filtering = !self.search_query.is_empty();
+    let is_filtering = !self.search.query().is_empty();
     let images_empty = total_count == 0;
     let filtered_empty = filtered_count == 0;
 
@@ -556,6 +727,18 @@ impl Render for ImageList {
       }
     };
 
+    let bulk_count = self.docker_state.read(cx).selected_image_ids.len();
+    let bulk_bar = (bulk_count > 0).then(|| {
+      render_bulk_delete_bar(
+        "images",
+        bulk_count,
+        "Images still used by a container are skipped and reported as failures.",
+        services::clear_image_bulk_selection,
+        services::delete_selected_images,
+        cx,
+      )
+    });
+
     // Toolbar
     let toolbar = h_flex()
       .h(px(52.))
@@ -567,14 +750,19 @@ impl Render for ImageList {
       .justify_between()
       .flex_shrink_0()
       .child(
-        v_flex()
-          .child(Label::new("Images"))
-          .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle)),
+        v_flex().child(Label::new("Images")).child(
+          h_flex()
+            .items_center()
+            .gap(px(8.))
+            .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle))
+            .child(self.refresh_status.clone()),
+        ),
       )
       .child(
         h_flex()
           .items_center()
           .gap(px(8.))
+          .child(render_docker_context_selector(cx))
           .child(
             Button::new("search")
               .icon(Icon::new(AppIcon::Search))
@@ -613,6 +801,13 @@ impl Render for ImageList {
                         crate::ui::dialogs::open_pull_image_dialog(window, cx);
                       }),
                   )
+                  .item(
+                    PopupMenuItem::new("Pull multiple")
+                      .icon(Icon::new(AppIcon::Plus))
+                      .on_click(|_, window, cx| {
+                        crate::ui::dialogs::open_pull_multiple_dialog(window, cx);
+                      }),
+                  )
                   .item(
                     PopupMenuItem::new("Load")
                       .icon(Icon::new(IconName::Inbox))
@@ -652,7 +847,7 @@ impl Render for ImageList {
           .child(div().flex_1().when_some(self.search_input.clone(), |el, input| {
             el.child(Input::new(&input).small().w_full())
           }))
-          .when(!self.search_query.is_empty(), |el| {
+          .when(!self.search.query().is_empty(), |el| {
             el.child(
               Button::new("clear-search")
                 .icon(IconName::Close)
@@ -668,6 +863,16 @@ impl Render for ImageList {
       None
     };
 
+    let sort_header = render_sort_header(
+      "image-sort",
+      &ImageSortKey::COLUMNS,
+      self.list_state.read(cx).delegate().sort,
+      cx.listener(|this, key: &ImageSortKey, window, cx| {
+        this.on_sort_column(*key, window, cx);
+      }),
+      cx,
+    );
+
     let content: gpui::Div = match &images_state {
       LoadState::NotLoaded | LoadState::Loading => render_loading("images", cx),
       LoadState::Error(e) => {
@@ -698,7 +903,11 @@ impl Render for ImageList {
       .flex_col()
       .overflow_hidden()
       .child(toolbar)
+      .children(bulk_bar)
       .children(search_bar)
+      .when(matches!(images_state, LoadState::Loaded) && !images_empty, |el| {
+        el.child(sort_header)
+      })
       .child(
         div()
           .id("image-list-scroll")
@@ -1,13 +1,16 @@
 pub mod build_dialog;
+mod delete;
 mod detail;
 mod lint_dialog;
 mod list;
 pub mod pull_dialog;
+pub mod pull_multiple_dialog;
 pub mod push_dialog;
 pub mod registry_dialog;
 pub mod tag_dialog;
 mod view;
 
+pub use delete::{image_users, prompt_delete_image};
 pub use lint_dialog::LintReportDialog;
 
 pub use view::ImagesView;
@@ -0,0 +1,253 @@
+use gpui::{App, Context, Entity, FocusHandle, Focusable, Render, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  IndexPath, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputEvent, InputState},
+  label::Label,
+  select::{Select, SelectState},
+  switch::Switch,
+  theme::ActiveTheme,
+  v_flex,
+};
+
+use super::pull_dialog::PullPlatform;
+
+/// Parse a newline-separated image list. Blank lines and `#` comments are
+/// skipped, duplicates dropped (first occurrence wins).
+pub fn parse_image_list(text: &str) -> Vec<String> {
+  let mut images: Vec<String> = Vec::new();
+  for line in text.lines() {
+    let line = line.split('#').next().unwrap_or_default().trim();
+    if !line.is_empty() && !images.iter().any(|i| i == line) {
+      images.push(line.to_string());
+    }
+  }
+  images
+}
+
+/// Pick a text file and append its contents to `input`.
+fn import_image_list(input: Entity<InputState>, window: &mut Window, cx: &mut App) {
+  let opts = gpui::PathPromptOptions {
+    files: true,
+    directories: false,
+    multiple: false,
+    prompt: Some("Import image list".into()),
+  };
+  let rx = cx.prompt_for_paths(opts);
+  let window_handle = window.window_handle();
+  cx.spawn(async move |cx| {
+    let Ok(Ok(Some(paths))) = rx.await else {
+      return;
+    };
+    let Some(path) = paths.into_iter().next() else {
+      return;
+    };
+    let content = match cx
+      .background_executor()
+      .spawn(async move { std::fs::read_to_string(path) })
+      .await
+    {
+      Ok(content) => content,
+      Err(e) => {
+        let _ = cx.update(|cx| {
+          crate::services::dispatcher(cx).update(cx, |_, cx| {
+            cx.emit(crate::services::DispatcherEvent::TaskFailed {
+              error: format!("Failed to read image list: {e}"),
+            });
+          });
+        });
+        return;
+      }
+    };
+    let _ = cx.update_window(window_handle, |_root, window, cx| {
+      input.update(cx, |state, cx| {
+        let existing = state.text().to_string();
+        let value = if existing.trim().is_empty() {
+          content
+        } else {
+          format!("{}\n{content}", existing.trim_end())
+        };
+        state.set_value(value, window, cx);
+      });
+    });
+  })
+  .detach();
+}
+
+/// Options for a batch pull
+#[derive(Debug, Clone, Default)]
+pub struct PullMultipleOptions {
+  pub images: Vec<String>,
+  pub platform: PullPlatform,
+  /// Pull images that are already present locally
+  pub force: bool,
+}
+
+/// Dialog for pulling a list of images
+pub struct PullMultipleDialog {
+  focus_handle: FocusHandle,
+  images_input: Option<Entity<InputState>>,
+  platform_select: Option<Entity<SelectState<Vec<PullPlatform>>>>,
+  force: bool,
+}
+
+impl PullMultipleDialog {
+  pub fn new(cx: &mut Context<'_, Self>) -> Self {
+    Self {
+      focus_handle: cx.focus_handle(),
+      images_input: None,
+      platform_select: None,
+      force: false,
+    }
+  }
+
+  fn ensure_inputs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.images_input.is_none() {
+      let input = cx.new(|cx| {
+        InputState::new(window, cx)
+          .multi_line(true)
+          .placeholder("One image per line, e.g.\nnginx:latest\npostgres:16")
+      });
+      // Keep the image count under the input current.
+      cx.subscribe(&input, |_this, _input, ev: &InputEvent, cx| {
+        if matches!(ev, InputEvent::Change) {
+          cx.notify();
+        }
+      })
+      .detach();
+      self.images_input = Some(input);
+    }
+
+    if self.platform_select.is_none() {
+      let preferred = crate::state::settings_state(cx)
+        .read(cx)
+        .settings
+        .default_pull_platform
+        .clone();
+      let all = PullPlatform::all();
+      let initial = all
+        .iter()
+        .position(|p| p.as_docker_arg() == Some(preferred.as_str()))
+        .unwrap_or(0);
+      self.platform_select = Some(cx.new(|cx| SelectState::new(all, Some(IndexPath::new(initial)), window, cx)));
+    }
+  }
+
+  pub fn get_options(&self, cx: &App) -> PullMultipleOptions {
+    let images = self
+      .images_input
+      .as_ref()
+      .map(|s| parse_image_list(&s.read(cx).text().to_string()))
+      .unwrap_or_default();
+
+    let platform = self
+      .platform_select
+      .as_ref()
+      .and_then(|s| s.read(cx).selected_value().copied())
+      .unwrap_or_default();
+
+    PullMultipleOptions {
+      images,
+      platform,
+      force: self.force,
+    }
+  }
+}
+
+impl Focusable for PullMultipleDialog {
+  fn focus_handle(&self, _cx: &App) -> FocusHandle {
+    self.focus_handle.clone()
+  }
+}
+
+impl Render for PullMultipleDialog {
+  fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+    self.ensure_inputs(window, cx);
+    let colors = cx.theme().colors;
+
+    let images_input = self.images_input.clone().unwrap();
+    let platform_select = self.platform_select.clone().unwrap();
+    let count = parse_image_list(&images_input.read(cx).text().to_string()).len();
+
+    let row = |label: &'static str, content: gpui::AnyElement| {
+      h_flex()
+        .w_full()
+        .py(px(12.))
+        .px(px(16.))
+        .justify_between()
+        .items_center()
+        .border_b_1()
+        .border_color(colors.border)
+        .child(Label::new(label).text_color(colors.foreground))
+        .child(content)
+    };
+
+    v_flex()
+      .w_full()
+      .child(
+        div()
+          .w_full()
+          .px(px(16.))
+          .py(px(12.))
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Pull several images one after another. Lines starting with # are ignored."),
+      )
+      .child(
+        v_flex()
+          .px(px(16.))
+          .pb(px(12.))
+          .gap(px(8.))
+          .border_b_1()
+          .border_color(colors.border)
+          .child(Input::new(&images_input).w_full().h(px(180.)))
+          .child(
+            h_flex()
+              .justify_between()
+              .items_center()
+              .child(
+                div()
+                  .text_xs()
+                  .text_color(colors.muted_foreground)
+                  .child(format!("{count} image(s)")),
+              )
+              .child(
+                Button::new("import-image-list")
+                  .label("Import from file...")
+                  .ghost()
+                  .small()
+                  .on_click(move |_ev, window, cx| import_image_list(images_input.clone(), window, cx)),
+              ),
+          ),
+      )
+      .child(row(
+        "Platform",
+        div()
+          .w(px(150.))
+          .child(Select::new(&platform_select).small())
+          .into_any_element(),
+      ))
+      .child(row(
+        "Pull images already present",
+        Switch::new("pull-multiple-force")
+          .checked(self.force)
+          .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+            this.force = *checked;
+            cx.notify();
+          }))
+          .into_any_element(),
+      ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_image_list() {
+    let text = "nginx:latest\n\n  postgres:16  \n# comment\nredis # cache\nnginx:latest\n";
+    assert_eq!(parse_image_list(text), vec!["nginx:latest", "postgres:16", "redis"]);
+  }
+}
@@ -7,20 +7,27 @@ use gpui_component::{
   v_flex,
 };
 
+use crate::docker::{RegistryAuth, registry_host};
+use crate::services;
+
 #[derive(Debug, Clone, Default)]
 pub struct PushImageOptions {
   pub image: String,
   pub tag: String,
-  pub username: Option<String>,
-  pub password: Option<String>,
+  /// `None` when username or password is blank, so the daemon's stored
+  /// credentials are used instead.
+  pub auth: Option<RegistryAuth>,
 }
 
 pub struct PushImageDialog {
   focus_handle: FocusHandle,
   default_image: String,
   default_tag: String,
+  /// Credentials remembered from an earlier push this session.
+  cached_auth: Option<RegistryAuth>,
   image_input: Option<Entity<InputState>>,
   tag_input: Option<Entity<InputState>>,
+  registry_input: Option<Entity<InputState>>,
   username_input: Option<Entity<InputState>>,
   password_input: Option<Entity<InputState>>,
 }
@@ -29,10 +36,12 @@ impl PushImageDialog {
   pub fn new(image: String, tag: String) -> impl FnOnce(&mut Context<'_, Self>) -> Self {
     move |cx| Self {
       focus_handle: cx.focus_handle(),
+      cached_auth: services::cached_registry_auth(registry_host(&image), cx),
       default_image: image,
       default_tag: tag,
       image_input: None,
       tag_input: None,
+      registry_input: None,
       username_input: None,
       password_input: None,
     }
@@ -47,14 +56,24 @@ impl PushImageDialog {
     if self.tag_input.is_none() {
       self.tag_input = Some(cx.new(|cx| InputState::new(window, cx).default_value(default_tag)));
     }
+    let cached = self.cached_auth.clone().unwrap_or_default();
+    if self.registry_input.is_none() {
+      let registry = registry_host(&self.default_image).to_string();
+      self.registry_input = Some(cx.new(|cx| InputState::new(window, cx).default_value(registry)));
+    }
     if self.username_input.is_none() {
-      self.username_input = Some(cx.new(|cx| InputState::new(window, cx).placeholder("username (optional)")));
+      self.username_input = Some(cx.new(|cx| {
+        InputState::new(window, cx)
+          .placeholder("username (optional)")
+          .default_value(cached.username)
+      }));
     }
     if self.password_input.is_none() {
       self.password_input = Some(cx.new(|cx| {
         InputState::new(window, cx)
-          .placeholder("password (optional)")
+          .placeholder("password or token (optional)")
           .masked(true)
+          .default_value(cached.password)
       }));
     }
   }
@@ -62,18 +81,21 @@ impl PushImageDialog {
   pub fn get_options(&self, cx: &App) -> PushImageOptions {
     let read =
       |opt: &Option<Entity<InputState>>| opt.as_ref().map(|s| s.read(cx).text().to_string()).unwrap_or_default();
-    let image = read(&self.image_input);
-    let tag = read(&self.tag_input);
-    let username = read(&self.username_input);
+    let image = read(&self.image_input).trim().to_string();
+    let tag = read(&self.tag_input).trim().to_string();
+    let registry = read(&self.registry_input).trim().to_string();
+    let username = read(&self.username_input).trim().to_string();
     let password = read(&self.password_input);
-    let auth_username = if username.is_empty() { None } else { Some(username) };
-    let auth_password = if password.is_empty() { None } else { Some(password) };
-    PushImageOptions {
-      image,
-      tag,
-      username: auth_username,
-      password: auth_password,
-    }
+    let auth = (!username.is_empty() && !password.is_empty()).then(|| RegistryAuth {
+      registry: if registry.is_empty() {
+        registry_host(&image).to_string()
+      } else {
+        registry
+      },
+      username,
+      password,
+    });
+    PushImageOptions { image, tag, auth }
   }
 }
 
@@ -89,6 +111,7 @@ impl Render for PushImageDialog {
     let colors = cx.theme().colors;
     let image_input = self.image_input.clone().unwrap();
     let tag_input = self.tag_input.clone().unwrap();
+    let registry_input = self.registry_input.clone().unwrap();
     let username_input = self.username_input.clone().unwrap();
     let password_input = self.password_input.clone().unwrap();
 
@@ -114,7 +137,10 @@ impl Render for PushImageDialog {
           .py(px(12.))
           .text_sm()
           .text_color(colors.muted_foreground)
-          .child("Push the image to its registry. Leave auth blank to use the daemon's stored credentials."),
+          .child(
+            "Push the image to its registry. Leave auth blank to use the daemon's stored credentials; \
+             credentials entered here are kept in memory until Dockside quits.",
+          ),
       )
       .child(row(
         "Image",
@@ -134,6 +160,15 @@ impl Render for PushImageDialog {
         colors.border,
         colors.foreground,
       ))
+      .child(row(
+        "Registry",
+        div()
+          .w(px(220.))
+          .child(Input::new(&registry_input).small())
+          .into_any_element(),
+        colors.border,
+        colors.foreground,
+      ))
       .child(row(
         "Username",
         div()
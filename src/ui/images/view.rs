@@ -1,10 +1,12 @@
 use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::theme::ActiveTheme;
 
-use crate::docker::ImageInfo;
-use crate::services;
+use crate::docker::{ImageHistoryEntry, ImageInfo};
+use crate::services::{self, RefreshInterval};
 use crate::state::{DockerState, ImageInspectData, Selection, StateChanged, docker_state};
+use crate::ui::components::copy_to_clipboard;
 
+use super::delete::confirm_force_delete;
 use super::detail::ImageDetail;
 use super::list::{ImageList, ImageListEvent};
 
@@ -14,6 +16,7 @@ pub struct ImagesView {
   image_list: Entity<ImageList>,
   // View-specific state (not selection - that's in global DockerState)
   inspect_data: Option<ImageInspectData>,
+  history: Option<Vec<ImageHistoryEntry>>,
   active_tab: usize,
 }
 
@@ -44,6 +47,18 @@ impl ImagesView {
     )
     .detach();
 
+    // A refused delete needs the window to ask about forcing it
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |_this, _state, event: &StateChanged, window, cx| {
+        if let StateChanged::ImageDeleteConflict { image_id, message } = event {
+          confirm_force_delete(image_id.clone(), message, window, cx);
+        }
+      },
+    )
+    .detach();
+
     // Subscribe to state changes
     cx.subscribe(&docker_state, |this, state, event: &StateChanged, cx| {
       match event {
@@ -68,6 +83,7 @@ impl ImagesView {
                 s.set_selection(Selection::None);
               });
               this.inspect_data = None;
+              this.history = None;
               this.active_tab = 0;
             }
           }
@@ -81,6 +97,14 @@ impl ImagesView {
             cx.notify();
           }
         }
+        StateChanged::ImageHistoryLoaded { image_id, history } => {
+          if let Some(selected) = this.selected_image(cx)
+            && selected.id == *image_id
+          {
+            this.history = Some(history.clone());
+            cx.notify();
+          }
+        }
         StateChanged::ImageScanStarted { image_id } => {
           if let Some(selected) = this.selected_image(cx)
             && selected.id == *image_id
@@ -117,10 +141,14 @@ impl ImagesView {
     })
     .detach();
 
+    // Periodic refresh for daemons without a reliable event stream
+    services::spawn_auto_refresh(RefreshInterval::Lists, 1, cx, |_, cx| services::refresh_images(cx));
+
     Self {
       docker_state,
       image_list,
       inspect_data: None,
+      history: None,
       active_tab: 0,
     }
   }
@@ -142,10 +170,12 @@ impl ImagesView {
 
     // Reset view-specific state
     self.inspect_data = None;
+    self.history = None;
     self.active_tab = 0;
 
-    // Load inspect data
+    // Load inspect data and layer history
     services::inspect_image(image.id.clone(), cx);
+    services::get_image_history(image.id.clone(), cx);
 
     cx.notify();
   }
@@ -161,6 +191,7 @@ impl Render for ImagesView {
     let colors = cx.theme().colors;
     let selected_image = self.selected_image(cx);
     let inspect_data = self.inspect_data.clone();
+    let history = self.history.clone();
     let active_tab = self.active_tab;
     let has_selection = selected_image.is_some();
 
@@ -168,10 +199,12 @@ impl Render for ImagesView {
     let detail = ImageDetail::new()
       .image(selected_image)
       .inspect_data(inspect_data)
+      .history(history)
       .active_tab(active_tab)
       .on_tab_change(cx.listener(|this, tab: &usize, _window, cx| {
         this.on_tab_change(*tab, cx);
-      }));
+      }))
+      .on_copy(copy_to_clipboard);
 
     div()
       .size_full()
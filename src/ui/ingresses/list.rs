@@ -15,7 +15,7 @@ use crate::assets::AppIcon;
 use crate::kubernetes::IngressInfo;
 use crate::services;
 use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_k8s_error, render_loading};
+use crate::ui::components::{CachedListDelegate, refresh_rows, render_k8s_error, render_loading};
 
 pub enum IngressListEvent {
   Selected(IngressInfo),
@@ -24,6 +24,7 @@ pub enum IngressListEvent {
 pub struct IngressListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<IngressInfo>,
 }
 
 impl IngressListDelegate {
@@ -41,7 +42,7 @@ impl IngressListDelegate {
     }
   }
 
-  fn filtered(&self, cx: &App) -> Vec<IngressInfo> {
+  fn collect_rows(&self, cx: &App) -> Vec<IngressInfo> {
     let items = self.items(cx);
     if self.search_query.is_empty() {
       return items;
@@ -53,16 +54,40 @@ impl IngressListDelegate {
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered(&self) -> &[IngressInfo] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for IngressListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|i| matches!(global_selection, Selection::Ingress { name, namespace } if *name == i.name && *namespace == i.namespace))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for IngressListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered().len()
   }
 
   fn render_item(
@@ -71,7 +96,7 @@ impl ListDelegate for IngressListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let items = self.filtered(cx);
+    let items = self.filtered();
     let i = items.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -212,13 +237,15 @@ impl IngressList {
     let delegate = IngressListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered(cx);
+        let filtered = delegate.filtered();
         if let Some(i) = filtered.get(ix.row) {
           cx.emit(IngressListEvent::Selected(i.clone()));
         }
@@ -227,18 +254,22 @@ impl IngressList {
     })
     .detach();
 
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(
-        event,
-        StateChanged::IngressesUpdated
-          | StateChanged::NamespacesUpdated
-          | StateChanged::MachinesUpdated
-          | StateChanged::SelectionChanged
-      ) {
-        this.list_state.update(cx, |_state, cx| cx.notify());
-        cx.notify();
-      }
-    })
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(
+          event,
+          StateChanged::IngressesUpdated
+            | StateChanged::NamespacesUpdated
+            | StateChanged::MachinesUpdated
+            | StateChanged::SelectionChanged
+        ) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
+          cx.notify();
+        }
+      },
+    )
     .detach();
 
     Self {
@@ -263,7 +294,7 @@ impl IngressList {
       if current_text != self.search_query {
         current_text.clone_into(&mut self.search_query);
         self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+          state.delegate_mut().set_search_query(current_text, cx);
           cx.notify();
         });
       }
@@ -278,7 +309,7 @@ impl IngressList {
       self.search_query.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -325,7 +356,7 @@ impl Render for IngressList {
     let state = self.docker_state.read(cx);
     let total_count = state.ingresses.len();
     let load_state = state.ingresses_state.clone();
-    let filtered_count = self.list_state.read(cx).delegate().filtered(cx).len();
+    let filtered_count = self.list_state.read(cx).delegate().filtered().len();
     let is_filtering = !self.search_query.is_empty();
     let empty = filtered_count == 0;
 
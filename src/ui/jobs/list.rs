@@ -15,7 +15,7 @@ use crate::assets::AppIcon;
 use crate::kubernetes::JobInfo;
 use crate::services;
 use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_k8s_error, render_loading};
+use crate::ui::components::{CachedListDelegate, refresh_rows, render_k8s_error, render_loading};
 
 pub enum JobListEvent {
   Selected(JobInfo),
@@ -24,6 +24,7 @@ pub enum JobListEvent {
 pub struct JobListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<JobInfo>,
 }
 
 impl JobListDelegate {
@@ -41,7 +42,7 @@ impl JobListDelegate {
     }
   }
 
-  fn filtered(&self, cx: &App) -> Vec<JobInfo> {
+  fn collect_rows(&self, cx: &App) -> Vec<JobInfo> {
     let items = self.items(cx);
     if self.search_query.is_empty() {
       return items;
@@ -53,16 +54,40 @@ impl JobListDelegate {
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered(&self) -> &[JobInfo] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for JobListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|j| matches!(global_selection, Selection::Job { name, namespace } if *name == j.name && *namespace == j.namespace))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for JobListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered().len()
   }
 
   fn render_item(
@@ -71,7 +96,7 @@ impl ListDelegate for JobListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let items = self.filtered(cx);
+    let items = self.filtered();
     let j = items.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -228,13 +253,15 @@ impl JobList {
     let delegate = JobListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered(cx);
+        let filtered = delegate.filtered();
         if let Some(j) = filtered.get(ix.row) {
           cx.emit(JobListEvent::Selected(j.clone()));
         }
@@ -243,18 +270,22 @@ impl JobList {
     })
     .detach();
 
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(
-        event,
-        StateChanged::JobsUpdated
-          | StateChanged::NamespacesUpdated
-          | StateChanged::MachinesUpdated
-          | StateChanged::SelectionChanged
-      ) {
-        this.list_state.update(cx, |_state, cx| cx.notify());
-        cx.notify();
-      }
-    })
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(
+          event,
+          StateChanged::JobsUpdated
+            | StateChanged::NamespacesUpdated
+            | StateChanged::MachinesUpdated
+            | StateChanged::SelectionChanged
+        ) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
+          cx.notify();
+        }
+      },
+    )
     .detach();
 
     Self {
@@ -279,7 +310,7 @@ impl JobList {
       if current_text != self.search_query {
         current_text.clone_into(&mut self.search_query);
         self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+          state.delegate_mut().set_search_query(current_text, cx);
           cx.notify();
         });
       }
@@ -294,7 +325,7 @@ impl JobList {
       self.search_query.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -341,7 +372,7 @@ impl Render for JobList {
     let state = self.docker_state.read(cx);
     let total_count = state.jobs.len();
     let load_state = state.jobs_state.clone();
-    let filtered_count = self.list_state.read(cx).delegate().filtered(cx).len();
+    let filtered_count = self.list_state.read(cx).delegate().filtered().len();
     let is_filtering = !self.search_query.is_empty();
     let empty = filtered_count == 0;
 
@@ -1,13 +1,11 @@
-use std::time::Duration;
-
-use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::theme::ActiveTheme;
 
 use super::detail::JobDetail;
 use super::list::{JobList, JobListEvent};
 use crate::kubernetes::JobInfo;
-use crate::services;
-use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+use crate::services::{self, RefreshInterval};
+use crate::state::{DockerState, Selection, StateChanged, docker_state};
 
 pub struct JobsView {
   docker_state: Entity<DockerState>,
@@ -109,14 +107,7 @@ impl JobsView {
     )
     .detach();
 
-    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval.max(5);
-    cx.spawn(async move |_this, cx| {
-      loop {
-        Timer::after(Duration::from_secs(refresh_interval)).await;
-        let _ = cx.update(services::refresh_jobs);
-      }
-    })
-    .detach();
+    services::spawn_auto_refresh(RefreshInterval::Lists, 5, cx, |_, cx| services::refresh_jobs(cx));
 
     services::refresh_jobs(cx);
     services::refresh_namespaces(cx);
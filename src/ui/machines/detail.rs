@@ -14,48 +14,8 @@ use std::rc::Rc;
 // Re-export from state module for backwards compatibility
 pub use crate::state::MachineDetailTab;
 
-/// Parsed colima version information
-#[derive(Debug, Clone, Default)]
-struct ColimaVersionInfo {
-  version: String,
-  git_commit: String,
-  runtime: String,
-  arch: String,
-  client_version: String,
-  server_version: String,
-}
-
-impl ColimaVersionInfo {
-  fn parse(raw: &str) -> Self {
-    let mut info = Self::default();
-
-    for line in raw.lines() {
-      let line = line.trim();
-      if line.starts_with("colima version") {
-        info.version = line.strip_prefix("colima version").unwrap_or("").trim().to_string();
-      } else if let Some(val) = line.strip_prefix("git commit:") {
-        info.git_commit = val.trim().to_string();
-      } else if let Some(val) = line.strip_prefix("runtime:") {
-        info.runtime = val.trim().to_string();
-      } else if let Some(val) = line.strip_prefix("arch:") {
-        info.arch = val.trim().to_string();
-      } else if let Some(val) = line.strip_prefix("client:") {
-        info.client_version = val.trim().to_string();
-      } else if let Some(val) = line.strip_prefix("server:") {
-        info.server_version = val.trim().to_string();
-      }
-    }
-
-    info
-  }
-
-  fn is_loaded(&self) -> bool {
-    !self.version.is_empty()
-  }
-}
-
 use crate::assets::AppIcon;
-use crate::colima::{ColimaVm, Machine};
+use crate::colima::{ColimaVersionInfo, ColimaVm, Machine, parse_disk_info, parse_memory_info};
 use crate::state::{MachineLogType, MachineTabState};
 use crate::terminal::TerminalView;
 use crate::ui::components::{FileExplorer, FileExplorerConfig, FileExplorerState, ProcessView};
@@ -83,6 +43,9 @@ pub struct MachineDetail {
   on_log_type_change: Option<LogTypeCallback>,
   on_file_select: Option<FileSelectCallback>,
   on_close_file_viewer: Option<RefreshCallback>,
+  on_edit_file: Option<RefreshCallback>,
+  on_cancel_file_edit: Option<RefreshCallback>,
+  on_save_file: Option<FileSelectCallback>,
   on_symlink_click: Option<SymlinkClickCallback>,
   on_copy: Option<CopyCallback>,
   on_open_in_editor: Option<OpenInEditorCallback>,
@@ -104,6 +67,9 @@ impl MachineDetail {
       on_log_type_change: None,
       on_file_select: None,
       on_close_file_viewer: None,
+      on_edit_file: None,
+      on_cancel_file_edit: None,
+      on_save_file: None,
       on_symlink_click: None,
       on_copy: None,
       on_open_in_editor: None,
@@ -193,6 +159,31 @@ impl MachineDetail {
     self
   }
 
+  pub fn on_edit_file<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_edit_file = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_cancel_file_edit<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_cancel_file_edit = Some(Rc::new(callback));
+    self
+  }
+
+  /// Write the edited file back (receives the file path)
+  pub fn on_save_file<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_save_file = Some(Rc::new(callback));
+    self
+  }
+
   pub fn on_symlink_click<F>(mut self, callback: F) -> Self
   where
     F: Fn(&str, &mut Window, &mut App) + 'static,
@@ -1235,6 +1226,40 @@ impl MachineDetail {
 
     let colors = &cx.theme().colors;
 
+    // A stopped VM has nothing to SSH into; offer to start it instead
+    if let Some(Machine::Colima(vm)) = &self.machine
+      && !vm.status.is_running()
+    {
+      let name = vm.name.clone();
+      return v_flex()
+        .flex_1()
+        .w_full()
+        .items_center()
+        .justify_center()
+        .gap(px(16.))
+        .child(
+          Icon::new(AppIcon::Terminal)
+            .size(px(48.))
+            .text_color(colors.muted_foreground),
+        )
+        .child(
+          div()
+            .text_sm()
+            .text_color(colors.muted_foreground)
+            .child(format!("'{}' is stopped. Start it to open a shell.", vm.name)),
+        )
+        .child(
+          Button::new("terminal-start-machine")
+            .label("Start machine")
+            .icon(AppIcon::Play)
+            .primary()
+            .small()
+            .on_click(move |_ev, _window, cx| {
+              crate::services::start_machine(name.clone(), cx);
+            }),
+        );
+    }
+
     // Fallback: show message when terminal not yet connected
     v_flex()
       .flex_1()
@@ -1265,7 +1290,8 @@ impl MachineDetail {
       selected_file: state.and_then(|s| s.selected_file.clone()),
       file_content: state.map(|s| s.file_content.clone()).unwrap_or_default(),
       file_content_loading: state.is_some_and(|s| s.file_content_loading),
-      file_content_error: None,
+      file_content_error: state.and_then(|s| s.file_content_error.clone()),
+      editing: state.is_some_and(|s| s.file_editing),
     };
 
     let files = state.map(|s| s.files.clone()).unwrap_or_default();
@@ -1301,6 +1327,27 @@ impl MachineDetail {
       });
     }
 
+    if let Some(ref cb) = self.on_edit_file {
+      let cb = cb.clone();
+      explorer = explorer.on_edit_file(move |(), window, cx| {
+        cb(&(), window, cx);
+      });
+    }
+
+    if let Some(ref cb) = self.on_cancel_file_edit {
+      let cb = cb.clone();
+      explorer = explorer.on_cancel_edit(move |(), window, cx| {
+        cb(&(), window, cx);
+      });
+    }
+
+    if let Some(ref cb) = self.on_save_file {
+      let cb = cb.clone();
+      explorer = explorer.on_save_file(move |path, window, cx| {
+        cb(path, window, cx);
+      });
+    }
+
     if let Some(ref cb) = self.on_symlink_click {
       let cb = cb.clone();
       explorer = explorer.on_symlink_click(move |path, window, cx| {
@@ -1395,84 +1442,3 @@ impl MachineDetail {
     result.into_any_element()
   }
 }
-
-/// Parse memory info from "free -h" output
-/// Returns (used, total, `percent_used`)
-fn parse_memory_info(info: &str) -> (String, String, f64) {
-  // Format:
-  //               total        used        free      shared  buff/cache   available
-  // Mem:          7.7Gi       1.2Gi       5.8Gi       0.0Ki       760Mi       6.2Gi
-  for line in info.lines() {
-    let line = line.trim();
-    if line.starts_with("Mem:") {
-      let parts: Vec<&str> = line.split_whitespace().collect();
-      if parts.len() >= 3 {
-        let total = parts[1].to_string();
-        let used = parts[2].to_string();
-
-        // Parse values to calculate percentage
-        let total_val = parse_memory_value(parts[1]);
-        let used_val = parse_memory_value(parts[2]);
-
-        let percent = if total_val > 0.0 {
-          (used_val / total_val) * 100.0
-        } else {
-          0.0
-        };
-
-        return (used, total, percent);
-      }
-    }
-  }
-
-  ("--".to_string(), "--".to_string(), 0.0)
-}
-
-/// Parse memory value like "7.7Gi", "760Mi", "1.2Gi" to bytes
-fn parse_memory_value(s: &str) -> f64 {
-  let s = s.trim();
-
-  // Find where the number ends and unit begins
-  let num_end = s
-    .chars()
-    .position(|c| !c.is_ascii_digit() && c != '.')
-    .unwrap_or(s.len());
-
-  let (num_str, unit) = s.split_at(num_end);
-  let value: f64 = num_str.parse().unwrap_or(0.0);
-
-  match unit.to_lowercase().as_str() {
-    "gi" | "g" => value * 1024.0 * 1024.0 * 1024.0,
-    "mi" | "m" => value * 1024.0 * 1024.0,
-    "ki" | "k" => value * 1024.0,
-    _ => value,
-  }
-}
-
-/// Parse disk info from "df -h /" output
-/// Returns (used, total, `percent_used`)
-fn parse_disk_info(info: &str) -> (String, String, f64) {
-  // Format:
-  // Filesystem      Size  Used Avail Use% Mounted on
-  // /dev/vda1        59G   10G   46G  19% /
-  for line in info.lines() {
-    let line = line.trim();
-    // Skip header
-    if line.starts_with("Filesystem") {
-      continue;
-    }
-
-    let parts: Vec<&str> = line.split_whitespace().collect();
-    if parts.len() >= 5 {
-      let total = parts[1].to_string();
-      let used = parts[2].to_string();
-      let percent_str = parts[4].trim_end_matches('%');
-
-      let percent: f64 = percent_str.parse().unwrap_or(0.0);
-
-      return (used, total, percent);
-    }
-  }
-
-  ("--".to_string(), "--".to_string(), 0.0)
-}
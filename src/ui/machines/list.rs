@@ -15,7 +15,7 @@ use crate::assets::AppIcon;
 use crate::colima::{Machine, MachineId};
 use crate::services;
 use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state, settings_state};
-use crate::ui::components::{render_error, render_loading};
+use crate::ui::components::{CachedListDelegate, refresh_rows, render_error, render_loading};
 
 /// Machine list events emitted to parent
 pub enum MachineListEvent {
@@ -26,6 +26,7 @@ pub enum MachineListEvent {
 pub struct MachineListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<Machine>,
 }
 
 impl MachineListDelegate {
@@ -33,7 +34,7 @@ impl MachineListDelegate {
     &self.docker_state.read(cx).machines
   }
 
-  fn filtered_machines(&self, cx: &App) -> Vec<Machine> {
+  fn collect_rows(&self, cx: &App) -> Vec<Machine> {
     let machines = self.machines(cx);
     if self.search_query.is_empty() {
       return machines.clone();
@@ -51,16 +52,40 @@ impl MachineListDelegate {
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered_machines(&self) -> &[Machine] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for MachineListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|machine| matches!(global_selection, Selection::Machine(id) if *id == machine.id()))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for MachineListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered_machines(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered_machines().len()
   }
 
   fn render_item(
@@ -69,7 +94,7 @@ impl ListDelegate for MachineListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let machines = self.filtered_machines(cx);
+    let machines = self.filtered_machines();
     let machine = machines.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -239,8 +264,8 @@ impl ListDelegate for MachineListDelegate {
                     .icon(Icon::new(AppIcon::Kubernetes))
                     .on_click({
                       let n = n.clone();
-                      move |_, _, cx| {
-                        services::kubernetes_reset(n.clone(), cx);
+                      move |_, window, cx| {
+                        crate::ui::dialogs::open_kubernetes_reset_dialog(n.clone(), window, cx);
                       }
                     }),
                 );
@@ -396,15 +421,17 @@ impl MachineList {
     let delegate = MachineListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
 
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     // Subscribe to list events
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered_machines(cx);
+        let filtered = delegate.filtered_machines();
         if let Some(machine) = filtered.get(ix.row) {
           cx.emit(MachineListEvent::Selected(Box::new(machine.clone())));
         }
@@ -414,15 +441,17 @@ impl MachineList {
     .detach();
 
     // Subscribe to docker state changes to refresh list
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(event, StateChanged::MachinesUpdated | StateChanged::SelectionChanged) {
-        // Notify list state to re-render
-        this.list_state.update(cx, |_state, cx| {
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(event, StateChanged::MachinesUpdated | StateChanged::SelectionChanged) {
+          // Notify list state to re-render
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
           cx.notify();
-        });
-        cx.notify();
-      }
-    })
+        }
+      },
+    )
     .detach();
 
     Self {
@@ -447,7 +476,7 @@ impl MachineList {
       if current_text != self.search_query {
         current_text.clone_into(&mut self.search_query);
         self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+          state.delegate_mut().set_search_query(current_text, cx);
           cx.notify();
         });
       }
@@ -462,7 +491,7 @@ impl MachineList {
       self.search_query.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -560,7 +589,7 @@ impl Render for MachineList {
     let colima_enabled = settings_state(cx).read(cx).settings.colima_enabled;
 
     // Get filtered count
-    let filtered_count = self.list_state.read(cx).delegate().filtered_machines(cx).len();
+    let filtered_count = self.list_state.read(cx).delegate().filtered_machines().len();
     let is_filtering = !self.search_query.is_empty();
     let machines_empty = filtered_count == 0;
 
@@ -11,6 +11,7 @@ use crate::services;
 use crate::state::{DockerState, MachineTabState, Selection, StateChanged, docker_state};
 use crate::terminal::TerminalView;
 use crate::ui::components::ProcessView;
+use crate::ui::dialogs;
 
 use super::detail::{MachineDetail, MachineDetailTab};
 use super::host_dialog::HostDialog;
@@ -98,6 +99,9 @@ impl MachinesView {
                 this.terminal_view = None;
               }
             }
+            if this.active_tab == MachineDetailTab::Terminal {
+              this.sync_terminal(window, cx);
+            }
             cx.notify();
           }
           StateChanged::MachineTabRequest { machine_id, tab } => {
@@ -123,6 +127,18 @@ impl MachinesView {
               Self::show_edit_dialog(&machine, window, cx);
             }
           }
+          StateChanged::MachineFileSaved { machine_name, path } if this.is_open_file(machine_name, path, cx) => {
+            // Reload so the viewer shows what actually landed on disk
+            this.last_synced_file_content.clear();
+            this.load_file_content(path, cx);
+          }
+          StateChanged::MachineFileWriteDenied {
+            machine_name,
+            path,
+            content,
+          } => {
+            Self::confirm_root_write(machine_name.clone(), path.clone(), content.clone(), window, cx);
+          }
           StateChanged::ConfigureHostRequest => {
             // Show Host Docker configuration dialog
             let host_info = {
@@ -199,10 +215,9 @@ impl MachinesView {
   }
 
   fn on_select_machine(&mut self, machine: &Machine, window: &mut Window, cx: &mut Context<'_, Self>) {
-    // Update global selection (single source of truth)
-    self.docker_state.update(cx, |state, _cx| {
-      state.set_selection(Selection::Machine(machine.id()));
-    });
+    // Update global selection (single source of truth); may also make
+    // the machine the active Docker daemon
+    services::select_machine(machine, cx);
 
     // Reset view-specific state but keep active_tab
     // This allows users to stay on their current tab when switching machines
@@ -256,16 +271,24 @@ impl MachinesView {
     cx.notify();
   }
 
+  /// Keep the terminal tab's `colima ssh` session in step with the VM:
+  /// connect once it's running, drop the dead session once it stops.
+  fn sync_terminal(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let Some(machine) = self.selected_machine(cx).filter(Machine::supports_terminal) else {
+      return;
+    };
+    if !machine.is_running() {
+      self.terminal_view = None;
+    } else if self.terminal_view.is_none() {
+      self.terminal_view = Some(cx.new(|cx| TerminalView::for_colima(machine.profile(), window, cx)));
+    }
+  }
+
   fn on_tab_change(&mut self, tab: MachineDetailTab, window: &mut Window, cx: &mut Context<'_, Self>) {
     self.active_tab = tab;
 
-    // If switching to terminal tab, create terminal view (only for machines that support it)
-    if tab == MachineDetailTab::Terminal
-      && self.terminal_view.is_none()
-      && let Some(machine) = self.selected_machine(cx)
-      && machine.supports_terminal()
-    {
-      self.terminal_view = Some(cx.new(|cx| TerminalView::for_colima(machine.profile(), window, cx)));
+    if tab == MachineDetailTab::Terminal {
+      self.sync_terminal(window, cx);
     }
 
     // If switching to processes tab, create the right process view for this machine
@@ -476,6 +499,8 @@ impl MachinesView {
   fn load_file_content(&mut self, path: &str, cx: &mut Context<'_, Self>) {
     if let Some(machine) = self.selected_machine(cx) {
       self.machine_tab_state.file_content_loading = true;
+      self.machine_tab_state.file_content_error = None;
+      self.machine_tab_state.file_editing = false;
       self.machine_tab_state.selected_file = Some(path.to_string());
       let machine_name = machine.name().to_string();
       let file_path = path.to_string();
@@ -490,12 +515,18 @@ impl MachinesView {
             } else {
               Some(machine_name.as_str())
             };
-            ColimaClient::read_file(name_opt, &file_path, 1000).unwrap_or_else(|_| "Failed to read file".to_string())
+            ColimaClient::read_file(name_opt, &file_path, 1000)
           })
           .await;
 
         let _ = this.update(cx, |this, cx| {
-          this.machine_tab_state.file_content = content;
+          match content {
+            Ok(content) => this.machine_tab_state.file_content = content,
+            Err(e) => {
+              this.machine_tab_state.file_content.clear();
+              this.machine_tab_state.file_content_error = Some(e.to_string());
+            }
+          }
           this.machine_tab_state.file_content_loading = false;
           cx.notify();
         });
@@ -569,11 +600,71 @@ impl MachinesView {
   fn on_close_file_viewer(&mut self, cx: &mut Context<'_, Self>) {
     self.machine_tab_state.selected_file = None;
     self.machine_tab_state.file_content.clear();
+    self.machine_tab_state.file_content_error = None;
+    self.machine_tab_state.file_editing = false;
     self.file_content_editor = None;
     self.last_synced_file_content.clear();
     cx.notify();
   }
 
+  fn on_cancel_file_edit(&mut self, cx: &mut Context<'_, Self>) {
+    self.machine_tab_state.file_editing = false;
+    // Force the next render to put the loaded content back in the editor
+    self.last_synced_file_content.clear();
+    cx.notify();
+  }
+
+  /// Whether `path` on `machine_name` is the file open in the viewer
+  fn is_open_file(&self, machine_name: &str, path: &str, cx: &App) -> bool {
+    self.selected_machine(cx).is_some_and(|m| m.name() == machine_name)
+      && self.machine_tab_state.selected_file.as_deref() == Some(path)
+  }
+
+  fn edited_file_content(&self, cx: &App) -> Option<String> {
+    self
+      .file_content_editor
+      .as_ref()
+      .map(|editor| editor.read(cx).text().to_string())
+  }
+
+  /// Confirm, then write the editor's text over `path`. Files owned by
+  /// root are written through sudo straight away.
+  fn on_save_file(&mut self, path: &str, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let (Some(machine), Some(content)) = (self.selected_machine(cx), self.edited_file_content(cx)) else {
+      return;
+    };
+    let name = machine.name().to_string();
+    let path = path.to_string();
+    let as_root = self
+      .machine_tab_state
+      .files
+      .iter()
+      .any(|f| f.path == path && f.owner == "root");
+    let warning = as_root.then(|| "The file is owned by root and will be written with sudo.".into());
+    dialogs::open_confirm_dialog(
+      "Overwrite file?",
+      format!("Replace {path} on '{name}' with your edits?"),
+      warning,
+      "Save",
+      move |_window, cx| services::write_machine_file(name.clone(), path.clone(), content.clone(), as_root, cx),
+      window,
+      cx,
+    );
+  }
+
+  /// Offer to repeat a save that hit a permission error, this time as root.
+  fn confirm_root_write(name: String, path: String, content: String, window: &mut Window, cx: &mut Context<'_, Self>) {
+    dialogs::open_confirm_dialog(
+      "Permission denied",
+      format!("The machine user can't write {path}. Save it as root instead?"),
+      Some("The file will be written with sudo.".into()),
+      "Save as Root",
+      move |_window, cx| services::write_machine_file(name.clone(), path.clone(), content.clone(), true, cx),
+      window,
+      cx,
+    );
+  }
+
   fn on_symlink_follow(&mut self, path: &str, window: &mut Window, cx: &mut Context<'_, Self>) {
     if let Some(machine) = self.selected_machine(cx) {
       let machine_name = machine.name().to_string();
@@ -649,37 +740,7 @@ impl MachinesView {
               // View file - set up the editor
               this.file_content_editor = Some(file_editor.clone());
               this.last_synced_file_content.clear();
-              this.machine_tab_state.selected_file = Some(target.clone());
-              this.machine_tab_state.file_content_loading = true;
-
-              // Load file content
-              if let Some(machine) = this.selected_machine(cx) {
-                let machine_name = machine.name().to_string();
-                let file_path = target.clone();
-
-                cx.spawn(async move |this, cx| {
-                  let content = cx
-                    .background_executor()
-                    .spawn(async move {
-                      use crate::colima::ColimaClient;
-                      let name_opt = if machine_name == "default" {
-                        None
-                      } else {
-                        Some(machine_name.as_str())
-                      };
-                      ColimaClient::read_file(name_opt, &file_path, 1000)
-                        .unwrap_or_else(|_| "Failed to read file".to_string())
-                    })
-                    .await;
-
-                  let _ = this.update(cx, |this, cx| {
-                    this.machine_tab_state.file_content = content;
-                    this.machine_tab_state.file_content_loading = false;
-                    cx.notify();
-                  });
-                })
-                .detach();
-              }
+              this.load_file_content(&target, cx);
             }
           }
           cx.notify();
@@ -690,9 +751,16 @@ impl MachinesView {
   }
 
   fn on_open_in_editor(&mut self, data: &(String, bool), _window: &mut Window, cx: &mut Context<'_, Self>) {
-    let (path, _is_dir) = data;
+    let (path, is_dir) = data;
     if let Some(machine) = self.selected_machine(cx) {
-      services::open_machine_in_editor(machine.name(), path, cx);
+      if *is_dir {
+        services::open_machine_in_editor(machine.name(), path, cx);
+      } else {
+        let source = services::EditorSource::Machine {
+          name: machine.name().to_string(),
+        };
+        services::open_file_copy_in_editor(source, path.clone(), cx);
+      }
     }
   }
 }
@@ -765,12 +833,20 @@ impl Render for MachinesView {
       .on_close_file_viewer(cx.listener(|this, (): &(), _window, cx| {
         this.on_close_file_viewer(cx);
       }))
+      .on_edit_file(cx.listener(|this, (): &(), _window, cx| {
+        this.machine_tab_state.file_editing = true;
+        cx.notify();
+      }))
+      .on_cancel_file_edit(cx.listener(|this, (): &(), _window, cx| {
+        this.on_cancel_file_edit(cx);
+      }))
+      .on_save_file(cx.listener(|this, path: &str, window, cx| {
+        this.on_save_file(path, window, cx);
+      }))
       .on_symlink_click(cx.listener(|this, path: &str, window, cx| {
         this.on_symlink_follow(path, window, cx);
       }))
-      .on_copy(|text: &str, _window, cx| {
-        cx.write_to_clipboard(gpui::ClipboardItem::new_string(text.to_string()));
-      })
+      .on_copy(crate::ui::components::copy_to_clipboard)
       .on_open_in_editor(cx.listener(|this, data: &(String, bool), window, cx| {
         this.on_open_in_editor(data, window, cx);
       }));
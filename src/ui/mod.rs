@@ -1,6 +1,7 @@
 pub mod activity;
 pub mod cluster;
 pub mod clusters;
+pub mod command_log;
 pub mod command_palette;
 pub mod components;
 pub mod compose;
@@ -7,7 +7,8 @@ use gpui_component::{
   v_flex,
 };
 
-use crate::ui::components::render_k8s_header;
+use crate::state::CurrentView;
+use crate::ui::components::{RefreshStatus, render_k8s_header};
 use crate::ui::ingresses::IngressesView;
 use crate::ui::services::ServicesView;
 
@@ -18,22 +19,44 @@ pub enum NetworkingTab {
   Ingresses,
 }
 
+impl NetworkingTab {
+  /// View whose refresh status the header shows for this tab
+  fn view(self) -> CurrentView {
+    match self {
+      NetworkingTab::Services => CurrentView::Services,
+      NetworkingTab::Ingresses => CurrentView::Ingresses,
+    }
+  }
+}
+
 pub struct NetworkingView {
   active_tab: NetworkingTab,
+  refresh_status: Entity<RefreshStatus>,
   services: Entity<ServicesView>,
   ingresses: Entity<IngressesView>,
 }
 
 impl NetworkingView {
-  pub fn new(services: Entity<ServicesView>, ingresses: Entity<IngressesView>) -> Self {
+  pub fn new(services: Entity<ServicesView>, ingresses: Entity<IngressesView>, cx: &mut Context<'_, Self>) -> Self {
     Self {
       active_tab: NetworkingTab::Services,
+      refresh_status: cx.new(|cx| RefreshStatus::new(NetworkingTab::Services.view(), cx)),
       services,
       ingresses,
     }
   }
 }
 
+impl NetworkingView {
+  fn select_tab(&mut self, tab: NetworkingTab, cx: &mut Context<'_, Self>) {
+    self.active_tab = tab;
+    self
+      .refresh_status
+      .update(cx, |status, cx| status.set_view(tab.view(), cx));
+    cx.notify();
+  }
+}
+
 impl Render for NetworkingView {
   fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let active = self.active_tab;
@@ -44,8 +67,7 @@ impl Render for NetworkingView {
           .label("Services")
           .selected(active == NetworkingTab::Services)
           .on_click(cx.listener(|this, _ev, _w, cx| {
-            this.active_tab = NetworkingTab::Services;
-            cx.notify();
+            this.select_tab(NetworkingTab::Services, cx);
           })),
       )
       .child(
@@ -53,8 +75,7 @@ impl Render for NetworkingView {
           .label("Ingresses")
           .selected(active == NetworkingTab::Ingresses)
           .on_click(cx.listener(|this, _ev, _w, cx| {
-            this.active_tab = NetworkingTab::Ingresses;
-            cx.notify();
+            this.select_tab(NetworkingTab::Ingresses, cx);
           })),
       );
 
@@ -65,7 +86,7 @@ impl Render for NetworkingView {
 
     v_flex()
       .size_full()
-      .child(render_k8s_header(tab_bar, true, div(), cx))
+      .child(render_k8s_header(tab_bar, true, self.refresh_status.clone(), cx))
       .child(div().flex_1().min_h_0().child(body))
   }
 }
@@ -13,6 +13,7 @@ use std::rc::Rc;
 
 use crate::assets::AppIcon;
 use crate::docker::NetworkInfo;
+use crate::ui::components::{CopyCallback, copy_button};
 
 type TabChangeCallback = Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>;
 
@@ -20,6 +21,7 @@ pub struct NetworkDetail {
   network: Option<NetworkInfo>,
   active_tab: usize,
   on_tab_change: Option<TabChangeCallback>,
+  on_copy: Option<CopyCallback>,
 }
 
 impl NetworkDetail {
@@ -28,6 +30,7 @@ impl NetworkDetail {
       network: None,
       active_tab: 0,
       on_tab_change: None,
+      on_copy: None,
     }
   }
 
@@ -49,6 +52,14 @@ impl NetworkDetail {
     self
   }
 
+  pub fn on_copy<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_copy = Some(Rc::new(callback));
+    self
+  }
+
   fn render_empty(cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -75,23 +86,31 @@ impl NetworkDetail {
       )
   }
 
-  fn render_info_tab(network: &NetworkInfo, cx: &App) -> gpui::Div {
+  fn render_info_tab(&self, network: &NetworkInfo, cx: &App) -> gpui::Div {
     let _colors = &cx.theme().colors;
 
     // Basic info rows
     let mut basic_info = vec![
-      ("ID", network.short_id().to_string()),
-      ("Name", network.name.clone()),
-      ("Driver", network.driver.clone()),
-      ("Scope", network.scope.clone()),
+      (
+        "ID",
+        network.short_id().to_string(),
+        self.copy_button("copy-network-id", network.id.clone()),
+      ),
+      ("Name", network.name.clone(), None),
+      ("Driver", network.driver.clone(), None),
+      ("Scope", network.scope.clone(), None),
     ];
 
     if let Some(created) = network.created {
-      basic_info.push(("Created", created.format("%Y-%m-%d %H:%M:%S").to_string()));
+      basic_info.push(("Created", created.format("%Y-%m-%d %H:%M:%S").to_string(), None));
     }
 
     if network.internal {
-      basic_info.push(("Internal", "Yes".to_string()));
+      basic_info.push(("Internal", "Yes".to_string(), None));
+    }
+
+    if network.enable_ipv6 {
+      basic_info.push(("IPv6", "Enabled".to_string(), None));
     }
 
     let mut content = v_flex()
@@ -99,7 +118,7 @@ impl NetworkDetail {
       .w_full()
       .p(px(16.))
       .gap(px(12.))
-      .child(Self::render_section(None, basic_info, cx));
+      .child(Self::render_copy_section(None, basic_info, cx));
 
     // IPAM section
     if let Some(ref ipam) = network.ipam {
@@ -109,7 +128,8 @@ impl NetworkDetail {
         ipam_rows.push(("Driver", driver.clone()));
       }
 
-      if let Some(config) = ipam.config.first() {
+      // Dual-stack networks carry one config per address family
+      for config in &ipam.config {
         if let Some(ref subnet) = config.subnet {
           ipam_rows.push(("Subnet", subnet.clone()));
         }
@@ -143,6 +163,11 @@ impl NetworkDetail {
   }
 
   fn render_section(header: Option<&str>, rows: Vec<(&str, String)>, cx: &App) -> gpui::Div {
+    Self::render_copy_section(header, rows.into_iter().map(|(l, v)| (l, v, None)).collect(), cx)
+  }
+
+  /// Like `render_section`, but a row can carry a copy button.
+  fn render_copy_section(header: Option<&str>, rows: Vec<(&str, String, Option<Button>)>, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
     let mut section = v_flex().gap(px(1.));
@@ -166,13 +191,13 @@ impl NetworkDetail {
         rows
           .into_iter()
           .enumerate()
-          .map(|(i, (label, value))| Self::render_section_row(label, value, i == 0, cx)),
+          .map(|(i, (label, value, copy))| Self::render_section_row(label, value, copy, i == 0, cx)),
       );
 
     section.child(rows_container)
   }
 
-  fn render_section_row(label: &str, value: String, is_first: bool, cx: &App) -> gpui::Div {
+  fn render_section_row(label: &str, value: String, copy: Option<Button>, is_first: bool, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
     let mut row = h_flex()
@@ -188,13 +213,19 @@ impl NetworkDetail {
           .child(label.to_string()),
       )
       .child(
-        div()
-          .text_sm()
-          .text_color(colors.foreground)
-          .max_w(px(250.))
-          .overflow_hidden()
-          .text_ellipsis()
-          .child(value),
+        h_flex()
+          .gap(px(4.))
+          .items_center()
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.foreground)
+              .max_w(px(250.))
+              .overflow_hidden()
+              .text_ellipsis()
+              .child(value),
+          )
+          .children(copy),
       );
 
     if !is_first {
@@ -204,6 +235,11 @@ impl NetworkDetail {
     row
   }
 
+  /// Copy button for `text` when the parent wired `on_copy`.
+  fn copy_button(&self, id: &'static str, text: String) -> Option<Button> {
+    self.on_copy.clone().map(|cb| copy_button(id, text, cb))
+  }
+
   fn render_containers_section(network: &NetworkInfo, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
     let network_id = network.id.clone();
@@ -219,6 +255,10 @@ impl NetworkDetail {
       .collect();
     available.sort_by(|a, b| a.1.cmp(&b.1));
 
+    // Stable row order across re-inspects
+    let mut attached: Vec<_> = network.containers.iter().collect();
+    attached.sort_by(|a, b| a.1.name.cmp(&b.1.name));
+
     let connect_button = {
       let net_id_for_menu = network_id.clone();
       Button::new("network-connect-menu")
@@ -289,7 +329,7 @@ impl NetworkDetail {
                                     .text_xs()
                                     .font_weight(gpui::FontWeight::MEDIUM)
                                     .text_color(colors.muted_foreground)
-                                    .child("IPv4 Address"),
+                                    .child("IP Address"),
                             )
                             .child(div().w(px(80.))),
                     )
@@ -302,9 +342,17 @@ impl NetworkDetail {
                         .child("No containers attached"),
                     ))
                     // Container rows
-                    .children(network.containers.iter().enumerate().map(|(i, (id, container))| {
+                    .children(attached.into_iter().enumerate().map(|(i, (id, container))| {
                         let name = container.name.clone().unwrap_or_else(|| id[..12.min(id.len())].to_string());
-                        let ip = container.ipv4_address.clone().unwrap_or_else(|| "-".to_string());
+                        let ip = container
+                            .ipv4_address
+                            .iter()
+                            .chain(&container.ipv6_address)
+                            .filter(|a| !a.is_empty())
+                            .cloned()
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        let ip = if ip.is_empty() { "-".to_string() } else { ip };
                         let cid = id.clone();
                         let net = network_id.clone();
                         let row_btn_id = ("net-disconnect", i);
@@ -448,7 +496,7 @@ impl NetworkDetail {
           .text_sm()
           .font_weight(gpui::FontWeight::MEDIUM)
           .text_color(colors.foreground)
-          .child("Options"),
+          .child("Driver Options"),
       )
       .child(
         v_flex()
@@ -546,7 +594,7 @@ impl NetworkDetail {
         );
 
     // Content based on active tab
-    let content = Self::render_info_tab(network, cx);
+    let content = self.render_info_tab(network, cx);
 
     div()
       .size_full()
@@ -14,8 +14,10 @@ use gpui_component::{
 use crate::assets::AppIcon;
 use crate::docker::NetworkInfo;
 use crate::services;
-use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_error, render_loading};
+use crate::state::{CurrentView, DockerState, LoadState, Selection, StateChanged, docker_state};
+use crate::ui::components::{
+  CachedListDelegate, RefreshStatus, refresh_rows, render_docker_context_selector, render_error, render_loading,
+};
 
 /// Network list events emitted to parent
 pub enum NetworkListEvent {
@@ -87,6 +89,37 @@ impl NetworkListDelegate {
   }
 }
 
+impl CachedListDelegate for NetworkListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rebuild_sections(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.total_filtered_count()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self.sections.iter().enumerate().find_map(|(section, (_, rows))| {
+      rows
+        .iter()
+        .position(|network| matches!(global_selection, Selection::Network(id) if *id == network.id))
+        .map(|row| IndexPath::new(row).section(section))
+    })
+  }
+
+  /// Section headers are counted as one row each.
+  fn flat_row(&self, ix: IndexPath) -> usize {
+    let above: usize = self
+      .sections
+      .iter()
+      .take(ix.section)
+      .map(|(_, rows)| rows.len() + 1)
+      .sum();
+    above + ix.row + 1
+  }
+}
+
 impl ListDelegate for NetworkListDelegate {
   type Item = ListItem;
 
@@ -238,6 +271,7 @@ impl ListDelegate for NetworkListDelegate {
 /// Self-contained network list component
 pub struct NetworkList {
   docker_state: Entity<DockerState>,
+  refresh_status: Entity<RefreshStatus>,
   list_state: Entity<ListState<NetworkListDelegate>>,
   search_input: Option<Entity<InputState>>,
   search_visible: bool,
@@ -272,19 +306,23 @@ impl NetworkList {
     .detach();
 
     // Subscribe to docker state changes to refresh list
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(event, StateChanged::NetworksUpdated | StateChanged::SelectionChanged) {
-        this.list_state.update(cx, |state, cx| {
-          state.delegate_mut().rebuild_sections(cx);
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(event, StateChanged::NetworksUpdated | StateChanged::SelectionChanged) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
           cx.notify();
-        });
-        cx.notify();
-      }
-    })
+        } else if matches!(event, StateChanged::DockerContextsUpdated) {
+          cx.notify();
+        }
+      },
+    )
     .detach();
 
     Self {
       docker_state,
+      refresh_status: cx.new(|cx| RefreshStatus::new(CurrentView::Networks, cx)),
       list_state,
       search_input: None,
       search_visible: false,
@@ -486,14 +524,19 @@ impl Render for NetworkList {
       .justify_between()
       .flex_shrink_0()
       .child(
-        v_flex()
-          .child(Label::new("Networks"))
-          .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle)),
+        v_flex().child(Label::new("Networks")).child(
+          h_flex()
+            .items_center()
+            .gap(px(8.))
+            .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle))
+            .child(self.refresh_status.clone()),
+        ),
       )
       .child(
         h_flex()
           .items_center()
           .gap(px(8.))
+          .child(render_docker_context_selector(cx))
           .child(
             Button::new("search")
               .icon(Icon::new(AppIcon::Search))
@@ -2,7 +2,9 @@ use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::theme::ActiveTheme;
 
 use crate::docker::NetworkInfo;
+use crate::services::{self, RefreshInterval};
 use crate::state::{DockerState, Selection, StateChanged, docker_state};
+use crate::ui::components::copy_to_clipboard;
 
 use super::detail::NetworkDetail;
 use super::list::{NetworkList, NetworkListEvent};
@@ -11,15 +13,22 @@ use super::list::{NetworkList, NetworkListEvent};
 pub struct NetworksView {
   docker_state: Entity<DockerState>,
   network_list: Entity<NetworkList>,
+  /// `docker network inspect` result for the selection (adds attached
+  /// containers, which the list endpoint omits)
+  inspected: Option<NetworkInfo>,
   // View-specific state (not selection - that's in global DockerState)
   active_tab: usize,
 }
 
 impl NetworksView {
-  /// Get the currently selected network from global state
+  /// Get the currently selected network from global state, preferring the
+  /// inspected copy once it has loaded
   fn selected_network(&self, cx: &App) -> Option<NetworkInfo> {
     let state = self.docker_state.read(cx);
     if let Selection::Network(ref id) = state.selection {
+      if let Some(inspected) = self.inspected.as_ref().filter(|n| n.id == *id) {
+        return Some(inspected.clone());
+      }
       state.networks.iter().find(|n| n.id == *id).cloned()
     } else {
       None
@@ -45,8 +54,8 @@ impl NetworksView {
     .detach();
 
     // Subscribe to state changes
-    cx.subscribe(&docker_state, |this, state, event: &StateChanged, cx| {
-      if let StateChanged::NetworksUpdated = event {
+    cx.subscribe(&docker_state, |this, state, event: &StateChanged, cx| match event {
+      StateChanged::NetworksUpdated => {
         // If selected network was deleted, clear selection
         let selected_id = {
           if let Selection::Network(ref id) = this.docker_state.read(cx).selection {
@@ -57,25 +66,46 @@ impl NetworksView {
         };
 
         if let Some(id) = selected_id {
-          let ds = state.read(cx);
-          if ds.networks.iter().any(|n| n.id == id) {
-            // Network still exists, nothing to update (selection stores ID, not full data)
+          if state.read(cx).networks.iter().any(|n| n.id == id) {
+            // Still there: re-inspect so connects/disconnects show up
+            services::inspect_network(id, cx);
           } else {
             // Network was deleted
             this.docker_state.update(cx, |s, _| {
               s.set_selection(Selection::None);
             });
+            this.inspected = None;
             this.active_tab = 0;
           }
         }
         cx.notify();
       }
+      // Selected from elsewhere (global search, favorites)
+      StateChanged::SelectionChanged => {
+        let selection = this.docker_state.read(cx).selection.clone();
+        if let Selection::Network(id) = selection
+          && this.inspected.as_ref().is_none_or(|n| n.id != id)
+        {
+          services::inspect_network(id, cx);
+        }
+      }
+      StateChanged::NetworkInspectLoaded { network_id, network } => {
+        if matches!(this.docker_state.read(cx).selection, Selection::Network(ref id) if id == network_id) {
+          this.inspected = Some(network.clone());
+          cx.notify();
+        }
+      }
+      _ => {}
     })
     .detach();
 
+    // Periodic refresh for daemons without a reliable event stream
+    services::spawn_auto_refresh(RefreshInterval::Lists, 1, cx, |_, cx| services::refresh_networks(cx));
+
     Self {
       docker_state,
       network_list,
+      inspected: None,
       active_tab: 0,
     }
   }
@@ -93,7 +123,9 @@ impl NetworksView {
     });
 
     // Reset view-specific state
+    self.inspected = None;
     self.active_tab = 0;
+    services::inspect_network(network.id.clone(), cx);
 
     cx.notify();
   }
@@ -117,7 +149,8 @@ impl Render for NetworksView {
       .active_tab(active_tab)
       .on_tab_change(cx.listener(|this, tab: &usize, _window, cx| {
         this.on_tab_change(*tab, cx);
-      }));
+      }))
+      .on_copy(copy_to_clipboard);
 
     div()
       .size_full()
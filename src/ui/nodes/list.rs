@@ -15,7 +15,7 @@ use crate::assets::AppIcon;
 use crate::kubernetes::NodeInfo;
 use crate::services;
 use crate::state::{DockerState, FavoriteRef, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_k8s_error, render_loading};
+use crate::ui::components::{CachedListDelegate, refresh_rows, render_k8s_error, render_loading};
 
 pub enum NodeListEvent {
   Selected(NodeInfo),
@@ -24,10 +24,11 @@ pub enum NodeListEvent {
 pub struct NodeListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<NodeInfo>,
 }
 
 impl NodeListDelegate {
-  fn filtered_nodes(&self, cx: &App) -> Vec<NodeInfo> {
+  fn collect_rows(&self, cx: &App) -> Vec<NodeInfo> {
     let nodes = self.docker_state.read(cx).nodes.clone();
     if self.search_query.is_empty() {
       return nodes;
@@ -39,16 +40,40 @@ impl NodeListDelegate {
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered_nodes(&self) -> &[NodeInfo] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for NodeListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|node| matches!(global_selection, Selection::Node(name) if *name == node.name))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for NodeListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered_nodes(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered_nodes().len()
   }
 
   fn render_item(
@@ -57,7 +82,7 @@ impl ListDelegate for NodeListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let nodes = self.filtered_nodes(cx);
+    let nodes = self.filtered_nodes();
     let node = nodes.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -233,13 +258,15 @@ impl NodeList {
     let delegate = NodeListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered_nodes(cx);
+        let filtered = delegate.filtered_nodes();
         if let Some(node) = filtered.get(ix.row) {
           cx.emit(NodeListEvent::Selected(node.clone()));
         }
@@ -248,15 +275,19 @@ impl NodeList {
     })
     .detach();
 
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(
-        event,
-        StateChanged::NodesUpdated | StateChanged::SelectionChanged | StateChanged::KubeContextSwitched
-      ) {
-        this.list_state.update(cx, |_state, cx| cx.notify());
-        cx.notify();
-      }
-    })
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(
+          event,
+          StateChanged::NodesUpdated | StateChanged::SelectionChanged | StateChanged::KubeContextSwitched
+        ) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
+          cx.notify();
+        }
+      },
+    )
     .detach();
 
     Self {
@@ -281,7 +312,7 @@ impl NodeList {
       if current_text != self.search_query {
         current_text.clone_into(&mut self.search_query);
         self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+          state.delegate_mut().set_search_query(current_text, cx);
           cx.notify();
         });
       }
@@ -296,7 +327,7 @@ impl NodeList {
       self.search_query.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -350,7 +381,7 @@ impl Render for NodeList {
     let total = state.nodes.len();
     let nodes_state = state.nodes_state.clone();
 
-    let filtered = self.list_state.read(cx).delegate().filtered_nodes(cx).len();
+    let filtered = self.list_state.read(cx).delegate().filtered_nodes().len();
     let is_filtering = !self.search_query.is_empty();
 
     let subtitle = match &nodes_state {
@@ -1,13 +1,11 @@
-use std::time::Duration;
-
-use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::theme::ActiveTheme;
 
 use super::detail::NodeDetail;
 use super::list::{NodeList, NodeListEvent};
 use crate::kubernetes::NodeInfo;
-use crate::services;
-use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+use crate::services::{self, RefreshInterval};
+use crate::state::{DockerState, Selection, StateChanged, docker_state};
 
 /// Nodes view: list + detail split, hosted in the Cluster view's Nodes tab.
 pub struct NodesView {
@@ -80,19 +78,12 @@ impl NodesView {
     )
     .detach();
 
-    let refresh = settings_state(cx).read(cx).settings.container_refresh_interval.max(5);
-    cx.spawn(async move |_this, cx| {
-      loop {
-        Timer::after(Duration::from_secs(refresh)).await;
-        let _ = cx.update(|cx| {
-          services::refresh_nodes(cx);
-          services::refresh_pods(cx);
-          services::refresh_events(cx);
-          services::refresh_node_metrics(cx);
-        });
-      }
-    })
-    .detach();
+    services::spawn_auto_refresh(RefreshInterval::Lists, 5, cx, |_, cx| {
+      services::refresh_nodes(cx);
+      services::refresh_pods(cx);
+      services::refresh_events(cx);
+      services::refresh_node_metrics(cx);
+    });
 
     services::refresh_nodes(cx);
     services::refresh_pods(cx);
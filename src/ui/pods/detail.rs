@@ -1,7 +1,10 @@
 use gpui::{App, Entity, Styled, Window, div, prelude::*, px};
 use gpui_component::{
-  Icon, Selectable, h_flex,
+  Disableable, Icon, IconName, Selectable, Sizable,
+  button::{Button, ButtonVariants},
+  h_flex,
   input::{Input, InputState},
+  menu::{DropdownMenu, PopupMenuItem},
   scroll::ScrollableElement,
   tab::{Tab, TabBar},
   theme::ActiveTheme,
@@ -10,8 +13,13 @@ use gpui_component::{
 use std::rc::Rc;
 
 use crate::assets::AppIcon;
-use crate::kubernetes::{PodInfo, PodPhase};
-use crate::terminal::TerminalView;
+use crate::kubernetes::{PodInfo, PodPhase, format_millicores};
+use crate::services::{self, PortForward};
+use crate::state::docker_state;
+use crate::terminal::{EXEC_SHELLS, TerminalView};
+use crate::ui::components::{ResourceEvents, render_event_list};
+
+use super::port_forward_dialog::open_port_forward_dialog;
 
 // Re-export from state module for backwards compatibility
 pub use crate::state::PodDetailTab;
@@ -19,6 +27,7 @@ pub use crate::state::PodDetailTab;
 type TabChangeCallback = Rc<dyn Fn(&PodDetailTab, &mut Window, &mut App) + 'static>;
 type RefreshCallback = Rc<dyn Fn(&(), &mut Window, &mut App) + 'static>;
 type ContainerSelectCallback = Rc<dyn Fn(&String, &mut Window, &mut App) + 'static>;
+type ShellChangeCallback = Rc<dyn Fn(&Option<String>, &mut Window, &mut App) + 'static>;
 
 /// State for pod detail tabs
 #[derive(Debug, Clone, Default)]
@@ -30,11 +39,21 @@ pub struct PodTabState {
   pub yaml: String,
   pub yaml_loading: bool,
   pub selected_container: Option<String>,
+  /// Shell the Terminal tab execs; `None` picks the first one found.
+  pub terminal_shell: Option<String>,
+  pub events: ResourceEvents,
+  /// Keep the log stream open (`kubectl logs -f`); off = paused.
+  pub logs_follow: bool,
+  /// Show the previous container instance's logs (after a restart).
+  pub logs_previous: bool,
 }
 
 impl PodTabState {
   pub fn new() -> Self {
-    Self::default()
+    Self {
+      logs_follow: true,
+      ..Self::default()
+    }
   }
 }
 
@@ -49,7 +68,12 @@ pub struct PodDetail {
   yaml_editor: Option<Entity<InputState>>,
   on_tab_change: Option<TabChangeCallback>,
   on_refresh_logs: Option<RefreshCallback>,
+  on_toggle_logs_follow: Option<RefreshCallback>,
+  on_toggle_logs_previous: Option<RefreshCallback>,
+  on_save_logs: Option<RefreshCallback>,
+  on_refresh_events: Option<RefreshCallback>,
   on_container_select: Option<ContainerSelectCallback>,
+  on_shell_change: Option<ShellChangeCallback>,
 }
 
 impl PodDetail {
@@ -65,7 +89,12 @@ impl PodDetail {
       yaml_editor: None,
       on_tab_change: None,
       on_refresh_logs: None,
+      on_toggle_logs_follow: None,
+      on_toggle_logs_previous: None,
+      on_save_logs: None,
+      on_refresh_events: None,
       on_container_select: None,
+      on_shell_change: None,
     }
   }
 
@@ -125,6 +154,38 @@ impl PodDetail {
     self
   }
 
+  pub fn on_toggle_logs_follow<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_toggle_logs_follow = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_save_logs<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_save_logs = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_refresh_events<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_refresh_events = Some(Rc::new(callback));
+    self
+  }
+
+  pub fn on_toggle_logs_previous<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&(), &mut Window, &mut App) + 'static,
+  {
+    self.on_toggle_logs_previous = Some(Rc::new(callback));
+    self
+  }
+
   pub fn on_container_select<F>(mut self, callback: F) -> Self
   where
     F: Fn(&String, &mut Window, &mut App) + 'static,
@@ -133,6 +194,14 @@ impl PodDetail {
     self
   }
 
+  pub fn on_shell_change<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&Option<String>, &mut Window, &mut App) + 'static,
+  {
+    self.on_shell_change = Some(Rc::new(callback));
+    self
+  }
+
   fn render_empty(cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -178,6 +247,16 @@ impl PodDetail {
         .child(div().text_sm().text_color(colors.foreground).child(value))
     };
 
+    // Live usage from metrics-server; placeholders until the first probe
+    // answers, and a plain note when the cluster has no metrics API.
+    let state_entity = docker_state(cx);
+    let state = state_entity.read(cx);
+    let (cpu, memory) = match state.pod_usage(&pod.namespace, &pod.name) {
+      Some((millicores, bytes)) => (format_millicores(millicores), bytesize::ByteSize(bytes).to_string()),
+      None if state.pod_metrics_unavailable() => ("Metrics unavailable".to_string(), "Metrics unavailable".to_string()),
+      None => ("—".to_string(), "—".to_string()),
+    };
+
     let status_text = pod.phase.to_string();
     let status_color = match pod.phase {
       PodPhase::Running => colors.success,
@@ -226,6 +305,8 @@ impl PodDetail {
             )
             .child(info_row("Ready", pod.ready.clone()))
             .child(info_row("Restarts", pod.restarts.to_string()))
+            .child(info_row("CPU", cpu))
+            .child(info_row("Memory", memory))
             .child(info_row("Age", pod.age.clone()))
             .child(info_row(
                 "Node",
@@ -295,45 +376,288 @@ impl PodDetail {
                             .child(format!("Restarts: {}", container.restart_count)),
                     )
             }))
+            .child(Self::render_port_forwards(pod, cx))
   }
 
-  fn render_logs_tab(&self, _pod: &PodInfo, cx: &App) -> gpui::Div {
+  fn render_port_forwards(pod: &PodInfo, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
-    let state = self.pod_state.as_ref();
-    let is_loading = state.is_some_and(|s| s.logs_loading);
+    let manager = services::port_forward_manager(cx);
+    let forwards: Vec<PortForward> = manager.read(cx).for_pod(&pod.name, &pod.namespace).cloned().collect();
 
-    if is_loading && self.logs_terminal.is_none() {
-      return v_flex().size_full().p(px(16.)).child(
+    let pod_name = pod.name.clone();
+    let namespace = pod.namespace.clone();
+    let header = h_flex()
+      .w_full()
+      .pt(px(16.))
+      .pb(px(8.))
+      .justify_between()
+      .items_center()
+      .child(
         div()
           .text_sm()
-          .text_color(colors.muted_foreground)
-          .child("Loading logs..."),
+          .font_weight(gpui::FontWeight::SEMIBOLD)
+          .text_color(colors.foreground)
+          .child("Port Forwards"),
+      )
+      .child(
+        Button::new("pod-port-forward")
+          .icon(Icon::new(AppIcon::Network))
+          .label("Forward Port...")
+          .ghost()
+          .xsmall()
+          .on_click(move |_ev, window, cx| {
+            open_port_forward_dialog(pod_name.clone(), namespace.clone(), window, cx);
+          }),
       );
-    }
 
-    if let Some(view) = self.logs_terminal.clone() {
-      return div().size_full().child(div().size_full().min_h_0().child(view));
-    }
+    let body = if forwards.is_empty() {
+      v_flex().child(
+        div()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child("No active port forwards for this pod."),
+      )
+    } else {
+      v_flex().gap(px(8.)).children(forwards.into_iter().map(|forward| {
+        let id = forward.id;
+        h_flex()
+          .w_full()
+          .py(px(8.))
+          .px(px(12.))
+          .rounded(px(6.))
+          .border_1()
+          .border_color(colors.border)
+          .gap(px(12.))
+          .items_center()
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .child(format!("{} → {}", forward.local_address(), forward.remote_port)),
+          )
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(format!("since {}", forward.started_at.format("%H:%M:%S"))),
+          )
+          .child(
+            Button::new(("stop-port-forward", id))
+              .label("Stop")
+              .ghost()
+              .xsmall()
+              .on_click(move |_ev, _window, cx| {
+                services::stop_port_forward(id, cx);
+              }),
+          )
+      }))
+    };
 
-    if let Some(ref editor) = self.logs_editor {
-      return div()
-        .size_full()
-        .child(Input::new(editor).size_full().appearance(false).disabled(true));
-    }
+    v_flex().w_full().child(header).child(body)
+  }
 
-    // Fallback to plain text
-    let logs_content = state.map_or_else(|| "No logs available".to_string(), |s| s.logs.clone());
-    div().size_full().child(
+  fn render_logs_toolbar(&self, pod: &PodInfo, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let state = self.pod_state.as_ref();
+    let follow_on = state.is_some_and(|s| s.logs_follow);
+    let previous_on = state.is_some_and(|s| s.logs_previous);
+    let selected = state.and_then(|s| s.selected_container.clone());
+    let has_restarted = pod
+      .containers
+      .iter()
+      .any(|c| c.restart_count > 0 && selected.as_ref().is_none_or(|name| *name == c.name));
+
+    let on_container_select = self.on_container_select.clone();
+    let toggle_follow = self.on_toggle_logs_follow.clone();
+    let toggle_previous = self.on_toggle_logs_previous.clone();
+    let refresh = self.on_refresh_logs.clone();
+    let save = self.on_save_logs.clone();
+    let containers: Vec<String> = pod.containers.iter().map(|c| c.name.clone()).collect();
+
+    h_flex()
+      .gap(px(8.))
+      .px(px(8.))
+      .py(px(6.))
+      .border_b_1()
+      .border_color(colors.border)
+      .when(containers.len() > 1, |el| {
+        el.child(
+          Button::new("pod-logs-container")
+            .label(selected.clone().unwrap_or_else(|| "Container".to_string()))
+            .icon(IconName::ChevronDown)
+            .small()
+            .ghost()
+            .dropdown_menu(move |mut menu, _window, _cx| {
+              for name in &containers {
+                let on_container_select = on_container_select.clone();
+                let name = name.clone();
+                menu = menu.item(PopupMenuItem::new(name.clone()).on_click(move |_, window, cx| {
+                  if let Some(ref cb) = on_container_select {
+                    cb(&name, window, cx);
+                  }
+                }));
+              }
+              menu
+            }),
+        )
+      })
+      .child(
+        Button::new("pod-logs-follow")
+          .label(if follow_on { "Following" } else { "Paused" })
+          .icon(if follow_on {
+            Icon::new(AppIcon::Pause)
+          } else {
+            Icon::new(AppIcon::Play)
+          })
+          .small()
+          .disabled(previous_on)
+          .when_some(toggle_follow, |b, cb| {
+            b.on_click(move |_ev, window, cx| {
+              cb(&(), window, cx);
+            })
+          }),
+      )
+      .when(has_restarted || previous_on, |el| {
+        el.child(
+          Button::new("pod-logs-previous")
+            .label("Previous logs")
+            .small()
+            .ghost()
+            .selected(previous_on)
+            .when_some(toggle_previous, |b, cb| {
+              b.on_click(move |_ev, window, cx| {
+                cb(&(), window, cx);
+              })
+            }),
+        )
+      })
+      .child(
+        Button::new("pod-logs-refresh")
+          .icon(Icon::new(AppIcon::Refresh))
+          .small()
+          .ghost()
+          .when_some(refresh, |b, cb| {
+            b.on_click(move |_ev, window, cx| {
+              cb(&(), window, cx);
+            })
+          }),
+      )
+      .child(
+        Button::new("pod-logs-save")
+          .label(if follow_on { "Save captured" } else { "Save logs" })
+          .small()
+          .ghost()
+          .when_some(save, |b, cb| {
+            b.on_click(move |_ev, window, cx| {
+              cb(&(), window, cx);
+            })
+          }),
+      )
+  }
+
+  fn render_logs_tab(&self, pod: &PodInfo, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let state = self.pod_state.as_ref();
+    let is_loading = state.is_some_and(|s| s.logs_loading);
+    let toolbar = self.render_logs_toolbar(pod, cx);
+
+    let body = if is_loading && self.logs_terminal.is_none() {
+      v_flex().size_full().p(px(16.)).child(
+        div()
+          .text_sm()
+          .text_color(colors.muted_foreground)
+          .child("Loading logs..."),
+      )
+    } else if let Some(view) = self.logs_terminal.clone() {
+      div().size_full().child(div().size_full().min_h_0().child(view))
+    } else if let Some(ref editor) = self.logs_editor {
       div()
         .size_full()
-        .overflow_y_scrollbar()
-        .bg(colors.sidebar)
-        .p(px(12.))
-        .font_family("monospace")
-        .text_xs()
-        .text_color(colors.foreground)
-        .child(logs_content),
-    )
+        .child(Input::new(editor).size_full().appearance(false).disabled(true))
+    } else {
+      // Fallback to plain text
+      let logs_content = state.map_or_else(|| "No logs available".to_string(), |s| s.logs.clone());
+      div().size_full().child(
+        div()
+          .size_full()
+          .overflow_y_scrollbar()
+          .bg(colors.sidebar)
+          .p(px(12.))
+          .font_family("monospace")
+          .text_xs()
+          .text_color(colors.foreground)
+          .child(logs_content),
+      )
+    };
+
+    v_flex()
+      .size_full()
+      .child(toolbar)
+      .child(div().flex_1().min_h_0().w_full().child(body))
+  }
+
+  fn render_terminal_toolbar(&self, pod: &PodInfo, cx: &App) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let state = self.pod_state.as_ref();
+    let selected = state.and_then(|s| s.selected_container.clone());
+    let shell = state
+      .and_then(|s| s.terminal_shell.clone())
+      .unwrap_or_else(|| "Auto".to_string());
+    let on_container_select = self.on_container_select.clone();
+    let on_shell_change = self.on_shell_change.clone();
+    let containers: Vec<String> = pod.containers.iter().map(|c| c.name.clone()).collect();
+
+    h_flex()
+      .gap(px(8.))
+      .px(px(8.))
+      .py(px(6.))
+      .items_center()
+      .border_b_1()
+      .border_color(colors.border)
+      .when(containers.len() > 1, |el| {
+        el.child(div().text_xs().text_color(colors.muted_foreground).child("Container"))
+          .child(
+            Button::new("pod-terminal-container")
+              .label(selected.unwrap_or_else(|| "Container".to_string()))
+              .icon(IconName::ChevronDown)
+              .small()
+              .ghost()
+              .dropdown_menu(move |mut menu, _window, _cx| {
+                for name in &containers {
+                  let on_container_select = on_container_select.clone();
+                  let name = name.clone();
+                  menu = menu.item(PopupMenuItem::new(name.clone()).on_click(move |_, window, cx| {
+                    if let Some(ref cb) = on_container_select {
+                      cb(&name, window, cx);
+                    }
+                  }));
+                }
+                menu
+              }),
+          )
+      })
+      .child(div().text_xs().text_color(colors.muted_foreground).child("Shell"))
+      .child(
+        Button::new("pod-terminal-shell")
+          .label(shell)
+          .icon(IconName::ChevronDown)
+          .small()
+          .ghost()
+          .dropdown_menu(move |mut menu, _window, _cx| {
+            for (label, shell) in EXEC_SHELLS {
+              let on_shell_change = on_shell_change.clone();
+              let shell = shell.map(String::from);
+              menu = menu.item(PopupMenuItem::new(label).on_click(move |_, window, cx| {
+                if let Some(ref cb) = on_shell_change {
+                  cb(&shell, window, cx);
+                }
+              }));
+            }
+            menu
+          }),
+      )
   }
 
   fn render_terminal_tab(&self, pod: &PodInfo, cx: &App) -> gpui::Div {
@@ -360,9 +684,12 @@ impl PodDetail {
         );
     }
 
-    // If we have a terminal view, render it full size
+    // If we have a terminal view, render it full size below the container/shell pickers
     if let Some(terminal) = &self.terminal_view {
-      return div().size_full().flex_1().min_h_0().p(px(8.)).child(terminal.clone());
+      return v_flex()
+        .size_full()
+        .child(self.render_terminal_toolbar(pod, cx))
+        .child(div().flex_1().min_h_0().w_full().p(px(8.)).child(terminal.clone()));
     }
 
     let colors = &cx.theme().colors;
@@ -388,6 +715,21 @@ impl PodDetail {
       )
   }
 
+  fn render_events_tab(&self, cx: &App) -> gpui::Div {
+    let events = self.pod_state.as_ref().map(|s| s.events.clone()).unwrap_or_default();
+    let refresh = self.on_refresh_events.clone();
+    render_event_list(
+      "pod",
+      &events,
+      move |window, cx| {
+        if let Some(ref cb) = refresh {
+          cb(&(), window, cx);
+        }
+      },
+      cx,
+    )
+  }
+
   fn render_describe_tab(&self, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
     let state = self.pod_state.as_ref();
@@ -421,7 +763,7 @@ impl PodDetail {
     )
   }
 
-  fn render_yaml_tab(&self, cx: &App) -> gpui::Div {
+  fn render_yaml_tab(&self, pod: &PodInfo, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
     let state = self.pod_state.as_ref();
     let is_loading = state.is_some_and(|s| s.yaml_loading);
@@ -434,9 +776,70 @@ impl PodDetail {
     }
 
     if let Some(ref editor) = self.yaml_editor {
-      return div()
-        .size_full()
-        .child(Input::new(editor).size_full().appearance(false).disabled(true));
+      let original = state.map(|s| s.yaml.clone()).unwrap_or_default();
+      let apply_editor = editor.clone();
+      let apply_name = pod.name.clone();
+      let apply_namespace = pod.namespace.clone();
+      let reload_name = pod.name.clone();
+      let reload_namespace = pod.namespace.clone();
+
+      let toolbar = h_flex()
+        .w_full()
+        .px(px(12.))
+        .py(px(6.))
+        .gap(px(6.))
+        .items_center()
+        .justify_between()
+        .border_b_1()
+        .border_color(colors.border)
+        .child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child("Only image, tolerations and a few other pod fields can change in place."),
+        )
+        .child(
+          h_flex()
+            .gap(px(6.))
+            .child(
+              Button::new("pod-yaml-reload")
+                .label("Reload")
+                .ghost()
+                .xsmall()
+                .on_click(move |_, _, cx| {
+                  services::get_pod_yaml(reload_name.clone(), reload_namespace.clone(), cx);
+                }),
+            )
+            .child(
+              Button::new("pod-yaml-apply")
+                .label("Apply")
+                .primary()
+                .xsmall()
+                .on_click(move |_, window, cx| {
+                  let yaml = apply_editor.read(cx).text().to_string();
+                  if yaml.trim().is_empty() {
+                    return;
+                  }
+                  let name = apply_name.clone();
+                  let namespace = apply_namespace.clone();
+                  crate::ui::dialogs::confirm_yaml_apply(
+                    "Pod",
+                    &original,
+                    yaml,
+                    move |yaml, cx| services::apply_pod_yaml(name.clone(), namespace.clone(), yaml, cx),
+                    window,
+                    cx,
+                  );
+                }),
+            ),
+        );
+
+      return v_flex().size_full().child(toolbar).child(
+        div()
+          .flex_1()
+          .min_h_0()
+          .child(Input::new(editor).size_full().appearance(false)),
+      );
     }
 
     // Fallback to plain text
@@ -491,12 +894,16 @@ impl PodDetail {
       PodDetailTab::Logs => self.render_logs_tab(pod, cx),
       PodDetailTab::Terminal => self.render_terminal_tab(pod, cx),
       PodDetailTab::Describe => self.render_describe_tab(cx),
-      PodDetailTab::Yaml => self.render_yaml_tab(cx),
+      PodDetailTab::Events => self.render_events_tab(cx),
+      PodDetailTab::Yaml => self.render_yaml_tab(pod, cx),
       PodDetailTab::Info => Self::render_info_tab(pod, cx),
     };
 
-    // Terminal tab needs full height without scroll
-    let is_terminal_tab = self.active_tab == PodDetailTab::Terminal;
+    // Terminal, log and YAML editor tabs need full height without scroll
+    let is_terminal_tab = matches!(
+      self.active_tab,
+      PodDetailTab::Terminal | PodDetailTab::Logs | PodDetailTab::Events | PodDetailTab::Yaml
+    );
 
     let mut result = div()
       .size_full()
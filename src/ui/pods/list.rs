@@ -12,10 +12,12 @@ use gpui_component::{
 };
 
 use crate::assets::AppIcon;
-use crate::kubernetes::{PodInfo, PodPhase};
+use crate::kubernetes::{PodInfo, PodPhase, format_millicores};
 use crate::services;
 use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_k8s_error, render_loading};
+use crate::ui::components::{
+  CachedListDelegate, SearchDebounce, labels_match, refresh_rows, render_k8s_error, render_loading,
+};
 
 /// Pod list events emitted to parent
 pub enum PodListEvent {
@@ -26,6 +28,7 @@ pub enum PodListEvent {
 pub struct PodListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<PodInfo>,
 }
 
 impl PodListDelegate {
@@ -43,7 +46,7 @@ impl PodListDelegate {
     }
   }
 
-  fn filtered_pods(&self, cx: &App) -> Vec<PodInfo> {
+  fn collect_rows(&self, cx: &App) -> Vec<PodInfo> {
     let pods = self.pods(cx);
     if self.search_query.is_empty() {
       return pods;
@@ -56,21 +59,47 @@ impl PodListDelegate {
         p.name.to_lowercase().contains(&query)
           || p.namespace.to_lowercase().contains(&query)
           || p.phase.to_string().to_lowercase().contains(&query)
+          || p.containers.iter().any(|c| c.image.to_lowercase().contains(&query))
+          || labels_match(&p.labels, &query)
       })
       .cloned()
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered_pods(&self) -> &[PodInfo] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for PodListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|pod| matches!(global_selection, Selection::Pod { name, namespace } if *name == pod.name && *namespace == pod.namespace))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for PodListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered_pods(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered_pods().len()
   }
 
   fn render_item(
@@ -79,7 +108,7 @@ impl ListDelegate for PodListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let pods = self.filtered_pods(cx);
+    let pods = self.filtered_pods();
     let pod = pods.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -108,7 +137,16 @@ impl ListDelegate for PodListDelegate {
       _ => colors.muted_foreground,
     };
 
-    let subtitle = format!("{} - {}", pod.namespace, pod.ready);
+    let subtitle = match self.docker_state.read(cx).pod_usage(&pod.namespace, &pod.name) {
+      Some((millicores, bytes)) => format!(
+        "{} - {} - {} CPU, {}",
+        pod.namespace,
+        pod.ready,
+        format_millicores(millicores),
+        bytesize::ByteSize(bytes)
+      ),
+      None => format!("{} - {}", pod.namespace, pod.ready),
+    };
     let status_color = icon_bg;
 
     // Three-dot menu button
@@ -174,6 +212,15 @@ impl ListDelegate for PodListDelegate {
                 }
               }),
           )
+          .item(PopupMenuItem::new("Wait Until Ready").icon(IconName::Bell).on_click({
+            let target = services::WaitTarget::Pod {
+              name: name.clone(),
+              namespace: ns.clone(),
+            };
+            move |_, _, cx| {
+              services::wait_for(target.clone(), services::WaitCondition::Ready, cx);
+            }
+          }))
           .separator()
           .item(
             PopupMenuItem::new(if pinned {
@@ -297,7 +344,7 @@ pub struct PodList {
   list_state: Entity<ListState<PodListDelegate>>,
   search_input: Option<Entity<InputState>>,
   search_visible: bool,
-  search_query: String,
+  search: SearchDebounce,
 }
 
 impl PodList {
@@ -307,15 +354,17 @@ impl PodList {
     let delegate = PodListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
 
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     // Subscribe to list events
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered_pods(cx);
+        let filtered = delegate.filtered_pods();
         if let Some(pod) = filtered.get(ix.row) {
           cx.emit(PodListEvent::Selected(pod.clone()));
         }
@@ -325,20 +374,23 @@ impl PodList {
     .detach();
 
     // Subscribe to docker state changes to refresh list
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(
-        event,
-        StateChanged::PodsUpdated
-          | StateChanged::NamespacesUpdated
-          | StateChanged::MachinesUpdated
-          | StateChanged::SelectionChanged
-      ) {
-        this.list_state.update(cx, |_state, cx| {
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(
+          event,
+          StateChanged::PodsUpdated
+            | StateChanged::PodMetricsUpdated
+            | StateChanged::NamespacesUpdated
+            | StateChanged::MachinesUpdated
+            | StateChanged::SelectionChanged
+        ) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
           cx.notify();
-        });
-        cx.notify();
-      }
-    })
+        }
+      },
+    )
     .detach();
 
     Self {
@@ -346,28 +398,31 @@ impl PodList {
       list_state,
       search_input: None,
       search_visible: false,
-      search_query: String::new(),
+      search: SearchDebounce::default(),
     }
   }
 
   fn ensure_search_input(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
     if self.search_input.is_none() {
-      let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Search pods..."));
+      let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Filter by name, image or label..."));
       self.search_input = Some(input_state);
     }
   }
 
   fn sync_search_query(&mut self, cx: &mut Context<'_, Self>) {
-    if let Some(input) = &self.search_input {
-      let current_text = input.read(cx).text().to_string();
-      if current_text != self.search_query {
-        current_text.clone_into(&mut self.search_query);
-        self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+    let Some(input) = &self.search_input else { return };
+    let current_text = input.read(cx).text().to_string();
+    self.search.sync(
+      current_text,
+      |this: &mut Self| &mut this.search,
+      cx,
+      |this, query, cx| {
+        this.list_state.update(cx, |state, cx| {
+          state.delegate_mut().set_search_query(query, cx);
           cx.notify();
         });
-      }
-    }
+      },
+    );
   }
 
   fn toggle_search(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
@@ -376,10 +431,10 @@ impl PodList {
       self.ensure_search_input(window, cx);
     } else {
       // Clear search when hiding
-      self.search_query.clear();
+      self.search.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -560,7 +615,7 @@ impl PodList {
         div()
           .text_sm()
           .text_color(colors.muted_foreground)
-          .child(format!("No pods match \"{}\"", self.search_query)),
+          .child(format!("No pods match \"{}\"", self.search.query())),
       )
   }
 }
@@ -579,8 +634,8 @@ impl Render for PodList {
       .count();
 
     // Get filtered count
-    let filtered_count = self.list_state.read(cx).delegate().filtered_pods(cx).len();
-    let is_filtering = !self.search_query.is_empty();
+    let filtered_count = self.list_state.read(cx).delegate().filtered_pods().len();
+    let is_filtering = !self.search.query().is_empty();
     let pods_empty = filtered_count == 0;
 
     let subtitle = match &pods_state {
@@ -641,13 +696,22 @@ impl Render for PodList {
               .ghost()
               .compact()
               .dropdown_menu(|menu, _window, _cx| {
-                menu.item(
-                  PopupMenuItem::new("Refresh")
-                    .icon(Icon::new(AppIcon::Refresh))
-                    .on_click(|_, _, cx| {
-                      services::refresh_pods(cx);
-                    }),
-                )
+                menu
+                  .item(
+                    PopupMenuItem::new("Apply YAML...")
+                      .icon(Icon::new(AppIcon::Edit))
+                      .on_click(|_, window, cx| {
+                        crate::ui::dialogs::open_apply_yaml_dialog(window, cx);
+                      }),
+                  )
+                  .separator()
+                  .item(
+                    PopupMenuItem::new("Refresh")
+                      .icon(Icon::new(AppIcon::Refresh))
+                      .on_click(|_, _, cx| {
+                        services::refresh_pods(cx);
+                      }),
+                  )
               }),
           ),
       );
@@ -672,7 +736,7 @@ impl Render for PodList {
           .child(div().flex_1().when_some(self.search_input.clone(), |el, input| {
             el.child(Input::new(&input).small().w_full())
           }))
-          .when(!self.search_query.is_empty(), |el| {
+          .when(!self.search.query().is_empty(), |el| {
             el.child(
               Button::new("clear-search")
                 .icon(IconName::Close)
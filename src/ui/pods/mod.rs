@@ -1,5 +1,6 @@
 mod detail;
 mod list;
+mod port_forward_dialog;
 mod view;
 
 pub use view::PodsView;
@@ -0,0 +1,100 @@
+//! Dialog for starting a `kubectl port-forward` to a pod. The forward is
+//! registered with the port-forward manager, which lists it in the pod's
+//! Info tab and stops it on request or when the app quits.
+
+use gpui::{App, Entity, ParentElement, Styled, Window, div, prelude::*, px};
+use gpui_component::{
+  WindowExt,
+  button::{Button, ButtonVariants},
+  h_flex,
+  input::{Input, InputState},
+  v_flex,
+};
+
+use crate::services;
+use crate::ui::components::form_field;
+
+/// Parse a user-entered port, rejecting 0 and anything outside u16.
+fn parse_port(value: &str) -> Option<u16> {
+  value.trim().parse::<u16>().ok().filter(|p| *p > 0)
+}
+
+pub fn open_port_forward_dialog(pod: String, namespace: String, window: &mut Window, cx: &mut App) {
+  let local_input: Entity<InputState> = cx.new(|cx| InputState::new(window, cx).placeholder("8080"));
+  let remote_input: Entity<InputState> = cx.new(|cx| InputState::new(window, cx).placeholder("80"));
+
+  window.open_dialog(cx, move |dialog, _window, cx| {
+    let local_for_btn = local_input.clone();
+    let remote_for_btn = remote_input.clone();
+    let pod_for_btn = pod.clone();
+    let ns_for_btn = namespace.clone();
+    dialog
+      .title(format!("Forward Port to {pod}"))
+      .min_w(px(420.))
+      .child(
+        v_flex().p(px(16.)).gap(px(12.)).child(
+          h_flex()
+            .gap(px(12.))
+            .child(div().flex_1().child(form_field(
+              "Local port",
+              Input::new(&local_input).w_full(),
+              Some("Port on this machine."),
+              cx,
+            )))
+            .child(div().flex_1().child(form_field(
+              "Pod port",
+              Input::new(&remote_input).w_full(),
+              Some("Port the container listens on."),
+              cx,
+            ))),
+        ),
+      )
+      .footer(move |_dialog_state, _, _window, _cx| {
+        let local = local_for_btn.clone();
+        let remote = remote_for_btn.clone();
+        let pod = pod_for_btn.clone();
+        let namespace = ns_for_btn.clone();
+        vec![
+          Button::new("port-forward-start")
+            .label("Start Forward")
+            .primary()
+            .on_click(move |_ev, window, cx| {
+              let remote_port = parse_port(&remote.read(cx).text().to_string());
+              // An empty local port mirrors the pod port, like `kubectl port-forward pod 80`.
+              let local_text = local.read(cx).text().to_string();
+              let local_port = if local_text.trim().is_empty() {
+                remote_port
+              } else {
+                parse_port(&local_text)
+              };
+              let (Some(local_port), Some(remote_port)) = (local_port, remote_port) else {
+                return;
+              };
+              services::port_forward_pod(pod.clone(), namespace.clone(), local_port, remote_port, cx);
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+          Button::new("port-forward-cancel")
+            .label("Cancel")
+            .ghost()
+            .on_click(|_ev, window, cx| {
+              window.close_dialog(cx);
+            })
+            .into_any_element(),
+        ]
+      })
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_port() {
+    assert_eq!(parse_port(" 8080 "), Some(8080));
+    assert_eq!(parse_port("0"), None);
+    assert_eq!(parse_port("70000"), None);
+    assert_eq!(parse_port("http"), None);
+  }
+}
@@ -1,10 +1,9 @@
-use gpui::{App, Context, Entity, Render, Styled, Task, Timer, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, Styled, Task, Window, div, prelude::*, px};
 use gpui_component::{input::InputState, theme::ActiveTheme};
 use std::sync::Arc;
-use std::time::Duration;
 
 use crate::kubernetes::{PodInfo, PodPhase};
-use crate::services;
+use crate::services::{self, RefreshInterval};
 use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
 use crate::terminal::{LogStream, TerminalSessionType, TerminalView};
 
@@ -54,6 +53,10 @@ impl PodsView {
     // Create pod list entity
     let pod_list = cx.new(|cx| PodList::new(window, cx));
 
+    // Re-render the Info tab's port-forward list as forwards start and stop
+    cx.observe(&services::port_forward_manager(cx), |_, _, cx| cx.notify())
+      .detach();
+
     // Subscribe to pod list events
     cx.subscribe_in(&pod_list, window, |this, _list, event: &PodListEvent, window, cx| {
       let PodListEvent::Selected(pod) = event;
@@ -93,6 +96,7 @@ impl PodsView {
             }
             cx.notify();
           }
+          StateChanged::PodMetricsUpdated => cx.notify(),
           StateChanged::PodDescribeLoaded {
             pod_name,
             namespace,
@@ -107,6 +111,21 @@ impl PodsView {
               cx.notify();
             }
           }
+          StateChanged::ResourceEventsLoaded {
+            kind,
+            name: pod_name,
+            namespace,
+            events,
+            error,
+          } if kind == "Pod" => {
+            if let Selection::Pod { name, namespace: ns } = &this.docker_state.read(cx).selection
+              && name == pod_name
+              && ns == namespace
+            {
+              this.pod_tab_state.events.set(events, error.as_deref());
+              cx.notify();
+            }
+          }
           StateChanged::PodYamlLoaded {
             pod_name,
             namespace,
@@ -143,18 +162,11 @@ impl PodsView {
     )
     .detach();
 
-    // Start periodic pod and machine refresh
-    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
-    cx.spawn(async move |_this, cx| {
-      loop {
-        Timer::after(Duration::from_secs(refresh_interval)).await;
-        let _ = cx.update(|cx| {
-          services::refresh_machines(cx);
-          services::refresh_pods(cx);
-        });
-      }
-    })
-    .detach();
+    // Periodic pod and machine refresh
+    services::spawn_auto_refresh(RefreshInterval::Lists, 1, cx, |_, cx| {
+      services::refresh_machines(cx);
+      services::refresh_pods(cx);
+    });
 
     // Initial data load
     services::refresh_machines(cx);
@@ -232,8 +244,11 @@ impl PodsView {
     }));
 
     // Reset state
+    // The shell choice carries over to the next pod
+    let terminal_shell = self.pod_tab_state.terminal_shell.take();
     self.pod_tab_state = PodTabState::new();
     self.pod_tab_state.selected_container = pod.containers.first().map(|c| c.name.clone());
+    self.pod_tab_state.terminal_shell = terminal_shell;
 
     // Load logs for the selected pod
     self.load_pod_logs(pod, cx);
@@ -247,19 +262,13 @@ impl PodsView {
     if let Some(pod) = self.selected_pod(cx) {
       match tab {
         PodDetailTab::Terminal if self.terminal_view.is_none() && matches!(pod.phase, PodPhase::Running) => {
-          let container = self.pod_tab_state.selected_container.clone();
-          self.terminal_view = Some(cx.new(|cx| {
-            TerminalView::new(
-              TerminalSessionType::kubectl_exec(pod.name.clone(), pod.namespace.clone(), container, None),
-              window,
-              cx,
-            )
-          }));
+          self.connect_terminal(&pod, window, cx);
         }
         PodDetailTab::Describe if self.pod_tab_state.describe.is_empty() && !self.pod_tab_state.describe_loading => {
           self.pod_tab_state.describe_loading = true;
           services::get_pod_describe(pod.name.clone(), pod.namespace.clone(), cx);
         }
+        PodDetailTab::Events => self.load_events(&pod, cx),
         PodDetailTab::Yaml if self.pod_tab_state.yaml.is_empty() && !self.pod_tab_state.yaml_loading => {
           self.pod_tab_state.yaml_loading = true;
           services::get_pod_yaml(pod.name.clone(), pod.namespace.clone(), cx);
@@ -271,6 +280,11 @@ impl PodsView {
     cx.notify();
   }
 
+  fn load_events(&mut self, pod: &PodInfo, cx: &mut Context<'_, Self>) {
+    self.pod_tab_state.events.start_loading();
+    services::get_resource_events("Pod", pod.name.clone(), pod.namespace.clone(), cx);
+  }
+
   fn load_pod_logs(&mut self, pod: &PodInfo, cx: &mut Context<'_, Self>) {
     self.pod_tab_state.logs_loading = true;
     self.pod_tab_state.logs.clear();
@@ -287,8 +301,14 @@ impl PodsView {
 
     let pod_name = pod.name.clone();
     let namespace = pod.namespace.clone();
-    let container = self.pod_tab_state.selected_container.clone();
     let max_lines: i64 = i64::try_from(settings_state(cx).read(cx).settings.max_log_lines).unwrap_or(i64::MAX);
+    let options = crate::kubernetes::PodLogOptions {
+      container: self.pod_tab_state.selected_container.clone(),
+      tail_lines: Some(max_lines),
+      follow: self.pod_tab_state.logs_follow,
+      previous: self.pod_tab_state.logs_previous,
+      timestamps: false,
+    };
     let target = self.logs_stream.clone();
     let tokio_handle = services::Tokio::runtime_handle();
 
@@ -302,17 +322,7 @@ impl PodsView {
             return;
           }
         };
-        if let Err(e) = kube
-          .stream_pod_logs(
-            &pod_name,
-            &namespace,
-            container.as_deref(),
-            Some(max_lines),
-            false,
-            tx.clone(),
-          )
-          .await
-        {
+        if let Err(e) = kube.stream_pod_logs(&pod_name, &namespace, &options, tx.clone()).await {
           let _ = tx.send(format!("\r\n[log stream ended: {e}]\r\n").into_bytes()).await;
         }
       });
@@ -332,6 +342,11 @@ impl PodsView {
         }
       }
       stream_handle.abort();
+      // Snapshot mode: the stream ends on its own, even with no output.
+      let _ = this.update(cx, |this, cx| {
+        this.pod_tab_state.logs_loading = false;
+        cx.notify();
+      });
     });
     self.logs_task = Some(task);
 
@@ -361,19 +376,70 @@ impl PodsView {
       && let Some(ref pod) = pod
       && matches!(pod.phase, PodPhase::Running)
     {
-      let container = self.pod_tab_state.selected_container.clone();
-      self.terminal_view = Some(cx.new(|cx| {
-        TerminalView::new(
-          TerminalSessionType::kubectl_exec(pod.name.clone(), pod.namespace.clone(), container, None),
-          window,
-          cx,
-        )
-      }));
+      self.connect_terminal(pod, window, cx);
     }
 
     cx.notify();
   }
 
+  /// Switch the exec shell and reconnect the Terminal tab with it.
+  fn on_shell_change(&mut self, shell: Option<String>, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.pod_tab_state.terminal_shell == shell {
+      return;
+    }
+    self.pod_tab_state.terminal_shell = shell;
+    self.terminal_view = None;
+    if self.active_tab == PodDetailTab::Terminal
+      && let Some(pod) = self.selected_pod(cx)
+      && matches!(pod.phase, PodPhase::Running)
+    {
+      self.connect_terminal(&pod, window, cx);
+    }
+    cx.notify();
+  }
+
+  /// Start a `kubectl exec` session in the selected container and shell.
+  fn connect_terminal(&mut self, pod: &PodInfo, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let session = TerminalSessionType::kubectl_exec(
+      pod.name.clone(),
+      pod.namespace.clone(),
+      self.pod_tab_state.selected_container.clone(),
+      self.pod_tab_state.terminal_shell.clone(),
+    );
+    self.terminal_view = Some(cx.new(|cx| TerminalView::new(session, window, cx)));
+  }
+
+  /// Pause drops the live stream and leaves the current output in place;
+  /// resuming reconnects in follow mode.
+  fn on_toggle_logs_follow(&mut self, cx: &mut Context<'_, Self>) {
+    self.pod_tab_state.logs_follow = !self.pod_tab_state.logs_follow;
+    if self.pod_tab_state.logs_follow {
+      self.on_refresh_logs(cx);
+    } else {
+      self.logs_task = None;
+      self.pod_tab_state.logs_loading = false;
+      cx.notify();
+    }
+  }
+
+  /// Save the captured log buffer, named after the pod and, for
+  /// multi-container pods, the container being viewed.
+  fn on_save_logs(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    let (Some(pod), Some(stream)) = (self.selected_pod(cx), self.logs_stream.as_ref()) else {
+      return;
+    };
+    let name = match &self.pod_tab_state.selected_container {
+      Some(container) if pod.containers.len() > 1 => format!("{}-{container}", pod.name),
+      _ => pod.name,
+    };
+    crate::ui::dialogs::prompt_save_logs(name, stream.captured_chunks(), window, cx);
+  }
+
+  fn on_toggle_logs_previous(&mut self, cx: &mut Context<'_, Self>) {
+    self.pod_tab_state.logs_previous = !self.pod_tab_state.logs_previous;
+    self.on_refresh_logs(cx);
+  }
+
   fn on_refresh_logs(&mut self, cx: &mut Context<'_, Self>) {
     if let Some(pod) = self.selected_pod(cx) {
       self.load_pod_logs(&pod, cx);
@@ -443,8 +509,26 @@ impl Render for PodsView {
       .on_refresh_logs(cx.listener(|this, (): &(), _window, cx| {
         this.on_refresh_logs(cx);
       }))
+      .on_toggle_logs_follow(cx.listener(|this, (): &(), _window, cx| {
+        this.on_toggle_logs_follow(cx);
+      }))
+      .on_toggle_logs_previous(cx.listener(|this, (): &(), _window, cx| {
+        this.on_toggle_logs_previous(cx);
+      }))
+      .on_save_logs(cx.listener(|this, (): &(), window, cx| {
+        this.on_save_logs(window, cx);
+      }))
+      .on_refresh_events(cx.listener(|this, (): &(), _window, cx| {
+        if let Some(pod) = this.selected_pod(cx) {
+          this.load_events(&pod, cx);
+          cx.notify();
+        }
+      }))
       .on_container_select(cx.listener(|this, container: &String, window, cx| {
         this.on_container_select(container, window, cx);
+      }))
+      .on_shell_change(cx.listener(|this, shell: &Option<String>, window, cx| {
+        this.on_shell_change(shell.clone(), window, cx);
       }));
 
     div()
@@ -10,12 +10,19 @@ use gpui_component::{
   v_flex,
 };
 
-use crate::docker::PruneResult;
+use crate::docker::{DiskUsageSummary, PruneCandidate, PrunePreview, PruneResult};
 use crate::services;
 use crate::ui::components::form_section;
 
+fn format_bytes(bytes: i64) -> String {
+  bytesize::ByteSize(u64::try_from(bytes).unwrap_or(0)).to_string()
+}
+
+/// Names listed per category in the preview before collapsing to "N more"
+const PREVIEW_LIST_LIMIT: usize = 8;
+
 /// Options for prune operation
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct PruneOptions {
   // Docker options
   pub prune_containers: bool,
@@ -53,13 +60,19 @@ pub struct PruneResultDisplay {
   pub error: Option<String>,
 }
 
-/// First-class Prune view: pick what to clean up, run it, see the
-/// result inline. Same options + service path as the old modal, just
+/// First-class Prune view: pick what to clean up, preview what that
+/// selects, confirm, see the result inline. Same options + service path as the old modal, just
 /// reframed as a compact scrollable sidebar view.
 pub struct PruneView {
   options: PruneOptions,
   result_display: PruneResultDisplay,
-  disk_usage: Option<crate::docker::DiskUsageSummary>,
+  disk_usage: Option<DiskUsageSummary>,
+  /// Snapshot taken when a prune starts, to show per-category savings
+  usage_before_prune: Option<DiskUsageSummary>,
+  /// Dry-run result and the options it was computed for. Pruning is only
+  /// offered while those still match the current options.
+  preview: Option<(PruneOptions, PrunePreview)>,
+  preview_loading: bool,
 }
 
 impl PruneView {
@@ -68,33 +81,20 @@ impl PruneView {
       options: PruneOptions::default(),
       result_display: PruneResultDisplay::default(),
       disk_usage: None,
+      usage_before_prune: None,
+      preview: None,
+      preview_loading: false,
     };
     Self::refresh_disk_usage(cx);
     view
   }
 
-  fn refresh_disk_usage(cx: &mut Context<'_, Self>) {
-    // Skip when the global Tokio runtime hasn't been initialised yet
-    // (gpui::test cases that build the view without the service layer).
-    let Some(tokio_handle) = services::Tokio::try_runtime_handle() else {
-      tracing::debug!("prune: skipping disk-usage refresh — Tokio not initialised");
-      return;
-    };
-    let client = services::docker_client();
+  /// Re-run `docker system df`. Also called once a prune finishes so the
+  /// breakdown shows what was freed.
+  pub fn refresh_disk_usage(cx: &mut Context<'_, Self>) {
+    let task = services::docker_system_df(cx);
     cx.spawn(async move |this, cx| {
-      let usage = cx
-        .background_executor()
-        .spawn(async move {
-          tokio_handle.block_on(async {
-            let guard = client.read().await;
-            match guard.as_ref() {
-              Some(c) => c.get_disk_usage().await.ok(),
-              None => None,
-            }
-          })
-        })
-        .await;
-
+      let usage = task.await;
       let _ = this.update(cx, |this, cx| {
         if let Some(u) = usage {
           this.disk_usage = Some(u);
@@ -105,11 +105,49 @@ impl PruneView {
     .detach();
   }
 
+  /// Select only the build cache and preview it, so the one-click prune
+  /// still goes through the confirm step.
+  fn preview_build_cache_prune(&mut self, cx: &mut Context<'_, Self>) {
+    if self.result_display.is_loading || self.preview_loading {
+      return;
+    }
+    self.options = PruneOptions {
+      prune_build_cache: true,
+      ..PruneOptions::default()
+    };
+    services::preview_prune(cx.entity(), &self.options, cx);
+    cx.notify();
+  }
+
   pub fn get_options(&self) -> PruneOptions {
     self.options.clone()
   }
 
+  /// The preview, if it was computed for the options currently selected
+  pub fn current_preview(&self) -> Option<&PrunePreview> {
+    self
+      .preview
+      .as_ref()
+      .filter(|(options, _)| *options == self.options)
+      .map(|(_, preview)| preview)
+  }
+
+  pub fn set_preview(&mut self, options: PruneOptions, preview: PrunePreview) {
+    self.preview = Some((options, preview));
+    self.preview_loading = false;
+  }
+
+  pub fn set_preview_loading(&mut self, loading: bool) {
+    self.preview_loading = loading;
+    if loading {
+      self.preview = None;
+      self.result_display.result = None;
+      self.result_display.error = None;
+    }
+  }
+
   pub fn set_result(&mut self, result: PruneResult) {
+    self.preview = None;
     self.result_display.result = Some(result);
     self.result_display.is_loading = false;
     self.result_display.error = None;
@@ -118,11 +156,13 @@ impl PruneView {
   pub fn set_error(&mut self, error: String) {
     self.result_display.error = Some(error);
     self.result_display.is_loading = false;
+    self.preview_loading = false;
   }
 
   pub fn set_loading(&mut self, loading: bool) {
     self.result_display.is_loading = loading;
     if loading {
+      self.usage_before_prune.clone_from(&self.disk_usage);
       self.result_display.result = None;
       self.result_display.error = None;
     }
@@ -130,8 +170,10 @@ impl PruneView {
 
   fn render_disk_usage(&self, cx: &Context<'_, Self>) -> Option<impl IntoElement> {
     let colors = cx.theme().colors;
+    let before = self.usage_before_prune.as_ref();
+    let is_loading = self.result_display.is_loading;
     self.disk_usage.as_ref().map(|u| {
-      let line = |label: &'static str, count: usize, size: i64, reclaimable: i64| {
+      let line = |label: &'static str, counts: String, size: i64, reclaimable: i64, freed: i64| {
         h_flex()
           .w_full()
           .py(px(3.))
@@ -153,37 +195,186 @@ impl PruneView {
               .text_xs()
               .text_color(colors.foreground)
               .child(format!(
-                "{count} · {} · {} reclaimable",
-                bytesize::ByteSize(u64::try_from(size).unwrap_or(0)),
-                bytesize::ByteSize(u64::try_from(reclaimable).unwrap_or(0))
+                "{counts}{} · {} reclaimable",
+                format_bytes(size),
+                format_bytes(reclaimable)
               )),
           )
+          .when(freed > 0, |el| {
+            el.child(
+              div()
+                .flex_shrink_0()
+                .text_xs()
+                .text_color(colors.success)
+                .child(format!("−{}", format_bytes(freed))),
+            )
+          })
       };
+      // Bytes freed since the last prune started, per category
+      let freed = |size: fn(&DiskUsageSummary) -> i64| before.map_or(0, |b| size(b) - size(u));
 
       v_flex()
         .w_full()
-        .child(line("Images", u.images_count, u.images_size, u.images_reclaimable))
+        .child(line(
+          "Images",
+          format!("{} ({} in use) · ", u.images_count, u.images_active),
+          u.images_size,
+          u.images_reclaimable,
+          freed(|d| d.images_size),
+        ))
         .child(line(
           "Containers",
-          u.containers_count,
+          format!("{} ({} running) · ", u.containers_count, u.containers_active),
           u.containers_size,
           u.containers_reclaimable,
+          freed(|d| d.containers_size),
         ))
         .child(line(
           "Local volumes",
-          u.volumes_count,
+          format!("{} ({} in use) · ", u.volumes_count, u.volumes_active),
           u.volumes_size,
           u.volumes_reclaimable,
+          freed(|d| d.volumes_size),
         ))
+        .child(
+          line(
+            "Build cache",
+            format!("{} · ", u.build_cache_count),
+            u.build_cache_size,
+            u.build_cache_reclaimable,
+            freed(|d| d.build_cache_size),
+          )
+          .when(u.build_cache_reclaimable > 0, |el| {
+            el.child(
+              Button::new("prune-build-cache-now")
+                .label("Prune")
+                .ghost()
+                .xsmall()
+                .disabled(is_loading)
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                  this.preview_build_cache_prune(cx);
+                })),
+            )
+          }),
+        )
         .child(line(
-          "Build cache",
-          u.build_cache_count,
-          u.build_cache_size,
-          u.build_cache_reclaimable,
+          "Total",
+          String::new(),
+          u.total_size(),
+          u.total_reclaimable(),
+          freed(DiskUsageSummary::total_size),
         ))
     })
   }
 
+  fn render_preview(&self, cx: &Context<'_, Self>) -> Option<impl IntoElement> {
+    let colors = cx.theme().colors;
+    let preview = self.current_preview()?;
+    let is_loading = self.result_display.is_loading;
+
+    let category = |title: &'static str, names: Vec<String>| {
+      let extra = names.len().saturating_sub(PREVIEW_LIST_LIMIT);
+      v_flex()
+        .w_full()
+        .py(px(4.))
+        .gap(px(2.))
+        .child(
+          div()
+            .text_xs()
+            .font_weight(gpui::FontWeight::SEMIBOLD)
+            .text_color(colors.foreground)
+            .child(format!("{title} ({})", names.len())),
+        )
+        .children(names.into_iter().take(PREVIEW_LIST_LIMIT).map(|name| {
+          div()
+            .pl(px(8.))
+            .text_xs()
+            .text_color(colors.secondary_foreground)
+            .overflow_hidden()
+            .text_ellipsis()
+            .whitespace_nowrap()
+            .child(name)
+        }))
+        .when(extra > 0, |el| {
+          el.child(
+            div()
+              .pl(px(8.))
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(format!("…and {extra} more")),
+          )
+        })
+    };
+    let sized = |candidates: &[PruneCandidate]| {
+      candidates
+        .iter()
+        .map(|c| match c.size {
+          Some(size) if size > 0 => format!("{} ({})", c.name, bytesize::ByteSize(size)),
+          _ => c.name.clone(),
+        })
+        .collect::<Vec<_>>()
+    };
+    let categories = [
+      ("Containers", sized(&preview.containers)),
+      ("Images", sized(&preview.images)),
+      ("Volumes", sized(&preview.volumes)),
+      ("Networks", sized(&preview.networks)),
+      ("Build cache", sized(&preview.build_cache)),
+      ("Deployments", preview.deployments.clone()),
+      ("Services", preview.services.clone()),
+      ("Pods", preview.pods.clone()),
+    ];
+
+    let summary = if preview.is_empty() {
+      "Nothing to prune with the selected options".to_string()
+    } else {
+      format!(
+        "{} item(s) · up to {} reclaimable",
+        preview.total_items(),
+        preview.display_reclaimable()
+      )
+    };
+
+    Some(
+      v_flex()
+        .w_full()
+        .p(px(12.))
+        .gap(px(4.))
+        .rounded(px(6.))
+        .bg(colors.sidebar)
+        .child(
+          h_flex()
+            .w_full()
+            .gap(px(8.))
+            .items_center()
+            .justify_between()
+            .child(div().text_sm().text_color(colors.foreground).child(summary))
+            .child(
+              Button::new("prune-confirm")
+                .label(if is_loading { "Pruning..." } else { "Confirm prune" })
+                .danger()
+                .small()
+                .disabled(preview.is_empty() || is_loading)
+                .on_click(cx.listener(|this, _ev, _window, cx| {
+                  if this.current_preview().is_none() || this.result_display.is_loading {
+                    return;
+                  }
+                  let options = this.get_options();
+                  this.set_loading(true);
+                  services::prune_docker(cx.entity(), &options, cx);
+                  cx.notify();
+                })),
+            ),
+        )
+        .children(
+          categories
+            .into_iter()
+            .filter(|(_, names)| !names.is_empty())
+            .map(|(title, names)| category(title, names)),
+        ),
+    )
+  }
+
   fn render_result(&self, cx: &Context<'_, Self>) -> impl IntoElement {
     let colors = cx.theme().colors;
     let display = &self.result_display;
@@ -264,9 +455,10 @@ impl Render for PruneView {
   fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
     let colors = cx.theme().colors;
     let o = &self.options;
-    let can_prune = !o.is_empty() && !self.result_display.is_loading;
-    let is_loading = self.result_display.is_loading;
+    let can_preview = !o.is_empty() && !self.result_display.is_loading && !self.preview_loading;
+    let preview_loading = self.preview_loading;
     let disk_section = self.render_disk_usage(cx);
+    let preview_section = self.render_preview(cx);
 
     // Label + description on the left, switch pinned to the far right
     // of the full-width row.
@@ -368,18 +560,16 @@ impl Render for PruneView {
                   })),
               )
               .child(
-                Button::new("prune-run")
-                  .label(if is_loading { "Pruning..." } else { "Prune" })
+                Button::new("prune-preview")
+                  .label(if preview_loading { "Previewing..." } else { "Preview" })
                   .primary()
-                  .disabled(!can_prune)
+                  .disabled(!can_preview)
                   .on_click(cx.listener(|this, _ev, _window, cx| {
                     let options = this.get_options();
-                    if options.is_empty() || this.result_display.is_loading {
+                    if options.is_empty() || this.result_display.is_loading || this.preview_loading {
                       return;
                     }
-                    this.set_loading(true);
-                    services::prune_docker(cx.entity(), &options, cx);
-                    cx.notify();
+                    services::preview_prune(cx.entity(), &options, cx);
                   })),
               ),
           ),
@@ -540,6 +730,9 @@ impl Render for PruneView {
                   .into_any_element(),
               ))
               .child(div().h(px(8.)))
+              .when_some(preview_section, |el, section| {
+                el.child(form_section("Preview", cx)).child(section)
+              })
               .child(self.render_result(cx)),
           ),
       )
@@ -735,6 +928,29 @@ mod tests {
     });
   }
 
+  #[gpui::test]
+  fn test_prune_view_preview_tracks_options(cx: &mut gpui::TestAppContext) {
+    let view = cx.new(PruneView::new);
+
+    view.update(cx, |view, _| {
+      view.options.prune_containers = true;
+      view.set_preview_loading(true);
+      view.set_preview(view.get_options(), PrunePreview::default());
+    });
+    view.read_with(cx, |view, _| {
+      assert!(!view.preview_loading);
+      assert!(view.current_preview().is_some());
+    });
+
+    // Changing the options invalidates the preview
+    view.update(cx, |view, _| {
+      view.options.prune_images = true;
+    });
+    view.read_with(cx, |view, _| {
+      assert!(view.current_preview().is_none());
+    });
+  }
+
   #[gpui::test]
   fn test_prune_view_loading_clears_previous_state(cx: &mut gpui::TestAppContext) {
     let view = cx.new(PruneView::new);
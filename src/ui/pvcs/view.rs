@@ -1,15 +1,13 @@
 //! K8s `PersistentVolumeClaim` view: list + detail split.
 
-use std::time::Duration;
-
-use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::theme::ActiveTheme;
 
 use super::detail::PvcDetail;
 use super::list::{PvcList, PvcListEvent};
 use crate::kubernetes::PvcInfo;
-use crate::services;
-use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+use crate::services::{self, RefreshInterval};
+use crate::state::{DockerState, Selection, StateChanged, docker_state};
 
 pub struct PvcsView {
   docker_state: Entity<DockerState>,
@@ -111,14 +109,7 @@ impl PvcsView {
     )
     .detach();
 
-    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval.max(5);
-    cx.spawn(async move |_this, cx| {
-      loop {
-        Timer::after(Duration::from_secs(refresh_interval)).await;
-        let _ = cx.update(services::refresh_pvcs);
-      }
-    })
-    .detach();
+    services::spawn_auto_refresh(RefreshInterval::Lists, 5, cx, |_, cx| services::refresh_pvcs(cx));
 
     services::refresh_pvcs(cx);
     services::refresh_namespaces(cx);
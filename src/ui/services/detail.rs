@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use gpui::{Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::{
   Icon, IconName, Selectable, Sizable,
@@ -13,8 +15,11 @@ use gpui_component::{
 
 use crate::assets::AppIcon;
 use crate::kubernetes::{PodInfo, ServiceInfo};
-use crate::services;
+use crate::services::{self, PortForward};
 use crate::state::{DockerState, ServiceDetailTab, StateChanged, docker_state};
+use crate::ui::components::{
+  DescribeOutput, ResourceEvents, copy_button, copy_to_clipboard, render_describe_tab, render_event_list,
+};
 
 /// Detail view for a service with tabs
 pub struct ServiceDetail {
@@ -22,6 +27,8 @@ pub struct ServiceDetail {
   service: Option<ServiceInfo>,
   active_tab: ServiceDetailTab,
   yaml_content: String,
+  describe: DescribeOutput,
+  events: ResourceEvents,
   yaml_editor: Option<Entity<InputState>>,
   last_synced_yaml: String,
 }
@@ -30,6 +37,10 @@ impl ServiceDetail {
   pub fn new(cx: &mut Context<'_, Self>) -> Self {
     let docker_state = docker_state(cx);
 
+    // Re-render the Ports tab as service forwards start and stop
+    cx.observe(&services::port_forward_manager(cx), |_, _, cx| cx.notify())
+      .detach();
+
     // Subscribe to state changes
     cx.subscribe(&docker_state, |this, ds, event: &StateChanged, cx| {
       match event {
@@ -46,6 +57,35 @@ impl ServiceDetail {
             cx.notify();
           }
         }
+        StateChanged::ServiceDescribeLoaded {
+          service_name,
+          namespace,
+          describe,
+        } => {
+          if let Some(ref current) = this.service
+            && current.name == *service_name
+            && current.namespace == *namespace
+          {
+            this.describe.set(describe);
+            cx.notify();
+          }
+        }
+        StateChanged::ResourceEventsLoaded {
+          kind,
+          name,
+          namespace,
+          events,
+          error,
+        } => {
+          if kind == "Service"
+            && let Some(ref current) = this.service
+            && current.name == *name
+            && current.namespace == *namespace
+          {
+            this.events.set(events, error.as_deref());
+            cx.notify();
+          }
+        }
         StateChanged::ServiceTabRequest {
           service_name,
           namespace,
@@ -57,6 +97,8 @@ impl ServiceDetail {
             this.service = Some(svc.clone());
             this.active_tab = *tab;
             this.yaml_content.clear();
+            this.describe.clear();
+            this.events = ResourceEvents::default();
             cx.notify();
           }
         }
@@ -80,6 +122,8 @@ impl ServiceDetail {
       service: None,
       active_tab: ServiceDetailTab::Info,
       yaml_content: String::new(),
+      describe: DescribeOutput::default(),
+      events: ResourceEvents::default(),
       yaml_editor: None,
       last_synced_yaml: String::new(),
     }
@@ -89,6 +133,8 @@ impl ServiceDetail {
     self.service = Some(service.clone());
     self.active_tab = ServiceDetailTab::Info;
     self.yaml_content.clear();
+    self.describe.clear();
+    self.events = ResourceEvents::default();
     self.yaml_editor = None;
     self.last_synced_yaml.clear();
 
@@ -124,21 +170,41 @@ impl ServiceDetail {
         .child(div().flex_1().text_sm().text_color(colors.foreground).child(value))
     };
 
+    let copy_row = |id: &'static str, label: &str, value: String| {
+      info_row(label, value.clone()).child(copy_button(id, value, Rc::new(copy_to_clipboard)))
+    };
+
     let mut content = v_flex()
       .w_full()
       .gap(px(4.))
       .child(info_row("Name", service.name.clone()))
       .child(info_row("Namespace", service.namespace.clone()))
-      .child(info_row("Type", service.service_type.clone()))
-      .child(info_row(
-        "Cluster IP",
-        service.cluster_ip.clone().unwrap_or_else(|| "None".to_string()),
-      ));
+      .child(info_row("Type", service.service_type.clone()));
+
+    content = match service.cluster_ip.clone().filter(|ip| ip != "None") {
+      Some(ip) => content.child(copy_row("copy-cluster-ip", "Cluster IP", ip)),
+      None => content.child(info_row("Cluster IP", "None".to_string())),
+    };
 
     if !service.external_ips.is_empty() {
       content = content.child(info_row("External IPs", service.external_ips.join(", ")));
     }
 
+    if service.service_type == "LoadBalancer" {
+      content = if service.load_balancer_ingress.is_empty() {
+        content.child(info_row("Load Balancer", "Pending".to_string()))
+      } else {
+        content.child(copy_row(
+          "copy-lb-address",
+          "Load Balancer",
+          service.load_balancer_ingress.join(", "),
+        ))
+      };
+      if let Some(url) = service.load_balancer_url() {
+        content = content.child(copy_row("copy-lb-url", "URL", url));
+      }
+    }
+
     content = content
       .child(info_row("Ports", service.ports_display()))
       .child(info_row("Age", service.age.clone()));
@@ -277,7 +343,8 @@ impl ServiceDetail {
           .font_weight(gpui::FontWeight::SEMIBOLD)
           .text_color(colors.muted_foreground)
           .child("Node Port"),
-      );
+      )
+      .child(div().w(px(96.)));
 
     let rows = service
       .ports
@@ -324,13 +391,101 @@ impl ServiceDetail {
               .text_color(colors.foreground)
               .child(port.node_port.map_or_else(|| "-".to_string(), |p| p.to_string())),
           )
+          .child(
+            div().w(px(96.)).flex().justify_end().children(
+              // kubectl port-forward only carries TCP
+              u16::try_from(port.port)
+                .ok()
+                .filter(|_| port.protocol == "TCP")
+                .map(|remote| {
+                  let name = service.name.clone();
+                  let namespace = service.namespace.clone();
+                  Button::new(("service-port-forward", i))
+                    .icon(Icon::new(AppIcon::Network))
+                    .label("Forward")
+                    .ghost()
+                    .xsmall()
+                    .on_click(move |_ev, _window, cx| {
+                      services::port_forward_service(name.clone(), namespace.clone(), remote, cx);
+                    })
+                }),
+            ),
+          )
       })
       .collect::<Vec<_>>();
 
-    div()
+    v_flex()
       .size_full()
       .p(px(16.))
       .child(v_flex().w_full().child(header).children(rows))
+      .child(Self::render_port_forwards(service, cx))
+  }
+
+  fn render_port_forwards(service: &ServiceInfo, cx: &Context<'_, Self>) -> gpui::Div {
+    let colors = &cx.theme().colors;
+    let manager = services::port_forward_manager(cx);
+    let forwards: Vec<PortForward> = manager
+      .read(cx)
+      .for_service(&service.name, &service.namespace)
+      .cloned()
+      .collect();
+
+    let header = div()
+      .pt(px(16.))
+      .pb(px(8.))
+      .text_sm()
+      .font_weight(gpui::FontWeight::SEMIBOLD)
+      .text_color(colors.foreground)
+      .child("Port Forwards");
+
+    let body = if forwards.is_empty() {
+      v_flex().child(
+        div()
+          .text_xs()
+          .text_color(colors.muted_foreground)
+          .child("No active port forwards for this service."),
+      )
+    } else {
+      v_flex().gap(px(8.)).children(forwards.into_iter().map(|forward| {
+        let id = forward.id;
+        let url = format!("http://{}", forward.local_address());
+        h_flex()
+          .w_full()
+          .py(px(8.))
+          .px(px(12.))
+          .rounded(px(6.))
+          .border_1()
+          .border_color(colors.border)
+          .gap(px(12.))
+          .items_center()
+          .child(
+            div()
+              .flex_1()
+              .text_sm()
+              .font_family("monospace")
+              .text_color(colors.foreground)
+              .child(format!("{} → {}", forward.local_address(), forward.remote_port)),
+          )
+          .child(
+            div()
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .child(format!("since {}", forward.started_at.format("%H:%M:%S"))),
+          )
+          .child(copy_button(("copy-forward-url", id), url, Rc::new(copy_to_clipboard)))
+          .child(
+            Button::new(("stop-service-forward", id))
+              .label("Stop")
+              .ghost()
+              .xsmall()
+              .on_click(move |_ev, _window, cx| {
+                services::stop_port_forward(id, cx);
+              }),
+          )
+      }))
+    };
+
+    v_flex().w_full().child(header).child(body)
   }
 
   fn render_endpoints_tab(&self, service: &ServiceInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
@@ -538,6 +693,38 @@ impl ServiceDetail {
     )
   }
 
+  fn load_events(&mut self, cx: &mut Context<'_, Self>) {
+    if let Some(ref current) = self.service {
+      self.events.start_loading();
+      services::get_resource_events("Service", current.name.clone(), current.namespace.clone(), cx);
+    }
+  }
+
+  fn render_events_tab(&self, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let entity = cx.entity();
+    render_event_list(
+      "service",
+      &self.events,
+      move |_window, cx| {
+        entity.update(cx, |this, cx| {
+          this.load_events(cx);
+          cx.notify();
+        });
+      },
+      cx,
+    )
+  }
+
+  fn render_describe_tab(&self, service: &ServiceInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
+    let name = service.name.clone();
+    let namespace = service.namespace.clone();
+    render_describe_tab(
+      &self.describe,
+      move |_window, cx| services::describe_service(name.clone(), namespace.clone(), cx),
+      cx,
+    )
+  }
+
   fn render_yaml_tab(&self, service: &ServiceInfo, cx: &mut Context<'_, Self>) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -578,22 +765,34 @@ impl ServiceDetail {
             let name = name.clone();
             let namespace = namespace.clone();
             let editor = editor_for_apply.clone();
+            let original = self.yaml_content.clone();
             move |menu, _w, _cx| {
               let apply_name = name.clone();
               let apply_namespace = namespace.clone();
               let apply_editor = editor.clone();
+              let apply_original = original.clone();
               let reload_name = name.clone();
               let reload_namespace = namespace.clone();
               menu
                 .item(
                   PopupMenuItem::new("Apply YAML")
                     .icon(Icon::new(AppIcon::Refresh))
-                    .on_click(move |_, _, cx| {
+                    .on_click(move |_, window, cx| {
                       let Some(ref e) = apply_editor else { return };
                       let yaml: String = e.read(cx).text().to_string();
-                      if !yaml.trim().is_empty() {
-                        services::apply_service_yaml(apply_name.clone(), apply_namespace.clone(), yaml, cx);
+                      if yaml.trim().is_empty() {
+                        return;
                       }
+                      let name = apply_name.clone();
+                      let namespace = apply_namespace.clone();
+                      crate::ui::dialogs::confirm_yaml_apply(
+                        "Service",
+                        &apply_original,
+                        yaml,
+                        move |yaml, cx| services::apply_service_yaml(name.clone(), namespace.clone(), yaml, cx),
+                        window,
+                        cx,
+                      );
                     }),
                 )
                 .separator()
@@ -687,6 +886,7 @@ impl Render for ServiceDetail {
       });
       self.last_synced_yaml = self.yaml_content.clone();
     }
+    self.describe.sync_editor(window, cx);
 
     let Some(service) = self.service.clone() else {
       return div().size_full().child(Self::render_empty(cx));
@@ -725,6 +925,28 @@ impl Render for ServiceDetail {
             cx.notify();
           })),
       )
+      .child(
+        Tab::new()
+          .label("Describe")
+          .selected(active_tab == ServiceDetailTab::Describe)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = ServiceDetailTab::Describe;
+            if let Some(ref current) = this.service {
+              services::describe_service(current.name.clone(), current.namespace.clone(), cx);
+            }
+            cx.notify();
+          })),
+      )
+      .child(
+        Tab::new()
+          .label("Events")
+          .selected(active_tab == ServiceDetailTab::Events)
+          .on_click(cx.listener(|this, _ev, _window, cx| {
+            this.active_tab = ServiceDetailTab::Events;
+            this.load_events(cx);
+            cx.notify();
+          })),
+      )
       .child(
         Tab::new()
           .label("YAML")
@@ -742,6 +964,8 @@ impl Render for ServiceDetail {
       ServiceDetailTab::Info => Self::render_info_tab(&service, cx),
       ServiceDetailTab::Ports => Self::render_ports_tab(&service, cx),
       ServiceDetailTab::Endpoints => self.render_endpoints_tab(&service, cx),
+      ServiceDetailTab::Describe => self.render_describe_tab(&service, cx),
+      ServiceDetailTab::Events => self.render_events_tab(cx),
       ServiceDetailTab::Yaml => self.render_yaml_tab(&service, cx),
     };
 
@@ -15,7 +15,7 @@ use crate::assets::AppIcon;
 use crate::kubernetes::ServiceInfo;
 use crate::services;
 use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_k8s_error, render_loading};
+use crate::ui::components::{CachedListDelegate, refresh_rows, render_k8s_error, render_loading};
 
 /// Service list events emitted to parent
 pub enum ServiceListEvent {
@@ -26,6 +26,7 @@ pub enum ServiceListEvent {
 pub struct ServiceListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<ServiceInfo>,
 }
 
 impl ServiceListDelegate {
@@ -43,7 +44,7 @@ impl ServiceListDelegate {
     }
   }
 
-  fn filtered_services(&self, cx: &App) -> Vec<ServiceInfo> {
+  fn collect_rows(&self, cx: &App) -> Vec<ServiceInfo> {
     let services = self.services(cx);
     if self.search_query.is_empty() {
       return services;
@@ -61,16 +62,40 @@ impl ServiceListDelegate {
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered_services(&self) -> &[ServiceInfo] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for ServiceListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|service| matches!(global_selection, Selection::Service { name, namespace } if *name == service.name && *namespace == service.namespace))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for ServiceListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered_services(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered_services().len()
   }
 
   fn render_item(
@@ -79,7 +104,7 @@ impl ListDelegate for ServiceListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let services = self.filtered_services(cx);
+    let services = self.filtered_services();
     let service = services.get(ix.row)?;
     let colors = &cx.theme().colors;
 
@@ -253,15 +278,17 @@ impl ServiceList {
     let delegate = ServiceListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
 
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     // Subscribe to list events
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered_services(cx);
+        let filtered = delegate.filtered_services();
         if let Some(service) = filtered.get(ix.row) {
           cx.emit(ServiceListEvent::Selected(Box::new(service.clone())));
         }
@@ -271,20 +298,22 @@ impl ServiceList {
     .detach();
 
     // Subscribe to docker state changes to refresh list
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(
-        event,
-        StateChanged::ServicesUpdated
-          | StateChanged::NamespacesUpdated
-          | StateChanged::MachinesUpdated
-          | StateChanged::SelectionChanged
-      ) {
-        this.list_state.update(cx, |_state, cx| {
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(
+          event,
+          StateChanged::ServicesUpdated
+            | StateChanged::NamespacesUpdated
+            | StateChanged::MachinesUpdated
+            | StateChanged::SelectionChanged
+        ) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
           cx.notify();
-        });
-        cx.notify();
-      }
-    })
+        }
+      },
+    )
     .detach();
 
     Self {
@@ -309,7 +338,7 @@ impl ServiceList {
       if current_text != self.search_query {
         current_text.clone_into(&mut self.search_query);
         self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+          state.delegate_mut().set_search_query(current_text, cx);
           cx.notify();
         });
       }
@@ -324,7 +353,7 @@ impl ServiceList {
       self.search_query.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -510,7 +539,7 @@ impl Render for ServiceList {
     let total_count = state.services.len();
     let services_state = state.services_state.clone();
 
-    let filtered_count = self.list_state.read(cx).delegate().filtered_services(cx).len();
+    let filtered_count = self.list_state.read(cx).delegate().filtered_services().len();
     let is_filtering = !self.search_query.is_empty();
     let services_empty = filtered_count == 0;
 
@@ -577,6 +606,13 @@ impl Render for ServiceList {
                         crate::ui::dialogs::open_create_service_dialog(window, cx);
                       }),
                   )
+                  .item(
+                    PopupMenuItem::new("Apply YAML...")
+                      .icon(Icon::new(AppIcon::Edit))
+                      .on_click(|_, window, cx| {
+                        crate::ui::dialogs::open_apply_yaml_dialog(window, cx);
+                      }),
+                  )
                   .separator()
                   .item(
                     PopupMenuItem::new("Refresh")
@@ -1,13 +1,11 @@
-use std::time::Duration;
-
-use gpui::{App, Context, Entity, Render, Styled, Timer, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::theme::ActiveTheme;
 
 use super::detail::ServiceDetail;
 use super::list::{ServiceList, ServiceListEvent};
 use crate::kubernetes::ServiceInfo;
-use crate::services;
-use crate::state::{DockerState, Selection, StateChanged, docker_state, settings_state};
+use crate::services::{self, RefreshInterval};
+use crate::state::{DockerState, Selection, StateChanged, docker_state};
 
 /// Main services view with list and detail panels
 pub struct ServicesView {
@@ -126,18 +124,11 @@ impl ServicesView {
     })
     .detach();
 
-    // Start periodic refresh
-    let refresh_interval = settings_state(cx).read(cx).settings.container_refresh_interval;
-    cx.spawn(async move |_this, cx| {
-      loop {
-        Timer::after(Duration::from_secs(refresh_interval)).await;
-        let _ = cx.update(|cx| {
-          services::refresh_machines(cx);
-          services::refresh_services(cx);
-        });
-      }
-    })
-    .detach();
+    // Periodic refresh
+    services::spawn_auto_refresh(RefreshInterval::Lists, 1, cx, |_, cx| {
+      services::refresh_machines(cx);
+      services::refresh_services(cx);
+    });
 
     // Trigger initial data load
     services::refresh_machines(cx);
@@ -1,4 +1,4 @@
-use gpui::{Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, Render, SharedString, Styled, Window, div, prelude::*, px};
 use gpui_component::{
   Disableable, Icon, IconName, IndexPath, Sizable, WindowExt,
   button::{Button, ButtonVariants},
@@ -7,15 +7,15 @@ use gpui_component::{
   scroll::ScrollableElement,
   select::{Select, SelectItem, SelectState},
   switch::Switch,
-  theme::{ActiveTheme, Theme, ThemeRegistry},
+  theme::ActiveTheme,
   v_flex,
 };
 
 use crate::assets::AppIcon;
 use crate::colima::ColimaClient;
 use crate::state::{
-  ExternalEditor, SettingsChanged, SettingsState, StateChanged, TerminalCursorStyle, ThemeName, docker_state,
-  settings_state,
+  AppSettings, ExternalEditor, SettingsChanged, SettingsState, StateChanged, TerminalCursorStyle, ThemeName,
+  apply_active_theme, docker_state, settings_state, split_env_entries, theme_catalog, user_themes_dir,
 };
 use crate::ui::components::{form_field, form_section};
 
@@ -25,17 +25,31 @@ use crate::ui::components::{form_field, form_section};
 
 #[derive(Debug, Clone)]
 struct ThemeOption {
-  theme: ThemeName,
+  /// `None` for "Auto (follow system)"
+  theme: Option<ThemeName>,
   label: SharedString,
 }
 
 impl ThemeOption {
   fn new(theme: ThemeName) -> Self {
     let label = SharedString::from(theme.display_name().to_string());
-    Self { theme, label }
+    Self {
+      theme: Some(theme),
+      label,
+    }
   }
-  fn all() -> Vec<Self> {
-    ThemeName::all().into_iter().map(Self::new).collect()
+  fn auto() -> Self {
+    Self {
+      theme: None,
+      label: "Auto (follow system)".into(),
+    }
+  }
+  /// Every theme currently found on disk
+  fn all(cx: &App) -> Vec<Self> {
+    theme_catalog(cx)
+      .names()
+      .map(|name| Self::new(ThemeName::new(name)))
+      .collect()
   }
 }
 
@@ -166,6 +180,8 @@ pub struct SettingsView {
   active: Category,
   // Form state — each input is built lazily on first render of the category.
   theme_select: Option<Entity<SelectState<Vec<ThemeOption>>>>,
+  light_theme_select: Option<Entity<SelectState<Vec<ThemeOption>>>>,
+  dark_theme_select: Option<Entity<SelectState<Vec<ThemeOption>>>>,
   editor_select: Option<Entity<SelectState<Vec<EditorOption>>>>,
   cursor_style_select: Option<Entity<SelectState<Vec<CursorStyleOption>>>>,
   docker_socket_input: Option<Entity<InputState>>,
@@ -177,6 +193,10 @@ pub struct SettingsView {
   font_size_input: Option<Entity<InputState>>,
   line_height_input: Option<Entity<InputState>>,
   font_family_input: Option<Entity<InputState>>,
+  shell_input: Option<Entity<InputState>>,
+  editor_command_input: Option<Entity<InputState>>,
+  env_input: Option<Entity<InputState>>,
+  secret_patterns_input: Option<Entity<InputState>>,
   scrollback_lines_input: Option<Entity<InputState>>,
   kubeconfig_input: Option<Entity<InputState>>,
   default_namespace_input: Option<Entity<InputState>>,
@@ -207,10 +227,23 @@ impl SettingsView {
     })
     .detach();
 
+    // A re-scan can add or drop themes; rebuild the select on next render.
+    cx.subscribe(&settings_state, |this, _state, event: &SettingsChanged, cx| {
+      if matches!(event, SettingsChanged::ThemesReloaded) {
+        this.theme_select = None;
+        this.light_theme_select = None;
+        this.dark_theme_select = None;
+        cx.notify();
+      }
+    })
+    .detach();
+
     Self {
       settings_state,
       active: Category::General,
       theme_select: None,
+      light_theme_select: None,
+      dark_theme_select: None,
       editor_select: None,
       cursor_style_select: None,
       docker_socket_input: None,
@@ -222,6 +255,10 @@ impl SettingsView {
       font_size_input: None,
       line_height_input: None,
       font_family_input: None,
+      shell_input: None,
+      editor_command_input: None,
+      env_input: None,
+      secret_patterns_input: None,
       scrollback_lines_input: None,
       kubeconfig_input: None,
       default_namespace_input: None,
@@ -239,15 +276,23 @@ impl SettingsView {
     }
   }
 
-  fn ensure_initialized(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
-    if self.initialized {
+  /// Theme selects with live preview. Rebuilt after the theme list is
+  /// re-scanned.
+  fn ensure_theme_select(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.theme_select.is_some() {
       return;
     }
     let settings = self.settings_state.read(cx).settings.clone();
-
-    // Theme select with live preview.
-    let themes = ThemeOption::all();
-    let current_theme_idx = themes.iter().position(|t| t.theme == settings.theme).unwrap_or(0);
+    let mut themes = vec![ThemeOption::auto()];
+    themes.extend(ThemeOption::all(cx));
+    let current_theme_idx = if settings.theme_follow_system {
+      0
+    } else {
+      themes
+        .iter()
+        .position(|t| t.theme.as_ref() == Some(&settings.theme))
+        .unwrap_or(0)
+    };
     self.last_theme_index = Some(current_theme_idx);
     let theme_select = cx.new(|cx| SelectState::new(themes, Some(IndexPath::new(current_theme_idx)), window, cx));
     cx.subscribe(
@@ -258,15 +303,11 @@ impl SettingsView {
           this.last_theme_index = current_index;
           let chosen = select.read(cx).selected_value().map(|opt| opt.theme.clone());
           if let Some(chosen) = chosen {
-            let theme_name = SharedString::from(chosen.theme_name().to_string());
-            if let Some(cfg) = ThemeRegistry::global(cx).themes().get(&theme_name).cloned() {
-              Theme::global_mut(cx).apply_config(&cfg);
-            }
-            this.settings_state.update(cx, |state, cx| {
-              state.settings.theme = chosen;
-              let _ = state.settings.save();
-              cx.emit(SettingsChanged::ThemeChanged);
-              cx.emit(SettingsChanged::SettingsUpdated);
+            this.update_theme_settings(cx, |settings| {
+              settings.theme_follow_system = chosen.is_none();
+              if let Some(theme) = chosen {
+                settings.theme = theme;
+              }
             });
           }
         }
@@ -274,6 +315,83 @@ impl SettingsView {
     )
     .detach();
     self.theme_select = Some(theme_select);
+    self.light_theme_select = Some(Self::mode_theme_select(false, &settings.light_theme, window, cx));
+    self.dark_theme_select = Some(Self::mode_theme_select(true, &settings.dark_theme, window, cx));
+  }
+
+  /// Select for the theme used with a light or dark system appearance.
+  fn mode_theme_select(
+    dark: bool,
+    current: &ThemeName,
+    window: &mut Window,
+    cx: &mut Context<'_, Self>,
+  ) -> Entity<SelectState<Vec<ThemeOption>>> {
+    let themes = ThemeOption::all(cx);
+    let idx = themes
+      .iter()
+      .position(|t| t.theme.as_ref() == Some(current))
+      .unwrap_or(0);
+    let select = cx.new(|cx| SelectState::new(themes, Some(IndexPath::new(idx)), window, cx));
+    cx.subscribe(
+      &select,
+      move |this, _select, event: &gpui_component::select::SelectEvent<Vec<ThemeOption>>, cx| {
+        let gpui_component::select::SelectEvent::Confirm(Some(ThemeOption { theme: Some(theme), .. })) = event else {
+          return;
+        };
+        let theme = theme.clone();
+        this.update_theme_settings(cx, |settings| {
+          if dark {
+            settings.dark_theme = theme;
+          } else {
+            settings.light_theme = theme;
+          }
+        });
+      },
+    )
+    .detach();
+    select
+  }
+
+  /// Change theme settings, save them and apply the resulting theme.
+  fn update_theme_settings(&mut self, cx: &mut Context<'_, Self>, change: impl FnOnce(&mut AppSettings)) {
+    let settings = self.settings_state.update(cx, |state, cx| {
+      change(&mut state.settings);
+      let _ = state.settings.save();
+      cx.emit(SettingsChanged::ThemeChanged);
+      cx.emit(SettingsChanged::SettingsUpdated);
+      state.settings.clone()
+    });
+    apply_active_theme(&settings, cx.window_appearance(), cx);
+    cx.notify();
+  }
+
+  /// Pick a theme JSON file and import it.
+  fn import_theme(cx: &mut App) {
+    let opts = gpui::PathPromptOptions {
+      files: true,
+      directories: false,
+      multiple: false,
+      prompt: Some("Import theme".into()),
+    };
+    let rx = cx.prompt_for_paths(opts);
+    cx.spawn(async move |cx| {
+      let Ok(Ok(Some(paths))) = rx.await else {
+        return;
+      };
+      if let Some(path) = paths.into_iter().next() {
+        let _ = cx.update(|cx| crate::services::import_theme(&path, cx));
+      }
+    })
+    .detach();
+  }
+
+  fn ensure_initialized(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.initialized {
+      return;
+    }
+    let settings = self.settings_state.read(cx).settings.clone();
+
+    self.ensure_theme_select(window, cx);
 
     // Editor select.
     let editors = EditorOption::all();
@@ -349,6 +467,26 @@ impl SettingsView {
         .placeholder("System mono")
         .default_value(&settings.terminal_font_family)
     }));
+    self.shell_input = Some(cx.new(|cx| {
+      InputState::new(window, cx)
+        .placeholder("Auto (bash, then sh)")
+        .default_value(&settings.terminal_shell)
+    }));
+    self.editor_command_input = Some(cx.new(|cx| {
+      InputState::new(window, cx)
+        .placeholder("subl --wait")
+        .default_value(&settings.external_editor_command)
+    }));
+    self.env_input = Some(cx.new(|cx| {
+      InputState::new(window, cx)
+        .placeholder("EDITOR=vim, LANG=C.UTF-8")
+        .default_value(settings.terminal_env.join(", "))
+    }));
+    self.secret_patterns_input = Some(cx.new(|cx| {
+      InputState::new(window, cx)
+        .placeholder("*_TOKEN, *_PASSWORD")
+        .default_value(settings.secret_key_patterns.join(", "))
+    }));
     self.default_platform_input = Some(cx.new(|cx| {
       InputState::new(window, cx)
         .placeholder("linux/amd64")
@@ -392,6 +530,10 @@ impl SettingsView {
       self.line_height_input.clone(),
       self.scrollback_lines_input.clone(),
       self.font_family_input.clone(),
+      self.shell_input.clone(),
+      self.editor_command_input.clone(),
+      self.env_input.clone(),
+      self.secret_patterns_input.clone(),
       self.default_platform_input.clone(),
       self.kubeconfig_input.clone(),
       self.default_namespace_input.clone(),
@@ -465,6 +607,21 @@ impl SettingsView {
       .as_ref()
       .map(|i| i.read(cx).text().to_string())
       .unwrap_or_default();
+    let shell = self
+      .shell_input
+      .as_ref()
+      .map(|i| i.read(cx).text().to_string().trim().to_string())
+      .unwrap_or_default();
+    let editor_command = self
+      .editor_command_input
+      .as_ref()
+      .map(|i| i.read(cx).text().to_string().trim().to_string())
+      .unwrap_or_default();
+    let env: Vec<String> = self
+      .env_input
+      .as_ref()
+      .map(|i| split_env_entries(&i.read(cx).text().to_string()))
+      .unwrap_or_default();
     let default_platform = self
       .default_platform_input
       .as_ref()
@@ -483,6 +640,20 @@ impl SettingsView {
         .unwrap_or_default();
       if v.is_empty() { "default".to_string() } else { v }
     };
+    let secret_patterns: Vec<String> = self
+      .secret_patterns_input
+      .as_ref()
+      .map(|i| {
+        i.read(cx)
+          .text()
+          .to_string()
+          .split(',')
+          .map(str::trim)
+          .filter(|pattern| !pattern.is_empty())
+          .map(String::from)
+          .collect()
+      })
+      .unwrap_or_default();
     let colima_cpus = self
       .colima_cpus_input
       .as_ref()
@@ -533,6 +704,10 @@ impl SettingsView {
       state.settings.terminal_line_height = line_height;
       state.settings.terminal_scrollback_lines = scrollback_lines;
       state.settings.terminal_font_family = font_family;
+      state.settings.terminal_shell = shell;
+      state.settings.external_editor_command = editor_command;
+      state.settings.terminal_env = env;
+      state.settings.secret_key_patterns = secret_patterns;
       state.settings.default_pull_platform = default_platform;
       state.settings.kubeconfig_path = kubeconfig;
       state.settings.default_namespace = default_namespace;
@@ -549,12 +724,8 @@ impl SettingsView {
   }
 
   fn reset_to_defaults(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
-    let default_settings = crate::state::AppSettings::default();
-    let default_theme = default_settings.theme.clone();
-    let theme_name = SharedString::from(default_theme.theme_name().to_string());
-    if let Some(cfg) = ThemeRegistry::global(cx).themes().get(&theme_name).cloned() {
-      Theme::global_mut(cx).apply_config(&cfg);
-    }
+    let default_settings = AppSettings::default();
+    apply_active_theme(&default_settings, cx.window_appearance(), cx);
     self.settings_state.update(cx, |state, cx| {
       state.settings = default_settings;
       let _ = state.settings.save();
@@ -563,6 +734,8 @@ impl SettingsView {
     // Rebuild form controls so values reflect defaults.
     self.initialized = false;
     self.theme_select = None;
+    self.light_theme_select = None;
+    self.dark_theme_select = None;
     self.editor_select = None;
     self.cursor_style_select = None;
     self.docker_socket_input = None;
@@ -574,6 +747,10 @@ impl SettingsView {
     self.line_height_input = None;
     self.scrollback_lines_input = None;
     self.font_family_input = None;
+    self.shell_input = None;
+    self.editor_command_input = None;
+    self.env_input = None;
+    self.secret_patterns_input = None;
     self.default_platform_input = None;
     self.kubeconfig_input = None;
     self.default_namespace_input = None;
@@ -695,12 +872,31 @@ impl SettingsView {
     let log_input = self.log_lines_input.clone().unwrap();
     let confirm = self.settings_state.read(cx).settings.confirm_destructive;
     let notify = self.settings_state.read(cx).settings.show_notifications;
+    let command_log = self.settings_state.read(cx).settings.command_log_enabled;
+    let mask_secrets = self.settings_state.read(cx).settings.mask_secrets;
+    let secret_patterns_input = self.secret_patterns_input.clone().unwrap();
+    let auto_refresh = !self.settings_state.read(cx).settings.auto_refresh_paused;
     Self::body()
       .child(form_section("Refresh", cx))
+      .child(form_field(
+        "Auto-refresh",
+        Switch::new("auto-refresh")
+          .checked(auto_refresh)
+          .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+            this.settings_state.update(cx, |state, cx| {
+              state.settings.auto_refresh_paused = !*checked;
+              let _ = state.settings.save();
+              cx.emit(SettingsChanged::SettingsUpdated);
+            });
+            cx.notify();
+          })),
+        Some("Reload lists and stats on the intervals below. Event watchers keep running when off."),
+        cx,
+      ))
       .child(form_field(
         "Container refresh",
         Input::new(&container_input).small().w_full(),
-        Some("How often to refresh the container list, in seconds."),
+        Some("How often to refresh resource lists, in seconds."),
         cx,
       ))
       .child(form_field(
@@ -746,11 +942,55 @@ impl SettingsView {
         Some("Surface OS notifications when background tasks finish."),
         cx,
       ))
+      .child(form_field(
+        "Record command log",
+        Switch::new("command-log-enabled")
+          .checked(command_log)
+          .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+            this.settings_state.update(cx, |state, cx| {
+              state.settings.command_log_enabled = *checked;
+              let _ = state.settings.save();
+              cx.emit(SettingsChanged::SettingsUpdated);
+            });
+            cx.notify();
+          })),
+        Some("Keep the CLI equivalent of each action (Command Log in the palette)."),
+        cx,
+      ))
+      .child(form_section("Privacy", cx))
+      .child(form_field(
+        "Mask secrets",
+        Switch::new("mask-secrets")
+          .checked(mask_secrets)
+          .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+            this.settings_state.update(cx, |state, cx| {
+              state.settings.mask_secrets = *checked;
+              let _ = state.settings.save();
+              cx.emit(SettingsChanged::SettingsUpdated);
+            });
+            cx.notify();
+          })),
+        Some("Hide env var values that look like secrets in inspect, image and compose views until clicked."),
+        cx,
+      ))
+      .child(form_field(
+        "Secret key patterns",
+        Input::new(&secret_patterns_input).small().w_full(),
+        Some("Comma-separated, case-insensitive; `*` matches anything."),
+        cx,
+      ))
       .into_any_element()
   }
 
   fn render_appearance(&self, cx: &Context<'_, Self>) -> gpui::AnyElement {
     let theme_select = self.theme_select.clone().unwrap();
+    let light_theme_select = self.light_theme_select.clone().unwrap();
+    let dark_theme_select = self.dark_theme_select.clone().unwrap();
+    let settings = &self.settings_state.read(cx).settings;
+    let follow_system = settings.theme_follow_system;
+    let show_docker = settings.show_docker_section;
+    let show_kubernetes = settings.kubernetes_enabled;
+    let show_runtimes = settings.show_runtimes_section;
     Self::body()
       .child(form_section("Theme", cx))
       .child(form_field(
@@ -759,6 +999,93 @@ impl SettingsView {
         Some("Color theme applied across the whole app."),
         cx,
       ))
+      .when(follow_system, |el| {
+        el.child(form_field(
+          "Light theme",
+          Select::new(&light_theme_select).w_full().small(),
+          Some("Used while the system appearance is light."),
+          cx,
+        ))
+        .child(form_field(
+          "Dark theme",
+          Select::new(&dark_theme_select).w_full().small(),
+          Some("Used while the system appearance is dark."),
+          cx,
+        ))
+      })
+      .child(form_field(
+        "Theme files",
+        h_flex()
+          .gap(px(8.))
+          .child(
+            Button::new("import-theme")
+              .label("Import...")
+              .ghost()
+              .small()
+              .on_click(|_ev, _window, cx| Self::import_theme(cx)),
+          )
+          .child(
+            Button::new("reload-themes")
+              .label("Reload")
+              .ghost()
+              .small()
+              .on_click(|_ev, _window, cx| crate::services::reload_themes(cx)),
+          ),
+        Some(&format!(
+          "Imported themes are copied to {}. Reload picks up edited files.",
+          user_themes_dir().display()
+        )),
+        cx,
+      ))
+      .child(form_section("Sidebar", cx))
+      .child(form_field(
+        "Show Docker",
+        Switch::new("show-docker-section")
+          .checked(show_docker)
+          .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+            this.settings_state.update(cx, |state, cx| {
+              state.settings.show_docker_section = *checked;
+              let _ = state.settings.save();
+              cx.emit(SettingsChanged::SettingsUpdated);
+            });
+            crate::services::apply_section_settings(cx);
+            cx.notify();
+          })),
+        Some("Containers, Compose, Images, Volumes and Networks. Hiding it also stops Docker event watching."),
+        cx,
+      ))
+      .child(form_field(
+        "Show Kubernetes",
+        Switch::new("show-kubernetes-section")
+          .checked(show_kubernetes)
+          .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+            this.settings_state.update(cx, |state, cx| {
+              state.settings.kubernetes_enabled = *checked;
+              let _ = state.settings.save();
+              cx.emit(SettingsChanged::SettingsUpdated);
+            });
+            crate::services::apply_section_settings(cx);
+            cx.notify();
+          })),
+        Some("Same as Enable Kubernetes on the Kubernetes page."),
+        cx,
+      ))
+      .child(form_field(
+        "Show Runtimes",
+        Switch::new("show-runtimes-section")
+          .checked(show_runtimes)
+          .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+            this.settings_state.update(cx, |state, cx| {
+              state.settings.show_runtimes_section = *checked;
+              let _ = state.settings.save();
+              cx.emit(SettingsChanged::SettingsUpdated);
+            });
+            crate::services::apply_section_settings(cx);
+            cx.notify();
+          })),
+        Some("Machines and Colima actions. Hiding it also stops machine polling."),
+        cx,
+      ))
       .into_any_element()
   }
 
@@ -768,6 +1095,8 @@ impl SettingsView {
     let scroll_input = self.scrollback_lines_input.clone().unwrap();
     let cursor_select = self.cursor_style_select.clone().unwrap();
     let font_family_input = self.font_family_input.clone().unwrap();
+    let shell_input = self.shell_input.clone().unwrap();
+    let env_input = self.env_input.clone().unwrap();
     let blink = self.settings_state.read(cx).settings.terminal_cursor_blink;
 
     Self::body()
@@ -818,6 +1147,19 @@ impl SettingsView {
         Some("Blink the terminal cursor."),
         cx,
       ))
+      .child(form_section("Sessions", cx))
+      .child(form_field(
+        "Shell",
+        Input::new(&shell_input).small().w_full(),
+        Some("Tried first for container exec and machine SSH. Falls back to bash, then sh. Applies to new sessions."),
+        cx,
+      ))
+      .child(form_field(
+        "Environment",
+        Input::new(&env_input).small().w_full(),
+        Some("Comma-separated KEY=VALUE pairs exported into new terminal sessions."),
+        cx,
+      ))
       .into_any_element()
   }
 
@@ -856,6 +1198,7 @@ impl SettingsView {
               let _ = state.settings.save();
               cx.emit(SettingsChanged::SettingsUpdated);
             });
+            crate::services::apply_section_settings(cx);
             cx.notify();
           },
         )),
@@ -1324,6 +1667,7 @@ impl SettingsView {
             let _ = state.settings.save();
             cx.emit(SettingsChanged::SettingsUpdated);
           });
+          crate::services::apply_section_settings(cx);
           cx.notify();
         })),
       Some("Show the Machines view and Colima actions."),
@@ -1338,7 +1682,23 @@ impl SettingsView {
     let memory_input = self.colima_memory_input.clone().unwrap();
     let disk_input = self.colima_disk_input.clone().unwrap();
 
+    let follows_machine = self.settings_state.read(cx).settings.docker_follows_machine;
     col = col
+      .child(form_field(
+        "Docker follows selected machine",
+        Switch::new("docker-follows-machine")
+          .checked(follows_machine)
+          .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+            this.settings_state.update(cx, |state, cx| {
+              state.settings.docker_follows_machine = *checked;
+              let _ = state.settings.save();
+              cx.emit(SettingsChanged::SettingsUpdated);
+            });
+            cx.notify();
+          })),
+        Some("Selecting a running machine switches the Docker context and reloads every list from its daemon."),
+        cx,
+      ))
       .child(form_section("VM defaults", cx))
       .child(form_field(
         "Default profile",
@@ -1408,13 +1768,37 @@ impl SettingsView {
 
   fn render_editor(&self, cx: &Context<'_, Self>) -> gpui::AnyElement {
     let editor_select = self.editor_select.clone().unwrap();
-    let wait_close = self.settings_state.read(cx).settings.editor_wait_close;
+    let command_input = self.editor_command_input.clone().unwrap();
+    let settings = &self.settings_state.read(cx).settings;
+    let wait_close = settings.editor_wait_close;
+    let sync_back = settings.editor_sync_back;
     Self::body()
       .child(form_section("External editor", cx))
       .child(form_field(
         "External editor",
         Select::new(&editor_select).w_full().small(),
-        Some("Editor used when opening container or volume files remotely."),
+        Some("Files open as a local copy; folders open remotely in VS Code, Cursor or Zed."),
+        cx,
+      ))
+      .child(form_field(
+        "Custom command",
+        Input::new(&command_input).small().w_full(),
+        Some("Used by the Custom command editor. The file path is appended."),
+        cx,
+      ))
+      .child(form_field(
+        "Write changes back",
+        Switch::new("editor-sync-back").checked(sync_back).on_click(cx.listener(
+          |this, checked: &bool, _window, cx| {
+            this.settings_state.update(cx, |state, cx| {
+              state.settings.editor_sync_back = *checked;
+              let _ = state.settings.save();
+              cx.emit(SettingsChanged::SettingsUpdated);
+            });
+            cx.notify();
+          },
+        )),
+        Some("Copy saves of an opened container or machine file back into it."),
         cx,
       ))
       .child(form_field(
@@ -1429,7 +1813,7 @@ impl SettingsView {
             });
             cx.notify();
           })),
-        Some("Block the calling task until the editor process exits."),
+        Some("Pass --wait to VS Code, Cursor and Zed so write-back stops when the file is closed."),
         cx,
       ))
       .into_any_element()
@@ -1582,6 +1966,7 @@ impl SettingsView {
 impl Render for SettingsView {
   fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
     self.ensure_initialized(window, cx);
+    self.ensure_theme_select(window, cx);
     let colors = cx.theme().colors;
 
     let body = match self.active {
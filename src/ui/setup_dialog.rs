@@ -1,6 +1,6 @@
-use gpui::{App, Context, FocusHandle, Focusable, Render, Styled, Window, div, prelude::*, px};
+use gpui::{App, Context, FocusHandle, Focusable, Render, Styled, Subscription, Window, div, prelude::*, px};
 use gpui_component::{
-  Icon, IconName, Sizable,
+  Disableable, Icon, IconName, Sizable, WindowExt,
   button::{Button, ButtonVariants},
   h_flex,
   label::Label,
@@ -8,12 +8,26 @@ use gpui_component::{
   theme::ActiveTheme,
   v_flex,
 };
+use std::io::{BufRead, BufReader};
 use std::path::Path;
+use std::process::Stdio;
 
 use crate::platform::Platform;
+use crate::services::{DispatcherEvent, complete_task, dispatcher, fail_task, log_command, start_task};
 #[cfg(any(target_os = "macos", target_os = "linux"))]
 use crate::utils::colima_cmd;
-use crate::utils::{find_binary, kubectl_cmd};
+use crate::utils::{brew_cmd, find_binary, kubectl_cmd};
+
+/// Lines of `brew install` output kept on screen
+const INSTALL_OUTPUT_LINES: usize = 200;
+
+/// How often the checks re-run while Colima starts from the dialog
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+const START_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Polls to wait for Docker after the start task has finished
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+const CONNECT_ATTEMPTS: u32 = 15;
 
 /// K8s diagnostic result
 #[derive(Debug, Clone, Default)]
@@ -26,6 +40,13 @@ pub struct K8sDiagnostic {
   pub error_message: Option<String>,
 }
 
+/// Context chosen in the app's context selector, if any. `KubeClient`
+/// uses it instead of kubeconfig's `current-context`.
+fn pinned_kube_context() -> Option<String> {
+  let pinned = crate::state::AppSettings::load().kube_context;
+  (!pinned.is_empty()).then_some(pinned)
+}
+
 /// Run K8s diagnostics (quick version - NO network calls)
 /// Use this for startup checks to avoid blocking the UI
 pub fn diagnose_k8s_quick() -> K8sDiagnostic {
@@ -44,12 +65,16 @@ pub fn diagnose_k8s_quick() -> K8sDiagnostic {
     ..Default::default()
   };
 
-  // Get current context (fast local operation - reads ~/.kube/config)
-  if let Ok(output) = kubectl_cmd().args(["config", "current-context"]).output()
-    && output.status.success()
-  {
-    diag.current_context = Some(String::from_utf8_lossy(&output.stdout).trim().to_string());
-  }
+  // Get current context: the one picked in the context selector, else
+  // kubeconfig's own (fast local operation - reads ~/.kube/config)
+  diag.current_context = pinned_kube_context().or_else(|| {
+    kubectl_cmd()
+      .args(["config", "current-context"])
+      .output()
+      .ok()
+      .filter(|output| output.status.success())
+      .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+  });
 
   // Check if colima is running and has K8s (fast local operation) - only on supported platforms
   #[cfg(any(target_os = "macos", target_os = "linux"))]
@@ -86,9 +111,11 @@ pub fn diagnose_k8s_quick() -> K8sDiagnostic {
 /// Run full K8s API check (may be slow - use in background only)
 pub fn check_k8s_api() -> (bool, Option<String>) {
   if find_binary("kubectl").is_some() {
-    let api_check = kubectl_cmd()
-      .args(["version", "--client=false", "--request-timeout=3s"])
-      .output();
+    let mut cmd = kubectl_cmd();
+    if let Some(context) = pinned_kube_context() {
+      cmd.args(["--context", &context]);
+    }
+    let api_check = cmd.args(["version", "--client=false", "--request-timeout=3s"]).output();
 
     match api_check {
       Ok(output) if output.status.success() => (true, None),
@@ -234,6 +261,14 @@ pub struct SetupDialog {
   homebrew_installed: bool,
   k8s_diagnostic: K8sDiagnostic,
   action_message: Option<String>,
+  /// Output of the last `brew install` run from the dialog
+  install_output: Vec<String>,
+  installing: bool,
+  install_error: Option<String>,
+  /// Start task while "Start Colima" is in progress
+  starting_task: Option<u64>,
+  start_error: Option<String>,
+  task_observer: Option<Subscription>,
 }
 
 impl SetupDialog {
@@ -251,6 +286,12 @@ impl SetupDialog {
       homebrew_installed: is_homebrew_installed(),
       k8s_diagnostic: diagnose_k8s_quick(),
       action_message: None,
+      install_output: Vec::new(),
+      installing: false,
+      install_error: None,
+      starting_task: None,
+      start_error: None,
+      task_observer: None,
     };
 
     // If K8s is expected, run async API check
@@ -318,6 +359,187 @@ impl SetupDialog {
     .detach();
   }
 
+  /// Install Colima (and the Docker CLI when it's missing) with Homebrew,
+  /// streaming brew's output into the dialog, then re-run the checks.
+  pub fn install_colima(&mut self, cx: &mut Context<'_, Self>) {
+    if self.installing {
+      return;
+    }
+    let mut packages = vec!["colima"];
+    if !self.docker_installed {
+      packages.push("docker");
+    }
+    let command = format!("brew install {}", packages.join(" "));
+    log_command(cx, command.clone());
+    let task_id = start_task(cx, format!("Installing {} with Homebrew...", packages.join(", ")));
+    self.installing = true;
+    self.install_error = None;
+    self.install_output = vec![format!("$ {command}")];
+    cx.notify();
+
+    // brew's output lines, appended to the dialog as they arrive
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    cx.spawn(async move |this, cx| {
+      while let Some(line) = rx.recv().await {
+        let appended = this.update(cx, |this, cx| {
+          this.push_install_line(line);
+          cx.notify();
+        });
+        if appended.is_err() {
+          break;
+        }
+      }
+    })
+    .detach();
+
+    let disp = dispatcher(cx);
+    cx.spawn(async move |this, cx| {
+      let result = cx
+        .background_executor()
+        .spawn(async move {
+          let mut child = brew_cmd()
+            .arg("install")
+            .args(&packages)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| anyhow::anyhow!("Failed to start brew: {e}"))?;
+          // Drain stderr on its own thread so neither pipe fills up
+          let stderr_lines = child.stderr.take().map(|stderr| {
+            let tx = tx.clone();
+            std::thread::spawn(move || {
+              let mut lines = Vec::new();
+              for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                let _ = tx.send(line.clone());
+                lines.push(line);
+              }
+              lines
+            })
+          });
+          if let Some(stdout) = child.stdout.take() {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+              let _ = tx.send(line);
+            }
+          }
+          let status = child.wait()?;
+          let stderr_lines = stderr_lines.and_then(|h| h.join().ok()).unwrap_or_default();
+          if status.success() {
+            Ok(())
+          } else {
+            let last = stderr_lines.iter().rev().find(|l| !l.trim().is_empty());
+            Err(anyhow::anyhow!(
+              "{}",
+              last.cloned().unwrap_or_else(|| format!("brew exited with {status}"))
+            ))
+          }
+        })
+        .await;
+
+      let _ = cx.update(|cx| match &result {
+        Ok(()) => {
+          complete_task(cx, task_id);
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskCompleted {
+              message: "Colima installed".to_string(),
+            });
+          });
+        }
+        Err(e) => {
+          fail_task(cx, task_id, e.to_string());
+          disp.update(cx, |_, cx| {
+            cx.emit(DispatcherEvent::TaskFailed {
+              error: format!("Failed to install Colima: {e}"),
+            });
+          });
+        }
+      });
+
+      let _ = this.update(cx, |this, cx| {
+        this.installing = false;
+        this.install_error = result.err().map(|e| e.to_string());
+        this.refresh_status(cx);
+      });
+    })
+    .detach();
+  }
+
+  /// Start the default Colima machine, re-running the checks while it
+  /// boots. Once Docker answers, reload everything and close the dialog.
+  #[cfg(any(target_os = "macos", target_os = "linux"))]
+  pub fn start_colima_and_connect(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+    if self.starting_task.is_some() {
+      return;
+    }
+    let task_id = crate::services::start_colima(None, cx);
+    self.starting_task = Some(task_id);
+    self.start_error = None;
+    // Re-render as the start task reports progress
+    let tasks = crate::services::task_manager(cx);
+    self.task_observer = Some(cx.observe(&tasks, |_, _, cx| cx.notify()));
+    cx.notify();
+
+    cx.spawn_in(window, async move |this, cx| {
+      let mut polls_after_start = 0;
+      loop {
+        cx.background_executor().timer(START_POLL_INTERVAL).await;
+        let (colima_running, docker_running) = cx
+          .background_executor()
+          .spawn(async { (is_colima_running(), is_docker_running()) })
+          .await;
+
+        let keep_polling = this.update_in(cx, |this, window, cx| {
+          this.colima_running = colima_running;
+          this.docker_running = docker_running;
+          if docker_running {
+            this.stop_starting();
+            crate::services::load_initial_data(cx);
+            window.close_dialog(cx);
+            return false;
+          }
+
+          let manager = crate::services::task_manager(cx).read(cx);
+          let task_running = manager.running_tasks().iter().any(|t| t.id == task_id);
+          let failure = manager
+            .history()
+            .find(|t| t.id == task_id)
+            .and_then(|t| match &t.status {
+              crate::services::TaskStatus::Failed(error) => Some(error.clone()),
+              _ => None,
+            });
+          if !task_running {
+            polls_after_start += 1;
+          }
+          if failure.is_some() || polls_after_start > CONNECT_ATTEMPTS {
+            this.start_error =
+              Some(failure.unwrap_or_else(|| "Colima started but Docker is not reachable yet".to_string()));
+            this.stop_starting();
+            cx.notify();
+            return false;
+          }
+          cx.notify();
+          true
+        });
+        if !matches!(keep_polling, Ok(true)) {
+          break;
+        }
+      }
+    })
+    .detach();
+  }
+
+  #[cfg(any(target_os = "macos", target_os = "linux"))]
+  fn stop_starting(&mut self) {
+    self.starting_task = None;
+    self.task_observer = None;
+  }
+
+  fn push_install_line(&mut self, line: String) {
+    if self.install_output.len() >= INSTALL_OUTPUT_LINES {
+      self.install_output.remove(0);
+    }
+    self.install_output.push(line);
+  }
+
   /// Render a requirement row with status
   #[allow(clippy::unused_self)]
   fn render_requirement_row(
@@ -410,12 +632,30 @@ impl SetupDialog {
             })
             .when(needs_colima, |el| {
               el.child(Self::render_command_row("copy-colima", "brew install colima", cx))
+            })
+            // With Homebrew present, offer to run the install from here
+            .when(needs_colima && !needs_homebrew, |el| {
+              let label = if needs_docker {
+                "Install Colima and Docker CLI"
+              } else {
+                "Install Colima"
+              };
+              el.child(
+                h_flex().child(
+                  Button::new("install-colima")
+                    .label(label)
+                    .icon(IconName::ArrowDown)
+                    .primary()
+                    .small()
+                    .loading(self.installing)
+                    .disabled(self.installing)
+                    .on_click(cx.listener(|this, _ev, _window, cx| this.install_colima(cx))),
+                ),
+              )
             }),
         )
       })
-      .when(needs_start, |el| {
-        el.child(Self::render_colima_start_section(cx, colors))
-      })
+      .when(needs_start, |el| el.child(self.render_colima_start_section(cx, colors)))
       .into_any_element()
   }
 
@@ -580,9 +820,21 @@ impl SetupDialog {
 
   #[cfg(any(target_os = "macos", target_os = "linux"))]
   fn render_colima_start_section(
-    _cx: &Context<'_, Self>,
+    &self,
+    cx: &Context<'_, Self>,
     colors: &gpui_component::theme::ThemeColor,
   ) -> impl IntoElement {
+    let starting = self.starting_task.is_some();
+    // Latest line from the start task while it runs
+    let progress = self.starting_task.and_then(|id| {
+      crate::services::task_manager(cx)
+        .read(cx)
+        .running_tasks()
+        .into_iter()
+        .find(|t| t.id == id)
+        .map(crate::services::Task::display_status)
+    });
+
     v_flex()
       .w_full()
       .gap(px(8.))
@@ -597,18 +849,19 @@ impl SetupDialog {
           .gap(px(8.))
           .child(
             Button::new("start-colima")
-              .label("Start")
+              .label("Start Colima")
               .primary()
               .small()
-              .on_click(|_ev, _window, cx| {
-                crate::services::start_machine("default".to_string(), cx);
-              }),
+              .loading(starting)
+              .disabled(starting)
+              .on_click(cx.listener(|this, _ev, window, cx| this.start_colima_and_connect(window, cx))),
           )
           .child(
             Button::new("start-colima-k8s")
               .label("Start with Kubernetes")
               .ghost()
               .small()
+              .disabled(starting)
               .on_click(|_ev, _window, cx| {
                 let mut config = crate::colima::ColimaConfig::default();
                 config.kubernetes.enabled = true;
@@ -616,16 +869,73 @@ impl SetupDialog {
               }),
           ),
       )
+      .when(starting, |el| {
+        el.child(
+          div()
+            .text_xs()
+            .text_color(colors.muted_foreground)
+            .child(progress.unwrap_or_else(|| "Waiting for Docker to become reachable...".to_string())),
+        )
+      })
+      .when_some(self.start_error.clone(), |el, error| {
+        el.child(div().text_xs().text_color(colors.danger).child(error))
+      })
   }
 
   #[cfg(not(any(target_os = "macos", target_os = "linux")))]
   fn render_colima_start_section(
+    &self,
     _cx: &Context<'_, Self>,
     _colors: &gpui_component::theme::ThemeColor,
   ) -> impl IntoElement {
     div()
   }
 
+  /// Output of the last in-dialog install, kept after it finishes so
+  /// failures can be read
+  fn render_install_output(&self, cx: &Context<'_, Self>) -> impl IntoElement {
+    let colors = &cx.theme().colors;
+    if self.install_output.is_empty() {
+      return div().into_any_element();
+    }
+
+    v_flex()
+      .w_full()
+      .gap(px(6.))
+      .child(
+        Label::new(if self.installing {
+          "Installing..."
+        } else {
+          "Install output"
+        })
+        .text_color(colors.foreground)
+        .text_xs()
+        .font_weight(gpui::FontWeight::MEDIUM),
+      )
+      .child(
+        div()
+          .w_full()
+          .max_h(px(160.))
+          .p(px(8.))
+          .rounded(px(4.))
+          .bg(colors.background)
+          .border_1()
+          .border_color(colors.border)
+          .overflow_y_scrollbar()
+          .child(
+            v_flex()
+              .font_family("monospace")
+              .text_xs()
+              .text_color(colors.muted_foreground)
+              .children(self.install_output.iter().map(|line| div().child(line.clone()))),
+          ),
+      )
+      .when_some(self.install_error.clone(), |el, error| {
+        el.child(div().text_xs().text_color(colors.danger).child(error))
+      })
+      .into_any_element()
+  }
+
   fn render_command_row(id: &'static str, command: &'static str, cx: &Context<'_, Self>) -> impl IntoElement {
     let colors = &cx.theme().colors;
     let cmd = command.to_string();
@@ -841,6 +1151,8 @@ impl Render for SetupDialog {
             )
             // Action section (only shows if something needs to be done)
             .child(self.render_action_section(cx))
+            // Output of an install started from the dialog
+            .child(self.render_install_output(cx))
             // K8s diagnostics section
             .child(self.render_k8s_diagnostic(cx))
   }
@@ -1,5 +1,5 @@
-//! Prompt flows for volume Backup, Restore, and Clone actions wired from
-//! the volume row menu.
+//! Prompt flows for volume Backup, Restore, Clone, and Delete actions wired
+//! from the volume row menu.
 
 use gpui::{App, Entity, ParentElement, Styled, Window, prelude::*, px};
 use gpui_component::{
@@ -9,9 +9,71 @@ use gpui_component::{
   v_flex,
 };
 
+use crate::docker::ContainerInfo;
 use crate::services;
+use crate::state::docker_state;
+use crate::ui::dialogs::open_confirm_dialog;
 
-pub fn prompt_backup_volume(name: String, _window: &mut Window, cx: &mut App) {
+/// Names of all containers, running or stopped, that mount `volume`.
+pub fn volume_users(volume: &str, containers: &[ContainerInfo]) -> Vec<String> {
+  containers
+    .iter()
+    .filter(|c| c.volumes_used.iter().any(|v| v == volume))
+    .map(|c| c.name.clone())
+    .collect()
+}
+
+/// Names of running containers that mount `volume`.
+fn running_users(volume: &str, containers: &[ContainerInfo]) -> Vec<String> {
+  containers
+    .iter()
+    .filter(|c| c.state.is_running() && c.volumes_used.iter().any(|v| v == volume))
+    .map(|c| c.name.clone())
+    .collect()
+}
+
+/// Run `proceed` right away when no running container mounts the volume,
+/// otherwise ask first. `risk` explains what can go wrong for this action.
+fn confirm_if_in_use(
+  name: &str,
+  action: &'static str,
+  risk: &'static str,
+  proceed: impl Fn(&mut Window, &mut App) + 'static,
+  window: &mut Window,
+  cx: &mut App,
+) {
+  let users = running_users(name, &docker_state(cx).read(cx).containers);
+  if users.is_empty() {
+    proceed(window, cx);
+    return;
+  }
+  open_confirm_dialog(
+    format!("{action} '{name}'?"),
+    format!(
+      "Volume '{name}' is in use by running container(s): {}.",
+      users.join(", ")
+    ),
+    Some(risk.into()),
+    format!("{action} Anyway"),
+    proceed,
+    window,
+    cx,
+  );
+}
+
+pub fn prompt_backup_volume(name: String, window: &mut Window, cx: &mut App) {
+  let volume = name.clone();
+  confirm_if_in_use(
+    &volume,
+    "Back Up",
+    "Files written during the backup may be captured half-changed. Stop the containers first for a consistent archive.",
+    move |_window, cx| pick_backup_folder(name.clone(), cx),
+    window,
+    cx,
+  );
+}
+
+fn pick_backup_folder(name: String, cx: &mut App) {
   let opts = gpui::PathPromptOptions {
     files: false,
     directories: true,
@@ -29,7 +91,19 @@ pub fn prompt_backup_volume(name: String, _window: &mut Window, cx: &mut App) {
   .detach();
 }
 
-pub fn prompt_restore_volume(name: String, _window: &mut Window, cx: &mut App) {
+pub fn prompt_restore_volume(name: String, window: &mut Window, cx: &mut App) {
+  let volume = name.clone();
+  confirm_if_in_use(
+    &volume,
+    "Restore",
+    "Restoring overwrites files the running containers may have open. Stop them first to avoid corrupting their data.",
+    move |_window, cx| pick_restore_archive(name.clone(), cx),
+    window,
+    cx,
+  );
+}
+
+fn pick_restore_archive(name: String, cx: &mut App) {
   let opts = gpui::PathPromptOptions {
     files: true,
     directories: false,
@@ -47,6 +121,26 @@ pub fn prompt_restore_volume(name: String, _window: &mut Window, cx: &mut App) {
   .detach();
 }
 
+/// Delete right away when no container references the volume, otherwise
+/// list the containers and ask first. Stopped containers count too: their
+/// data is still in the volume.
+pub fn prompt_delete_volume(name: String, window: &mut Window, cx: &mut App) {
+  let users = volume_users(&name, &docker_state(cx).read(cx).containers);
+  if users.is_empty() {
+    services::delete_volume(name, cx);
+    return;
+  }
+  open_confirm_dialog(
+    format!("Delete '{name}'?"),
+    format!("Volume '{name}' is used by container(s): {}.", users.join(", ")),
+    Some("Its data is removed for good and those containers lose it, even if they are stopped right now.".into()),
+    "Delete Anyway",
+    move |_window, cx| services::delete_volume(name.clone(), cx),
+    window,
+    cx,
+  );
+}
+
 pub fn prompt_clone_volume(src: String, window: &mut Window, cx: &mut App) {
   let suggested = format!("{src}-clone");
   let input_state: Entity<InputState> = cx.new(|cx| {
@@ -96,3 +190,31 @@ pub fn prompt_clone_volume(src: String, window: &mut Window, cx: &mut App) {
       })
   });
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::docker::ContainerState;
+
+  fn container(name: &str, state: ContainerState, volumes: &[&str]) -> ContainerInfo {
+    ContainerInfo {
+      volumes_used: volumes.iter().map(ToString::to_string).collect(),
+      ..ContainerInfo::for_test(name, "postgres", state)
+    }
+  }
+
+  #[test]
+  fn test_running_users() {
+    let containers = vec![
+      container("db", ContainerState::Running, &["pgdata"]),
+      container("old-db", ContainerState::Exited, &["pgdata"]),
+      container("web", ContainerState::Running, &["static"]),
+    ];
+    assert_eq!(running_users("pgdata", &containers), vec!["db".to_string()]);
+    assert!(running_users("cache", &containers).is_empty());
+    assert_eq!(
+      volume_users("pgdata", &containers),
+      vec!["db".to_string(), "old-db".to_string()]
+    );
+  }
+}
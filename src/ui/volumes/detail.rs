@@ -1,6 +1,8 @@
 use gpui::{App, Entity, Styled, Window, div, prelude::*, px};
 use gpui_component::{
-  Icon, Selectable, h_flex,
+  Icon, Selectable,
+  button::Button,
+  h_flex,
   input::InputState,
   scroll::ScrollableElement,
   tab::{Tab, TabBar},
@@ -11,7 +13,7 @@ use std::rc::Rc;
 
 use crate::assets::AppIcon;
 use crate::docker::{VolumeFileEntry, VolumeInfo};
-use crate::ui::components::{FileExplorer, FileExplorerConfig, FileExplorerState};
+use crate::ui::components::{CopyCallback, FileExplorer, FileExplorerConfig, FileExplorerState, copy_button};
 
 type TabChangeCallback = Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>;
 type FileNavigateCallback = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
@@ -49,6 +51,7 @@ pub struct VolumeDetail {
   file_content_editor: Option<Entity<InputState>>,
   used_by: Vec<String>,
   on_tab_change: Option<TabChangeCallback>,
+  on_copy: Option<CopyCallback>,
   on_navigate_path: Option<FileNavigateCallback>,
   on_file_select: Option<FileSelectCallback>,
   on_close_file_viewer: Option<CloseViewerCallback>,
@@ -64,6 +67,7 @@ impl VolumeDetail {
       file_content_editor: None,
       used_by: Vec::new(),
       on_tab_change: None,
+      on_copy: None,
       on_navigate_path: None,
       on_file_select: None,
       on_close_file_viewer: None,
@@ -104,6 +108,14 @@ impl VolumeDetail {
     self
   }
 
+  pub fn on_copy<F>(mut self, callback: F) -> Self
+  where
+    F: Fn(&str, &mut Window, &mut App) + 'static,
+  {
+    self.on_copy = Some(Rc::new(callback));
+    self
+  }
+
   pub fn on_navigate_path<F>(mut self, callback: F) -> Self
   where
     F: Fn(&str, &mut Window, &mut App) + 'static,
@@ -166,10 +178,17 @@ impl VolumeDetail {
     let _colors = &cx.theme().colors;
 
     // Basic info rows
-    let mut basic_info = vec![("Name", volume.name.clone()), ("Size", volume.display_size())];
+    let mut basic_info = vec![
+      (
+        "Name",
+        volume.name.clone(),
+        self.copy_button("copy-volume-name", volume.name.clone()),
+      ),
+      ("Size", volume.display_size(), None),
+    ];
 
     if let Some(created) = volume.created {
-      basic_info.insert(1, ("Created", created.format("%Y-%m-%d %H:%M:%S").to_string()));
+      basic_info.insert(1, ("Created", created.format("%Y-%m-%d %H:%M:%S").to_string(), None));
     }
 
     v_flex()
@@ -177,7 +196,7 @@ impl VolumeDetail {
             .w_full()
             .p(px(16.))
             .gap(px(12.))
-            .child(Self::render_section(None, basic_info, cx))
+            .child(Self::render_copy_section(None, basic_info, cx))
             // Labels section if not empty
             .when(!volume.labels.is_empty(), |el| {
                 el.child(Self::render_labels_section(volume, cx))
@@ -232,6 +251,11 @@ impl VolumeDetail {
   }
 
   fn render_section(header: Option<&str>, rows: Vec<(&str, String)>, cx: &App) -> gpui::Div {
+    Self::render_copy_section(header, rows.into_iter().map(|(l, v)| (l, v, None)).collect(), cx)
+  }
+
+  /// Like `render_section`, but a row can carry a copy button.
+  fn render_copy_section(header: Option<&str>, rows: Vec<(&str, String, Option<Button>)>, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
     let mut section = v_flex().gap(px(1.));
@@ -255,13 +279,13 @@ impl VolumeDetail {
         rows
           .into_iter()
           .enumerate()
-          .map(|(i, (label, value))| Self::render_section_row(label, value, i == 0, cx)),
+          .map(|(i, (label, value, copy))| Self::render_section_row(label, value, copy, i == 0, cx)),
       );
 
     section.child(rows_container)
   }
 
-  fn render_section_row(label: &str, value: String, is_first: bool, cx: &App) -> gpui::Div {
+  fn render_section_row(label: &str, value: String, copy: Option<Button>, is_first: bool, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
     let mut row = h_flex()
@@ -277,13 +301,19 @@ impl VolumeDetail {
           .child(label.to_string()),
       )
       .child(
-        div()
-          .text_sm()
-          .text_color(colors.foreground)
-          .max_w(px(200.))
-          .overflow_hidden()
-          .text_ellipsis()
-          .child(value),
+        h_flex()
+          .gap(px(4.))
+          .items_center()
+          .child(
+            div()
+              .text_sm()
+              .text_color(colors.foreground)
+              .max_w(px(200.))
+              .overflow_hidden()
+              .text_ellipsis()
+              .child(value),
+          )
+          .children(copy),
       );
 
     if !is_first {
@@ -293,6 +323,11 @@ impl VolumeDetail {
     row
   }
 
+  /// Copy button for `text` when the parent wired `on_copy`.
+  fn copy_button(&self, id: &'static str, text: String) -> Option<Button> {
+    self.on_copy.clone().map(|cb| copy_button(id, text, cb))
+  }
+
   fn render_labels_section(volume: &VolumeInfo, cx: &App) -> gpui::Div {
     let colors = &cx.theme().colors;
 
@@ -382,6 +417,7 @@ impl VolumeDetail {
       file_content: state.map(|s| s.file_content.clone()).unwrap_or_default(),
       file_content_loading: state.is_some_and(|s| s.file_content_loading),
       file_content_error: None,
+      editing: false,
     };
 
     let files = state.map(|s| s.files.clone()).unwrap_or_default();
@@ -1,7 +1,8 @@
-use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
+use gpui::{App, Context, Entity, MouseButton, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::{
   Icon, IconName, IndexPath, Sizable,
   button::{Button, ButtonVariants},
+  checkbox::Checkbox,
   h_flex,
   input::{Input, InputState},
   label::Label,
@@ -14,8 +15,11 @@ use gpui_component::{
 use crate::assets::AppIcon;
 use crate::docker::VolumeInfo;
 use crate::services;
-use crate::state::{DockerState, LoadState, Selection, StateChanged, docker_state};
-use crate::ui::components::{render_error, render_loading};
+use crate::state::{CurrentView, DockerState, LoadState, Selection, StateChanged, docker_state};
+use crate::ui::components::{
+  CachedListDelegate, RefreshStatus, refresh_rows, render_bulk_delete_bar, render_docker_context_selector,
+  render_error, render_loading,
+};
 
 /// Volume list events emitted to parent
 pub enum VolumeListEvent {
@@ -26,6 +30,7 @@ pub enum VolumeListEvent {
 pub struct VolumeListDelegate {
   docker_state: Entity<DockerState>,
   search_query: String,
+  rows: Vec<VolumeInfo>,
 }
 
 impl VolumeListDelegate {
@@ -33,7 +38,7 @@ impl VolumeListDelegate {
     &self.docker_state.read(cx).volumes
   }
 
-  fn filtered_volumes(&self, cx: &App) -> Vec<VolumeInfo> {
+  fn collect_rows(&self, cx: &App) -> Vec<VolumeInfo> {
     let volumes = self.volumes(cx);
     if self.search_query.is_empty() {
       return volumes.clone();
@@ -51,16 +56,40 @@ impl VolumeListDelegate {
       .collect()
   }
 
-  pub fn set_search_query(&mut self, query: String) {
+  fn filtered_volumes(&self) -> &[VolumeInfo] {
+    &self.rows
+  }
+
+  pub fn set_search_query(&mut self, query: String, cx: &App) {
     self.search_query = query;
+    self.rebuild_rows(cx);
+  }
+}
+
+impl CachedListDelegate for VolumeListDelegate {
+  fn rebuild_rows(&mut self, cx: &App) {
+    self.rows = self.collect_rows(cx);
+  }
+
+  fn row_count(&self) -> usize {
+    self.rows.len()
+  }
+
+  fn selected_row(&self, cx: &App) -> Option<IndexPath> {
+    let global_selection = &self.docker_state.read(cx).selection;
+    self
+      .rows
+      .iter()
+      .position(|volume| matches!(global_selection, Selection::Volume(name) if *name == volume.name))
+      .map(IndexPath::new)
   }
 }
 
 impl ListDelegate for VolumeListDelegate {
   type Item = ListItem;
 
-  fn items_count(&self, _section: usize, cx: &App) -> usize {
-    self.filtered_volumes(cx).len()
+  fn items_count(&self, _section: usize, _cx: &App) -> usize {
+    self.filtered_volumes().len()
   }
 
   fn render_item(
@@ -69,14 +98,17 @@ impl ListDelegate for VolumeListDelegate {
     _window: &mut Window,
     cx: &mut Context<'_, ListState<Self>>,
   ) -> Option<Self::Item> {
-    let volumes = self.filtered_volumes(cx);
+    let volumes = self.filtered_volumes();
     let volume = volumes.get(ix.row)?;
     let colors = &cx.theme().colors;
 
     // Use global selection as single source of truth
     let global_selection = &self.docker_state.read(cx).selection;
     let is_selected = matches!(global_selection, Selection::Volume(name) if *name == volume.name);
-    let is_in_use = volume.is_in_use();
+    // Containers come from the list poll, so stopped ones count even when
+    // the daemon's usage data (ref count) wasn't requested
+    let used_by = super::volume_users(&volume.name, &self.docker_state.read(cx).containers);
+    let is_in_use = volume.is_in_use() || !used_by.is_empty();
     let volume_name = volume.name.clone();
 
     let icon_bg = if is_in_use {
@@ -128,17 +160,24 @@ impl ListDelegate for VolumeListDelegate {
             .item(
               PopupMenuItem::new("Delete")
                 .icon(Icon::new(AppIcon::Trash))
-                .on_click(move |_, _, cx| {
-                  services::delete_volume(name_delete.clone(), cx);
+                .on_click(move |_, w, cx| {
+                  crate::ui::volumes::prompt_delete_volume(name_delete.clone(), w, cx);
                 }),
             )
         }
       });
 
+    let checked = self.docker_state.read(cx).selected_volume_names.contains(&volume.name);
+    let cb_name = volume_name.clone();
+    let checkbox = Checkbox::new(("vol-cb", row))
+      .checked(checked)
+      .on_click(move |_, _, cx| services::toggle_volume_bulk_selection(&cb_name, cx));
+
     let item_content = h_flex()
       .w_full()
       .items_center()
       .gap(px(10.))
+      .child(div().flex_shrink_0().child(checkbox))
       .child(
         div()
           .size(px(36.))
@@ -175,8 +214,34 @@ impl ListDelegate for VolumeListDelegate {
               .child(size_text),
           ),
       )
+      .when(!used_by.is_empty(), |el| {
+        let tooltip = format!("Used by {}", used_by.join(", "));
+        el.child(
+          div()
+            .id(("vol-used-by", row))
+            .flex_shrink_0()
+            .px(px(6.))
+            .py(px(1.))
+            .rounded(px(4.))
+            .bg(colors.primary.opacity(0.15))
+            .text_xs()
+            .text_color(colors.primary)
+            .tooltip(move |window, cx| gpui_component::tooltip::Tooltip::new(tooltip.clone()).build(window, cx))
+            .child(format!("{} in use", used_by.len())),
+        )
+      })
       .child(div().flex_shrink_0().child(menu_button));
 
+    // Cmd/Ctrl-click ticks the row for bulk delete without moving the
+    // single selection.
+    let click_name = volume_name.clone();
+    let item_content = item_content.on_mouse_down(MouseButton::Left, move |event, _window, cx| {
+      if event.modifiers.platform || event.modifiers.control {
+        services::toggle_volume_bulk_selection(&click_name, cx);
+        cx.stop_propagation();
+      }
+    });
+
     let item = ListItem::new(ix)
       .py(px(6.))
       .rounded(px(6.))
@@ -201,6 +266,7 @@ impl ListDelegate for VolumeListDelegate {
 /// Self-contained volume list component
 pub struct VolumeList {
   docker_state: Entity<DockerState>,
+  refresh_status: Entity<RefreshStatus>,
   list_state: Entity<ListState<VolumeListDelegate>>,
   search_input: Option<Entity<InputState>>,
   search_visible: bool,
@@ -214,15 +280,17 @@ impl VolumeList {
     let delegate = VolumeListDelegate {
       docker_state: docker_state.clone(),
       search_query: String::new(),
+      rows: Vec::new(),
     };
 
     let list_state = cx.new(|cx| ListState::new(delegate, window, cx));
+    list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
 
     // Subscribe to list events
     cx.subscribe(&list_state, |_this, state, event: &ListEvent, cx| match event {
       ListEvent::Select(ix) | ListEvent::Confirm(ix) => {
         let delegate = state.read(cx).delegate();
-        let filtered = delegate.filtered_volumes(cx);
+        let filtered = delegate.filtered_volumes();
         if let Some(volume) = filtered.get(ix.row) {
           cx.emit(VolumeListEvent::Selected(Box::new(volume.clone())));
         }
@@ -232,18 +300,23 @@ impl VolumeList {
     .detach();
 
     // Subscribe to docker state changes to refresh list
-    cx.subscribe(&docker_state, |this, _state, event: &StateChanged, cx| {
-      if matches!(event, StateChanged::VolumesUpdated | StateChanged::SelectionChanged) {
-        this.list_state.update(cx, |_state, cx| {
+    cx.subscribe_in(
+      &docker_state,
+      window,
+      |this, _state, event: &StateChanged, window, cx| {
+        if matches!(event, StateChanged::VolumesUpdated | StateChanged::SelectionChanged) {
+          this.list_state.update(cx, |state, cx| refresh_rows(state, window, cx));
           cx.notify();
-        });
-        cx.notify();
-      }
-    })
+        } else if matches!(event, StateChanged::DockerContextsUpdated) {
+          cx.notify();
+        }
+      },
+    )
     .detach();
 
     Self {
       docker_state,
+      refresh_status: cx.new(|cx| RefreshStatus::new(CurrentView::Volumes, cx)),
       list_state,
       search_input: None,
       search_visible: false,
@@ -264,7 +337,7 @@ impl VolumeList {
       if current_text != self.search_query {
         current_text.clone_into(&mut self.search_query);
         self.list_state.update(cx, |state, cx| {
-          state.delegate_mut().set_search_query(current_text);
+          state.delegate_mut().set_search_query(current_text, cx);
           cx.notify();
         });
       }
@@ -279,7 +352,7 @@ impl VolumeList {
       self.search_query.clear();
       self.search_input = None;
       self.list_state.update(cx, |state, cx| {
-        state.delegate_mut().set_search_query(String::new());
+        state.delegate_mut().set_search_query(String::new(), cx);
         cx.notify();
       });
     }
@@ -417,7 +490,7 @@ impl Render for VolumeList {
     let colors = cx.theme().colors;
 
     // Get filtered count
-    let filtered_count = self.list_state.read(cx).delegate().filtered_volumes(cx).len();
+    let filtered_count = self.list_state.read(cx).delegate().filtered_volumes().len();
     let is_filtering = !self.search_query.is_empty();
     let volumes_empty = filtered_count == 0;
 
@@ -441,6 +514,18 @@ impl Render for VolumeList {
       }
     };
 
+    let bulk_count = self.docker_state.read(cx).selected_volume_names.len();
+    let bulk_bar = (bulk_count > 0).then(|| {
+      render_bulk_delete_bar(
+        "volumes",
+        bulk_count,
+        "The selected volumes and their data will be removed. Volumes mounted by a container are skipped.",
+        services::clear_volume_bulk_selection,
+        services::delete_selected_volumes,
+        cx,
+      )
+    });
+
     // Toolbar
     let toolbar = h_flex()
       .h(px(52.))
@@ -452,14 +537,19 @@ impl Render for VolumeList {
       .justify_between()
       .flex_shrink_0()
       .child(
-        v_flex()
-          .child(Label::new("Volumes"))
-          .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle)),
+        v_flex().child(Label::new("Volumes")).child(
+          h_flex()
+            .items_center()
+            .gap(px(8.))
+            .child(div().text_xs().text_color(colors.muted_foreground).child(subtitle))
+            .child(self.refresh_status.clone()),
+        ),
       )
       .child(
         h_flex()
           .items_center()
           .gap(px(8.))
+          .child(render_docker_context_selector(cx))
           .child(
             Button::new("search")
               .icon(Icon::new(AppIcon::Search))
@@ -553,6 +643,7 @@ impl Render for VolumeList {
       .flex_col()
       .overflow_hidden()
       .child(toolbar)
+      .children(bulk_bar)
       .children(search_bar)
       .child(
         div()
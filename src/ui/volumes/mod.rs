@@ -4,5 +4,7 @@ mod detail;
 mod list;
 mod view;
 
-pub use backup_restore::{prompt_backup_volume, prompt_clone_volume, prompt_restore_volume};
+pub use backup_restore::{
+  prompt_backup_volume, prompt_clone_volume, prompt_delete_volume, prompt_restore_volume, volume_users,
+};
 pub use view::VolumesView;
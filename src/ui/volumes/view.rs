@@ -2,12 +2,13 @@ use gpui::{App, Context, Entity, Render, Styled, Window, div, prelude::*, px};
 use gpui_component::{input::InputState, theme::ActiveTheme};
 
 use crate::docker::VolumeInfo;
-use crate::services;
+use crate::services::{self, RefreshInterval};
 use crate::state::{DockerState, Selection, StateChanged, docker_state};
-use crate::ui::components::detect_language_from_path;
+use crate::ui::components::{copy_to_clipboard, detect_language_from_path};
 
 use super::detail::{VolumeDetail, VolumeTabState};
 use super::list::{VolumeList, VolumeListEvent};
+use super::volume_users;
 
 /// Self-contained Volumes view - handles list, detail, and all state
 pub struct VolumesView {
@@ -105,6 +106,9 @@ impl VolumesView {
     })
     .detach();
 
+    // Periodic refresh for daemons without a reliable event stream
+    services::spawn_auto_refresh(RefreshInterval::Lists, 1, cx, |_, cx| services::refresh_volumes(cx));
+
     Self {
       docker_state,
       volume_list,
@@ -326,14 +330,7 @@ impl Render for VolumesView {
     // container list in docker_state; volumes_used (named volumes
     // only) is populated from the container list response.
     let used_by: Vec<String> = if let Some(ref vol) = selected_volume {
-      self
-        .docker_state
-        .read(cx)
-        .containers
-        .iter()
-        .filter(|c| c.volumes_used.iter().any(|v| v == &vol.name))
-        .map(|c| c.name.clone())
-        .collect()
+      volume_users(&vol.name, &self.docker_state.read(cx).containers)
     } else {
       Vec::new()
     };
@@ -348,6 +345,7 @@ impl Render for VolumesView {
       .on_tab_change(cx.listener(|this, tab: &usize, _window, cx| {
         this.on_tab_change(*tab, cx);
       }))
+      .on_copy(copy_to_clipboard)
       .on_navigate_path(cx.listener(|this, path: &str, _window, cx| {
         this.on_navigate_path(path, cx);
       }))
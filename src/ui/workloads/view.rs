@@ -8,7 +8,8 @@ use gpui_component::{
   v_flex,
 };
 
-use crate::ui::components::render_k8s_header;
+use crate::state::CurrentView;
+use crate::ui::components::{RefreshStatus, render_k8s_header};
 use crate::ui::cronjobs::CronJobsView;
 use crate::ui::daemonsets::DaemonSetsView;
 use crate::ui::deployments::DeploymentsView;
@@ -27,8 +28,23 @@ pub enum WorkloadsTab {
   CronJobs,
 }
 
+impl WorkloadsTab {
+  /// View whose refresh status the header shows for this tab
+  fn view(self) -> CurrentView {
+    match self {
+      WorkloadsTab::Pods => CurrentView::Pods,
+      WorkloadsTab::Deployments => CurrentView::Deployments,
+      WorkloadsTab::StatefulSets => CurrentView::StatefulSets,
+      WorkloadsTab::DaemonSets => CurrentView::DaemonSets,
+      WorkloadsTab::Jobs => CurrentView::Jobs,
+      WorkloadsTab::CronJobs => CurrentView::CronJobs,
+    }
+  }
+}
+
 pub struct WorkloadsView {
   active_tab: WorkloadsTab,
+  refresh_status: Entity<RefreshStatus>,
   pods: Entity<PodsView>,
   deployments: Entity<DeploymentsView>,
   statefulsets: Entity<StatefulSetsView>,
@@ -45,9 +61,11 @@ impl WorkloadsView {
     daemonsets: Entity<DaemonSetsView>,
     jobs: Entity<JobsView>,
     cronjobs: Entity<CronJobsView>,
+    cx: &mut Context<'_, Self>,
   ) -> Self {
     Self {
       active_tab: WorkloadsTab::Pods,
+      refresh_status: cx.new(|cx| RefreshStatus::new(WorkloadsTab::Pods.view(), cx)),
       pods,
       deployments,
       statefulsets,
@@ -68,6 +86,9 @@ impl Render for WorkloadsView {
         .selected(active == tab)
         .on_click(cx.listener(move |this, _ev, _w, cx| {
           this.active_tab = tab;
+          this
+            .refresh_status
+            .update(cx, |status, cx| status.set_view(tab.view(), cx));
           cx.notify();
         }))
     };
@@ -91,7 +112,7 @@ impl Render for WorkloadsView {
 
     v_flex()
       .size_full()
-      .child(render_k8s_header(tab_bar, true, div(), cx))
+      .child(render_k8s_header(tab_bar, true, self.refresh_status.clone(), cx))
       .child(div().flex_1().min_h_0().child(body))
   }
 }
@@ -85,6 +85,12 @@ pub fn kubectl_cmd() -> Command {
   create_cmd(path)
 }
 
+/// Create a Command for Homebrew's `brew`, finding the binary in common paths
+pub fn brew_cmd() -> Command {
+  let path = find_binary("brew").unwrap_or_else(|| PathBuf::from("brew"));
+  create_cmd(path)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;